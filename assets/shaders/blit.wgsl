@@ -0,0 +1,31 @@
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) texcoord: vec2<f32>,
+}
+
+
+
+@group(0) @binding(0)
+var texture: texture_2d<f32>;
+@group(0) @binding(1)
+var tex_sampler: sampler;
+
+
+@vertex 
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    // (한국어) 화면 전체를 덮는 삼각형 하나를 그립니다.
+    // (English Translation) Draws a single triangle that covers the whole screen.
+    let x = f32((vertex_index << 1u) & 2u);
+    let y = f32(vertex_index & 2u);
+
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(x * 2.0 - 1.0, 1.0 - y * 2.0, 0.0, 1.0);
+    out.texcoord = vec2<f32>(x, y);
+    return out;
+}
+
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return textureSample(texture, tex_sampler, in.texcoord);
+}
@@ -0,0 +1,88 @@
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) texcoord: vec2<f32>,
+}
+
+
+
+// (한국어) 이 밝기보다 밝은 부분만 빛나게 됩니다.
+// (English Translation) Only the parts brighter than this luminance will glow.
+const THRESHOLD: f32 = 0.75;
+
+// (한국어) 장면에 더해지는 빛의 세기 입니다.
+// (English Translation) The strength of the glow added to the scene.
+const INTENSITY: f32 = 0.6;
+
+
+
+@group(0) @binding(0)
+var texture: texture_2d<f32>;
+@group(0) @binding(1)
+var tex_sampler: sampler;
+@group(1) @binding(0)
+var bloom_texture: texture_2d<f32>;
+@group(1) @binding(1)
+var bloom_sampler: sampler;
+
+
+@vertex 
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    // (한국어) 화면 전체를 덮는 삼각형 하나를 그립니다.
+    // (English Translation) Draws a single triangle that covers the whole screen.
+    let x = f32((vertex_index << 1u) & 2u);
+    let y = f32(vertex_index & 2u);
+
+    var out: VertexOutput;
+    out.clip_position = vec4<f32>(x * 2.0 - 1.0, 1.0 - y * 2.0, 0.0, 1.0);
+    out.texcoord = vec2<f32>(x, y);
+    return out;
+}
+
+
+fn luminance(color: vec3<f32>) -> f32 {
+    return dot(color, vec3<f32>(0.2126, 0.7152, 0.0722));
+}
+
+
+fn blur(texcoord: vec2<f32>, direction: vec2<f32>) -> vec4<f32> {
+    // (한국어) 9개의 텍셀을 가우시안 가중치로 섞습니다.
+    // (English Translation) Mixes 9 texels with gaussian weights.
+    var weights = array<f32, 5>(0.227027, 0.1945946, 0.1216216, 0.054054, 0.016216);
+    let step = direction / vec2<f32>(textureDimensions(texture));
+
+    var color = textureSample(texture, tex_sampler, texcoord).rgb * weights[0];
+    for (var i = 1; i < 5; i++) {
+        let offset = step * f32(i);
+        color += textureSample(texture, tex_sampler, texcoord + offset).rgb * weights[i];
+        color += textureSample(texture, tex_sampler, texcoord - offset).rgb * weights[i];
+    }
+    return vec4<f32>(color, 1.0);
+}
+
+
+@fragment
+fn fs_bright(in: VertexOutput) -> @location(0) vec4<f32> {
+    let color = textureSample(texture, tex_sampler, in.texcoord).rgb;
+    let factor = smoothstep(THRESHOLD, 1.0, luminance(color));
+    return vec4<f32>(color * factor, 1.0);
+}
+
+
+@fragment
+fn fs_blur_horizontal(in: VertexOutput) -> @location(0) vec4<f32> {
+    return blur(in.texcoord, vec2<f32>(1.0, 0.0));
+}
+
+
+@fragment
+fn fs_blur_vertical(in: VertexOutput) -> @location(0) vec4<f32> {
+    return blur(in.texcoord, vec2<f32>(0.0, 1.0));
+}
+
+
+@fragment
+fn fs_composite(in: VertexOutput) -> @location(0) vec4<f32> {
+    let scene = textureSample(texture, tex_sampler, in.texcoord);
+    let glow = textureSample(bloom_texture, bloom_sampler, in.texcoord).rgb;
+    return vec4<f32>(min(scene.rgb + glow * INTENSITY, vec3<f32>(1.0)), scene.a);
+}
@@ -7,6 +7,7 @@ struct VertexInput {
     @location(4) color: vec4<f32>, 
     @location(5) size: vec2<f32>, 
     @location(6) texture_index: u32, 
+    @location(7) texcoord_rect: vec4<f32>, 
 }
 
 struct VertexOutput {
@@ -71,7 +72,7 @@ fn vs_main(in: VertexInput) -> VertexOutput {
     var out: VertexOutput;
     out.clip_position = cam.projection * cam.camera * transform * vec4<f32>(position, 1.0);
     out.color = in.color;
-    out.texcoord = texcoord;
+    out.texcoord = mix(in.texcoord_rect.xy, in.texcoord_rect.zw, texcoord);
     out.texture_index = in.texture_index;
     return out;
 }
@@ -10,12 +10,14 @@ struct VertexInput {
     @location(7) texcoord_right: f32, 
     @location(8) color: vec4<f32>, 
     @location(9) size: vec2<f32>, 
+    @location(10) pattern: u32, 
 }
 
 struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>, 
     @location(0) color: vec4<f32>, 
     @location(1) texcoord: vec2<f32>, 
+    @location(2) @interpolate(flat) pattern: u32, 
 }
 
 struct CameraData {
@@ -74,12 +76,33 @@ fn vs_main(in: VertexInput) -> VertexOutput {
     out.clip_position = cam.projection * cam.camera * transform * vec4<f32>(position, 1.0);
     out.color = in.color;
     out.texcoord = texcoord;
+    out.pattern = in.pattern;
     return out;
 }
 
 
 
+// Pattern drawn over the tile in screen space. (0: Solid, 1: Stripes, 2: CrossHatch)
+fn pattern_shade(pattern: u32, pixel: vec2<f32>) -> f32 {
+    let rising = fract((pixel.x + pixel.y) / 12.0);
+    let falling = fract((pixel.x - pixel.y) / 12.0);
+    switch (pattern) {
+        case 1u: {
+            return select(1.0, 0.8, rising < 0.5);
+        }
+        case 2u: {
+            return select(1.0, 0.45, rising < 0.25 || falling < 0.25);
+        }
+        default {
+            return 1.0;
+        }
+    }
+}
+
+
+
 @fragment 
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
-    return in.color;
+    let shade = pattern_shade(in.pattern, in.clip_position.xy);
+    return vec4<f32>(in.color.rgb * shade, in.color.a);
 }
@@ -11,7 +11,7 @@ mod assets {
     pub const KEY_DIR_REL_PATH_STR: &'static str = "./keys";
     pub const ASSET_DIR_REL_PATH_STR: &'static str = "./assets";
     lazy_static! {
-        pub static ref ASSET_LISTS: HashMap<PathBuf, Types> = {
+        pub static ref ASSET_LISTS: HashMap<PathBuf, (Types, Compression)> = {
             let txt = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/AssetLists.txt"));
             parsing_asset_lists_txt(txt)
         };
@@ -84,6 +84,19 @@ mod assets {
     }
 
 
+    /// #### 한국어 </br>
+    /// 에셋 파일이 저장될 압축 방식입니다. </br>
+    /// 
+    /// #### English (Translation)
+    /// The compression method in which an asset file will be stored. </br>
+    /// 
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Compression {
+        Stored,
+        Deflate,
+    }
+
+
     /// #### 한국어 </br>
     /// `AssetLists.txt`파일을 구문분석하여 에셋 목록를 반환합니다. </br>
     /// 구문 분석 도중 오류가 발생할 경우 오류 메시지를 반환합니다. </br>
@@ -92,7 +105,7 @@ mod assets {
     /// Parses the file `AssetLists.txt` and returns a list of assets. </br>
     /// If an error occurs during parsing, an error message is returned. </br>
     /// 
-    pub fn parsing_asset_lists_txt(txt: &str) -> HashMap<PathBuf, Types> {
+    pub fn parsing_asset_lists_txt(txt: &str) -> HashMap<PathBuf, (Types, Compression)> {
         const COMMENT_CH: char = '#';
         let mut list = HashMap::new();
 
@@ -103,6 +116,7 @@ mod assets {
             // (English Translation) Read one line of content word by word.
             let mut path_str = String::new();
             let mut type_str = String::new();
+            let mut compression_str = String::new();
             'line: for (idx, word) in line_str.trim().split_whitespace().enumerate() {
                 for ch in word.chars() {
                     if ch == COMMENT_CH {
@@ -114,6 +128,7 @@ mod assets {
                     match idx {
                         0 => path_str.push(ch),
                         1 => type_str.push(ch),
+                        2 => compression_str.push(ch),
                         _ => panic!("invalid syntax. (line:{})", line + 1),
                     }
                 }
@@ -140,6 +155,14 @@ mod assets {
                     _ => panic!("invalid types. (line:{})", line + 1),
                 };
 
+                // (한국어) <압축>이 유효한지 확인합니다. 쓰기 가능한 에셋은 압축할 수 없습니다.
+                // (English Translation) Checks if <Compression> is valid. Writable assets cannot be compressed.
+                let compression = match compression_str.as_str() {
+                    "" => Compression::Stored,
+                    "Deflate" if !types.writable() => Compression::Deflate,
+                    _ => panic!("invalid compression. (line:{})", line + 1),
+                };
+
                 // (한국어) <경로>가 루트 에셋 디렉토리의 하위 경로인지 확인합니다.
                 // (English Translation) Checks if <Path> is a subpath of the root asset directory.
                 if !utils::is_subpath(ASSET_DIR_REL_PATH_STR, &path) {
@@ -155,7 +178,7 @@ mod assets {
                 // (한국어) 이미 리스트에 <경로>가 포함되어 있는지 확인합니다.
                 // (English Translation) Check if the list already contains the <Path>.
                 match list.contains_key(&path) {
-                    false => list.insert(path, types),
+                    false => list.insert(path, (types, compression)),
                     true => panic!("duplicate path. (line:{})", line + 1),
                 };
             }
@@ -182,6 +205,25 @@ mod utils {
         path.starts_with(root)
     }
 
+    /// #### 한국어 </br>
+    /// 에셋 파일을 deflate 방식으로 압축한 바이트 배열을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the byte array of the asset file compressed with deflate. </br>
+    /// 
+    pub fn deflate<P: AsRef<Path>>(path: P) -> Result<Vec<u8>, String> {
+        use std::fs;
+        use std::io::Write;
+        use flate2::{Compression, write::DeflateEncoder};
+
+        let bytes = fs::read(path)
+            .map_err(|err| format!("Failed to read asset file. (error: {})", err))?;
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&bytes)
+            .and_then(|_| encoder.finish())
+            .map_err(|err| format!("Failed to compress asset file. (error: {})", err))
+    }
+
     pub fn file_exsist<R: AsRef<Path>, P: AsRef<Path>>(root: R, subpath: P) -> bool {
         let path = PathBuf::from_iter([root.as_ref(), subpath.as_ref()])
             .canonicalize()
@@ -215,6 +257,7 @@ fn gen_asset_sha256_keys() {
         KEY_DIR_REL_PATH_STR,
         ASSET_DIR_REL_PATH_STR,
         ASSET_LISTS,
+        Compression,
     };
 
 
@@ -228,7 +271,7 @@ fn gen_asset_sha256_keys() {
 
     // (한국어) 각각의 스레드에서 해시 값을 생성하는 작업을 수행합니다.
     // (English Translation) Each thread performs the task of generating a hash value.
-    for (path, types) in ASSET_LISTS.iter() {
+    for (path, (types, compression)) in ASSET_LISTS.iter() {
         handles.push(thread::spawn(move || {
             // (한국어) `AssetLists.txt`목록에 있는 에셋 파일이 존재하는지 확인합니다.
             // (English) Checking if the asset file in the `AssetLists.txt` list exists.
@@ -249,19 +292,32 @@ fn gen_asset_sha256_keys() {
                 }
             }
 
-            // (한국어) 정적 데이터 유형일경우 SHA256 해시 값을 생성하고 파일에 저장합니다.
-            // (English) If it's a static datatype, generate a SHA256 hash value and save it to a file.
+            // (한국어) 
+            // 정적 데이터 유형일경우 SHA256 해시 값을 생성하고 파일에 저장합니다.
+            // 압축된 에셋은 저장될 (압축된) 바이트 배열의 해시 값을 생성합니다.
+            // 
+            // (English) 
+            // If it's a static datatype, generate a SHA256 hash value and save it to a file.
+            // For compressed assets, the hash value of the stored (compressed) byte array is generated.
+            // 
             if !types.writable() {
                 let hash = {
-                    let mut asset_file = fs::File::open(&asset_file_path)
-                        .map_err(|err| {
-                            format!("Failed to open asset file. (error: {})", err.to_string())
-                        })?;
                     let mut hasher = Sha256::new();
-                    io::copy(&mut asset_file, &mut hasher)
-                        .map_err(|err| {
-                            format!("Failed to copy asset file. (error: {})", err.to_string())
-                        })?;
+                    match compression {
+                        Compression::Stored => {
+                            let mut asset_file = fs::File::open(&asset_file_path)
+                                .map_err(|err| {
+                                    format!("Failed to open asset file. (error: {})", err.to_string())
+                                })?;
+                            io::copy(&mut asset_file, &mut hasher)
+                                .map_err(|err| {
+                                    format!("Failed to copy asset file. (error: {})", err.to_string())
+                                })?;
+                        },
+                        Compression::Deflate => {
+                            hasher.update(utils::deflate(&asset_file_path)?);
+                        },
+                    }
                     hasher.finalize()
                 };
 
@@ -288,13 +344,16 @@ fn gen_asset_sha256_keys() {
 /// #### 한국어 </br>
 /// 원본 에셋 파일을 빌드 대상 디렉토리에 복사합니다. </br>
 /// `AssetList.txt`목록에 존재하는 에셋만 복사합니다. </br>
+/// 압축 방식이 지정된 에셋은 압축하여 저장합니다. </br>
 /// 
 /// #### English </br>
 /// Copy the original asset files to the build target directory. </br>
 /// Copy only the assets that exist in the `AssetList.txt` list. </br>
+/// Assets with a compression method specified are stored compressed. </br>
 /// 
 fn copy_asset_to_target() {
     use std::env;
+    use std::fs;
     use std::path::{Path, PathBuf};
 
     use fs_extra::{dir, file};
@@ -302,6 +361,7 @@ fn copy_asset_to_target() {
     use assets::{
         ASSET_DIR_REL_PATH_STR,
         ASSET_LISTS,
+        Compression,
     };
 
     let target = env::var("TARGET").unwrap();
@@ -312,7 +372,7 @@ fn copy_asset_to_target() {
         let target_asset_path = PathBuf::from_iter([&target_path, Path::new(ASSET_DIR_REL_PATH_STR)]);
         dir::create(&target_asset_path, true).expect("Failed to install asset directory.");
 
-        for (path, types) in ASSET_LISTS.iter() {
+        for (path, (types, compression)) in ASSET_LISTS.iter() {
             if types.creatable() {
                 continue;
             }
@@ -328,8 +388,16 @@ fn copy_asset_to_target() {
                 }
             }
 
-            file::copy(asset_file_path, &target_asset_file_path, &file::CopyOptions::default())
-                .expect("Failed to copy assets.");
+            match compression {
+                Compression::Stored => {
+                    file::copy(asset_file_path, &target_asset_file_path, &file::CopyOptions::default())
+                        .expect("Failed to copy assets.");
+                },
+                Compression::Deflate => {
+                    let bytes = utils::deflate(asset_file_path).unwrap_or_else(|err| panic!("{}", err));
+                    fs::write(&target_asset_file_path, bytes).expect("Failed to write compressed assets.");
+                },
+            }
         }
         return;
     }
@@ -339,7 +407,7 @@ fn copy_asset_to_target() {
         let target_asset_path = PathBuf::from_iter([&target_path, Path::new(ASSET_DIR_REL_PATH_STR)]);
         dir::create(&target_asset_path, true).expect("Failed to install asset directory.");
 
-        for (path, data_type) in ASSET_LISTS.iter() {
+        for (path, (data_type, compression)) in ASSET_LISTS.iter() {
             if data_type.creatable() {
                 continue;
             }
@@ -355,8 +423,16 @@ fn copy_asset_to_target() {
                 }
             }
 
-            file::copy(asset_file_path, &target_asset_file_path, &file::CopyOptions::default())
-                .expect("Failed to copy assets.");
+            match compression {
+                Compression::Stored => {
+                    file::copy(asset_file_path, &target_asset_file_path, &file::CopyOptions::default())
+                        .expect("Failed to copy assets.");
+                },
+                Compression::Deflate => {
+                    let bytes = utils::deflate(asset_file_path).unwrap_or_else(|err| panic!("{}", err));
+                    fs::write(&target_asset_file_path, bytes).expect("Failed to write compressed assets.");
+                },
+            }
         }
         return;
     }
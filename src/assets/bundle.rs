@@ -1,23 +1,10 @@
-use std::io;
-use std::fs::OpenOptions;
+#[cfg(debug_assertions)]
+use std::env;
 use std::path::{Path, PathBuf};
+#[cfg(debug_assertions)]
+use std::time::SystemTime;
 use std::collections::HashMap;
-use std::thread;
 use std::sync::{Arc, RwLock};
-use std::sync::mpsc::{self, Receiver};
-use std::sync::atomic::{AtomicBool, Ordering as MemOrdering};
-
-use sha2::{Digest, Sha256};
-use notify::{
-    Event,
-    EventKind,
-    event::ModifyKind,
-    Config,
-    Watcher,
-    RecursiveMode,
-    RecommendedWatcher,
-    Result as NotifyResult,
-};
 
 use crate::{
     game_err,
@@ -28,11 +15,13 @@ use crate::{
             DynamicHandle,
             OptionalHandle,
         },
-        list::{
-            ASSET_LISTS,
-            AssetKeys,
-        },
+        compression::Compression,
+        list::ASSET_LISTS,
         path::ROOT_ASSET_PATH,
+        source::{
+            AssetSource,
+            Packed,
+        },
         types::Types,
     },
     system::error::{
@@ -40,12 +29,18 @@ use crate::{
         GameError,
     },
 };
+#[cfg(debug_assertions)]
+use crate::assets::source::Directory;
 
 
-const ERR_TITLE_VERIFICATION_FAILED: &'static str = "Asset verification failed";
-const ERR_TITLE_WATCHER_INIT_FAILED: &'static str = "Asset file watcher initialize failed";
-const ERR_VERIFICATION_FAILED: &'static str = "Asset file verification failed for the following reasons:";
-const ERR_WATCHER_INIT_FAILED: &'static str = "Asset file watcher initialization failed for following reasons:";
+/// #### 한국어 </br>
+/// 디버그 빌드에서 이 환경 변수가 설정된 경우, 해당 디렉토리의 에셋 파일을 그대로 읽어옵니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// In debug builds, if this environment variable is set, loose asset files are read from that directory. </br>
+/// 
+#[cfg(debug_assertions)]
+const ASSET_DIR_ENV: &'static str = "MILLENNIUM_RUN_ASSET_DIR";
 
 
 
@@ -59,47 +54,27 @@ const ERR_WATCHER_INIT_FAILED: &'static str = "Asset file watcher initialization
 /// 
 #[derive(Debug, Clone)]
 pub struct AssetBundle {
-    root_path: PathBuf,
-    asset_list: Arc<HashMap<PathBuf, Types>>,
-    loaded_assets: Arc<RwLock<HashMap<PathBuf, AssetHandle>>>,
-    integrity_flag: Arc<AtomicBool>,
+    source: Arc<dyn AssetSource>,
+    asset_list: Arc<HashMap<PathBuf, (Types, Compression)>>,
+    loaded_assets: Arc<RwLock<HashMap<PathBuf, (AssetHandle, usize)>>>,
 }
 
 impl AssetBundle {
+    /// #### 한국어 </br>
+    /// 새로운 에셋 묶음을 생성합니다. </br>
+    /// 디버그 빌드에서 `MILLENNIUM_RUN_ASSET_DIR` 환경 변수가 설정된 경우 
+    /// 해당 디렉토리를 출처로 사용하고, 그렇지 않은 경우 배포된 에셋 디렉토리를 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a new asset bundle. </br>
+    /// In debug builds, if the `MILLENNIUM_RUN_ASSET_DIR` environment variable is set, 
+    /// that directory is used as the source; otherwise the shipped asset directory is used. </br>
+    /// 
     pub fn new() -> AppResult<Self> {
-        let root_path = ROOT_ASSET_PATH.clone()?;
         let asset_list = Arc::new(ASSET_LISTS.clone()?);
         let loaded_assets = Arc::new(RwLock::new(HashMap::with_capacity(asset_list.len())));
-        
-        // (한국어) 에셋 파일 감시자를 생성합니다.
-        // (English Translation) Create an asset file watcher.
-        let (sender, receiver) = mpsc::channel();
-        let mut watcher = RecommendedWatcher::new(sender, Config::default())
-            .map_err(|e| game_err!(
-                ERR_TITLE_WATCHER_INIT_FAILED, "{} {}", ERR_WATCHER_INIT_FAILED, e.to_string()
-            ))?;
-        watcher.watch(&root_path, RecursiveMode::Recursive)
-            .map_err(|e| game_err!(
-                ERR_TITLE_WATCHER_INIT_FAILED, "{} {}", ERR_WATCHER_INIT_FAILED, e.to_string()
-            ))?;
-
-        // (한국어) 에셋 파일 감시를 시작합니다.
-        // (English Translation) Start monitoring asset files.
-        let integrity_flag = Arc::new(AtomicBool::new(true));
-        let integrity_flag_cloned = integrity_flag.clone();
-        let root_path_cloned = root_path.clone();
-        let asset_list_cloned = asset_list.clone();
-        thread::spawn(move || {
-            watcher_main(watcher, receiver, root_path_cloned, asset_list_cloned);
-            integrity_flag_cloned.store(false, MemOrdering::Release);
-        });
-
-        // (한국어) 에셋 파일 검사를 시작합니다.
-        // (English Translation) Start checking asset files.
-        check_assets(&root_path, &asset_list)?;
-
-
-        Ok(Self { root_path, asset_list, loaded_assets, integrity_flag })
+        let source = create_source(asset_list.clone())?;
+        Ok(Self { source, asset_list, loaded_assets })
     }
 
     /// #### 한국어 </br>
@@ -110,230 +85,267 @@ impl AssetBundle {
     /// 
     #[inline]
     pub fn check_integrity(&self) -> bool {
-        self.integrity_flag.load(MemOrdering::Acquire)
+        self.source.check_integrity()
+    }
+
+    /// #### 한국어 </br>
+    /// 로드된 에셋의 개수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the number of loaded assets. </br>
+    /// 
+    #[cfg(debug_assertions)]
+    #[inline]
+    pub fn num_loaded(&self) -> usize {
+        self.loaded_assets.read().expect("Failed to access loaded assets.").len()
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 경로가 에셋 목록에 등록되어 있는 경우 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if the given path is registered in the asset list. </br>
+    /// 
+    #[inline]
+    pub fn contains<P: AsRef<Path>>(&self, rel_path: P) -> bool {
+        self.asset_list.contains_key(rel_path.as_ref())
     }
 
     /// #### 한국어 </br>
-    /// 에셋 파일의 핸들을 가져옵니다. </br>
+    /// 에셋 파일의 핸들을 가져오고 참조 횟수를 하나 늘립니다. </br>
+    /// 가져온 핸들은 사용을 마친 후 `release` 함수로 반드시 해제해야 합니다. </br>
     /// 핸들을 가져오는 도중 오류가 발생한 경우 `PanicMsg`를 반환합니다. </br>
     /// 
     /// #### English (Translation) </br>
-    /// Gets the handle to the asset file. </br>
+    /// Gets the handle to the asset file and increments its reference count by one. </br>
+    /// The handle must be released with the `release` function once it is no longer used. </br>
     /// Returns `PanicMsg` if an error occurred while retrieving the handle. </br>
     /// 
     pub fn get<P: AsRef<Path>>(&self, rel_path: P) -> AppResult<AssetHandle> {
-        {
-            let loaded_assets = self.loaded_assets
-                .read()
-                .expect("Failed to access loaded assets.");
-            if let Some(handle) = loaded_assets.get(rel_path.as_ref()) {
-                return Ok(handle.clone())
+        return self.acquire(rel_path.as_ref(), 1);
+    }
+
+    /// #### 한국어 </br>
+    /// 참조 횟수를 늘리지 않고 에셋 파일을 미리 불러옵니다. </br>
+    /// 불러온 에셋은 다음 `get` 함수 호출 시 다시 읽지 않고 사용되며, 
+    /// 마지막 참조가 해제될 때 함께 해제됩니다. </br>
+    /// 에셋을 불러오는 도중 오류가 발생한 경우 `PanicMsg`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Loads the asset file in advance without incrementing its reference count. </br>
+    /// The loaded asset is used by the next `get` call without being read again, 
+    /// and is freed together when the last reference is released. </br>
+    /// Returns `PanicMsg` if an error occurred while loading the asset. </br>
+    /// 
+    pub fn prefetch<P: AsRef<Path>>(&self, rel_path: P) -> AppResult<()> {
+        self.acquire(rel_path.as_ref(), 0)?;
+        return Ok(());
+    }
+
+    /// #### 한국어 </br>
+    /// 에셋 파일을 다시 읽어 로드된 핸들을 교체합니다. </br>
+    /// 참조 횟수는 변하지 않으며, 로드되지 않은 에셋인 경우 미리 불러옵니다. </br>
+    /// 에셋을 불러오는 도중 오류가 발생한 경우 `PanicMsg`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Reads the asset file again and replaces the loaded handle. </br>
+    /// The reference count is unchanged, and an asset that is not loaded is prefetched. </br>
+    /// Returns `PanicMsg` if an error occurred while loading the asset. </br>
+    /// 
+    #[cfg(debug_assertions)]
+    pub fn reload<P: AsRef<Path>>(&self, rel_path: P) -> AppResult<AssetHandle> {
+        let handle = self.open(rel_path.as_ref())?;
+        self.loaded_assets
+            .write()
+            .expect("Failed to access loaded assets.")
+            .entry(rel_path.as_ref().into())
+            .and_modify(|(loaded, _)| *loaded = handle.clone())
+            .or_insert_with(|| (handle.clone(), 0));
+        return Ok(handle);
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 경로의 에셋 참조 횟수를 하나 줄입니다. </br>
+    /// 마지막 참조가 해제된 경우 로드된 에셋 목록에서 에셋을 제거합니다. </br>
+    /// 디버그 빌드에서 가져오지 않은 에셋을 해제하는 경우 패닉이 발생합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Decrements the reference count of the asset at the given path by one. </br>
+    /// When the last reference is released, the asset is removed from the list of loaded assets. </br>
+    /// In debug builds, releasing an asset that was not acquired panics. </br>
+    /// 
+    pub fn release<P: AsRef<Path>>(&self, rel_path: P) {
+        let mut loaded_assets = self.loaded_assets
+            .write()
+            .expect("Failed to access loaded assets.");
+        let refs = match loaded_assets.get_mut(rel_path.as_ref()) {
+            Some((_, refs)) if *refs > 0 => refs,
+            _ => {
+                debug_assert!(false, "Asset released more times than acquired: {}", rel_path.as_ref().display());
+                return;
             }
+        };
+
+        *refs -= 1;
+        if *refs == 0 {
+            loaded_assets.remove(rel_path.as_ref());
         }
+    }
 
+    /// #### 한국어 </br>
+    /// 에셋 파일이 마지막으로 수정된 시간을 가져옵니다. </br>
+    /// 파일의 메타데이터를 읽을 수 없는 경우 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the time the asset file was last modified. </br>
+    /// Returns `None` if the file's metadata cannot be read. </br>
+    /// 
+    #[cfg(debug_assertions)]
+    pub fn modified<P: AsRef<Path>>(&self, rel_path: P) -> Option<SystemTime> {
+        let abs_path = self.source.resolve(rel_path.as_ref());
+        return abs_path.metadata().and_then(|meta| meta.modified()).ok();
+    }
+
+    /// #### 한국어 </br>
+    /// 에셋을 로드된 에셋 목록에 등록하고 참조 횟수를 주어진 값만큼 늘립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Registers the asset in the list of loaded assets and increments its reference count by the given amount. </br>
+    /// 
+    fn acquire(&self, rel_path: &Path, count: usize) -> AppResult<AssetHandle> {
         {
-            if let Some(types) = self.asset_list.get(rel_path.as_ref()) {
-                let abs_path = PathBuf::from_iter([&self.root_path, rel_path.as_ref()]);
-                let handle = match types {
-                    Types::Static => AssetHandle::Static(Arc::new(RwLock::new(StaticHandle::new(abs_path)?))),
-                    Types::Dynamic => AssetHandle::Dynamic(Arc::new(RwLock::new(DynamicHandle::new(abs_path)?))),
-                    Types::Optional => AssetHandle::Optional(Arc::new(RwLock::new(OptionalHandle::new(abs_path)?))),
-                };
-
-                let mut loaded_assets = self.loaded_assets
-                    .write()
-                    .expect("Failed to access loaded assets.");
-                loaded_assets.insert(rel_path.as_ref().into(), handle.clone());
-                return Ok(handle);
+            let mut loaded_assets = self.loaded_assets
+                .write()
+                .expect("Failed to access loaded assets.");
+            if let Some((handle, refs)) = loaded_assets.get_mut(rel_path) {
+                *refs += count;
+                return Ok(handle.clone());
             }
         }
 
-        Err(game_err!(
-            "Failed to get asset handle",
-            "The asset path given in the asset list does no exist."
-        ))
+        let handle = self.open(rel_path)?;
+        let mut loaded_assets = self.loaded_assets
+            .write()
+            .expect("Failed to access loaded assets.");
+        let (handle, refs) = loaded_assets
+            .entry(rel_path.into())
+            .or_insert((handle, 0));
+        *refs += count;
+        return Ok(handle.clone());
     }
 
-    
     /// #### 한국어 </br>
-    /// 로드된 에셋 목록에서 주어진 경로의 에셋을 제거합니다. </br>
+    /// 에셋 목록에 등록된 에셋 파일의 새로운 핸들을 생성합니다. </br>
     /// 
     /// #### English (Translation) </br>
-    /// Removes the asset at the given path from the list of loaded assets. </br>
+    /// Creates a new handle to an asset file registered in the asset list. </br>
     /// 
-    pub fn release<P: AsRef<Path>>(&self, rel_path: P) {
-        self.loaded_assets
-            .write()
-            .expect("Failed to access loaded assets.")
-            .remove(rel_path.as_ref());
+    fn open(&self, rel_path: &Path) -> AppResult<AssetHandle> {
+        if let Some(&(types, compression)) = self.asset_list.get(rel_path) {
+            let abs_path = self.source.resolve(rel_path);
+            let compression = match self.source.stores_compressed() {
+                true => compression,
+                false => Compression::Stored,
+            };
+            return Ok(match types {
+                Types::Static => AssetHandle::Static(Arc::new(RwLock::new(StaticHandle::new(abs_path, compression)?))),
+                Types::Dynamic => AssetHandle::Dynamic(Arc::new(RwLock::new(DynamicHandle::new(abs_path)?))),
+                Types::Optional => AssetHandle::Optional(Arc::new(RwLock::new(OptionalHandle::new(abs_path)?))),
+            });
+        }
+
+        Err(game_err!(
+            "Failed to get asset handle",
+            "The asset path given in the asset list does no exist."
+        ))
     }
 }
 
 
 
 /// #### 한국어 </br>
-/// 에셋 파일을 감시하는 루프입니다. </br>
-/// 에셋 파일의 데이터가 손상된 경우, 혹은 오류가 발생한 경우 
-/// 프로그램 실행 중에 이 루프를 빠져나옵니다. </br>
+/// 에셋 묶음이 에셋 파일을 읽어올 출처를 생성합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// This is a loop that monitors asset files. </br>
-/// If the data in the asset file is corrupted, or an error occurs, 
-/// this loop will be exited during program execution. </br>
+/// Creates the source from which the asset bundle reads asset files. </br>
 /// 
-fn watcher_main(
-    _watcher: RecommendedWatcher, 
-    receiver: Receiver<NotifyResult<Event>>,
-    root_path: PathBuf,
-    asset_list: Arc<HashMap<PathBuf, Types>>,
-) {
-    log::info!("Start monitoring asset files.");
-
-    // (한국어) 에셋 파일 감시자로부터 받아온 이벤트를 처리합니다.
-    // (English Translation) Processes events received from the asset file watcher. 
-    for result in receiver {
-        let event = match result {
-            Ok(event) => event,
-            Err(e) => {
-                log::error!("Asset file watcher has stopped for the following reasones: {}", e.to_string());
-                return;
-            }
-        };
-
-        match &event.kind {
-            EventKind::Modify(kind) => match kind {
-                // (한국어) 파일 데이터가 수정됬다는 이벤트를 수신한 경우.
-                // (English Trnaslation) If an event that file data has been modified is received.
-                ModifyKind::Data(_) => {
-                    // (한국어) 
-                    // 해당 파일이 에셋 리스트에 포함되어 있는지 확인합니다.
-                    // 포함되어 있는 경우 에셋 파일의 유형을 확인합니다.
-                    // 
-                    // (English Translation) 
-                    // Checks if the file is included in the asset list.
-                    // Checks the type of asset file, if included.
-                    // 
-                    for path in event.paths.iter() {
-                        let path = match get_subpath(&path, &root_path) {
-                            Ok(path) => path,
-                            Err(e) => {
-                                log::error!("Asset file watcher has stopped for the following reasones: {}", e.to_string());
-                                return;
-                            }
-                        };
-
-                        if let Some(types) = asset_list.get(path) {
-                            if !types.writable() {
-                                log::error!("[MODIFY] The data in the asset file is corrupted! (file:{})", path.display());
-                                return;
-                            }
-                        }
-                    }
-                },
-                _ => { }
-            },
-            _ => { }
-        };
+fn create_source(asset_list: Arc<HashMap<PathBuf, (Types, Compression)>>) -> AppResult<Arc<dyn AssetSource>> {
+    #[cfg(debug_assertions)]
+    if let Some(dir) = env::var_os(ASSET_DIR_ENV) {
+        return Ok(Arc::new(Directory::new(dir)?));
     }
 
-    log::info!("Finish monitoring asset files.");
+    return Ok(Arc::new(Packed::new(ROOT_ASSET_PATH.clone()?, asset_list)?));
 }
 
 
+#[cfg(all(test, debug_assertions))]
+impl AssetBundle {
+    /// #### 한국어 </br>
+    /// 테스트에서 사용할 에셋 묶음을 생성합니다. 저장소의 에셋 디렉토리를 출처로 사용하며, 
+    /// 주어진 경로들만 정적 에셋으로 등록합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates an asset bundle for tests. It uses the asset directory of the repository as the source, 
+    /// and registers only the given paths as static assets. </br>
+    /// 
+    pub fn for_test(rel_paths: &[&str]) -> Self {
+        let source = Directory::new(concat!(env!("CARGO_MANIFEST_DIR"), "/assets"))
+            .expect("Failed to open asset directory.");
+        let asset_list = rel_paths.iter()
+            .map(|rel_path| (PathBuf::from(rel_path), (Types::Static, Compression::Stored)))
+            .collect();
+        Self {
+            source: Arc::new(source),
+            asset_list: Arc::new(asset_list),
+            loaded_assets: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
 
-#[inline]
-fn get_subpath<'a>(path: &'a Path, base: &'a Path) -> Result<&'a Path, String> {
-    path.strip_prefix(base).map_err(|e| e.to_string())
+    /// #### 한국어 </br>
+    /// 주어진 경로의 에셋이 로드된 에셋 목록에 있는 경우 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if the asset at the given path is in the list of loaded assets. </br>
+    /// 
+    pub fn is_loaded<P: AsRef<Path>>(&self, rel_path: P) -> bool {
+        self.loaded_assets.read().unwrap().contains_key(rel_path.as_ref())
+    }
 }
 
 
+#[cfg(all(test, debug_assertions))]
+mod test {
+    use super::*;
 
-/// #### 한국어 </br>
-/// `AssetLists.txt`목록의 에셋을 확인합니다. </br>
-/// `AssetLists.txt`목록의 에셋 중 정적 유형의 에셋 파일은 
-/// 컴파일 타임에 바이너리에 키값이 저장됩니다. </br>
-/// 모든 정적 및 동적 유형의 에셋 파일이 존재하는 검사하고, 
-/// 정적 유형의 에셋파일에 대해 키값이 일치하는지 검사합니다. </br>
-/// 검사 도중 오류가 발생한 경우 `PanicMsg`를 반환합니다. </br>
-/// 
-/// 
-/// #### English (Translation) </br>
-/// Check the assets in the `AssetLists.txt` list. </br>
-/// Among the assets in the `AssetLists.txt` list, 
-/// the key value of static type asset files are stored in the binary at compile time. </br>
-/// Checks if all static and dynamic type asset files exist 
-/// and checks if the key values match for static type asset files. </br>
-/// If an error occurs during the check, it returns `PanicMsg`. </br>
-/// 
-fn check_assets(
-    root_path: &Path, 
-    asset_list: &HashMap<PathBuf, Types>,
-) -> AppResult<()> {
-    let mut handles = Vec::with_capacity(asset_list.len());
-
-    for (rel_path, types) in asset_list.iter() {
-        let types_cloned = types.clone();
-        let rel_path_cloned = rel_path.clone();
-        let root_path_cloned = root_path.to_path_buf().clone();
-        handles.push(thread::spawn(move || {
-            let abs_path = PathBuf::from_iter([&root_path_cloned, &rel_path_cloned]);
-            if !types_cloned.creatable() && !abs_path.is_file() {
-                return Err(game_err!(
-                    ERR_TITLE_VERIFICATION_FAILED,
-                    "{} {}",
-                    ERR_VERIFICATION_FAILED,
-                    "Asset is not a file or path cannot be found!"
-                ));
-            }
-            
-            if !types_cloned.writable() {
-                let key_file = AssetKeys::get(
-                    rel_path_cloned.to_str().unwrap()
-                ).ok_or_else(|| game_err!(
-                    ERR_TITLE_VERIFICATION_FAILED,
-                    "{} {}",
-                    ERR_VERIFICATION_FAILED,
-                    "Asset key not found!"
-                ))?;
-                
-                let hash = {
-                    let mut file = OpenOptions::new()
-                    .read(true)
-                    .open(abs_path)
-                    .map_err(|e| game_err!(
-                        ERR_TITLE_VERIFICATION_FAILED,
-                        "{} {}",
-                        ERR_VERIFICATION_FAILED,
-                        e.to_string()
-                    ))?;
-                    let mut hasher = Sha256::new();
-                    io::copy(&mut file, &mut hasher)
-                    .map_err(|e| game_err!(
-                        ERR_TITLE_VERIFICATION_FAILED,
-                        "{} {}",
-                        ERR_VERIFICATION_FAILED,
-                        e.to_string()
-                    ))?;
-                    hasher.finalize()
-                };
-                
-                if key_file.data.as_ref().ne(hash.as_slice()) {
-                    return Err(game_err!(
-                        ERR_TITLE_VERIFICATION_FAILED,
-                        "{} {}",
-                        ERR_VERIFICATION_FAILED,
-                        "Key values in asset files do not match!"
-                    ));
-                }
-            }
+    const FONT_PATH: &'static str = "fonts/nexon_lv2_gothic.ttf";
+
+    fn create_bundle() -> AssetBundle {
+        AssetBundle::for_test(&[FONT_PATH])
+    }
 
-            Ok(())
-        }));
+    fn is_loaded(asset_bundle: &AssetBundle) -> bool {
+        asset_bundle.is_loaded(FONT_PATH)
     }
 
-    for th in handles { 
-        th.join().unwrap()?;
+    #[test]
+    fn asset_stays_loaded_until_last_release() {
+        let asset_bundle = create_bundle();
+        asset_bundle.get(FONT_PATH).unwrap();
+        asset_bundle.get(FONT_PATH).unwrap();
+
+        asset_bundle.release(FONT_PATH);
+        assert!(is_loaded(&asset_bundle));
+
+        asset_bundle.release(FONT_PATH);
+        assert!(!is_loaded(&asset_bundle));
     }
 
-    Ok(())
+    #[test]
+    #[should_panic(expected = "released more times than acquired")]
+    fn double_release_is_caught() {
+        let asset_bundle = create_bundle();
+        asset_bundle.prefetch(FONT_PATH).unwrap();
+        asset_bundle.release(FONT_PATH);
+    }
 }
@@ -0,0 +1,105 @@
+use std::io::Read;
+
+use flate2::read::DeflateDecoder;
+
+use crate::{
+    game_err,
+    system::error::{
+        AppResult,
+        GameError,
+    },
+};
+
+
+
+/// #### 한국어 </br>
+/// 에셋 파일이 저장된 압축 방식입니다. </br>
+/// - 저장: 에셋 파일을 압축하지 않고 그대로 저장합니다. (예: 이미 압축된 오디오) </br>
+/// - 디플레이트: 에셋 파일을 deflate 방식으로 압축하여 저장합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The compression method in which an asset file is stored. </br>
+/// - Stored: The asset file is stored as is without compression. (e.g. already-compressed audio) </br>
+/// - Deflate: The asset file is stored compressed with deflate. </br>
+/// 
+#[repr(u8)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Compression {
+    #[default]
+    Stored,
+    Deflate,
+}
+
+impl Compression {
+    /// #### 한국어 </br>
+    /// 저장된 에셋 파일의 바이트 배열을 원래의 바이트 배열로 되돌립니다. </br>
+    /// 함수를 실행하는 도중 오류가 발생한 경우 `GameError`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Restores the stored byte array of an asset file to the original byte array. </br>
+    /// If an error occurs while executing the function, it returns `GameError`. </br>
+    /// 
+    pub fn decompress(&self, bytes: Vec<u8>) -> AppResult<Vec<u8>> {
+        match self {
+            Self::Stored => Ok(bytes),
+            Self::Deflate => {
+                let mut decoded = Vec::with_capacity(bytes.len() * 2);
+                DeflateDecoder::new(bytes.as_slice())
+                    .read_to_end(&mut decoded)
+                    .map_err(|e| game_err!(
+                        "Asset load failed",
+                        "Decompressing the asset file failed for the following reasons: {}",
+                        e.to_string()
+                    ))?;
+                Ok(decoded)
+            },
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::io::Write;
+    use std::env;
+
+    use flate2::write::DeflateEncoder;
+
+    use super::*;
+    use crate::assets::{
+        handle::StaticHandle,
+        interface::{AssetDecoder, HandleInner},
+    };
+
+    struct BytesDecoder;
+
+    impl AssetDecoder for BytesDecoder {
+        type Output = Vec<u8>;
+
+        fn decode(&self, buf: &[u8]) -> AppResult<Self::Output> {
+            Ok(buf.to_vec())
+        }
+    }
+
+    #[test]
+    fn compressed_asset_reads_back_original_bytes() {
+        let original: Vec<u8> = b"MillenniumRun ".iter().copied().cycle().take(64 * 1024).collect();
+
+        // (한국어) 압축하기 좋은 데이터를 압축하여 임시 파일에 저장합니다.
+        // (English Translation) Compresses a compressible blob and stores it in a temporary file.
+        let mut encoder = DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&original).unwrap();
+        let stored = encoder.finish().unwrap();
+        assert!(stored.len() < original.len());
+
+        let path = env::temp_dir().join(format!("millennium_run_compressed_{}.bin", std::process::id()));
+        fs::write(&path, &stored).unwrap();
+        let handle = StaticHandle::new(&path, Compression::Deflate);
+        fs::remove_file(&path).unwrap();
+
+        let bytes = handle.unwrap().read(&BytesDecoder).unwrap();
+        assert_eq!(bytes, original);
+        assert_eq!(Compression::Stored.decompress(stored.clone()).unwrap(), stored);
+    }
+}
@@ -6,6 +6,7 @@ use std::sync::{Arc, Weak, RwLock};
 use crate::{
     game_err,
     assets::{
+        compression::Compression,
         interface::{
             HandleInner,
             AssetDecoder,
@@ -35,15 +36,15 @@ pub struct StaticHandle {
 impl StaticHandle {
     /// #### 한국어 </br>
     /// 새로운 에셋 핸들의 내부 데이터를 생성합니다. </br>
-    /// 이 함수에서 파일을 열고 내부 바이트 배열을 읽어옵니다. </br>
+    /// 이 함수에서 파일을 열고 내부 바이트 배열을 읽어오며, 압축된 경우 압축을 해제합니다. </br>
     /// 함수를 실행하는 도중 오류가 발생한 경우 `GameError`를 반환합니다. </br>
     /// 
     /// #### English (Translation) </br>
     /// Creates internal data for a new asset handle. </br>
-    /// In this function, Developer open a file and read its internal byte array. </br>
+    /// In this function, Developer open a file and read its internal byte array, decompressing it if compressed. </br>
     /// If an error occurs while executing the function, it returns `GameError`. </br>
     /// 
-    pub(super) fn new<P: AsRef<Path>>(abs_path: P) -> AppResult<Self> {
+    pub(super) fn new<P: AsRef<Path>>(abs_path: P, compression: Compression) -> AppResult<Self> {
         log::info!("load static asset :: <Path:{}>", abs_path.as_ref().display());
         let mut file = OpenOptions::new()
             .read(true)
@@ -68,6 +69,7 @@ impl StaticHandle {
             ));
         }
 
+        let bytes = compression.decompress(bytes)?;
         Ok(Self { bytes })
     }
 }
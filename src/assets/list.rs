@@ -7,7 +7,10 @@ use lazy_static::lazy_static;
 
 use crate::{
     game_err,
-    assets::types::Types,
+    assets::{
+        compression::Compression,
+        types::Types,
+    },
     system::error::{
         AppResult,
         GameError,
@@ -21,7 +24,7 @@ use crate::{
 pub(super) struct AssetKeys;
 
 lazy_static! {
-    pub(super) static ref ASSET_LISTS: AppResult<HashMap<PathBuf, Types>> = {
+    pub(super) static ref ASSET_LISTS: AppResult<HashMap<PathBuf, (Types, Compression)>> = {
         let txt = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/AssetLists.txt"));
         parsing_asset_lists_txt(txt).map_err(|e| game_err!(
             "Failed to initialize asset list",
@@ -40,7 +43,7 @@ lazy_static! {
 /// Parses the file `AssetLists.txt` and returns a list of assets. </br>
 /// If an error occurs during parsing, an error message is returned. </br>
 /// 
-fn parsing_asset_lists_txt(txt: &str) -> Result<HashMap<PathBuf, Types>, String> {
+fn parsing_asset_lists_txt(txt: &str) -> Result<HashMap<PathBuf, (Types, Compression)>, String> {
     const COMMENT_CH: char = '#';
     let mut list = HashMap::new();
 
@@ -51,6 +54,7 @@ fn parsing_asset_lists_txt(txt: &str) -> Result<HashMap<PathBuf, Types>, String>
         // (English Translation) Read one line of content word by word.
         let mut path_str = String::new();
         let mut type_str = String::new();
+        let mut compression_str = String::new();
         'line: for (idx, word) in line_str.trim().split_whitespace().enumerate() {
             for ch in word.chars() {
                 if ch == COMMENT_CH {
@@ -62,6 +66,7 @@ fn parsing_asset_lists_txt(txt: &str) -> Result<HashMap<PathBuf, Types>, String>
                 match idx {
                     0 => path_str.push(ch),
                     1 => type_str.push(ch),
+                    2 => compression_str.push(ch),
                     _ => return Err(format!("invalid syntax. (line:{})", line + 1)),
                 }
             }
@@ -87,11 +92,19 @@ fn parsing_asset_lists_txt(txt: &str) -> Result<HashMap<PathBuf, Types>, String>
                 "Optional" => Types::Optional,
                 _ => return Err(format!("invalid types. (line:{})", line + 1)),
             };
+
+            // (한국어) <압축>이 유효한지 확인합니다. 쓰기 가능한 에셋은 압축할 수 없습니다.
+            // (English Translation) Checks if <Compression> is valid. Writable assets cannot be compressed.
+            let compression = match compression_str.as_str() {
+                "" => Compression::Stored,
+                "Deflate" if !types.writable() => Compression::Deflate,
+                _ => return Err(format!("invalid compression. (line:{})", line + 1)),
+            };
             
             // (한국어) 이미 리스트에 <경로>가 포함되어 있는지 확인합니다.
             // (English Translation) Check if the list already contains the <Path>.
             match list.contains_key(&path) {
-                false => list.insert(path, types),
+                false => list.insert(path, (types, compression)),
                 true => return Err(format!("duplicate path. (line:{})", line + 1)),
             };
         }
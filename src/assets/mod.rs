@@ -1,6 +1,8 @@
 pub mod bundle;
+pub mod compression;
 pub mod handle;
 pub mod interface;
 pub mod list;
 pub mod path;
+pub mod source;
 pub mod types;
@@ -0,0 +1,411 @@
+use std::io;
+use std::fmt;
+use std::fs::OpenOptions;
+use std::path::{Path, PathBuf};
+use std::collections::HashMap;
+use std::thread;
+use std::sync::Arc;
+use std::sync::mpsc::{self, Receiver};
+use std::sync::atomic::{AtomicBool, Ordering as MemOrdering};
+
+use sha2::{Digest, Sha256};
+use notify::{
+    Event,
+    EventKind,
+    event::ModifyKind,
+    Config,
+    Watcher,
+    RecursiveMode,
+    RecommendedWatcher,
+    Result as NotifyResult,
+};
+
+use crate::{
+    game_err,
+    assets::{
+        compression::Compression,
+        list::AssetKeys,
+        types::Types,
+    },
+    system::error::{
+        join_thread,
+        AppResult,
+        GameError,
+    },
+};
+
+
+const ERR_TITLE_VERIFICATION_FAILED: &'static str = "Asset verification failed";
+const ERR_TITLE_WATCHER_INIT_FAILED: &'static str = "Asset file watcher initialize failed";
+#[cfg(debug_assertions)]
+const ERR_TITLE_DIRECTORY_NOT_FOUND: &'static str = "Failed to get asset directory path";
+const ERR_VERIFICATION_FAILED: &'static str = "Asset file verification failed for the following reasons:";
+const ERR_WATCHER_INIT_FAILED: &'static str = "Asset file watcher initialization failed for following reasons:";
+#[cfg(debug_assertions)]
+const ERR_DIRECTORY_NOT_FOUND: &'static str = "Failed to get asset directory path for the following reasons:";
+
+
+
+/// #### 한국어 </br>
+/// 에셋 묶음이 에셋 파일을 읽어오는 출처의 인터페이스 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is an interface for the source from which the asset bundle reads asset files. </br>
+/// 
+pub trait AssetSource: fmt::Debug + Send + Sync {
+    /// #### 한국어 </br>
+    /// 에셋 디렉토리의 경로를 반환합니다. </br>
+    ///
+    /// #### English (Translation) </br>
+    /// Returns the path to the asset directory. </br>
+    ///
+    fn root_path(&self) -> &Path;
+
+    /// #### 한국어 </br>
+    /// 에셋 파일에 이상이 없는 경우 `true`를 반환합니다. </br>
+    ///
+    /// #### English (Translation) </br>
+    /// If there is no problem with the asset file, it returns `true`. </br>
+    ///
+    fn check_integrity(&self) -> bool;
+
+    /// #### 한국어 </br>
+    /// 에셋 파일이 에셋 목록에 지정된 압축 방식으로 저장되어 있는 경우 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if asset files are stored with the compression specified in the asset list. </br>
+    /// 
+    fn stores_compressed(&self) -> bool;
+
+    /// #### 한국어 </br>
+    /// 에셋 파일의 상대 경로를 절대 경로로 변환합니다. </br>
+    ///
+    /// #### English (Translation) </br>
+    /// Converts the relative path of the asset file to an absolute path. </br>
+    ///
+    #[inline]
+    fn resolve(&self, rel_path: &Path) -> PathBuf {
+        PathBuf::from_iter([self.root_path(), rel_path])
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 배포된 에셋 디렉토리에서 에셋 파일을 읽어오는 출처입니다. </br>
+/// 생성될 때 모든 에셋 파일의 키값을 검사하고, 실행 중에 에셋 파일을 감시합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A source that reads asset files from the shipped asset directory. </br>
+/// When created, it checks the key values of all asset files and monitors them at runtime. </br>
+/// 
+#[derive(Debug)]
+pub struct Packed {
+    root_path: PathBuf,
+    integrity_flag: Arc<AtomicBool>,
+}
+
+impl Packed {
+    pub fn new(root_path: PathBuf, asset_list: Arc<HashMap<PathBuf, (Types, Compression)>>) -> AppResult<Self> {
+        // (한국어) 에셋 파일 감시자를 생성합니다.
+        // (English Translation) Create an asset file watcher.
+        let (sender, receiver) = mpsc::channel();
+        let mut watcher = RecommendedWatcher::new(sender, Config::default())
+            .map_err(|e| game_err!(
+                ERR_TITLE_WATCHER_INIT_FAILED, "{} {}", ERR_WATCHER_INIT_FAILED, e.to_string()
+            ))?;
+        watcher.watch(&root_path, RecursiveMode::Recursive)
+            .map_err(|e| game_err!(
+                ERR_TITLE_WATCHER_INIT_FAILED, "{} {}", ERR_WATCHER_INIT_FAILED, e.to_string()
+            ))?;
+
+        // (한국어) 에셋 파일 감시를 시작합니다.
+        // (English Translation) Start monitoring asset files.
+        let integrity_flag = Arc::new(AtomicBool::new(true));
+        let integrity_flag_cloned = integrity_flag.clone();
+        let root_path_cloned = root_path.clone();
+        let asset_list_cloned = asset_list.clone();
+        thread::spawn(move || {
+            watcher_main(watcher, receiver, root_path_cloned, asset_list_cloned);
+            integrity_flag_cloned.store(false, MemOrdering::Release);
+        });
+
+        // (한국어) 에셋 파일 검사를 시작합니다.
+        // (English Translation) Start checking asset files.
+        check_assets(&root_path, &asset_list)?;
+
+        Ok(Self { root_path, integrity_flag })
+    }
+}
+
+impl AssetSource for Packed {
+    #[inline]
+    fn root_path(&self) -> &Path {
+        &self.root_path
+    }
+
+    #[inline]
+    fn check_integrity(&self) -> bool {
+        self.integrity_flag.load(MemOrdering::Acquire)
+    }
+
+    #[inline]
+    fn stores_compressed(&self) -> bool {
+        true
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 디버그 빌드에서 임의의 디렉토리의 에셋 파일을 그대로 읽어오는 출처입니다. </br>
+/// 에셋 파일을 수정하며 반복 작업할 수 있도록 키값 검사와 감시를 하지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A source that reads loose asset files from any directory in debug builds. </br>
+/// It skips key value checks and monitoring so asset files can be edited while iterating. </br>
+/// 
+#[cfg(debug_assertions)]
+#[derive(Debug)]
+pub struct Directory {
+    root_path: PathBuf,
+}
+
+#[cfg(debug_assertions)]
+impl Directory {
+    pub fn new<P: AsRef<Path>>(root_path: P) -> AppResult<Self> {
+        let root_path = root_path.as_ref()
+            .canonicalize()
+            .map_err(|e| game_err!(
+                ERR_TITLE_DIRECTORY_NOT_FOUND, "{} {}", ERR_DIRECTORY_NOT_FOUND, e.to_string()
+            ))?;
+        if !root_path.is_dir() {
+            return Err(game_err!(
+                ERR_TITLE_DIRECTORY_NOT_FOUND, "{} {}", ERR_DIRECTORY_NOT_FOUND, "The path is not a directory."
+            ));
+        }
+
+        log::info!("Loading loose asset files. (directory:{})", root_path.display());
+        Ok(Self { root_path })
+    }
+}
+
+#[cfg(debug_assertions)]
+impl AssetSource for Directory {
+    #[inline]
+    fn root_path(&self) -> &Path {
+        &self.root_path
+    }
+
+    #[inline]
+    fn check_integrity(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn stores_compressed(&self) -> bool {
+        false
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 에셋 파일을 감시하는 루프입니다. </br>
+/// 에셋 파일의 데이터가 손상된 경우, 혹은 오류가 발생한 경우 
+/// 프로그램 실행 중에 이 루프를 빠져나옵니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a loop that monitors asset files. </br>
+/// If the data in the asset file is corrupted, or an error occurs, 
+/// this loop will be exited during program execution. </br>
+/// 
+fn watcher_main(
+    _watcher: RecommendedWatcher, 
+    receiver: Receiver<NotifyResult<Event>>,
+    root_path: PathBuf,
+    asset_list: Arc<HashMap<PathBuf, (Types, Compression)>>,
+) {
+    log::info!("Start monitoring asset files.");
+
+    // (한국어) 에셋 파일 감시자로부터 받아온 이벤트를 처리합니다.
+    // (English Translation) Processes events received from the asset file watcher. 
+    for result in receiver {
+        let event = match result {
+            Ok(event) => event,
+            Err(e) => {
+                log::error!("Asset file watcher has stopped for the following reasones: {}", e.to_string());
+                return;
+            }
+        };
+
+        match &event.kind {
+            EventKind::Modify(kind) => match kind {
+                // (한국어) 파일 데이터가 수정됬다는 이벤트를 수신한 경우.
+                // (English Trnaslation) If an event that file data has been modified is received.
+                ModifyKind::Data(_) => {
+                    // (한국어) 
+                    // 해당 파일이 에셋 리스트에 포함되어 있는지 확인합니다.
+                    // 포함되어 있는 경우 에셋 파일의 유형을 확인합니다.
+                    // 
+                    // (English Translation) 
+                    // Checks if the file is included in the asset list.
+                    // Checks the type of asset file, if included.
+                    // 
+                    for path in event.paths.iter() {
+                        let path = match get_subpath(&path, &root_path) {
+                            Ok(path) => path,
+                            Err(e) => {
+                                log::error!("Asset file watcher has stopped for the following reasones: {}", e.to_string());
+                                return;
+                            }
+                        };
+
+                        if let Some((types, _)) = asset_list.get(path) {
+                            if !types.writable() && !is_hot_reloadable(path) {
+                                log::error!("[MODIFY] The data in the asset file is corrupted! (file:{})", path.display());
+                                return;
+                            }
+                        }
+                    }
+                },
+                _ => { }
+            },
+            _ => { }
+        };
+    }
+
+    log::info!("Finish monitoring asset files.");
+}
+
+
+
+/// #### 한국어 </br>
+/// 디버그 빌드에서 실행 도중 수정하고 다시 불러올 수 있는 에셋인 경우 `true`를 반환합니다. </br>
+/// 이 에셋들은 무결성 검사에서 제외됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns `true` if the asset can be edited and reloaded at runtime in debug builds. </br>
+/// These assets are excluded from integrity checks. </br>
+/// 
+#[inline]
+fn is_hot_reloadable(rel_path: &Path) -> bool {
+    cfg!(debug_assertions) && rel_path.starts_with("scripts")
+}
+
+#[inline]
+fn get_subpath<'a>(path: &'a Path, base: &'a Path) -> Result<&'a Path, String> {
+    path.strip_prefix(base).map_err(|e| e.to_string())
+}
+
+
+
+/// #### 한국어 </br>
+/// `AssetLists.txt`목록의 에셋을 확인합니다. </br>
+/// `AssetLists.txt`목록의 에셋 중 정적 유형의 에셋 파일은 
+/// 컴파일 타임에 바이너리에 키값이 저장됩니다. </br>
+/// 모든 정적 및 동적 유형의 에셋 파일이 존재하는 검사하고, 
+/// 정적 유형의 에셋파일에 대해 키값이 일치하는지 검사합니다. </br>
+/// 압축된 에셋 파일은 저장된 (압축된) 바이트 배열로 키값을 검사합니다. </br>
+/// 검사 도중 오류가 발생한 경우 `PanicMsg`를 반환합니다. </br>
+/// 
+/// 
+/// #### English (Translation) </br>
+/// Check the assets in the `AssetLists.txt` list. </br>
+/// Among the assets in the `AssetLists.txt` list, 
+/// the key value of static type asset files are stored in the binary at compile time. </br>
+/// Checks if all static and dynamic type asset files exist 
+/// and checks if the key values match for static type asset files. </br>
+/// Compressed asset files are checked against the stored (compressed) byte array. </br>
+/// If an error occurs during the check, it returns `PanicMsg`. </br>
+/// 
+fn check_assets(
+    root_path: &Path, 
+    asset_list: &HashMap<PathBuf, (Types, Compression)>,
+) -> AppResult<()> {
+    let mut handles = Vec::with_capacity(asset_list.len());
+
+    for (rel_path, (types, _)) in asset_list.iter() {
+        let types_cloned = types.clone();
+        let rel_path_cloned = rel_path.clone();
+        let root_path_cloned = root_path.to_path_buf().clone();
+        handles.push(thread::spawn(move || {
+            let abs_path = PathBuf::from_iter([&root_path_cloned, &rel_path_cloned]);
+            if !types_cloned.creatable() && !abs_path.is_file() {
+                return Err(game_err!(
+                    ERR_TITLE_VERIFICATION_FAILED,
+                    "{} {}",
+                    ERR_VERIFICATION_FAILED,
+                    "Asset is not a file or path cannot be found!"
+                ));
+            }
+            
+            if !types_cloned.writable() && !is_hot_reloadable(&rel_path_cloned) {
+                let key_file = AssetKeys::get(
+                    rel_path_cloned.to_str().unwrap()
+                ).ok_or_else(|| game_err!(
+                    ERR_TITLE_VERIFICATION_FAILED,
+                    "{} {}",
+                    ERR_VERIFICATION_FAILED,
+                    "Asset key not found!"
+                ))?;
+                
+                let hash = {
+                    let mut file = OpenOptions::new()
+                    .read(true)
+                    .open(abs_path)
+                    .map_err(|e| game_err!(
+                        ERR_TITLE_VERIFICATION_FAILED,
+                        "{} {}",
+                        ERR_VERIFICATION_FAILED,
+                        e.to_string()
+                    ))?;
+                    let mut hasher = Sha256::new();
+                    io::copy(&mut file, &mut hasher)
+                    .map_err(|e| game_err!(
+                        ERR_TITLE_VERIFICATION_FAILED,
+                        "{} {}",
+                        ERR_VERIFICATION_FAILED,
+                        e.to_string()
+                    ))?;
+                    hasher.finalize()
+                };
+                
+                if key_file.data.as_ref().ne(hash.as_slice()) {
+                    return Err(game_err!(
+                        ERR_TITLE_VERIFICATION_FAILED,
+                        "{} {}",
+                        ERR_VERIFICATION_FAILED,
+                        "Key values in asset files do not match!"
+                    ));
+                }
+            }
+
+            Ok(())
+        }));
+    }
+
+    for th in handles { 
+        join_thread(th, "asset loading")?;
+    }
+
+    Ok(())
+}
+
+
+#[cfg(all(test, debug_assertions))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn directory_source_resolves_relative_path() {
+        let source = Directory::new(concat!(env!("CARGO_MANIFEST_DIR"), "/assets"))
+            .expect("Failed to open asset directory.");
+
+        let abs_path = source.resolve(Path::new("fonts/nexon_lv2_gothic.ttf"));
+        assert!(abs_path.starts_with(source.root_path()));
+        assert!(abs_path.is_file());
+        assert!(source.check_integrity());
+        assert!(Directory::new(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/fonts/nexon_lv2_gothic.ttf")).is_err());
+    }
+}
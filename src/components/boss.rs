@@ -2,14 +2,15 @@ use std::thread;
 use std::sync::Arc;
 use std::f32::consts::PI;
 
-use rodio::OutputStreamHandle;
-use rand::{Rng, seq::SliceRandom};
+use rand::{Rng, rngs::StdRng, seq::SliceRandom};
 use glam::{Quat, Vec3, Vec3Swizzles, Vec2};
 
 use crate::{
+    state_enum, 
     assets::bundle::AssetBundle, 
     components::{
         collider2d::shape::AABB, 
+        camera::GameCamera, 
         sprite::{Sprite, SpriteBrush, Instance as SpriteData}, 
         bullet::Instance as BulletData,  
         table::{self, Table},
@@ -21,8 +22,10 @@ use crate::{
         in_game::InGameScene, 
         consts::PIXEL_PER_METER, 
     }, 
+    scene::state::state_fn, 
     system::{
         error::AppResult, 
+        rng::GameRng, 
         shared::Shared, 
     }, 
 };
@@ -48,23 +51,26 @@ pub enum BossFaceState {
 }
 
 
-/// #### 한국어 </br>
-/// 보스의 행동 상태 목록입니다. </br>
-/// 
-/// #### English (Translation) </br>
-/// This is a list of the boss's action status. </br>
-/// 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum BossBehaviorState {
-    #[default]
-    Idle = 0, 
-    PrepareRush = 1, 
-    Rush = 2, 
-    FireBulletPattern0 = 3, 
-    FireBulletPattern1 = 4, 
-    FireBulletPattern2 = 5,
-    WaitForFinish, 
-    MoveForFinish, 
+state_enum! {
+    /// #### 한국어 </br>
+    /// 보스의 행동 상태 목록입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// This is a list of the boss's action status. </br>
+    /// 
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub enum BossBehaviorState {
+        #[default]
+        Idle = 0, 
+        PrepareRush = 1, 
+        Rush = 2, 
+        FireBulletPattern0 = 3, 
+        FireBulletPattern1 = 4, 
+        FireBulletPattern2 = 5,
+        WaitForFinish, 
+        MoveForFinish, 
+        Chase, 
+    }
 }
 
 
@@ -93,7 +99,8 @@ impl Boss {
         device: &wgpu::Device, 
         tex_sampler: &wgpu::Sampler, 
         texture_view: &wgpu::TextureView, 
-        sprite_brush: &SpriteBrush
+        sprite_brush: &SpriteBrush, 
+        rng: &mut StdRng
     ) -> Self {
         let x = table::position(table.origin.x, table.size.x, col);
         let y = table::position(table.origin.y, table.size.y, row);
@@ -111,7 +118,6 @@ impl Boss {
             instances
         );
 
-        let mut rng = rand::thread_rng();
         let rotation = Quat::from_rotation_z(rng.gen_range(0.0..2.0 * PI));
         let direction = rotation.mul_vec3(Vec3::X).xy().normalize();
 
@@ -128,6 +134,44 @@ impl Boss {
         }
     }
 
+    /// #### 한국어 </br>
+    /// 보스를 플레이어의 현재 위치를 향해 `elapsed_time` 동안 이동시킨 위치를 반환합니다. </br>
+    /// 이미 점령된 타일로는 이동하지 않으며, 반환된 위치는 타일 영역 안으로 제한됩니다. </br>
+    /// 이동 속도는 점령된 타일의 비율에 따라 빨라집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the position of the boss moved toward the player's current position during `elapsed_time`. </br>
+    /// It does not move onto tiles that are already captured, and the returned position is clamped to the tile area. </br>
+    /// The movement speed increases with the ratio of captured tiles. </br>
+    /// 
+    pub fn update(&self, elapsed_time: f64, player_position: Vec2, table: &Table, visited: &[Vec<bool>]) -> Vec2 {
+        const SPEED: f32 = 4.0 * PIXEL_PER_METER; // meter per sec
+
+        let position = {
+            let instances = self.sprite.instances.lock().expect("Failed to access variable.");
+            instances[0].translation.xy()
+        };
+
+        let speed = SPEED * (1.0 + captured_ratio(visited));
+        let movement = pursuit_vector(position, player_position, speed * elapsed_time as f32);
+
+        // (한국어) 
+        // 이동할 위치가 점령된 타일인 경우 한 축으로만 이동해 봅니다.
+        // 모든 방향이 막힌 경우 제자리에 머뭅니다.
+        //
+        // (English Translation) 
+        // If the position to move to is a captured tile, tries moving along only one axis.
+        // If every direction is blocked, it stays in place.
+        //
+        let candidates = [movement, Vec2::new(movement.x, 0.0), Vec2::new(0.0, movement.y)];
+        let position = candidates.into_iter()
+            .map(|movement| position + movement)
+            .find(|&next| !is_captured(table, visited, next))
+            .unwrap_or(position);
+
+        return clamp_to_table(table, position);
+    }
+
     /// #### 한국어 </br>
     /// 보스의 충돌체를 반환합니다. </br>
     /// 
@@ -149,7 +193,7 @@ impl Boss {
 
 
 type UpdateFn = dyn for<'a> Fn(&'a mut InGameScene, &'a mut Shared, f64, f64) -> AppResult<()>;
-const UPDATE_FUNC: [&'static UpdateFn; 8] = [
+const UPDATE_FUNC: [&'static UpdateFn; 9] = [
     &update_boss_idle_state, 
     &update_boss_prepare_rush_state,
     &update_boss_rush_state,
@@ -158,10 +202,12 @@ const UPDATE_FUNC: [&'static UpdateFn; 8] = [
     &update_boss_fire_bullet_pattern2, 
     &update_boss_wait_for_finish, 
     &update_boss_move_for_finish, 
+    &update_boss_chase_state, 
 ];
+const _: () = assert!(UPDATE_FUNC.len() == BossBehaviorState::NUM_STATES, "`UPDATE_FUNC` must have one entry for each `BossBehaviorState`.");
 
 pub fn update_boss(this: &mut InGameScene, shared: &mut Shared, total_time: f64, elapsed_time: f64) -> AppResult<()> {
-    UPDATE_FUNC[this.boss.behavior_state as usize](this, shared, total_time, elapsed_time)?;
+    state_fn(&UPDATE_FUNC, this.boss.behavior_state as usize, "UPDATE_FUNC")?(this, shared, total_time, elapsed_time)?;
     adjust_boss_position(&this.table, &mut this.boss);
     apply_boss_position(shared.get::<Arc<wgpu::Queue>>().unwrap(), &mut this.boss);
     Ok(())
@@ -197,12 +243,13 @@ fn update_boss_idle_state(this: &mut InGameScene, shared: &mut Shared, _total_ti
             BossBehaviorState::FireBulletPattern1, 
             BossBehaviorState::FireBulletPattern2, 
             BossBehaviorState::PrepareRush,
+            BossBehaviorState::Chase, 
         ];
-        next_state.shuffle(&mut rand::thread_rng());
+        next_state.shuffle(&mut *shared.get::<Arc<GameRng>>().unwrap().lock());
 
         // (한국어) 사용할 공유 객체들을 가져옵니다.
         // (English Translation) Get shared object to use. 
-        let stream = shared.get::<OutputStreamHandle>().unwrap();
+        let stream = sound::output(shared);
         let settings = shared.get::<Settings>().unwrap();
         let asset_bundle = shared.get::<AssetBundle>().unwrap();
 
@@ -210,6 +257,7 @@ fn update_boss_idle_state(this: &mut InGameScene, shared: &mut Shared, _total_ti
             BossBehaviorState::PrepareRush => {
                 let source = asset_bundle.get(path::YUUKA_ATTACK1_SOUND_PATH)?
                     .read(&sound::SoundDecoder)?;
+                asset_bundle.release(path::YUUKA_ATTACK1_SOUND_PATH);
                 let sink = sound::play_sound(settings.voice_volume, source, stream)?;
                 thread::spawn(move || {
                     sink.sleep_until_end();
@@ -220,12 +268,13 @@ fn update_boss_idle_state(this: &mut InGameScene, shared: &mut Shared, _total_ti
                 this.boss.behavior_state = BossBehaviorState::PrepareRush;
             }, 
             BossBehaviorState::FireBulletPattern0 => {
-                let mut rng = rand::thread_rng();
+                let mut rng = shared.get::<Arc<GameRng>>().unwrap().lock();
                 if rng.gen_ratio(1, 4) {
                     const PATHS: [&'static str; 2]  = [path::YUUKA_ATTACK2_SOUND_PATH, path::YUUKA_ATTACK3_SOUND_PATH];
                     let rel_path = PATHS[rng.gen_range(0..2)];
                     let source = asset_bundle.get(rel_path)?
                         .read(&sound::SoundDecoder)?;
+                    asset_bundle.release(rel_path);
                     let sink = sound::play_sound(settings.voice_volume, source, stream)?;
                     thread::spawn(move || {
                         sink.sleep_until_end();
@@ -239,12 +288,13 @@ fn update_boss_idle_state(this: &mut InGameScene, shared: &mut Shared, _total_ti
                 this.boss.behavior_state = BossBehaviorState::FireBulletPattern0;
             },
             BossBehaviorState::FireBulletPattern1 => {
-                let mut rng = rand::thread_rng();
+                let mut rng = shared.get::<Arc<GameRng>>().unwrap().lock();
                 if rng.gen_ratio(1, 4) {
                     const PATHS: [&'static str; 2]  = [path::YUUKA_ATTACK2_SOUND_PATH, path::YUUKA_ATTACK3_SOUND_PATH];
                     let rel_path = PATHS[rng.gen_range(0..2)];
                     let source = asset_bundle.get(rel_path)?
                         .read(&sound::SoundDecoder)?;
+                    asset_bundle.release(rel_path);
                     let sink = sound::play_sound(settings.voice_volume, source, stream)?;
                     thread::spawn(move || {
                         sink.sleep_until_end();
@@ -258,12 +308,13 @@ fn update_boss_idle_state(this: &mut InGameScene, shared: &mut Shared, _total_ti
                 this.boss.behavior_state = BossBehaviorState::FireBulletPattern1;
             }, 
             BossBehaviorState::FireBulletPattern2 => {
-                let mut rng = rand::thread_rng();
+                let mut rng = shared.get::<Arc<GameRng>>().unwrap().lock();
                 if rng.gen_ratio(1, 4) {
                     const PATHS: [&'static str; 2]  = [path::YUUKA_ATTACK2_SOUND_PATH, path::YUUKA_ATTACK3_SOUND_PATH];
                     let rel_path = PATHS[rng.gen_range(0..2)];
                     let source = asset_bundle.get(rel_path)?
                         .read(&sound::SoundDecoder)?;
+                    asset_bundle.release(rel_path);
                     let sink = sound::play_sound(settings.voice_volume, source, stream)?;
                     thread::spawn(move || {
                         sink.sleep_until_end();
@@ -276,6 +327,10 @@ fn update_boss_idle_state(this: &mut InGameScene, shared: &mut Shared, _total_ti
                 this.boss.behavior_timer = 0.0;
                 this.boss.behavior_state = BossBehaviorState::FireBulletPattern2;
             },
+            BossBehaviorState::Chase => {
+                this.boss.behavior_timer = 0.0;
+                this.boss.behavior_state = BossBehaviorState::Chase;
+            },
             _ => { panic!("Invalid patterns!") }
         }
     }
@@ -360,14 +415,20 @@ fn update_boss_rush_state(this: &mut InGameScene, _shared: &mut Shared, _total_t
 fn update_boss_fire_bullet_pattern0(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, _elapsed_time: f64) -> AppResult<()> {
     const BULLET_SPEED: f32 = 0.5 * PIXEL_PER_METER;
 
-    // (한국어) 총알 발사 소리를 재생합니다.
-    // (English Translation) Play the sound of a bullet being fired.
-    let stream = shared.get::<OutputStreamHandle>().unwrap();
+    // (한국어) 보스의 위치에 따라 좌우로 나뉜 총알 발사 소리를 재생합니다.
+    // (English Translation) Play the sound of a bullet being fired, panned by the position of the boss.
+    let stream = sound::output(shared);
     let settings = shared.get::<Settings>().unwrap();
     let asset_bundle = shared.get::<AssetBundle>().unwrap();    
     let source = asset_bundle.get(path::BULLET_FIRE_SOUND_PATH)?
         .read(&sound::SoundDecoder)?;
-    let sink = sound::play_sound(settings.effect_volume, source, stream)?;
+    asset_bundle.release(path::BULLET_FIRE_SOUND_PATH);
+    let camera = shared.get::<Arc<GameCamera>>().unwrap();
+    let pan = {
+        let instances = this.boss.sprite.instances.lock().expect("Failed to access variable.");
+        sound::camera_pan(camera, instances[0].translation.x)
+    };
+    let sink = sound::play_sound(settings.effect_volume, sound::Panned::new(source, pan), stream)?;
     thread::spawn(move || {
         sink.sleep_until_end();
         sink.detach();
@@ -424,14 +485,20 @@ fn update_boss_fire_bullet_pattern0(this: &mut InGameScene, shared: &mut Shared,
 fn update_boss_fire_bullet_pattern1(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, _elapsed_time: f64) -> AppResult<()> {
     const BULLET_SPEED: f32 = 0.75 * PIXEL_PER_METER;
 
-    // (한국어) 총알 발사 소리를 재생합니다.
-    // (English Translation) Play the sound of a bullet being fired.
-    let stream = shared.get::<OutputStreamHandle>().unwrap();
+    // (한국어) 보스의 위치에 따라 좌우로 나뉜 총알 발사 소리를 재생합니다.
+    // (English Translation) Play the sound of a bullet being fired, panned by the position of the boss.
+    let stream = sound::output(shared);
     let settings = shared.get::<Settings>().unwrap();
     let asset_bundle = shared.get::<AssetBundle>().unwrap();    
     let source = asset_bundle.get(path::BULLET_FIRE_SOUND_PATH)?
         .read(&sound::SoundDecoder)?;
-    let sink = sound::play_sound(settings.effect_volume, source, stream)?;
+    asset_bundle.release(path::BULLET_FIRE_SOUND_PATH);
+    let camera = shared.get::<Arc<GameCamera>>().unwrap();
+    let pan = {
+        let instances = this.boss.sprite.instances.lock().expect("Failed to access variable.");
+        sound::camera_pan(camera, instances[0].translation.x)
+    };
+    let sink = sound::play_sound(settings.effect_volume, sound::Panned::new(source, pan), stream)?;
     thread::spawn(move || {
         sink.sleep_until_end();
         sink.detach();
@@ -483,14 +550,20 @@ fn update_boss_fire_bullet_pattern1(this: &mut InGameScene, shared: &mut Shared,
 fn update_boss_fire_bullet_pattern2(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, _elapsed_time: f64) -> AppResult<()> {
     const BULLET_SPEED: f32 = 0.5 * PIXEL_PER_METER;
 
-    // (한국어) 총알 발사 소리를 재생합니다.
-    // (English Translation) Play the sound of a bullet being fired.
-    let stream = shared.get::<OutputStreamHandle>().unwrap();
+    // (한국어) 보스의 위치에 따라 좌우로 나뉜 총알 발사 소리를 재생합니다.
+    // (English Translation) Play the sound of a bullet being fired, panned by the position of the boss.
+    let stream = sound::output(shared);
     let settings = shared.get::<Settings>().unwrap();
     let asset_bundle = shared.get::<AssetBundle>().unwrap();    
     let source = asset_bundle.get(path::BULLET_FIRE_SOUND_PATH)?
         .read(&sound::SoundDecoder)?;
-    let sink = sound::play_sound(settings.effect_volume, source, stream)?;
+    asset_bundle.release(path::BULLET_FIRE_SOUND_PATH);
+    let camera = shared.get::<Arc<GameCamera>>().unwrap();
+    let pan = {
+        let instances = this.boss.sprite.instances.lock().expect("Failed to access variable.");
+        sound::camera_pan(camera, instances[0].translation.x)
+    };
+    let sink = sound::play_sound(settings.effect_volume, sound::Panned::new(source, pan), stream)?;
     thread::spawn(move || {
         sink.sleep_until_end();
         sink.detach();
@@ -593,6 +666,136 @@ fn update_boss_move_for_finish(this: &mut InGameScene, _shared: &mut Shared, _to
     Ok(())
 }
 
+/// #### 한국어 </br>
+/// 보스의 행동 상태가 `Chase`일 때 호출되는 업데이트 함수입니다. </br>
+/// 플레이어에게 가까워지면 보스의 얼굴이 `Smile` 상태로 변경됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is an update function that is called when the boss's behavior state is `Chase`. </br>
+/// When it gets close to the player, the boss's face changes to the `Smile` state. </br>
+/// 
+fn update_boss_chase_state(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, elapsed_time: f64) -> AppResult<()> {
+    const DURATION: f64 = 3.0;
+    const CLOSE_DISTANCE: f32 = 3.0 * PIXEL_PER_METER;
+
+    // (한국어) 타이머를 갱신합니다.
+    // (English Translation) Updates the timer.
+    this.boss.behavior_timer += elapsed_time;
+
+    // (한국어) 플레이어의 현재 위치를 가져옵니다.
+    // (English Translation) Get the current position of the player.
+    let player_position = {
+        let instances = this.player.sprite.instances.lock().expect("Failed to access variable.");
+        instances[0].translation.xy()
+    };
+
+    // (한국어) 보스의 위치를 갱신합니다.
+    // (English Translation) Update the boss's position. 
+    let next_position = this.boss.update(elapsed_time, player_position, &this.table, &this.sim.visited);
+    let previous_position = {
+        let mut instances = this.boss.sprite.instances.lock().expect("Failed to access variable.");
+        let previous_position = instances[0].translation.xy();
+        instances[0].translation.x = next_position.x;
+        instances[0].translation.y = next_position.y;
+        previous_position
+    };
+
+    // (한국어) 
+    // 다른 상태에서도 같은 방향으로 이동하도록 방향을 갱신합니다.
+    // 가까워지는 중이라면 보스의 얼굴을 변경합니다.
+    //
+    // (English Translation) 
+    // Updates the direction so it keeps moving the same way in other states.
+    // If it is closing in, changes the boss's face.
+    //
+    let movement = next_position - previous_position;
+    if movement != Vec2::ZERO {
+        this.boss.direction = movement.normalize();
+    }
+
+    let closing_in = next_position.distance(player_position) < previous_position.distance(player_position);
+    if closing_in 
+    && next_position.distance(player_position) <= CLOSE_DISTANCE 
+    && this.boss.face_state == BossFaceState::Idle {
+        let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+        this.boss.face_timer = 0.0;
+        this.boss.face_state = BossFaceState::Smile;
+        this.boss.sprite.set_frame(queue, BossFaceState::Smile as u32);
+    }
+
+    // (한국어) 지속 시간보다 클 경우 `Idle` 상태로 변경합니다.
+    // (English Translation) If it is greater than the duration, it changes to `Idle` state. 
+    if this.boss.behavior_timer >= DURATION {
+        this.boss.behavior_timer = 0.0;
+        this.boss.behavior_state = BossBehaviorState::Idle;
+    }
+
+    Ok(())
+}
+
+/// #### 한국어 </br>
+/// `from`에서 `to`를 향해 최대 `distance` 만큼 이동하는 벡터를 반환합니다. </br>
+/// 목표를 지나치지 않으며, 두 위치가 같은 경우 영벡터를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the vector moving from `from` toward `to` by at most `distance`. </br>
+/// It does not overshoot the target, and returns the zero vector if the two positions are the same. </br>
+/// 
+#[inline]
+fn pursuit_vector(from: Vec2, to: Vec2, distance: f32) -> Vec2 {
+    let delta = to - from;
+    let length = delta.length();
+    if length <= f32::EPSILON {
+        return Vec2::ZERO;
+    }
+    return delta / length * distance.min(length);
+}
+
+/// #### 한국어 </br>
+/// 주어진 위치의 타일이 이미 점령되었는지 확인합니다. </br>
+/// 타일 영역 밖의 위치는 점령되지 않은 것으로 봅니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Checks whether the tile at the given position is already captured. </br>
+/// Positions outside the tile area are treated as not captured. </br>
+/// 
+fn is_captured(table: &Table, visited: &[Vec<bool>], position: Vec2) -> bool {
+    return table::tile_index(table, position)
+        .is_some_and(|(row, col)| visited[row][col]);
+}
+
+/// #### 한국어 </br>
+/// 전체 타일 중 점령된 타일의 비율을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the ratio of captured tiles to all tiles. </br>
+/// 
+fn captured_ratio(visited: &[Vec<bool>]) -> f32 {
+    let total: usize = visited.iter().map(|lines| lines.len()).sum();
+    if total == 0 {
+        return 0.0;
+    }
+
+    let captured = visited.iter()
+        .flat_map(|lines| lines.iter())
+        .filter(|&&visited| visited)
+        .count();
+    return captured as f32 / total as f32;
+}
+
+/// #### 한국어 </br>
+/// 주어진 위치를 타일 영역 안으로 제한합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Clamps the given position into the tile area. </br>
+/// 
+#[inline]
+fn clamp_to_table(table: &Table, position: Vec2) -> Vec2 {
+    let min = Vec2::new(table.aabb.x - 0.5 * table.aabb.width, table.aabb.y - 0.5 * table.aabb.height);
+    let max = Vec2::new(table.aabb.x + 0.5 * table.aabb.width, table.aabb.y + 0.5 * table.aabb.height);
+    return position.clamp(min, max);
+}
+
 /// #### 한국어 </br>
 /// 보스의 위치가 이동 가능한 영역을 벗어나는지 확인합니다. </br> 
 /// 보스의 위치가 이동 가능한 영역을 벗어날 경우 위치와 방향을 조정합니다. </br>
@@ -692,9 +895,7 @@ fn update_boss_embarrass_face(elapsed_time: f64, queue: &wgpu::Queue, boss: &mut
     if boss.face_timer >= DURATION {
         boss.face_timer = 0.0;
         boss.face_state = BossFaceState::Idle;
-        boss.sprite.update(queue, |instances| {
-            instances[0].texture_index = BossFaceState::Idle as u32;
-        });
+        boss.sprite.set_frame(queue, BossFaceState::Idle as u32);
     }
 }
 
@@ -711,8 +912,23 @@ fn update_boss_smile_face(elapsed_time: f64, queue: &wgpu::Queue, boss: &mut Bos
     if boss.face_timer >= DURATION {
         boss.face_timer = 0.0;
         boss.face_state = BossFaceState::Idle;
-        boss.sprite.update(queue, |instances| {
-            instances[0].texture_index = BossFaceState::Idle as u32;
-        });
+        boss.sprite.set_frame(queue, BossFaceState::Idle as u32);
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pursuit_moves_toward_target_without_overshooting() {
+        let movement = pursuit_vector(Vec2::ZERO, Vec2::new(3.0, 4.0), 1.0);
+        assert!((movement - Vec2::new(0.6, 0.8)).length() < 1e-6);
+
+        let movement = pursuit_vector(Vec2::ZERO, Vec2::new(3.0, 4.0), 10.0);
+        assert!((movement - Vec2::new(3.0, 4.0)).length() < 1e-6);
+
+        assert_eq!(pursuit_vector(Vec2::ONE, Vec2::ONE, 1.0), Vec2::ZERO);
     }
 }
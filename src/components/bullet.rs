@@ -10,7 +10,7 @@ use crate::{
         collider2d::{Collider2d, shape::OBB}, 
         table::Table, 
     }, 
-    render::shader::WgslDecoder, 
+    render::{shader::WgslDecoder, tracker::Tracked}, 
     system::error::AppResult, 
 };
 
@@ -121,6 +121,7 @@ pub struct Bullet {
     bind_group: wgpu::BindGroup, 
     pub instances: Mutex<Vec<Instance>>, 
     capacity: usize, 
+    _tracked: Tracked, 
 }
 
 impl Bullet {
@@ -170,6 +171,7 @@ impl Bullet {
             bind_group, 
             instances: Vec::with_capacity(capacity).into(),
             capacity, 
+            _tracked: Tracked::new(1, 1), 
         }
     }
 
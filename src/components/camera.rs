@@ -126,6 +126,21 @@ impl GameCamera {
         }));
     }
 
+    /// #### 한국어 </br>
+    /// 카메라의 투영을 `from`에서 `to`로 `t`(0.0 ~ 1.0) 만큼 보간한 값으로 설정하고 유니폼 버퍼를 갱신합니다. </br>
+    /// 장면의 타이머로 `t`를 구해 매 프레임 호출하면 두 투영 사이를 부드럽게 전환할 수 있습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the camera's projection to the value interpolated from `from` to `to` by `t` (0.0 ~ 1.0) and updates the uniform buffer. </br>
+    /// Calling it every frame with `t` taken from the scene's timer smoothly transitions between the two projections. </br>
+    /// 
+    #[inline]
+    pub fn lerp_projection(&self, queue: &wgpu::Queue, from: Projection, to: Projection, t: f32) {
+        self.update(queue, |data| {
+            data.projection = Projection::lerp(from, to, t);
+        });
+    }
+
     /// #### 한국어 </br>
     /// 카메라 데이터 유니폼 버퍼를 렌더 패스에 바인드 합니다. </br>
     /// 
@@ -288,7 +303,7 @@ impl CameraCreator {
 /// #### English (Translation) </br>
 /// Create a camera data bind group layout. </br>
 /// 
-fn create_camera_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+pub(crate) fn create_camera_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
     device.create_bind_group_layout(
         &wgpu::BindGroupLayoutDescriptor {
             label: Some("BindGroupLayout(GameCamera)"),
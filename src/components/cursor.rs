@@ -0,0 +1,210 @@
+use std::sync::{Arc, Mutex};
+
+use winit::{dpi::PhysicalPosition, window::Window};
+
+use crate::{
+    components::{
+        collider2d::Collider2d,
+        anchor::Anchor,
+        margin::Margin,
+        camera::{GameCamera, Viewport},
+        ui::{UiBrush, UiObject, UiObjectBuilder},
+        user::{CursorHidden, Settings},
+    },
+    system::shared::Shared,
+};
+
+
+
+/// #### 한국어 </br>
+/// 게임 커서 이미지의 크기 입니다. (화면 배율 적용 전) </br>
+/// 
+/// #### English (Translation) </br>
+/// The size of the game cursor image. (before applying the screen scale factor) </br>
+/// 
+const CURSOR_SIZE: i32 = 32;
+
+
+/// #### 한국어 </br>
+/// 윈도우 좌표계의 커서 위치를 위아래로 뒤집어 사용자 인터페이스 좌표계(왼쪽 아래가 원점)로 변환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Flips the cursor position of the window coordinate system upside down
+/// and converts it into the user interface coordinate system. (the origin is at the bottom left) </br>
+/// 
+#[inline]
+pub fn flip_cursor_position(position: &PhysicalPosition<f64>, height: f64) -> PhysicalPosition<f64> {
+    PhysicalPosition::new(position.x, height - position.y)
+}
+
+/// #### 한국어 </br>
+/// 커서 이미지의 왼쪽 위 모서리가 커서 위치에 오도록 하는 기준점을 계산합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Calculates the anchor that places the top left corner of the cursor image at the cursor position. </br>
+/// 
+fn cursor_anchor(cursor_pos: &PhysicalPosition<f64>, viewport: &Viewport) -> Anchor {
+    let x = (cursor_pos.x as f32 - viewport.x) / viewport.width;
+    let y = (cursor_pos.y as f32 - viewport.y) / viewport.height;
+    return Anchor::new(y, x, y, x);
+}
+
+
+
+/// #### 한국어 </br>
+/// 운영체제 커서 대신 그려지는 게임 커서 입니다. </br>
+/// 평소에는 기본 이미지를, 커서가 버튼 위에 있는 경우 강조 이미지를 그립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The game cursor drawn instead of the OS cursor. </br>
+/// It usually draws the normal image, and draws the highlighted image if the cursor is over a button. </br>
+/// 
+#[derive(Debug)]
+pub struct CustomCursor {
+    normal: UiObject,
+    hover: UiObject,
+    hovered: Mutex<bool>,
+}
+
+impl CustomCursor {
+    pub fn new(
+        device: &wgpu::Device,
+        tex_sampler: &wgpu::Sampler,
+        normal_view: &wgpu::TextureView,
+        hover_view: &wgpu::TextureView,
+        ui_brush: &UiBrush
+    ) -> Self {
+        let margin = Margin::new(0, 0, -CURSOR_SIZE, CURSOR_SIZE);
+        let normal = UiObjectBuilder::new(Some("Cursor(Normal)"), tex_sampler, normal_view, ui_brush)
+            .with_margin(margin)
+            .build(device);
+        let hover = UiObjectBuilder::new(Some("Cursor(Hover)"), tex_sampler, hover_view, ui_brush)
+            .with_margin(margin)
+            .build(device);
+
+        Self {
+            normal,
+            hover,
+            hovered: false.into(),
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 커서가 버튼 위에 있는 경우 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if the cursor is over a button. </br>
+    /// 
+    #[inline]
+    pub fn is_hovered(&self) -> bool {
+        *self.hovered.lock().expect("Failed to access variable.")
+    }
+
+    /// #### 한국어 </br>
+    /// 커서 이미지를 주어진 커서 위치로 옮기고, 버튼 위에 있는지 여부를 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Moves the cursor image to the given cursor position and updates whether it is over a button. </br>
+    /// 
+    pub fn update(&self, queue: &wgpu::Queue, cursor_pos: &PhysicalPosition<f64>, camera: &GameCamera, hovered: bool) {
+        let viewport = camera.data.lock().expect("Failed to access variable.").viewport;
+        let anchor = cursor_anchor(cursor_pos, &viewport);
+        for ui in [&self.normal, &self.hover] {
+            ui.update(queue, |data| data.anchor = anchor);
+        }
+        *self.hovered.lock().expect("Failed to access variable.") = hovered;
+    }
+
+    #[inline]
+    pub fn draw<'pass>(&'pass self, ui_brush: &'pass UiBrush, rpass: &mut wgpu::RenderPass<'pass>) {
+        let ui = match self.is_hovered() {
+            true => &self.hover,
+            false => &self.normal,
+        };
+        ui_brush.draw(rpass, [ui].into_iter());
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 커서가 주어진 버튼들 중 하나의 위에 있는 경우 `true`를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns `true` if the cursor is over one of the given buttons. </br>
+/// 
+pub fn is_over_any<'a, I>(shared: &Shared, buttons: I) -> bool
+where I: IntoIterator<Item = &'a UiObject> {
+    let camera = shared.get::<Arc<GameCamera>>().unwrap();
+    let cursor_pos = shared.get::<PhysicalPosition<f64>>().unwrap();
+    buttons.into_iter().any(|ui| ui.test(&(cursor_pos, camera)))
+}
+
+/// #### 한국어 </br>
+/// 게임 커서를 사용하도록 설정되어 있고, 운영체제 커서가 숨겨지지 않은 경우 `true`를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns `true` if the game cursor is enabled and the OS cursor is not hidden. </br>
+/// 
+#[inline]
+pub fn is_visible(shared: &Shared) -> bool {
+    shared.get::<Settings>().is_some_and(|settings| settings.custom_cursor)
+    && shared.get::<CursorHidden>().is_none()
+}
+
+/// #### 한국어 </br>
+/// 게임 커서 설정에 맞게 운영체제 커서를 보이거나 숨깁니다. </br>
+/// 운영체제 커서가 숨겨져 있는 동안에는 계속 숨겨진 상태로 둡니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Shows or hides the OS cursor to match the game cursor setting. </br>
+/// While the OS cursor is hidden, it is kept hidden. </br>
+/// 
+#[inline]
+pub fn apply_custom_cursor(shared: &Shared) {
+    let custom_cursor = shared.get::<Settings>().is_some_and(|settings| settings.custom_cursor);
+    let window = shared.get::<Arc<Window>>().unwrap();
+    window.set_cursor_visible(!custom_cursor && shared.get::<CursorHidden>().is_none());
+}
+
+/// #### 한국어 </br>
+/// 게임 커서에 커서 위치와 현재 게임 장면의 버튼 위에 있는지 여부를 반영합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reflects the cursor position and whether it is over a button of the current game scene to the game cursor. </br>
+/// 
+pub fn update_custom_cursor(shared: &Shared, hovered: bool) {
+    if let Some(cursor) = shared.get::<Arc<CustomCursor>>() {
+        let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+        let camera = shared.get::<Arc<GameCamera>>().unwrap();
+        let cursor_pos = shared.get::<PhysicalPosition<f64>>().unwrap();
+        cursor.update(queue, cursor_pos, camera, hovered);
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cursor_anchor_tracks_flipped_position() {
+        let viewport = Viewport {
+            x: 100.0,
+            y: 0.0,
+            width: 800.0,
+            height: 600.0,
+            ..Default::default()
+        };
+
+        // (한국어) 창의 왼쪽 위에서 (300, 150)만큼 떨어진 위치는 아래에서 450만큼 떨어진 위치입니다.
+        // (English Translation) The position (300, 150) from the top left of the window is 450 from the bottom.
+        let cursor_pos = flip_cursor_position(&PhysicalPosition::new(300.0, 150.0), 600.0);
+        assert_eq!(cursor_pos, PhysicalPosition::new(300.0, 450.0));
+
+        let anchor = cursor_anchor(&cursor_pos, &viewport);
+        assert_eq!(viewport.x + anchor.left() * viewport.width, 300.0);
+        assert_eq!(viewport.x + anchor.right() * viewport.width, 300.0);
+        assert_eq!(viewport.y + anchor.top() * viewport.height, 450.0);
+        assert_eq!(viewport.y + anchor.bottom() * viewport.height, 450.0);
+    }
+}
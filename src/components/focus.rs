@@ -0,0 +1,165 @@
+use winit::keyboard::KeyCode;
+
+use crate::components::ui::UiObject;
+
+
+
+/// #### 한국어 </br>
+/// 포커스된 버튼의 배율입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Scale of the focused button. </br>
+/// 
+pub const FOCUS_SCALE: f32 = 1.1;
+
+
+
+/// #### 한국어 </br>
+/// 키보드 입력으로 발생한 포커스 동작 목록입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// List of focus actions caused by keyboard input. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusAction<K> {
+    Moved(K),
+    Activate(K),
+}
+
+
+
+/// #### 한국어 </br>
+/// 현재 장면 상태의 버튼들 사이에서 키보드 포커스를 이동시키는 포커스 링 입니다. </br>
+/// 위/아래 방향키(또는 `Tab`)로 포커스를 옮기며 목록의 끝에서 처음으로 되돌아갑니다. </br>
+/// `Enter` 키를 누르면 포커스된 버튼을 활성화하며, 장면은 기존의 클릭 처리 함수를 호출합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A focus ring that moves keyboard focus between the buttons of the current scene state. </br>
+/// The Up/Down arrow keys (or `Tab`) move the focus, wrapping around from the end of the list to the start. </br>
+/// Pressing `Enter` activates the focused button, and the scene calls its existing click handler. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct FocusRing<K> {
+    order: Vec<K>,
+    index: Option<usize>,
+}
+
+impl<K: Copy + PartialEq> FocusRing<K> {
+    /// #### 한국어 </br>
+    /// 주어진 순서의 버튼들로 포커스가 없는 포커스 링을 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a focus ring with no focus over the buttons in the given order. </br>
+    /// 
+    pub fn new(order: Vec<K>) -> Self {
+        Self { order, index: None }
+    }
+
+    /// #### 한국어 </br>
+    /// 포커스된 버튼을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the focused button. </br>
+    /// 
+    #[inline]
+    pub fn focused(&self) -> Option<K> {
+        self.index.map(|idx| self.order[idx])
+    }
+
+    /// #### 한국어 </br>
+    /// 다음 버튼으로 포커스를 옮깁니다. 포커스가 없는 경우 첫 번째 버튼에 포커스를 줍니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Moves the focus to the next button. If there is no focus, focuses the first button. </br>
+    /// 
+    pub fn next(&mut self) -> Option<K> {
+        if self.order.is_empty() {
+            return None;
+        }
+        self.index = Some(self.index.map_or(0, |idx| (idx + 1) % self.order.len()));
+        return self.focused();
+    }
+
+    /// #### 한국어 </br>
+    /// 이전 버튼으로 포커스를 옮깁니다. 포커스가 없는 경우 마지막 버튼에 포커스를 줍니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Moves the focus to the previous button. If there is no focus, focuses the last button. </br>
+    /// 
+    pub fn prev(&mut self) -> Option<K> {
+        if self.order.is_empty() {
+            return None;
+        }
+        let len = self.order.len();
+        self.index = Some(self.index.map_or(len - 1, |idx| (idx + len - 1) % len));
+        return self.focused();
+    }
+
+    /// #### 한국어 </br>
+    /// 포커스를 해제합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Clears the focus. </br>
+    /// 
+    #[inline]
+    pub fn clear(&mut self) {
+        self.index = None;
+    }
+
+    /// #### 한국어 </br>
+    /// 눌린 키에 따라 포커스를 옮기거나 포커스된 버튼을 활성화합니다. </br>
+    /// 포커스와 관련 없는 키인 경우 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Moves the focus or activates the focused button depending on the pressed key. </br>
+    /// Returns `None` if the key is not related to the focus. </br>
+    /// 
+    pub fn handle_key(&mut self, code: KeyCode) -> Option<FocusAction<K>> {
+        match code {
+            KeyCode::ArrowDown | KeyCode::Tab => self.next().map(FocusAction::Moved),
+            KeyCode::ArrowUp => self.prev().map(FocusAction::Moved),
+            KeyCode::Enter | KeyCode::NumpadEnter => self.focused().map(FocusAction::Activate),
+            _ => None,
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 포커스된 버튼만 크게 보이도록 주어진 버튼들의 배율을 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the scale of the given buttons so that only the focused button appears larger. </br>
+    /// 
+    pub fn highlight<'a, I>(&self, queue: &wgpu::Queue, buttons: I)
+    where I: Iterator<Item = (K, &'a UiObject)> {
+        let focused = self.focused();
+        for (tag, ui) in buttons {
+            let scale = if focused == Some(tag) { FOCUS_SCALE } else { 1.0 };
+            ui.update(queue, |data| data.local_scale = (scale, scale, scale).into());
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cycling_focus_wraps_around() {
+        let mut ring = FocusRing::new(vec!['a', 'b', 'c']);
+        assert_eq!(ring.focused(), None);
+        assert_eq!(ring.handle_key(KeyCode::Enter), None);
+
+        assert_eq!(ring.next(), Some('a'));
+        assert_eq!(ring.next(), Some('b'));
+        assert_eq!(ring.next(), Some('c'));
+        assert_eq!(ring.next(), Some('a'));
+        assert_eq!(ring.prev(), Some('c'));
+        assert_eq!(ring.handle_key(KeyCode::Tab), Some(FocusAction::Moved('a')));
+        assert_eq!(ring.handle_key(KeyCode::Enter), Some(FocusAction::Activate('a')));
+
+        ring.clear();
+        assert_eq!(ring.prev(), Some('c'));
+        assert_eq!(FocusRing::<char>::new(Vec::new()).next(), None);
+    }
+}
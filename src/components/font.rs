@@ -1,4 +1,4 @@
-use ab_glyph::FontArc;
+use ab_glyph::{Font, FontArc};
 
 use crate::{
     game_err,
@@ -33,3 +33,79 @@ impl AssetDecoder for FontDecoder {
             ))
     }
 }
+
+
+
+/// #### 한국어 </br>
+/// 주어진 폰트가 텍스트의 모든 문자(공백 제외)에 대한 글리프를 가지고 있는 경우 `true`를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns `true` if the given font has a glyph for every character (except whitespace) of the text. </br>
+/// 
+pub fn has_glyphs(font: &FontArc, text: &str) -> bool {
+    text.chars()
+        .filter(|ch| !ch.is_whitespace())
+        .all(|ch| font.glyph_id(ch).0 != 0)
+}
+
+
+
+/// #### 한국어 </br>
+/// 기본 폰트에 없는 글리프를 찾을 때 순서대로 확인하는 대체 폰트 목록입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A list of fallback fonts checked in order when looking up glyphs missing from the primary font. </br>
+/// 
+#[derive(Debug, Clone, Default)]
+pub struct FontFallback(Vec<FontArc>);
+
+impl FontFallback {
+    #[inline]
+    pub fn new<I>(fonts: I) -> Self
+    where I: IntoIterator<Item = FontArc> {
+        Self(fonts.into_iter().collect())
+    }
+
+    /// #### 한국어 </br>
+    /// 문자의 글리프를 가지고 있는 첫 번째 폰트를 반환합니다. </br>
+    /// 기본 폰트부터 확인하며, 어떤 폰트에도 글리프가 없는 경우 기본 폰트를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the first font that has a glyph for the character. </br>
+    /// The primary font is checked first, and if no font has the glyph, the primary font is returned. </br>
+    /// 
+    pub fn resolve<'a>(&'a self, primary: &'a FontArc, ch: char) -> &'a FontArc {
+        return std::iter::once(primary)
+            .chain(self.0.iter())
+            .find(|font| font.glyph_id(ch).0 != 0)
+            .unwrap_or(primary);
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn each_glyph_resolves_to_the_first_covering_font() {
+        let primary = FontArc::try_from_slice(include_bytes!("../../assets/fonts/nexon_lv2_gothic_bold.ttf"))
+            .expect("Failed to load font.");
+        let medium = FontArc::try_from_slice(include_bytes!("../../assets/fonts/nexon_lv2_gothic_medium.ttf"))
+            .expect("Failed to load font.");
+        let fallback = FontFallback::new([medium]);
+
+        // (한국어) 기본 폰트에는 줄 바꿈 없는 공백(U+00A0)의 글리프가 없습니다.
+        // (English Translation) The primary font has no glyph for the no-break space (U+00A0).
+        let resolved: Vec<_> = "A\u{a0}가".chars()
+            .map(|ch| fallback.resolve(&primary, ch))
+            .collect();
+        assert!(std::ptr::eq(resolved[0], &primary));
+        assert!(std::ptr::eq(resolved[1], &fallback.0[0]));
+        assert!(std::ptr::eq(resolved[2], &primary));
+
+        // (한국어) 어떤 폰트에도 없는 글리프는 기본 폰트를 사용합니다.
+        // (English Translation) A glyph missing from every font uses the primary font.
+        assert!(std::ptr::eq(fallback.resolve(&primary, '😀'), &primary));
+    }
+}
@@ -0,0 +1,196 @@
+use crate::components::{
+    ui::UiObject,
+    player::MAX_PLAYER_HEARTS,
+    interpolation,
+};
+
+
+
+/// #### 한국어 </br>
+/// 하트가 나타나거나 사라지는 애니메이션의 지속 시간입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Duration of the animation in which a heart appears or disappears. </br>
+/// 
+const DURATION: f64 = 0.4;
+
+
+
+/// #### 한국어 </br>
+/// 하트의 애니메이션 상태 목록입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// List of heart animation states. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum HeartAnimation {
+    Lose(f64),
+    Gain(f64),
+}
+
+
+
+/// #### 한국어 </br>
+/// 플레이어의 체력을 보여주는 하트 모음입니다. </br>
+/// 하트는 왼쪽부터 순서대로 채워지며, 잃거나 얻은 하트는 크기가 변하는 애니메이션을 재생합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A set of hearts showing the player's health. </br>
+/// Hearts are filled in order from the left,
+/// and lost or gained hearts play an animation that changes their size. </br>
+/// 
+#[derive(Debug)]
+pub struct HeartBar<T = UiObject> {
+    hearts: Vec<T>,
+    owned: usize,
+    animations: Vec<Option<HeartAnimation>>,
+}
+
+impl<T> HeartBar<T> {
+    /// #### 한국어 </br>
+    /// 주어진 하트들을 모두 가진 상태로 생성합니다. </br>
+    /// 하트의 수는 `MAX_PLAYER_HEARTS`를 넘을 수 없습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates it with all the given hearts owned. </br>
+    /// The number of hearts cannot exceed `MAX_PLAYER_HEARTS`. </br>
+    /// 
+    pub fn new(mut hearts: Vec<T>) -> Self {
+        hearts.truncate(MAX_PLAYER_HEARTS);
+        let owned = hearts.len();
+        let animations = vec![None; owned];
+        Self { hearts, owned, animations }
+    }
+
+    /// #### 한국어 </br>
+    /// 하트 하나를 잃고 남은 하트의 수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Loses one heart and returns the number of remaining hearts. </br>
+    /// 
+    pub fn lose_one(&mut self) -> usize {
+        if self.owned > 0 {
+            self.owned -= 1;
+            self.animations[self.owned] = Some(HeartAnimation::Lose(0.0));
+        }
+        return self.owned;
+    }
+
+    /// #### 한국어 </br>
+    /// 하트 하나를 얻습니다. 이미 모든 하트를 가진 경우 `false`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gains one heart. Returns `false` if all hearts are already owned. </br>
+    /// 
+    #[allow(dead_code)]
+    pub fn gain_one(&mut self) -> bool {
+        if self.owned >= self.hearts.len() {
+            return false;
+        }
+        self.animations[self.owned] = Some(HeartAnimation::Gain(0.0));
+        self.owned += 1;
+        return true;
+    }
+
+    /// #### 한국어 </br>
+    /// 남은 하트의 수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the number of remaining hearts. </br>
+    /// 
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.owned
+    }
+
+    /// #### 한국어 </br>
+    /// 남은 하트가 없는 경우 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if there are no hearts left. </br>
+    /// 
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.owned == 0
+    }
+
+    /// #### 한국어 </br>
+    /// 남은 하트들을 순회합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Iterates over the remaining hearts. </br>
+    /// 
+    #[inline]
+    pub fn owned(&self) -> impl Iterator<Item = &T> {
+        self.hearts[..self.owned].iter()
+    }
+
+    /// #### 한국어 </br>
+    /// 그려야 하는 모든 하트들을 순회합니다. (사라지는 중인 하트 포함) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Iterates over all hearts to draw. (Including hearts that are disappearing) </br>
+    /// 
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.hearts.iter()
+            .enumerate()
+            .filter(|(idx, _)| *idx < self.owned || self.animations[*idx].is_some())
+            .map(|(_, it)| it)
+    }
+}
+
+impl HeartBar<UiObject> {
+    /// #### 한국어 </br>
+    /// 하트가 나타나거나 사라지는 애니메이션을 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the animation in which hearts appear or disappear. </br>
+    /// 
+    pub fn update(&mut self, elapsed_time: f64, queue: &wgpu::Queue) {
+        for (heart, animation) in self.hearts.iter().zip(self.animations.iter_mut()) {
+            let (timer, scale) = match animation {
+                Some(HeartAnimation::Lose(timer)) => {
+                    *timer += elapsed_time;
+                    (*timer, 1.0 - interpolation::f64::smooth_step(*timer, DURATION) as f32)
+                },
+                Some(HeartAnimation::Gain(timer)) => {
+                    *timer += elapsed_time;
+                    (*timer, interpolation::f64::smooth_step(*timer, DURATION) as f32)
+                },
+                None => continue,
+            };
+
+            heart.update(queue, |data| {
+                data.local_scale = (scale, scale, scale).into();
+            });
+
+            if timer >= DURATION {
+                *animation = None;
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn losing_then_gaining_restores_count() {
+        let mut hearts = HeartBar::new(vec![(); MAX_PLAYER_HEARTS + 2]);
+        assert_eq!(hearts.len(), MAX_PLAYER_HEARTS);
+        assert!(!hearts.gain_one());
+
+        assert_eq!(hearts.lose_one(), MAX_PLAYER_HEARTS - 1);
+        assert_eq!(hearts.lose_one(), MAX_PLAYER_HEARTS - 2);
+        assert_eq!(hearts.iter().count(), MAX_PLAYER_HEARTS);
+        assert_eq!(hearts.owned().count(), MAX_PLAYER_HEARTS - 2);
+
+        assert!(hearts.gain_one());
+        assert!(hearts.gain_one());
+        assert_eq!(hearts.len(), MAX_PLAYER_HEARTS);
+        assert!(!hearts.gain_one());
+    }
+}
@@ -2,15 +2,18 @@ pub mod collider2d;
 pub mod sprite;
 pub mod text;
 pub mod ui;
+pub mod focus;
 
 pub mod anchor;
 pub mod camera;
 pub mod control;
+pub mod cursor;
 pub mod font;
 pub mod interpolation;
 pub mod margin;
 pub mod script;
 pub mod sound;
+pub mod toast;
 pub mod transform;
 pub mod user;
 pub mod save;
@@ -19,3 +22,4 @@ pub mod bullet;
 pub mod player;
 pub mod table;
 pub mod boss;
+pub mod heart;
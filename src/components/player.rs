@@ -2,6 +2,7 @@ use std::f32::consts::PI;
 use std::collections::VecDeque;
 
 use glam::Vec3;
+use serde::{Serialize, Deserialize};
 
 use crate::components::{
     collider2d::shape::AABB, 
@@ -12,14 +13,46 @@ use crate::components::{
 
 pub const MAX_PLAYER_HEARTS: usize = 5;
 
+/// #### 한국어 </br>
+/// 플레이어가 피해를 입은 후 무적 상태가 유지되는 시간입니다. </br>
+/// 이 시간 동안 플레이어 스프라이트가 깜빡이며 추가 피해를 받지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Time for which the player stays invincible after taking damage. </br>
+/// During this time the player sprite blinks and no further damage is taken. </br>
+/// 
+pub const INVINCIBILITY_DURATION: f64 = 3.0;
+
+/// #### 한국어 </br>
+/// 방향키 입력을 기억하는 시간입니다. </br>
+/// 다음 타일에 도착하기 직전에 눌렀다 뗀 방향키도 이 시간 안이라면 다음 타일에서 적용됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Time for which a direction key input is remembered. </br>
+/// A direction key pressed and released just before reaching the next tile 
+/// is still applied at that tile if it is within this time. </br>
+/// 
+pub const INPUT_BUFFER_SEC: f64 = 0.1;
+
+/// #### 한국어 </br>
+/// 이동 중인 플레이어의 (출발 타일, 도착 타일, 보간 값) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// (start tile, end tile, interpolation value) of a moving player. </br>
+/// 
+pub type TileMove = ((usize, usize), (usize, usize), f32);
+
 
 
 /// #### 한국어 </br>
 /// 사용자가 선택 가능한 캐릭터의 목록입니다. </br>
+/// 기본값인 `Aris`는 선택된 캐릭터 없이 게임 장면에 진입한 경우 사용하는 대체 캐릭터 입니다. </br>
 /// 
 /// #### English (Translation) </br>
 /// This is a list of characters that the user can select. </br>
+/// The default value `Aris` is the fallback character used when the game scene is entered without a selected character. </br>
 /// 
+#[derive(Serialize, Deserialize)]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Actor {
     #[default]
@@ -98,11 +131,88 @@ pub enum PlayerGameState {
 
 
 
+/// #### 한국어 </br>
+/// 플레이어의 이동 방식을 조절하는 설정입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Settings that tune how the player moves. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlayerConfig {
+    /// #### 한국어 </br>
+    /// 최고 속도일 때 타일 하나를 이동하는 데 걸리는 시간(초)입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Time (in seconds) to move one tile at full speed. </br>
+    /// 
+    pub tile_duration: f64, 
+
+    /// #### 한국어 </br>
+    /// 멈춰 있다가 출발할 때 타일 하나를 이동하는 데 걸리는 시간(초)입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Time (in seconds) to move one tile when starting from a standstill. </br>
+    /// 
+    pub start_tile_duration: f64, 
+
+    /// #### 한국어 </br>
+    /// 최고 속도에 도달할 때까지 이동하는 타일의 수입니다. 0이면 가속하지 않습니다. </br>
+    /// 멈추면 다시 출발 속도로 감속합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Number of tiles moved until full speed is reached. 0 means no acceleration. </br>
+    /// Stopping decelerates back to the starting speed. </br>
+    /// 
+    pub acceleration_tiles: u32, 
+
+    /// #### 한국어 </br>
+    /// `true`이면 타일 사이를 보간하며 이동하고, `false`이면 다음 타일로 한 번에 이동합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// If `true`, moves by interpolating between tiles; if `false`, jumps to the next tile at once. </br>
+    /// 
+    pub smooth_movement: bool, 
+}
+
+impl PlayerConfig {
+    pub const DEFAULT: Self = Self {
+        tile_duration: 0.05, 
+        start_tile_duration: 0.05, 
+        acceleration_tiles: 0, 
+        smooth_movement: true, 
+    };
+
+    /// #### 한국어 </br>
+    /// 연속으로 이동한 타일의 수에 따라 다음 타일로 이동하는 데 걸리는 시간을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the time to move to the next tile based on the number of tiles moved in a row. </br>
+    /// 
+    pub fn step_duration(&self, streak: u32) -> f64 {
+        if self.acceleration_tiles == 0 {
+            return self.tile_duration;
+        }
+        let t = (streak as f64 / self.acceleration_tiles as f64).min(1.0);
+        return self.start_tile_duration + (self.tile_duration - self.start_tile_duration) * t;
+    }
+}
+
+impl Default for PlayerConfig {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+
+
 /// #### 한국어 </br>
 /// 플레이어 데이터를 담고있는 구조체 입니다. </br>
+/// 게임 진행에 필요한 데이터는 [`PlayerBody`]에 있으며, 이 구조체는 화면에 그려지는 데이터만 담고 있습니다. </br>
 /// 
 /// #### English (Translation) </br>
 /// This is a structure that contains player data. </br>
+/// The data needed for the game progress is in [`PlayerBody`], and this structure only contains the data drawn on the screen. </br>
 /// 
 #[derive(Debug)]
 pub struct Player {
@@ -111,17 +221,7 @@ pub struct Player {
     pub face_timer: f64, 
     pub face_state: PlayerFaceState, 
 
-    pub moving_timer: f64, 
-    pub control_state: PlayerControlState,
-
-    pub game_timer: f64,
-    pub game_state: PlayerGameState, 
-
     pub depth: f32, 
-    pub curr: (usize, usize),
-    pub next: Option<(usize, usize)>,
-    pub path: VecDeque<(usize, usize)>,
-
     pub sprite: Sprite,
 }
 
@@ -157,14 +257,7 @@ impl Player {
             actor, 
             face_timer: 0.0, 
             face_state: PlayerFaceState::default(), 
-            moving_timer: 0.0, 
-            control_state: PlayerControlState::default(), 
-            game_timer: 0.0, 
-            game_state: PlayerGameState::default(), 
             depth, 
-            curr: (row, col), 
-            next: None, 
-            path: VecDeque::with_capacity(64), 
             sprite 
         }
     }
@@ -188,50 +281,163 @@ impl Player {
 }
 
 
-const GAME_UPDATE_FN: [&'static dyn Fn(f64, &wgpu::Queue, &mut Player); 2] = [
-    &update_player_empty_state, 
-    &update_player_invincibility_state, 
-];
 
 /// #### 한국어 </br>
-/// 플레이어의 게임 상태를 갱신합니다. </br>
+/// 타일 위를 움직이는 플레이어의 게임 진행 데이터를 담고있는 구조체 입니다. </br>
+/// 그래픽스 자원을 가지지 않으므로 화면 없이도 갱신하고 검사할 수 있습니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Updates the playaer's game state. </br>
+/// This is a structure that contains the game progress data of the player moving on the tiles. </br>
+/// It has no graphics resources, so it can be updated and tested without a screen. </br>
 /// 
-#[inline]
-pub fn update_player_game_state(elapsed_time: f64, queue: &wgpu::Queue, player: &mut Player) {
-    GAME_UPDATE_FN[player.game_state as usize](elapsed_time, queue, player);
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PlayerBody {
+    pub moving_timer: f64, 
+    pub moving_streak: u32, 
+    pub control_state: PlayerControlState,
+    pub input_buffer: Option<(PlayerControlState, f64)>, 
+    pub config: PlayerConfig, 
+
+    pub game_timer: f64,
+    pub game_state: PlayerGameState, 
+
+    pub curr: (usize, usize),
+    pub next: Option<(usize, usize)>,
+    pub target: Option<(usize, usize)>,
+    pub path: VecDeque<(usize, usize)>,
 }
 
-#[inline]
-fn update_player_empty_state(_elapsed_time: f64, _queue: &wgpu::Queue, _player: &mut Player) {
-    /* empty */
+impl PlayerBody {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { 
+            moving_timer: 0.0, 
+            moving_streak: 0, 
+            control_state: PlayerControlState::default(), 
+            input_buffer: None, 
+            config: PlayerConfig::default(), 
+            game_timer: 0.0, 
+            game_state: PlayerGameState::default(), 
+            curr: (row, col), 
+            next: None, 
+            target: None, 
+            path: VecDeque::with_capacity(64), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 눌린 방향키를 입력 버퍼에 기억합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Remembers the pressed direction key in the input buffer. </br>
+    /// 
+    #[inline]
+    pub fn buffer_input(&mut self, direction: PlayerControlState) {
+        self.input_buffer = Some((direction, INPUT_BUFFER_SEC));
+    }
+
+    /// #### 한국어 </br>
+    /// 플레이어가 조작이 없을 때 한 타일씩 이동해 갈 목표 타일을 지정합니다. </br>
+    /// 방향 조작이 입력되면 목표는 취소됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the target tile that the player moves toward one tile at a time while there is no control input. </br>
+    /// The target is canceled when a direction control is entered. </br>
+    /// 
+    #[cfg(test)]
+    #[inline]
+    pub fn set_target(&mut self, row: usize, col: usize) {
+        self.target = Some((row, col));
+    }
+
+    /// #### 한국어 </br>
+    /// 플레이어의 이동 타이머를 갱신하고 다음 타일을 향해 이동합니다. </br>
+    /// 이동 중인 경우 (출발 타일, 도착 타일, 보간 값)을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the player's movement timer and moves toward the next tile. </br>
+    /// Returns (start tile, end tile, interpolation value) while moving. </br>
+    /// 
+    #[inline]
+    pub fn update(&mut self, elapsed_time: f64) -> Option<TileMove> {
+        tick_input_buffer(&mut self.input_buffer, elapsed_time);
+        advance_movement(
+            &mut self.curr, 
+            &mut self.next, 
+            &mut self.moving_timer, 
+            &mut self.moving_streak, 
+            &self.config, 
+            elapsed_time
+        )
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 이동 중인 (출발 타일, 도착 타일, 보간 값)을 반환합니다. </br>
+    /// 멈춰 있는 경우 출발 타일과 도착 타일이 현재 타일과 같습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the current (start tile, end tile, interpolation value) of the movement. </br>
+    /// When standing still, both the start and end tiles are the current tile. </br>
+    /// 
+    pub fn tile_move(&self) -> TileMove {
+        let Some(next) = self.next else {
+            return (self.curr, self.curr, 0.0);
+        };
+
+        let duration = self.config.step_duration(self.moving_streak);
+        let delta = match self.config.smooth_movement {
+            true => (self.moving_timer / duration).min(1.0) as f32,
+            false => if self.moving_timer >= duration { 1.0 } else { 0.0 },
+        };
+        return (self.curr, next, delta);
+    }
+
+    /// #### 한국어 </br>
+    /// 플레이어의 게임 상태를 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the player's game state. </br>
+    /// 
+    #[inline]
+    pub fn tick_game_state(&mut self, elapsed_time: f64) {
+        if self.game_state == PlayerGameState::Invincibility {
+            tick_invincibility(&mut self.game_state, &mut self.game_timer, elapsed_time);
+        }
+    }
 }
 
-fn update_player_invincibility_state(elapsed_time: f64, queue: &wgpu::Queue, player: &mut Player) {
-    const DURATION: f64 = 3.0;
-    
-    // (한국어) 타이머를 갱신합니다.
-    // (English Translation) Updates the timer.
-    player.game_timer += elapsed_time;
 
-    // (한국어) 플레이어 스프라이트의 색상을 갱신합니다.
-    // (English Translation) Updates the color of the player sprite. 
+/// #### 한국어 </br>
+/// 플레이어 스프라이트의 밝기를 반환합니다. 무적 상태인 동안에는 깜빡입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the brightness of the player sprite. It blinks while invincible. </br>
+/// 
+fn blink_brightness(body: &PlayerBody) -> f32 {
+    if body.game_state != PlayerGameState::Invincibility {
+        return 1.0;
+    }
+
     let delta = {
-        let t = (player.game_timer / DURATION).min(1.0) as f32;
+        let t = (body.game_timer / INVINCIBILITY_DURATION).min(1.0) as f32;
         0.5 * (36.0 * PI * t).cos() + 0.5
     };
-    let c = 0.75 + 0.25 * delta.round();
-    player.sprite.update(queue, |instances| {
-        instances[0].color = (c, c, c, instances[0].color.w).into();
-    });
-    
-    // (한국어) 지속 시간보다 클 경우 `Empty` 상태로 변경합니다.
-    // (English Translation) If it is greater than the duration, it changes to `Empty` state. 
-    if player.game_timer >= DURATION {
-        player.game_timer = 0.0;
-        player.game_state = PlayerGameState::Empty;
+    return 0.75 + 0.25 * delta.round();
+}
+
+/// #### 한국어 </br>
+/// 무적 상태의 타이머를 갱신합니다. </br>
+/// 지속 시간보다 클 경우 `Empty` 상태로 변경합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Updates the timer of the invincibility state. </br>
+/// If it is greater than the duration, it changes to `Empty` state. </br>
+/// 
+#[inline]
+fn tick_invincibility(game_state: &mut PlayerGameState, game_timer: &mut f64, elapsed_time: f64) {
+    *game_timer += elapsed_time;
+    if *game_timer >= INVINCIBILITY_DURATION {
+        *game_timer = 0.0;
+        *game_state = PlayerGameState::Empty;
     }
 }
 
@@ -274,9 +480,7 @@ fn update_player_smile_face(elapsed_time: f64, queue: &wgpu::Queue, player: &mut
     if player.face_timer >= DURATION {
         player.face_timer = 0.0;
         player.face_state = PlayerFaceState::Idle;
-        player.sprite.update(queue, |instances| {
-            instances[0].texture_index = PlayerFaceState::Idle as u32;
-        });
+        player.sprite.set_frame(queue, PlayerFaceState::Idle as u32);
     }
 }
 
@@ -293,81 +497,205 @@ fn update_player_hit_face(elapsed_time: f64, queue: &wgpu::Queue, player: &mut P
     if player.face_timer >= DURATION {
         player.face_timer = 0.0;
         player.face_state = PlayerFaceState::Idle;
-        player.sprite.update(queue, |instances| {
-            instances[0].texture_index = PlayerFaceState::Idle as u32;
-        });
+        player.sprite.set_frame(queue, PlayerFaceState::Idle as u32);
     }
 }
 
 
 
 /// #### 한국어 </br>
-/// 플레이어를 다음 위치로 이동시키는 함수입니다. </br>
+/// 플레이어 스프라이트에 플레이어의 위치와 무적 상태를 반영하는 함수입니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// This function moves the player to the next position. </br>
+/// This function reflects the player's position and invincibility state to the player sprite. </br>
 /// 
-pub fn translation_player(
-    elapsed_time: f64,
+pub fn sync_player_sprite(
     table: &Table, 
-    player: &mut Player, 
+    player: &Player, 
+    body: &PlayerBody, 
     queue: &wgpu::Queue
 ) {
-    const DURATION: f64 = 0.05;
-
-    // (한국어) 플레이어 타이머를 갱신합니다.
-    // (English Translation) Updates the player timer.
-    player.moving_timer += elapsed_time;
-
-    if let Some(next) = player.next.take() {
-        let delta = (player.moving_timer / DURATION).min(1.0) as f32;
-        let beg_x = table::position(table.origin.x, table.size.x, player.curr.1);
-        let beg_y = table::position(table.origin.y, table.size.y, player.curr.0);
-        let end_x = table::position(table.origin.x, table.size.x, next.1);
-        let end_y = table::position(table.origin.y, table.size.y, next.0);
-
-        // (한국어) 현재 플레이어의 위치를 계산합니다.
-        // (English Translation) Calculates the current player's position.
-        let x = beg_x + (end_x - beg_x) * delta;
-        let y = beg_y + (end_y - beg_y) * delta;
-
-        player.sprite.update(queue, |instances| {
-            instances[0].translation.x = x;
-            instances[0].translation.y = y;
-        });
-
-        if player.moving_timer >= DURATION {
-            player.moving_timer = 0.0;
-            player.curr = next;
-            player.next = None;
-        } else {
-            player.next = Some(next);
+    let (curr, next, delta) = body.tile_move();
+    let beg_x = table::position(table.origin.x, table.size.x, curr.1);
+    let beg_y = table::position(table.origin.y, table.size.y, curr.0);
+    let end_x = table::position(table.origin.x, table.size.x, next.1);
+    let end_y = table::position(table.origin.y, table.size.y, next.0);
+
+    // (한국어) 현재 플레이어의 위치를 계산합니다.
+    // (English Translation) Calculates the current player's position.
+    let x = beg_x + (end_x - beg_x) * delta;
+    let y = beg_y + (end_y - beg_y) * delta;
+    let c = blink_brightness(body);
+
+    player.sprite.update(queue, |instances| {
+        instances[0].translation.x = x;
+        instances[0].translation.y = y;
+        instances[0].color = (c, c, c, instances[0].color.w).into();
+
+        // (한국어) 마지막으로 좌우로 이동한 방향을 바라보도록 스프라이트를 반전합니다.
+        // (English Translation) Flips the sprite to face the last horizontal movement direction.
+        if next.1 != curr.1 {
+            instances[0].flip_x = next.1 < curr.1;
+        }
+    });
+}
+
+
+/// #### 한국어 </br>
+/// 이동 타이머를 갱신하고, 다음 타일로 이동하는 데 걸리는 시간이 지나면 현재 타일을 갱신합니다. </br>
+/// 다음 타일이 없으면 연속 이동이 끊겨 출발 속도로 감속합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Updates the movement timer, and updates the current tile once the time to move to the next tile has passed. </br>
+/// If there is no next tile, the streak is broken and it decelerates to the starting speed. </br>
+/// 
+fn advance_movement(
+    curr: &mut (usize, usize), 
+    next: &mut Option<(usize, usize)>, 
+    moving_timer: &mut f64, 
+    moving_streak: &mut u32, 
+    config: &PlayerConfig, 
+    elapsed_time: f64
+) -> Option<TileMove> {
+    *moving_timer += elapsed_time;
+
+    let Some(next_pos) = *next else {
+        *moving_streak = 0;
+        return None;
+    };
+
+    let begin = *curr;
+    let duration = config.step_duration(*moving_streak);
+    let delta = match config.smooth_movement {
+        true => (*moving_timer / duration).min(1.0) as f32,
+        false => if *moving_timer >= duration { 1.0 } else { 0.0 },
+    };
+
+    if *moving_timer >= duration {
+        *moving_timer = 0.0;
+        *moving_streak = moving_streak.saturating_add(1);
+        *curr = next_pos;
+        *next = None;
+    }
+
+    return Some((begin, next_pos, delta));
+}
+
+
+/// #### 한국어 </br>
+/// 입력 버퍼의 남은 시간을 갱신합니다. 시간이 지나면 버퍼를 비웁니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Updates the remaining time of the input buffer. Clears the buffer when the time is up. </br>
+/// 
+#[inline]
+fn tick_input_buffer(input_buffer: &mut Option<(PlayerControlState, f64)>, elapsed_time: f64) {
+    if let Some((_, remaining)) = input_buffer.as_mut() {
+        *remaining -= elapsed_time;
+        if *remaining <= 0.0 {
+            *input_buffer = None;
         }
     }
 }
 
 
+/// #### 한국어 </br>
+/// 타일 경계에서 적용할 이동 방향을 반환하고 입력 버퍼를 비웁니다. </br>
+/// 방향키를 누르고 있는 경우 그 방향을, 그렇지 않으면 버퍼에 남아있는 방향을 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the movement direction to apply at a tile boundary and clears the input buffer. </br>
+/// Uses the held direction key if any, otherwise the direction left in the buffer. </br>
+/// 
+#[inline]
+fn take_direction(
+    control_state: PlayerControlState, 
+    input_buffer: &mut Option<(PlayerControlState, f64)>
+) -> PlayerControlState {
+    let buffered = input_buffer.take();
+    if control_state != PlayerControlState::Idle {
+        return control_state;
+    }
+    return buffered.map(|(direction, _)| direction).unwrap_or(PlayerControlState::Idle);
+}
+
+
+/// #### 한국어 </br>
+/// 현재 타일에서 목표 타일을 향한 다음 타일을 반환합니다. (열을 먼저 맞춥니다.) </br>
+/// 이미 목표 타일에 있는 경우 `None`을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the next tile from the current tile toward the target tile. (Aligns the column first.) </br>
+/// Returns `None` if already on the target tile. </br>
+/// 
+#[inline]
+fn step_toward(curr: (usize, usize), target: (usize, usize)) -> Option<(usize, usize)> {
+    use std::cmp::Ordering;
+    match (curr.1.cmp(&target.1), curr.0.cmp(&target.0)) {
+        (Ordering::Less, _) => Some((curr.0, curr.1 + 1)), 
+        (Ordering::Greater, _) => Some((curr.0, curr.1 - 1)), 
+        (Ordering::Equal, Ordering::Less) => Some((curr.0 + 1, curr.1)), 
+        (Ordering::Equal, Ordering::Greater) => Some((curr.0 - 1, curr.1)), 
+        (Ordering::Equal, Ordering::Equal) => None, 
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 플레이어가 타일에 도착했을 때 경로에 일어난 변화 목록입니다. </br>
+/// - 늘어남: 방문하지 않은 타일이 경로에 추가되었습니다. </br>
+/// - 줄어듦: 이전 타일로 되돌아가 마지막 타일이 경로에서 제거되었습니다. </br>
+/// - 닫힘: 경로가 닫혀 영역을 획득할 수 있습니다. </br>
+/// - 교차: 자신의 경로와 교차했습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// List of changes to the trail when the player arrives on a tile. </br>
+/// - Extended: An unvisited tile was added to the trail. </br>
+/// - Retracted: The player went back to the previous tile and the last tile was removed from the trail. </br>
+/// - Closed: The trail is closed and the tiles can be acquired. </br>
+/// - Crossed: The player crossed its own trail. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrailStep {
+    Extended((usize, usize)), 
+    Retracted((usize, usize)), 
+    Closed, 
+    Crossed, 
+}
+
+
 /// #### 한국어 </br>
 /// 플레이어의 다음 위치를 지정하는 함수입니다. </br>
 /// 
 /// #### English (Translation) </br>
 /// This function specifies the player's next position. </br>
 /// 
-pub fn set_player_next_position(table: &Table, player: &mut Player) {
-    if player.next.is_none() {
-        player.next = match player.control_state {
-            PlayerControlState::Idle => None,
-            PlayerControlState::Left => (player.curr.1 > 0).then(|| {
-                (player.curr.0, player.curr.1 - 1)
+pub fn set_player_next_position(num_rows: usize, num_cols: usize, body: &mut PlayerBody) {
+    if body.control_state != PlayerControlState::Idle {
+        body.target = None;
+    }
+
+    if body.next.is_none() {
+        body.next = match take_direction(body.control_state, &mut body.input_buffer) {
+            PlayerControlState::Idle => body.target.and_then(|target| {
+                let next = step_toward(body.curr, target)
+                    .filter(|&(r, c)| r < num_rows && c < num_cols);
+                if next.is_none() {
+                    body.target = None;
+                }
+                next
+            }),
+            PlayerControlState::Left => (body.curr.1 > 0).then(|| {
+                (body.curr.0, body.curr.1 - 1)
             }),
-            PlayerControlState::Right => (player.curr.1 + 1 < table.num_cols).then(|| {
-                (player.curr.0, player.curr.1 + 1)
+            PlayerControlState::Right => (body.curr.1 + 1 < num_cols).then(|| {
+                (body.curr.0, body.curr.1 + 1)
             }),
-            PlayerControlState::Down => (player.curr.0 > 0).then(|| {
-                (player.curr.0 - 1, player.curr.1)
+            PlayerControlState::Down => (body.curr.0 > 0).then(|| {
+                (body.curr.0 - 1, body.curr.1)
             }), 
-            PlayerControlState::Up => (player.curr.0 + 1 < table.num_rows).then(|| {
-                (player.curr.0 + 1, player.curr.1)
+            PlayerControlState::Up => (body.curr.0 + 1 < num_rows).then(|| {
+                (body.curr.0 + 1, body.curr.1)
             })
         }
     }
@@ -376,55 +704,36 @@ pub fn set_player_next_position(table: &Table, player: &mut Player) {
 
 /// #### 한국어 </br>
 /// 현재 플레이어의 위치가 경로에 포함되는지, 닫힌 공간이 만들어 졌는지 확인합니다. </br>
-/// 플레이어가 영역을 획득했을 경우 `true`를 반환합니다. </br>
+/// 경로에 변화가 생긴 경우 그 변화를 반환합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Checks whether the current player's positiohn is included in the path </br>
+/// Checks whether the current player's position is included in the path </br>
 /// or whether an enclosed space has been created. </br>
-/// Returns `true` if the plyaer has acquired the tiles. </br>
+/// Returns the change if the path has changed. </br>
 /// 
-pub fn check_current_pos(
-    table: &mut Table, 
-    player: &mut Player, 
-    tile_brush: &TileBrush,
-    queue: &wgpu::Queue
-) -> Option<bool> {
-    if player.next.is_none() {
-        if !table.tiles[player.curr.0][player.curr.1].visited {
+pub fn check_current_pos(visited: &mut [Vec<bool>], body: &mut PlayerBody) -> Option<TrailStep> {
+    if body.next.is_none() {
+        let (row, col) = body.curr;
+        if !visited[row][col] {
             // (한국어) 현재 타일에 방문하지 않았을 경우 경로에 추가한다.
             // (English Translation) If the tile is not currently visited, it is added to the path.
-            table.tiles[player.curr.0][player.curr.1].visited = true;
-            player.path.push_back(player.curr);
-            tile_brush.update(queue, |instances| {
-                instances[player.curr.0 * table.num_cols + player.curr.1].color = table.line_color;
-            })
-        } else if player.path.back().is_some_and(|&(r, c)| {
-            r == player.curr.0 && c == player.curr.1
-        }) {
+            visited[row][col] = true;
+            body.path.push_back(body.curr);
+            return Some(TrailStep::Extended(body.curr));
+        } else if body.path.back().is_some_and(|&pos| pos == body.curr) {
             // (한국어) 플레이어가 타일 위에 멈춰 있는 경우 아무 처리도 하지 않는다.
             // (English Translation) If the player is stopped on a tile, no action is taken.
-        } else if player.path.len() > 1 && player.path.iter().nth_back(1).is_some_and(|&(r, c)| {
-            r == player.curr.0 && c == player.curr.1
-        }) {
+        } else if body.path.len() > 1 && body.path.iter().nth_back(1).is_some_and(|&pos| pos == body.curr) {
             // (한국어) 플레이어가 이전 타일에 있는 경우 경로에 마지막으로 추가된 타일을 제거한다.
             // (English Translation) If the player is at the previous tile, remove the last tile added to the path.
-            let (row, col) = player.path.pop_back().unwrap();
-            table.tiles[row][col].visited = false;
-            tile_brush.update(queue, |instances| {
-                instances[row * table.num_cols + col].color = table.tiles[row][col].color;
-            });
+            let (row, col) = body.path.pop_back().unwrap();
+            visited[row][col] = false;
+            return Some(TrailStep::Retracted((row, col)));
         } else {
-            let included = player.path.iter()
-                .skip(1)
-                .find(|(r, c)| *r == player.curr.0 && *c == player.curr.1)
-                .is_some();
-            if !included {
-                if !player.path.is_empty() {
-                    return Some(true);
-                } 
-            } else {
-                return Some(false);
-            }
+            return closes_trail(&body.path, body.curr).map(|closed| match closed {
+                true => TrailStep::Closed, 
+                false => TrailStep::Crossed, 
+            });
         }
     }
 
@@ -432,49 +741,171 @@ pub fn check_current_pos(
 }
 
 /// #### 한국어 </br>
-/// 경로의 모든 타일을 원래 상태로 복구하고, 경로를 비웁니다. </br>
-/// 플레이어를 처음 위치에 스폰합니다. </br>
+/// 플레이어가 이미 방문한 타일 위에 도착했을 때 경로가 어떻게 되는지 확인합니다. </br>
+/// 경로가 닫혀 영역을 획득한 경우 `Some(true)`를, 자신의 경로와 교차한 경우 `Some(false)`를 반환합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Restores all tiles in the path to their original state and clears the path. </br>
-/// Players spawn at their initial position. </br>
-/// 
-pub fn restore(
-    queue: &wgpu::Queue, 
-    table: &mut Table, 
-    boss: &mut Boss, 
-    player: &mut Player, 
-    tile_brush: &TileBrush
-) {
+/// Checks what happens to the trail when the player arrives on a tile that has already been visited. </br>
+/// Returns `Some(true)` if the trail is closed and the tiles are acquired, 
+/// or `Some(false)` if it crosses its own trail. </br>
+/// 
+fn closes_trail(trail: &VecDeque<(usize, usize)>, curr: (usize, usize)) -> Option<bool> {
+    let included = trail.iter()
+        .skip(1)
+        .any(|&pos| pos == curr);
+    if included {
+        return Some(false);
+    } else if !trail.is_empty() {
+        return Some(true);
+    }
+    return None;
+}
+
+/// #### 한국어 </br>
+/// 주어진 타일이 아직 닫히지 않은 플레이어의 경로 위에 있는지 확인합니다. </br>
+/// 영역을 획득하면 경로가 비워지므로 닫힌 경로는 더 이상 공격받지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Checks whether the given tile is on the player's trail that has not been closed yet. </br>
+/// Since the trail is cleared once the tiles are acquired, a closed trail can no longer be hit. </br>
+/// 
+#[inline]
+pub fn trail_hit(trail: &VecDeque<(usize, usize)>, point: (usize, usize)) -> bool {
+    trail.contains(&point)
+}
+
+/// #### 한국어 </br>
+/// 경로의 모든 타일을 방문하지 않은 상태로 되돌리고, 경로를 비웁니다. </br>
+/// 비워진 경로의 타일들을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns all tiles in the path to the unvisited state and clears the path. </br>
+/// Returns the tiles of the cleared path. </br>
+/// 
+pub fn clear_trail(visited: &mut [Vec<bool>], path: &mut VecDeque<(usize, usize)>) -> Vec<(usize, usize)> {
+    let trail: Vec<_> = path.drain(..).collect();
+    for &(r, c) in trail.iter() {
+        visited[r][c] = false;
+    }
+    return trail;
+}
+
+/// #### 한국어 </br>
+/// 플레이어를 주어진 스폰 위치로 되돌리고 무적 상태로 만듭니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the player to the given spawn position and makes it invincible. </br>
+/// 
+pub fn respawn(body: &mut PlayerBody, spawn_pos: (usize, usize)) {
+    body.next = None;
+    body.curr = spawn_pos;
+    body.moving_timer = 0.0;
+    body.control_state = PlayerControlState::Idle;
+    body.input_buffer = None;
+    body.game_timer = 0.0;
+    body.game_state = PlayerGameState::Invincibility;
+}
+
+/// #### 한국어 </br>
+/// 주어진 타일들의 모습을 원래 상태로 되돌립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Restores the appearance of the given tiles to their original state. </br>
+/// 
+pub fn restore_tiles(queue: &wgpu::Queue, table: &Table, tiles: &[(usize, usize)], tile_brush: &TileBrush) {
     tile_brush.update(queue, |instances| {
-        for &(r, c) in player.path.iter() {
-            instances[r * table.num_cols + c].color = table.tiles[r][c].color;
+        for &(r, c) in tiles.iter() {
+            instances[r * table.num_cols + c].apply_tile(&table.tiles[r][c]);
         }
     });
-    while let Some((r, c)) = player.path.pop_front() {
-        table.tiles[r][c].visited = false;
-    }
-    
-    player.next = None;
-    player.curr = table.player_spawn_pos;
-    player.moving_timer = 0.0;
-    player.control_state = PlayerControlState::Idle;
+}
+
+/// #### 한국어 </br>
+/// 플레이어가 피해를 입었을 때의 표정으로 바꿉니다. 보스는 웃는 표정으로 바뀝니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Changes to the face of the player taking damage. The boss changes to a smiling face. </br>
+/// 
+pub fn show_hit_faces(queue: &wgpu::Queue, player: &mut Player, boss: &mut Boss) {
     player.face_timer = 0.0;
     player.face_state = PlayerFaceState::Hit;
-    player.game_timer = 0.0;
-    player.game_state = PlayerGameState::Invincibility;
-    let x = table::position(table.origin.x, table.size.x, player.curr.1);
-    let y = table::position(table.origin.y, table.size.y, player.curr.0);
-    
-    player.sprite.update(queue, |instances| {
-        instances[0].translation.x = x;
-        instances[0].translation.y = y;
-        instances[0].texture_index = PlayerFaceState::Hit as u32;
-    });
+    player.sprite.set_frame(queue, PlayerFaceState::Hit as u32);
 
     boss.face_timer = 0.0;
     boss.face_state = BossFaceState::Smile;
-    boss.sprite.update(queue, |instances| {
-        instances[0].texture_index = BossFaceState::Smile as u32;
-    });
+    boss.sprite.set_frame(queue, BossFaceState::Smile as u32);
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn movement_reaches_target_in_expected_steps() {
+        fn steps_to_reach(config: &PlayerConfig, target: (usize, usize), elapsed_time: f64) -> usize {
+            let (mut curr, mut next, mut timer, mut streak) = ((0, 0), None, 0.0, 0);
+            let mut steps = 0;
+            while curr != target {
+                advance_movement(&mut curr, &mut next, &mut timer, &mut streak, config, elapsed_time);
+                if next.is_none() {
+                    next = step_toward(curr, target);
+                }
+                steps += 1;
+                assert!(steps < 1000, "The player never reached the target!");
+            }
+            steps
+        }
+
+        // (한국어) 첫 번째 갱신에서 다음 타일이 정해지고, 이후 타일마다 두 번의 갱신에 걸쳐 이동합니다.
+        // (English Translation) The first update picks the next tile, then each tile takes two updates.
+        let config = PlayerConfig { tile_duration: 0.125, start_tile_duration: 0.125, ..PlayerConfig::DEFAULT };
+        assert_eq!(steps_to_reach(&config, (0, 3), 0.0625), 6);
+        assert_eq!(steps_to_reach(&config, (2, 1), 0.0625), 6);
+
+        let config = PlayerConfig { start_tile_duration: 0.25, acceleration_tiles: 1, ..config };
+        assert_eq!(config.step_duration(0), 0.25);
+        assert_eq!(config.step_duration(5), 0.125);
+        assert_eq!(steps_to_reach(&config, (0, 3), 0.0625), 8);
+    }
+
+    #[test]
+    fn direction_tapped_before_boundary_is_honored() {
+        let config = PlayerConfig { tile_duration: 0.125, ..PlayerConfig::DEFAULT };
+        let (mut curr, mut next, mut timer, mut streak) = ((0, 0), Some((0, 1)), 0.0, 0);
+
+        // (한국어) 타일 경계 직전에 `위쪽`키를 눌렀다 뗍니다.
+        // (English Translation) Presses and releases the `Up` key just before the tile boundary.
+        advance_movement(&mut curr, &mut next, &mut timer, &mut streak, &config, 0.0625);
+        let mut input_buffer = Some((PlayerControlState::Up, INPUT_BUFFER_SEC));
+        tick_input_buffer(&mut input_buffer, 0.0625);
+        advance_movement(&mut curr, &mut next, &mut timer, &mut streak, &config, 0.0625);
+        assert_eq!((curr, next), ((0, 1), None));
+        assert_eq!(take_direction(PlayerControlState::Idle, &mut input_buffer), PlayerControlState::Up);
+        assert_eq!(input_buffer, None);
+
+        // (한국어) 너무 일찍 누른 방향키는 무시됩니다.
+        // (English Translation) A direction key pressed too early is ignored.
+        let mut input_buffer = Some((PlayerControlState::Left, INPUT_BUFFER_SEC));
+        tick_input_buffer(&mut input_buffer, INPUT_BUFFER_SEC + 0.0625);
+        assert_eq!(take_direction(PlayerControlState::Idle, &mut input_buffer), PlayerControlState::Idle);
+
+        // (한국어) 누르고 있는 방향키가 우선합니다.
+        // (English Translation) A held direction key takes precedence.
+        let mut input_buffer = Some((PlayerControlState::Left, INPUT_BUFFER_SEC));
+        assert_eq!(take_direction(PlayerControlState::Right, &mut input_buffer), PlayerControlState::Right);
+    }
+
+    #[test]
+    fn open_trail_is_vulnerable_and_closed_trail_captures() {
+        let mut trail: VecDeque<_> = [(1, 1), (1, 2), (1, 3)].into_iter().collect();
+        assert!(trail_hit(&trail, (1, 2)));
+        assert!(!trail_hit(&trail, (2, 2)));
+        assert_eq!(closes_trail(&trail, (1, 2)), Some(false));
+
+        assert_eq!(closes_trail(&trail, (0, 3)), Some(true));
+        trail.clear();
+        assert!(!trail_hit(&trail, (1, 2)));
+        assert_eq!(closes_trail(&trail, (0, 3)), None);
+    }
 }
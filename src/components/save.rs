@@ -1,8 +1,12 @@
+use std::fs;
+use std::path::Path;
+
 use serde::{Serialize, Deserialize};
 
 use crate::{
     game_err, 
     assets::interface::{AssetDecoder, AssetEncoder},
+    components::player::Actor,
     system::error::{AppResult, GameError}, 
 };
 
@@ -16,13 +20,15 @@ use crate::{
 /// 
 #[repr(C)]
 #[derive(Serialize, Deserialize)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct SaveData {
     pub stage_aris: u16, 
     pub stage_momoi: u16, 
     pub stage_midori: u16, 
     pub stage_yuzu: u16, 
     pub beginner: bool, 
+    pub best_times: [Option<f64>; 4], 
+    pub last_actor: Option<Actor>, 
 }
 
 impl Default for SaveData {
@@ -33,9 +39,178 @@ impl Default for SaveData {
             stage_momoi: 0, 
             stage_midori: 0, 
             stage_yuzu: 0, 
-            beginner: true
+            beginner: true, 
+            best_times: [None; 4], 
+            last_actor: None, 
+        }
+    }
+}
+
+impl SaveData {
+    /// #### 한국어 </br>
+    /// 캐릭터 스테이지의 최단 클리어 시간(초)을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the best clear time (in seconds) of the character's stage. </br>
+    /// 
+    #[inline]
+    pub fn best_time(&self, actor: Actor) -> Option<f64> {
+        self.best_times[actor as usize]
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 클리어 시간이 최단 기록보다 짧은 경우 갱신하고 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the best record if the given clear time is shorter, and returns `true`. </br>
+    /// 
+    pub fn update_best_time(&mut self, actor: Actor, clear_time: f64) -> bool {
+        let best = &mut self.best_times[actor as usize];
+        if best.map_or(true, |best| clear_time < best) {
+            *best = Some(clear_time);
+            return true;
+        }
+        return false;
+    }
+
+    /// #### 한국어 </br>
+    /// 캐릭터별 최고 기록을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the best records for each character. </br>
+    /// 
+    pub fn records(&self) -> Records {
+        return Records {
+            aris: StageRecord::new(self.stage_aris, self.best_time(Actor::Aris)),
+            momoi: StageRecord::new(self.stage_momoi, self.best_time(Actor::Momoi)),
+            midori: StageRecord::new(self.stage_midori, self.best_time(Actor::Midori)),
+            yuzu: StageRecord::new(self.stage_yuzu, self.best_time(Actor::Yuzu)),
+        };
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 기록 중 현재 기록보다 높은 기록(더 짧은 클리어 시간)만 반영합니다. </br>
+    /// 기록이 갱신된 경우 `true`를 반환합니다. </br>
+    /// 잘못된 기록이 포함된 경우 아무것도 반영하지 않고 `GameError`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Applies only the given records that are higher (shorter clear times) than the current ones. </br>
+    /// Returns `true` if any record was updated. </br>
+    /// If an invalid record is included, nothing is applied and `GameError` is returned. </br>
+    /// 
+    pub fn merge_best(&mut self, records: &Records) -> AppResult<bool> {
+        is_validate(records.aris.owned_tiles)?;
+        is_validate(records.momoi.owned_tiles)?;
+        is_validate(records.midori.owned_tiles)?;
+        is_validate(records.yuzu.owned_tiles)?;
+
+        let prev = *self;
+        self.stage_aris = self.stage_aris.max(records.aris.owned_tiles);
+        self.stage_momoi = self.stage_momoi.max(records.momoi.owned_tiles);
+        self.stage_midori = self.stage_midori.max(records.midori.owned_tiles);
+        self.stage_yuzu = self.stage_yuzu.max(records.yuzu.owned_tiles);
+        for (actor, record) in [
+            (Actor::Aris, &records.aris), 
+            (Actor::Momoi, &records.momoi), 
+            (Actor::Midori, &records.midori), 
+            (Actor::Yuzu, &records.yuzu), 
+        ] {
+            if let Some(clear_time) = record.best_time.filter(|time| time.is_finite() && *time > 0.0) {
+                self.update_best_time(actor, clear_time);
+            }
         }
+        return Ok(prev != *self);
+    }
+
+    /// #### 한국어 </br>
+    /// 최고 기록을 사람이 읽을 수 있는 `json` 파일로 내보냅니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Exports the best records to a human-readable `json` file. </br>
+    /// 
+    pub fn export_json<P: AsRef<Path>>(&self, path: P) -> AppResult<()> {
+        let text = serde_json::to_string_pretty(&self.records())
+            .map_err(|err| game_err!(
+                "Failed to export records",
+                "The records failed to export for the following reasons: {}",
+                err.to_string()
+            ))?;
+        fs::write(path, text)
+            .map_err(|err| game_err!(
+                "Failed to export records",
+                "The records failed to export for the following reasons: {}",
+                err.to_string()
+            ))?;
+        return Ok(());
     }
+
+    /// #### 한국어 </br>
+    /// `json` 파일의 기록을 불러와 현재 기록보다 높은 기록만 반영합니다. </br>
+    /// 기록이 갱신된 경우 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Loads the records of a `json` file and applies only those higher than the current ones. </br>
+    /// Returns `true` if any record was updated. </br>
+    /// 
+    pub fn import_json<P: AsRef<Path>>(&mut self, path: P) -> AppResult<bool> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| game_err!(
+                "Failed to import records",
+                "The records failed to import for the following reasons: {}",
+                err.to_string()
+            ))?;
+        let records: Records = serde_json::from_str(&text)
+            .map_err(|err| game_err!(
+                "Failed to import records",
+                "The records failed to import for the following reasons: {}",
+                err.to_string()
+            ))?;
+        return self.merge_best(&records);
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 한 캐릭터 스테이지의 최고 기록입니다. </br>
+/// 진행도는 읽기 편하도록 함께 기록되며, 불러올 때는 차지한 타일 수만 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The best record of one character's stage. </br>
+/// The progress is written alongside for readability; only the number of owned tiles is used when importing. </br>
+/// 
+#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StageRecord {
+    pub owned_tiles: u16, 
+    #[serde(default)]
+    pub percent: f32, 
+    #[serde(default)]
+    pub best_time: Option<f64>, 
+}
+
+impl StageRecord {
+    #[inline]
+    fn new(owned_tiles: u16, best_time: Option<f64>) -> Self {
+        use crate::nodes::in_game::NUM_TILES;
+        Self { owned_tiles, percent: owned_tiles as f32 / NUM_TILES as f32 * 100.0, best_time }
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 내보내거나 불러오는 캐릭터별 최고 기록입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The best records for each character that are exported or imported. </br>
+/// 
+#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Records {
+    pub aris: StageRecord, 
+    pub momoi: StageRecord, 
+    pub midori: StageRecord, 
+    pub yuzu: StageRecord, 
 }
 
 
@@ -53,12 +228,19 @@ impl AssetDecoder for SaveDecoder {
 
     #[inline]
     fn decode(&self, buf: &[u8]) -> AppResult<Self::Output> {
-        let output: SaveData = bincode::deserialize(buf)
-            .map_err(|err| game_err!(
-                "Failed to load save file", 
-                "The save file failed to load for the following reasons: {}", 
-                err.to_string()
-            ))?;
+        // (한국어) 클리어 시간이나 마지막으로 플레이한 캐릭터가 추가되기 이전의 세이브 파일도 불러올 수 있습니다.
+        // (English Translation) Save files from before clear times or the last played character were added can also be loaded.
+        let output: SaveData = match bincode::deserialize(buf) {
+            Ok(output) => output, 
+            Err(err) => bincode::deserialize::<ClearTimeSaveData>(buf)
+                .map(SaveData::from)
+                .or_else(|_| bincode::deserialize::<LegacySaveData>(buf).map(SaveData::from))
+                .map_err(|_| game_err!(
+                    "Failed to load save file", 
+                    "The save file failed to load for the following reasons: {}", 
+                    err.to_string()
+                ))?,
+        };
 
         is_validate(output.stage_aris)?;
         is_validate(output.stage_momoi)?;
@@ -69,6 +251,67 @@ impl AssetDecoder for SaveDecoder {
     }
 }
 
+/// #### 한국어 </br>
+/// 클리어 시간이 추가되기 이전의 세이브 데이터 형식입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The save data format from before clear times were added. </br>
+/// 
+#[derive(Deserialize)]
+struct LegacySaveData {
+    stage_aris: u16, 
+    stage_momoi: u16, 
+    stage_midori: u16, 
+    stage_yuzu: u16, 
+    beginner: bool, 
+}
+
+impl From<LegacySaveData> for SaveData {
+    #[inline]
+    fn from(value: LegacySaveData) -> Self {
+        Self {
+            stage_aris: value.stage_aris, 
+            stage_momoi: value.stage_momoi, 
+            stage_midori: value.stage_midori, 
+            stage_yuzu: value.stage_yuzu, 
+            beginner: value.beginner, 
+            best_times: [None; 4], 
+            last_actor: None, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 마지막으로 플레이한 캐릭터가 추가되기 이전의 세이브 데이터 형식입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The save data format from before the last played character was added. </br>
+/// 
+#[derive(Deserialize)]
+struct ClearTimeSaveData {
+    stage_aris: u16, 
+    stage_momoi: u16, 
+    stage_midori: u16, 
+    stage_yuzu: u16, 
+    beginner: bool, 
+    best_times: [Option<f64>; 4], 
+}
+
+impl From<ClearTimeSaveData> for SaveData {
+    #[inline]
+    fn from(value: ClearTimeSaveData) -> Self {
+        Self {
+            stage_aris: value.stage_aris, 
+            stage_momoi: value.stage_momoi, 
+            stage_midori: value.stage_midori, 
+            stage_yuzu: value.stage_yuzu, 
+            beginner: value.beginner, 
+            best_times: value.best_times, 
+            last_actor: None, 
+        }
+    }
+}
+
 #[inline]
 fn is_validate(num_owned_tiles: u16) -> AppResult<()> {
     use crate::nodes::in_game::NUM_TILES;
@@ -104,3 +347,62 @@ impl AssetEncoder for SaveEncoder {
         return Ok(byte);
     }
 }
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn records_round_trip() {
+        let mut save = SaveData { stage_aris: 120, stage_momoi: 7, ..Default::default() };
+        save.update_best_time(Actor::Aris, 61.25);
+        let path = std::env::temp_dir().join(format!("records_round_trip_{}.json", std::process::id()));
+        save.export_json(&path).unwrap();
+
+        let mut loaded = SaveData::default();
+        assert!(loaded.import_json(&path).unwrap());
+        fs::remove_file(&path).ok();
+        assert_eq!(loaded.records(), save.records());
+    }
+
+    #[test]
+    fn merge_keeps_best() {
+        let mut save = SaveData { stage_aris: 100, stage_momoi: 10, ..Default::default() };
+        let mut records = save.records();
+        records.aris.owned_tiles = 50;
+        records.momoi.owned_tiles = 30;
+
+        assert!(save.merge_best(&records).unwrap());
+        assert_eq!((save.stage_aris, save.stage_momoi), (100, 30));
+        assert!(!save.merge_best(&records).unwrap());
+
+        records.aris.best_time = Some(40.0);
+        save.best_times[Actor::Aris as usize] = Some(45.0);
+        assert!(save.merge_best(&records).unwrap());
+        records.aris.best_time = Some(50.0);
+        assert!(!save.merge_best(&records).unwrap());
+        assert_eq!(save.best_time(Actor::Aris), Some(40.0));
+
+        records.yuzu.owned_tiles = u16::MAX;
+        assert!(save.merge_best(&records).is_err());
+        assert_eq!(save.stage_yuzu, 0);
+    }
+
+    #[test]
+    fn legacy_save_is_loaded() {
+        let buf = bincode::serialize(&(10u16, 20u16, 30u16, 40u16, false)).unwrap();
+        let save = SaveDecoder.decode(&buf).unwrap();
+        assert_eq!((save.stage_aris, save.stage_yuzu, save.beginner), (10, 40, false));
+        assert_eq!(save.best_times, [None; 4]);
+
+        let buf = bincode::serialize(&(10u16, 20u16, 30u16, 40u16, false, [Some(61.25f64), None, None, None])).unwrap();
+        let save = SaveDecoder.decode(&buf).unwrap();
+        assert_eq!(save.best_time(Actor::Aris), Some(61.25));
+        assert_eq!(save.last_actor, None);
+
+        let save = SaveData { stage_aris: 10, last_actor: Some(Actor::Momoi), ..Default::default() };
+        let buf = SaveEncoder.encode(&save).unwrap();
+        assert_eq!(SaveDecoder.decode(&buf).unwrap(), save);
+    }
+}
@@ -1,6 +1,11 @@
 use std::collections::HashMap;
+#[cfg(debug_assertions)]
+use std::sync::Arc;
+#[cfg(debug_assertions)]
+use std::time::SystemTime;
 
 use serde::{Serialize, Deserialize};
+use winit::window::Window;
 
 use crate::{
     game_err,
@@ -10,6 +15,8 @@ use crate::{
         GameError
     }, 
 };
+#[cfg(debug_assertions)]
+use crate::system::shared::Shared;
 
 
 
@@ -26,6 +33,11 @@ pub enum ScriptTags {
     BackgroundVolume, 
     EffectVolume, 
     VoiceVolume, 
+    WindowTitle, 
+
+    /* First Time Setup */
+    FirstTimePreviewText, 
+    FirstTimeConfirmButton, 
 
     /* Intro */
     IntroTitle,
@@ -33,20 +45,33 @@ pub enum ScriptTags {
     
     /* Title */
     TitleStartButton,
+    TitleContinueButton,
     TitleSettingButton,
     TitleExitButton,
     TitleStageEnterButton,
+    TitleGpuWarning,
 
     /* Setting */
     SettingTitle, 
     SettingResetButton, 
     SettingReturnButton, 
+    SettingApplyButton, 
     SettingLanguageOptionTitle, 
     SettingLanguageOptionSubTitle, 
     SettingResolutionOptionTitle, 
     SettingResolutionOptionSubTitle, 
     SettingVolumeOptionTitle,
     SettingVolumeOptionSubTitle,  
+    SettingResolutionRevertMessage, 
+    SettingResolutionKeepButton, 
+    SettingResolutionRevertButton, 
+    SettingResetReconfirmMessage, 
+    SettingResetOkayButton, 
+    SettingResetCancelButton, 
+    SettingHideCursorToggle, 
+    SettingCustomCursorToggle, 
+    SettingReduceMotionToggle, 
+    SettingSmallBoardToggle, 
 
     /* Exit Message Box */
     GameExitReconfirmMessage,
@@ -57,6 +82,7 @@ pub enum ScriptTags {
     InGamePauseTitle, 
     InGameResumeButton,
     InGameSettingButton, 
+    InGameRestartButton, 
     InGameGiveUpButton, 
     InGameChallenge0,
     InGameChallenge1,
@@ -65,6 +91,44 @@ pub enum ScriptTags {
     InGameGiveUpReconfirmMessage, 
     InGameGiveUpOkayButton, 
     InGameGiveUpCancelButton, 
+    InGameNewRecordToast, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 스크립트에 창 제목이 없는 경우 사용하는 애플리케이션 윈도우의 제목입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The title of the application window used if the script has no window title. </br>
+/// 
+pub const DEFAULT_WINDOW_TITLE: &'static str = "Millennium Run";
+
+/// #### 한국어 </br>
+/// 언어를 선택하기 전 스크립트가 없는 경우 사용하는 애플리케이션 윈도우의 제목입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The title of the application window used if there is no script before selecting a language. </br>
+/// 
+pub const LANGUAGE_SELECT_WINDOW_TITLE: &'static str = "Select a language";
+
+
+/// #### 한국어 </br>
+/// 주어진 스크립트의 창 제목으로 애플리케이션 윈도우의 제목을 변경합니다. </br>
+/// 스크립트가 없는 경우(언어를 선택하기 전) 언어 선택 제목을 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Changes the title of the application window to the window title of the given script. </br>
+/// If there is no script (before selecting a language), the language selection title is used. </br>
+/// 
+pub fn set_window_title(window: &Window, script: Option<&Script>) {
+    let title = match script {
+        Some(script) => script.get(ScriptTags::WindowTitle)
+            .map(String::as_str)
+            .unwrap_or(DEFAULT_WINDOW_TITLE),
+        None => LANGUAGE_SELECT_WINDOW_TITLE,
+    };
+    window.set_title(title);
 }
 
 
@@ -120,3 +184,110 @@ impl AssetDecoder for ScriptDecoder {
             ))
     }
 }
+
+
+
+/// #### 한국어 </br>
+/// 디버그 빌드에서 현재 언어의 스크립트 파일을 감시하는 구조체 입니다. </br>
+/// 파일의 수정 시간이 바뀐 경우에만 스크립트를 다시 불러옵니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A structure that watches the script file of the current language in debug builds. </br>
+/// The script is reloaded only when the file's modification time changes. </br>
+/// 
+#[cfg(debug_assertions)]
+#[derive(Debug, Default)]
+pub struct ScriptWatcher {
+    tracked: Option<(&'static str, SystemTime)>,
+    last_poll: f64,
+}
+
+#[cfg(debug_assertions)]
+impl ScriptWatcher {
+    const POLL_INTERVAL_SEC: f64 = 0.5;
+
+    /// #### 한국어 </br>
+    /// 감시 중인 파일의 수정 시간을 기록하고, 이전과 달라진 경우 `true`를 반환합니다. </br>
+    /// 처음 관찰하거나 감시할 파일이 바뀐 경우에는 `false`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Records the modification time of the watched file and returns `true` if it has changed. </br>
+    /// Returns `false` on the first observation or when the watched file changes. </br>
+    /// 
+    fn observe(&mut self, rel_path: &'static str, modified: SystemTime) -> bool {
+        let changed = matches!(self.tracked, Some((p, t)) if p == rel_path && t != modified);
+        self.tracked = Some((rel_path, modified));
+        return changed;
+    }
+
+    /// #### 한국어 </br>
+    /// 스크립트 파일이 수정된 경우 다시 불러와 공유 객체에 등록하고 `true`를 반환합니다. </br>
+    /// 스크립트를 읽는 도중 오류가 발생한 경우 경고를 남기고 이전 스크립트를 유지합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// If the script file has been modified, reloads it, registers it in the shared object and returns `true`. </br>
+    /// If an error occurs while reading the script, logs a warning and keeps the previous script. </br>
+    /// 
+    pub fn reload_if_modified(&mut self, shared: &mut Shared, total_time: f64) -> bool {
+        use crate::nodes::path;
+        use crate::assets::bundle::AssetBundle;
+        use crate::components::user::{Language, Settings};
+
+        if total_time - self.last_poll < Self::POLL_INTERVAL_SEC {
+            return false;
+        }
+        self.last_poll = total_time;
+
+        // (한국어) 설정과 스크립트가 준비되기 전에는 실행하지 않습니다.
+        // (English Translation) It will not run before the settings and script are ready.
+        if shared.get::<Arc<Script>>().is_none() {
+            return false;
+        }
+        let rel_path = match shared.get::<Settings>().map(|settings| settings.language) {
+            Some(Language::Korean) => path::KOR_SCRIPTS_PATH,
+            _ => return false,
+        };
+
+        let asset_bundle = shared.get::<AssetBundle>().unwrap();
+        let modified = match asset_bundle.modified(rel_path) {
+            Some(modified) => modified,
+            None => return false,
+        };
+        if !self.observe(rel_path, modified) {
+            return false;
+        }
+
+        // (한국어) 캐시된 핸들을 교체하고 스크립트 파일을 다시 읽습니다.
+        // (English Translation) Replaces the cached handle and reads the script file again.
+        let script = match asset_bundle.reload(rel_path).and_then(|handle| handle.read(&ScriptDecoder)) {
+            Ok(script) => script,
+            Err(err) => {
+                log::warn!("Failed to reload script (file:{}): {:?}", rel_path, err);
+                return false;
+            }
+        };
+
+        log::info!("Script reloaded. (file:{})", rel_path);
+        shared.push(Arc::new(script));
+        return true;
+    }
+}
+
+
+#[cfg(all(test, debug_assertions))]
+mod test {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn reload_only_when_modified_time_changes() {
+        let mut watcher = ScriptWatcher::default();
+        let t0 = SystemTime::UNIX_EPOCH;
+        let t1 = t0 + Duration::from_secs(1);
+
+        assert!(!watcher.observe("scripts/kor.ron", t0));
+        assert!(!watcher.observe("scripts/kor.ron", t0));
+        assert!(watcher.observe("scripts/kor.ron", t1));
+        assert!(!watcher.observe("scripts/other.ron", t0));
+    }
+}
@@ -5,6 +5,7 @@ use rodio::{
     Sink, 
     Sample,
     Source,
+    OutputStream, 
     OutputStreamHandle, 
     cpal::FromSample,
 };
@@ -15,7 +16,11 @@ use crate::{
         bundle::AssetBundle, 
         interface::AssetDecoder, 
     },
-    components::user::Settings,
+    components::{
+        user::Settings,
+        camera::GameCamera,
+        transform::Projection,
+    },
     system::{
         error::{AppResult, GameError}, 
         shared::Shared
@@ -36,6 +41,14 @@ pub struct Volume(u8);
 
 #[allow(dead_code)]
 impl Volume {
+    /// #### 한국어 </br>
+    /// 볼륨이 가질 수 있는 최댓값 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The maximum value a volume can have. </br>
+    /// 
+    pub const MAX: u8 = 100;
+
     /// #### 한국어 </br>
     /// 새로운 볼륨을 생성합니다. </br>
     /// 
@@ -68,11 +81,73 @@ impl Volume {
     pub fn norm(&self) -> f32 {
         self.0 as f32 / 100.0
     }
+
+    /// #### 한국어 </br>
+    /// 소리 재생기에 전달할 음량 배율을 가져옵니다. </br>
+    /// 사람의 귀는 소리의 크기를 로그 단위로 느끼기 때문에, 
+    /// 슬라이더 위치(`norm`)를 제곱하여 체감 음량이 고르게 변하도록 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Get the volume gain to pass to the sound player. </br>
+    /// Since human hearing perceives loudness roughly logarithmically, 
+    /// the slider position (`norm`) is squared so that the perceived loudness changes evenly. </br>
+    /// 
+    #[inline]
+    pub fn gain(&self) -> f32 {
+        let norm = self.norm();
+        norm * norm
+    }
 }
 
 
 
 
+/// #### 한국어 </br>
+/// 소리 에셋이 지원하는 파일 형식 목록 입니다. </br>
+/// - Wav: `RIFF` 청크와 `WAVE` 형식 식별자로 시작하는 파일 입니다. </br>
+/// - Ogg: `OggS` 페이지로 시작하는 Ogg Vorbis 파일 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a list of file formats supported by sound assets. </br>
+/// - Wav: A file that starts with a `RIFF` chunk and a `WAVE` format identifier. </br>
+/// - Ogg: An Ogg Vorbis file that starts with an `OggS` page. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundFormat {
+    Wav,
+    Ogg,
+}
+
+impl SoundFormat {
+    /// #### 한국어 </br>
+    /// 바이트 배열의 머리말로 소리 파일 형식을 감지합니다. </br>
+    /// 지원하지 않는 형식인 경우 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Detects the sound file format from the header of the byte array. </br>
+    /// Returns `None` if the format is not supported. </br>
+    /// 
+    pub fn detect(buf: &[u8]) -> Option<Self> {
+        if buf.len() >= 12 && &buf[0..4] == b"RIFF" && &buf[8..12] == b"WAVE" {
+            return Some(Self::Wav);
+        }
+        if buf.starts_with(b"OggS") {
+            return Some(Self::Ogg);
+        }
+        return None;
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 소리 에셋을 디코딩 하는 디코더 입니다. </br>
+/// 바이트 배열의 머리말로 형식을 감지하여 해당 형식의 디코더를 사용합니다. (상세: [`SoundFormat`]) </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a decoder that decodes sound assets. </br>
+/// Detects the format from the header of the byte array and uses the decoder of that format. (see also: [`SoundFormat`]) </br>
+/// 
 #[derive(Debug)]
 pub struct SoundDecoder;
 
@@ -80,21 +155,814 @@ impl AssetDecoder for SoundDecoder {
     type Output = rodio::Decoder<Cursor<Vec<u8>>>;
 
     fn decode(&self, buf: &[u8]) -> AppResult<Self::Output> {
+        let format = SoundFormat::detect(buf)
+            .ok_or_else(|| game_err!(
+                "Unsupported sound format",
+                "Sound assets must be WAV or OGG Vorbis files. (header:{:02X?})",
+                &buf[..buf.len().min(12)]
+            ))?;
+
         let cursor = Cursor::new(buf.to_vec());
-        rodio::Decoder::new(cursor)
-            .map_err(|err| game_err!(
-                "Sound decoding failed",
-                "Sound decoding failed for following reasons: {}",
-                err.to_string()
-            ))
+        let decoder = match format {
+            SoundFormat::Wav => rodio::Decoder::new_wav(cursor),
+            SoundFormat::Ogg => rodio::Decoder::new_vorbis(cursor),
+        };
+        decoder.map_err(|err| game_err!(
+            "Sound decoding failed",
+            "Sound decoding failed for following reasons: {} (format:{:?})",
+            err.to_string(),
+            format
+        ))
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 배경 음악의 반복 구간 입니다. </br>
+/// 도입부(`intro_sec`)는 한 번만 재생되고, 이후 `intro_sec`부터 `end_sec`까지의 구간이 반복됩니다. </br>
+/// `end_sec`이 없는 경우 곡의 끝까지 재생한 후 반복합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The loop section of the background music. </br>
+/// The intro (`intro_sec`) plays only once, then the section from `intro_sec` to `end_sec` repeats. </br>
+/// If there is no `end_sec`, it plays until the end of the track before repeating. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LoopSection {
+    pub intro_sec: f64,
+    pub end_sec: Option<f64>,
+}
+
+impl LoopSection {
+    /// #### 한국어 </br>
+    /// 곡 전체를 반복하는 구간 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// A section that repeats the whole track. </br>
+    /// 
+    pub const WHOLE: Self = Self { intro_sec: 0.0, end_sec: None };
+}
+
+
+/// #### 한국어 </br>
+/// 도입부와 반복 구간이 따로 있는 배경 음악의 목록입니다. </br>
+/// 목록에 없는 곡은 곡 전체를 반복합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A list of background music that has a separate intro and loop section. </br>
+/// Tracks not in the list repeat the whole track. </br>
+/// 
+const BGM_LOOP_SECTIONS: &[(&str, LoopSection)] = &[];
+
+
+/// #### 한국어 </br>
+/// 주어진 배경 음악의 반복 구간을 가져옵니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Gets the loop section of the given background music. </br>
+/// 
+pub fn bgm_loop_section(rel_path: &str) -> LoopSection {
+    return BGM_LOOP_SECTIONS.iter()
+        .find(|(path, _)| *path == rel_path)
+        .map_or(LoopSection::WHOLE, |(_, section)| *section);
+}
+
+
+/// #### 한국어 </br>
+/// 주어진 시간에 해당하는 샘플의 위치를 반환합니다. 항상 프레임의 시작 위치를 가리킵니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the position of the sample at the given time. It always points to the start of a frame. </br>
+/// 
+#[inline]
+fn sample_offset(sec: f64, sample_rate: u32, channels: u16) -> usize {
+    let frames = (sec.max(0.0) * sample_rate as f64).round() as usize;
+    return frames * channels as usize;
+}
+
+
+
+/// #### 한국어 </br>
+/// 도입부를 한 번 재생한 후 반복 구간을 끊김 없이 반복하는 소리 입니다. </br>
+/// 처음 재생하는 동안 샘플을 저장해 두었다가, 반복 구간의 끝에 도달하면 저장된 샘플을 재생합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A sound that plays the intro once and then seamlessly repeats the loop section. </br>
+/// It stores samples during the first playback, 
+/// and plays the stored samples once it reaches the end of the loop section. </br>
+/// 
+pub struct IntroLoop<S> 
+where 
+    S: Source,
+    S::Item: Sample,
+{
+    source: Option<S>,
+    buffer: Vec<S::Item>,
+    pos: usize,
+    loop_start: usize,
+    loop_end: Option<usize>,
+    channels: u16,
+    sample_rate: u32,
+}
+
+impl<S> IntroLoop<S> 
+where 
+    S: Source,
+    S::Item: Sample,
+{
+    pub fn new(source: S, section: LoopSection) -> Self {
+        let channels = source.channels();
+        let sample_rate = source.sample_rate();
+        Self {
+            source: Some(source),
+            buffer: Vec::new(),
+            pos: 0,
+            loop_start: sample_offset(section.intro_sec, sample_rate, channels),
+            loop_end: section.end_sec.map(|sec| sample_offset(sec, sample_rate, channels)),
+            channels,
+            sample_rate,
+        }
+    }
+}
+
+impl<S> Iterator for IntroLoop<S> 
+where 
+    S: Source,
+    S::Item: Sample,
+{
+    type Item = S::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // (한국어) 처음 재생하는 동안에는 원본 소리의 샘플을 저장하면서 재생합니다.
+        // (English Translation) During the first playback, plays the samples of the original sound while storing them.
+        if let Some(source) = self.source.as_mut() {
+            let before_end = self.loop_end.map_or(true, |end| self.buffer.len() < end);
+            if let Some(sample) = source.next().filter(|_| before_end) {
+                self.buffer.push(sample);
+                self.pos = self.buffer.len();
+                return Some(sample);
+            }
+
+            self.source = None;
+            if self.loop_start >= self.buffer.len() {
+                self.loop_start = 0;
+            }
+            self.pos = self.loop_start;
+        }
+
+        // (한국어) 반복 구간의 끝에 도달하면 반복 구간의 시작으로 돌아갑니다.
+        // (English Translation) When the end of the loop section is reached, returns to the start of the loop section.
+        if self.pos >= self.buffer.len() {
+            self.pos = self.loop_start;
+        }
+        let sample = *self.buffer.get(self.pos)?;
+        self.pos += 1;
+        return Some(sample);
+    }
+}
+
+impl<S> Source for IntroLoop<S> 
+where 
+    S: Source,
+    S::Item: Sample,
+{
+    #[inline]
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    #[inline]
+    fn channels(&self) -> u16 {
+        self.channels
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    #[inline]
+    fn total_duration(&self) -> Option<std::time::Duration> {
+        None
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 월드 좌표상의 x 위치를 화면의 왼쪽 끝과 오른쪽 끝을 기준으로 스테레오 팬 값으로 변환합니다. </br>
+/// 왼쪽 끝은 `-1.0`, 가운데는 `0.0`, 오른쪽 끝은 `1.0`이며, 화면 밖의 위치는 `[-1.0, 1.0]` 범위로 제한됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Converts an x position in world coordinates to a stereo pan value relative to the left and right edges of the screen. </br>
+/// The left edge is `-1.0`, the center is `0.0` and the right edge is `1.0`,
+/// and positions outside the screen are clamped to the range `[-1.0, 1.0]`. </br>
+/// 
+#[inline]
+pub fn stereo_pan(x: f32, left: f32, right: f32) -> f32 {
+    let half_width = 0.5 * (right - left);
+    if half_width.abs() <= f32::EPSILON {
+        return 0.0;
+    }
+    let center = 0.5 * (left + right);
+    return ((x - center) / half_width).clamp(-1.0, 1.0);
+}
+
+
+/// #### 한국어 </br>
+/// 월드 좌표상의 x 위치를 카메라가 보고 있는 영역을 기준으로 스테레오 팬 값으로 변환합니다. </br>
+/// 직교 투영이 아닌 카메라의 경우 `0.0`을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Converts an x position in world coordinates to a stereo pan value relative to the area the camera is looking at. </br>
+/// Returns `0.0` for cameras without an orthographic projection. </br>
+/// 
+pub fn camera_pan(camera: &GameCamera, x: f32) -> f32 {
+    let data = camera.data.lock().expect("Failed to access variable.");
+    return match data.projection {
+        Projection::Orthographic(ortho) => {
+            let offset = data.transform.get_position().x;
+            stereo_pan(x, offset + ortho.left, offset + ortho.right)
+        },
+        Projection::Perspective(_) => 0.0,
+    };
+}
+
+
+/// #### 한국어 </br>
+/// 주어진 팬 값에 따라 왼쪽과 오른쪽 채널의 크기를 조절하는 소리 입니다. </br>
+/// 가운데의 소리는 원래 크기로 재생되며, 모노 소리는 스테레오 소리로 재생됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A sound that adjusts the volume of the left and right channels according to the given pan value. </br>
+/// Sounds at the center play at their original volume, and mono sounds are played as stereo sounds. </br>
+/// 
+pub struct Panned<S> 
+where 
+    S: Source,
+    S::Item: Sample,
+{
+    source: S,
+    gains: (f32, f32),
+    channel: u16,
+    pending: Option<S::Item>,
+}
+
+impl<S> Panned<S> 
+where 
+    S: Source,
+    S::Item: Sample,
+{
+    pub fn new(source: S, pan: f32) -> Self {
+        Self { source, gains: pan_gains(pan), channel: 0, pending: None }
+    }
+}
+
+impl<S> Iterator for Panned<S> 
+where 
+    S: Source,
+    S::Item: Sample,
+{
+    type Item = S::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // (한국어) 모노 소리의 경우 같은 샘플을 오른쪽 채널에 한 번 더 재생합니다.
+        // (English Translation) For mono sounds, plays the same sample once more on the right channel.
+        if let Some(sample) = self.pending.take() {
+            return Some(sample.amplify(self.gains.1));
+        }
+
+        let sample = self.source.next()?;
+        let channels = self.source.channels();
+        if channels == 1 {
+            self.pending = Some(sample);
+            return Some(sample.amplify(self.gains.0));
+        }
+
+        let channel = self.channel;
+        self.channel = (self.channel + 1) % channels;
+        return Some(match channel {
+            0 => sample.amplify(self.gains.0),
+            1 => sample.amplify(self.gains.1),
+            _ => sample,
+        });
+    }
+}
+
+impl<S> Source for Panned<S> 
+where 
+    S: Source,
+    S::Item: Sample,
+{
+    #[inline]
+    fn current_frame_len(&self) -> Option<usize> {
+        match self.source.channels() {
+            1 => self.source.current_frame_len().map(|len| len * 2),
+            _ => self.source.current_frame_len(),
+        }
+    }
+
+    #[inline]
+    fn channels(&self) -> u16 {
+        self.source.channels().max(2)
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> u32 {
+        self.source.sample_rate()
+    }
+
+    #[inline]
+    fn total_duration(&self) -> Option<std::time::Duration> {
+        self.source.total_duration()
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 팬 값에 따른 왼쪽과 오른쪽 채널의 크기를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the volume of the left and right channels for the pan value. </br>
+/// 
+#[inline]
+fn pan_gains(pan: f32) -> (f32, f32) {
+    let pan = pan.clamp(-1.0, 1.0);
+    return ((1.0 - pan).min(1.0), (1.0 + pan).min(1.0));
+}
+
+
+
+/// #### 한국어 </br>
+/// 오디오 출력 장치를 다시 찾기 전에 기다리는 시간 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The time to wait before looking for an audio output device again. </br>
+/// 
+const AUDIO_RETRY_SEC: f64 = 5.0;
+
+
+
+/// #### 한국어 </br>
+/// 오디오 출력 장치가 없는 상태를 나타냅니다. </br>
+/// 이 상태가 공유 객체에 등록되어 있는 동안 게임은 소리 없이 실행됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Indicates that there is no audio output device. </br>
+/// While this state is registered in the shared object, the game runs silently. </br>
+/// 
+#[derive(Debug, Default)]
+pub struct NoAudioDevice {
+    retry_timer: f64,
+}
+
+
+
+/// #### 한국어 </br>
+/// 기본 오디오 출력 장치를 엽니다. 장치가 없는 경우 `None`을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Opens the default audio output device. Returns `None` if there is no device. </br>
+/// 
+pub fn open_output_stream() -> Option<(OutputStream, OutputStreamHandle)> {
+    match OutputStream::try_default() {
+        Ok(it) => Some(it),
+        Err(err) => {
+            log::warn!("No audio output device. The game runs silently. ({})", err);
+            None
+        }
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 사용할 수 있는 오디오 출력 장치의 핸들을 가져옵니다. </br>
+/// 오디오 출력 장치가 없는 경우 `None`을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Gets the handle of the available audio output device. </br>
+/// Returns `None` if there is no audio output device. </br>
+/// 
+#[inline]
+pub fn output(shared: &Shared) -> Option<&OutputStreamHandle> {
+    if shared.get::<NoAudioDevice>().is_some() {
+        return None;
+    }
+    return shared.get::<OutputStreamHandle>();
+}
+
+
+/// #### 한국어 </br>
+/// 오디오 출력 장치가 없는 경우, 일정 시간마다 장치가 새로 연결되었는지 확인합니다. </br>
+/// 장치를 찾으면 이후에 재생되는 소리부터 다시 들리게 됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// If there is no audio output device, checks at intervals whether a new device has been connected. </br>
+/// Once a device is found, sounds played from then on become audible again. </br>
+/// 
+pub fn poll_output_device(shared: &mut Shared, elapsed_time: f64) {
+    let no_device = match shared.get_mut::<NoAudioDevice>() {
+        Some(no_device) => no_device,
+        None => return,
+    };
+
+    no_device.retry_timer += elapsed_time;
+    if no_device.retry_timer < AUDIO_RETRY_SEC {
+        return;
+    }
+    no_device.retry_timer = 0.0;
+
+    if let Ok((stream, handle)) = OutputStream::try_default() {
+        log::info!("An audio output device has been found.");
+        shared.pop::<NoAudioDevice>();
+        shared.push(stream);
+        shared.push(handle);
     }
 }
 
 
 
+/// #### 한국어 </br>
+/// 오디오 출력 장치가 없을 때 아무것도 재생하지 않는 [`rodio::Sink`] 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A [`rodio::Sink`] that plays nothing when there is no audio output device. </br>
+/// 
+#[derive(Default)]
+pub struct SoundSink(Option<Sink>);
+
+impl SoundSink {
+    #[inline]
+    pub fn append<S>(&self, source: S)
+    where 
+        S: Source + Send + 'static,
+        f32: FromSample<S::Item>,
+        S::Item: Sample + Send,
+    {
+        if let Some(sink) = &self.0 {
+            sink.append(source);
+        }
+    }
+
+    #[inline]
+    pub fn set_volume(&self, value: f32) {
+        if let Some(sink) = &self.0 {
+            sink.set_volume(value);
+        }
+    }
+
+    #[inline]
+    pub fn stop(&self) {
+        if let Some(sink) = &self.0 {
+            sink.stop();
+        }
+    }
+
+    #[inline]
+    pub fn pause(&self) {
+        if let Some(sink) = &self.0 {
+            sink.pause();
+        }
+    }
+
+    #[inline]
+    pub fn play(&self) {
+        if let Some(sink) = &self.0 {
+            sink.play();
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 일시정지 된 경우 `true`를 반환합니다. 오디오 출력 장치가 없는 경우 항상 `false`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if paused. Always returns `false` if there is no audio output device. </br>
+    /// 
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.0.as_ref().is_some_and(|sink| sink.is_paused())
+    }
+
+    /// #### 한국어 </br>
+    /// 재생할 소리가 남아있지 않은 경우 `true`를 반환합니다. </br>
+    /// 오디오 출력 장치가 없는 경우 항상 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if there are no sounds left to play. </br>
+    /// Always returns `true` if there is no audio output device. </br>
+    /// 
+    #[inline]
+    pub fn empty(&self) -> bool {
+        self.0.as_ref().map_or(true, |sink| sink.empty())
+    }
+
+    /// #### 한국어 </br>
+    /// 재생하던 소리가 모두 끝난 경우 `true`를 반환합니다. </br>
+    /// `empty`와 달리 오디오 출력 장치가 없는 경우 항상 `false`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if all the sounds being played have finished. </br>
+    /// Unlike `empty`, always returns `false` if there is no audio output device. </br>
+    /// 
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.0.as_ref().is_some_and(|sink| sink.empty())
+    }
+
+    #[inline]
+    pub fn sleep_until_end(&self) {
+        if let Some(sink) = &self.0 {
+            sink.sleep_until_end();
+        }
+    }
+
+    #[inline]
+    pub fn detach(self) {
+        if let Some(sink) = self.0 {
+            sink.detach();
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 배경 음악이 바뀔 때 두 곡이 겹쳐지는 시간 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The time during which two tracks overlap when the background music changes. </br>
+/// 
+pub const BGM_CROSSFADE_SEC: f64 = 1.0;
+
+
+/// #### 한국어 </br>
+/// 크로스페이드 진행도에 따른 이전 곡과 새 곡의 음량 배율을 반환합니다. </br>
+/// 두 배율의 제곱의 합이 항상 `1`이 되어, 전환하는 동안 체감 음량이 일정하게 유지됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the gains of the old and new tracks according to the crossfade progress. </br>
+/// The sum of the squares of the two gains is always `1`, 
+/// so the perceived loudness stays constant during the transition. </br>
+/// 
 #[inline]
-pub fn create_sink(stream: &OutputStreamHandle) -> AppResult<Sink> {
+fn crossfade_gains(t: f64) -> (f32, f32) {
+    let angle = t.clamp(0.0, 1.0) * std::f64::consts::FRAC_PI_2;
+    return (angle.cos() as f32, angle.sin() as f32);
+}
+
+
+
+/// #### 한국어 </br>
+/// 일시정지 하고 다시 재생할 수 있는 소리 재생기의 인터페이스 입니다. </br>
+/// [`rodio::Sink`]는 일시정지 된 위치를 기억하므로, 다시 재생하면 멈춘 위치부터 이어서 재생합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is an interface of a sound player that can be paused and resumed. </br>
+/// [`rodio::Sink`] remembers the paused position, so resuming continues from where it stopped. </br>
+/// 
+pub trait Playback {
+    fn pause(&self);
+    fn play(&self);
+    fn is_paused(&self) -> bool;
+}
+
+impl Playback for SoundSink {
+    #[inline]
+    fn pause(&self) {
+        SoundSink::pause(self)
+    }
+
+    #[inline]
+    fn play(&self) {
+        SoundSink::play(self)
+    }
+
+    #[inline]
+    fn is_paused(&self) -> bool {
+        SoundSink::is_paused(self)
+    }
+}
+
+/// #### 한국어 </br>
+/// 주어진 소리 재생기들을 일시정지 하거나 다시 재생합니다. 이미 같은 상태인 재생기는 건너뜁니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Pauses or resumes the given sound players. Players already in the same state are skipped. </br>
+/// 
+pub fn set_paused<'a, P, I>(sinks: I, paused: bool)
+where P: Playback + 'a, I: IntoIterator<Item = &'a P> {
+    for sink in sinks.into_iter().filter(|sink| sink.is_paused() != paused) {
+        match paused {
+            true => sink.pause(),
+            false => sink.play(),
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 게임 장면이 바뀌어도 유지되는 배경 음악 재생기 입니다. </br>
+/// 새 곡을 재생할 때 이전 곡을 서서히 줄이면서 새 곡을 서서히 키웁니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A background music player that persists across game scenes. </br>
+/// When playing a new track, the old track fades out while the new track fades in. </br>
+/// 
+pub struct BgmPlayer {
+    current: Option<(&'static str, SoundSink)>,
+    fading: Option<SoundSink>,
+    volume: f32,
+    timer: f64,
+    duration: f64,
+}
+
+impl BgmPlayer {
+    #[inline]
+    pub fn new(volume: Volume) -> Self {
+        Self { 
+            current: None, 
+            fading: None, 
+            volume: volume.gain(), 
+            timer: 0.0, 
+            duration: 0.0, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 시간 동안 현재 곡에서 주어진 곡으로 전환합니다. </br>
+    /// 이미 같은 곡을 재생하고 있는 경우 아무것도 하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Switches from the current track to the given track over the given time. </br>
+    /// Does nothing if the same track is already playing. </br>
+    /// 
+    pub fn crossfade_to(
+        &mut self, 
+        rel_path: &'static str, 
+        duration: f64, 
+        asset_bundle: &AssetBundle, 
+        stream: Option<&OutputStreamHandle>
+    ) -> AppResult<()> {
+        if self.current.as_ref().is_some_and(|(path, _)| *path == rel_path) {
+            return Ok(());
+        }
+
+        let source = asset_bundle.get(rel_path)?
+            .read(&SoundDecoder)?
+            .amplify(0.5);
+        asset_bundle.release(rel_path);
+        let sink = create_sink(stream)?;
+        sink.append(IntroLoop::new(source, bgm_loop_section(rel_path)));
+        self.switch_to(rel_path, sink, duration);
+        return Ok(());
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 시간 동안 현재 곡에서 주어진 곡으로 전환하고, 주어진 곡을 반복하지 않고 한 번만 재생합니다. </br>
+    /// 곡이 끝났는지는 `is_finished`로 확인할 수 있습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Switches from the current track to the given track over the given time, and plays the given track only once without looping. </br>
+    /// Whether the track has finished can be checked with `is_finished`. </br>
+    /// 
+    pub fn crossfade_to_once(
+        &mut self, 
+        rel_path: &'static str, 
+        duration: f64, 
+        asset_bundle: &AssetBundle, 
+        stream: Option<&OutputStreamHandle>
+    ) -> AppResult<()> {
+        let source = asset_bundle.get(rel_path)?
+            .read(&SoundDecoder)?
+            .amplify(0.5);
+        asset_bundle.release(rel_path);
+        let sink = create_sink(stream)?;
+        sink.append(source);
+        self.switch_to(rel_path, sink, duration);
+        return Ok(());
+    }
+
+    /// #### 한국어 </br>
+    /// 재생 중인 곡이 끝난 경우 `true`를 반환합니다. </br>
+    /// 반복 재생하는 곡이나 오디오 출력 장치가 없는 경우 항상 `false`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if the playing track has finished. </br>
+    /// Always returns `false` for a looping track or if there is no audio output device. </br>
+    /// 
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.current.as_ref().is_some_and(|(_, sink)| sink.is_finished())
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 재생기를 현재 곡으로 설정하고, 이전 곡을 사라지는 곡으로 설정하여 크로스페이드를 시작합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the given sink as the current track, sets the previous track as the fading track and starts the crossfade. </br>
+    /// 
+    fn switch_to(&mut self, rel_path: &'static str, sink: SoundSink, duration: f64) {
+        sink.set_volume(0.0);
+
+        // (한국어) 아직 사라지는 중인 곡이 있다면 바로 멈춥니다.
+        // (English Translation) If a track is still fading out, stops it right away.
+        if let Some(old) = self.fading.take() {
+            old.stop();
+        }
+        self.fading = self.current.replace((rel_path, sink)).map(|(_, sink)| sink);
+        self.timer = 0.0;
+        self.duration = duration.max(0.0);
+        self.update(0.0);
+    }
+
+    /// #### 한국어 </br>
+    /// 배경 음악의 음량 배율을 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the volume gain of the background music. </br>
+    /// 
+    #[inline]
+    pub fn set_volume(&mut self, gain: f32) {
+        self.volume = gain;
+        self.update(0.0);
+    }
+
+    /// #### 한국어 </br>
+    /// 재생 중인 곡과 사라지는 중인 곡을 멈춥니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Stops the playing track and the track that is fading out. </br>
+    /// 
+    pub fn stop(&mut self) {
+        if let Some((_, sink)) = self.current.take() {
+            sink.stop();
+        }
+        if let Some(sink) = self.fading.take() {
+            sink.stop();
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 재생 중인 곡과 사라지는 중인 곡을 일시정지 하거나 다시 재생합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Pauses or resumes the playing track and the track that is fading out. </br>
+    /// 
+    pub fn set_paused(&self, paused: bool) {
+        let sinks = self.current.iter().map(|(_, sink)| sink).chain(self.fading.iter());
+        set_paused(sinks, paused);
+    }
+
+    /// #### 한국어 </br>
+    /// 크로스페이드를 진행합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances the crossfade. </br>
+    /// 
+    pub fn update(&mut self, elapsed_time: f64) {
+        self.timer += elapsed_time;
+        let t = match self.duration > 0.0 {
+            true => self.timer / self.duration,
+            false => 1.0,
+        };
+        
+        let (fade_out, fade_in) = crossfade_gains(t);
+        if let Some((_, sink)) = &self.current {
+            sink.set_volume(self.volume * fade_in);
+        }
+        if let Some(sink) = &self.fading {
+            sink.set_volume(self.volume * fade_out);
+        }
+
+        if t >= 1.0 {
+            if let Some(old) = self.fading.take() {
+                old.stop();
+            }
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 새로운 소리 재생기를 생성합니다. </br>
+/// 오디오 출력 장치가 없는 경우 아무것도 재생하지 않는 재생기를 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a new sound player. </br>
+/// If there is no audio output device, creates a player that plays nothing. </br>
+/// 
+#[inline]
+pub fn create_sink(stream: Option<&OutputStreamHandle>) -> AppResult<SoundSink> {
+    let stream = match stream {
+        Some(stream) => stream,
+        None => return Ok(SoundSink::default()),
+    };
+
     Sink::try_new(stream)
+        .map(|sink| SoundSink(Some(sink)))
         .map_err(|err| game_err!(
             "Sound player creation failed",
             "Sound player creation failed for following reasons: {}",
@@ -114,15 +982,15 @@ pub fn create_sink(stream: &OutputStreamHandle) -> AppResult<Sink> {
 pub fn play_sound<S>(
     volume: Volume,
     source: S,
-    stream: &OutputStreamHandle
-) -> AppResult<Sink> 
+    stream: Option<&OutputStreamHandle>
+) -> AppResult<SoundSink> 
 where 
     S: Source + Send + 'static,
     f32: FromSample<S::Item>,
     S::Item: Sample + Send,
 {
     let sink = create_sink(stream)?;
-    sink.set_volume(volume.norm());
+    sink.set_volume(volume.gain());
     sink.append(source);
     return Ok(sink);
 }
@@ -140,9 +1008,15 @@ pub fn play_click_sound(shared: &Shared) -> AppResult<()> {
     use std::thread;
     use crate::nodes::path;
 
+    // (한국어) 오디오 출력 장치가 없는 경우 아무것도 하지 않습니다.
+    // (English Translation) Does nothing if there is no audio output device.
+    let stream = match output(shared) {
+        Some(stream) => stream,
+        None => return Ok(()),
+    };
+
     // (한국어) 사용할 공유 객체 가져오기.
     // (English Translation) Get shared object to use. 
-    let stream = shared.get::<OutputStreamHandle>().unwrap();
     let asset_bundle = shared.get::<AssetBundle>().unwrap();
     let settings = shared.get::<Settings>().unwrap();
 
@@ -150,10 +1024,11 @@ pub fn play_click_sound(shared: &Shared) -> AppResult<()> {
     // (English Translation) Load and play the click sound. 
     let source = asset_bundle.get(path::CLICK_SOUND_PATH)?
         .read(&SoundDecoder)?;
+    asset_bundle.release(path::CLICK_SOUND_PATH);
     let sink = play_sound(
         settings.effect_volume, 
         source, 
-        stream
+        Some(stream)
     )?;
 
     // (한국어) 새로운 스레드에서 재생이 끝날 때까지 기다립니다.
@@ -179,9 +1054,15 @@ pub fn play_cancel_sound(shared: &Shared) -> AppResult<()> {
     use std::thread;
     use crate::nodes::path;
 
+    // (한국어) 오디오 출력 장치가 없는 경우 아무것도 하지 않습니다.
+    // (English Translation) Does nothing if there is no audio output device.
+    let stream = match output(shared) {
+        Some(stream) => stream,
+        None => return Ok(()),
+    };
+
     // (한국어) 사용할 공유 객체 가져오기.
     // (English Translation) Get shared object to use. 
-    let stream = shared.get::<OutputStreamHandle>().unwrap();
     let asset_bundle = shared.get::<AssetBundle>().unwrap();
     let settings = shared.get::<Settings>().unwrap();
 
@@ -189,10 +1070,11 @@ pub fn play_cancel_sound(shared: &Shared) -> AppResult<()> {
     // (English Translation) Load and play the click sound. 
     let source = asset_bundle.get(path::CANCEL_SOUND_PATH)?
         .read(&SoundDecoder)?;
+    asset_bundle.release(path::CANCEL_SOUND_PATH);
     let sink = play_sound(
         settings.effect_volume, 
         source, 
-        stream
+        Some(stream)
     )?;
 
     // (한국어) 새로운 스레드에서 재생이 끝날 때까지 기다립니다.
@@ -204,3 +1086,192 @@ pub fn play_cancel_sound(shared: &Shared) -> AppResult<()> {
 
     Ok(())
 }
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::cell::Cell;
+
+    /// #### 한국어 </br>
+    /// 재생 중일 때만 재생 위치가 앞으로 가는 가짜 소리 재생기 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// A fake sound player whose playback position only advances while playing. </br>
+    /// 
+    #[derive(Default)]
+    struct MockSink {
+        paused: Cell<bool>,
+        position: Cell<u32>,
+    }
+
+    impl MockSink {
+        fn advance(&self) {
+            if !self.paused.get() {
+                self.position.set(self.position.get() + 1);
+            }
+        }
+    }
+
+    impl Playback for MockSink {
+        fn pause(&self) {
+            self.paused.set(true);
+        }
+
+        fn play(&self) {
+            self.paused.set(false);
+        }
+
+        fn is_paused(&self) -> bool {
+            self.paused.get()
+        }
+    }
+
+    #[test]
+    fn pause_then_resume_keeps_playing_from_same_position() {
+        let background = MockSink::default();
+        let voice = MockSink::default();
+        for _ in 0..3 {
+            background.advance();
+        }
+        voice.advance();
+
+        set_paused([&background, &voice], true);
+        assert!(background.is_paused() && voice.is_paused());
+        background.advance();
+        voice.advance();
+        assert_eq!((background.position.get(), voice.position.get()), (3, 1));
+
+        set_paused([&background, &voice], false);
+        assert!(!background.is_paused() && !voice.is_paused());
+        assert_eq!((background.position.get(), voice.position.get()), (3, 1));
+        background.advance();
+        assert_eq!(background.position.get(), 4);
+
+        // (한국어) 오디오 출력 장치가 없는 재생기는 일시정지 되지 않습니다.
+        // (English Translation) A player without an audio output device is never paused.
+        let silent = SoundSink::default();
+        set_paused([&silent], true);
+        assert!(!Playback::is_paused(&silent));
+    }
+
+    #[test]
+    fn gain_follows_a_perceptual_curve() {
+        assert_eq!(Volume::new(0).gain(), 0.0);
+        assert_eq!(Volume::new(100).gain(), 1.0);
+        assert!(Volume::new(50).gain() < Volume::new(50).norm());
+        for val in 0..100 {
+            assert!(Volume::new(val).gain() < Volume::new(val + 1).gain());
+        }
+    }
+
+    #[test]
+    fn loop_points_are_frame_aligned() {
+        assert_eq!(sample_offset(1.5, 44100, 2), 132300);
+        assert_eq!(sample_offset(0.0, 48000, 2), 0);
+        assert_eq!(sample_offset(2.0, 22050, 1), 44100);
+        assert_eq!(sample_offset(1.0 / 3.0, 44100, 2) % 2, 0);
+    }
+
+    #[test]
+    fn intro_plays_once_before_the_loop() {
+        use rodio::buffer::SamplesBuffer;
+
+        // (한국어) 초당 2개의 프레임으로, 1초의 도입부 후 [1초, 2초) 구간을 반복합니다.
+        // (English Translation) At 2 frames per second, repeats [1s, 2s) after a 1 second intro.
+        let source = SamplesBuffer::new(1, 2, vec![0i16, 1, 2, 3, 4, 5]);
+        let section = LoopSection { intro_sec: 1.0, end_sec: Some(2.0) };
+        let samples: Vec<i16> = IntroLoop::new(source, section).take(8).collect();
+        assert_eq!(samples, vec![0, 1, 2, 3, 2, 3, 2, 3]);
+    }
+
+    #[test]
+    fn crossfade_keeps_constant_power() {
+        assert_eq!(crossfade_gains(0.0), (1.0, 0.0));
+        let (fade_out, fade_in) = crossfade_gains(1.0);
+        assert!(fade_out.abs() < 1e-6 && (fade_in - 1.0).abs() < 1e-6);
+
+        let (fade_out, fade_in) = crossfade_gains(0.5);
+        assert!((fade_out - fade_in).abs() < 1e-6);
+        assert!((fade_out * fade_out + fade_in * fade_in - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn world_position_maps_to_stereo_pan() {
+        let (left, right) = (-40.0, 40.0);
+        assert_eq!(stereo_pan(-40.0, left, right), -1.0);
+        assert_eq!(stereo_pan(0.0, left, right), 0.0);
+        assert_eq!(stereo_pan(40.0, left, right), 1.0);
+        assert_eq!(stereo_pan(20.0, left, right), 0.5);
+        assert_eq!(stereo_pan(-100.0, left, right), -1.0);
+        assert_eq!(stereo_pan(100.0, left, right), 1.0);
+        assert_eq!(stereo_pan(15.0, 10.0, 20.0), 0.0);
+    }
+
+    #[test]
+    fn mono_sound_is_panned_to_stereo() {
+        use rodio::buffer::SamplesBuffer;
+
+        let source = SamplesBuffer::new(1, 2, vec![1.0f32, 0.5]);
+        let panned = Panned::new(source, 0.5);
+        assert_eq!(panned.channels(), 2);
+        let samples: Vec<f32> = panned.collect();
+        assert_eq!(samples, vec![0.5, 1.0, 0.25, 0.5]);
+
+        let source = SamplesBuffer::new(2, 2, vec![1.0f32, 1.0]);
+        let samples: Vec<f32> = Panned::new(source, 0.0).collect();
+        assert_eq!(samples, vec![1.0, 1.0]);
+    }
+
+    #[test]
+    fn click_sound_is_muted_without_audio_device() {
+        // (한국어) 에셋 번들과 설정이 없어도 소리를 재생하지 않고 바로 반환해야 합니다.
+        // (English Translation) Must return right away without playing, even without an asset bundle or settings.
+        let mut shared = Shared::new();
+        shared.push(NoAudioDevice::default());
+        assert!(output(&shared).is_none());
+        assert!(play_click_sound(&shared).is_ok());
+        assert!(play_cancel_sound(&shared).is_ok());
+    }
+    #[test]
+    fn sound_format_is_detected_from_header() {
+        // (한국어) 16비트 모노 8000Hz 샘플 두 개를 담은 WAV 파일 입니다.
+        // (English Translation) A WAV file containing two 16-bit mono 8000Hz samples.
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&40u32.to_le_bytes());
+        wav.extend_from_slice(b"WAVEfmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes());
+        wav.extend_from_slice(&8000u32.to_le_bytes());
+        wav.extend_from_slice(&16000u32.to_le_bytes());
+        wav.extend_from_slice(&2u16.to_le_bytes());
+        wav.extend_from_slice(&16u16.to_le_bytes());
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&4u32.to_le_bytes());
+        wav.extend_from_slice(&[0x00, 0x40, 0x00, 0xC0]);
+        assert_eq!(SoundFormat::detect(&wav), Some(SoundFormat::Wav));
+
+        let decoder = SoundDecoder.decode(&wav).unwrap();
+        assert_eq!(decoder.channels(), 1);
+        assert_eq!(decoder.sample_rate(), 8000);
+        assert_eq!(decoder.collect::<Vec<i16>>(), vec![0x4000, -0x4000]);
+
+        // (한국어) Vorbis 식별 헤더를 시작하는 Ogg 페이지 머리말 입니다.
+        // (English Translation) The header of an Ogg page that begins a Vorbis identification header.
+        let mut ogg = Vec::new();
+        ogg.extend_from_slice(b"OggS");
+        ogg.extend_from_slice(&[0x00, 0x02]);
+        ogg.extend_from_slice(&[0x00; 20]);
+        ogg.extend_from_slice(&[0x01, 0x1E]);
+        ogg.extend_from_slice(b"\x01vorbis");
+        assert_eq!(SoundFormat::detect(&ogg), Some(SoundFormat::Ogg));
+        let err = SoundDecoder.decode(&ogg).err().unwrap();
+        assert!(err.to_string().contains("format:Ogg"), "{}", err.to_string());
+
+        let err = SoundDecoder.decode(b"ID3\x04\x00\x00\x00\x00\x00\x00").err().unwrap();
+        assert!(err.to_string().starts_with("<Unsupported sound format>"));
+        assert_eq!(SoundFormat::detect(b"RIFF"), None);
+    }
+}
@@ -12,7 +12,7 @@ use bytemuck::{Pod, Zeroable, offset_of};
 
 use crate::{
     assets::bundle::AssetBundle,
-    render::shader::WgslDecoder,
+    render::{shader::WgslDecoder, tracker::Tracked},
     system::error::AppResult,
 };
 
@@ -29,6 +29,7 @@ use crate::{
 struct Data {
     transform: Mat4,
     color: Vec4,
+    texcoord: Vec4,
     size: Vec2,
     texture_index: u32,
 }
@@ -39,12 +40,21 @@ impl Default for Data {
         Self { 
             transform: Mat4::IDENTITY, 
             color: Vec4 { x: 1.0, y: 1.0, z: 1.0, w: 1.0 }, 
+            texcoord: TEXCOORD_RECT, 
             size: Vec2 { x: 0.0, y: 0.0 }, 
             texture_index: 0, 
         }
     }
 }
 
+/// #### 한국어 </br>
+/// 스프라이트의 왼쪽 위 텍스처 좌표 `(x, y)`와 오른쪽 아래 텍스처 좌표 `(z, w)` 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The top-left texture coordinates `(x, y)` and bottom-right texture coordinates `(z, w)` of the sprite. </br>
+/// 
+const TEXCOORD_RECT: Vec4 = Vec4::new(0.0, 0.0, 1.0, 1.0);
+
 
 
 /// #### 한국어 </br>
@@ -61,6 +71,7 @@ pub struct Instance {
     pub color: Vec4, 
     pub size: Vec2, 
     pub texture_index: u32, 
+    pub flip_x: bool, 
 }
 
 impl Instance {
@@ -73,10 +84,29 @@ impl Instance {
                 self.translation
             ),
             color: self.color, 
+            texcoord: self.texcoord_rect(), 
             size: self.size, 
             texture_index: self.texture_index, 
         }
     }
+
+    /// #### 한국어 </br>
+    /// 스프라이트에 입힐 텍스처 좌표 영역을 반환합니다. </br>
+    /// 좌우 반전된 경우 왼쪽과 오른쪽 텍스처 좌표를 서로 바꿉니다. </br>
+    /// 정점의 위치는 바뀌지 않으므로 변환 행렬과 기준점에 영향을 주지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the texture coordinate area to apply to the sprite. </br>
+    /// If flipped horizontally, swaps the left and right texture coordinates. </br>
+    /// The vertex positions do not change, so it does not affect the transform matrix or the anchor. </br>
+    /// 
+    #[inline]
+    pub fn texcoord_rect(&self) -> Vec4 {
+        match self.flip_x {
+            true => Vec4::new(TEXCOORD_RECT.z, TEXCOORD_RECT.y, TEXCOORD_RECT.x, TEXCOORD_RECT.w), 
+            false => TEXCOORD_RECT, 
+        }
+    }
 }
 
 impl Default for Instance {
@@ -89,12 +119,78 @@ impl Default for Instance {
             color: Vec4 { x: 1.0, y: 1.0, z: 1.0, w: 1.0 }, 
             size: Vec2 { x: 0.0, y: 0.0 }, 
             texture_index: 0, 
+            flip_x: false, 
         }
     }
 }
 
 
 
+/// #### 한국어 </br>
+/// 텍스처 배열의 레이어를 프레임으로 사용하는 플립북 애니메이션 입니다. </br>
+/// 주어진 레이어 번호들을 초당 `fps`장의 속도로 순서대로 보여줍니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A flipbook animation that uses the layers of a texture array as frames. </br>
+/// Shows the given layer indices in order at a rate of `fps` frames per second. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub struct Flipbook {
+    frames: Vec<u32>, 
+    fps: f64, 
+    looping: bool, 
+    timer: f64, 
+}
+
+impl Flipbook {
+    pub fn new(frames: &[u32], fps: f64, looping: bool) -> Self {
+        debug_assert!(!frames.is_empty(), "A flipbook must have at least one frame!");
+        debug_assert!(fps > 0.0, "The frame rate must be greater than 0!");
+        Self { frames: frames.to_vec(), fps, looping, timer: 0.0 }
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 보여줄 텍스처 레이어 번호를 반환합니다. </br>
+    /// 반복하지 않는 애니메이션은 끝난 후 마지막 프레임에 머무릅니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the texture layer index to show now. </br>
+    /// An animation that does not loop stays on the last frame after it ends. </br>
+    /// 
+    pub fn frame(&self) -> u32 {
+        let num_frames = self.frames.len();
+        let idx = (self.timer * self.fps).floor() as usize;
+        let idx = match self.looping {
+            true => idx % num_frames, 
+            false => idx.min(num_frames - 1), 
+        };
+        return self.frames[idx];
+    }
+
+    /// #### 한국어 </br>
+    /// 반복하지 않는 애니메이션의 모든 프레임을 보여준 경우 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if an animation that does not loop has shown all of its frames. </br>
+    /// 
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        !self.looping && self.timer * self.fps >= self.frames.len() as f64
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 경과 시간만큼 애니메이션을 진행합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances the animation by the given elapsed time. </br>
+    /// 
+    #[inline]
+    pub fn advance(&mut self, elapsed_time: f64) {
+        self.timer += elapsed_time;
+    }
+}
+
+
 
 /// #### 한국어 </br>
 /// 조명에 영향을 받는 스프라이트 데이터 버퍼가 포함되어 있는 구조체 입니다. </br>
@@ -107,7 +203,9 @@ pub struct Sprite {
     buffer: wgpu::Buffer,
     bind_group: wgpu::BindGroup,
     pub instances: Mutex<Vec<Instance>>,
+    animation: Mutex<Option<Flipbook>>, 
     capacity: usize, 
+    _tracked: Tracked, 
 }
 
 impl Sprite {
@@ -164,6 +262,75 @@ impl Sprite {
             bind_group, 
             capacity: instances.len(), 
             instances: instances.into(), 
+            animation: None.into(), 
+            _tracked: Tracked::new(1, 1), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 재생 중인 애니메이션을 멈추고 모든 인스턴스의 텍스처 레이어를 주어진 번호로 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Stops the playing animation and sets the texture layer of all instances to the given index. </br>
+    /// 
+    pub fn set_frame(&self, queue: &wgpu::Queue, index: u32) {
+        self.animation.lock().expect("Failed to access variable.").take();
+        self.update(queue, |instances| {
+            for instance in instances.iter_mut() {
+                instance.texture_index = index;
+            }
+        });
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 텍스처 레이어 번호들을 초당 `fps`장의 속도로 보여주는 애니메이션을 재생합니다. </br>
+    /// 애니메이션은 `tick` 함수를 호출할 때마다 진행됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Plays an animation that shows the given texture layer indices at a rate of `fps` frames per second. </br>
+    /// The animation advances each time the `tick` function is called. </br>
+    /// 
+    #[allow(dead_code)]
+    pub fn play(&self, queue: &wgpu::Queue, frames: &[u32], fps: f64, looping: bool) {
+        let animation = Flipbook::new(frames, fps, looping);
+        let frame = animation.frame();
+        *self.animation.lock().expect("Failed to access variable.") = Some(animation);
+        self.update(queue, |instances| {
+            for instance in instances.iter_mut() {
+                instance.texture_index = frame;
+            }
+        });
+    }
+
+    /// #### 한국어 </br>
+    /// 재생 중인 애니메이션을 진행하고, 프레임이 바뀐 경우 인스턴스 데이터 버퍼를 갱신합니다. </br>
+    /// 반복하지 않는 애니메이션이 끝난 경우 마지막 프레임에서 멈춥니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances the playing animation, and updates the instance data buffer if the frame has changed. </br>
+    /// If an animation that does not loop has ended, it stops on the last frame. </br>
+    /// 
+    pub fn tick(&self, queue: &wgpu::Queue, elapsed_time: f64) {
+        let mut guard = self.animation.lock().expect("Failed to access variable.");
+        let animation = match guard.as_mut() {
+            Some(animation) => animation, 
+            None => return, 
+        };
+
+        let prev = animation.frame();
+        animation.advance(elapsed_time);
+        let frame = animation.frame();
+        if animation.is_finished() {
+            guard.take();
+        }
+        drop(guard);
+
+        if frame != prev {
+            self.update(queue, |instances| {
+                for instance in instances.iter_mut() {
+                    instance.texture_index = frame;
+                }
+            });
         }
     }
 
@@ -390,6 +557,11 @@ fn create_pipeline(
                                 format: wgpu::VertexFormat::Uint32,
                                 offset: offset_of!(Data, texture_index) as wgpu::BufferAddress,
                             },
+                            wgpu::VertexAttribute {
+                                shader_location: 7,
+                                format: wgpu::VertexFormat::Float32x4,
+                                offset: offset_of!(Data, texcoord) as wgpu::BufferAddress,
+                            },
                         ],
                     },
                 ],
@@ -419,3 +591,55 @@ fn create_pipeline(
         },
     )
 }
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn three_frame_loop_at_10fps() {
+        const FRAME_SEC: f64 = 1.0 / 10.0;
+
+        let mut animation = Flipbook::new(&[0, 1, 2], 10.0, true);
+        assert_eq!(animation.frame(), 0);
+        animation.advance(0.5 * FRAME_SEC);
+        assert_eq!(animation.frame(), 0);
+        animation.advance(FRAME_SEC);
+        assert_eq!(animation.frame(), 1);
+        animation.advance(FRAME_SEC);
+        assert_eq!(animation.frame(), 2);
+
+        // (한국어) 마지막 프레임 다음에는 처음 프레임으로 돌아갑니다.
+        // (English Translation) After the last frame, it returns to the first frame.
+        animation.advance(FRAME_SEC);
+        assert_eq!(animation.frame(), 0);
+        animation.advance(10.0 * FRAME_SEC);
+        assert_eq!(animation.frame(), 1);
+        assert!(!animation.is_finished());
+
+        // (한국어) 반복하지 않는 애니메이션은 마지막 프레임에 머무릅니다.
+        // (English Translation) An animation that does not loop stays on the last frame.
+        let mut animation = Flipbook::new(&[0, 1, 2], 10.0, false);
+        animation.advance(2.5 * FRAME_SEC);
+        assert_eq!(animation.frame(), 2);
+        assert!(!animation.is_finished());
+        animation.advance(FRAME_SEC);
+        assert_eq!(animation.frame(), 2);
+        assert!(animation.is_finished());
+    }
+    #[test]
+    fn flipping_twice_restores_texcoords() {
+        let mut instance = Instance::default();
+        let original = instance.to_data();
+        assert_eq!(original.texcoord, TEXCOORD_RECT);
+
+        instance.flip_x = !instance.flip_x;
+        let flipped = instance.to_data();
+        assert_eq!(flipped.texcoord, Vec4::new(1.0, 0.0, 0.0, 1.0));
+        assert_eq!(flipped.transform, original.transform);
+
+        instance.flip_x = !instance.flip_x;
+        assert_eq!(instance.to_data(), original);
+    }
+}
@@ -2,8 +2,9 @@ use std::mem::size_of;
 use std::sync::{Arc, Mutex, MutexGuard};
 use std::collections::VecDeque;
 
-use rand::seq::SliceRandom;
+use rand::{rngs::StdRng, seq::SliceRandom};
 use glam::{Mat4, Vec4, Vec3, Vec2};
+use serde::{Serialize, Deserialize};
 use bytemuck::{Pod, Zeroable, offset_of};
 
 use crate::{
@@ -12,7 +13,7 @@ use crate::{
         collider2d::shape::AABB, 
         transform::Transform, 
     },
-    render::shader::WgslDecoder,
+    render::{shader::WgslDecoder, tracker::Tracked},
     system::error::AppResult,
 };
 
@@ -34,6 +35,21 @@ pub struct InstanceData {
     pub texcoord_right: f32, 
     pub color: Vec4, 
     pub size: Vec2, 
+    pub pattern: u32, 
+}
+
+impl InstanceData {
+    /// #### 한국어 </br>
+    /// 주어진 타일의 색상과 무늬를 적용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Applies the color and pattern of the given tile. </br>
+    /// 
+    #[inline]
+    pub fn apply_tile(&mut self, tile: &Tile) {
+        self.color = tile.color;
+        self.pattern = tile.pattern as u32;
+    }
 }
 
 impl Default for InstanceData {
@@ -46,9 +62,221 @@ impl Default for InstanceData {
             texcoord_bottom: 1.0, 
             texcoord_right: 1.0, 
             color: Vec4 { x: 1.0, y: 1.0, z: 1.0, w: 1.0 }, 
-            size: Vec2 { x: 0.0, y: 0.0 } 
+            size: Vec2 { x: 0.0, y: 0.0 }, 
+            pattern: TilePattern::Solid as u32, 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 타일 위에 겹쳐 그리는 무늬 목록 입니다. </br>
+/// 색상뿐만 아니라 밝기가 다른 무늬로도 타일을 구분할 수 있게 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a list of patterns drawn over the tile. </br>
+/// It allows tiles to be told apart by patterns of different brightness, not only by color. </br>
+/// 
+#[repr(u32)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TilePattern {
+    #[default]
+    Solid = 0, 
+    Stripes = 1, 
+    CrossHatch = 2, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 타일의 색상과 무늬 설정 목록 입니다. </br>
+/// `HighContrast`는 색상을 구분하기 어려운 사용자를 위해 
+/// 밝기 차이가 큰 색상과 무늬를 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a list of tile color and pattern presets. </br>
+/// `HighContrast` uses colors with large brightness differences and patterns
+/// for users who have difficulty telling colors apart. </br>
+/// 
+#[derive(Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TableTheme {
+    #[default]
+    Standard, 
+    HighContrast, 
+}
+
+impl TableTheme {
+    /// #### 한국어 </br>
+    /// 소유한 영역의 경계 타일 색상을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the color of the edge tiles of the owned area. </br>
+    /// 
+    pub fn edge_color(self) -> Vec4 {
+        match self {
+            Self::Standard => Vec4::new(137.0 / 255.0, 207.0 / 255.0, 243.0 / 255.0, 1.0), 
+            Self::HighContrast => Vec4::new(10.0 / 255.0, 30.0 / 255.0, 110.0 / 255.0, 1.0), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 소유하지 않은 타일 색상을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the color of the tiles not owned. </br>
+    /// 
+    pub fn fill_color(self) -> Vec4 {
+        match self {
+            Self::Standard => Vec4::new(160.0 / 255.0, 233.0 / 255.0, 1.0, 1.0), 
+            Self::HighContrast => Vec4::new(240.0 / 255.0, 240.0 / 255.0, 240.0 / 255.0, 1.0), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 플레이어가 지나온 경로 타일 색상을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the color of the tiles on the path the player has passed. </br>
+    /// 
+    pub fn line_color(self) -> Vec4 {
+        match self {
+            Self::Standard => Vec4::new(1.0, 0.0, 0.0, 1.0), 
+            Self::HighContrast => Vec4::new(200.0 / 255.0, 20.0 / 255.0, 110.0 / 255.0, 1.0), 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 소유한 영역의 경계 타일 무늬를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the pattern of the edge tiles of the owned area. </br>
+    /// 
+    #[inline]
+    pub fn edge_pattern(self) -> TilePattern {
+        TilePattern::Solid
+    }
+
+    /// #### 한국어 </br>
+    /// 소유하지 않은 타일 무늬를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the pattern of the tiles not owned. </br>
+    /// 
+    pub fn fill_pattern(self) -> TilePattern {
+        match self {
+            Self::Standard => TilePattern::Solid, 
+            Self::HighContrast => TilePattern::Stripes, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 플레이어가 지나온 경로 타일 무늬를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the pattern of the tiles on the path the player has passed. </br>
+    /// 
+    pub fn line_pattern(self) -> TilePattern {
+        match self {
+            Self::Standard => TilePattern::Solid, 
+            Self::HighContrast => TilePattern::CrossHatch, 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 게임 보드의 크기 설정 목록 입니다. </br>
+/// 보드가 차지하는 화면 영역은 같으며, 보드가 작을수록 타일이 커집니다. </br>
+/// - 기본: 100x100 타일 입니다. </br>
+/// - 작음: 빠르게 플레이할 수 있는 50x50 타일 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a list of game board size settings. </br>
+/// The board occupies the same area of the screen, and the smaller the board, the larger the tiles. </br>
+/// - Standard: 100x100 tiles. </br>
+/// - Small: 50x50 tiles for quick play. </br>
+/// 
+#[derive(Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoardSize {
+    #[default]
+    Standard, 
+    Small, 
+}
+
+impl BoardSize {
+    /// #### 한국어 </br>
+    /// 보드의 행 수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the number of rows of the board. </br>
+    /// 
+    #[inline]
+    pub fn num_rows(self) -> usize {
+        use crate::nodes::in_game::NUM_TILE_ROWS;
+        match self {
+            Self::Standard => NUM_TILE_ROWS, 
+            Self::Small => NUM_TILE_ROWS / 2, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 보드의 열 수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the number of columns of the board. </br>
+    /// 
+    #[inline]
+    pub fn num_cols(self) -> usize {
+        use crate::nodes::in_game::NUM_TILE_COLS;
+        match self {
+            Self::Standard => NUM_TILE_COLS, 
+            Self::Small => NUM_TILE_COLS / 2, 
         }
     }
+
+    /// #### 한국어 </br>
+    /// 보드의 전체 타일 수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the total number of tiles of the board. </br>
+    /// 
+    #[inline]
+    pub fn num_tiles(self) -> usize {
+        self.num_rows() * self.num_cols()
+    }
+
+    /// #### 한국어 </br>
+    /// 이 크기의 보드에서 얻은 결과를 세이브 파일에 기록하는 경우 `true`를 반환합니다. </br>
+    /// 작은 보드는 빠르게 플레이하기 위한 것이므로 진행도와 최단 클리어 시간을 기록하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if the results on a board of this size are recorded in the save file. </br>
+    /// The small board is for quick play, so its progress and best clear time are not recorded. </br>
+    /// 
+    #[inline]
+    pub fn is_recorded(self) -> bool {
+        matches!(self, Self::Standard)
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 주어진 `sRGB` 색상의 상대 휘도를 반환합니다. (0.0 ~ 1.0) </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the relative luminance of the given `sRGB` color. (0.0 ~ 1.0) </br>
+/// 
+#[cfg(test)]
+pub fn luminance(color: Vec4) -> f32 {
+    let linear = |c: f32| match c <= 0.04045 {
+        true => c / 12.92,
+        false => ((c + 0.055) / 1.055).powf(2.4),
+    };
+    return 0.2126 * linear(color.x) + 0.7152 * linear(color.y) + 0.0722 * linear(color.z);
 }
 
 
@@ -64,6 +292,7 @@ pub struct TileBrush {
     pipeline: wgpu::RenderPipeline, 
     instance_buffer: wgpu::Buffer, 
     pub instances: Mutex<Vec<InstanceData>>, 
+    _tracked: Tracked, 
 }
 
 impl TileBrush {
@@ -95,7 +324,8 @@ impl TileBrush {
         Ok(Self { 
             pipeline, 
             instance_buffer, 
-            instances: instances.into() 
+            instances: instances.into(), 
+            _tracked: Tracked::new(1, 0), 
         }.into())
     }
 
@@ -240,6 +470,11 @@ fn create_pipeline(
                                 format: wgpu::VertexFormat::Float32x2, 
                                 offset: offset_of!(InstanceData, size) as wgpu::BufferAddress, 
                             }, 
+                            wgpu::VertexAttribute {
+                                shader_location: 10, 
+                                format: wgpu::VertexFormat::Uint32, 
+                                offset: offset_of!(InstanceData, pattern) as wgpu::BufferAddress, 
+                            }, 
                         ],
                     },
                 ],
@@ -302,8 +537,8 @@ fn create_instance_buffer(
 /// 
 #[derive(Debug)]
 pub struct Tile {
-    pub visited: bool, 
     pub color: Vec4, 
+    pub pattern: TilePattern, 
     pub transform: Transform, 
 }
 
@@ -325,6 +560,9 @@ pub struct Table {
     pub edge_color: Vec4, 
     pub fill_color: Vec4,
     pub line_color: Vec4,  
+    pub edge_pattern: TilePattern, 
+    pub fill_pattern: TilePattern, 
+    pub line_pattern: TilePattern, 
     pub origin: Vec3, 
     pub size: Vec2, 
     pub aabb: AABB
@@ -335,17 +573,18 @@ impl Table {
         num_rows: usize, 
         num_cols: usize, 
         half_spawn_area: usize, 
-        edge_color: Vec4, 
-        fill_color: Vec4, 
-        line_color: Vec4, 
+        theme: TableTheme, 
         origin: Vec3, 
         size: Vec2, 
         queue: &wgpu::Queue, 
-        tile_brush: &TileBrush
+        tile_brush: &TileBrush, 
+        rng: &mut StdRng
     ) -> Self {
         debug_assert!(0 < half_spawn_area, "The given \'spawn_half_area\' must be greater than 0!");
         debug_assert!(num_rows > 8 * half_spawn_area, "The number of rows given must be greater than \'8 * spawn_half_area\'!");
         debug_assert!(num_cols > 8 * half_spawn_area, "The number of cols given must be greater than \'8 * spawn_half_area\'!");
+        let (edge_color, fill_color, line_color) = (theme.edge_color(), theme.fill_color(), theme.line_color());
+        let (edge_pattern, fill_pattern, line_pattern) = (theme.edge_pattern(), theme.fill_pattern(), theme.line_pattern());
     
         // (한국어) 주어진 위치와 크기로 타일을 생성합니다.
         // (English Translation) Creates a tile with a given position and size. 
@@ -356,14 +595,11 @@ impl Table {
                 let x = self::position(origin.x, size.x, col);
                 let y = self::position(origin.y, size.y, row);
 
+                let is_edge = row == 0 || row == num_rows - 1 || col == 0 || col == num_cols - 1;
                 lines.push(Tile {
-                    visited: false, 
                     transform: Mat4::from_translation(Vec3::new(x, y, origin.y)).into(), 
-                    color: if row == 0 || row == num_rows - 1 || col == 0 || col == num_cols - 1 {
-                        edge_color
-                    } else {
-                        fill_color
-                    },
+                    color: if is_edge { edge_color } else { fill_color },
+                    pattern: if is_edge { edge_pattern } else { fill_pattern },
                 });
             }
             tiles.push(lines);
@@ -375,7 +611,7 @@ impl Table {
             for row in 0..num_rows {
                 for col in 0..num_cols {
                     instances[row * num_cols + col].transform = tiles[row][col].transform;
-                    instances[row * num_cols + col].color = tiles[row][col].color;
+                    instances[row * num_cols + col].apply_tile(&tiles[row][col]);
                     instances[row * num_cols + col].size = size;
                 }
             }
@@ -396,7 +632,7 @@ impl Table {
             ((3 * nr, 2 * nc), (1 * nr, 2 * nc)), 
             ((3 * nr, 3 * nc), (1 * nr, 1 * nc)),
         ];
-        spawns.shuffle(&mut rand::thread_rng());
+        spawns.shuffle(rng);
         let (player_spawn_pos, boss_spawn_pos) = spawns.pop().unwrap();
 
 
@@ -418,6 +654,9 @@ impl Table {
             edge_color, 
             fill_color, 
             line_color, 
+            edge_pattern, 
+            fill_pattern, 
+            line_pattern, 
             origin, 
             size, 
             aabb, 
@@ -439,72 +678,94 @@ pub fn position(pos: f32, size: f32, index: usize) -> f32 {
 }
 
 /// #### 한국어 </br>
-/// 플레이어가 소유한 타일을 갱신합니다. </br>
+/// 주어진 월드 좌표에 있는 타일의 위치(행, 열)를 반환합니다. </br>
+/// 타일 영역 밖의 위치인 경우 `None`을 반환합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Updates tiles owned by the player. </br>
+/// Returns the position (row, column) of the tile at the given world coordinates. </br>
+/// Returns `None` if the position is outside the tile area. </br>
 /// 
-pub fn update_owned_tiles(
-    queue: &wgpu::Queue, 
-    tile_brush: &TileBrush, 
-    table: &mut Table, 
-    path: &mut VecDeque<(usize, usize)>, 
-    num_owned_tiles: &mut u32, 
-    owned_tiles: &mut VecDeque<(f64, Vec<(usize, usize)>)>, 
-) {
+pub fn tile_index(table: &Table, position: Vec2) -> Option<(usize, usize)> {
+    let col = ((position.x - table.origin.x) / table.size.x).floor();
+    let row = ((position.y - table.origin.y) / table.size.y).floor();
+    if col < 0.0 || row < 0.0 || col >= table.num_cols as f32 || row >= table.num_rows as f32 {
+        return None;
+    }
+    return Some((row as usize, col as usize));
+}
+
+/// #### 한국어 </br>
+/// 플레이어가 경로를 닫아 획득한 타일들 입니다. </br>
+/// - 안쪽: 경로와 경로로 둘러싸인 타일들로, 플레이어가 소유하게 됩니다. </br>
+/// - 선분: 획득한 영역을 둘러싸는 타일들로, 경계선으로 그려집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Tiles acquired by the player by closing the path. </br>
+/// - Inside: The path and the tiles surrounded by it, which become owned by the player. </br>
+/// - Edge: The tiles surrounding the acquired area, which are drawn as the boundary line. </br>
+/// 
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Capture {
+    pub inside: Vec<(usize, usize)>, 
+    pub edge: Vec<(usize, usize)>, 
+}
+
+/// #### 한국어 </br>
+/// 닫힌 경로로 획득한 타일들을 구하고, 안쪽 타일들을 방문한 상태로 만듭니다. </br>
+/// 경로는 안쪽 타일에 포함되며 비워집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Finds the tiles acquired by the closed path, and marks the inner tiles as visited. </br>
+/// The path is included in the inner tiles and cleared. </br>
+/// 
+pub fn capture_tiles(visited: &mut [Vec<bool>], path: &mut VecDeque<(usize, usize)>) -> Capture {
+    let max_rows = visited.len();
+    let max_cols = visited.first().map_or(0, |lines| lines.len());
+
     // (한국어) 안쪽 영역의 타일들을 구한다.
     // (English Translation) Finds the tiles in the inner area. 
-    let mut inside_tiles = search_inside_tiles(
-        table.num_rows, 
-        table.num_cols, 
-        &table.tiles, 
-        &path, 
-    );
+    let mut inside = search_inside_tiles(max_rows, max_cols, visited, path);
 
     // (한국어) 선분 영역의 타일들을 구한다.
     // (English Translation) Finds the tiles in edge area.
-    let mut edge_tiles = search_edge_tiles(
-        table.num_rows, 
-        table.num_cols, 
-        &table.tiles,
-        &path, 
-        &inside_tiles
-    );
+    let edge = search_edge_tiles(max_rows, max_cols, visited, path, &inside);
 
     // (한국어) 안쪽 영역 타일에 경로를 포함시킵니다.
     // (English Translation) Include the path in the inner area tile.
-    while let Some(path) = path.pop_front() {
-        inside_tiles.push(path);
+    inside.extend(path.drain(..));
+    for &(r, c) in inside.iter() {
+        visited[r][c] = true;
     }
 
-    tile_brush.update(queue, |instances| {
-        // (한국어) 선분 영역의 타일을 갱신합니다.
-        // (English Translation) Updates the tiles in the edge area.
-        for &(r, c) in edge_tiles.iter() {
-            instances[r * table.num_cols + c].color = table.edge_color;
-        }
-        
-        // (한국어) 안쪽 영역의 타일을 갱신합니다.
-        // (English Translation) Updates the tiles in the inner area.
-        for &(r, c) in inside_tiles.iter() {
-            instances[r * table.num_cols + c].color = table.fill_color;
-        }
-    });
+    return Capture { inside, edge };
+}
 
-    // (한국어) 타일을 재설정 합니다.
-    // (English Translation) Reset the tile.
-    while let Some((r, c)) = edge_tiles.pop() {
+/// #### 한국어 </br>
+/// 획득한 타일들의 색상과 무늬를 갱신합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Updates the colors and patterns of the acquired tiles. </br>
+/// 
+pub fn apply_capture(
+    queue: &wgpu::Queue, 
+    tile_brush: &TileBrush, 
+    table: &mut Table, 
+    capture: &Capture
+) {
+    for &(r, c) in capture.edge.iter() {
         table.tiles[r][c].color = table.edge_color;
+        table.tiles[r][c].pattern = table.edge_pattern;
     }
-    for &(r, c) in inside_tiles.iter() {
+    for &(r, c) in capture.inside.iter() {
         table.tiles[r][c].color = table.fill_color;
-        table.tiles[r][c].visited = true;
+        table.tiles[r][c].pattern = table.fill_pattern;
     }
 
-    // (한국어) 소유 타일 목록에 추가합니다.
-    // (English Translation) Add it to the list of owned tiles. 
-    *num_owned_tiles += inside_tiles.len() as u32;
-    owned_tiles.push_back((0.0, inside_tiles));
+    tile_brush.update(queue, |instances| {
+        for &(r, c) in capture.edge.iter().chain(capture.inside.iter()) {
+            instances[r * table.num_cols + c].apply_tile(&table.tiles[r][c]);
+        }
+    });
 }
 
 /// #### 한국어 </br>
@@ -516,7 +777,7 @@ pub fn update_owned_tiles(
 fn search_inside_tiles(
     max_rows: usize, 
     max_cols: usize, 
-    tiles: &Vec<Vec<Tile>>,
+    marked: &[Vec<bool>],
     path: &VecDeque<(usize, usize)>,
 ) -> Vec<(usize, usize)> {
     // (한국어) 타일의 캐시입니다.
@@ -528,14 +789,14 @@ fn search_inside_tiles(
         // (한국어) 한 위치의 탐색 가능한 영역을 담습니다.
         // (English Translation) Contains the navigable area of a position. 
         let mut begins = Vec::with_capacity(8);
-        if r > 0 && !tiles[r - 1][c].visited { begins.push((r - 1, c)); }
-        if r + 1 < max_rows && !tiles[r + 1][c].visited { begins.push((r + 1, c)); }
-        if c > 0 && !tiles[r][c - 1].visited { begins.push((r, c - 1)); }
-        if c + 1 < max_cols && !tiles[r][c + 1].visited { begins.push((r, c + 1)); }
-        if r > 0 && c > 0 && !tiles[r - 1][c - 1].visited { begins.push((r - 1, c - 1)); }
-        if r > 0 && c + 1 < max_cols && !tiles[r - 1][c + 1].visited { begins.push((r - 1, c + 1)); }
-        if r + 1 < max_rows && c > 0 && !tiles[r + 1][c - 1].visited { begins.push((r + 1, c - 1)); }
-        if r + 1 < max_rows && c + 1 < max_cols && !tiles[r + 1][c + 1].visited { begins.push((r + 1, c + 1)); }
+        if r > 0 && !marked[r - 1][c] { begins.push((r - 1, c)); }
+        if r + 1 < max_rows && !marked[r + 1][c] { begins.push((r + 1, c)); }
+        if c > 0 && !marked[r][c - 1] { begins.push((r, c - 1)); }
+        if c + 1 < max_cols && !marked[r][c + 1] { begins.push((r, c + 1)); }
+        if r > 0 && c > 0 && !marked[r - 1][c - 1] { begins.push((r - 1, c - 1)); }
+        if r > 0 && c + 1 < max_cols && !marked[r - 1][c + 1] { begins.push((r - 1, c + 1)); }
+        if r + 1 < max_rows && c > 0 && !marked[r + 1][c - 1] { begins.push((r + 1, c - 1)); }
+        if r + 1 < max_rows && c + 1 < max_cols && !marked[r + 1][c + 1] { begins.push((r + 1, c + 1)); }
         
         // (한국어) 깊이 우선 탐색으로 인접한 영역을 찾습니다.
         // (English Translation) Find adjacent regions using `DFS`.
@@ -566,39 +827,39 @@ fn search_inside_tiles(
 
                 visited[r][c] = true;
 
-                if r > 0 && !tiles[r - 1][c].visited && !visited[r - 1][c] { 
+                if r > 0 && !marked[r - 1][c] && !visited[r - 1][c] { 
                     stack.push_back((r - 1, c)); 
                 }
 
-                if r + 1 < max_rows && !tiles[r + 1][c].visited && !visited[r + 1][c] { 
+                if r + 1 < max_rows && !marked[r + 1][c] && !visited[r + 1][c] { 
                     stack.push_back((r + 1, c)); 
                 }
 
-                if c > 0 && !tiles[r][c - 1].visited && !visited[r][c - 1] { 
+                if c > 0 && !marked[r][c - 1] && !visited[r][c - 1] { 
                     stack.push_back((r, c - 1));
                 }
 
-                if c + 1 < max_cols && !tiles[r][c + 1].visited && !visited[r][c + 1] { 
+                if c + 1 < max_cols && !marked[r][c + 1] && !visited[r][c + 1] { 
                     stack.push_back((r, c + 1)); 
                 }
 
                 if r > 0 && c > 0 
-                && !tiles[r - 1][c - 1].visited && !visited[r - 1][c - 1] { 
+                && !marked[r - 1][c - 1] && !visited[r - 1][c - 1] { 
                     stack.push_back((r - 1, c - 1)); 
                 }
 
                 if r > 0 && c + 1 < max_cols 
-                && !tiles[r - 1][c + 1].visited && !visited[r - 1][c + 1] { 
+                && !marked[r - 1][c + 1] && !visited[r - 1][c + 1] { 
                     stack.push_back((r - 1, c + 1)); 
                 }
 
                 if r + 1 < max_rows && c > 0 
-                && !tiles[r + 1][c - 1].visited && !visited[r + 1][c - 1] { 
+                && !marked[r + 1][c - 1] && !visited[r + 1][c - 1] { 
                     stack.push_back((r + 1, c - 1)); 
                 }
 
                 if r + 1 < max_rows && c + 1 < max_cols 
-                && !tiles[r + 1][c + 1].visited && !visited[r + 1][c + 1] { 
+                && !marked[r + 1][c + 1] && !visited[r + 1][c + 1] { 
                     stack.push_back((r + 1, c + 1)); 
                 }
             }
@@ -642,7 +903,7 @@ fn search_inside_tiles(
 fn search_edge_tiles(
     max_rows: usize, 
     max_cols: usize, 
-    tiles: &Vec<Vec<Tile>>,
+    marked: &[Vec<bool>],
     path: &VecDeque<(usize, usize)>,
     inside_tiles: &Vec<(usize, usize)>, 
 ) -> Vec<(usize, usize)> {
@@ -656,46 +917,80 @@ fn search_edge_tiles(
 
     let mut edge_tiles = Vec::with_capacity(path.len() * 2);
     for &(r, c) in path.iter() {
-        if r > 0 && !visited[r - 1][c] && !tiles[r - 1][c].visited {
+        if r > 0 && !visited[r - 1][c] && !marked[r - 1][c] {
             edge_tiles.push((r - 1, c));
             visited[r - 1][c] = true;
         }
 
-        if r + 1 < max_rows && !visited[r + 1][c] && !tiles[r + 1][c].visited {
+        if r + 1 < max_rows && !visited[r + 1][c] && !marked[r + 1][c] {
             edge_tiles.push((r + 1, c));
             visited[r + 1][c] = true;
         }
 
-        if c > 0 && !visited[r][c - 1] && !tiles[r][c - 1].visited {
+        if c > 0 && !visited[r][c - 1] && !marked[r][c - 1] {
             edge_tiles.push((r, c - 1));
             visited[r][c - 1] = true;
         }
 
-        if c + 1 < max_cols && !visited[r][c + 1] && !tiles[r][c + 1].visited {
+        if c + 1 < max_cols && !visited[r][c + 1] && !marked[r][c + 1] {
             edge_tiles.push((r, c + 1));
             visited[r][c + 1] = true;
         }
 
-        if r > 0 && c > 0 && !visited[r - 1][c - 1] && !tiles[r - 1][c - 1].visited {
+        if r > 0 && c > 0 && !visited[r - 1][c - 1] && !marked[r - 1][c - 1] {
             edge_tiles.push((r - 1, c - 1));
             visited[r - 1][c - 1] = true;
         }
 
-        if r > 0 && c + 1 < max_cols && !visited[r - 1][c + 1] && !tiles[r - 1][c + 1].visited {
+        if r > 0 && c + 1 < max_cols && !visited[r - 1][c + 1] && !marked[r - 1][c + 1] {
             edge_tiles.push((r - 1, c + 1));
             visited[r - 1][c + 1] = true;
         }
 
-        if r + 1 < max_rows && c > 0 && !visited[r + 1][c - 1] && !tiles[r + 1][c - 1].visited {
+        if r + 1 < max_rows && c > 0 && !visited[r + 1][c - 1] && !marked[r + 1][c - 1] {
             edge_tiles.push((r + 1, c - 1));
             visited[r + 1][c - 1] = true;
         }
 
-        if r + 1 < max_rows && c + 1 < max_cols && !visited[r + 1][c + 1] && !tiles[r + 1][c + 1].visited {
+        if r + 1 < max_rows && c + 1 < max_cols && !visited[r + 1][c + 1] && !marked[r + 1][c + 1] {
             edge_tiles.push((r + 1, c + 1));
             visited[r + 1][c + 1] = true;
         }
     }
     
     return edge_tiles;
-}
\ No newline at end of file
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn high_contrast_theme_differs_in_luminance() {
+        const MIN_LUMINANCE_DIFFERENCE: f32 = 0.4;
+
+        let theme = TableTheme::HighContrast;
+        let unowned = luminance(theme.fill_color());
+        assert!((unowned - luminance(theme.edge_color())).abs() >= MIN_LUMINANCE_DIFFERENCE);
+        assert!((unowned - luminance(theme.line_color())).abs() >= MIN_LUMINANCE_DIFFERENCE);
+        assert_ne!(theme.fill_pattern(), theme.line_pattern());
+
+        assert!((luminance(Vec4::ONE) - 1.0).abs() < 1e-6);
+        assert_eq!(luminance(Vec4::ZERO), 0.0);
+    }
+
+    #[test]
+    fn board_sizes_have_consistent_tile_counts() {
+        use crate::nodes::in_game::NUM_TILES;
+
+        assert_eq!(BoardSize::Standard.num_tiles(), NUM_TILES);
+        assert_eq!(BoardSize::Small.num_tiles(), 50 * 50);
+        assert!(BoardSize::Small.num_tiles() < BoardSize::Standard.num_tiles());
+
+        // (한국어) 작은 보드의 결과는 스테이지를 열거나 기록을 덮어쓰지 않아야 합니다.
+        // (English Translation) Results on the small board must not unlock stages or overwrite records.
+        assert!(BoardSize::Standard.is_recorded());
+        assert!(!BoardSize::Small.is_recorded());
+    }
+}
@@ -1,9 +1,22 @@
+//! #### 한국어 </br>
+//! 게임의 모든 텍스트는 유지형(retained) [`Text`] 객체로 그려집니다. </br>
+//! 1. [`TextBuilder`]로 [`Text`]를 한 번 생성합니다. </br>
+//! 2. 위치나 색상은 [`Text::update`]로, 내용은 [`Text::change`]로 갱신합니다. </br>
+//! 3. 렌더 패스 안에서 [`TextBrush::draw`]에 그릴 텍스트들의 반복자를 전달합니다. </br>
+//! 
+//! #### English (Translation) </br>
+//! All text in the game is drawn with retained [`Text`] objects. </br>
+//! 1. Create a [`Text`] once with [`TextBuilder`]. </br>
+//! 2. Update the position or color with [`Text::update`] and the content with [`Text::change`]. </br>
+//! 3. Pass an iterator of the texts to draw to [`TextBrush::draw`] inside a render pass. </br>
+//! 
 use std::mem::size_of;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex, MutexGuard};
 
 use wgpu::util::DeviceExt;
-use ab_glyph::{FontArc, Font, ScaleFont};
+use serde::{Serialize, Deserialize};
+use ab_glyph::{FontArc, Font, GlyphId, OutlinedGlyph, ScaleFont};
 use bytemuck::{Pod, Zeroable, offset_of};
 use glam::{Mat4, Quat, Vec4, Vec3, Vec2};
 
@@ -11,14 +24,64 @@ use crate::{
     assets::bundle::AssetBundle, 
     components::{
         anchor::Anchor, 
+        font::FontFallback, 
         margin::Margin, 
     }, 
-    render::shader::WgslDecoder, 
+    render::{shader::WgslDecoder, tracker::Tracked}, 
     system::error::AppResult, 
 };
 
 
 
+/// #### 한국어 </br>
+/// 모든 텍스트의 글자 크기에 곱해지는 배율을 백분율로 담고있는 구조체 입니다. </br>
+/// 글자가 영역을 넘치지 않도록 `80% ~ 150%` 범위로 제한됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a structure that contains the scale multiplied to the glyph size of all text, as a percentage. </br>
+/// It is limited to the range `80% ~ 150%` so that the glyphs do not overflow their area. </br>
+/// 
+#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TextScale(u8);
+
+impl TextScale {
+    pub const MIN_PERCENT: u8 = 80;
+    pub const MAX_PERCENT: u8 = 150;
+
+    /// #### 한국어 </br>
+    /// 주어진 백분율로 새로운 글자 크기 배율을 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a new text scale with the given percentage. </br>
+    /// 
+    #[cfg(test)]
+    #[inline]
+    pub fn new(percent: u8) -> Self {
+        Self(percent.clamp(Self::MIN_PERCENT, Self::MAX_PERCENT))
+    }
+
+    /// #### 한국어 </br>
+    /// `0.8 ~ 1.5` 사이의 값으로 변환된 배율을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Get the scale converted to a value between `0.8 and 1.5`. </br>
+    /// 
+    #[inline]
+    pub fn norm(&self) -> f32 {
+        self.0.clamp(Self::MIN_PERCENT, Self::MAX_PERCENT) as f32 / 100.0
+    }
+}
+
+impl Default for TextScale {
+    #[inline]
+    fn default() -> Self {
+        Self(100)
+    }
+}
+
+
+
 /// #### 한국어 </br>
 /// 문자를 렌더링 할 때 필요한 버텍스 입력 데이터 구조체입니다. </br>
 /// 
@@ -216,6 +279,50 @@ impl Default for TextData {
 
 
 
+/// #### 한국어 </br>
+/// 텍스트 아래에 그려지는 그림자의 데이터를 담고있는 구조체입니다. </br>
+/// 그림자는 같은 문자들을 주어진 픽셀 만큼 이동시켜 텍스트보다 먼저 한 번 더 그립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a structure that contains the data of the shadow drawn under the text. </br>
+/// The shadow draws the same characters once more before the text, moved by the given pixels. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextShadow {
+    pub offset: (i32, i32), 
+    pub color: Vec4, 
+}
+
+impl TextShadow {
+    /// #### 한국어 </br>
+    /// 텍스트의 데이터로부터 그림자의 데이터를 만듭니다. </br>
+    /// 그림자의 알파 값은 텍스트의 알파 값을 따라갑니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Makes the shadow data from the text data. </br>
+    /// The alpha value of the shadow follows the alpha value of the text. </br>
+    /// 
+    fn apply(&self, data: &TextData) -> TextData {
+        let (dx, dy) = self.offset;
+        let margin = Margin::new(
+            data.margin.top() - dy, 
+            data.margin.left() + dx, 
+            data.margin.bottom() - dy, 
+            data.margin.right() + dx
+        );
+        let color = Vec4::new(
+            self.color.x, 
+            self.color.y, 
+            self.color.z, 
+            self.color.w * data.color.w
+        );
+
+        return TextData { margin, color, ..*data };
+    }
+}
+
+
+
 /// #### 한국어 </br>
 /// 텍스트의 렌더링 데이터를 담고있는 구조체입니다. </br>
 /// 
@@ -228,9 +335,12 @@ pub struct Text {
     font: FontArc, 
     buffer: wgpu::Buffer, 
     buffer_bind_group: wgpu::BindGroup, 
-    texture_bind_groups: HashMap<char, wgpu::BindGroup>, 
+    texture_bind_groups: HashMap<char, Arc<wgpu::BindGroup>>, 
     characters: Vec<Option<Char>>, 
+    shadow: Option<(TextShadow, wgpu::Buffer, wgpu::BindGroup)>, 
+    text_scale: f32, 
     pub data: Mutex<TextData>, 
+    _tracked: Tracked, 
 }
 
 #[allow(dead_code)]
@@ -277,18 +387,48 @@ impl Text {
             },
         );
 
+        // (한국어) 그림자가 있는 경우 그림자의 유니폼 버퍼와 바인드 그룹을 생성합니다.
+        // (English Translation) If there is a shadow, creates the uniform buffer and bind group for the shadow.
+        let shadow = builder.shadow.map(|shadow| {
+            let buffer = device.create_buffer_init(
+                &wgpu::util::BufferInitDescriptor {
+                    label: Some(&format!("Uniform(TextShadow({}))", name)), 
+                    contents: bytemuck::bytes_of(&shadow.apply(&data).to_data()), 
+                    usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+                },
+            );
+            let bind_group = device.create_bind_group(
+                &wgpu::BindGroupDescriptor {
+                    label: Some(&format!("BindGroup(Uniform(TextShadow({})))", name)),
+                    layout: &builder.brush.buffer_layout, 
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0, 
+                            resource: wgpu::BindingResource::Buffer(
+                                buffer.as_entire_buffer_binding()
+                            ),
+                        },
+                    ],
+                },
+            );
+            (shadow, buffer, bind_group)
+        });
+
         let mut texture_bind_groups = HashMap::new();
+        let text_scale = builder.brush.text_scale;
         let characters = create_characters(
             name, 
             builder.font, 
+            &builder.brush.fallback, 
             builder.text, 
+            text_scale, 
             device, 
             queue, 
-            &builder.brush.tex_sampler, 
-            &builder.brush.texture_layout, 
+            builder.brush, 
             &mut texture_bind_groups
         );
 
+        let num_shadows = shadow.iter().count();
         Self { 
             name: name.to_string(), 
             font: builder.font.clone(), 
@@ -296,7 +436,10 @@ impl Text {
             buffer_bind_group, 
             texture_bind_groups, 
             characters, 
-            data: data.into() 
+            shadow, 
+            text_scale, 
+            data: data.into(), 
+            _tracked: Tracked::new(1 + num_shadows, 1 + num_shadows), 
         }
     }
 
@@ -313,15 +456,23 @@ impl Text {
         let mut guard = self.data.lock().expect("Failed to access variable.");
         mapping_func(&mut guard);
         queue.write_buffer(&self.buffer, 0, bytemuck::bytes_of(&guard.to_data()));
+        if let Some((shadow, buffer, _)) = self.shadow.as_ref() {
+            queue.write_buffer(buffer, 0, bytemuck::bytes_of(&shadow.apply(&guard).to_data()));
+        }
     }
 
     /// #### 한국어 </br>
     /// 텍스트의 내용을 변경합니다. </br>
+    /// 문자들은 매번 다시 배치되지만, 처음 나오는 문자만 텍스처를 생성합니다. </br>
+    /// 출력되는 문자의 배치가 같으면 (예: 숫자 하나만 바뀐 경우) 기존 버퍼를 재사용하고 
+    /// 바뀐 문자의 버퍼만 다시 올리며, 그렇지 않으면 문자 버퍼들을 새로 생성합니다. </br>
     /// 
     /// #### English (Translation) </br>
     /// Change the content of the text. </br>
+    /// Characters are laid out again every time, but textures are only created for characters seen for the first time. </br>
+    /// If the layout of the drawn characters is the same (e.g. only one digit changed), the existing buffers are reused 
+    /// and only the buffers of the changed characters are uploaded again; otherwise the character buffers are created anew. </br>
     /// 
-    #[inline]
     pub fn change(
         &mut self, 
         text: &str, 
@@ -329,16 +480,58 @@ impl Text {
         queue: &wgpu::Queue, 
         text_brush: &TextBrush
     ) {
-        self.characters = create_characters(
+        let layout = layout_characters(&self.font, &text_brush.fallback, text, self.text_scale);
+        create_textures(
             &self.name, 
             &self.font, 
-            text, 
+            &text_brush.fallback, 
+            &layout, 
             device, 
             queue, 
-            &text_brush.tex_sampler, 
-            &text_brush.texture_layout, 
+            text_brush, 
             &mut self.texture_bind_groups
         );
+
+        let reusable = layout.len() == self.characters.len() 
+            && layout.iter().zip(self.characters.iter()).all(|(new, old)| new.is_some() == old.is_some());
+        if !reusable {
+            self.characters = layout.into_iter()
+                .map(|char| char.map(|(ch, data)| create_char(&self.name, ch, data, device)))
+                .collect();
+            return;
+        }
+
+        for (new, old) in layout.into_iter().zip(self.characters.iter_mut()) {
+            if let (Some((ch, data)), Some(old)) = (new, old) {
+                let changed = old.ch != ch || *old.data.lock().expect("Failed to access variable.") != data;
+                if changed {
+                    old.ch = ch;
+                    old.update(queue, |it| **it = data);
+                }
+            }
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 화면에 그려지는 문자(사각형)의 개수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the number of characters (quads) drawn on the screen. </br>
+    /// 
+    #[inline]
+    pub fn num_quads(&self) -> usize {
+        self.characters.iter().flatten().count()
+    }
+
+    /// #### 한국어 </br>
+    /// 그림자를 포함하여 화면에 그려지는 문자(사각형)의 개수를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the number of characters (quads) drawn on the screen, including the shadow. </br>
+    /// 
+    #[inline]
+    pub fn num_instances(&self) -> usize {
+        num_instances(self.num_quads(), self.shadow.is_some())
     }
 
     #[inline]
@@ -352,6 +545,31 @@ impl Text {
             rpass.set_bind_group(2, bind_group, &[])
         }
     }
+
+    #[inline]
+    fn draw_characters<'pass>(&'pass self, rpass: &mut wgpu::RenderPass<'pass>) {
+        for ch in self.characters.iter().flatten() {
+            self.bind_texture(ch.char(), rpass);
+            ch.bind(rpass);
+            ch.draw(rpass);
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 그림자의 유무에 따라 화면에 그려지는 문자(사각형)의 개수를 반환합니다. </br>
+/// 그림자가 있는 경우 같은 문자들을 한 번 더 그립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the number of characters (quads) drawn on the screen depending on whether there is a shadow. </br>
+/// If there is a shadow, the same characters are drawn once more. </br>
+/// 
+#[inline]
+fn num_instances(num_quads: usize, has_shadow: bool) -> usize {
+    match has_shadow {
+        true => 2 * num_quads,
+        false => num_quads,
+    }
 }
 
 /// #### 한국어 </br>
@@ -371,6 +589,7 @@ pub struct TextBuilder<'a> {
     pub translation: Vec3, 
     pub anchor: Anchor, 
     pub margin: Margin, 
+    pub shadow: Option<TextShadow>, 
     pub brush: &'a TextBrush, 
 }
 
@@ -393,6 +612,7 @@ impl<'a> TextBuilder<'a> {
             translation: Vec3 { x: 0.0, y: 0.0, z: 0.0 }, 
             anchor: Anchor::default(), 
             margin: Margin::default(), 
+            shadow: None, 
             brush 
         }
     }
@@ -433,6 +653,20 @@ impl<'a> TextBuilder<'a> {
         return self;
     }
 
+    /// #### 한국어 </br>
+    /// 텍스트 아래에 주어진 픽셀 만큼 이동한 그림자를 그립니다. </br>
+    /// `offset`의 양수 값은 오른쪽 아래 방향입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws a shadow under the text, moved by the given pixels. </br>
+    /// Positive values of `offset` point to the bottom right. </br>
+    /// 
+    #[inline]
+    pub fn with_shadow(mut self, offset: (i32, i32), color: Vec4) -> Self {
+        self.shadow = Some(TextShadow { offset, color });
+        return self;
+    }
+
     #[inline]
     pub fn build(self, device: &wgpu::Device, queue: &wgpu::Queue) -> Text {
         Text::new(self, device, queue)
@@ -448,14 +682,50 @@ impl<'a> TextBuilder<'a> {
 fn create_characters(
     name: &str, 
     font: &FontArc, 
+    fallback: &FontFallback, 
     text: &str, 
+    text_scale: f32, 
     device: &wgpu::Device, 
     queue: &wgpu::Queue, 
-    tex_sampler: &wgpu::Sampler, 
-    texture_layout: &wgpu::BindGroupLayout, 
-    texture_bind_groups: &mut HashMap<char, wgpu::BindGroup>
+    brush: &TextBrush, 
+    texture_bind_groups: &mut HashMap<char, Arc<wgpu::BindGroup>>
 ) -> Vec<Option<Char>> {
-    let font = font.as_scaled(128.0);
+    let layout = layout_characters(font, fallback, text, text_scale);
+    create_textures(
+        name, 
+        font, 
+        fallback, 
+        &layout, 
+        device, 
+        queue, 
+        brush, 
+        texture_bind_groups
+    );
+
+    layout.into_iter()
+        .map(|char| char.map(|(ch, data)| create_char(name, ch, data, device)))
+        .collect()
+}
+
+/// #### 한국어 </br>
+/// 텍스트의 문자들을 배치합니다. </br>
+/// 출력되지 않는 문자(공백 등)는 `None`이 됩니다. </br>
+/// 글자 크기 배율은 텍스트 영역의 중심을 기준으로 적용되므로 고정된 위치는 바뀌지 않습니다. </br>
+/// 각 글리프는 대체 폰트 목록에서 해당 글리프를 가진 첫 번째 폰트에서 가져옵니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Lays out the characters of text. </br>
+/// Characters that are not drawn (such as spaces) become `None`. </br>
+/// The text scale is applied around the center of the text area, so the anchored position does not change. </br>
+/// Each glyph is taken from the first font in the fallback list that has it. </br>
+/// 
+fn layout_characters(
+    primary: &FontArc, 
+    fallback: &FontFallback, 
+    text: &str, 
+    text_scale: f32
+) -> Vec<Option<(char, CharData)>> {
+    let font = primary.as_scaled(GLYPH_PX_SIZE);
     let lines: Vec<_> = text.trim().split('\n').collect();
     let mut str: Vec<Vec<_>> = Vec::with_capacity(lines.len());
 
@@ -467,6 +737,7 @@ fn create_characters(
     for line in lines {
         let mut chars = Vec::with_capacity(line.trim().chars().count());
         for ch in line.trim().chars() {
+            let font = fallback.resolve(primary, ch).as_scaled(GLYPH_PX_SIZE);
             let glyph = font.scaled_glyph(ch);
             let h_advance = font.h_advance(glyph.id);
             chars.push(font.outline_glyph(glyph).map(|outline| {
@@ -478,74 +749,6 @@ fn create_characters(
                 let bearing_x = bound.min.x;
                 let bearing_y = bound.min.y;
 
-                // (한국어) 문자의 텍스처 데이터를 가져옵니다.
-                // (English Translation) Get texture data for a character.
-                let w = width as usize;
-                let h = height as usize;
-                let mut data = vec![0u8; w * h];
-                outline.draw(|x, y, v| {
-                    data[(y as usize) * w + (x as usize)] = (v * 255.0) as u8;
-                });
-
-                // (한국어) 
-                // 텍스처 캐시에 해당 문자의 텍스처가 없는 경우, 해당 문자의 텍스처를 생성합니다.
-                //
-                // (English Translation)
-                // If there is no texture for that character in the texture cache,
-                // creates a texture for that character.
-                //
-                texture_bind_groups.entry(ch).or_insert_with(|| {
-                    // (한국어) 문자의 텍스처와 텍스처 뷰를 생성합니다.
-                    // (English Translation) Create a texture and texture view for teh character.
-                    let texture = device.create_texture_with_data(
-                        queue, 
-                        &wgpu::TextureDescriptor {
-                            label: Some(&format!("Texture(Text({}))", name)), 
-                            size: wgpu::Extent3d {
-                                width: w as u32, 
-                                height: h as u32, 
-                                depth_or_array_layers: 1,
-                            }, 
-                            mip_level_count: 1,
-                            sample_count: 1,
-                            dimension: wgpu::TextureDimension::D2, 
-                            format: wgpu::TextureFormat::R8Unorm, 
-                            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST, 
-                            view_formats: &[]
-                        }, 
-                        wgpu::util::TextureDataOrder::LayerMajor,
-                        &data
-                    );
-                    let texture_view = texture.create_view(
-                        &wgpu::TextureViewDescriptor {
-                            ..Default::default()
-                        }
-                    );
-
-                    // (한국어) 문자 텍스처의 바인드 그룹을 생성합니다.
-                    // (English Translation) Creates a bind group of character texture.
-                    device.create_bind_group(
-                        &wgpu::BindGroupDescriptor {
-                            label: Some(&format!("BindGroup(Texture(Text({})))", name)), 
-                            layout: texture_layout, 
-                            entries: &[
-                                wgpu::BindGroupEntry {
-                                    binding: 0,
-                                    resource: wgpu::BindingResource::TextureView(
-                                        &texture_view
-                                    ),
-                                },
-                                wgpu::BindGroupEntry {
-                                    binding: 1,
-                                    resource: wgpu::BindingResource::Sampler(
-                                        tex_sampler
-                                    ),
-                                },
-                            ],
-                        },
-                    )
-                });
-
                 let x = caret_x + bearing_x;
                 let y = caret_y - height - bearing_y;
 
@@ -599,30 +802,283 @@ fn create_characters(
             data.translation.y /= maximum_height;
             data.size.x /= data.size.y;
             data.size.y /= maximum_height;
+
+            // (한국어) 
+            // 가로 위치는 글자의 높이에 비례하므로 세로 값에만 배율을 곱합니다.
+            //
+            // (English Translation) 
+            // Horizontal positions are proportional to the glyph height, 
+            // so only the vertical values are multiplied by the scale.
+            //
+            data.translation.y *= text_scale;
+            data.size.y *= text_scale;
         }
     }
 
-    str.into_iter().flatten()
-        .map(|char| {
-            char.map(|(ch, data)| {
-                // (한국어) 문자의 버텍스 입력 버퍼를 생성합니다.
-                // (English Translation) Creates a vertex input buffer for characters. 
-                let buffer = device.create_buffer_init(
-                    &wgpu::util::BufferInitDescriptor {
-                        label: Some(&format!("Vertex(Text({}))", name)), 
-                        contents: bytemuck::bytes_of(&data.to_data()), 
-                        usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
+    return str.into_iter().flatten().collect();
+}
+
+/// #### 한국어 </br>
+/// 텍스처 캐시에 없는 문자들의 텍스처를 생성합니다. </br>
+/// 다른 텍스트가 이미 만든 글리프 텍스처는 그리기 도구의 글리프 캐시에서 재사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates textures for characters that are not in the texture cache. </br>
+/// Glyph textures already created by other texts are reused from the glyph cache of the brush. </br>
+/// 
+fn create_textures(
+    name: &str, 
+    primary: &FontArc, 
+    fallback: &FontFallback, 
+    layout: &[Option<(char, CharData)>], 
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    brush: &TextBrush, 
+    texture_bind_groups: &mut HashMap<char, Arc<wgpu::BindGroup>>
+) {
+    let mut glyph_cache = brush.glyph_cache.lock().expect("Failed to access variable.");
+    cache_glyphs(&mut glyph_cache, primary, fallback, layout, texture_bind_groups, |outline| {
+        // (한국어) 문자의 텍스처 데이터를 가져옵니다.
+        // (English Translation) Get texture data for a character.
+        let bound = outline.px_bounds();
+        let w = bound.width() as usize;
+        let h = bound.height() as usize;
+        let mut data = vec![0u8; w * h];
+        outline.draw(|x, y, v| {
+            data[(y as usize) * w + (x as usize)] = (v * 255.0) as u8;
+        });
+
+        // (한국어) 문자의 텍스처와 텍스처 뷰를 생성합니다.
+        // (English Translation) Create a texture and texture view for teh character.
+        let texture = device.create_texture_with_data(
+            queue, 
+            &wgpu::TextureDescriptor {
+                label: Some(&format!("Texture(Text({}))", name)), 
+                size: wgpu::Extent3d {
+                    width: w as u32, 
+                    height: h as u32, 
+                    depth_or_array_layers: 1,
+                }, 
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2, 
+                format: wgpu::TextureFormat::R8Unorm, 
+                usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST, 
+                view_formats: &[]
+            }, 
+            wgpu::util::TextureDataOrder::LayerMajor,
+            &data
+        );
+        let texture_view = texture.create_view(
+            &wgpu::TextureViewDescriptor {
+                ..Default::default()
+            }
+        );
+
+        // (한국어) 문자 텍스처의 바인드 그룹을 생성합니다.
+        // (English Translation) Creates a bind group of character texture.
+        Arc::new(device.create_bind_group(
+            &wgpu::BindGroupDescriptor {
+                label: Some(&format!("BindGroup(Texture(Text({})))", name)), 
+                layout: &brush.texture_layout, 
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: wgpu::BindingResource::TextureView(
+                            &texture_view
+                        ),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::Sampler(
+                            &brush.tex_sampler
+                        ),
                     },
-                );
+                ],
+            },
+        ))
+    });
+}
 
-                Char {
-                    ch, 
-                    buffer, 
-                    data: data.into(),
-                }
-            })
-        })
-        .collect()
+/// #### 한국어 </br>
+/// 텍스트의 문자들에 글리프 캐시의 값을 연결합니다. </br>
+/// 캐시에 없는 글리프만 `rasterize` 함수로 생성하여 캐시에 추가합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Links the values of the glyph cache to the characters of the text. </br>
+/// Only glyphs not in the cache are created with the `rasterize` function and added to the cache. </br>
+/// 
+fn cache_glyphs<V, F>(
+    glyph_cache: &mut GlyphCache<V>, 
+    primary: &FontArc, 
+    fallback: &FontFallback, 
+    layout: &[Option<(char, CharData)>], 
+    values: &mut HashMap<char, V>, 
+    mut rasterize: F
+) 
+where V: Clone, F: FnMut(&OutlinedGlyph) -> V {
+    for &(ch, _) in layout.iter().flatten() {
+        if values.contains_key(&ch) {
+            continue;
+        }
+
+        let font = fallback.resolve(primary, ch);
+        let scaled = font.as_scaled(GLYPH_PX_SIZE);
+        let glyph = scaled.scaled_glyph(ch);
+        let key = GlyphKey::new(font, glyph.id, GLYPH_PX_SIZE);
+        let value = match glyph_cache.get(&key) {
+            Some(value) => value, 
+            None => {
+                let outline = match scaled.outline_glyph(glyph) {
+                    Some(outline) => outline, 
+                    None => continue, 
+                };
+                let bound = outline.px_bounds();
+                let area = bound.width() as u32 * bound.height() as u32;
+                let value = rasterize(&outline);
+                glyph_cache.insert(key, area, value.clone());
+                value
+            }
+        };
+        values.insert(ch, value);
+    }
+}
+
+/// #### 한국어 </br>
+/// 문자의 버텍스 입력 버퍼를 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a vertex input buffer for characters. </br>
+/// 
+fn create_char(name: &str, ch: char, data: CharData, device: &wgpu::Device) -> Char {
+    let buffer = device.create_buffer_init(
+        &wgpu::util::BufferInitDescriptor {
+            label: Some(&format!("Vertex(Text({}))", name)), 
+            contents: bytemuck::bytes_of(&data.to_data()), 
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST, 
+        },
+    );
+
+    return Char {
+        ch, 
+        buffer, 
+        data: data.into(),
+    };
+}
+
+
+
+/// #### 한국어 </br>
+/// 글리프를 래스터화할 때 사용하는 픽셀 크기입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The pixel size used when rasterizing glyphs. </br>
+/// 
+const GLYPH_PX_SIZE: f32 = 128.0;
+
+/// #### 한국어 </br>
+/// 글리프 캐시가 담을 수 있는 글리프 텍스처의 총 넓이(픽셀)입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The total area (in pixels) of glyph textures the glyph cache can hold. </br>
+/// 
+const GLYPH_CACHE_AREA: u32 = 2048 * 2048;
+
+/// #### 한국어 </br>
+/// 글리프 캐시의 키 입니다. (폰트, 글리프, 크기) </br>
+/// 폰트는 폰트 데이터의 주소로 구분합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The key of the glyph cache. (font, glyph, size) </br>
+/// Fonts are distinguished by the address of their font data. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct GlyphKey {
+    font: usize, 
+    glyph: GlyphId, 
+    size: u32, 
+}
+
+impl GlyphKey {
+    #[inline]
+    fn new(font: &FontArc, glyph: GlyphId, size: f32) -> Self {
+        Self { 
+            font: font.font_data().as_ptr() as usize, 
+            glyph, 
+            size: size.to_bits(), 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 여러 텍스트가 공유하는 글리프 캐시 입니다. </br>
+/// 캐시된 글리프들의 넓이 합이 용량을 넘으면 가장 오래 사용되지 않은 글리프부터 제거합니다. </br>
+/// 제거된 글리프는 이를 사용 중인 텍스트가 모두 사라질 때 해제됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A glyph cache shared by multiple texts. </br>
+/// When the total area of the cached glyphs exceeds the capacity, the least recently used glyphs are removed first. </br>
+/// Removed glyphs are released when all texts using them are gone. </br>
+/// 
+#[derive(Debug)]
+struct GlyphCache<V> {
+    entries: HashMap<GlyphKey, (V, u32, u64)>, 
+    capacity: u32, 
+    used: u32, 
+    tick: u64, 
+}
+
+impl<V: Clone> GlyphCache<V> {
+    #[inline]
+    fn new(capacity: u32) -> Self {
+        Self { 
+            entries: HashMap::new(), 
+            capacity, 
+            used: 0, 
+            tick: 0, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 캐시된 글리프를 가져오고 최근 사용 시점을 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets a cached glyph and updates its last used time. </br>
+    /// 
+    fn get(&mut self, key: &GlyphKey) -> Option<V> {
+        self.tick += 1;
+        let tick = self.tick;
+        return self.entries.get_mut(key).map(|(value, _, last_used)| {
+            *last_used = tick;
+            value.clone()
+        });
+    }
+
+    /// #### 한국어 </br>
+    /// 글리프를 캐시에 추가합니다. </br>
+    /// 용량이 부족한 경우 가장 오래 사용되지 않은 글리프들을 제거합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds a glyph to the cache. </br>
+    /// If there is not enough capacity, the least recently used glyphs are removed. </br>
+    /// 
+    fn insert(&mut self, key: GlyphKey, area: u32, value: V) {
+        while self.used.saturating_add(area) > self.capacity {
+            let oldest = self.entries.iter()
+                .min_by_key(|(_, (_, _, last_used))| *last_used)
+                .map(|(key, _)| *key);
+            match oldest.and_then(|key| self.entries.remove(&key)) {
+                Some((_, area, _)) => self.used -= area, 
+                None => break, 
+            }
+        }
+
+        self.tick += 1;
+        self.used += area;
+        if let Some((_, area, _)) = self.entries.insert(key, (value, area, self.tick)) {
+            self.used -= area;
+        }
+    }
 }
 
 
@@ -636,6 +1092,9 @@ fn create_characters(
 #[derive(Debug)]
 pub struct TextBrush {
     pipeline: wgpu::RenderPipeline, 
+    text_scale: f32, 
+    fallback: Arc<FontFallback>, 
+    glyph_cache: Mutex<GlyphCache<Arc<wgpu::BindGroup>>>, 
     pub tex_sampler: wgpu::Sampler, 
     pub buffer_layout: wgpu::BindGroupLayout, 
     pub texture_layout: wgpu::BindGroupLayout, 
@@ -649,7 +1108,9 @@ impl TextBrush {
         depth_stencil: Option<wgpu::DepthStencilState>, 
         multisample: wgpu::MultisampleState, 
         multiview: Option<std::num::NonZeroU32>, 
-        asset_bundle: &AssetBundle
+        asset_bundle: &AssetBundle, 
+        text_scale: TextScale, 
+        fallback: Arc<FontFallback>
     ) -> AppResult<Arc<Self>> {
         let module = create_shader_module(device, asset_bundle)?;
         let tex_sampler = create_texture_sampler(device);
@@ -668,6 +1129,9 @@ impl TextBrush {
 
         Ok(Self {
             pipeline, 
+            text_scale: text_scale.norm(), 
+            fallback, 
+            glyph_cache: GlyphCache::new(GLYPH_CACHE_AREA).into(), 
             tex_sampler, 
             buffer_layout, 
             texture_layout, 
@@ -684,14 +1148,15 @@ impl TextBrush {
     where I: Iterator<Item = &'pass Text> {
         rpass.set_pipeline(&self.pipeline);
         for text in iter {
-            text.bind_buffer(rpass);
-            for ch in text.characters.iter() {
-                if let Some(ch) = ch {
-                    text.bind_texture(ch.char(), rpass);
-                    ch.bind(rpass);
-                    ch.draw(rpass);
-                }
+            // (한국어) 그림자가 있는 경우 텍스트보다 먼저 그립니다.
+            // (English Translation) If there is a shadow, it is drawn before the text.
+            if let Some((_, _, bind_group)) = text.shadow.as_ref() {
+                rpass.set_bind_group(1, bind_group, &[]);
+                text.draw_characters(rpass);
             }
+
+            text.bind_buffer(rpass);
+            text.draw_characters(rpass);
         }
     }
 }
@@ -890,3 +1355,158 @@ fn create_render_pipeline(
         },
     )
 }
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn shadow_follows_text() {
+        let shadow = TextShadow { offset: (2, 3), color: Vec4::new(0.0, 0.0, 0.0, 0.5) };
+        let data = TextData {
+            margin: Margin::new(10, -20, -10, 20), 
+            color: Vec4::new(1.0, 1.0, 1.0, 0.5), 
+            ..Default::default()
+        };
+
+        let out = shadow.apply(&data);
+        assert_eq!(out.margin, Margin::new(7, -18, -13, 22));
+        assert_eq!(out.color, Vec4::new(0.0, 0.0, 0.0, 0.25));
+        assert_eq!(out.anchor, data.anchor);
+        assert_eq!(out.translation, data.translation);
+    }
+
+    /// #### 한국어 </br>
+    /// 테스트에 사용할 장치와 대기열을 생성합니다. 어뎁터가 없는 환경에서는 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a device and queue for tests. Returns `None` in environments without an adapter. </br>
+    /// 
+    #[cfg(debug_assertions)]
+    fn test_device() -> Option<(wgpu::Device, wgpu::Queue)> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
+        let adapter = pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))?;
+        pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default(), None)).ok()
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn shadow_doubles_the_drawn_instances() {
+        use crate::{components::camera::create_camera_layout, nodes::path, render::tracker::TEST_LOCK};
+
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let (device, queue) = match test_device() {
+            Some(it) => it,
+            None => {
+                eprintln!("No adapter was found. Skipping the test.");
+                return;
+            }
+        };
+
+        let font = FontArc::try_from_slice(include_bytes!("../../assets/fonts/nexon_lv2_gothic_medium.ttf"))
+            .expect("Failed to load font.");
+        let asset_bundle = AssetBundle::for_test(&[path::UI_TEXT_SHADER_PATH]);
+        let camera_layout = create_camera_layout(&device);
+        let brush = TextBrush::new(
+            &device, 
+            &camera_layout, 
+            wgpu::TextureFormat::Rgba8UnormSrgb, 
+            None, 
+            wgpu::MultisampleState::default(), 
+            None, 
+            &asset_bundle, 
+            TextScale::default(), 
+            FontFallback::default().into()
+        ).expect("Failed to create text brush.");
+
+        let plain = TextBuilder::new(Some("Plain"), &font, "02:59", &brush)
+            .build(&device, &queue);
+        let shadowed = TextBuilder::new(Some("Shadowed"), &font, "02:59", &brush)
+            .with_shadow((2, -2), Vec4::new(0.0, 0.0, 0.0, 0.5))
+            .build(&device, &queue);
+
+        assert_eq!(plain.num_quads(), 5);
+        assert_eq!(plain.num_instances(), 5);
+        assert_eq!(shadowed.num_quads(), 5);
+        assert_eq!(shadowed.num_instances(), 10);
+    }
+
+    #[test]
+    fn relayout_matches_glyph_count() {
+        let font = FontArc::try_from_slice(include_bytes!("../../assets/fonts/nexon_lv2_gothic_medium.ttf"))
+            .expect("Failed to load font.");
+
+        let before = layout_characters(&font, &FontFallback::default(), "0%", 1.0);
+        let after = layout_characters(&font, &FontFallback::default(), "100%", 1.0);
+        assert_eq!(before.iter().flatten().count(), 2);
+        assert_eq!(after.iter().flatten().count(), 4);
+
+        // (한국어) 공백은 배치되지만 그려지지 않습니다.
+        // (English Translation) Spaces are laid out but not drawn.
+        let spaced = layout_characters(&font, &FontFallback::default(), "1 0", 1.0);
+        assert_eq!(spaced.len(), 3);
+        assert_eq!(spaced.iter().flatten().count(), 2);
+    }
+
+    #[test]
+    fn doubling_the_scale_doubles_glyph_height() {
+        let font = FontArc::try_from_slice(include_bytes!("../../assets/fonts/nexon_lv2_gothic_medium.ttf"))
+            .expect("Failed to load font.");
+
+        let normal = layout_characters(&font, &FontFallback::default(), "Ag", 1.0);
+        let doubled = layout_characters(&font, &FontFallback::default(), "Ag", 2.0);
+        for (a, b) in normal.iter().flatten().zip(doubled.iter().flatten()) {
+            assert!((b.1.size.y - 2.0 * a.1.size.y).abs() < 1e-6);
+            assert!((b.1.translation.y - 2.0 * a.1.translation.y).abs() < 1e-6);
+            assert_eq!(b.1.size.x, a.1.size.x);
+            assert_eq!(b.1.translation.x, a.1.translation.x);
+        }
+
+        assert_eq!(TextScale::new(200).norm(), 1.5);
+        assert_eq!(TextScale::new(10).norm(), 0.8);
+        assert_eq!(TextScale::default().norm(), 1.0);
+    }
+
+    #[test]
+    fn overlapping_texts_share_one_entry_per_glyph() {
+        let font = FontArc::try_from_slice(include_bytes!("../../assets/fonts/nexon_lv2_gothic_medium.ttf"))
+            .expect("Failed to load font.");
+        let fallback = FontFallback::default();
+        let mut glyph_cache = GlyphCache::new(GLYPH_CACHE_AREA);
+
+        // (한국어) 두 텍스트는 각자의 문자 목록을 가지지만 글리프 캐시는 공유합니다.
+        // (English Translation) The two texts have their own character lists but share the glyph cache.
+        let mut rasterized = 0;
+        let mut first = HashMap::new();
+        let mut second = HashMap::new();
+        let layout = layout_characters(&font, &fallback, "hello", 1.0);
+        cache_glyphs(&mut glyph_cache, &font, &fallback, &layout, &mut first, |_| rasterized += 1);
+        let layout = layout_characters(&font, &fallback, "world", 1.0);
+        cache_glyphs(&mut glyph_cache, &font, &fallback, &layout, &mut second, |_| rasterized += 1);
+
+        assert_eq!(first.len(), 4);
+        assert_eq!(second.len(), 5);
+        assert_eq!(glyph_cache.entries.len(), 7);
+        assert_eq!(rasterized, 7);
+    }
+
+    #[test]
+    fn glyph_cache_evicts_least_recently_used() {
+        let font = FontArc::try_from_slice(include_bytes!("../../assets/fonts/nexon_lv2_gothic_medium.ttf"))
+            .expect("Failed to load font.");
+        let key = |ch| GlyphKey::new(&font, font.glyph_id(ch), GLYPH_PX_SIZE);
+        let mut glyph_cache = GlyphCache::new(20);
+
+        glyph_cache.insert(key('a'), 10, 'a');
+        glyph_cache.insert(key('b'), 10, 'b');
+        assert_eq!(glyph_cache.get(&key('a')), Some('a'));
+
+        glyph_cache.insert(key('c'), 10, 'c');
+        assert_eq!(glyph_cache.entries.len(), 2);
+        assert_eq!(glyph_cache.get(&key('b')), None);
+        assert_eq!(glyph_cache.get(&key('a')), Some('a'));
+        assert_eq!(glyph_cache.get(&key('c')), Some('c'));
+    }
+}
@@ -0,0 +1,308 @@
+use std::sync::Arc;
+use std::collections::HashMap;
+
+use ab_glyph::FontArc;
+
+use crate::{
+    components::{
+        anchor::Anchor,
+        interpolation,
+        margin::Margin,
+        text::{Text, TextBrush, TextBuilder},
+    },
+    nodes::path,
+    system::shared::Shared,
+};
+
+
+
+/// #### 한국어 </br>
+/// 알림 메시지가 화면 밖에서 미끄러져 들어오는 시간 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The time it takes for the notification message to slide in from outside the screen. </br>
+/// 
+pub const TOAST_SLIDE_SEC: f64 = 0.25;
+
+/// #### 한국어 </br>
+/// 알림 메시지가 사라지는 시간 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The time it takes for the notification message to fade out. </br>
+/// 
+pub const TOAST_FADE_SEC: f64 = 0.5;
+
+/// #### 한국어 </br>
+/// 알림 메시지가 유지되는 기본 시간 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The default time the notification message is held. </br>
+/// 
+pub const TOAST_HOLD_SEC: f64 = 2.0;
+
+/// #### 한국어 </br>
+/// 화면에 동시에 보여지는 알림 메시지의 최대 개수 입니다. 넘친 경우 가장 오래된 메시지부터 버립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum number of notification messages shown at the same time. If it overflows, the oldest messages are dropped first. </br>
+/// 
+const MAX_TOASTS: usize = 5;
+
+/// #### 한국어 </br>
+/// 쌓인 알림 메시지 사이의 간격 입니다. (픽셀) </br>
+/// 
+/// #### English (Translation) </br>
+/// The spacing between stacked notification messages. (pixels) </br>
+/// 
+const TOAST_SPACING: i32 = 40;
+
+/// #### 한국어 </br>
+/// 알림 메시지가 미끄러져 들어오는 거리 입니다. (픽셀) </br>
+/// 
+/// #### English (Translation) </br>
+/// The distance the notification message slides in. (pixels) </br>
+/// 
+const TOAST_SLIDE_DISTANCE: f32 = 320.0;
+
+
+
+/// #### 한국어 </br>
+/// 알림 메시지의 진행 단계 목록 입니다. </br>
+/// - 들어오기: 화면 밖에서 미끄러져 들어옵니다. </br>
+/// - 유지: 주어진 시간 동안 보여집니다. </br>
+/// - 사라지기: 점점 투명해집니다. </br>
+/// - 끝남: 대기열에서 제거됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A list of the phases of a notification message. </br>
+/// - SlideIn: Slides in from outside the screen. </br>
+/// - Hold: Shown for the given time. </br>
+/// - FadeOut: Gradually becomes transparent. </br>
+/// - Finished: Removed from the queue. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ToastPhase {
+    SlideIn,
+    Hold,
+    FadeOut,
+    Finished,
+}
+
+
+
+/// #### 한국어 </br>
+/// 알림 메시지가 나타난 후 경과한 시간과 유지 시간을 담고 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Contains the time elapsed since the notification message appeared and its hold time. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ToastLifetime {
+    elapsed: f64,
+    duration: f64,
+}
+
+impl ToastLifetime {
+    #[inline]
+    pub fn new(duration: f64) -> Self {
+        Self { elapsed: 0.0, duration: duration.max(0.0) }
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 시간만큼 경과한 시간을 증가시킵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Increases the elapsed time by the given time. </br>
+    /// 
+    #[inline]
+    pub fn advance(&mut self, elapsed_time: f64) {
+        self.elapsed += elapsed_time;
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 진행 단계를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the current phase. </br>
+    /// 
+    pub fn phase(&self) -> ToastPhase {
+        if self.elapsed < TOAST_SLIDE_SEC {
+            ToastPhase::SlideIn
+        } else if self.elapsed < TOAST_SLIDE_SEC + self.duration {
+            ToastPhase::Hold
+        } else if self.elapsed < TOAST_SLIDE_SEC + self.duration + TOAST_FADE_SEC {
+            ToastPhase::FadeOut
+        } else {
+            ToastPhase::Finished
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 알림 메시지가 화면 밖으로 밀려난 정도를 반환합니다. (`1.0`: 화면 밖, `0.0`: 제자리) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns how far the notification message is pushed outside the screen. (`1.0`: outside, `0.0`: in place) </br>
+    /// 
+    #[inline]
+    pub fn slide(&self) -> f32 {
+        1.0 - interpolation::f64::smooth_step(self.elapsed.min(TOAST_SLIDE_SEC), TOAST_SLIDE_SEC) as f32
+    }
+
+    /// #### 한국어 </br>
+    /// 알림 메시지의 알파 값을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the alpha value of the notification message. </br>
+    /// 
+    #[inline]
+    pub fn alpha(&self) -> f32 {
+        let fade = (self.elapsed - TOAST_SLIDE_SEC - self.duration).max(0.0);
+        1.0 - interpolation::f64::linear(fade, TOAST_FADE_SEC) as f32
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 모든 게임 장면 위에 그려지는 짧은 알림 메시지 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A short notification message drawn on top of every game scene. </br>
+/// 
+#[derive(Debug)]
+pub struct Toast {
+    lifetime: ToastLifetime,
+    text: Text,
+}
+
+impl Toast {
+    /// #### 한국어 </br>
+    /// 주어진 메시지를 알림 대기열에 추가합니다. 메시지는 주어진 시간(초) 동안 유지된 후 사라집니다. </br>
+    /// 글꼴이 아직 불러와지지 않은 경우 메시지를 무시합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds the given message to the notification queue. The message is held for the given time (seconds) and then fades out. </br>
+    /// If the fonts have not been loaded yet, the message is ignored. </br>
+    /// 
+    pub fn push(shared: &mut Shared, message: &str, duration: f64) {
+        let fonts = match shared.get::<Arc<HashMap<String, FontArc>>>() {
+            Some(fonts) => fonts,
+            None => {
+                log::warn!("Fonts are not loaded yet. Ignoring the notification: {}", message);
+                return;
+            }
+        };
+        let font = fonts.get(path::NEXON_LV2_GOTHIC_MEDIUM_PATH)
+            .expect("A registered font could not be found.");
+        let device = shared.get::<Arc<wgpu::Device>>().unwrap();
+        let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+        let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
+
+        let text = TextBuilder::new(Some("Toast"), font, message, text_brush)
+            .with_anchor(Anchor::new(0.9, 0.6, 0.85, 1.0))
+            .with_color((1.0, 1.0, 1.0, 0.0).into())
+            .with_shadow((2, 2), (0.0, 0.0, 0.0, 1.0).into())
+            .build(device, queue);
+        let toast = Self { lifetime: ToastLifetime::new(duration), text };
+
+        match shared.get_mut::<ToastQueue>() {
+            Some(toasts) => toasts.push(toast),
+            None => {
+                let mut toasts = ToastQueue::default();
+                toasts.push(toast);
+                shared.push(toasts);
+            }
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 화면에 보여지는 알림 메시지 대기열 입니다. 가장 최근의 메시지가 맨 위에 쌓입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The queue of notification messages shown on the screen. The most recent message is stacked at the top. </br>
+/// 
+#[derive(Debug, Default)]
+pub struct ToastQueue(Vec<Toast>);
+
+impl ToastQueue {
+    fn push(&mut self, toast: Toast) {
+        if self.0.len() >= MAX_TOASTS {
+            self.0.remove(0);
+        }
+        self.0.push(toast);
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    #[inline]
+    pub fn texts(&self) -> impl Iterator<Item = &Text> {
+        self.0.iter().map(|toast| &toast.text)
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 알림 메시지들의 경과 시간을 갱신하고, 끝난 메시지를 제거한 뒤 위치와 알파 값을 갱신합니다. </br>
+/// 게임이 일시정지 되어도 알림 메시지는 계속 진행되도록 실제로 경과한 시간을 사용해야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Updates the elapsed time of the notification messages, removes finished messages, and then updates their positions and alpha values. </br>
+/// The actual elapsed time must be used so that notification messages keep going even while the game is paused. </br>
+/// 
+pub fn update(shared: &mut Shared, elapsed_time: f64) {
+    let toasts = match shared.pop::<ToastQueue>() {
+        Some(toasts) => toasts,
+        None => return,
+    };
+    let mut toasts = toasts.0;
+    toasts.iter_mut().for_each(|toast| toast.lifetime.advance(elapsed_time));
+    toasts.retain(|toast| toast.lifetime.phase() != ToastPhase::Finished);
+
+    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+    for (depth, toast) in toasts.iter().rev().enumerate() {
+        let offset_y = -(depth as i32) * TOAST_SPACING;
+        let offset_x = (toast.lifetime.slide() * TOAST_SLIDE_DISTANCE) as i32;
+        let alpha = toast.lifetime.alpha();
+        toast.text.update(queue, |data| {
+            data.margin = Margin::new(offset_y, offset_x, offset_y, offset_x);
+            data.color.w = alpha;
+        });
+    }
+
+    if !toasts.is_empty() {
+        shared.push(ToastQueue(toasts));
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn toast_lifetime_advances_through_phases() {
+        let mut lifetime = ToastLifetime::new(2.0);
+        assert_eq!(lifetime.phase(), ToastPhase::SlideIn);
+        assert_eq!(lifetime.slide(), 1.0);
+        assert_eq!(lifetime.alpha(), 1.0);
+
+        lifetime.advance(TOAST_SLIDE_SEC);
+        assert_eq!(lifetime.phase(), ToastPhase::Hold);
+        assert_eq!(lifetime.slide(), 0.0);
+        assert_eq!(lifetime.alpha(), 1.0);
+
+        lifetime.advance(2.0 + 0.5 * TOAST_FADE_SEC);
+        assert_eq!(lifetime.phase(), ToastPhase::FadeOut);
+        assert!((lifetime.alpha() - 0.5).abs() < 1e-6);
+
+        lifetime.advance(0.5 * TOAST_FADE_SEC);
+        assert_eq!(lifetime.phase(), ToastPhase::Finished);
+        assert_eq!(lifetime.alpha(), 0.0);
+    }
+}
@@ -319,4 +319,56 @@ impl Projection {
             }
         }
     }
+
+    /// #### 한국어 </br>
+    /// 두 투영 사이를 `t`(0.0 ~ 1.0) 만큼 선형 보간합니다. </br>
+    /// 두 투영의 종류가 다른 경우 보간하지 않고 `t`가 1.0보다 작으면 `a`를, 그렇지 않으면 `b`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Linearly interpolates between two projections by `t` (0.0 ~ 1.0). </br>
+    /// If the two projections are of different kinds, returns `a` if `t` is less than 1.0, otherwise `b`, without interpolating. </br>
+    /// 
+    pub fn lerp(a: Self, b: Self, t: f32) -> Self {
+        #[inline]
+        fn mix(a: f32, b: f32, t: f32) -> f32 {
+            a + (b - a) * t
+        }
+
+        let t = t.clamp(0.0, 1.0);
+        match (a, b) {
+            (Self::Orthographic(a), Self::Orthographic(b)) => Self::new_ortho(
+                mix(a.top, b.top, t), 
+                mix(a.left, b.left, t), 
+                mix(a.bottom, b.bottom, t), 
+                mix(a.right, b.right, t), 
+                mix(a.near, b.near, t), 
+                mix(a.far, b.far, t)
+            ),
+            (Self::Perspective(a), Self::Perspective(b)) => Self::Perspective(Perspective {
+                fov_y: mix(a.fov_y, b.fov_y, t),
+                aspect_ratio: mix(a.aspect_ratio, b.aspect_ratio, t),
+                near: mix(a.near, b.near, t),
+                far: mix(a.far, b.far, t),
+            }),
+            _ => if t < 1.0 { a } else { b },
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn lerp_returns_end_points() {
+        let a = Projection::new_ortho(1.0, -2.0, -1.0, 2.0, 0.0, 1000.0);
+        let b = Projection::new_ortho(4.0, -8.0, -2.0, 8.0, 0.0, 500.0);
+        assert_eq!(Projection::lerp(a, b, 0.0), a);
+        assert_eq!(Projection::lerp(a, b, 1.0), b);
+        assert_eq!(
+            Projection::lerp(a, b, 0.5), 
+            Projection::new_ortho(2.5, -5.0, -1.5, 5.0, 0.0, 750.0)
+        );
+    }
 }
@@ -1,7 +1,10 @@
+use std::hash::Hash;
 use std::mem::size_of;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex, MutexGuard};
 
 use winit::dpi::PhysicalPosition; 
+use winit::keyboard::KeyCode;
 use glam::{Mat4, Vec4, Vec3, Quat};
 use bytemuck::{Pod, Zeroable, offset_of};
 
@@ -11,9 +14,12 @@ use crate::{
         collider2d::Collider2d, 
         anchor::Anchor, 
         margin::Margin, 
-        camera::GameCamera, 
+        camera::{GameCamera, Viewport}, 
+        text::{Text, TextBrush}, 
+        focus::{FocusRing, FocusAction}, 
+        interpolation, 
     },
-    render::shader::WgslDecoder, 
+    render::{shader::WgslDecoder, tracker::Tracked}, 
     system::error::AppResult, 
 };
 
@@ -240,6 +246,7 @@ pub struct UiObject {
     buffer: wgpu::Buffer,
     bind_group: wgpu::BindGroup,
     pub data: Mutex<UiData>,
+    _tracked: Tracked,
 }
 
 impl UiObject {
@@ -294,6 +301,7 @@ impl UiObject {
             buffer, 
             bind_group, 
             data: data.into(), 
+            _tracked: Tracked::new(1, 1), 
         }
     }
 
@@ -327,29 +335,725 @@ impl UiObject {
 
 impl Collider2d<(&PhysicalPosition<f64>, &GameCamera)> for UiObject {
     fn test(&self, other: &(&PhysicalPosition<f64>, &GameCamera)) -> bool {
-        let (pos, view, scale) = {
+        let (view, scale) = {
             let guard = other.1.data.lock().expect("Failed to access variable.");
-            (other.0, guard.viewport, guard.scale_factor)
+            (guard.viewport, guard.scale_factor)
         };
         
         let guard = self.data.lock().expect("Failed to access variable.");
-        let anchor = guard.anchor;
-        let margin = guard.margin;
+        return hit_test(&guard.anchor, &guard.margin, &view, scale, other.0);
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 커서 위치가 주어진 기준점과 여백으로 배치된 사용자 인터페이스 영역 안에 있는 경우 `true`를 반환합니다. </br>
+/// 커서 위치(왼쪽 아래가 원점)와 뷰포트는 물리 픽셀 단위이고 여백은 논리 픽셀 단위이므로, 여백에만 화면 배율을 곱합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns `true` if the cursor position is inside the user interface area placed with the given anchor and margin. </br>
+/// The cursor position (the origin is at the bottom left) and the viewport are in physical pixels 
+/// and the margin is in logical pixels, so only the margin is multiplied by the screen scale factor. </br>
+/// 
+pub fn hit_test(
+    anchor: &Anchor, 
+    margin: &Margin, 
+    viewport: &Viewport, 
+    scale_factor: f32, 
+    cursor: &PhysicalPosition<f64>
+) -> bool {
+    let top = viewport.y + anchor.top() * viewport.height + margin.top() as f32 * scale_factor;
+    let left = viewport.x + anchor.left() * viewport.width + margin.left() as f32 * scale_factor;
+    let bottom = viewport.y + anchor.bottom() * viewport.height + margin.bottom() as f32 * scale_factor;
+    let right = viewport.x + anchor.right() * viewport.width + margin.right() as f32 * scale_factor;
+
+    let x = cursor.x as f32;
+    let y = cursor.y as f32;
+
+    return left <= x && x <= right
+    && bottom <= y && y <= top;
+}
+
+
+
+/// #### 한국어 </br>
+/// 가로 트랙 위에서 손잡이를 움직여 `0.0`~`1.0` 사이의 값을 조절하는 사용자 인터페이스입니다. </br>
+/// 트랙의 범위는 손잡이 기준점으로부터의 여백(`min`~`max`)으로 나타냅니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A user interface that adjusts a value between `0.0` and `1.0` by moving a handle along a horizontal track. </br>
+/// The range of the track is given as margins (`min` to `max`) from the anchor of the handle. </br>
+/// 
+#[derive(Debug)]
+pub struct Slider {
+    min: i32, 
+    max: i32, 
+    handle_width: i32, 
+    value: Mutex<f32>, 
+    grabbed: Mutex<bool>, 
+    pub handle: UiObject, 
+}
+
+impl Slider {
+    /// #### 한국어 </br>
+    /// 새로운 슬라이더를 생성합니다. </br>
+    /// 손잡이는 [`Slider::position`]으로 계산된 위치에 생성되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a new slider. </br>
+    /// The handle must be created at the position calculated by [`Slider::position`]. </br>
+    /// 
+    #[inline]
+    pub fn new(handle: UiObject, min: i32, max: i32, handle_width: i32, value: f32) -> Self {
+        debug_assert!(min < max, "The minimum value of the track must be less than the maximum value!");
+        Self { 
+            min, 
+            max, 
+            handle_width, 
+            value: value.clamp(0.0, 1.0).into(), 
+            grabbed: false.into(), 
+            handle 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 값에 해당하는 트랙 위의 위치(여백)를 계산합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Calculates the position (margin) on the track corresponding to the given value. </br>
+    /// 
+    #[inline]
+    pub fn position(min: i32, max: i32, value: f32) -> i32 {
+        let delta = (max - min) as f32 * value.clamp(0.0, 1.0);
+        return min + delta as i32;
+    }
+
+    /// #### 한국어 </br>
+    /// 화면 위의 커서 위치를 트랙 위의 위치(여백)와 값으로 변환합니다. </br>
+    /// 트랙을 벗어난 위치는 트랙의 양 끝으로 제한됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Converts the cursor position on the screen to a position (margin) on the track and a value. </br>
+    /// Positions outside the track are clamped to both ends of the track. </br>
+    /// 
+    fn cursor_to_value(cursor_x: f32, origin: f32, scale: f32, min: i32, max: i32) -> (i32, f32) {
+        let pos = (cursor_x - origin).clamp(
+            min as f32 * scale, 
+            max as f32 * scale
+        ) / scale;
+        let pos = pos as i32;
+        let value = (pos - min) as f32 / (max - min) as f32;
+        return (pos, value);
+    }
+
+    #[inline]
+    pub fn is_grabbed(&self) -> bool {
+        *self.grabbed.lock().expect("Failed to access variable.")
+    }
+
+    /// #### 한국어 </br>
+    /// 손잡이를 잡습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Grabs the handle. </br>
+    /// 
+    #[inline]
+    pub fn grab(&self) {
+        *self.grabbed.lock().expect("Failed to access variable.") = true;
+    }
+
+    /// #### 한국어 </br>
+    /// 손잡이를 놓습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Releases the handle. </br>
+    /// 
+    #[inline]
+    pub fn release(&self) {
+        *self.grabbed.lock().expect("Failed to access variable.") = false;
+    }
+
+    /// #### 한국어 </br>
+    /// 슬라이더의 값을 설정하고 손잡이의 위치를 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the value of the slider and updates the position of the handle. </br>
+    /// 
+    pub fn set_value(&self, queue: &wgpu::Queue, value: f32) {
+        let value = value.clamp(0.0, 1.0);
+        let pos = Self::position(self.min, self.max, value);
+        self.move_handle(queue, pos);
+        *self.value.lock().expect("Failed to access variable.") = value;
+    }
+
+    /// #### 한국어 </br>
+    /// 손잡이가 잡혀 있는 경우 커서 위치로 손잡이를 옮기고 새로운 값을 반환합니다. </br>
+    /// 손잡이가 잡혀 있지 않은 경우 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// If the handle is grabbed, moves the handle to the cursor position and returns the new value. </br>
+    /// Returns `None` if the handle is not grabbed. </br>
+    /// 
+    pub fn handle_drag(
+        &self, 
+        queue: &wgpu::Queue, 
+        cursor: &PhysicalPosition<f64>, 
+        camera: &GameCamera
+    ) -> Option<f32> {
+        if !self.is_grabbed() {
+            return None;
+        }
+
+        let (scale, view) = {
+            let guard = camera.data.lock().expect("Failed to access variable.");
+            (guard.scale_factor, guard.viewport)
+        };
+        let anchor = { self.handle.data.lock().expect("Failed to access variable.").anchor };
+        let origin = view.x + anchor.left() * view.width;
+
+        let (pos, value) = Self::cursor_to_value(cursor.x as f32, origin, scale, self.min, self.max);
+        self.move_handle(queue, pos);
+        *self.value.lock().expect("Failed to access variable.") = value;
+        return Some(value);
+    }
+
+    #[inline]
+    fn move_handle(&self, queue: &wgpu::Queue, pos: i32) {
+        let half_width = self.handle_width / 2;
+        self.handle.update(queue, |data| {
+            data.margin.set_left(pos - half_width);
+            data.margin.set_right(pos + half_width);
+        });
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 모달 창이 나타나거나 사라지는 애니메이션의 기본 지속 시간입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The default duration of the animation in which a modal window appears or disappears. </br>
+/// 
+pub const MODAL_ANIMATION_SEC: f64 = 0.2;
+
+
+
+/// #### 한국어 </br>
+/// 모달 창의 표시 상태 목록입니다. 애니메이션 중인 상태는 경과 시간을 담고 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// List of display states of the modal window. Animating states contain the elapsed time. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ModalState {
+    Hidden, 
+    Showing(f64), 
+    Shown, 
+    Hiding(f64), 
+}
+
+impl ModalState {
+    /// #### 한국어 </br>
+    /// 주어진 지속 시간에 따른 창의 배율을 반환합니다. (0.0 ~ 1.0) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the scale of the window for the given duration. (0.0 ~ 1.0) </br>
+    /// 
+    pub fn scale(self, duration: f64) -> f32 {
+        match self {
+            Self::Hidden => 0.0, 
+            Self::Showing(timer) => interpolation::f64::smooth_step(timer, duration) as f32, 
+            Self::Shown => 1.0, 
+            Self::Hiding(timer) => 1.0 - interpolation::f64::smooth_step(timer, duration) as f32, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 애니메이션을 진행한 상태를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the state with the animation advanced. </br>
+    /// 
+    fn advance(self, elapsed_time: f64, duration: f64) -> Self {
+        match self {
+            Self::Showing(timer) if timer + elapsed_time >= duration => Self::Shown, 
+            Self::Showing(timer) => Self::Showing(timer + elapsed_time), 
+            Self::Hiding(timer) if timer + elapsed_time >= duration => Self::Hidden, 
+            Self::Hiding(timer) => Self::Hiding(timer + elapsed_time), 
+            state => state, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 창이 나타나기 시작한 상태를 반환합니다. </br>
+    /// 사라지는 중인 경우 현재 배율에서 이어서 나타납니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the state in which the window starts to appear. </br>
+    /// If it is disappearing, it appears again from the current scale. </br>
+    /// 
+    fn show(self, duration: f64) -> Self {
+        match self {
+            Self::Hidden => Self::Showing(0.0), 
+            Self::Hiding(timer) => Self::Showing((duration - timer).max(0.0)), 
+            state => state, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 창이 사라지기 시작한 상태를 반환합니다. </br>
+    /// 나타나는 중인 경우 현재 배율에서 이어서 사라집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the state in which the window starts to disappear. </br>
+    /// If it is appearing, it disappears again from the current scale. </br>
+    /// 
+    fn hide(self, duration: f64) -> Self {
+        match self {
+            Self::Shown => Self::Hiding(0.0), 
+            Self::Showing(timer) => Self::Hiding((duration - timer).max(0.0)), 
+            state => state, 
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 알파 값이 목표 값까지 부드럽게 변하는 시간표 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A timeline in which the alpha value changes smoothly up to the target value. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct DimTimeline {
+    from: f32, 
+    to: f32, 
+    timer: f64, 
+    duration: f64, 
+}
+
+impl DimTimeline {
+    /// #### 한국어 </br>
+    /// 현재 알파 값을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the current alpha value. </br>
+    /// 
+    fn alpha(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return self.to;
+        }
+        let delta = interpolation::f64::smooth_step(self.timer, self.duration) as f32;
+        return self.from + (self.to - self.from) * delta;
+    }
+
+    /// #### 한국어 </br>
+    /// 애니메이션을 진행한 시간표를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the timeline with the animation advanced. </br>
+    /// 
+    fn advance(self, elapsed_time: f64) -> Self {
+        Self { timer: (self.timer + elapsed_time).min(self.duration), ..self }
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 알파 값에서 주어진 목표 값까지 변하는 시간표를 반환합니다. </br>
+    /// 이미 같은 목표 값으로 변하는 중인 경우 그대로 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns a timeline that changes from the current alpha value to the given target value. </br>
+    /// If it is already changing to the same target value, it is returned as is. </br>
+    /// 
+    fn retarget(self, to: f32, duration: f64) -> Self {
+        if self.to == to {
+            return self;
+        }
+        Self { from: self.alpha(), to, timer: 0.0, duration }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 모달 창이 열릴 때 뒤의 장면을 어둡게 만드는 화면 전체 크기의 오버레이 입니다. </br>
+/// 알파 값은 `show` 함수로 최대 값까지 올라가고, `hide` 함수로 `0`까지 내려갑니다. </br>
+/// 애니메이션 도중에 방향이 바뀐 경우 현재 알파 값에서 이어서 변합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A full-screen overlay that dims the scene behind when a modal window opens. </br>
+/// The alpha value goes up to the maximum value with the `show` function, and down to `0` with the `hide` function. </br>
+/// If the direction changes during the animation, it continues from the current alpha value. </br>
+/// 
+#[derive(Debug)]
+pub struct DimOverlay {
+    overlay: UiObject, 
+    max_alpha: f32, 
+    timeline: DimTimeline, 
+}
+
+impl DimOverlay {
+    /// #### 한국어 </br>
+    /// 숨겨진 상태의 새로운 오버레이를 생성합니다. </br>
+    /// 오버레이는 화면 전체를 덮고 알파 값이 0인 상태로 생성되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a new overlay in the hidden state. </br>
+    /// The overlay must have been created covering the whole screen with an alpha value of 0. </br>
+    /// 
+    pub fn new(overlay: UiObject, max_alpha: f32) -> Self {
+        Self { 
+            overlay, 
+            max_alpha, 
+            timeline: DimTimeline { from: 0.0, to: 0.0, timer: 0.0, duration: 0.0 }, 
+        }
+    }
+
+    #[inline]
+    pub fn alpha(&self) -> f32 {
+        self.timeline.alpha()
+    }
+
+    #[inline]
+    pub fn is_hidden(&self) -> bool {
+        self.timeline.to == 0.0 && self.alpha() == 0.0
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 시간 동안 장면을 어둡게 만드는 애니메이션을 시작합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Starts the animation that dims the scene over the given duration. </br>
+    /// 
+    #[inline]
+    pub fn show(&mut self, duration: f64) {
+        self.timeline = self.timeline.retarget(self.max_alpha, duration);
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 시간 동안 장면을 원래대로 밝게 만드는 애니메이션을 시작합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Starts the animation that brightens the scene back over the given duration. </br>
+    /// 
+    #[inline]
+    pub fn hide(&mut self, duration: f64) {
+        self.timeline = self.timeline.retarget(0.0, duration);
+    }
+
+    /// #### 한국어 </br>
+    /// 애니메이션을 진행하고 오버레이의 알파 값을 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances the animation and updates the alpha value of the overlay. </br>
+    /// 
+    pub fn update(&mut self, queue: &wgpu::Queue, elapsed_time: f64) {
+        self.timeline = self.timeline.advance(elapsed_time);
+        let alpha = self.alpha();
+        self.overlay.update(queue, |data| data.color.w = alpha);
+    }
+
+    /// #### 한국어 </br>
+    /// 오버레이가 숨겨지지 않은 경우 오버레이를 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the overlay if it is not hidden. </br>
+    /// 
+    pub fn draw<'pass>(&'pass self, ui_brush: &'pass UiBrush, rpass: &mut wgpu::RenderPass<'pass>) {
+        if !self.is_hidden() {
+            ui_brush.draw(rpass, [&self.overlay].into_iter());
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 모달 창 바깥을 클릭했을 때의 동작 목록입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// List of actions when clicking outside the modal window. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OutsideClick {
+    #[default]
+    Block, 
+    Close, 
+}
+
+
+/// #### 한국어 </br>
+/// 모달 창이 클릭을 처리한 결과 목록입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// List of results of the modal window handling a click. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModalHit<K> {
+    Button(K), 
+    Blocked, 
+    Dismissed, 
+}
+
+
+
+/// #### 한국어 </br>
+/// 화면 위에 떠서 입력을 가로채는 모달 창 입니다. </br>
+/// 배경을 어둡게 하는 배경막, 창, 텍스트와 버튼들을 함께 배율이 0에서 커지거나 작아지는 애니메이션으로 보여줍니다. </br>
+/// 장면은 창의 내용(텍스트, 버튼)을 만들어 전달하고, 버튼의 동작만 처리합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A modal window that floats over the screen and captures input. </br>
+/// It shows a backdrop that dims the background, the window, texts and buttons together
+/// with an animation that scales them up from 0 or down to 0. </br>
+/// Scenes create the contents of the window (texts, buttons) and only handle the actions of the buttons. </br>
+/// 
+#[derive(Debug)]
+pub struct ModalWindow<K> {
+    backdrop: Option<DimOverlay>, 
+    pub window: UiObject, 
+    pub texts: Vec<Text>, 
+    pub buttons: HashMap<K, (UiObject, Text)>, 
+    focus: FocusRing<K>, 
+    outside_click: OutsideClick, 
+    duration: f64, 
+    state: ModalState, 
+}
+
+impl<K> ModalWindow<K> 
+where K: Copy + Eq + Hash {
+    /// #### 한국어 </br>
+    /// 숨겨진 상태의 새로운 모달 창을 생성합니다. </br>
+    /// 창과 내용은 배율이 0인 상태로 생성되어 있어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a new modal window in the hidden state. </br>
+    /// The window and its contents must have been created with a scale of 0. </br>
+    /// 
+    pub fn new(window: UiObject, texts: Vec<Text>, buttons: HashMap<K, (UiObject, Text)>) -> Self {
+        Self { 
+            backdrop: None, 
+            window, 
+            texts, 
+            buttons, 
+            focus: FocusRing::new(Vec::new()), 
+            outside_click: OutsideClick::default(), 
+            duration: MODAL_ANIMATION_SEC, 
+            state: ModalState::Hidden, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 창이 나타날 때 주어진 알파 값까지 어두워지는 배경막을 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds a backdrop that darkens up to the given alpha value when the window appears. </br>
+    /// 
+    #[inline]
+    pub fn with_backdrop(mut self, backdrop: UiObject, alpha: f32) -> Self {
+        self.backdrop = Some(DimOverlay::new(backdrop, alpha));
+        return self;
+    }
+
+    /// #### 한국어 </br>
+    /// 키보드로 포커스를 옮길 버튼의 순서를 설정합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Sets the order of the buttons to move the keyboard focus through. </br>
+    /// 
+    #[inline]
+    pub fn with_focus_order(mut self, order: Vec<K>) -> Self {
+        self.focus = FocusRing::new(order);
+        return self;
+    }
+
+    #[inline]
+    pub fn with_outside_click(mut self, outside_click: OutsideClick) -> Self {
+        self.outside_click = outside_click;
+        return self;
+    }
+
+    #[inline]
+    pub fn is_shown(&self) -> bool {
+        self.state == ModalState::Shown
+    }
+
+    #[inline]
+    pub fn is_hidden(&self) -> bool {
+        self.state == ModalState::Hidden
+    }
+
+    /// #### 한국어 </br>
+    /// 창이 나타나는 애니메이션을 시작합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Starts the animation in which the window appears. </br>
+    /// 
+    #[inline]
+    pub fn show(&mut self) {
+        self.state = self.state.show(self.duration);
+        self.focus.clear();
+        if let Some(backdrop) = self.backdrop.as_mut() {
+            backdrop.show(self.duration);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 창이 사라지는 애니메이션을 시작합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Starts the animation in which the window disappears. </br>
+    /// 
+    #[inline]
+    pub fn hide(&mut self) {
+        self.state = self.state.hide(self.duration);
+        if let Some(backdrop) = self.backdrop.as_mut() {
+            backdrop.hide(self.duration);
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 애니메이션을 진행하고 창과 내용의 배율, 배경막의 알파 값을 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances the animation and updates the scale of the window and its contents, 
+    /// and the alpha value of the backdrop. </br>
+    /// 
+    pub fn update(&mut self, queue: &wgpu::Queue, elapsed_time: f64) {
+        self.state = self.state.advance(elapsed_time, self.duration);
+
+        let scale = self.state.scale(self.duration);
+        if let Some(backdrop) = self.backdrop.as_mut() {
+            backdrop.update(queue, elapsed_time);
+        }
+        self.window.update(queue, |data| data.global_scale = (scale, scale, scale).into());
+        for text in self.texts.iter() {
+            text.update(queue, |data| data.scale = (scale, scale, scale).into());
+        }
+        for (ui, text) in self.buttons.values() {
+            ui.update(queue, |data| data.global_scale = (scale, scale, scale).into());
+            text.update(queue, |data| data.scale = (scale, scale, scale).into());
+        }
+        self.focus.highlight(queue, self.buttons.iter().map(|(tag, (ui, _))| (*tag, ui)));
+    }
+
+    /// #### 한국어 </br>
+    /// 창이 완전히 나타난 경우 눌린 키로 버튼 사이의 포커스를 옮기거나 포커스된 버튼을 활성화합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Once the window has fully appeared, moves the focus between the buttons 
+    /// or activates the focused button with the pressed key. </br>
+    /// 
+    pub fn handle_key(&mut self, queue: &wgpu::Queue, code: KeyCode) -> Option<FocusAction<K>> {
+        if !self.is_shown() {
+            return None;
+        }
+
+        let action = self.focus.handle_key(code);
+        if let Some(FocusAction::Moved(_)) = action {
+            self.focus.highlight(queue, self.buttons.iter().map(|(tag, (ui, _))| (*tag, ui)));
+        }
+        return action;
+    }
+
+    /// #### 한국어 </br>
+    /// 창이 입력을 가로채고 있는 경우 `true`를 반환합니다. </br>
+    /// 창이 완전히 사라지기 전까지는 모든 포인터 입력이 창 뒤의 장면으로 전달되지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if the window is capturing input. </br>
+    /// Until the window has fully disappeared, no pointer input reaches the scene behind it. </br>
+    /// 
+    #[inline]
+    pub fn captures_input(&self) -> bool {
+        !self.is_hidden()
+    }
+
+    /// #### 한국어 </br>
+    /// `Escape` 키 등으로 창을 닫습니다. 창이 완전히 나타난 경우에만 닫히며, 닫힌 경우 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Closes the window, e.g. with the `Escape` key. 
+    /// It only closes once the window has fully appeared, and returns `true` if it was closed. </br>
+    /// 
+    pub fn dismiss(&mut self) -> bool {
+        if !self.is_shown() {
+            return false;
+        }
+        self.hide();
+        return true;
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 마우스 커서 위치의 클릭을 처리합니다. </br>
+    /// 창이 입력을 가로채지 않는 경우 `None`을 반환하며, 이때에만 장면이 클릭을 처리해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Handles a click at the given mouse cursor position. </br>
+    /// Returns `None` if the window is not capturing input, and only then should the scene handle the click. </br>
+    /// 
+    pub fn hit_test(&mut self, cursor_pos: &PhysicalPosition<f64>, camera: &GameCamera) -> Option<ModalHit<K>> {
+        let button = self.buttons.iter()
+            .find(|(_, (ui, _))| ui.test(&(cursor_pos, camera)))
+            .map(|(tag, _)| *tag);
+        let inside = self.window.test(&(cursor_pos, camera));
+
+        let hit = resolve_hit(self.state, self.outside_click, button, inside);
+        if hit == Some(ModalHit::Dismissed) {
+            self.hide();
+        }
+        return hit;
+    }
+
+    /// #### 한국어 </br>
+    /// 배경막, 창, 버튼, 텍스트 순서로 모달 창을 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Draws the modal window in the order of backdrop, window, buttons and texts. </br>
+    /// 
+    pub fn draw<'pass>(
+        &'pass self, 
+        ui_brush: &'pass UiBrush, 
+        text_brush: &'pass TextBrush, 
+        rpass: &mut wgpu::RenderPass<'pass>
+    ) {
+        if let Some(backdrop) = self.backdrop.as_ref() {
+            backdrop.draw(ui_brush, rpass);
+        }
+        ui_brush.draw(rpass, [&self.window].into_iter());
+        ui_brush.draw(rpass, self.buttons.values().map(|(it, _)| it));
+        text_brush.draw(rpass, self.texts.iter());
+        text_brush.draw(rpass, self.buttons.values().map(|(_, it)| it));
+    }
+}
 
-        let top = view.y + anchor.top() * view.height + margin.top() as f32 * scale;
-        let left = view.x + anchor.left() * view.width + margin.left() as f32 * scale;
-        let bottom = view.y + anchor.bottom() * view.height + margin.bottom() as f32 * scale;
-        let right = view.x + anchor.right() * view.width + margin.right() as f32 * scale;
 
-        let x = pos.x as f32;
-        let y = pos.y as f32;
 
-        return left <= x && x <= right
-        && bottom <= y && y <= top;
+/// #### 한국어 </br>
+/// 모달 창의 상태와 클릭 위치로 클릭의 처리 결과를 결정합니다. </br>
+/// 창이 완전히 나타나기 전이나 버튼이 아닌 곳의 클릭은 막히며, 
+/// `OutsideClick::Close`인 경우 창 바깥(배경막)의 클릭은 창을 닫습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Decides the result of a click from the state of the modal window and the click position. </br>
+/// Clicks before the window has fully appeared or outside the buttons are blocked,
+/// and with `OutsideClick::Close`, clicks outside the window (on the backdrop) close it. </br>
+/// 
+fn resolve_hit<K>(state: ModalState, outside_click: OutsideClick, button: Option<K>, inside: bool) -> Option<ModalHit<K>> {
+    match state {
+        ModalState::Hidden => None, 
+        ModalState::Shown => match button {
+            Some(tag) => Some(ModalHit::Button(tag)), 
+            None if !inside && outside_click == OutsideClick::Close => Some(ModalHit::Dismissed), 
+            None => Some(ModalHit::Blocked), 
+        }, 
+        _ => Some(ModalHit::Blocked), 
     }
 }
 
 
+
 #[derive(Debug)]
 pub struct UiBrush {
     pipeline: wgpu::RenderPipeline,
@@ -579,3 +1283,147 @@ fn create_render_pipeline(
         }
     )
 }
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn hit_test_is_consistent_across_scale_factors() {
+        use crate::components::cursor::flip_cursor_position;
+
+        // (한국어) 화면 가운데에 놓인 논리 픽셀 100x40 크기의 버튼입니다.
+        // (English Translation) A button of 100x40 logical pixels placed at the center of the screen.
+        let anchor = Anchor::new(0.5, 0.5, 0.5, 0.5);
+        let margin = Margin::new(20, -50, -20, 50);
+
+        for scale_factor in [1.0, 1.5, 2.0] {
+            let viewport = Viewport {
+                width: 800.0 * scale_factor,
+                height: 600.0 * scale_factor,
+                ..Default::default()
+            };
+
+            // (한국어) 창의 왼쪽 위를 기준으로 한 논리 위치를 물리 위치로 바꾼 뒤 위아래로 뒤집습니다.
+            // (English Translation) Converts the logical position from the top left of the window to a physical position and flips it upside down.
+            let click = |x: f64, y: f64| flip_cursor_position(
+                &PhysicalPosition::new(x * scale_factor as f64, y * scale_factor as f64), 
+                viewport.height as f64
+            );
+
+            assert!(hit_test(&anchor, &margin, &viewport, scale_factor, &click(400.0, 300.0)));
+            assert!(hit_test(&anchor, &margin, &viewport, scale_factor, &click(445.0, 285.0)));
+            assert!(hit_test(&anchor, &margin, &viewport, scale_factor, &click(355.0, 315.0)));
+            assert!(!hit_test(&anchor, &margin, &viewport, scale_factor, &click(455.0, 300.0)));
+            assert!(!hit_test(&anchor, &margin, &viewport, scale_factor, &click(400.0, 325.0)));
+        }
+    }
+
+    #[test]
+    fn slider_track_extremes() {
+        const MIN: i32 = -240;
+        const MAX: i32 = 272;
+
+        assert_eq!(Slider::cursor_to_value(400.0 - 240.0, 400.0, 1.0, MIN, MAX), (MIN, 0.0));
+        assert_eq!(Slider::cursor_to_value(400.0 + 272.0, 400.0, 1.0, MIN, MAX), (MAX, 1.0));
+        assert_eq!(Slider::cursor_to_value(400.0 + 16.0, 400.0, 1.0, MIN, MAX), (16, 0.5));
+
+        // (한국어) 트랙을 벗어난 위치는 양 끝으로 제한됩니다.
+        // (English Translation) Positions outside the track are clamped to both ends.
+        assert_eq!(Slider::cursor_to_value(0.0, 400.0, 1.0, MIN, MAX), (MIN, 0.0));
+        assert_eq!(Slider::cursor_to_value(1000.0, 400.0, 1.0, MIN, MAX), (MAX, 1.0));
+
+        // (한국어) 화면 배율이 적용된 경우.
+        // (English Translation) When the screen scale factor is applied.
+        assert_eq!(Slider::cursor_to_value(800.0 - 480.0, 800.0, 2.0, MIN, MAX), (MIN, 0.0));
+        assert_eq!(Slider::cursor_to_value(800.0 + 544.0, 800.0, 2.0, MIN, MAX), (MAX, 1.0));
+    }
+
+    #[test]
+    fn slider_position_round_trip() {
+        const MIN: i32 = -240;
+        const MAX: i32 = 272;
+
+        assert_eq!(Slider::position(MIN, MAX, 0.0), MIN);
+        assert_eq!(Slider::position(MIN, MAX, 1.0), MAX);
+        assert_eq!(Slider::position(MIN, MAX, 1.5), MAX);
+        assert_eq!(Slider::position(MIN, MAX, 0.5), 16);
+    }
+
+    #[test]
+    fn modal_scale_timeline() {
+        const DURATION: f64 = MODAL_ANIMATION_SEC;
+
+        let mut state = ModalState::Hidden;
+        assert_eq!(state.scale(DURATION), 0.0);
+
+        state = state.show(DURATION);
+        assert_eq!(state, ModalState::Showing(0.0));
+        assert_eq!(state.scale(DURATION), 0.0);
+        state = state.advance(0.5 * DURATION, DURATION);
+        assert!((state.scale(DURATION) - 0.5).abs() < 1e-6);
+        state = state.advance(0.5 * DURATION, DURATION);
+        assert_eq!(state, ModalState::Shown);
+        assert_eq!(state.scale(DURATION), 1.0);
+
+        // (한국어) 나타나는 중에 숨기면 현재 배율에서 이어서 작아집니다.
+        // (English Translation) Hiding while appearing shrinks from the current scale.
+        let showing = ModalState::Showing(0.25 * DURATION);
+        let hiding = showing.hide(DURATION);
+        assert!((showing.scale(DURATION) - hiding.scale(DURATION)).abs() < 1e-6);
+
+        state = state.hide(DURATION);
+        assert_eq!(state.scale(DURATION), 1.0);
+        state = state.advance(DURATION, DURATION);
+        assert_eq!(state, ModalState::Hidden);
+        assert_eq!(state.hide(DURATION), ModalState::Hidden);
+    }
+
+    #[test]
+    fn dim_alpha_reaches_target() {
+        const DURATION: f64 = MODAL_ANIMATION_SEC;
+        const MAX_ALPHA: f32 = 0.5;
+
+        let mut timeline = DimTimeline { from: 0.0, to: 0.0, timer: 0.0, duration: 0.0 };
+        assert_eq!(timeline.alpha(), 0.0);
+
+        timeline = timeline.retarget(MAX_ALPHA, DURATION);
+        assert_eq!(timeline.alpha(), 0.0);
+        timeline = timeline.advance(0.5 * DURATION);
+        assert!((timeline.alpha() - 0.5 * MAX_ALPHA).abs() < 1e-6);
+        timeline = timeline.advance(DURATION);
+        assert_eq!(timeline.alpha(), MAX_ALPHA);
+
+        // (한국어) 같은 목표 값으로 다시 요청해도 애니메이션이 처음부터 시작되지 않습니다.
+        // (English Translation) Requesting the same target again does not restart the animation.
+        assert_eq!(timeline.retarget(MAX_ALPHA, DURATION), timeline);
+
+        // (한국어) 어두워지는 중에 숨기면 현재 알파 값에서 이어서 밝아집니다.
+        // (English Translation) Hiding while dimming brightens from the current alpha value.
+        let dimming = DimTimeline { from: 0.0, to: 0.0, timer: 0.0, duration: 0.0 }
+            .retarget(MAX_ALPHA, DURATION)
+            .advance(0.25 * DURATION);
+        let hiding = dimming.retarget(0.0, DURATION);
+        assert!((dimming.alpha() - hiding.alpha()).abs() < 1e-6);
+
+        timeline = timeline.retarget(0.0, DURATION).advance(2.0 * DURATION);
+        assert_eq!(timeline.alpha(), 0.0);
+    }
+
+    #[test]
+    fn modal_swallows_clicks_behind_it() {
+        // (한국어) 창 뒤에 있는 버튼 위치의 클릭은 창 안의 버튼에도, 창 안에도 해당하지 않습니다.
+        // (English Translation) A click at a button behind the window hits neither a window button nor the window.
+        let behind: Option<u8> = None;
+
+        assert_eq!(resolve_hit(ModalState::Hidden, OutsideClick::Block, behind, false), None);
+        assert_eq!(resolve_hit(ModalState::Showing(0.1), OutsideClick::Block, behind, false), Some(ModalHit::Blocked));
+        assert_eq!(resolve_hit(ModalState::Shown, OutsideClick::Block, behind, false), Some(ModalHit::Blocked));
+        assert_eq!(resolve_hit(ModalState::Hiding(0.1), OutsideClick::Close, behind, false), Some(ModalHit::Blocked));
+        assert_eq!(resolve_hit(ModalState::Shown, OutsideClick::Close, behind, false), Some(ModalHit::Dismissed));
+
+        assert_eq!(resolve_hit(ModalState::Shown, OutsideClick::Close, None::<u8>, true), Some(ModalHit::Blocked));
+        assert_eq!(resolve_hit(ModalState::Shown, OutsideClick::Close, Some(1u8), true), Some(ModalHit::Button(1)));
+    }
+}
@@ -1,6 +1,15 @@
-use serde::{Serialize, Deserialize};
+use std::sync::Arc;
+
+use std::fmt;
+
+use serde::{
+    Serialize, 
+    Deserialize, 
+    Deserializer, 
+    de::{self, EnumAccess, VariantAccess, Visitor},
+};
 use winit::{
-    window::Window,
+    window::{Window, CursorGrabMode},
     dpi::{
         PhysicalPosition, 
         PhysicalSize, 
@@ -10,8 +19,10 @@ use winit::{
 
 use crate::{
     game_err,
-    components::{sound::Volume, control::Control},
+    components::{sound::Volume, control::Control, table::{BoardSize, TableTheme}, text::TextScale},
+    system::shared::Shared,
     assets::interface::{AssetDecoder, AssetEncoder},
+    render::{adapter::GpuPreference, offscreen::RenderScale, sampler::TextureQuality},
     system::error::{AppResult, GameError},
 };
 
@@ -54,6 +65,21 @@ pub enum Resolution {
 }
 
 impl Resolution {
+    /// #### 한국어 </br>
+    /// 지원하는 모든 해상도 목록 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// A list of all supported resolutions. </br>
+    /// 
+    pub const ALL: [Self; 6] = [
+        Self::W800H600,
+        Self::W1024H768,
+        Self::W1152H864,
+        Self::W1280H960,
+        Self::W1400H1050,
+        Self::W1600H1200,
+    ];
+
     #[inline]
     pub fn downgrade(self) -> Option<Self> {
         match self {
@@ -94,11 +120,44 @@ impl Into<LogicalSize<u32>> for Resolution {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Settings {
     pub control: Control, 
+    #[serde(deserialize_with = "coerce_language")]
     pub language: Language,
+    #[serde(deserialize_with = "coerce_resolution")]
     pub resolution: Resolution,
+    #[serde(deserialize_with = "coerce_volume")]
     pub background_volume: Volume,
+    #[serde(deserialize_with = "coerce_volume")]
     pub effect_volume: Volume,
+    #[serde(deserialize_with = "coerce_volume")]
     pub voice_volume: Volume,
+    #[serde(default)]
+    pub skip_intro: bool, 
+    #[serde(default)]
+    pub record_results: bool, 
+    #[serde(default)]
+    pub table_theme: TableTheme, 
+    #[serde(default)]
+    pub text_scale: TextScale, 
+    #[serde(default)]
+    pub texture_quality: TextureQuality, 
+    #[serde(default)]
+    pub render_scale: RenderScale, 
+    #[serde(default)]
+    pub bloom: bool, 
+    #[serde(default)]
+    pub gpu_preference: GpuPreference, 
+    #[serde(default)]
+    pub suppress_gpu_warning: bool, 
+    #[serde(default)]
+    pub hide_cursor_in_game: bool, 
+    #[serde(default)]
+    pub custom_cursor: bool, 
+    #[serde(default)]
+    pub attract_timeout_sec: u32, 
+    #[serde(default)]
+    pub reduce_motion: bool, 
+    #[serde(default)]
+    pub board_size: BoardSize, 
 }
 
 impl Default for Settings {
@@ -111,17 +170,103 @@ impl Default for Settings {
             background_volume: Volume::new(80),
             effect_volume: Volume::new(100),
             voice_volume: Volume::new(60),
+            skip_intro: false, 
+            record_results: false, 
+            table_theme: TableTheme::default(), 
+            text_scale: TextScale::default(), 
+            texture_quality: TextureQuality::default(), 
+            render_scale: RenderScale::default(), 
+            bloom: false, 
+            gpu_preference: GpuPreference::default(), 
+            suppress_gpu_warning: false, 
+            hide_cursor_in_game: false, 
+            custom_cursor: false, 
+            attract_timeout_sec: 0, 
+            reduce_motion: false, 
+            board_size: BoardSize::default(), 
         }
     }
 }
 
+/// #### 한국어 </br>
+/// 설정 창에서 켜고 끌 수 있는 설정 목록입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a list of settings that can be turned on and off in the setting window. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SettingToggle {
+    HideCursorInGame, 
+    CustomCursor, 
+    ReduceMotion, 
+    SmallBoard, 
+}
 
+impl SettingToggle {
+    /// #### 한국어 </br>
+    /// 설정 창에 표시되는 순서대로 정렬된 모든 설정 목록입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// All settings, sorted in the order they are displayed in the setting window. </br>
+    /// 
+    pub const ALL: [Self; 4] = [
+        Self::HideCursorInGame, 
+        Self::CustomCursor, 
+        Self::ReduceMotion, 
+        Self::SmallBoard, 
+    ];
+
+    /// #### 한국어 </br>
+    /// 주어진 설정에서 이 설정이 켜져 있는 경우 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if this setting is turned on in the given settings. </br>
+    /// 
+    #[inline]
+    pub fn is_on(self, settings: &Settings) -> bool {
+        match self {
+            Self::HideCursorInGame => settings.hide_cursor_in_game, 
+            Self::CustomCursor => settings.custom_cursor, 
+            Self::ReduceMotion => settings.reduce_motion, 
+            Self::SmallBoard => settings.board_size == BoardSize::Small, 
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 설정에서 이 설정을 켜거나 끕니다. 다른 설정은 바뀌지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Turns this setting on or off in the given settings. Other settings are left unchanged. </br>
+    /// 
+    #[inline]
+    pub fn flip(self, settings: &mut Settings) {
+        match self {
+            Self::HideCursorInGame => settings.hide_cursor_in_game ^= true, 
+            Self::CustomCursor => settings.custom_cursor ^= true, 
+            Self::ReduceMotion => settings.reduce_motion ^= true, 
+            // (한국어) 보드 크기는 다음 게임 장면을 불러올 때 적용됩니다.
+            // (English Translation) The board size is applied when the next game scene is loaded.
+            Self::SmallBoard => settings.board_size = match settings.board_size {
+                BoardSize::Standard => BoardSize::Small, 
+                BoardSize::Small => BoardSize::Standard, 
+            }, 
+        }
+    }
+}
 
 /// #### 한국어 </br>
 /// 애플리케이션 설정의 디코더 입니다. </br>
+/// 직접 수정되거나 손상된 설정 파일을 읽을 때 실패하지 않도록 다음 값들을 보정합니다. </br>
+/// - 볼륨: `0`~`Volume::MAX` 범위로 제한합니다. </br>
+/// - 해상도: 알 수 없는 해상도는 가장 가까운 지원 해상도로 바꿉니다. </br>
+/// - 언어: 알 수 없는 언어는 `Unknown`으로 바꾸어 처음 설정 장면으로 진입하게 합니다. </br>
 /// 
 /// #### English (Translation) </br>
 /// This is a decoder for application settings. </br>
+/// Corrects the following values so that reading a hand-edited or corrupted settings file does not fail. </br>
+/// - Volume: Clamps it to the range `0` to `Volume::MAX`. </br>
+/// - Resolution: Replaces an unknown resolution with the nearest supported one. </br>
+/// - Language: Replaces an unknown language with `Unknown`, entering the first time setup scene. </br>
 /// 
 #[derive(Debug)]
 pub struct SettingsDecoder;
@@ -143,6 +288,151 @@ impl AssetDecoder for SettingsDecoder {
 
 
 
+/// #### 한국어 </br>
+/// 열거형 값의 이름만 읽어오는 방문자 입니다. </br>
+/// 알 수 없는 이름도 오류 없이 읽어오므로, 읽은 후에 보정할 수 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A visitor that only reads the name of an enum value. </br>
+/// Unknown names are also read without errors, so they can be corrected after reading. </br>
+/// 
+struct VariantName(String);
+
+impl<'de> Deserialize<'de> for VariantName {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_identifier(VariantNameVisitor)
+    }
+}
+
+struct VariantNameVisitor;
+
+impl<'de> Visitor<'de> for VariantNameVisitor {
+    type Value = VariantName;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("the name of an enum variant")
+    }
+
+    #[inline]
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(VariantName(v.to_string()))
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let (name, variant) = data.variant::<VariantName>()?;
+        variant.unit_variant()?;
+        Ok(name)
+    }
+}
+
+/// #### 한국어 </br>
+/// 주어진 크기와 가장 가까운 지원 해상도를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the supported resolution nearest to the given size. </br>
+/// 
+fn nearest_resolution(width: u32, height: u32) -> Resolution {
+    Resolution::ALL.into_iter()
+        .min_by_key(|&resolution| {
+            let size: LogicalSize<u32> = resolution.into();
+            size.width.abs_diff(width) + size.height.abs_diff(height)
+        })
+        .unwrap()
+}
+
+/// #### 한국어 </br>
+/// 해상도 이름(`W<너비>H<높이>`)에서 너비와 높이를 읽어옵니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads the width and height from the resolution name (`W<WIDTH>H<HEIGHT>`). </br>
+/// 
+fn parse_resolution_name(name: &str) -> Option<(u32, u32)> {
+    let (width, height) = name.strip_prefix('W')?.split_once('H')?;
+    Some((width.parse().ok()?, height.parse().ok()?))
+}
+
+/// #### 한국어 </br>
+/// 설정 파일의 언어를 읽어오고, 알 수 없는 언어는 `Unknown`으로 바꿉니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads the language of the settings file, replacing an unknown language with `Unknown`. </br>
+/// 
+fn coerce_language<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Language, D::Error> {
+    let VariantName(name) = deserializer.deserialize_enum("Language", &["Unknown", "Korean"], VariantNameVisitor)?;
+    match name.as_str() {
+        "Unknown" => Ok(Language::Unknown),
+        "Korean" => Ok(Language::Korean),
+        _ => {
+            log::warn!("Unknown language \"{}\" in the settings file. It is replaced with {:?}.", name, Language::Unknown);
+            Ok(Language::Unknown)
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 설정 파일의 해상도를 읽어오고, 알 수 없는 해상도는 가장 가까운 지원 해상도로 바꿉니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads the resolution of the settings file, replacing an unknown resolution with the nearest supported one. </br>
+/// 
+fn coerce_resolution<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Resolution, D::Error> {
+    const VARIANTS: &[&str] = &["W800H600", "W1024H768", "W1152H864", "W1280H960", "W1400H1050", "W1600H1200"];
+    let VariantName(name) = deserializer.deserialize_enum("Resolution", VARIANTS, VariantNameVisitor)?;
+    if let Some(idx) = VARIANTS.iter().position(|&it| it == name) {
+        return Ok(Resolution::ALL[idx]);
+    }
+
+    let resolution = match parse_resolution_name(&name) {
+        Some((width, height)) => nearest_resolution(width, height),
+        None => Resolution::default(),
+    };
+    log::warn!("Unknown resolution \"{}\" in the settings file. It is replaced with {:?}.", name, resolution);
+    Ok(resolution)
+}
+
+/// #### 한국어 </br>
+/// 설정 파일의 볼륨을 읽어오고, `0`~`Volume::MAX` 범위로 제한합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads the volume of the settings file, clamping it to the range `0` to `Volume::MAX`. </br>
+/// 
+fn coerce_volume<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Volume, D::Error> {
+    struct VolumeVisitor;
+
+    impl<'de> Visitor<'de> for VolumeVisitor {
+        type Value = i64;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a volume value")
+        }
+
+        #[inline]
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            Ok(v)
+        }
+
+        #[inline]
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            Ok(v.min(i64::MAX as u64) as i64)
+        }
+
+        #[inline]
+        fn visit_newtype_struct<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+            deserializer.deserialize_i64(self)
+        }
+    }
+
+    let value = deserializer.deserialize_newtype_struct("Volume", VolumeVisitor)?;
+    let clamped = value.clamp(0, Volume::MAX as i64);
+    if clamped != value {
+        log::warn!("The volume {} in the settings file is out of range. It is clamped to {}.", value, clamped);
+    }
+    Ok(Volume::new(clamped as u8))
+}
+
+
+
 /// #### 한국어 </br>
 /// 애플리케이션 설정의 인코더 입니다. </br>
 /// 
@@ -178,44 +468,170 @@ impl AssetEncoder for SettingsEncoder {
 
 
 
+/// #### 한국어 </br>
+/// 설정 창이 열렸을 때의 애플리케이션 설정입니다. </br>
+/// 설정 창은 공유 객체의 설정을 바로 바꾸어 변경 사항을 보여주며, 
+/// 취소하는 경우 이 설정으로 되돌립니다. 확인하는 경우에만 설정 파일에 저장됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Application settings at the time the setting window was opened. </br>
+/// The setting window changes the shared settings directly to preview the changes,
+/// and reverts to these settings when canceled. They are only saved to the settings file when confirmed. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SettingsSnapshot(Settings);
+
+impl SettingsSnapshot {
+    #[inline]
+    pub fn new(settings: &Settings) -> Self {
+        Self(*settings)
+    }
+
+    #[inline]
+    pub fn original(&self) -> &Settings {
+        &self.0
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 설정을 설정 창이 열렸을 때의 설정으로 되돌립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Reverts the given settings to the settings at the time the setting window was opened. </br>
+    /// 
+    #[inline]
+    pub fn restore(self, settings: &mut Settings) {
+        *settings = self.0;
+    }
+}
+
+/// #### 한국어 </br>
+/// 모든 설정을 기본값으로 되돌린 설정과, 되돌린 설정으로 새로 만든 설정 창의 스냅샷을 반환합니다. </br>
+/// 현재 모니터가 기본 해상도를 지원하지 않을 수 있으므로 해상도는 실제로 적용된 해상도를 유지합니다. </br>
+/// 초기화는 확정된 변경이므로 이후 설정 창을 취소하면 초기화된 설정으로 되돌아갑니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the settings reset to their default values, and a new snapshot of the setting window taken from them. </br>
+/// The resolution keeps the one actually applied, since the current monitor may not support the default resolution. </br>
+/// The reset is a confirmed change, so canceling the setting window afterwards reverts to the reset settings. </br>
+/// 
+pub fn reset_settings(applied: &Settings) -> (Settings, SettingsSnapshot) {
+    let settings = Settings {
+        resolution: applied.resolution, 
+        ..Default::default()
+    };
+    return (settings, SettingsSnapshot::new(&settings));
+}
+
+
+
+/// #### 한국어 </br>
+/// 모니터의 위치와 크기(물리 픽셀) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The position and size of a monitor. (physical pixels) </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonitorArea {
+    pub position: PhysicalPosition<i32>,
+    pub size: PhysicalSize<u32>,
+}
+
+impl MonitorArea {
+    /// #### 한국어 </br>
+    /// 애플리케이션 윈도우가 위치한 모니터의 영역을 가져옵니다. </br>
+    /// 운영체제가 모니터 정보를 알려주지 않는 경우 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the area of the monitor where the application window is located. </br>
+    /// Returns `None` if the operating system does not report the monitor information. </br>
+    /// 
+    #[inline]
+    pub fn of(window: &Window) -> Option<Self> {
+        window.current_monitor().map(|monitor| Self {
+            position: monitor.position(),
+            size: monitor.size(),
+        })
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 크기가 모니터 안에 들어가는 경우 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if the given size fits in the monitor. </br>
+    /// 
+    #[inline]
+    pub fn fits(&self, size: PhysicalSize<u32>) -> bool {
+        size.width <= self.size.width && size.height <= self.size.height
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 크기의 윈도우를 모니터 중앙에 놓는 위치를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the position that places a window of the given size at the center of the monitor. </br>
+    /// 
+    #[inline]
+    pub fn center(&self, size: PhysicalSize<u32>) -> PhysicalPosition<i32> {
+        let center_x = self.position.x + (self.size.width / 2) as i32;
+        let center_y = self.position.y + (self.size.height / 2) as i32;
+        PhysicalPosition::new(
+            center_x - (size.width / 2) as i32,
+            center_y - (size.height / 2) as i32
+        )
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 주어진 해상도가 현재 애플리케이션 윈도우가 위치한 모니터에 표시될 수 있는지 확인합니다. </br>
+/// 모니터 정보를 알 수 없는 경우 표시될 수 있다고 간주합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Checks whether the given resolution can be displayed on the monitor where the current application window is located. </br>
+/// If the monitor information is unknown, it is considered displayable. </br>
+/// 
+#[inline]
+pub fn is_resolution_supported(window: &Window, resolution: Resolution) -> bool {
+    let logical_size: LogicalSize<u32> = resolution.into();
+    let physical_size: PhysicalSize<u32> = logical_size.to_physical(window.scale_factor());
+    return MonitorArea::of(window).map_or(true, |monitor| monitor.fits(physical_size));
+}
+
 /// #### 한국어 </br>
 /// 애플리케이션 윈도우 크기를 설정합니다. </br>
+/// 주어진 해상도가 모니터보다 큰 경우 표시할 수 있는 해상도로 낮춥니다. </br>
+/// 모니터 정보를 알 수 없는 경우 윈도우를 화면 중앙으로 옮기지 않습니다. </br>
 /// <b>애플리케이션 윈도우 크기 조정에 실패한 경우 `GameError`를 반환합니다.</b></br>
 /// 
 /// #### English (Translation) </br>
 /// Sets the application window size. </br>
+/// If the given resolution is larger than the monitor, it is lowered to a resolution that can be displayed. </br>
+/// If the monitor information is unknown, the window is not moved to the center of the screen. </br>
 /// <b>Returns `GameError` if application window resizing fails.</b></br>
 /// 
 #[inline]
 pub fn set_window_size(window: &Window, resolution: Resolution) -> AppResult<Resolution> {
-    let monitor = window.current_monitor()
-        .ok_or_else(|| game_err!(
-            "Application window resize failed", 
-            "Unable to get information about the monitor where the current application window is located."
-        ))?;
-    
     let logical_size: LogicalSize<u32> = resolution.into();
     let physical_size: PhysicalSize<u32> = logical_size.to_physical(window.scale_factor());
-    if physical_size.width <= monitor.size().width 
-    && physical_size.height <= monitor.size().height {
-        if window.request_inner_size(physical_size).is_some() {
-            Err(game_err!(
-                "Application window resize failed",
-                "The application window cannot be resized."
-            ))
-        } else {
-            // (한국어) 애플리케이션 윈도우를 화면 중앙에 위치시킵니다.
-            // (English Translation) Centers the application window on the screen.
-            let monitor = window.current_monitor().unwrap();
-            let center_x = monitor.position().x + (monitor.size().width / 2) as i32;
-            let center_y = monitor.position().y + (monitor.size().height / 2) as i32;
-            window.set_outer_position(PhysicalPosition::new(
-                center_x - (physical_size.width / 2) as i32,
-                center_y - (physical_size.height / 2) as i32
-            ));
-            
-            Ok(resolution)
+    if is_resolution_supported(window, resolution) {
+        // (한국어) 
+        // 크기가 바로 적용된 경우 `Some`이 반환되고, 
+        // 그렇지 않은 경우 `Resized` 이벤트로 적용된 크기가 전달됩니다.
+        //
+        // (English Translation)
+        // `Some` is returned if the size was applied immediately, 
+        // otherwise the applied size is delivered by the `Resized` event.
+        //
+        let _ = window.request_inner_size(physical_size);
+
+        // (한국어) 애플리케이션 윈도우를 화면 중앙에 위치시킵니다.
+        // (English Translation) Centers the application window on the screen.
+        match MonitorArea::of(window) {
+            Some(monitor) => window.set_outer_position(monitor.center(physical_size)),
+            None => log::warn!("Unable to get information about the monitor. The window is not centered."),
         }
+        
+        Ok(resolution)
     } else {
         if let Some(resolution) = resolution.downgrade() {
             set_window_size(window, resolution)
@@ -227,3 +643,235 @@ pub fn set_window_size(window: &Window, resolution: Resolution) -> AppResult<Res
         }
     }
 }
+
+
+
+/// #### 한국어 </br>
+/// 공유 객체에 등록되어 있는 동안 운영체제 커서가 숨겨져 있고 창 안에 묶여 있음을 나타냅니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Indicates that the OS cursor is hidden and grabbed in the window while it is registered in the shared object. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CursorHidden;
+
+/// #### 한국어 </br>
+/// 커서를 창 안에 묶을 때 시도할 방식들을 우선순위 순서대로 반환합니다. </br>
+/// macOS는 커서를 제자리에 고정하는 방식만 지원하고, Windows는 창 안에 가두는 방식만 지원합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the modes to try when grabbing the cursor in the window, in order of priority. </br>
+/// macOS only supports locking the cursor in place, and Windows only supports confining it in the window. </br>
+/// 
+#[inline]
+fn cursor_grab_modes() -> [CursorGrabMode; 2] {
+    match cfg!(target_os = "macos") {
+        true => [CursorGrabMode::Locked, CursorGrabMode::Confined],
+        false => [CursorGrabMode::Confined, CursorGrabMode::Locked],
+    }
+}
+
+/// #### 한국어 </br>
+/// 주어진 함수로 커서를 묶는 방식들을 우선순위 순서대로 시도합니다. </br>
+/// 한 방식이 성공하면 나머지 방식은 시도하지 않으며, 모든 방식이 실패한 경우 `false`를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Tries the modes of grabbing the cursor with the given function, in order of priority. </br>
+/// The remaining modes are not tried once one succeeds, and `false` is returned if all of them fail. </br>
+/// 
+#[inline]
+fn grab_cursor<F, E>(mut grab: F) -> bool
+where F: FnMut(CursorGrabMode) -> Result<(), E> {
+    cursor_grab_modes().into_iter().any(|mode| grab(mode).is_ok())
+}
+
+/// #### 한국어 </br>
+/// 운영체제 커서를 숨기고 창 안에 묶거나, 다시 보이게 하고 풀어줍니다. </br>
+/// 이미 같은 상태인 경우 아무것도 하지 않습니다. 커서를 묶을 수 없는 플랫폼에서는 숨기기만 합니다. </br>
+/// 커서 위치는 계속 갱신되므로, 커서를 다시 보이게 하면 메뉴를 바로 조작할 수 있습니다. </br>
+/// 게임 커서를 사용하는 경우 운영체제 커서는 계속 숨겨진 상태로 둡니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Hides the OS cursor and grabs it in the window, or shows it again and releases it. </br>
+/// Does nothing if it is already in the same state. On platforms where the cursor cannot be grabbed, it is only hidden. </br>
+/// The cursor position keeps being updated, so menus can be used right away once the cursor is shown again. </br>
+/// If the game cursor is used, the OS cursor is kept hidden. </br>
+/// 
+pub fn set_cursor_hidden(shared: &mut Shared, hidden: bool) {
+    if hidden == shared.get::<CursorHidden>().is_some() {
+        return;
+    }
+
+    let custom_cursor = shared.get::<Settings>().is_some_and(|settings| settings.custom_cursor);
+    let window = shared.get::<Arc<Window>>().unwrap();
+    window.set_cursor_visible(!hidden && !custom_cursor);
+    if hidden {
+        let grabbed = grab_cursor(|mode| window.set_cursor_grab(mode));
+        if !grabbed {
+            log::warn!("The cursor cannot be grabbed on this platform. It is only hidden.");
+        }
+        shared.push(CursorHidden);
+    } else {
+        let _ = window.set_cursor_grab(CursorGrabMode::None);
+        shared.pop::<CursorHidden>();
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn window_is_centered_on_the_monitor() {
+        // (한국어) 주 모니터의 오른쪽에 놓인 2560x1440 크기의 모니터입니다.
+        // (English Translation) A 2560x1440 monitor placed to the right of the primary monitor.
+        let monitor = MonitorArea {
+            position: PhysicalPosition::new(1920, 0),
+            size: PhysicalSize::new(2560, 1440),
+        };
+        assert!(monitor.fits(PhysicalSize::new(1600, 1200)));
+        assert!(!monitor.fits(PhysicalSize::new(2400, 1800)));
+        assert_eq!(monitor.center(PhysicalSize::new(1600, 1200)), PhysicalPosition::new(1920 + 480, 120));
+    }
+
+    #[test]
+    fn canceling_changes_restores_settings() {
+        let original = Settings::default();
+        let snapshot = SettingsSnapshot::new(&original);
+
+        let mut settings = original;
+        settings.resolution = Resolution::W1600H1200;
+        settings.background_volume.set(10);
+        settings.voice_volume.set(0);
+        assert_ne!(settings, original);
+
+        snapshot.restore(&mut settings);
+        assert_eq!(settings, original);
+        assert_eq!(snapshot.original(), &original);
+    }
+
+    #[test]
+    fn resetting_restores_default_settings() {
+        let mut applied = Settings::default();
+        applied.language = Language::Korean;
+        applied.effect_volume.set(25);
+        applied.skip_intro = true;
+        assert_ne!(applied, Settings::default());
+
+        let (settings, snapshot) = reset_settings(&applied);
+        assert_eq!(settings, Settings::default());
+        assert_eq!(snapshot.original(), &settings);
+    }
+
+    #[test]
+    fn resetting_keeps_the_applied_resolution() {
+        // (한국어) 모니터가 기본 해상도를 지원하지 않아 다른 해상도가 적용된 경우입니다.
+        // (English Translation) The case where another resolution is applied because the monitor does not support the default resolution.
+        let mut applied = Settings::default();
+        applied.resolution = Resolution::W1280H960;
+        applied.background_volume.set(10);
+        assert_ne!(applied.resolution, Settings::default().resolution);
+
+        let (settings, snapshot) = reset_settings(&applied);
+        assert_eq!(settings.resolution, Resolution::W1280H960);
+        assert_eq!(settings.background_volume, Settings::default().background_volume);
+        assert_eq!(settings, Settings { resolution: Resolution::W1280H960, ..Default::default() });
+
+        // (한국어) 이후 설정 창을 취소해도 초기화된 설정으로 되돌아갑니다.
+        // (English Translation) Canceling the setting window afterwards reverts to the reset settings.
+        let mut changed = settings;
+        changed.voice_volume.set(0);
+        snapshot.restore(&mut changed);
+        assert_eq!(changed, settings);
+    }
+
+    #[test]
+    fn toggles_flip_only_their_own_setting() {
+        for toggle in SettingToggle::ALL {
+            let original = Settings::default();
+            let mut settings = original;
+            assert!(!toggle.is_on(&settings));
+
+            toggle.flip(&mut settings);
+            assert!(toggle.is_on(&settings));
+            for other in SettingToggle::ALL.into_iter().filter(|&it| it != toggle) {
+                assert_eq!(other.is_on(&settings), other.is_on(&original));
+            }
+
+            // (한국어) 설정 창을 취소하면 켜기 전의 상태로 되돌아갑니다.
+            // (English Translation) Canceling the setting window reverts to the state before turning it on.
+            SettingsSnapshot::new(&original).restore(&mut settings);
+            assert!(!toggle.is_on(&settings));
+        }
+    }
+
+    #[test]
+    fn out_of_range_values_are_coerced_when_decoding() {
+        let mut settings = Settings::default();
+        settings.language = Language::Korean;
+        settings.resolution = Resolution::W1280H960;
+        let buf = SettingsEncoder.encode(&settings).unwrap();
+        let text = String::from_utf8(buf).unwrap()
+            .replace("background_volume: Volume(80)", "background_volume: Volume(250)")
+            .replace("effect_volume: Volume(100)", "effect_volume: Volume(300)")
+            .replace("voice_volume: Volume(60)", "voice_volume: Volume(-5)")
+            .replace("resolution: W1280H960", "resolution: W1920H1080")
+            .replace("language: Korean", "language: French");
+
+        let decoded = SettingsDecoder.decode(text.as_bytes()).unwrap();
+        assert_eq!(decoded.background_volume, Volume::new(Volume::MAX));
+        assert_eq!(decoded.effect_volume, Volume::new(Volume::MAX));
+        assert_eq!(decoded.voice_volume, Volume::new(0));
+        assert_eq!(decoded.resolution, Resolution::W1600H1200);
+        assert_eq!(decoded.language, Language::Unknown);
+    }
+
+    #[test]
+    fn valid_settings_are_decoded_unchanged() {
+        let mut settings = Settings::default();
+        settings.language = Language::Korean;
+        settings.resolution = Resolution::W1024H768;
+        settings.voice_volume.set(Volume::MAX);
+        let buf = SettingsEncoder.encode(&settings).unwrap();
+        assert_eq!(SettingsDecoder.decode(&buf).unwrap(), settings);
+
+        assert_eq!(nearest_resolution(1000, 700), Resolution::W1024H768);
+        assert_eq!(parse_resolution_name("Fullscreen"), None);
+    }
+
+    #[test]
+    fn cursor_grab_falls_back_to_the_other_mode() {
+        let [first, second] = cursor_grab_modes();
+        assert_ne!(first, second);
+        assert!(!cursor_grab_modes().contains(&CursorGrabMode::None));
+
+        // (한국어) 첫 번째 방식이 실패하면 두 번째 방식을 시도합니다.
+        // (English Translation) If the first mode fails, the second mode is tried.
+        let mut tried = Vec::new();
+        let grabbed = grab_cursor(|mode| {
+            tried.push(mode);
+            if mode == first { Err(()) } else { Ok(()) }
+        });
+        assert!(grabbed);
+        assert_eq!(tried, vec![first, second]);
+
+        // (한국어) 첫 번째 방식이 성공하면 두 번째 방식은 시도하지 않습니다.
+        // (English Translation) If the first mode succeeds, the second mode is not tried.
+        let mut tried = Vec::new();
+        assert!(grab_cursor(|mode| { tried.push(mode); Ok::<(), ()>(()) }));
+        assert_eq!(tried, vec![first]);
+
+        // (한국어) 모든 방식이 실패하면 커서를 묶지 못합니다.
+        // (English Translation) If all modes fail, the cursor is not grabbed.
+        let mut tried = Vec::new();
+        assert!(!grab_cursor(|mode| { tried.push(mode); Err(()) }));
+        assert_eq!(tried, vec![first, second]);
+    }
+
+    #[test]
+    #[cfg(not(target_os = "macos"))]
+    fn confined_grab_is_tried_before_locked() {
+        assert_eq!(cursor_grab_modes(), [CursorGrabMode::Confined, CursorGrabMode::Locked]);
+    }
+}
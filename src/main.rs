@@ -12,28 +12,29 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering as MemOrdering};
 use std::collections::VecDeque;
 
-use crossbeam_queue::SegQueue;
 use winit::{
     event::{Event, WindowEvent},
+    keyboard::{PhysicalKey, KeyCode},
     event_loop::{EventLoop, EventLoopProxy, EventLoopBuilder, ControlFlow},
-    window::{Window, WindowBuilder},
+    window::{CursorGrabMode, Icon, Window, WindowBuilder},
     dpi::PhysicalPosition,
 };
 
 use crate::{
     assets::bundle::AssetBundle,
-    components::camera::GameCamera,
+    components::{cursor, sound, toast},
     nodes::setup::SetupScene,
-    render::depth::DepthBuffer,
+    render::{adapter::{AdapterWarning, GpuPreference}, depth::DepthBuffer, visibility::{self, WindowVisibility}},
     scene::{
         node::SceneNode,
+        shutdown,
         state::SceneState,
     },
     system::{
         error::{AppResult, GameError},
-        event::AppEvent,
+        event::{AppEvent, EventQueue, EventRoute},
         shared::Shared,
-        timer::GameTimer,
+        timer::{GameTimer, GamePaused},
     },
 };
 
@@ -46,13 +47,31 @@ use crate::{
 /// 
 static RUNNING_FLAG: AtomicBool = AtomicBool::new(true);
 
+/// #### 한국어 </br>
+/// 이벤트 대기열에 쌓아둘 수 있는 이벤트의 최대 개수 입니다. </br>
+/// 넘친 경우 가장 오래된 중요하지 않은 이벤트부터 버립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum number of events that can be held in the event queue. </br>
+/// If it overflows, the oldest non-critical events are dropped first. </br>
+/// 
+const MAX_QUEUED_EVENTS: usize = 256;
+
 /// #### 한국어 </br>
 /// 애플리케이션 윈도우 이벤트 대기열 입니다. </br>
 /// 
 /// #### English (Translation) </br>
 /// Application window event queue. </br>
 /// 
-static EVENT_QUEUE: SegQueue<Event<AppEvent>> = SegQueue::new();
+static EVENT_QUEUE: EventQueue = EventQueue::new(Some(MAX_QUEUED_EVENTS));
+
+/// #### 한국어 </br>
+/// 이 환경 변수가 설정된 경우, 같은 이름의 렌더링 장치 어뎁터를 우선하여 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// If this environment variable is set, the rendering device adapter with the same name is used first. </br>
+/// 
+const ADAPTER_ENV: &'static str = "MILLENNIUM_RUN_ADAPTER";
 
 
 
@@ -82,7 +101,7 @@ fn game_loop(
     // (한국어) wgpu 프레임 버퍼를 설정합니다.
     // (English Translation) Set the wgpu framebuffer.
     let config = wgpu::SurfaceConfiguration {
-        usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+        usage: render::screenshot::surface_usage(&surface, &adapter),
         format: wgpu::TextureFormat::Bgra8Unorm,
         width: window.inner_size().width,
         height: window.inner_size().height,
@@ -101,6 +120,9 @@ fn game_loop(
     shared.push(asset_bundle);
     shared.push(instance);
     shared.push(surface);
+    if let Some(warning) = AdapterWarning::classify(&adapter.get_info()) {
+        shared.push(warning);
+    }
     shared.push(adapter);
     shared.push(device);
     shared.push(queue);
@@ -130,48 +152,61 @@ fn game_loop(
     log::info!("Run game loop.");
     let mut timer = GameTimer::new();
     let mut elapsed_time_sec = 0.0;
+    let mut visibility = WindowVisibility::default();
+    #[cfg(debug_assertions)]
+    let mut script_watcher = crate::components::script::ScriptWatcher::default();
+    #[cfg(debug_assertions)]
+    let mut resource_counts = render::tracker::ResourceCounts::live(shared.get::<AssetBundle>().unwrap());
     while RUNNING_FLAG.load(MemOrdering::Acquire) {
         // (한국어) 타이머를 갱신합니다.
         // (English Translation) Update the timer.
         timer.tick(None);
         elapsed_time_sec += timer.elapsed_time_sec();
 
+        // (한국어) 한 번에 갱신할 수 있는 시간보다 오래 멈춰 있었던 경우 남는 시간은 버립니다.
+        // (English Translation) If it stalled longer than can be updated at once, the remaining time is dropped.
+        elapsed_time_sec = elapsed_time_sec.min(MAX_UPDATE_COUNT as f64 * FIXED_TIME_SEC);
+
+        // (한국어) 게임 장면이 일시정지를 요청한 경우 전체 시간을 멈추고 배경 음악을 일시정지 합니다.
+        // (English Translation) If the game scene requested a pause, stops the total time and pauses the background music.
+        let paused = shared.get::<GamePaused>().is_some();
+        if paused != timer.is_paused() {
+            match paused {
+                true => timer.pause(),
+                false => timer.resume(),
+            }
+            if let Some(bgm) = shared.get::<sound::BgmPlayer>() {
+                bgm.set_paused(paused);
+            }
+        }
+
         // (한국어) 윈도우 이벤트를 처리합니다.
         // (English Translation) Handles window events.
         while let Some(event) = EVENT_QUEUE.pop() {
             let event_cloned = event.clone();
+            if let Event::WindowEvent { event, .. } = &event_cloned {
+                visibility.handle_event(event);
+            }
+
             match event_cloned {
                 Event::WindowEvent { event, .. } => match event {
-                    WindowEvent::Resized(_) | WindowEvent::ScaleFactorChanged { .. } => {
-                        let instance = shared.get::<Arc<wgpu::Instance>>().unwrap().clone();
-                        let surface = shared.get::<Arc<wgpu::Surface>>().unwrap().clone();
-                        let device = shared.get::<Arc<wgpu::Device>>().unwrap().clone();
-                        let queue = shared.get::<Arc<wgpu::Queue>>().unwrap().clone();
-                        let config = shared.get_mut::<wgpu::SurfaceConfiguration>().unwrap();
-    
-                        let width = window.inner_size().width;
-                        let height = window.inner_size().height;
-    
-                        if width > 0 && height > 0 {
-                            instance.poll_all(true);
-                            config.width = width;
-                            config.height = height;
-                            surface.configure(&device, config);
-                            shared.push(Arc::new(DepthBuffer::new(&window, &device)));
-                            if let Some(camera) = shared.get::<Arc<GameCamera>>() {
-                                camera.update(&queue, |data| {
-                                    data.viewport.width = width as f32;
-                                    data.viewport.height = height as f32;
-                                    data.scale_factor = window.current_monitor().map_or(1.0, |monitor| monitor.scale_factor() as f32);
-                                });
-                            }
+                    WindowEvent::Resized(_) => {
+                        render::resize_render_ctx(&window, &mut shared, None);
+                    },
+                    WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                        render::resize_render_ctx(&window, &mut shared, Some(scale_factor));
+                    },
+                    WindowEvent::KeyboardInput { event, .. } => {
+                        // (한국어) `F12` 키를 누르면 다음 프레임의 화면 캡처를 요청합니다.
+                        // (English Translation) Pressing the `F12` key requests a screenshot of the next frame.
+                        if PhysicalKey::Code(KeyCode::F12) == event.physical_key && !event.repeat && event.state.is_pressed() {
+                            render::screenshot::request();
                         }
                     },
                     WindowEvent::CursorMoved { position, .. } => {
                         let height = shared.get::<Arc<Window>>().unwrap().inner_size().height as f64;
                         let cursor = shared.get_mut::<PhysicalPosition<f64>>().unwrap();
-                        cursor.x = position.x;
-                        cursor.y = height - position.y;
+                        *cursor = cursor::flip_cursor_position(&position, height);
                     },
                     _ => { /* empty */ }
                 },
@@ -183,8 +218,25 @@ fn game_loop(
             scene_stack.back_mut().unwrap().handle_events(&mut shared, event)?;
         }
 
+        // (한국어) 디버그 빌드에서 스크립트 파일이 수정된 경우 다시 불러옵니다.
+        // (English Translation) Reloads the script file if it has been modified in debug builds.
+        #[cfg(debug_assertions)]
+        if script_watcher.reload_if_modified(&mut shared, timer.total_time_sec()) {
+            scene_stack.back_mut().unwrap().on_script_reload(&mut shared)?;
+        }
+
         let mut update_cnt = 0;
         while elapsed_time_sec >= FIXED_TIME_SEC && update_cnt < MAX_UPDATE_COUNT {
+            // (한국어) 오디오 출력 장치가 없는 경우 장치가 새로 연결되었는지 확인합니다.
+            // (English Translation) If there is no audio output device, checks whether one has been connected.
+            sound::poll_output_device(&mut shared, FIXED_TIME_SEC);
+
+            // (한국어) 배경 음악의 크로스페이드를 진행합니다.
+            // (English Translation) Advances the crossfade of the background music.
+            if let Some(bgm) = shared.get_mut::<sound::BgmPlayer>() {
+                bgm.update(FIXED_TIME_SEC);
+            }
+
             // (한국어) 게임 장면을 갱신합니다.
             // (English Translation) Update the game scene.
             scene_stack.back_mut().unwrap().update(
@@ -196,16 +248,40 @@ fn game_loop(
             elapsed_time_sec -= FIXED_TIME_SEC;
             update_cnt += 1;
         }
+
+        // (한국어) 게임이 일시정지 되어도 알림 메시지가 진행되도록 실제로 경과한 시간으로 갱신합니다.
+        // (English Translation) Updates the notification messages with the actual elapsed time so that they keep going even while the game is paused.
+        toast::update(&mut shared, timer.elapsed_time_sec());
         
 
-        // (한국어) 게임 장면을 그립니다.
-        // (English Translation) Draw the game scene.
-        window.pre_present_notify();
-        scene_stack.back().unwrap().draw(&mut shared)?;
+        // (한국어) 게임 커서를 커서 위치로 옮기고, 현재 게임 장면의 버튼 위에 있는지 확인합니다.
+        // (English Translation) Moves the game cursor to the cursor position and checks whether it is over a button of the current game scene.
+        if cursor::is_visible(&shared) {
+            let hovered = scene_stack.back().unwrap().hovers_button(&shared);
+            cursor::update_custom_cursor(&shared, hovered);
+        }
+
+        // (한국어) 
+        // 게임 장면을 그립니다.
+        // 윈도우가 최소화되었거나 가려진 경우 그리지 않고, 수직 동기화 대신 잠시 쉽니다.
+        // 
+        // (English Translation) 
+        // Draw the game scene.
+        // If the window is minimized or occluded, it does not draw and rests briefly instead of waiting for vertical sync.
+        //
+        if visibility.should_draw() {
+            window.pre_present_notify();
+            scene_stack.back().unwrap().draw(&mut shared)?;
+        } else {
+            thread::sleep(visibility::HIDDEN_FRAME_TIME);
+        }
 
         // (한국어) 게임 장면 상태에 따라 게임 장면을 갱신합니다.
         // (English Translation) Updates the game scene according to the game scene state.
-        match shared.pop::<SceneState>().unwrap() {
+        let scene_state = shared.pop::<SceneState>().unwrap();
+        #[cfg(debug_assertions)]
+        let scene_changed = !matches!(scene_state, SceneState::Keep);
+        match scene_state {
             SceneState::Keep => { /* pass */ },
             SceneState::Pop => { 
                 // (한국어) 가장 최근의 게임 장면을 장면 스택에서 제거하고 종료합니다.
@@ -216,7 +292,6 @@ fn game_loop(
                 // (한국어) 장면 스택이 비어있는 경우 애플리케이션을 종료합니다.
                 // (English Translation) Terminates the application if the scene stack is empty.
                 if scene_stack.is_empty() {
-                    RUNNING_FLAG.store(false, MemOrdering::Release);
                     event_loop_proxy.send_event(AppEvent::Terminate).unwrap();
                     break;
                 }
@@ -252,15 +327,102 @@ fn game_loop(
             }
         };
 
+        // (한국어) 디버그 빌드에서 게임 장면이 바뀐 경우 남아있는 자원의 변화량을 기록합니다.
+        // (English Translation) In debug builds, logs the change of the remaining resources when the game scene changed.
+        #[cfg(debug_assertions)]
+        if scene_changed {
+            resource_counts = render::tracker::log_scene_transition(&resource_counts, shared.get::<AssetBundle>().unwrap());
+        }
+
         shared.push(SceneState::default());
     }
 
+    // (한국어) 
+    // 남아있는 게임 장면들을 정리하고, 설정과 저장 데이터를 기록한 뒤 소리를 멈춥니다.
+    // 윈도우 스레드는 이 함수가 반환되었다는 알림을 받은 후 이벤트 루프를 끝냅니다.
+    //
+    // (English Translation) 
+    // Cleans up the remaining game scenes, writes the settings and save data, and then stops the sounds.
+    // The window thread ends the event loop after it is notified that this function has returned.
+    //
+    log::info!("Shut down game loop.");
+    shutdown::shutdown(&mut scene_stack, &mut shared, shutdown::flush_user_data)?;
+
     Ok(())
 }
 
 
 
 
+/// #### 한국어 </br>
+/// 렌더링 컨텍스트를 생성하기 전에 설정 파일에서 선호하는 전력 사용 방식을 읽어옵니다. </br>
+/// 설정 파일을 읽을 수 없는 경우 기본값을 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads the preferred power usage from the settings file before creating the rendering context. </br>
+/// If the settings file cannot be read, the default value is used. </br>
+/// 
+fn load_gpu_preference(asset_bundle: &AssetBundle) -> GpuPreference {
+    use crate::{
+        components::user::SettingsDecoder,
+        nodes::path,
+    };
+
+    let settings = asset_bundle.get(path::SETTINGS_PATH)
+        .and_then(|handle| {
+            let settings = handle.read(&SettingsDecoder);
+            asset_bundle.release(path::SETTINGS_PATH);
+            settings
+        });
+    match settings {
+        Ok(settings) => settings.gpu_preference,
+        Err(err) => {
+            log::warn!("Failed to read the GPU preference. The default is used: {:?}", err);
+            GpuPreference::default()
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 애플리케이션 윈도우 아이콘을 불러옵니다. </br>
+/// 아이콘을 불러올 수 없는 경우 경고를 남기고 아이콘 없이 실행합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Loads the application window icon. </br>
+/// If the icon cannot be loaded, it leaves a warning and runs without an icon. </br>
+/// 
+fn load_window_icon(asset_bundle: &AssetBundle) -> Option<Icon> {
+    use crate::{
+        nodes::path,
+        render::texture::IconDecoder,
+    };
+
+    let image = asset_bundle.get(path::WINDOW_ICON_PATH)
+        .and_then(|handle| {
+            let image = handle.read(&IconDecoder);
+            asset_bundle.release(path::WINDOW_ICON_PATH);
+            image
+        });
+    let icon = image.and_then(|image| {
+        let (width, height) = image.dimensions();
+        Icon::from_rgba(image.into_raw(), width, height)
+            .map_err(|err| game_err!(
+                "Window icon creation failed",
+                "Window icon creation failed for the following reasons: {}",
+                err.to_string()
+            ))
+    });
+    match icon {
+        Ok(icon) => Some(icon),
+        Err(err) => {
+            log::warn!("Failed to load the window icon. Runs without an icon: {:?}", err);
+            None
+        }
+    }
+}
+
+
+
 /// #### 한국어 </br>
 /// 애플리케이션의 진입점 입니다. </br>
 /// <b>대상 플랫폼이 `Windows` 또는 `Linux` 또는 `macOS`가 아닐 경우 애플리케이션이 동작하지 않습니다.</b></br>
@@ -272,7 +434,7 @@ fn game_loop(
 fn main() {
     use crate::{
         render::setup_render_ctx,
-        system::error::popup_err_msg_and_abort
+        system::error::{join_thread, popup_err_msg_and_abort}
     };
 
     // (한국어) 로그 시스템을 초기화 합니다.
@@ -303,7 +465,7 @@ fn main() {
         WindowBuilder::new()
             .with_visible(false)
             .with_resizable(false)
-            .with_window_icon(None)
+            .with_window_icon(load_window_icon(&asset_bundle))
             .with_title("Application Initialize...")
             .build(&event_loop)
             .map_err(|err| game_err!(
@@ -314,8 +476,16 @@ fn main() {
             .unwrap_or_else(|err| popup_err_msg_and_abort(err))
     );
 
-    // (한국어) 렌더링 컨텍스트들을 생성합니다.
-    // (English Translation) Create rendering contexts.
+    // (한국어) 
+    // 렌더링 컨텍스트들을 생성합니다. 
+    // 설정 파일의 선호하는 전력 사용 방식과 `MILLENNIUM_RUN_ADAPTER` 환경 변수의 어뎁터 이름을 사용합니다.
+    //
+    // (English Translation) 
+    // Create rendering contexts. 
+    // Uses the preferred power usage of the settings file and the adapter name of the `MILLENNIUM_RUN_ADAPTER` environment variable.
+    //
+    let gpu_preference = load_gpu_preference(&asset_bundle);
+    let adapter_name = std::env::var(ADAPTER_ENV).ok();
     let (
         instance,
         surface,
@@ -323,41 +493,68 @@ fn main() {
         device,
         queue,
         depth_buffer,
-    ) = setup_render_ctx(window.clone())
+    ) = setup_render_ctx(window.clone(), gpu_preference, adapter_name.as_deref())
         .unwrap_or_else(|err| popup_err_msg_and_abort(err));
 
 
-    // (한국어) 새로운 스레드를 생성하고, 게임 루프를 실행시킵니다.
-    // (English Translation) Create a new thread and run the game loop.
+    // (한국어) 
+    // 새로운 스레드를 생성하고, 게임 루프를 실행시킵니다.
+    // 게임 루프가 끝나면 윈도우 스레드가 게임 스레드를 join 할 수 있도록 알립니다.
+    //
+    // (English Translation) 
+    // Create a new thread and run the game loop.
+    // When the game loop ends, it notifies the window thread so that it can join the game thread.
+    //
     let window_cloned = window.clone();
     let event_loop_proxy = event_loop.create_proxy();
+    let finished_proxy = event_loop.create_proxy();
     let asset_bundle_cloned = asset_bundle.clone();
-    let mut handle = Some(thread::spawn(move || game_loop(
-        window_cloned, 
-        event_loop_proxy,
-        asset_bundle_cloned, 
-        instance, 
-        surface, 
-        adapter, 
-        device, 
-        queue,
-        depth_buffer
-    )));
+    let mut handle = Some(thread::spawn(move || {
+        let result = game_loop(
+            window_cloned, 
+            event_loop_proxy,
+            asset_bundle_cloned, 
+            instance, 
+            surface, 
+            adapter, 
+            device, 
+            queue,
+            depth_buffer
+        );
+        let _ = finished_proxy.send_event(AppEvent::Finished);
+        result
+    }));
 
     // (한국어) 윈도우 메시지 루프를 실행합니다.
     // (English Translation) Executes the window message loop.
     log::info!("Run window message loop.");
     event_loop.set_control_flow(ControlFlow::Wait);
     event_loop.run(move |event, elwt| {
-        // (한국어) 현재 게임 스레드가 작동하고 있는지 확인합니다.
-        // (English Translation) Verify that the current game thread is working.
-        if RUNNING_FLAG.load(MemOrdering::Acquire) 
-        && handle.as_ref().is_some_and(|it| it.is_finished()) {
-            // (한국어) 현재 게임 스레드가 작동하고 있지 않는 경우 스레드를 join 합니다.
-            // (English Translation) Joins a thread if the current game thread is not working.
+        // (한국어) 
+        // 종료가 요청된 경우 게임 스레드에 종료를 요청합니다.
+        // 게임 스레드는 종료 절차 중에도 윈도우 스레드에 창 작업을 요청할 수 있으므로, 
+        // 여기서 기다리지 않고 게임 스레드가 종료 절차를 마쳤다고 알린 후에 join 합니다.
+        //
+        // (English Translation) 
+        // If termination was requested, requests the game thread to terminate.
+        // The game thread may request window operations from the window thread even during the shutdown procedure, 
+        // so it does not wait here and joins only after the game thread notifies that it finished the shutdown procedure.
+        //
+        let route = EventRoute::of(&event, window.id());
+        if route == EventRoute::Terminate {
+            RUNNING_FLAG.store(false, MemOrdering::Release);
+        }
+
+        if handle.as_ref().is_some_and(|it| route == EventRoute::Finished || it.is_finished()) {
+            // (한국어) 게임 스레드를 join 합니다.
+            // (English Translation) Joins the game thread.
             //
-            let result = handle.take().unwrap().join().unwrap();
-            
+            let result = join_thread(handle.take().unwrap(), "game");
+
+            // (한국어) 게임 장면이 숨겼거나 묶어둔 커서를 윈도우 스레드에서 되돌립니다.
+            // (English Translation) Restores the cursor hidden or grabbed by the game scene on the window thread.
+            window.set_cursor_visible(true);
+            let _ = window.set_cursor_grab(CursorGrabMode::None);
 
             // (한국어) 
             // 오류 메시지를 반환했을 경우 오류 메시지를 화면에 띄우고 애플리케이션을 중단시킵니다.
@@ -383,33 +580,18 @@ fn main() {
             ));
         }
 
-        // (한국어) 윈도우 이벤트를 처리합니다.
-        // (English Translation) Handles window events.
-        let event_cloned = event.clone();
-        if let Event::NewEvents(_) = event_cloned {
-            return;
-        } else if let Event::AboutToWait = event_cloned {
-            return;
-        } else if let Event::WindowEvent { window_id, event } = event_cloned {
-            if window_id == window.id() && (event == WindowEvent::CloseRequested || event == WindowEvent::Destroyed) {
-                RUNNING_FLAG.store(false, MemOrdering::Release);
-                elwt.exit();
-                return;
-            } else if window_id != window.id() {
-                return;
-            }
-        } else if let Event::UserEvent(event) = event_cloned {
-            match event {
-                AppEvent::Terminate => {
-                    elwt.exit();
-                },
-            };
-            return;
-        };
-
-        // (한국어) 윈도우 이벤트를 이벤트 대기열에 추가합니다.
-        // (English Translation) Add a window event to the event queue.
-        EVENT_QUEUE.push(event.clone());
+        // (한국어) 
+        // 윈도우 이벤트를 처리합니다. 
+        // 게임 스레드가 처리하는 이벤트만 이벤트 대기열에 한 번 추가합니다. (상세: [`EventRoute::of`])
+        //
+        // (English Translation) 
+        // Handles window events. 
+        // Only events handled by the game thread are added once to the event queue. (see also: [`EventRoute::of`])
+        //
+        match route {
+            EventRoute::Enqueue => EVENT_QUEUE.push(event),
+            EventRoute::Terminate | EventRoute::Finished | EventRoute::Drop => { /* empty */ },
+        }
     }).map_err(|err| game_err!(
         "Window system running failed",
         "Application running failed for the following reasons: {}",
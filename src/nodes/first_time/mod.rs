@@ -18,14 +18,20 @@ use crate::{
         transform::Projection, 
         anchor::Anchor, 
         margin::Margin, 
-        script::Script,
+        focus::FOCUS_SCALE, 
+        font::has_glyphs, 
+        script::{self, Script, ScriptDecoder, ScriptTags},
         user::{Language, Settings, SettingsEncoder}, 
     },
-    nodes::{path, consts::PIXEL_PER_METER},
-    render::texture::DdsTextureDecoder, 
-    scene::{node::SceneNode, state::SceneState},
+    nodes::{
+        path, 
+        consts::PIXEL_PER_METER, 
+        preload::{self, RequiredAssets}, 
+    },
+    render::{texture::DdsTextureDecoder, frame::with_frame}, 
+    scene::{node::SceneNode, state::{state_fn, SceneState}},
     system::{
-        error::{AppResult, GameError},
+        error::{join_thread, AppResult, GameError},
         event::AppEvent,
         shared::Shared,
     }, 
@@ -48,9 +54,48 @@ const UI_TRANSLATION: Vec3 = Vec3::new(0.0, 0.0, 0.5);
 const TEXT_COLOR: Vec4 = Vec4::new(0.0, 0.0, 0.0, 1.0);
 const TEXT_TRANSLATION: Vec3 = Vec3::new(0.0, 0.0, 0.25);
 
+const PREVIEW_COLOR: Vec4 = Vec4::new(1.0, 1.0, 1.0, 1.0);
+
 const INIT_BUTTON_SCALE: Vec3 = Vec3::new(1.0, 1.0, 1.0);
 const MAX_BUTTON_SCALE: Vec3 = Vec3::new(1.25, 1.25, 1.0);
 
+const PREVIEW_TOP: i32 = 176;
+const PREVIEW_LEFT: i32 = -320;
+const PREVIEW_BOTTOM: i32 = 112;
+const PREVIEW_RIGHT: i32 = 320;
+
+const CONFIRM_BTN_TOP: i32 = -144;
+const CONFIRM_BTN_LEFT: i32 = -128;
+const CONFIRM_BTN_BOTTOM: i32 = -208;
+const CONFIRM_BTN_RIGHT: i32 = 128;
+
+/// #### 한국어 </br>
+/// 처음 실행할 때 선택할 수 있는 언어 목록입니다. 첫 번째 언어가 처음에 강조됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// List of languages that can be selected on the first run. The first language is highlighted initially. </br>
+/// 
+const LANGUAGES: [Language; 1] = [Language::Korean];
+
+/// #### 한국어 </br>
+/// 글리프가 없는 경우 사용하는 기본 폰트입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The base font used when glyphs are missing. </br>
+/// 
+const BASE_FONT_PATH: &'static str = path::NEXON_LV2_GOTHIC_MEDIUM_PATH;
+
+/// #### 한국어 </br>
+/// `FirstTimeSetup` 게임 장면에서 사용하는 에셋 목록입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// List of assets used in the `FirstTimeSetup` game scene. </br>
+/// 
+const REQUIRED_ASSETS: [&'static str; 2] = [
+    path::CLICK_SOUND_PATH, 
+    path::BUTTON_WIDE_TEXTURE_PATH, 
+];
+
 
 
 /// #### 한국어 </br>
@@ -64,6 +109,13 @@ pub struct FirstTimeSetupLoading {
     loading: Option<JoinHandle<AppResult<FirstTimeSetupScene>>>,
 }
 
+impl RequiredAssets for FirstTimeSetupLoading {
+    #[inline]
+    fn required_assets() -> &'static [&'static str] {
+        &REQUIRED_ASSETS
+    }
+}
+
 impl SceneNode for FirstTimeSetupLoading {
     fn enter(&mut self, shared: &mut Shared) -> AppResult<()> {
         // (한국어) 사용할 공유 객체 가져오기.
@@ -80,8 +132,7 @@ impl SceneNode for FirstTimeSetupLoading {
         self.loading = Some(thread::spawn(move || {
             // (한국어) 현재 게임 장면에서 사용할 에셋들을 불러옵니다.
             // (English Translation) Loads assets to be used in the current game scene. 
-            asset_bundle.get(path::CLICK_SOUND_PATH)?;
-            asset_bundle.get(path::BUTTON_WIDE_TEXTURE_PATH)?;
+            preload::preload_scene::<Self>(&asset_bundle)?;
 
             // (한국어) 버튼 텍스처를 생성합니다.
             // (English Translation) Create a button texture.
@@ -132,11 +183,42 @@ impl SceneNode for FirstTimeSetupLoading {
                 )?
             );
 
+            // (한국어) 미리보기 문장을 보여주기 위해 선택할 수 있는 모든 언어의 스크립트를 불러옵니다.
+            // (English Translation) Loads the scripts of all selectable languages to show the preview sentence.
+            let mut scripts = HashMap::new();
+            for language in LANGUAGES {
+                let script = asset_bundle.get(script_path(language)?)?
+                    .read(&ScriptDecoder)?;
+                scripts.insert(language, Arc::new(script));
+            }
+
+            // (한국어) 첫 번째 언어를 강조하고 해당 언어로 미리보기를 생성합니다.
+            // (English Translation) Highlights the first language and creates the preview in that language.
+            let highlighted = LANGUAGES[0];
+            if let Some((ui, _)) = buttons.get(&highlighted) {
+                ui.update(&queue, |data| data.local_scale = (FOCUS_SCALE, FOCUS_SCALE, FOCUS_SCALE).into());
+            }
+            let (preview_text, confirm_text) = setup_preview_texts(
+                &fonts, 
+                highlighted, 
+                &scripts[&highlighted], 
+                &device, 
+                &queue, 
+                &text_brush
+            )?;
+            let confirm_button = (
+                setup_confirm_button(&device, &tex_sampler, &texture_view, &ui_brush), 
+                confirm_text
+            );
+
             Ok(FirstTimeSetupScene {
                 timer: 0.0, 
                 state: state::FirstTimeSetupSceneState::Wait,
-                loading: None,
                 buttons,
+                scripts, 
+                preview_text, 
+                confirm_button, 
+                highlighted, 
                 language: Language::default(),
             })
         }));
@@ -168,67 +250,16 @@ impl SceneNode for FirstTimeSetupLoading {
         // (한국어) `FirstTimeSetup` 게임 장면이 준비된 경우 게임 장면으로 변경합니다.
         // (English Translation) If the `FirstTimeSetup` game scene is ready, change to the game scene.
         if self.loading.as_ref().is_some_and(|it| it.is_finished()) {
-            let result = self.loading.take().unwrap().join().unwrap();
+            let result = join_thread(self.loading.take().unwrap(), "loading");
             *shared.get_mut::<SceneState>().unwrap() = SceneState::Change(Box::new(result?));
         }
         Ok(())
     }
 
     fn draw(&self, shared: &mut Shared) -> AppResult<()> {
-        // (한국어) 사용할 공유 객체 가져오기.
-        // (English Translation) Get shared object to use.
-        let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-        let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-        let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-
-        // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-        // (English Translation) Wait until the previous operation is finished.
-        device.poll(wgpu::Maintain::Wait);
-
-        // (한국어) 다음 프레임을 가져옵니다.
-        // (English Translation) Get the next frame.
-        let frame = surface.get_current_texture()
-            .map_err(|err| game_err!(
-                "Failed to get next frame",
-                "Failed to get next frame for the following reasons: {}",
-                err.to_string()
-            ))?;
-
-        // (한국어) 프레임 버퍼의 텍스쳐 뷰를 생성합니다.
-        // (English Translation) Creates a texture view of the framebuffer.
-        let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-        // (한국어) 커맨드 버퍼를 생성합니다.
-        // (English Translation) Creates a command buffer.
-        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-
-        {
-            let mut _rpass = encoder.begin_render_pass(
-                &wgpu::RenderPassDescriptor {
-                    label: Some("RenderPass(FirstTimeSetupLoading)"),
-                    color_attachments: &[
-                        Some(wgpu::RenderPassColorAttachment { 
-                            view: &view, 
-                            resolve_target: None, 
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                                store: wgpu::StoreOp::Store,
-                            }, 
-                        }),
-                    ],
-                    depth_stencil_attachment: None,
-                    timestamp_writes: None,
-                    occlusion_query_set: None,
-                }
-            );
-        }
-
-        // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-        // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-        queue.submit(Some(encoder.finish()));
-        frame.present();
-
-        Ok(())
+        with_frame(shared, self.clear_color(), |frame| {
+            frame.begin_pass("RenderPass(FirstTimeSetupLoading)");
+        })
     }
 }
 
@@ -251,11 +282,19 @@ impl Default for FirstTimeSetupLoading {
 pub struct FirstTimeSetupScene { 
     timer: f64,
     state: state::FirstTimeSetupSceneState,
-    loading: Option<JoinHandle<AppResult<Arc<Script>>>>,
     buttons: HashMap<Language, (UiObject, Text)>,
+    scripts: HashMap<Language, Arc<Script>>, 
+    preview_text: Text, 
+    confirm_button: (UiObject, Text), 
+    highlighted: Language, 
     language: Language,
 }
 
+#[cfg(test)]
+impl crate::scene::state::SceneStates for FirstTimeSetupScene {
+    const NUM_STATES: usize = state::FirstTimeSetupSceneState::NUM_STATES;
+}
+
 impl SceneNode for FirstTimeSetupScene {
     fn exit(&mut self, shared: &mut Shared) -> AppResult<()> {
         // (한국어) 사용할 공유 객체 가져오기.
@@ -263,7 +302,11 @@ impl SceneNode for FirstTimeSetupScene {
         let mut settings = shared.pop::<Settings>().unwrap();
         let asset_bundle = shared.get::<AssetBundle>().unwrap();
         let window = shared.get::<Arc<Window>>().unwrap();
-        window.set_title("Millennium Run");
+        script::set_window_title(window, shared.get::<Arc<Script>>().map(|script| script.as_ref()));
+
+        // (한국어) 현재 게임 장면에서 사용한 에셋들을 해제합니다.
+        // (English Translation) Release assets used in the current game scene.
+        preload::release_scene::<FirstTimeSetupLoading>(asset_bundle);
 
         // (한국어) 선택된 언어를 확인합니다.
         // (English Translation) Confirm the selected language.
@@ -286,17 +329,17 @@ impl SceneNode for FirstTimeSetupScene {
 
     #[inline]
     fn handle_events(&mut self, shared: &mut Shared, event: Event<AppEvent>) -> AppResult<()> {
-        state::HANDLE_EVENTS[self.state as usize](self, shared, event)
+        state_fn(&state::HANDLE_EVENTS, self.state as usize, "HANDLE_EVENTS")?(self, shared, event)
     }
 
     #[inline]
     fn update(&mut self, shared: &mut Shared, total_time: f64, elapsed_time: f64) -> AppResult<()> {
-        state::UPDATES[self.state as usize](self, shared, total_time, elapsed_time)
+        state_fn(&state::UPDATES, self.state as usize, "UPDATES")?(self, shared, total_time, elapsed_time)
     }
 
     #[inline]
     fn draw(&self, shared: &mut Shared) -> AppResult<()> {
-        state::DRAWS[self.state as usize](self, shared)
+        state_fn(&state::DRAWS, self.state as usize, "DRAWS")?(self, shared)
     }
 }
 
@@ -345,3 +388,115 @@ fn setup_korean_button(
 
     Ok((ui, text))
 }
+
+
+/// #### 한국어 </br>
+/// 주어진 언어의 스크립트 파일 경로를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the path of the script file of the given language. </br>
+/// 
+fn script_path(language: Language) -> AppResult<&'static str> {
+    match language {
+        Language::Korean => Ok(path::KOR_SCRIPTS_PATH), 
+        Language::Unknown => Err(game_err!("Game Logic Error", "Unknown locale!")), 
+    }
+}
+
+/// #### 한국어 </br>
+/// 주어진 언어의 텍스트를 그릴 폰트를 반환합니다. </br>
+/// 언어의 폰트에 텍스트의 글리프가 없는 경우 기본 폰트를 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the font to draw the text of the given language. </br>
+/// If the font of the language is missing glyphs of the text, the base font is used. </br>
+/// 
+fn preview_font<'a>(fonts: &'a HashMap<String, FontArc>, language: Language, text: &str) -> &'a FontArc {
+    let base = fonts.get(BASE_FONT_PATH)
+        .expect("A registered font could not be found.");
+    let font = match language {
+        Language::Korean => fonts.get(path::NEXON_LV2_GOTHIC_MEDIUM_PATH), 
+        Language::Unknown => None, 
+    };
+
+    match font {
+        Some(font) if has_glyphs(font, text) => font, 
+        _ => {
+            log::warn!("The font of {:?} is missing glyphs. The base font is used instead.", language);
+            base
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 주어진 언어의 스크립트로 미리보기 문장과 확인 버튼의 텍스트를 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the preview sentence and the text of the confirm button with the script of the given language. </br>
+/// 
+fn setup_preview_texts(
+    fonts: &HashMap<String, FontArc>, 
+    language: Language, 
+    script: &Script, 
+    device: &wgpu::Device,
+    queue: &wgpu::Queue,
+    text_brush: &TextBrush,
+) -> AppResult<(Text, Text)> {
+    let anchor = Anchor::new(ANCHOR_TOP, ANCHOR_LEFT, ANCHOR_BOTTOM, ANCHOR_RIGHT);
+
+    let sample = script.get(ScriptTags::FirstTimePreviewText)?;
+    let preview_text = TextBuilder::new(
+        Some("Text(Preview)"), 
+        preview_font(fonts, language, sample), 
+        sample, 
+        text_brush
+    )
+    .with_anchor(anchor)
+    .with_margin(Margin::new(PREVIEW_TOP, PREVIEW_LEFT, PREVIEW_BOTTOM, PREVIEW_RIGHT))
+    .with_color(PREVIEW_COLOR)
+    .with_scale(INIT_BUTTON_SCALE)
+    .with_translation(TEXT_TRANSLATION)
+    .build(device, queue);
+
+    let label = script.get(ScriptTags::FirstTimeConfirmButton)?;
+    let confirm_text = TextBuilder::new(
+        Some("Text(Confirm)"), 
+        preview_font(fonts, language, label), 
+        label, 
+        text_brush
+    )
+    .with_anchor(anchor)
+    .with_margin(Margin::new(CONFIRM_BTN_TOP, CONFIRM_BTN_LEFT, CONFIRM_BTN_BOTTOM, CONFIRM_BTN_RIGHT))
+    .with_color(TEXT_COLOR)
+    .with_scale(INIT_BUTTON_SCALE)
+    .with_translation(TEXT_TRANSLATION)
+    .build(device, queue);
+
+    Ok((preview_text, confirm_text))
+}
+
+/// #### 한국어 </br>
+/// 확인 버튼의 사용자 인터페이스를 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a user interface for the confirm button. </br>
+/// 
+fn setup_confirm_button(
+    device: &wgpu::Device,
+    tex_sampler: &wgpu::Sampler,
+    texture_view: &wgpu::TextureView,
+    ui_brush: &UiBrush,
+) -> UiObject {
+    UiObjectBuilder::new(
+        Some("Button(Confirm)"), 
+        tex_sampler, 
+        texture_view, 
+        ui_brush
+    )
+    .with_anchor(Anchor::new(ANCHOR_TOP, ANCHOR_LEFT, ANCHOR_BOTTOM, ANCHOR_RIGHT))
+    .with_margin(Margin::new(CONFIRM_BTN_TOP, CONFIRM_BTN_LEFT, CONFIRM_BTN_BOTTOM, CONFIRM_BTN_RIGHT))
+    .with_color(UI_COLOR)
+    .with_global_scale(INIT_BUTTON_SCALE)
+    .with_global_translation(UI_TRANSLATION)
+    .build(device)
+}
@@ -25,12 +25,12 @@ use crate::{
         }, 
         intro::IntroLoading,
     },
-    scene::state::SceneState,
+    scene::{node::SceneNode, state::SceneState},
     system::{
         error::{AppResult, GameError},
         event::AppEvent,
         shared::Shared,
-    }, render::depth::DepthBuffer, 
+    }, render::frame::with_frame, 
 };
 
 const TOTAL_DURATION: f64 = 1.0;
@@ -55,7 +55,7 @@ pub fn update(this: &mut FirstTimeSetupScene, shared: &mut Shared, _total_time:
     // (한국어) 버튼의 알파값을 갱신합니다.
     // (English Translation) Updates the alpha value of the button 
     let alpha = 1.0 - 1.0 * delta;
-    for (ui, text) in this.buttons.values_mut() {
+    for (ui, text) in this.buttons.values().chain([&this.confirm_button]) {
         ui.update(queue, |data| {
             data.color.w = alpha;
         });
@@ -63,6 +63,9 @@ pub fn update(this: &mut FirstTimeSetupScene, shared: &mut Shared, _total_time:
             data.color.w = alpha;
         });
     }
+    this.preview_text.update(queue, |data| {
+        data.color.w = alpha;
+    });
 
     // (한국어) 버튼의 크기를 갱신합니다.
     // (English Translation) Updates the scale value of the button.
@@ -77,17 +80,17 @@ pub fn update(this: &mut FirstTimeSetupScene, shared: &mut Shared, _total_time:
     }
 
     // (한국어) 
-    // 지속 시간보다 크고, 선택한 언어의 스크립트가 로드되었을 경우 
+    // 지속 시간보다 큰 경우 선택한 언어의 스크립트를 등록하고 
     // 다음 게임 장면으로 변경합니다.
     //
     // (English Translation)
-    // Changes to the next game scene 
-    // if it is greater than the duration 
-    // and the script for the selected language has been loaded.
+    // If it is greater than the duration, registers the script 
+    // for the selected language and changes to the next game scene.
     //
-    if this.timer >= TOTAL_DURATION 
-    && this.loading.as_ref().is_some_and(|it| it.is_finished()) {
-        shared.push(this.loading.take().unwrap().join().unwrap()?);
+    if this.timer >= TOTAL_DURATION {
+        let script = this.scripts.get(&this.language)
+            .ok_or_else(|| game_err!("Game Logic Error", "Unknown locale!"))?;
+        shared.push(script.clone());
         *shared.get_mut::<SceneState>().unwrap() = SceneState::Change(Box::new(IntroLoading::default()));
         return Ok(());
     }
@@ -100,74 +103,26 @@ pub fn draw(this: &FirstTimeSetupScene, shared: &mut Shared) -> AppResult<()> {
     // (English Translation) Get shared object to use.
     let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
     let ui_brush = shared.get::<Arc<UiBrush>>().unwrap();
-    let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
     let camera = shared.get::<Arc<GameCamera>>().unwrap();
 
 
-    // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-    // (English Translation) Wait until the previous operation is finished.
-    device.poll(wgpu::Maintain::Wait);
-
-    // (한국어) 다음 프레임을 가져옵니다.
-    // (English Translation) Get the next frame.
-    let frame = surface.get_current_texture()
-        .map_err(|err| game_err!(
-            "Failed to get next frame",
-            "Failed to get next frame for the following reasons: {}",
-            err.to_string()
-        ))?;
-
-    // (한국어) 프레임 버퍼의 텍스쳐 뷰를 생성합니다.
-    // (English Translation) Creates a texture view of the framebuffer.
-    let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-    // (한국어) 커맨드 버퍼를 생성합니다.
-    // (English Translation) Creates a command buffer.
-    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(FirstTimeSetupScene(Wait(Ui))))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment { 
-                view: &view, 
-                resolve_target: None, 
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        // (한국어) 카메라 바인딩.
-        // (English Translation) Bind the camera.
-        camera.bind(&mut rpass);
-
-        // (한국어) 유저 인터페이스 오브젝트 그리기.
-        // (English Translation) Drawing user interface objects.
-        ui_brush.draw(&mut rpass, this.buttons.values().map(|(ui, _)| ui));
+    with_frame(shared, this.clear_color(), |frame| {
+        {
+            let mut rpass = frame.begin_pass("RenderPass(FirstTimeSetupScene(Wait(Ui))))");
 
-        // // (한국어) 텍스트 그리기.
-        // // (English Translation) Drawing texts.
-        text_brush.draw(&mut rpass, this.buttons.values().map(|(_, text)| text));
-    }
+            // (한국어) 카메라 바인딩.
+            // (English Translation) Bind the camera.
+            camera.bind(&mut rpass);
 
-    // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-    // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-    queue.submit(Some(encoder.finish()));
-    frame.present();
+            // (한국어) 유저 인터페이스 오브젝트 그리기.
+            // (English Translation) Drawing user interface objects.
+            ui_brush.draw(&mut rpass, this.buttons.values().map(|(ui, _)| ui).chain([&this.confirm_button.0]));
 
-    Ok(())
+            // // (한국어) 텍스트 그리기.
+            // // (English Translation) Drawing texts.
+            let iter = this.buttons.values().map(|(_, text)| text)
+                .chain([&this.confirm_button.1, &this.preview_text]);
+            text_brush.draw(&mut rpass, iter);
+        }
+    })
 }
@@ -4,22 +4,25 @@ mod exit;
 use winit::event::Event;
 
 use crate::{
+    state_enum,
     nodes::first_time::FirstTimeSetupScene,
     system::{error::AppResult, event::AppEvent, shared::Shared},
 };
 
 
-/// #### 한국어 </br>
-/// `FirstTimeSetup` 게임 장면의 상태 목록 입니다. </br>
-/// 
-/// #### English (Translation) </br>
-/// List of status in the `FirstTimeSetup` game scene. </br>
-/// 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum FirstTimeSetupSceneState {
-    #[default]
-    Wait = 0,
-    Exit = 1,
+state_enum! {
+    /// #### 한국어 </br>
+    /// `FirstTimeSetup` 게임 장면의 상태 목록 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// List of status in the `FirstTimeSetup` game scene. </br>
+    /// 
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub enum FirstTimeSetupSceneState {
+        #[default]
+        Wait = 0,
+        Exit = 1,
+    }
 }
 
 type HandleEventsFn = dyn Fn(&mut FirstTimeSetupScene, &mut Shared, Event<AppEvent>) -> AppResult<()>;
@@ -40,3 +43,35 @@ pub const DRAWS: [&'static DrawFn; 2] = [
     &wait::draw,
     &exit::draw,
 ];
+
+
+/// #### 한국어 </br>
+/// `FirstTimeSetup` 게임 장면의 상태별 함수 목록들의 (이름, 길이) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The (name, length) of the per-state function tables of the `FirstTimeSetup` game scene. </br>
+/// 
+#[cfg(test)]
+pub const TABLES: [(&'static str, usize); 3] = [
+    ("HANDLE_EVENTS", HANDLE_EVENTS.len()), 
+    ("UPDATES", UPDATES.len()), 
+    ("DRAWS", DRAWS.len()), 
+];
+
+// (한국어) 상태별 함수 목록의 길이가 상태의 개수와 같은지 컴파일 시간에 확인합니다.
+// (English Translation) Checks at compile time that the length of each per-state function table equals the number of states.
+const _: () = assert!(HANDLE_EVENTS.len() == FirstTimeSetupSceneState::NUM_STATES, "`HANDLE_EVENTS` must have one entry for each `FirstTimeSetupSceneState`.");
+const _: () = assert!(UPDATES.len() == FirstTimeSetupSceneState::NUM_STATES, "`UPDATES` must have one entry for each `FirstTimeSetupSceneState`.");
+const _: () = assert!(DRAWS.len() == FirstTimeSetupSceneState::NUM_STATES, "`DRAWS` must have one entry for each `FirstTimeSetupSceneState`.");
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::scene::state::debug_validate_tables;
+
+    #[test]
+    fn state_tables_match_the_scene() {
+        assert!(debug_validate_tables::<FirstTimeSetupScene>(&TABLES).is_ok());
+    }
+}
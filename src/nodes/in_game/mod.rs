@@ -1,3 +1,4 @@
+mod sim;
 mod state;
 mod utils;
 
@@ -6,37 +7,57 @@ use std::thread::{self, JoinHandle};
 use std::collections::{VecDeque, HashMap};
 
 use ab_glyph::FontArc;
-use winit::event::Event;
-use rodio::{OutputStreamHandle, Source};
+use winit::{
+    event::{Event, WindowEvent},
+    keyboard::{PhysicalKey, KeyCode},
+};
 
 use crate::components::anchor::Anchor;
 use crate::{
-    game_err,
     assets::bundle::AssetBundle,
     components::{
-        ui::{UiBrush, UiObject},
+        ui::{UiBrush, UiObject, Slider, ModalWindow},
+        focus::FocusRing,
         text::{TextBrush, Text, TextBuilder}, 
         sprite::SpriteBrush,
         bullet::{Bullet, BulletBrush},
         camera::{CameraCreator, GameCamera},
+        cursor, 
         transform::Projection,
-        table::{Table, TileBrush}, 
+        table::{BoardSize, Table, TileBrush}, 
         player::{Actor, Player, PlayerFaceState},
         boss::{Boss, BossFaceState},
-        sound::SoundDecoder, 
+        heart::HeartBar, 
+        save::SaveData, 
+        sound, 
         script::Script, 
-        user::{Language, Resolution, Settings}, 
+        user::{self, Language, Resolution, Settings, SettingToggle}, 
     },
-    nodes::{path, consts::PIXEL_PER_METER}, 
-    scene::{node::SceneNode, state::SceneState},
-    render::depth::DepthBuffer, 
+    nodes::{
+        path, 
+        consts::PIXEL_PER_METER, 
+        preload::{self, CancelFlag, LoadResult, RequiredAssets}, 
+        title::TitleLoading, 
+    }, 
+    scene::{back_stack::BackStack, node::SceneNode, state::{ignores_button_input, is_scene_changing, state_fn, SceneState}},
+    render::frame::with_frame, 
     system::{
-        error::{AppResult, GameError},
+        error::{join_thread, AppResult},
         event::AppEvent,
+        rng::{GameRng, CosmeticRng},
         shared::Shared,
+        timer::GamePaused,
     },
 };
 
+/// #### 한국어 </br>
+/// 기본 크기 보드의 행 수, 열 수, 타일 수 입니다. </br>
+/// 세이브 파일의 진행도는 보드 크기와 관계없이 기본 크기 보드의 타일 수로 기록됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The number of rows, columns, and tiles of the standard size board. </br>
+/// The progress in the save file is recorded as a number of tiles of the standard size board regardless of the board size. </br>
+/// 
 pub const NUM_TILE_ROWS: usize = 100;
 pub const NUM_TILE_COLS: usize = 100;
 pub const NUM_TILES: usize = NUM_TILE_ROWS * NUM_TILE_COLS;
@@ -44,11 +65,46 @@ pub const NUM_TILES: usize = NUM_TILE_ROWS * NUM_TILE_COLS;
 pub const GAME_DURATION_SEC: f64 = 90.0;
 pub const PERCENT_DURATION: f64 = 0.25;
 
+pub const LOW_TIME_WARNING_SEC: f64 = 10.0;
+pub const LOW_TIME_FLASH_RATE: f64 = 2.0;
+
+/// #### 한국어 </br>
+/// `InGame` 게임 장면에서 사용하는 음향 에셋 목록입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// List of audio assets used in the `InGame` game scene. </br>
+/// 
+const REQUIRED_ASSETS: [&'static str; 15] = [
+    path::CLICK_SOUND_PATH, 
+    path::CANCEL_SOUND_PATH, 
+    path::START_SOUND_PATH, 
+    path::PAUSE_SOUND_PATH, 
+    path::FINISH_SOUND_PATH, 
+    path::BULLET_FIRE_SOUND_PATH, 
+    path::THEME23_SOUND_PATH, 
+    path::THEME27_SOUND_PATH, 
+    path::YUUKA_TITLE_SOUND_PATH, 
+    path::YUUKA_DEFEAT_SOUND_PATH, 
+    path::YUUKA_VICTORY_SOUND_PATH, 
+    path::YUUKA_HIDDEN_SOUND_PATH, 
+    path::YUUKA_ATTACK1_SOUND_PATH, 
+    path::YUUKA_ATTACK2_SOUND_PATH, 
+    path::YUUKA_ATTACK3_SOUND_PATH, 
+];
+
 
 #[derive(Debug)]
 pub struct InGameLoading {
     loading_text: Option<Text>, 
-    loading: Option<JoinHandle<AppResult<InGameScene>>>,
+    loading: Option<JoinHandle<AppResult<LoadResult<InGameScene>>>>,
+    cancel: CancelFlag, 
+}
+
+impl RequiredAssets for InGameLoading {
+    #[inline]
+    fn required_assets() -> &'static [&'static str] {
+        &REQUIRED_ASSETS
+    }
 }
 
 impl SceneNode for InGameLoading {
@@ -59,12 +115,40 @@ impl SceneNode for InGameLoading {
         Ok(())
     }
 
+    fn handle_events(&mut self, shared: &mut Shared, event: Event<AppEvent>) -> AppResult<()> {
+        // (한국어) `Escape` 키를 누른 경우 불러오기를 취소합니다.
+        // (English Translation) Cancels loading when the `Escape` key is pressed.
+        if let Event::WindowEvent { event: WindowEvent::KeyboardInput { event, .. }, .. } = event {
+            if let PhysicalKey::Code(KeyCode::Escape) = event.physical_key {
+                if !event.repeat && event.state.is_pressed() && !self.cancel.is_canceled() {
+                    sound::play_cancel_sound(shared)?;
+                    self.cancel.cancel();
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn update(&mut self, shared: &mut Shared, _total_time: f64, _elapsed_time: f64) -> AppResult<()> {
         // (한국어) `InGame` 게임 장면이 로드 될 때까지 기다립니다.
         // (English Translation) Wait for the `InGame` game scene to load.
         if self.loading.as_ref().is_some_and(|it| it.is_finished()) {
-            let next_scene = self.loading.take().unwrap().join().unwrap()?;
-            *shared.get_mut::<SceneState>().unwrap() = SceneState::Change(Box::new(next_scene));
+            match join_thread(self.loading.take().unwrap(), "loading")? {
+                LoadResult::Loaded(next_scene) if !self.cancel.is_canceled() => {
+                    *shared.get_mut::<SceneState>().unwrap() = SceneState::Change(Box::new(next_scene));
+                },
+                result => {
+                    // (한국어) 취소되기 직전에 준비를 마친 게임 장면은 사용하지 않고 버립니다.
+                    // (English Translation) A game scene that finished preparing just before cancellation is discarded without being used.
+                    if let LoadResult::Loaded(next_scene) = result {
+                        next_scene.release_assets(shared.get::<AssetBundle>().unwrap());
+                    }
+                    discard_brushes(shared);
+
+                    let actor = shared.get::<Actor>().cloned().unwrap_or_default();
+                    *shared.get_mut::<SceneState>().unwrap() = SceneState::Change(Box::new(TitleLoading::new(actor)));
+                },
+            }
         }
         Ok(())
     }
@@ -72,71 +156,17 @@ impl SceneNode for InGameLoading {
     fn draw(&self, shared: &mut Shared) -> AppResult<()> {
         // (한국어) 사용할 공유 객체 가져오기.
         // (English Translation) Get shared object to use.
-        let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-        let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-        let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-        let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
         let camera = shared.get::<Arc<GameCamera>>().unwrap();
         let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
 
-        // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-        // (English Translation) Wait until the previous operation is finished.
-        device.poll(wgpu::Maintain::Wait);
-
-        // (한국어) 다음 프레임을 가져옵니다.
-        // (English Translation) Get the next frame.
-        let frame = surface.get_current_texture()
-            .map_err(|err| game_err!(
-                "Failed to get next frame",
-                "Failed to get next frame for the following reasons: {}",
-                err.to_string()
-            ))?;
-
-        // (한국어) 프레임 버퍼의 텍스처 뷰를 생성합니다.
-        // (English Translation) Creates a texture view of the framebuffer.
-        let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-        // (한국어) 커맨드 버퍼를 생성합니다.
-        // (English Translation) Creates a command buffer.
-        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-        
-        {
-            let mut rpass = encoder.begin_render_pass(
-                &wgpu::RenderPassDescriptor {
-                    label: Some("RenderPass(InGameLoading)"),
-                    color_attachments: &[
-                        Some(wgpu::RenderPassColorAttachment {
-                            view: &view,
-                            resolve_target: None,
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                                store: wgpu::StoreOp::Store,
-                            },
-                        }),
-                    ],
-                    depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                        view: depth.view(), 
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(1.0), 
-                            store: wgpu::StoreOp::Discard,
-                        }),
-                        stencil_ops: None,
-                    }),
-                    timestamp_writes: None,
-                    occlusion_query_set: None,
-                },
-            );
-
-            camera.bind(&mut rpass);
-            text_brush.draw(&mut rpass, [self.loading_text.as_ref().unwrap()].into_iter());
-        }
+        with_frame(shared, self.clear_color(), |frame| {
+            {
+                let mut rpass = frame.begin_pass("RenderPass(InGameLoading)");
 
-        // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-        // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-        queue.submit(Some(encoder.finish()));
-        frame.present();
-
-        Ok(())
+                camera.bind(&mut rpass);
+                text_brush.draw(&mut rpass, [self.loading_text.as_ref().unwrap()].into_iter());
+            }
+        })
     }
 }
 
@@ -147,6 +177,7 @@ impl Default for InGameLoading {
         Self { 
             loading_text: None, 
             loading: None, 
+            cancel: CancelFlag::default(), 
         }
     }
 }
@@ -164,6 +195,7 @@ fn prepare_brushes(_this: &mut InGameLoading, shared: &mut Shared) -> AppResult<
     let config = shared.get::<wgpu::SurfaceConfiguration>().unwrap();
     let camera_creator = shared.get::<Arc<CameraCreator>>().unwrap();
     let asset_bundle = shared.get::<AssetBundle>().unwrap();
+    let settings = shared.get::<Settings>().unwrap();
 
     // (한국어) 총알 그리기 도구를 생성합니다.
     // (English Translation) Create a bullet drawing tool. 
@@ -180,7 +212,8 @@ fn prepare_brushes(_this: &mut InGameLoading, shared: &mut Shared) -> AppResult<
         device, 
         &camera_creator.camera_layout, 
         config.format, 
-        asset_bundle
+        asset_bundle, 
+        settings.board_size.num_tiles()
     )?;
 
     // (한국어) 생성된 그리기 도구들을 공유 객체에 추가합니다.
@@ -191,6 +224,17 @@ fn prepare_brushes(_this: &mut InGameLoading, shared: &mut Shared) -> AppResult<
     Ok(())
 }
 
+/// #### 한국어 </br>
+/// `InGame` 게임 장면을 불러오기가 취소된 경우 준비했던 그리기 도구들을 공유 객체에서 해제합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Releases the prepared drawing tools from the shared object when loading the `InGame` game scene is canceled. </br>
+/// 
+fn discard_brushes(shared: &mut Shared) {
+    shared.pop::<Arc<BulletBrush>>();
+    shared.pop::<Arc<TileBrush>>();
+}
+
 /// #### 한국어 </br>
 /// 총알을 그리는 도구를 생성합니다. </br>
 /// 
@@ -221,16 +265,17 @@ fn create_bullet_brush(
 }
 
 /// #### 한국어 </br>
-/// 타일 그리기 도구를 설정합니다. </br>
+/// 주어진 타일 수만큼 그리는 타일 그리기 도구를 설정합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Sets tile drawing tools. </br>
+/// Sets tile drawing tools that draw the given number of tiles. </br>
 /// 
 fn create_tile_brush(
     device: &wgpu::Device, 
     camera_layout: &wgpu::BindGroupLayout, 
     render_format: wgpu::TextureFormat, 
-    asset_bundle: &AssetBundle
+    asset_bundle: &AssetBundle, 
+    num_tiles: usize
 ) -> AppResult<Arc<TileBrush>> {
     TileBrush::new(
         device, 
@@ -246,7 +291,7 @@ fn create_tile_brush(
         wgpu::MultisampleState::default(), 
         None,
         asset_bundle, 
-        NUM_TILES, 
+        num_tiles, 
     )
 }
 
@@ -259,7 +304,18 @@ fn create_tile_brush(
 fn prepare_in_game_scene(this: &mut InGameLoading, shared: &mut Shared) -> AppResult<()> {
     // (한국어) 사용할 공유 객체 가져오기.
     // (English Translation) Get shared object to use.
-    let actor = shared.get::<Actor>().cloned().unwrap_or_default();
+    let actor = utils::resolve_actor(
+        shared.get::<Actor>().cloned(), 
+        shared.get::<AssetBundle>().unwrap(), 
+        cfg!(debug_assertions)
+    )?;
+
+    // (한국어) 타이틀 화면에서 이어할 수 있도록 마지막으로 플레이한 캐릭터를 기록합니다.
+    // (English Translation) Records the last played character so that it can be continued from the title screen.
+    if let Some(save) = shared.get_mut::<SaveData>() {
+        save.last_actor = Some(actor);
+    }
+
     let fonts = shared.get::<Arc<HashMap<String, FontArc>>>().unwrap().clone();
     let settings = shared.get::<Settings>().unwrap().clone();
     let script = shared.get::<Arc<Script>>().unwrap().clone();
@@ -273,25 +329,20 @@ fn prepare_in_game_scene(this: &mut InGameLoading, shared: &mut Shared) -> AppRe
     let bullet_brush = shared.get::<Arc<BulletBrush>>().unwrap().clone();
     let texture_map = shared.get::<Arc<HashMap<String, wgpu::Texture>>>().unwrap().clone();
     let asset_bundle = shared.get::<AssetBundle>().unwrap().clone();
+    let mut rng = shared.get::<Arc<GameRng>>().unwrap().fork();
+    let mut cosmetic_rng = shared.get::<Arc<CosmeticRng>>().unwrap().fork();
+    let cancel = this.cancel.clone();
 
     // (한국어) 다른 스레드에서 `InGame` 게임 장면을 준비합니다.
     // (English Translation) Prepare the `InGame` game scene in another thread. 
     this.loading = Some(thread::spawn(move || {
         // (한국어) 현재 게임 장면에서 사용할 음향 에셋들을 불러옵니다.
         // (English Translation) Loads audio assets to be used in the current game scene. 
-        asset_bundle.get(path::CLICK_SOUND_PATH)?;
-        asset_bundle.get(path::CANCEL_SOUND_PATH)?;
-        asset_bundle.get(path::START_SOUND_PATH)?;
-        asset_bundle.get(path::PAUSE_SOUND_PATH)?;
-        asset_bundle.get(path::FINISH_SOUND_PATH)?;
-        asset_bundle.get(path::THEME23_SOUND_PATH)?;
-        asset_bundle.get(path::THEME27_SOUND_PATH)?;
-        asset_bundle.get(path::YUUKA_TITLE_SOUND_PATH)?;
-        asset_bundle.get(path::YUUKA_DEFEAT_SOUND_PATH)?;
-        asset_bundle.get(path::YUUKA_VICTORY_SOUND_PATH)?;
-        asset_bundle.get(path::YUUKA_HIDDEN_SOUND_PATH)?;
-
-        utils::create_game_scene(
+        if let LoadResult::Canceled = preload::preload_scene_cancelable::<InGameLoading>(&asset_bundle, &cancel)? {
+            return Ok(LoadResult::Canceled);
+        }
+
+        let result = utils::create_game_scene(
             actor, 
             &fonts, 
             &settings, 
@@ -305,8 +356,18 @@ fn prepare_in_game_scene(this: &mut InGameLoading, shared: &mut Shared) -> AppRe
             &tile_brush, 
             &bullet_brush, 
             &texture_map, 
-            &asset_bundle
-        )
+            &asset_bundle, 
+            &mut rng, 
+            &mut cosmetic_rng, 
+            &cancel
+        )?;
+
+        // (한국어) 게임 장면을 만드는 도중 취소된 경우 미리 불러온 에셋들을 해제합니다.
+        // (English Translation) If canceled while creating the game scene, releases the preloaded assets.
+        if let LoadResult::Canceled = result {
+            preload::release_scene::<InGameLoading>(&asset_bundle);
+        }
+        Ok(result)
     }));
 
     Ok(())
@@ -342,22 +403,25 @@ fn prepare_loading_scene(this: &mut InGameLoading, shared: &mut Shared) -> AppRe
 #[derive(Debug)]
 pub struct InGameScene {
     pub timer: f64, 
-    pub remaining_time: f64, 
     pub state: state::InGameState,
+    pub back_stack: BackStack<state::InGameState>, 
+
+    pub sim: sim::SimState, 
+    pub sim_input: sim::SimInput, 
+    pub board_size: BoardSize, 
 
     pub pause_text: Text, 
     pub pause_buttons: HashMap<utils::PauseButton, (UiObject, Text)>, 
-    pub pause_exit_window: (UiObject, Text), 
-    pub pause_exit_buttons: HashMap<utils::ExitWndButton, (UiObject, Text)>, 
+    pub pause_focus: FocusRing<utils::PauseButton>, 
+    pub pause_exit_window: ModalWindow<utils::ExitWndButton>, 
     
     pub percent: Text, 
     pub percent_timer: f64, 
-    pub num_total_tiles: u32,
-    pub num_owned_tiles: u32,
     pub owned_tiles: VecDeque<(f64, Vec<(usize, usize)>)>, 
 
-    pub owned_hearts: VecDeque<UiObject>, 
-    pub lost_hearts: VecDeque<(f64, UiObject)>, 
+    pub hearts: HeartBar, 
+
+    pub combo_text: Text, 
 
     pub foreground: UiObject, 
     pub background: UiObject, 
@@ -370,6 +434,8 @@ pub struct InGameScene {
     pub result_stars: Vec<UiObject>, 
     pub result_star_index: usize, 
     pub result_challenge_texts: Vec<Text>, 
+    pub result_clear_time: Text, 
+    pub clear_time: Option<f64>, 
 
     pub table: Table, 
     pub player: Player, 
@@ -382,16 +448,52 @@ pub struct InGameScene {
     pub player_startup_sound: &'static str, 
     pub player_smile_sounds: Vec<&'static str>, 
     pub player_damage_sounds: Vec<&'static str>,
+    pub voice_cooldown: utils::VoiceCooldown, 
 
-    pub bgm_sound: &'static str, 
+    pub bgm_playlist: utils::BgmPlaylist, 
 
     pub setting_titles: Vec<Text>, 
     pub setting_windows: Vec<UiObject>, 
     pub setting_languages: HashMap<Language, (UiObject, Text)>, 
     pub setting_resolutions: HashMap<Resolution, (UiObject, Text)>, 
+    pub setting_toggles: HashMap<SettingToggle, (UiObject, Text)>, 
     pub setting_return_button: (UiObject, Text), 
+    pub setting_apply_button: (UiObject, Text), 
+    pub setting_reset_button: (UiObject, Text), 
+    pub setting_reset_window: ModalWindow<utils::ResetWndButton>, 
     pub setting_volume_background: HashMap<utils::VolumeOptions, (UiObject, Text)>,
-    pub setting_volume_bar: HashMap<utils::VolumeOptions, UiObject>, 
+    pub setting_volume_bar: HashMap<utils::VolumeOptions, Slider>, 
+    pub setting_revert_window: (UiObject, Text), 
+    pub setting_revert_countdown: Text, 
+    pub setting_revert_buttons: HashMap<utils::RevertWndButton, (UiObject, Text)>, 
+    pub setting_revert: Option<(Resolution, f64)>, 
+}
+
+impl InGameScene {
+    /// #### 한국어 </br>
+    /// 게임 장면을 준비할 때 획득한 에셋 참조들을 해제합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Releases the asset references acquired while preparing the game scene. </br>
+    /// 
+    fn release_assets(&self, asset_bundle: &AssetBundle) {
+        preload::release_scene::<InGameLoading>(asset_bundle);
+        for rel_path in self.bgm_playlist.tracks() {
+            asset_bundle.release(rel_path);
+        }
+        for rel_path in self.player_damage_sounds.iter() {
+            asset_bundle.release(rel_path);
+        }
+        for rel_path in self.player_smile_sounds.iter() {
+            asset_bundle.release(rel_path);
+        }
+        asset_bundle.release(self.player_startup_sound);
+    }
+}
+
+#[cfg(test)]
+impl crate::scene::state::SceneStates for InGameScene {
+    const NUM_STATES: usize = state::InGameState::NUM_STATES;
 }
 
 impl SceneNode for InGameScene {
@@ -418,18 +520,17 @@ impl SceneNode for InGameScene {
         // (한국어) 현재 게임 장면에서 사용되는 [`rodio::Sink`] 집합을 생성합니다.
         // (English Translation) Creates a set of [`rodio::Sink`] used in current game scene. 
         let settings = shared.get::<Settings>().unwrap();
-        let stream = shared.get::<OutputStreamHandle>().unwrap();
+        let stream = sound::output(shared);
         let audio = utils::InGameAudio::new(settings, stream)?;
+        shared.push(audio);
         
-        // (한국어) 배경 음악 소리를 재생합니다.
-        // (English Translation) Play background music sound. 
+        // (한국어) 이전 배경 음악에서 현재 게임 장면의 배경 음악으로 전환합니다.
+        // (English Translation) Switches from the previous background music to the background music of the current game scene.
+        let mut bgm = shared.pop::<sound::BgmPlayer>().unwrap();
+        let stream = sound::output(shared);
         let asset_bundle = shared.get::<AssetBundle>().unwrap();
-        let source = asset_bundle.get(self.bgm_sound)?
-            .read(&SoundDecoder)?
-            .amplify(0.5)
-            .repeat_infinite();
-        audio.background.append(source);
-        shared.push(audio);
+        bgm.crossfade_to_once(self.bgm_playlist.current(), sound::BGM_CROSSFADE_SEC, asset_bundle, stream)?;
+        shared.push(bgm);
         
         Ok(())
     }
@@ -437,29 +538,20 @@ impl SceneNode for InGameScene {
     fn exit(&mut self, shared: &mut Shared) -> AppResult<()> {
         // (한국어) 사용한 음향 에셋들을 해제합니다. 
         // (English Translation) Release used sound assets.
-        let asset_bundle = shared.get::<AssetBundle>().unwrap();
-        asset_bundle.release(path::START_SOUND_PATH);
-        asset_bundle.release(path::PAUSE_SOUND_PATH);
-        asset_bundle.release(path::FINISH_SOUND_PATH);
-        asset_bundle.release(path::THEME23_SOUND_PATH);
-        asset_bundle.release(path::THEME27_SOUND_PATH);
-        asset_bundle.release(path::YUUKA_TITLE_SOUND_PATH);
-        asset_bundle.release(path::YUUKA_DEFEAT_SOUND_PATH);
-        asset_bundle.release(path::YUUKA_VICTORY_SOUND_PATH);
-        asset_bundle.release(path::YUUKA_HIDDEN_SOUND_PATH);
-        asset_bundle.release(self.bgm_sound);
-        for rel_path in self.player_damage_sounds.iter() {
-            asset_bundle.release(rel_path);
-        }
-        for rel_path in self.player_smile_sounds.iter() {
-            asset_bundle.release(rel_path);
-        }
-        asset_bundle.release(self.player_startup_sound);
+        self.release_assets(shared.get::<AssetBundle>().unwrap());
 
         // (한국어) 현재 게임 장면에서 사용되는 [`rodio::Sink`] 집합을 해제합니다.
         // (English Translation) Releases a set of [`rodio::Sink`] used in current game scene. 
         shared.pop::<Arc<utils::InGameAudio>>().unwrap();
 
+        // (한국어) 일시정지 메뉴에서 게임 장면을 나가는 경우 일시정지를 해제합니다.
+        // (English Translation) Releases the pause when leaving the game scene from the pause menu.
+        shared.pop::<GamePaused>();
+
+        // (한국어) 숨겼던 커서를 다시 보이게 합니다.
+        // (English Translation) Shows the hidden cursor again.
+        user::set_cursor_hidden(shared, false);
+
         // (한국어) 사용한 그리기 도구를 공유객체에서 해제합니다.
         // (English Translation) Release the used drawing tool from the shared object. 
         shared.pop::<Arc<BulletBrush>>().unwrap();
@@ -470,16 +562,99 @@ impl SceneNode for InGameScene {
 
     #[inline]
     fn handle_events(&mut self, shared: &mut Shared, event: Event<AppEvent>) -> AppResult<()> {
-        state::HANDLE_EVENTS[self.state as usize](self, shared, event)
+        // (한국어) 화면이 바뀌는 중에는 버튼 입력을 무시합니다.
+        // (English Translation) Ignores button input while the screen is transitioning.
+        let transitioning = self.state.is_transitioning() || is_scene_changing(shared);
+        if ignores_button_input(transitioning, &event) {
+            return Ok(());
+        }
+
+        state_fn(&state::HANDLE_EVENTS, self.state as usize, "HANDLE_EVENTS")?(self, shared, event)
     }
 
     #[inline]
     fn update(&mut self, shared: &mut Shared, total_time: f64, elapsed_time: f64) -> AppResult<()> {
-        state::UPDATES[self.state as usize](self, shared, total_time, elapsed_time)
+        state_fn(&state::UPDATES, self.state as usize, "UPDATES")?(self, shared, total_time, elapsed_time)?;
+
+        // (한국어) 일시정지 상태가 바뀐 경우 게임 루프에 알리고 목소리 재생기를 일시정지 하거나 다시 재생합니다.
+        // (English Translation) If the pause state has changed, notifies the game loop and pauses or resumes the voice player.
+        let paused = self.state.is_paused();
+        if paused != shared.get::<GamePaused>().is_some() {
+            let audio = shared.get::<Arc<utils::InGameAudio>>().unwrap();
+            audio.set_paused(paused);
+            match paused {
+                true => shared.push(GamePaused),
+                false => shared.pop::<GamePaused>(),
+            };
+        }
+
+        // (한국어) 재생 중인 배경 음악이 끝난 경우 목록의 다음 곡을 재생합니다.
+        // (English Translation) If the playing background music has finished, plays the next track of the list.
+        if shared.get::<sound::BgmPlayer>().is_some_and(|bgm| bgm.is_finished()) {
+            let rel_path = self.bgm_playlist.advance(&mut *shared.get::<Arc<CosmeticRng>>().unwrap().lock());
+            let mut bgm = shared.pop::<sound::BgmPlayer>().unwrap();
+            let stream = sound::output(shared);
+            let asset_bundle = shared.get::<AssetBundle>().unwrap();
+            let result = bgm.crossfade_to_once(rel_path, 0.0, asset_bundle, stream);
+            shared.push(bgm);
+            result?;
+        }
+
+        // (한국어) 
+        // 설정된 경우 스테이지를 진행하는 동안 커서를 숨깁니다. 
+        // 일시정지 메뉴와 결과 화면에서는 메뉴를 조작할 수 있도록 다시 보이게 합니다.
+        //
+        // (English Translation) 
+        // If configured, hides the cursor while playing through the stage. 
+        // Shows it again in the pause menu and the result screen so the menus can be used.
+        //
+        let hide_cursor = shared.get::<Settings>().unwrap().hide_cursor_in_game;
+        user::set_cursor_hidden(shared, hide_cursor && self.state.is_gameplay());
+
+        // (한국어) 일시정지 되지 않은 경우 플레이어와 보스의 스프라이트 애니메이션을 진행합니다.
+        // (English Translation) Advances the sprite animations of the player and the boss if not paused.
+        if !paused {
+            let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+            self.player.sprite.tick(queue, elapsed_time);
+            self.boss.sprite.tick(queue, elapsed_time);
+        }
+        Ok(())
     }
 
     #[inline]
     fn draw(&self, shared: &mut Shared) -> AppResult<()> {
-        state::DRAWS[self.state as usize](self, shared)
+        state_fn(&state::DRAWS, self.state as usize, "DRAWS")?(self, shared)
+    }
+
+    fn hovers_button(&self, shared: &Shared) -> bool {
+        match self.state {
+            state::InGameState::Run => cursor::is_over_any(shared, [&self.menu_button]),
+            state::InGameState::Pause => cursor::is_over_any(
+                shared, 
+                self.pause_buttons.values().map(|(ui, _)| ui)
+            ),
+            state::InGameState::MsgBox => cursor::is_over_any(
+                shared, 
+                self.pause_exit_window.buttons.values().map(|(ui, _)| ui)
+            ),
+            state::InGameState::Setting => state::hovers_setting_button(self, shared),
+            state::InGameState::Result => cursor::is_over_any(shared, [&self.result_window_btn.0]),
+            _ => false,
+        }
+    }
+
+    fn on_shutdown(&mut self, shared: &mut Shared) -> AppResult<()> {
+        // (한국어) 재생 중인 목소리를 멈춥니다.
+        // (English Translation) Stops the playing voice.
+        if let Some(audio) = shared.get::<Arc<utils::InGameAudio>>() {
+            audio.voice.stop();
+        }
+        Ok(())
+    }
+
+    #[cfg(debug_assertions)]
+    fn on_script_reload(&mut self, shared: &mut Shared) -> AppResult<()> {
+        let script = shared.get::<Arc<Script>>().unwrap().clone();
+        state::apply_script(self, shared, &script)
     }
 }
@@ -0,0 +1,515 @@
+use crate::{
+    components::{
+        player::{self, PlayerBody, PlayerControlState, PlayerGameState, TrailStep},
+        table::{self, Capture},
+    },
+    nodes::in_game::{utils, NUM_TILES},
+};
+
+
+
+/// #### 한국어 </br>
+/// 게임 진행의 결과 목록입니다. </br>
+/// - 클리어: 플레이어가 모든 타일을 차지했습니다. </br>
+/// - 패배: 플레이어의 체력이 모두 떨어졌습니다. </br>
+/// - 시간 초과: 남은 시간이 모두 지났습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// List of game progress results. </br>
+/// - Cleared: The player occupied all tiles. </br>
+/// - Defeated: The player ran out of health. </br>
+/// - TimeOver: The remaining time has run out. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SimOutcome {
+    Cleared,
+    Defeated,
+    TimeOver,
+}
+
+impl SimOutcome {
+    /// #### 한국어 </br>
+    /// 플레이어가 이긴 결과인 경우 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if the result is a win for the player. </br>
+    /// 
+    #[inline]
+    pub fn is_win(self) -> bool {
+        matches!(self, Self::Cleared)
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 게임이 끝나는 조건을 확인하여 게임 진행의 결과를 반환합니다. 게임이 계속되는 경우 `None`을 반환합니다. </br>
+/// 같은 갱신에서 여러 조건을 만족한 경우 하트를 모두 잃은 것, 모든 타일을 차지한 것, 시간이 초과된 것 순서로 우선합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Checks the conditions that end the game and returns the result of the game progress. Returns `None` if the game continues. </br>
+/// If several conditions are met in the same update, losing all hearts takes precedence, then owning all tiles, then running out of time. </br>
+/// 
+pub fn evaluate_outcome(remaining_time: f64, hearts: usize, num_owned_tiles: u32, num_total_tiles: u32) -> Option<SimOutcome> {
+    if hearts == 0 {
+        return Some(SimOutcome::Defeated);
+    } else if num_owned_tiles >= num_total_tiles {
+        return Some(SimOutcome::Cleared);
+    } else if remaining_time <= 0.0 {
+        return Some(SimOutcome::TimeOver);
+    }
+    return None;
+}
+
+
+
+/// #### 한국어 </br>
+/// 한 번의 갱신에서 게임 진행 상태에 일어난 변화 목록입니다. </br>
+/// 화면에 그려지는 오브젝트들은 이 변화를 보고 갱신됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// List of changes made to the game progress state in one update. </br>
+/// The objects drawn on the screen are updated by looking at these changes. </br>
+/// 
+#[derive(Debug, Clone, PartialEq)]
+pub enum SimEvent {
+    /// #### 한국어 </br>
+    /// 주어진 타일이 플레이어의 경로에 추가되었습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The given tile was added to the player's trail. </br>
+    /// 
+    TrailExtended((usize, usize)),
+
+    /// #### 한국어 </br>
+    /// 주어진 타일이 플레이어의 경로에서 제거되었습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The given tile was removed from the player's trail. </br>
+    /// 
+    TrailRetracted((usize, usize)),
+
+    /// #### 한국어 </br>
+    /// 플레이어가 경로를 닫아 타일들을 획득했습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The player closed the trail and acquired the tiles. </br>
+    /// 
+    Captured(Capture),
+
+    /// #### 한국어 </br>
+    /// 플레이어가 피해를 입어 하트 하나를 잃었습니다. </br>
+    /// `trail`은 피해를 입어 비워진 경로의 타일들 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The player took damage and lost one heart. </br>
+    /// `trail` is the tiles of the trail cleared by the damage. </br>
+    /// 
+    Damaged { trail: Vec<(usize, usize)>, remaining_hearts: usize },
+}
+
+
+
+/// #### 한국어 </br>
+/// 한 번의 갱신에 주어지는 입력입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The input given to one update. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SimInput {
+    /// #### 한국어 </br>
+    /// 현재 누르고 있는 방향키 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The direction key currently being held. </br>
+    /// 
+    pub control: PlayerControlState,
+
+    /// #### 한국어 </br>
+    /// 이전 갱신 이후에 새로 누른 방향키 입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// The direction key newly pressed since the previous update. </br>
+    /// 
+    pub pressed: Option<PlayerControlState>,
+
+    /// #### 한국어 </br>
+    /// 플레이어나 플레이어의 경로가 보스 또는 총알과 충돌했는지 여부입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Whether the player or the player's trail collided with the boss or a bullet. </br>
+    /// 
+    pub hit: bool,
+}
+
+
+
+/// #### 한국어 </br>
+/// 그래픽스 자원 없이 갱신되는 게임 진행 상태입니다. </br>
+/// 타일의 방문 여부, 플레이어의 이동과 경로, 체력, 연속 획득, 남은 시간을 담고 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The game progress state updated without graphics resources. </br>
+/// It contains whether tiles are visited, the player's movement and trail, health, combo, and remaining time. </br>
+/// 
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SimState {
+    pub num_rows: usize,
+    pub num_cols: usize,
+    pub visited: Vec<Vec<bool>>,
+    pub spawn_pos: (usize, usize),
+
+    pub player: PlayerBody,
+    pub hearts: usize,
+
+    pub remaining_time: f64,
+    pub num_total_tiles: u32,
+    pub num_owned_tiles: u32,
+
+    pub combo: u32,
+    pub combo_timer: f64,
+    pub max_combo: u32,
+
+    pub outcome: Option<SimOutcome>,
+
+    /// #### 한국어 </br>
+    /// 마지막 갱신에서 일어난 변화 목록입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// List of changes made in the last update. </br>
+    /// 
+    pub events: Vec<SimEvent>,
+}
+
+impl SimState {
+    pub fn new(
+        num_rows: usize,
+        num_cols: usize,
+        spawn_pos: (usize, usize),
+        hearts: usize,
+        duration: f64
+    ) -> Self {
+        Self {
+            num_rows,
+            num_cols,
+            visited: vec![vec![false; num_cols]; num_rows],
+            spawn_pos,
+            player: PlayerBody::new(spawn_pos.0, spawn_pos.1),
+            hearts,
+            remaining_time: duration,
+            num_total_tiles: (num_rows * num_cols) as u32,
+            num_owned_tiles: 0,
+            combo: 0,
+            combo_timer: 0.0,
+            max_combo: 0,
+            outcome: None,
+            events: Vec::new(),
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 스폰 위치 주변의 영역을 플레이어가 소유하도록 합니다. </br>
+    /// 영역의 테두리는 선분 타일로, 안쪽은 소유한 타일로 반환됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Makes the player own the area around the spawn position. </br>
+    /// The border of the area is returned as edge tiles, and the inside as owned tiles. </br>
+    /// 
+    pub fn claim_spawn_area(&mut self, half_spawn_area: usize) -> Capture {
+        let (r, c) = self.spawn_pos;
+        let hs = half_spawn_area;
+        let mut capture = Capture::default();
+        for row in r - hs..=r + hs {
+            for col in c - hs..=c + hs {
+                if row == r - hs
+                || row == r + hs
+                || col == c - hs
+                || col == c + hs {
+                    self.visited[row][col] = false;
+                    capture.edge.push((row, col));
+                } else {
+                    self.visited[row][col] = true;
+                    capture.inside.push((row, col));
+                }
+            }
+        }
+
+        self.num_owned_tiles += capture.inside.len() as u32;
+        return capture;
+    }
+
+    /// #### 한국어 </br>
+    /// 플레이어가 차지한 영역의 비율을 백분율로 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the ratio of the area occupied by the player as a percentage. </br>
+    /// 
+    #[inline]
+    pub fn percent(&self) -> f32 {
+        self.num_owned_tiles as f32 / self.num_total_tiles as f32 * 100.0
+    }
+
+    /// #### 한국어 </br>
+    /// 차지한 타일의 수를 기본 크기 보드의 타일 수로 환산하여 반환합니다. </br>
+    /// 세이브 파일에 진행도를 기록할 때 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the number of owned tiles converted to the number of tiles of the standard size board. </br>
+    /// Used when recording the progress in the save file. </br>
+    /// 
+    #[inline]
+    pub fn standard_owned_tiles(&self) -> u32 {
+        let scaled = self.num_owned_tiles as u64 * NUM_TILES as u64 / self.num_total_tiles as u64;
+        return scaled.min(NUM_TILES as u64) as u32;
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 주어진 입력으로 게임 진행 상태를 `elapsed_time`만큼 진행시킨 상태를 반환합니다. </br>
+/// 그래픽스 자원을 사용하지 않으며, 같은 상태와 입력이 주어지면 항상 같은 결과를 반환합니다. </br>
+/// 이번 갱신에서 일어난 변화는 반환된 상태의 `events`에 담깁니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the game progress state advanced by `elapsed_time` with the given input. </br>
+/// It does not use graphics resources, and always returns the same result for the same state and input. </br>
+/// The changes made in this update are stored in the `events` of the returned state. </br>
+/// 
+pub fn step(mut state: SimState, input: &SimInput, elapsed_time: f64) -> SimState {
+    state.events.clear();
+    if state.outcome.is_some() {
+        return state;
+    }
+
+    // (한국어) 입력을 적용합니다.
+    // (English Translation) Applies the input.
+    state.player.control_state = input.control;
+    if let Some(direction) = input.pressed {
+        state.player.buffer_input(direction);
+    }
+
+    // (한국어) 플레이어를 이동시키고, 도착한 타일에 따라 경로를 갱신합니다.
+    // (English Translation) Moves the player, and updates the trail according to the arrived tile.
+    state.player.tick_game_state(elapsed_time);
+    state.player.update(elapsed_time);
+    match player::check_current_pos(&mut state.visited, &mut state.player) {
+        Some(TrailStep::Extended(pos)) => state.events.push(SimEvent::TrailExtended(pos)),
+        Some(TrailStep::Retracted(pos)) => state.events.push(SimEvent::TrailRetracted(pos)),
+        Some(TrailStep::Closed) => capture(&mut state),
+        Some(TrailStep::Crossed) => damage(&mut state),
+        None => { /* empty */ }
+    }
+    player::set_player_next_position(state.num_rows, state.num_cols, &mut state.player);
+
+    // (한국어) 무적 상태가 아닌 경우 충돌로 피해를 입습니다.
+    // (English Translation) Takes damage from the collision if not invincible.
+    if input.hit && state.player.game_state != PlayerGameState::Invincibility && state.hearts > 0 {
+        damage(&mut state);
+    }
+
+    // (한국어) 일정 시간 동안 영역을 획득하지 못하면 연속 획득이 끊깁니다.
+    // (English Translation) If no area is captured for a while, the combo is broken.
+    state.combo_timer += elapsed_time;
+    if state.combo_timer > utils::COMBO_WINDOW_SEC {
+        state.combo = 0;
+    }
+
+    // (한국어) 남은 시간을 갱신하고 게임이 끝났는지 확인합니다.
+    // (English Translation) Updates the remaining time and checks whether the game is over.
+    state.remaining_time = (state.remaining_time - elapsed_time).max(0.0);
+    state.outcome = evaluate_outcome(state.remaining_time, state.hearts, state.num_owned_tiles, state.num_total_tiles);
+
+    return state;
+}
+
+/// #### 한국어 </br>
+/// 닫힌 경로로 둘러싸인 타일들을 획득하고 연속 획득 수를 갱신합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Acquires the tiles surrounded by the closed trail and updates the combo count. </br>
+/// 
+fn capture(state: &mut SimState) {
+    let capture = table::capture_tiles(&mut state.visited, &mut state.player.path);
+    state.num_owned_tiles += capture.inside.len() as u32;
+    state.combo = utils::next_combo(state.combo, state.combo_timer);
+    state.combo_timer = 0.0;
+    state.max_combo = state.max_combo.max(state.combo);
+    state.events.push(SimEvent::Captured(capture));
+}
+
+/// #### 한국어 </br>
+/// 플레이어의 하트 하나를 잃고 경로를 비웁니다. </br>
+/// 하트가 남아있으면 플레이어를 스폰 위치로 되돌립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Loses one of the player's hearts and clears the trail. </br>
+/// If hearts remain, the player returns to the spawn position. </br>
+/// 
+fn damage(state: &mut SimState) {
+    state.hearts = state.hearts.saturating_sub(1);
+    let trail = player::clear_trail(&mut state.visited, &mut state.player.path);
+    if state.hearts > 0 {
+        player::respawn(&mut state.player, state.spawn_pos);
+    }
+    state.events.push(SimEvent::Damaged { trail, remaining_hearts: state.hearts });
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::components::player::INVINCIBILITY_DURATION;
+
+    const DT: f64 = 0.05;
+
+    fn create_state() -> SimState {
+        let mut state = SimState::new(16, 16, (6, 6), 3, 90.0);
+        state.claim_spawn_area(2);
+        state
+    }
+
+    /// #### 한국어 </br>
+    /// 조작 없이 주어진 타일까지 걸어가며 일어난 변화들을 모읍니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Walks to the given tile without control input and collects the changes made along the way. </br>
+    /// 
+    fn walk_to(mut state: SimState, target: (usize, usize)) -> (SimState, Vec<SimEvent>) {
+        let mut events = Vec::new();
+        state.player.set_target(target.0, target.1);
+        for _ in 0..1000 {
+            state = step(state, &SimInput::default(), DT);
+            events.append(&mut state.events);
+            if state.player.curr == target && state.player.next.is_none() {
+                return (state, events);
+            }
+        }
+        panic!("The player never reached the target!");
+    }
+
+    #[test]
+    fn closing_the_trail_captures_the_enclosed_area() {
+        let state = create_state();
+        assert_eq!(state.num_owned_tiles, 9);
+
+        let (state, events) = walk_to(state, (6, 9));
+        assert_eq!(events, vec![SimEvent::TrailExtended((6, 8)), SimEvent::TrailExtended((6, 9))]);
+
+        let (state, _) = walk_to(state, (9, 9));
+        let (state, _) = walk_to(state, (9, 6));
+        let (state, events) = walk_to(state, (7, 6));
+        let Some(SimEvent::Captured(capture)) = events.last() else {
+            panic!("The trail was not closed!");
+        };
+
+        // (한국어) 경로 9개와 경로로 둘러싸인 타일 3개를 획득합니다.
+        // (English Translation) Acquires the 9 trail tiles and the 3 tiles surrounded by the trail.
+        assert_eq!(capture.inside.len(), 12);
+        assert!([(7, 8), (8, 7), (8, 8)].iter().all(|pos| capture.inside.contains(pos)));
+        assert!(capture.inside.iter().all(|&(r, c)| state.visited[r][c]));
+        assert!(state.player.path.is_empty());
+        assert_eq!(state.num_owned_tiles, 21);
+        assert_eq!(state.combo, 1);
+        assert_eq!(state.max_combo, 1);
+    }
+
+    #[test]
+    fn hits_lose_hearts_until_defeated() {
+        let hit = SimInput { hit: true, ..Default::default() };
+        let (state, _) = walk_to(create_state(), (6, 9));
+
+        // (한국어) 피해를 입으면 경로가 비워지고 스폰 위치로 돌아갑니다.
+        // (English Translation) Taking damage clears the trail and returns to the spawn position.
+        let state = step(state, &hit, DT);
+        assert_eq!(state.hearts, 2);
+        assert_eq!(state.events, vec![SimEvent::Damaged { trail: vec![(6, 8), (6, 9)], remaining_hearts: 2 }]);
+        assert!(!state.visited[6][8] && !state.visited[6][9]);
+        assert_eq!(state.player.curr, state.spawn_pos);
+
+        // (한국어) 무적 상태인 동안에는 피해를 입지 않습니다.
+        // (English Translation) No damage is taken while invincible.
+        let state = step(state, &hit, DT);
+        assert_eq!(state.hearts, 2);
+        assert!(state.events.is_empty());
+
+        let state = step(state, &SimInput::default(), INVINCIBILITY_DURATION);
+        let state = step(state, &hit, DT);
+        assert_eq!(state.hearts, 1);
+
+        let state = step(state, &SimInput::default(), INVINCIBILITY_DURATION);
+        let state = step(state, &hit, DT);
+        assert_eq!(state.hearts, 0);
+        assert_eq!(state.outcome, Some(SimOutcome::Defeated));
+
+        // (한국어) 게임이 끝난 후에는 상태가 변하지 않습니다.
+        // (English Translation) The state no longer changes after the game is over.
+        let next = step(state.clone(), &hit, DT);
+        assert!(next.events.is_empty());
+        assert_eq!(
+            (next.hearts, next.remaining_time, next.outcome), 
+            (state.hearts, state.remaining_time, state.outcome)
+        );
+    }
+
+    #[test]
+    fn time_over_and_clear_end_the_game() {
+        let state = step(create_state(), &SimInput::default(), 45.0);
+        assert_eq!(state.remaining_time, 45.0);
+        assert_eq!(state.outcome, None);
+
+        let state = step(state, &SimInput::default(), 45.0);
+        assert_eq!(state.remaining_time, 0.0);
+        assert_eq!(state.outcome, Some(SimOutcome::TimeOver));
+
+        let mut state = create_state();
+        state.num_owned_tiles = state.num_total_tiles;
+        let state = step(state, &SimInput::default(), DT);
+        assert_eq!(state.outcome, Some(SimOutcome::Cleared));
+    }
+
+    #[test]
+    fn smaller_board_reaches_full_percent() {
+        use crate::components::table::BoardSize;
+
+        let board_size = BoardSize::Small;
+        let mut state = SimState::new(board_size.num_rows(), board_size.num_cols(), (25, 25), 3, 90.0);
+        assert_eq!(state.num_total_tiles as usize, board_size.num_tiles());
+
+        state.num_owned_tiles = state.num_total_tiles / 2;
+        assert_eq!(state.percent(), 50.0);
+        assert_eq!(state.standard_owned_tiles() as usize, NUM_TILES / 2);
+
+        state.num_owned_tiles = state.num_total_tiles;
+        assert_eq!(state.percent(), 100.0);
+        assert_eq!(state.standard_owned_tiles() as usize, NUM_TILES);
+
+        let state = step(state, &SimInput::default(), DT);
+        assert_eq!(state.outcome, Some(SimOutcome::Cleared));
+    }
+
+    #[test]
+    fn outcome_rules() {
+        assert_eq!(evaluate_outcome(30.0, 3, 100, 400), None);
+
+        // (한국어) 차지한 영역이 부족한 채로 시간이 초과되면 패배합니다.
+        // (English Translation) Running out of time without enough area is a loss.
+        let outcome = evaluate_outcome(0.0, 3, 100, 400);
+        assert_eq!(outcome, Some(SimOutcome::TimeOver));
+        assert!(!outcome.unwrap().is_win());
+
+        // (한국어) 하트를 모두 잃으면 패배하며, 다른 조건보다 우선합니다.
+        // (English Translation) Losing all hearts is a loss, and it takes precedence over the other conditions.
+        let outcome = evaluate_outcome(30.0, 0, 100, 400);
+        assert_eq!(outcome, Some(SimOutcome::Defeated));
+        assert!(!outcome.unwrap().is_win());
+        assert_eq!(evaluate_outcome(0.0, 0, 400, 400), Some(SimOutcome::Defeated));
+
+        // (한국어) 모든 타일을 차지하면 승리하며, 시간 초과보다 우선합니다.
+        // (English Translation) Owning all tiles is a win, and it takes precedence over running out of time.
+        let outcome = evaluate_outcome(30.0, 3, 400, 400);
+        assert_eq!(outcome, Some(SimOutcome::Cleared));
+        assert!(outcome.unwrap().is_win());
+        assert_eq!(evaluate_outcome(0.0, 1, 400, 400), Some(SimOutcome::Cleared));
+    }
+}
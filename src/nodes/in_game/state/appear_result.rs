@@ -3,7 +3,6 @@ use std::sync::Arc;
 use winit::event::Event;
 
 use crate::{
-    game_err, 
     components::{
         ui::UiBrush, 
         text::TextBrush, 
@@ -15,9 +14,10 @@ use crate::{
         InGameScene, 
         state::InGameState, 
     },
-    render::depth::DepthBuffer,
+    render::frame::with_frame,
+    scene::node::SceneNode,
     system::{
-        error::{AppResult, GameError}, 
+        error::AppResult, 
         event::AppEvent, 
         shared::Shared, 
     }, 
@@ -48,7 +48,7 @@ pub fn update(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, ela
     this.result_window_btn.1.update(queue, |data| {
         data.color.w = delta;
     });
-    for text in this.result_challenge_texts.iter() {
+    for text in this.result_challenge_texts.iter().chain(Some(&this.result_clear_time)) {
         text.update(queue, |data| {
             data.color.w = delta;
         });
@@ -75,120 +75,40 @@ pub fn update(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, ela
 
 pub fn draw(this: &InGameScene, shared: &mut Shared) -> AppResult<()> {
     // (한국어) 사용할 공유 객체 가져오기.
-    let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
     let camera = shared.get::<Arc<GameCamera>>().unwrap();
     let ui_brush = shared.get::<Arc<UiBrush>>().unwrap();
     let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
     let tile_brush = shared.get::<Arc<TileBrush>>().unwrap();
     // let sprite_brush = shared.get::<Arc<SpriteBrush>>().unwrap();
 
-    // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-    // (English Translation) Wait until the previous operation is finished.
-    device.poll(wgpu::Maintain::Wait);
-
-    // (한국어) 다음 프레임을 가져옵니다.
-    // (English Translation) Get the next frame.
-    let frame = surface.get_current_texture()
-        .map_err(|err| game_err!(
-            "Failed to get next frame",
-            "Failed to get next frame for the following reasons: {}",
-            err.to_string()
-        ))?;
-
-    // (한국어) 프레임 버퍼의 텍스처 뷰를 생성합니다.
-    // (English Translation) Creates a texture view of the framebuffer.
-    let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-    // (한국어) 커맨드 버퍼를 생성합니다.
-    // (English Translation) Creates a command buffer.
-    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-    
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(AppearResult(Background)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view, 
-                        resolve_target: None, 
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                            store: wgpu::StoreOp::Store, 
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(
-                    wgpu::RenderPassDepthStencilAttachment {
-                        view: depth.view(), 
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(1.0),
-                            store: wgpu::StoreOp::Store, 
-                        }), 
-                        stencil_ops: None, 
-                    }
-                ), 
-                timestamp_writes: None,
-                occlusion_query_set: None, 
-            }
-        );
-
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        ui_brush.draw(&mut rpass, [
-            &this.background, 
-            &this.stage_images[this.result_star_index.min(3)], 
-        ].into_iter());
-        tile_brush.draw(&mut rpass);
-    }
-
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(AppearResult(Ui)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: wgpu::StoreOp::Store,
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment { 
-                    view: depth.view(), 
-                    depth_ops: Some(wgpu::Operations { 
-                        load: wgpu::LoadOp::Clear(1.0), 
-                        store: wgpu::StoreOp::Store 
-                    }), 
-                    stencil_ops: None 
-                }),
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            },
-        );
-
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        ui_brush.draw(&mut rpass, [
-            &this.result_window_btn.0, 
-            &this.result_title, 
-            &this.result_stars[this.result_star_index], 
-        ].into_iter());
-        text_brush.draw(&mut rpass, [&this.percent, &this.result_window_btn.1].into_iter());
-        text_brush.draw(&mut rpass, this.result_challenge_texts.iter());
-    }
-
-    // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-    // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-    queue.submit(Some(encoder.finish()));
-    frame.present();
-
-    Ok(())
+    with_frame(shared, this.clear_color(), |frame| {
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(AppearResult(Background)))");
+
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            ui_brush.draw(&mut rpass, [
+                &this.background, 
+                &this.stage_images[this.result_star_index.min(3)], 
+            ].into_iter());
+            tile_brush.draw(&mut rpass);
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(AppearResult(Ui)))");
+
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            ui_brush.draw(&mut rpass, [
+                &this.result_window_btn.0, 
+                &this.result_title, 
+                &this.result_stars[this.result_star_index], 
+            ].into_iter());
+            text_brush.draw(&mut rpass, [&this.percent, &this.result_window_btn.1].into_iter());
+            text_brush.draw(&mut rpass, this.result_challenge_texts.iter().chain(Some(&this.result_clear_time)));
+        }
+    })
 }
 
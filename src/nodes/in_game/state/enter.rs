@@ -3,11 +3,10 @@ use std::sync::Arc;
 use winit::event::Event;
 
 use crate::{
-    game_err, 
     components::{
         ui::UiBrush, 
         text::TextBrush, 
-        table::TileBrush, 
+        table::{self, TileBrush}, 
         camera::GameCamera, 
         interpolation, 
     },
@@ -15,9 +14,10 @@ use crate::{
         InGameScene, 
         state::InGameState, 
     },
-    render::depth::DepthBuffer,
+    render::frame::with_frame,
+    scene::node::SceneNode,
     system::{
-        error::{AppResult, GameError}, 
+        error::AppResult, 
         event::AppEvent, 
         shared::Shared, 
     }, 
@@ -51,42 +51,18 @@ pub fn update(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, ela
     if this.timer >= DURATION {
         // (한국어) 플레이어 주변 타일을 비웁니다.
         // (English Translation) Clears tiles around the player. 
-        let (r, c) = this.table.player_spawn_pos;
-        let hs = this.table.half_spawn_area;
-        let mut temp = Vec::with_capacity(4 * hs * hs);
-        for row in r - hs..=r + hs {
-            for col in c - hs..=c + hs {
-                if row == r - hs 
-                || row == r + hs
-                || col == c - hs
-                || col == c + hs {
-                    this.table.tiles[row][col].color = this.table.edge_color;
-                    this.table.tiles[row][col].visited = false;
-                } else {
-                    this.num_owned_tiles += 1;
-                    this.table.tiles[row][col].color = this.table.fill_color;
-                    this.table.tiles[row][col].visited = true;
-                    temp.push((row, col));
-                }
-            }
-        }
+        let capture = this.sim.claim_spawn_area(this.table.half_spawn_area);
         
         // (한국어) 타일의 변경된 내용을 적용합니다.
         // (English Translation) Apply changes to the tile. 
         let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
         let tile_brush = shared.get::<Arc<TileBrush>>().unwrap();
-        tile_brush.update(queue, |instances| {
-            for row in r - hs..=r + hs {
-                for col in c - hs..=c + hs {
-                    instances[row * this.table.num_cols + col].color = this.table.tiles[row][col].color;
-                }
-            }
-        });
+        table::apply_capture(queue, tile_brush, &mut this.table, &capture);
 
         // (한국어) 플레이어가 소유한 영역을 갱신합니다.
         // (English Translation) Updates player owned area. 
-        this.owned_tiles.push_back((0.0, temp));
-        let per = this.num_owned_tiles as f32 /  this.num_total_tiles as f32 * 100.0;
+        this.owned_tiles.push_back((0.0, capture.inside));
+        let per = this.sim.percent();
         let device = shared.get::<Arc<wgpu::Device>>().unwrap();
         let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
         let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
@@ -107,187 +83,52 @@ pub fn update(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, ela
 pub fn draw(this: &InGameScene, shared: &mut Shared) -> AppResult<()> {
     // (한국어) 사용할 공유 객체 가져오기.
     // (English Translation) Get shared object to use. 
-    let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
     let ui_brush = shared.get::<Arc<UiBrush>>().unwrap();
     let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
     let tile_brush = shared.get::<Arc<TileBrush>>().unwrap();
     let camera = shared.get::<Arc<GameCamera>>().unwrap();
 
-    // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-    // (English Translation) Wait until the previous operation is finished.
-    device.poll(wgpu::Maintain::Wait);
-
-    // (한국어) 다음 프레임을 가져옵니다.
-    // (English Translation) Get the next frame.
-    let frame = surface.get_current_texture()
-        .map_err(|err| game_err!(
-            "Failed to get next frame",
-            "Failed to get next frame for the following reasons: {}",
-            err.to_string()
-        ))?;
-
-    // (한국어) 프레임 버퍼의 텍스처 뷰를 생성합니다.
-    // (English Translation) Creates a texture view of the framebuffer.
-    let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-    // (한국어) 커맨드 버퍼를 생성합니다.
-    // (English Translation) Creates a command buffer.
-    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-    
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(Enter(Background)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view, 
-                        resolve_target: None, 
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                            store: wgpu::StoreOp::Store, 
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(
-                    wgpu::RenderPassDepthStencilAttachment {
-                        view: depth.view(), 
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(1.0),
-                            store: wgpu::StoreOp::Store, 
-                        }), 
-                        stencil_ops: None, 
-                    }
-                ), 
-                timestamp_writes: None,
-                occlusion_query_set: None, 
-            }
-        );
-
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        ui_brush.draw(&mut rpass, [
-            &this.background, 
-            &this.stage_images[this.result_star_index.min(3)], 
-            ].into_iter());
-        tile_brush.draw(&mut rpass);
-    }
-
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(Enter(Ui)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view, 
-                        resolve_target: None, 
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: wgpu::StoreOp::Store, 
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(
-                    wgpu::RenderPassDepthStencilAttachment {
-                        view: depth.view(), 
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(1.0),
-                            store: wgpu::StoreOp::Store, 
-                        }), 
-                        stencil_ops: None, 
-                    }
-                ), 
-                timestamp_writes: None,
-                occlusion_query_set: None, 
-            }
-        );
-
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        ui_brush.draw(&mut rpass, [&this.menu_button, &this.remaining_timer_bg].into_iter());
-        text_brush.draw(&mut rpass, [&this.remaining_timer_text, &this.percent].into_iter());
-    }
-
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(Enter(Foreground)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view, 
-                        resolve_target: None, 
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: wgpu::StoreOp::Store, 
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(
-                    wgpu::RenderPassDepthStencilAttachment {
-                        view: depth.view(), 
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(1.0),
-                            store: wgpu::StoreOp::Store, 
-                        }), 
-                        stencil_ops: None, 
-                    }
-                ), 
-                timestamp_writes: None,
-                occlusion_query_set: None, 
-            }
-        );
+    with_frame(shared, this.clear_color(), |frame| {
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(Enter(Background)))");
+
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            ui_brush.draw(&mut rpass, [
+                &this.background, 
+                &this.stage_images[this.result_star_index.min(3)], 
+                ].into_iter());
+            tile_brush.draw(&mut rpass);
+        }
 
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        ui_brush.draw(&mut rpass, [&this.foreground].into_iter());
-    }
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(Enter(Ui)))");
 
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(Enter(Foreground)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view, 
-                        resolve_target: None, 
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: wgpu::StoreOp::Store, 
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(
-                    wgpu::RenderPassDepthStencilAttachment {
-                        view: depth.view(), 
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(1.0),
-                            store: wgpu::StoreOp::Store, 
-                        }), 
-                        stencil_ops: None, 
-                    }
-                ), 
-                timestamp_writes: None,
-                occlusion_query_set: None, 
-            }
-        );
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            ui_brush.draw(&mut rpass, [&this.menu_button, &this.remaining_timer_bg].into_iter());
+            text_brush.draw(&mut rpass, [&this.remaining_timer_text, &this.percent].into_iter());
+        }
 
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        ui_brush.draw(&mut rpass, [&this.foreground].into_iter());
-    }
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(Enter(Foreground)))");
 
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            ui_brush.draw(&mut rpass, [&this.foreground].into_iter());
+        }
 
-    // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-    // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-    queue.submit(Some(encoder.finish()));
-    frame.present();
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(Enter(Foreground)))");
 
-    Ok(())
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            ui_brush.draw(&mut rpass, [&this.foreground].into_iter());
+        }
+    })
 }
 
@@ -3,7 +3,6 @@ use std::sync::Arc;
 use winit::event::Event;
 
 use crate::{
-    game_err, 
     components::{
         ui::UiBrush, 
         text::TextBrush, 
@@ -13,13 +12,11 @@ use crate::{
         camera::GameCamera, 
         interpolation, 
     },
-    nodes::in_game::{
-        InGameScene, 
-        state::InGameState, 
-    },
-    render::depth::DepthBuffer, 
+    nodes::in_game::InGameScene,
+    render::frame::with_frame, 
+    scene::node::SceneNode,
     system::{
-        error::{AppResult, GameError}, 
+        error::AppResult, 
         event::AppEvent, 
         shared::Shared,
     },
@@ -55,12 +52,15 @@ pub fn update(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, ela
     let scale = 1.0 - 1.0 * delta;
     let iter = [
             &this.setting_return_button.0, 
+            &this.setting_apply_button.0, 
+            &this.setting_reset_button.0, 
         ].into_iter()
         .chain(this.setting_windows.iter())
         .chain(this.setting_languages.values().map(|(it, _)| it))
         .chain(this.setting_resolutions.values().map(|(it, _)| it))
+        .chain(this.setting_toggles.values().map(|(it, _)| it))
         .chain(this.setting_volume_background.values().map(|(it, _)| it))
-        .chain(this.setting_volume_bar.values());
+        .chain(this.setting_volume_bar.values().map(|it| &it.handle));
     for ui in iter {
         ui.update(queue, |data| {
             data.global_scale = (scale, scale, scale).into() 
@@ -69,10 +69,13 @@ pub fn update(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, ela
 
     let iter = [
             &this.setting_return_button.1, 
+            &this.setting_apply_button.1, 
+            &this.setting_reset_button.1, 
         ].into_iter()
         .chain(this.setting_titles.iter())
         .chain(this.setting_languages.values().map(|(_, it)| it))
         .chain(this.setting_resolutions.values().map(|(_, it)| it))
+        .chain(this.setting_toggles.values().map(|(_, it)| it))
         .chain(this.setting_volume_background.values().map(|(_, it)| it));
     for text in iter {
         text.update(queue, |data| {
@@ -84,7 +87,7 @@ pub fn update(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, ela
     // (English Translation) Changes to the next state if it is greater than the duration. 
     if this.timer >= DURATION {
         shared.pop::<usize>().unwrap();
-        this.state = InGameState::Pause;
+        this.state = this.back_stack.current();
         this.timer = 0.0;
     }
     
@@ -94,10 +97,6 @@ pub fn update(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, ela
 pub fn draw(this: &InGameScene, shared: &mut Shared) -> AppResult<()> {
     // (한국어) 사용할 공유 객체들을 가져옵니다.
     // (English Translation) Get shared objects to use.
-    let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
     let camera = shared.get::<Arc<GameCamera>>().unwrap();
     let ui_brush = shared.get::<Arc<UiBrush>>().unwrap();
     let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
@@ -106,235 +105,92 @@ pub fn draw(this: &InGameScene, shared: &mut Shared) -> AppResult<()> {
     let bullet_brush = shared.get::<Arc<BulletBrush>>().unwrap();
 
 
-    // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-    // (English Translation) Wait until the previous operation is finished.
-    device.poll(wgpu::Maintain::Wait);
-
-    // (한국어) 다음 프레임을 가져옵니다.
-    // (English Translation) Get the next frame.
-    let frame = surface.get_current_texture()
-        .map_err(|err| game_err!(
-            "Failed to get next frame",
-            "Failed to get next frame for the following reasons: {}",
-            err.to_string()
-        ))?;
-
-    // (한국어) 프레임 버퍼의 텍스처 뷰를 생성합니다.
-    // (English Translation) Creates a texture view of the framebuffer.
-    let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-    // (한국어) 커맨드 버퍼를 생성합니다.
-    // (English Translation) Creates a command buffer.
-    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(ExitSetting(Background)))"), 
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view, 
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(wgpu::Color::BLACK), 
-                            store: wgpu::StoreOp::Store,
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(
-                    wgpu::RenderPassDepthStencilAttachment {
-                        view: depth.view(), 
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(1.0), 
-                            store: wgpu::StoreOp::Store, 
-                        }),
-                        stencil_ops: None,
-                    },
-                ),
-                timestamp_writes: None,
-                occlusion_query_set: None, 
-            },
-        );
-
-        camera.bind(&mut rpass);
-
-        let iter = [
-                &this.background, 
-                &this.stage_images[this.result_star_index.min(3)], 
-                &this.player_faces[&this.player.face_state], 
-                &this.boss_faces[&this.boss.face_state], 
-                &this.menu_button, 
-                &this.remaining_timer_bg, 
-            ].into_iter()
-            .chain(this.owned_hearts.iter())
-            .chain(this.lost_hearts.iter().map(|(_, it)| it));
-        ui_brush.draw(&mut rpass, iter);
-
-        text_brush.draw(&mut rpass, [
-            &this.remaining_timer_text, 
-            &this.percent, 
-        ].into_iter());
-
-        tile_brush.draw(&mut rpass);
-    }
-
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(ExitSetting(Sprite)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: wgpu::StoreOp::Store,
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment { 
-                    view: depth.view(), 
-                    depth_ops: Some(wgpu::Operations { 
-                        load: wgpu::LoadOp::Clear(1.0), 
-                        store: wgpu::StoreOp::Store 
-                    }), 
-                    stencil_ops: None 
-                }),
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            },
-        );
-
-        camera.bind(&mut rpass);
-        
-        sprite_brush.draw(&mut rpass, [
-            &this.player.sprite, 
-            &this.boss.sprite, 
-        ].into_iter());
-
-        bullet_brush.draw(&mut rpass, [&this.enemy_bullet].into_iter());
-    }
-
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(ExitSetting(Foreground)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: wgpu::StoreOp::Store,
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment { 
-                    view: depth.view(), 
-                    depth_ops: Some(wgpu::Operations { 
-                        load: wgpu::LoadOp::Clear(1.0), 
-                        store: wgpu::StoreOp::Store 
-                    }), 
-                    stencil_ops: None 
-                }),
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            },
-        );
-
-        camera.bind(&mut rpass);
-        ui_brush.draw(&mut rpass, [&this.foreground].into_iter());
-    }
-
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(ExitSetting(PauseUI)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: wgpu::StoreOp::Store,
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment { 
-                    view: depth.view(), 
-                    depth_ops: Some(wgpu::Operations { 
-                        load: wgpu::LoadOp::Clear(1.0), 
-                        store: wgpu::StoreOp::Store 
-                    }), 
-                    stencil_ops: None 
-                }),
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            },
-        );
-
-        camera.bind(&mut rpass);
-        
-        ui_brush.draw(&mut rpass, this.pause_buttons.values().map(|(it, _)| it));
-        
-        let iter = [&this.pause_text].into_iter()
-            .chain(this.pause_buttons.values().map(|(_, it)| it));
-        text_brush.draw(&mut rpass, iter);
-    }
-
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(ExitSetting(SettingUI)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: wgpu::StoreOp::Store,
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment { 
-                    view: depth.view(), 
-                    depth_ops: Some(wgpu::Operations { 
-                        load: wgpu::LoadOp::Clear(1.0), 
-                        store: wgpu::StoreOp::Store 
-                    }), 
-                    stencil_ops: None 
-                }),
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            },
-        );
-
-        camera.bind(&mut rpass);
-
-        let iter = [
-                &this.setting_return_button.0, 
-            ].into_iter()
-            .chain(this.setting_windows.iter())
-            .chain(this.setting_languages.values().map(|(it, _)| it))
-            .chain(this.setting_resolutions.values().map(|(it, _)| it))
-            .chain(this.setting_volume_background.values().map(|(it, _)| it))
-            .chain(this.setting_volume_bar.values());
-        ui_brush.draw(&mut rpass, iter);
-
-        let iter = [
-                &this.setting_return_button.1, 
-            ].into_iter()
-            .chain(this.setting_titles.iter())
-            .chain(this.setting_languages.values().map(|(_, it)| it))
-            .chain(this.setting_resolutions.values().map(|(_, it)| it))
-            .chain(this.setting_volume_background.values().map(|(_, it)| it));
-        text_brush.draw(&mut rpass, iter);
-    }
-
-
-    // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-    // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-    queue.submit(Some(encoder.finish()));
-    frame.present();
-
-    Ok(())
+    with_frame(shared, this.clear_color(), |frame| {
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(ExitSetting(Background)))");
+
+            camera.bind(&mut rpass);
+
+            let iter = [
+                    &this.background, 
+                    &this.stage_images[this.result_star_index.min(3)], 
+                    &this.player_faces[&this.player.face_state], 
+                    &this.boss_faces[&this.boss.face_state], 
+                    &this.menu_button, 
+                    &this.remaining_timer_bg, 
+                ].into_iter()
+                .chain(this.hearts.iter());
+            ui_brush.draw(&mut rpass, iter);
+
+            text_brush.draw(&mut rpass, [
+                &this.remaining_timer_text, 
+                &this.percent, 
+            ].into_iter());
+
+            tile_brush.draw(&mut rpass);
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(ExitSetting(Sprite)))");
+
+            camera.bind(&mut rpass);
+
+            sprite_brush.draw(&mut rpass, [
+                &this.player.sprite, 
+                &this.boss.sprite, 
+            ].into_iter());
+
+            bullet_brush.draw(&mut rpass, [&this.enemy_bullet].into_iter());
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(ExitSetting(Foreground)))");
+
+            camera.bind(&mut rpass);
+            ui_brush.draw(&mut rpass, [&this.foreground].into_iter());
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(ExitSetting(PauseUI)))");
+
+            camera.bind(&mut rpass);
+
+            ui_brush.draw(&mut rpass, this.pause_buttons.values().map(|(it, _)| it));
+
+            let iter = [&this.pause_text].into_iter()
+                .chain(this.pause_buttons.values().map(|(_, it)| it));
+            text_brush.draw(&mut rpass, iter);
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(ExitSetting(SettingUI)))");
+
+            camera.bind(&mut rpass);
+
+            let iter = [
+                    &this.setting_return_button.0, 
+                    &this.setting_apply_button.0, 
+                    &this.setting_reset_button.0, 
+                ].into_iter()
+                .chain(this.setting_windows.iter())
+                .chain(this.setting_languages.values().map(|(it, _)| it))
+                .chain(this.setting_resolutions.values().map(|(it, _)| it))
+                .chain(this.setting_toggles.values().map(|(it, _)| it))
+                .chain(this.setting_volume_background.values().map(|(it, _)| it))
+                .chain(this.setting_volume_bar.values().map(|it| &it.handle));
+            ui_brush.draw(&mut rpass, iter);
+
+            let iter = [
+                    &this.setting_return_button.1, 
+                    &this.setting_apply_button.1, 
+                    &this.setting_reset_button.1, 
+                ].into_iter()
+                .chain(this.setting_titles.iter())
+                .chain(this.setting_languages.values().map(|(_, it)| it))
+                .chain(this.setting_resolutions.values().map(|(_, it)| it))
+                .chain(this.setting_toggles.values().map(|(_, it)| it))
+                .chain(this.setting_volume_background.values().map(|(_, it)| it));
+            text_brush.draw(&mut rpass, iter);
+        }
+    })
 }
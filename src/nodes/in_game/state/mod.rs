@@ -16,10 +16,16 @@ mod disappear_run;
 mod appear_result;
 mod result;
 
+pub use setting::hovers_setting_button;
+#[cfg(debug_assertions)]
+pub use setting::apply_script;
+
 use winit::event::Event;
 
 use crate::{
+    state_enum,
     nodes::in_game::InGameScene,
+    scene::back_stack::Layer, 
     system::{
         error::AppResult,
         event::AppEvent,
@@ -29,32 +35,122 @@ use crate::{
 
 
 
+state_enum! {
+    /// #### 한국어 </br>
+    /// `InGame` 게임 장면의 상태 목록입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// State list of `InGame` game scene. </br>
+    /// 
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub enum InGameState {
+        #[default]
+        Enter, 
+        Spawn, 
+        Ready, 
+        Run,
+        EnterPause, 
+        Pause, 
+        ExitPause, 
+        EnterMsgBox,
+        MsgBox,
+        ExitMsgBox,
+        EnterSetting,
+        Setting,
+        ExitSetting,
+        WaitForFinish, 
+        DisappearRun, 
+        AppearResult, 
+        Result, 
+    }
+}
+
+impl InGameState {
+    /// #### 한국어 </br>
+    /// 일시정지 메뉴와 그 하위 창(설정, 메시지 상자)이 열려 있는 상태인 경우 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if the pause menu or one of its sub-windows (settings, message box) is open. </br>
+    /// 
+    #[inline]
+    pub fn is_paused(self) -> bool {
+        matches!(
+            self, 
+            Self::EnterPause | Self::Pause | Self::ExitPause 
+            | Self::EnterMsgBox | Self::MsgBox | Self::ExitMsgBox 
+            | Self::EnterSetting | Self::Setting | Self::ExitSetting
+        )
+    }
+
+    /// #### 한국어 </br>
+    /// 화면이 바뀌는 애니메이션이 진행 중인 상태인 경우 `true`를 반환합니다. </br>
+    /// 이 상태에서는 버튼이 사라지거나 나타나는 중이므로 버튼 입력을 받지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if the state is playing a screen transition animation. </br>
+    /// Buttons are fading in or out in this state, so button input is not accepted. </br>
+    /// 
+    #[inline]
+    pub fn is_transitioning(self) -> bool {
+        !matches!(self, Self::Run | Self::Pause | Self::MsgBox | Self::Setting | Self::Result)
+    }
+
+    /// #### 한국어 </br>
+    /// 플레이어가 스테이지를 진행 중인 상태인 경우 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if the player is playing through the stage. </br>
+    /// 
+    #[inline]
+    pub fn is_gameplay(self) -> bool {
+        matches!(self, Self::Spawn | Self::Ready | Self::Run | Self::WaitForFinish)
+    }
+}
+
+impl Layer for InGameState {
+    fn enter_transition(self) -> Self {
+        match self {
+            Self::Pause => Self::EnterPause, 
+            Self::MsgBox => Self::EnterMsgBox, 
+            Self::Setting => Self::EnterSetting, 
+            _ => self, 
+        }
+    }
+
+    fn exit_transition(self) -> Self {
+        match self {
+            Self::Pause => Self::ExitPause, 
+            Self::MsgBox => Self::ExitMsgBox, 
+            Self::Setting => Self::ExitSetting, 
+            _ => self, 
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 주어진 상태를 현재 상태 위에 열고, 그 상태가 열리는 전환 상태로 변경합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Opens the given state on top of the current state, and changes to the transition state in which it opens. </br>
+/// 
+pub fn open_layer(this: &mut InGameScene, layer: InGameState) {
+    this.timer = 0.0;
+    this.state = this.back_stack.open(layer);
+}
+
 /// #### 한국어 </br>
-/// `InGame` 게임 장면의 상태 목록입니다. </br>
+/// 가장 위에 열린 상태 하나를 닫고, 그 상태가 닫히는 전환 상태로 변경합니다. </br>
+/// 전환이 끝나면 [`BackStack::current`](crate::scene::back_stack::BackStack::current)의 상태로 돌아갑니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// State list of `InGame` game scene. </br>
+/// Closes the topmost open state, and changes to the transition state in which it closes. </br>
+/// When the transition ends, it returns to the state of [`BackStack::current`](crate::scene::back_stack::BackStack::current). </br>
 /// 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum InGameState {
-    #[default]
-    Enter, 
-    Spawn, 
-    Ready, 
-    Run,
-    EnterPause, 
-    Pause, 
-    ExitPause, 
-    EnterMsgBox,
-    MsgBox,
-    ExitMsgBox,
-    EnterSetting,
-    Setting,
-    ExitSetting,
-    WaitForFinish, 
-    DisappearRun, 
-    AppearResult, 
-    Result, 
+pub fn close_layer(this: &mut InGameScene) {
+    if let Some(state) = this.back_stack.back() {
+        this.timer = 0.0;
+        this.state = state;
+    }
 }
 
 type HandleEventsFn = dyn Fn(&mut InGameScene, &mut Shared, Event<AppEvent>) -> AppResult<()>;
@@ -120,3 +216,53 @@ pub const DRAWS: [&'static DrawFn; 17] = [
     &appear_result::draw, 
     &result::draw, 
 ];
+
+
+/// #### 한국어 </br>
+/// `InGame` 게임 장면의 상태별 함수 목록들의 (이름, 길이) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The (name, length) of the per-state function tables of the `InGame` game scene. </br>
+/// 
+#[cfg(test)]
+pub const TABLES: [(&'static str, usize); 3] = [
+    ("HANDLE_EVENTS", HANDLE_EVENTS.len()), 
+    ("UPDATES", UPDATES.len()), 
+    ("DRAWS", DRAWS.len()), 
+];
+
+// (한국어) 상태별 함수 목록의 길이가 상태의 개수와 같은지 컴파일 시간에 확인합니다.
+// (English Translation) Checks at compile time that the length of each per-state function table equals the number of states.
+const _: () = assert!(HANDLE_EVENTS.len() == InGameState::NUM_STATES, "`HANDLE_EVENTS` must have one entry for each `InGameState`.");
+const _: () = assert!(UPDATES.len() == InGameState::NUM_STATES, "`UPDATES` must have one entry for each `InGameState`.");
+const _: () = assert!(DRAWS.len() == InGameState::NUM_STATES, "`DRAWS` must have one entry for each `InGameState`.");
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::scene::{back_stack::BackStack, state::debug_validate_tables};
+
+    #[test]
+    fn state_tables_match_the_scene() {
+        assert!(debug_validate_tables::<InGameScene>(&TABLES).is_ok());
+    }
+
+    #[test]
+    fn escape_from_nested_state_pops_one_level() {
+        let mut back_stack = BackStack::new(InGameState::Run);
+        assert_eq!(back_stack.open(InGameState::Pause), InGameState::EnterPause);
+        assert_eq!(back_stack.open(InGameState::Setting), InGameState::EnterSetting);
+        assert_eq!(back_stack.open(InGameState::MsgBox), InGameState::EnterMsgBox);
+
+        // (한국어) 한 번 뒤로 갈 때마다 가장 위의 창 하나만 닫힙니다.
+        // (English Translation) Each time going back closes only the topmost window.
+        assert_eq!(back_stack.back(), Some(InGameState::ExitMsgBox));
+        assert_eq!(back_stack.current(), InGameState::Setting);
+        assert_eq!(back_stack.back(), Some(InGameState::ExitSetting));
+        assert_eq!(back_stack.current(), InGameState::Pause);
+        assert_eq!(back_stack.back(), Some(InGameState::ExitPause));
+        assert_eq!(back_stack.current(), InGameState::Run);
+        assert_eq!(back_stack.back(), None);
+    }
+}
@@ -8,9 +8,8 @@ use winit::{
 };
 
 use crate::{
-    game_err, 
     components::{
-        ui::UiBrush, 
+        ui::{UiBrush, ModalHit}, 
         text::TextBrush, 
         sprite::SpriteBrush, 
         bullet::BulletBrush, 
@@ -18,19 +17,21 @@ use crate::{
         collider2d::Collider2d, 
         camera::GameCamera, 
         player::Actor, 
+        focus::FocusAction, 
+        sound, 
     },
     nodes::{
         title::TitleLoading, 
         in_game::{
             utils, 
             InGameScene, 
-            state::InGameState, 
+            state::close_layer, 
         }
     },
-    scene::state::SceneState, 
-    render::depth::DepthBuffer,
+    scene::{node::SceneNode, state::SceneState}, 
+    render::frame::with_frame,
     system::{
-        error::{AppResult, GameError}, 
+        error::AppResult, 
         event::AppEvent, 
         shared::Shared, 
     }, 
@@ -58,10 +59,6 @@ pub fn update(_this: &mut InGameScene, _shared: &mut Shared, _total_time: f64, _
 
 pub fn draw(this: &InGameScene, shared: &mut Shared) -> AppResult<()> {
     // (한국어) 사용할 공유 객체 가져오기.
-    let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
     let camera = shared.get::<Arc<GameCamera>>().unwrap();
     let ui_brush = shared.get::<Arc<UiBrush>>().unwrap();
     let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
@@ -69,217 +66,60 @@ pub fn draw(this: &InGameScene, shared: &mut Shared) -> AppResult<()> {
     let tile_brush = shared.get::<Arc<TileBrush>>().unwrap();
     let bullet_brush = shared.get::<Arc<BulletBrush>>().unwrap();
 
-    // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-    // (English Translation) Wait until the previous operation is finished.
-    device.poll(wgpu::Maintain::Wait);
-
-    // (한국어) 다음 프레임을 가져옵니다.
-    // (English Translation) Get the next frame.
-    let frame = surface.get_current_texture()
-        .map_err(|err| game_err!(
-            "Failed to get next frame",
-            "Failed to get next frame for the following reasons: {}",
-            err.to_string()
-        ))?;
-
-    // (한국어) 프레임 버퍼의 텍스처 뷰를 생성합니다.
-    // (English Translation) Creates a texture view of the framebuffer.
-    let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-    // (한국어) 커맨드 버퍼를 생성합니다.
-    // (English Translation) Creates a command buffer.
-    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-    
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(MsgBox(Background)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view, 
-                        resolve_target: None, 
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                            store: wgpu::StoreOp::Store, 
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(
-                    wgpu::RenderPassDepthStencilAttachment {
-                        view: depth.view(), 
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(1.0),
-                            store: wgpu::StoreOp::Store, 
-                        }), 
-                        stencil_ops: None, 
-                    }
-                ), 
-                timestamp_writes: None,
-                occlusion_query_set: None, 
-            }
-        );
-
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        ui_brush.draw(&mut rpass, [
-            &this.background, 
-            &this.stage_images[this.result_star_index.min(3)], 
-            &this.player_faces[&this.player.face_state], 
-            &this.boss_faces[&this.boss.face_state], 
-        ].into_iter());
-        ui_brush.draw(&mut rpass, this.owned_hearts.iter());
-        ui_brush.draw(&mut rpass, this.lost_hearts.iter().map(|(_, it)| it));
-        tile_brush.draw(&mut rpass);
-    }
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(Pause(Ui)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: wgpu::StoreOp::Store,
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment { 
-                    view: depth.view(), 
-                    depth_ops: Some(wgpu::Operations { 
-                        load: wgpu::LoadOp::Clear(1.0), 
-                        store: wgpu::StoreOp::Store 
-                    }), 
-                    stencil_ops: None 
-                }),
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            },
-        );
-
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        ui_brush.draw(&mut rpass, [&this.menu_button, &this.remaining_timer_bg].into_iter());
-        text_brush.draw(&mut rpass, [&this.remaining_timer_text, &this.percent].into_iter());
-    }
-    
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(MsgBox(Sprite)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: wgpu::StoreOp::Store,
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment { 
-                    view: depth.view(), 
-                    depth_ops: Some(wgpu::Operations { 
-                        load: wgpu::LoadOp::Clear(1.0), 
-                        store: wgpu::StoreOp::Store 
-                    }), 
-                    stencil_ops: None 
-                }),
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            },
-        );
+    with_frame(shared, this.clear_color(), |frame| {
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(MsgBox(Background)))");
+
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            ui_brush.draw(&mut rpass, [
+                &this.background, 
+                &this.stage_images[this.result_star_index.min(3)], 
+                &this.player_faces[&this.player.face_state], 
+                &this.boss_faces[&this.boss.face_state], 
+            ].into_iter());
+            ui_brush.draw(&mut rpass, this.hearts.iter());
+            tile_brush.draw(&mut rpass);
+        }
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(Pause(Ui)))");
+
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            ui_brush.draw(&mut rpass, [&this.menu_button, &this.remaining_timer_bg].into_iter());
+            text_brush.draw(&mut rpass, [&this.remaining_timer_text, &this.percent, &this.combo_text].into_iter());
+        }
 
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        sprite_brush.draw(&mut rpass, [&this.player.sprite, &this.boss.sprite].into_iter());
-        bullet_brush.draw(&mut rpass, [&this.enemy_bullet].into_iter());
-    }
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(MsgBox(Sprite)))");
 
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(MsgBox(Foreground)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view, 
-                        resolve_target: None, 
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: wgpu::StoreOp::Store, 
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(
-                    wgpu::RenderPassDepthStencilAttachment {
-                        view: depth.view(), 
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(1.0),
-                            store: wgpu::StoreOp::Store, 
-                        }), 
-                        stencil_ops: None, 
-                    }
-                ), 
-                timestamp_writes: None,
-                occlusion_query_set: None, 
-            }
-        );
-
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        ui_brush.draw(&mut rpass, [&this.foreground].into_iter());
-    }
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            sprite_brush.draw(&mut rpass, [&this.player.sprite, &this.boss.sprite].into_iter());
+            bullet_brush.draw(&mut rpass, [&this.enemy_bullet].into_iter());
+        }
 
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(MsgBox(WindowUi)))"), 
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view, 
-                        resolve_target: None, 
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load, 
-                            store: wgpu::StoreOp::Store, 
-                        }, 
-                    }), 
-                ], 
-                depth_stencil_attachment: Some(
-                    wgpu::RenderPassDepthStencilAttachment {
-                        view: depth.view(), 
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(1.0), 
-                            store: wgpu::StoreOp::Store, 
-                        }),
-                        stencil_ops: None, 
-                    }
-                ), 
-                timestamp_writes: None, 
-                occlusion_query_set: None, 
-            }
-        );
-
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        ui_brush.draw(&mut rpass, [&this.pause_exit_window.0].into_iter());
-        ui_brush.draw(&mut rpass, this.pause_exit_buttons.values().map(|(it, _)| it));
-        text_brush.draw(&mut rpass, [&this.pause_exit_window.1].into_iter());
-        text_brush.draw(&mut rpass, this.pause_exit_buttons.values().map(|(_, it)| it));
-    }
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(MsgBox(Foreground)))");
 
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            ui_brush.draw(&mut rpass, [&this.foreground].into_iter());
+        }
 
-    // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-    // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-    queue.submit(Some(encoder.finish()));
-    frame.present();
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(MsgBox(WindowUi)))");
 
-    Ok(())
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            this.pause_exit_window.draw(ui_brush, text_brush, &mut rpass);
+        }
+    })
 }
 
 fn handle_keyboard_input(this: &mut InGameScene, shared: &mut Shared, event: &Event<AppEvent>) -> AppResult<()> {
@@ -291,27 +131,41 @@ fn handle_keyboard_input(this: &mut InGameScene, shared: &mut Shared, event: &Ev
         Event::WindowEvent { event, .. } => match event {
             WindowEvent::KeyboardInput { event, .. } =>
             if let PhysicalKey::Code(code) = event.physical_key {
+                // (한국어) 키보드 포커스 입력을 처리합니다. 포커스된 버튼의 활성화는 클릭과 같이 처리됩니다.
+                // (English Translation) Handles keyboard focus input. Activating the focused button is handled like a click.
+                if !event.repeat && event.state.is_pressed() {
+                    match this.pause_exit_window.handle_key(queue, code) {
+                        Some(FocusAction::Activate(tag)) => {
+                            btn_pressed(tag, this, shared)?;
+                            return btn_released(tag, this, shared);
+                        },
+                        Some(FocusAction::Moved(_)) => return Ok(()), 
+                        None => { /* empty */ }
+                    }
+                }
+
                 if KeyCode::Escape == code && !event.repeat && event.state.is_pressed() {
                     // (한국어) 선택했던 ui의 색상을 원래대로 되돌립니다.
                     // (English Translation) Returns the color of the selected ui to its original color.
                     let mut guard = FOCUSED_BTN.lock().expect("Failed to access variable.");
                     if let Some((tag, ui_color, text_color)) = guard.take() {
-                        if let Some((ui, text)) = this.pause_exit_buttons.get(&tag) {
+                        if let Some((ui, text)) = this.pause_exit_window.buttons.get(&tag) {
                             ui.update(queue, |data| data.color = (ui_color, data.color.w).into());
                             text.update(queue, |data| data.color = (text_color, data.color.w).into());
                         }
                     }
 
-                    // (한국어) 다음 게임 장면 상태로 변경합니다.
-                    // (English Translation) Change to the next game scene state.
-                    this.timer = 0.0;
-                    this.state = InGameState::ExitMsgBox;
+                    // (한국어) 창을 닫고 다음 게임 장면 상태로 변경합니다.
+                    // (English Translation) Closes the window and changes to the next game scene state.
+                    if this.pause_exit_window.dismiss() {
+                        close_layer(this);
+                    }
                 } else if KeyCode::Enter == code && !event.repeat && event.state.is_pressed() {
                     // (한국어) 선택했던 ui의 색상을 원래대로 되돌립니다.
                     // (English Translation) Returns the color of the selected ui to its original color.
                     let mut guard = FOCUSED_BTN.lock().expect("Failed to access variable.");
                     if let Some((tag, ui_color, text_color)) = guard.take() {
-                        if let Some((ui, text)) = this.pause_exit_buttons.get(&tag) {
+                        if let Some((ui, text)) = this.pause_exit_window.buttons.get(&tag) {
                             ui.update(queue, |data| data.color = (ui_color, data.color.w).into());
                             text.update(queue, |data| data.color = (text_color, data.color.w).into());
                         }
@@ -343,12 +197,22 @@ fn handle_mouse_input(this: &mut InGameScene, shared: &mut Shared, event: &Event
         Event::WindowEvent { event, .. } => match event {
             WindowEvent::MouseInput { state, button, .. } => 
             if MouseButton::Left == *button && state.is_pressed() {
-                // (한국어) 마우스 커서가 ui 영역 안에 있는지 확인합니다.
-                // (English Translation) Make sure the mouse curosr is inside the ui area.
-                let select = this.pause_exit_buttons.iter()
-                    .find(|(_, (ui, _))| {
-                        ui.test(&(cursor_pos, camera))
-                    });
+                // (한국어) 
+                // 창이 모든 클릭을 가로챕니다. 
+                // 배경막을 클릭한 경우 창을 닫고 다음 게임 장면 상태로 변경합니다.
+                //
+                // (English Translation) 
+                // The window captures every click. 
+                // If the backdrop is clicked, closes the window and changes to the next game scene state.
+                //
+                let select = match this.pause_exit_window.hit_test(cursor_pos, camera) {
+                    Some(ModalHit::Button(tag)) => this.pause_exit_window.buttons.get_key_value(&tag), 
+                    Some(ModalHit::Dismissed) => {
+                        close_layer(this);
+                        return sound::play_cancel_sound(shared);
+                    }, 
+                    _ => None, 
+                };
 
                 // (한국어)
                 // 마우스 커서가 ui영역 안에 있는 경우:
@@ -381,7 +245,7 @@ fn handle_mouse_input(this: &mut InGameScene, shared: &mut Shared, event: &Event
                 if let Some((tag, ui_color, text_color)) = guard.take() {
                     // (한국어) 선택했던 ui의 색상을 원래 색상으로 되돌립니다.
                     // (English Translation) Returns the color of the selected ui to its original color.
-                    if let Some((ui, text)) = this.pause_exit_buttons.get(&tag) {
+                    if let Some((ui, text)) = this.pause_exit_window.buttons.get(&tag) {
                         ui.update(queue, |data| data.color = (ui_color, data.color.w).into());
                         text.update(queue, |data| data.color = (text_color, data.color.w).into());
 
@@ -406,8 +270,6 @@ fn handle_mouse_input(this: &mut InGameScene, shared: &mut Shared, event: &Event
 #[allow(unused_variables)]
 #[allow(unreachable_patterns)]
 fn btn_pressed(tag: utils::ExitWndButton, this: &mut InGameScene, shared: &mut Shared) -> AppResult<()> {
-    use crate::components::sound;
-
     match tag {
         utils::ExitWndButton::Yes => sound::play_click_sound(shared),
         utils::ExitWndButton::No => sound::play_cancel_sound(shared), 
@@ -428,8 +290,8 @@ fn btn_released(tag: utils::ExitWndButton, this: &mut InGameScene, shared: &mut
             Ok(())
         }, 
         utils::ExitWndButton::No => {
-            this.timer = 0.0;
-            this.state = InGameState::ExitMsgBox;
+            close_layer(this);
+            this.pause_exit_window.hide();
             Ok(())
         }
         _ => Ok(())
@@ -7,7 +7,6 @@ use winit::{
 };
 
 use crate::{
-    game_err, 
     components::{
         collider2d::Collider2d, 
         ui::UiBrush, 
@@ -16,16 +15,20 @@ use crate::{
         table::TileBrush, 
         bullet::BulletBrush, 
         camera::GameCamera, 
+        focus::FocusAction, 
         sound, 
+        user::{Settings, SettingsSnapshot}, 
     },
     nodes::in_game::{
         utils, 
         InGameScene, 
-        state::InGameState, 
+        InGameLoading, 
+        state::{InGameState, open_layer, close_layer}, 
     },
-    render::depth::DepthBuffer,
+    render::frame::with_frame,
+    scene::{node::SceneNode, state::SceneState}, 
     system::{
-        error::{AppResult, GameError}, 
+        error::AppResult, 
         event::AppEvent, 
         shared::Shared, 
     }, 
@@ -53,10 +56,6 @@ pub fn update(_this: &mut InGameScene, _shared: &mut Shared, _total_time: f64, _
 pub fn draw(this: &InGameScene, shared: &mut Shared) -> AppResult<()> {
     // (한국어) 사용할 공유 객체 가져오기.
     // (English Translation) Get shared object to use. 
-    let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
     let camera = shared.get::<Arc<GameCamera>>().unwrap();
     let ui_brush = shared.get::<Arc<UiBrush>>().unwrap();
     let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
@@ -64,218 +63,66 @@ pub fn draw(this: &InGameScene, shared: &mut Shared) -> AppResult<()> {
     let sprite_brush = shared.get::<Arc<SpriteBrush>>().unwrap();
     let bullet_brush = shared.get::<Arc<BulletBrush>>().unwrap();
 
-    // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-    // (English Translation) Wait until the previous operation is finished.
-    device.poll(wgpu::Maintain::Wait);
-
-    // (한국어) 다음 프레임을 가져옵니다.
-    // (English Translation) Get the next frame.
-    let frame = surface.get_current_texture()
-        .map_err(|err| game_err!(
-            "Failed to get next frame",
-            "Failed to get next frame for the following reasons: {}",
-            err.to_string()
-        ))?;
-
-    // (한국어) 프레임 버퍼의 텍스처 뷰를 생성합니다.
-    // (English Translation) Creates a texture view of the framebuffer.
-    let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-    // (한국어) 커맨드 버퍼를 생성합니다.
-    // (English Translation) Creates a command buffer.
-    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-    
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(Pause(Background)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                            store: wgpu::StoreOp::Store,
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment { 
-                    view: depth.view(), 
-                    depth_ops: Some(wgpu::Operations { 
-                        load: wgpu::LoadOp::Clear(1.0), 
-                        store: wgpu::StoreOp::Store 
-                    }), 
-                    stencil_ops: None 
-                }),
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            },
-        );
-
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        ui_brush.draw(
-            &mut rpass, 
-            [
-                &this.background, 
-                &this.stage_images[this.result_star_index.min(3)], 
-                &this.player_faces[&this.player.face_state], 
-                &this.boss_faces[&this.boss.face_state], 
-            ].into_iter()
-        );
-        ui_brush.draw(&mut rpass, this.owned_hearts.iter());
-        ui_brush.draw(&mut rpass, this.lost_hearts.iter().map(|(_, it)| it));
-        tile_brush.draw(&mut rpass);
-    }
-
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(Pause(Ui)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: wgpu::StoreOp::Store,
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment { 
-                    view: depth.view(), 
-                    depth_ops: Some(wgpu::Operations { 
-                        load: wgpu::LoadOp::Clear(1.0), 
-                        store: wgpu::StoreOp::Store 
-                    }), 
-                    stencil_ops: None 
-                }),
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            },
-        );
+    with_frame(shared, this.clear_color(), |frame| {
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(Pause(Background)))");
+
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            ui_brush.draw(
+                &mut rpass, 
+                [
+                    &this.background, 
+                    &this.stage_images[this.result_star_index.min(3)], 
+                    &this.player_faces[&this.player.face_state], 
+                    &this.boss_faces[&this.boss.face_state], 
+                ].into_iter()
+            );
+            ui_brush.draw(&mut rpass, this.hearts.iter());
+            tile_brush.draw(&mut rpass);
+        }
 
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        ui_brush.draw(&mut rpass, [&this.menu_button, &this.remaining_timer_bg].into_iter());
-        text_brush.draw(&mut rpass, [&this.remaining_timer_text, &this.percent].into_iter());
-    }
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(Pause(Ui)))");
 
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(Pause(Sprite)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: wgpu::StoreOp::Store,
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment { 
-                    view: depth.view(), 
-                    depth_ops: Some(wgpu::Operations { 
-                        load: wgpu::LoadOp::Clear(1.0), 
-                        store: wgpu::StoreOp::Store 
-                    }), 
-                    stencil_ops: None 
-                }),
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            },
-        );
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            ui_brush.draw(&mut rpass, [&this.menu_button, &this.remaining_timer_bg].into_iter());
+            text_brush.draw(&mut rpass, [&this.remaining_timer_text, &this.percent, &this.combo_text].into_iter());
+        }
 
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        sprite_brush.draw(&mut rpass, [&this.player.sprite, &this.boss.sprite].into_iter());
-        bullet_brush.draw(&mut rpass, [&this.enemy_bullet].into_iter());
-    }
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(Pause(Sprite)))");
 
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(Pause(Foreground)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view, 
-                        resolve_target: None, 
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: wgpu::StoreOp::Store, 
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(
-                    wgpu::RenderPassDepthStencilAttachment {
-                        view: depth.view(), 
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(1.0),
-                            store: wgpu::StoreOp::Store, 
-                        }), 
-                        stencil_ops: None, 
-                    }
-                ), 
-                timestamp_writes: None,
-                occlusion_query_set: None, 
-            }
-        );
-
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        ui_brush.draw(&mut rpass, [&this.foreground].into_iter());
-    }
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            sprite_brush.draw(&mut rpass, [&this.player.sprite, &this.boss.sprite].into_iter());
+            bullet_brush.draw(&mut rpass, [&this.enemy_bullet].into_iter());
+        }
 
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(Pause(PauseUI)))"), 
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view, 
-                        resolve_target: None, 
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load, 
-                            store: wgpu::StoreOp::Store, 
-                        }, 
-                    }), 
-                ], 
-                depth_stencil_attachment: Some(
-                    wgpu::RenderPassDepthStencilAttachment {
-                        view: depth.view(), 
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(1.0), 
-                            store: wgpu::StoreOp::Store, 
-                        }),
-                        stencil_ops: None, 
-                    }
-                ), 
-                timestamp_writes: None, 
-                occlusion_query_set: None, 
-            }
-        );
-
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        ui_brush.draw(&mut rpass, this.pause_buttons.values().map(|(it, _)| it));
-        text_brush.draw(&mut rpass, [&this.pause_text].into_iter());
-        text_brush.draw(&mut rpass, this.pause_buttons.values().map(|(_, it)| it));
-    }
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(Pause(Foreground)))");
 
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            ui_brush.draw(&mut rpass, [&this.foreground].into_iter());
+        }
 
-    // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-    // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-    queue.submit(Some(encoder.finish()));
-    frame.present();
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(Pause(PauseUI)))");
 
-    Ok(())
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            ui_brush.draw(&mut rpass, this.pause_buttons.values().map(|(it, _)| it));
+            text_brush.draw(&mut rpass, [&this.pause_text].into_iter());
+            text_brush.draw(&mut rpass, this.pause_buttons.values().map(|(_, it)| it));
+        }
+    })
 }
 
 fn handle_keyboard_input(this: &mut InGameScene, shared: &mut Shared, event: &Event<AppEvent>) -> AppResult<()> {
@@ -287,6 +134,22 @@ fn handle_keyboard_input(this: &mut InGameScene, shared: &mut Shared, event: &Ev
         Event::WindowEvent { event, .. } => match event {
             WindowEvent::KeyboardInput { event, .. } => 
             if let PhysicalKey::Code(code) = event.physical_key {
+                // (한국어) 키보드 포커스 입력을 처리합니다. 포커스된 버튼의 활성화는 클릭과 같이 처리됩니다.
+                // (English Translation) Handles keyboard focus input. Activating the focused button is handled like a click.
+                if !event.repeat && event.state.is_pressed() {
+                    match this.pause_focus.handle_key(code) {
+                        Some(FocusAction::Activate(tag)) => {
+                            btn_pressed(tag, this, shared)?;
+                            return btn_released(tag, this, shared);
+                        },
+                        Some(FocusAction::Moved(_)) => {
+                            this.pause_focus.highlight(queue, this.pause_buttons.iter().map(|(tag, (ui, _))| (*tag, ui)));
+                            return Ok(());
+                        },
+                        None => { /* empty */ }
+                    }
+                }
+
                 if KeyCode::Escape == code && !event.repeat && event.state.is_pressed() {
                     // (한국어) 선택했던 ui의 색상을 원래대로 되돌립니다.
                     // (English Translation) Returns the color of the selected ui to its original color.
@@ -300,8 +163,7 @@ fn handle_keyboard_input(this: &mut InGameScene, shared: &mut Shared, event: &Ev
 
                     // (한국어) 다음 게임 장면 상태로 변경합니다.
                     // (English Translation) Change to the next game scene state. 
-                    this.timer = 0.0;
-                    this.state = InGameState::ExitPause; 
+                    close_layer(this);
                 }
             },
             _ => { /* empty */ }
@@ -396,6 +258,7 @@ fn btn_pressed(tag: utils::PauseButton, this: &mut InGameScene, shared: &mut Sha
     match tag {
         utils::PauseButton::Resume => sound::play_cancel_sound(shared),
         utils::PauseButton::Setting => sound::play_click_sound(shared),
+        utils::PauseButton::Restart => sound::play_click_sound(shared),
         utils::PauseButton::GiveUp => sound::play_click_sound(shared), 
         _ => Ok(())
     }
@@ -406,19 +269,36 @@ fn btn_pressed(tag: utils::PauseButton, this: &mut InGameScene, shared: &mut Sha
 fn btn_released(tag: utils::PauseButton, this: &mut InGameScene, shared: &mut Shared) -> AppResult<()> {
     match tag {
         utils::PauseButton::Resume => {
-            this.timer = 0.0;
-            this.state = InGameState::ExitPause;
+            close_layer(this);
             Ok(())
         },
         utils::PauseButton::Setting => {
             shared.push(1usize);
-            this.timer = 0.0;
-            this.state = InGameState::EnterSetting;
+
+            // (한국어) 설정 창을 취소할 때 되돌릴 현재 설정을 저장합니다.
+            // (English Translation) Stores the current settings to revert to when the setting window is canceled.
+            let snapshot = SettingsSnapshot::new(shared.get::<Settings>().unwrap());
+            shared.push(snapshot);
+
+            open_layer(this, InGameState::Setting);
+            Ok(())
+        },
+        utils::PauseButton::Restart => {
+            // (한국어) 
+            // 같은 캐릭터로 새로운 `InGame` 게임 장면을 불러옵니다.
+            // 현재 게임 장면의 음향과 그리기 도구는 `exit` 함수에서 해제됩니다.
+            // 
+            // (English Translation) 
+            // Loads a new `InGame` game scene with the same actor.
+            // The sounds and drawing tools of the current game scene are released in the `exit` function.
+            //
+            let state = shared.get_mut::<SceneState>().unwrap();
+            *state = SceneState::Change(Box::new(InGameLoading::default()));
             Ok(())
         },
         utils::PauseButton::GiveUp => {
-            this.timer = 0.0;
-            this.state = InGameState::EnterMsgBox;
+            open_layer(this, InGameState::MsgBox);
+            this.pause_exit_window.show();
             Ok(())
         }
         _ => Ok(())
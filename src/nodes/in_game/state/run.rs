@@ -1,10 +1,8 @@
-use std::thread;
 use std::sync::{Arc, Mutex};
 use std::collections::VecDeque;
 
 use rand::prelude::*;
-use glam::{Vec3, Vec4Swizzles, Vec4};
-use rodio::{Sink, OutputStreamHandle};
+use glam::{Vec3, Vec3Swizzles, Vec4Swizzles, Vec4};
 use winit::{
     keyboard::{PhysicalKey, KeyCode},
     event::{Event, WindowEvent, MouseButton},
@@ -12,31 +10,36 @@ use winit::{
 };
 
 use crate::{
-    game_err,
     assets::bundle::AssetBundle, 
     components::{
         collider2d::Collider2d, 
         text::TextBrush,
-        ui::{UiBrush, UiObject},
+        ui::UiBrush,
         camera::GameCamera,
         sprite::SpriteBrush,
         user::Settings,
-        table::{self, TileBrush},
+        table::{self, Table, TileBrush},
         bullet::{self, BulletBrush, Instance as BulletData}, 
-        player::{self, Player, PlayerControlState, PlayerFaceState, PlayerGameState}, 
+        player::{self, Player, PlayerBody, PlayerControlState, PlayerFaceState, PlayerGameState}, 
         boss::{self, Boss, BossFaceState}, 
-        sound::{self, SoundDecoder}, 
+        sound::{self, SoundDecoder, SoundSink}, 
         interpolation, 
     },
     nodes::in_game::{
         utils, 
         InGameScene, 
-        state::InGameState, 
+        sim::{self, SimEvent, SimInput}, 
+        state::{InGameState, open_layer}, 
+        GAME_DURATION_SEC, 
+        LOW_TIME_WARNING_SEC, 
+        LOW_TIME_FLASH_RATE, 
     },
-    render::depth::DepthBuffer,
+    render::frame::with_frame,
+    scene::node::SceneNode,
     system::{
-        error::{AppResult, GameError},
+        error::AppResult,
         event::AppEvent,
+        rng::CosmeticRng, 
         shared::Shared,
     },
 };
@@ -49,6 +52,22 @@ use crate::{
 /// 
 static FOCUSED_MENU_BTN: Mutex<Option<Vec3>> = Mutex::new(None);
 
+/// #### 한국어 </br>
+/// 남은 시간 텍스트의 기본 색상입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The default color of the remaining time text. </br>
+/// 
+const REMAINING_TIMER_COLOR: Vec4 = Vec4::new(0.0, 0.0, 0.0, 1.0);
+
+/// #### 한국어 </br>
+/// 남은 시간이 얼마 남지 않았을 때 남은 시간 텍스트가 깜빡이는 색상입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The color the remaining time text flashes when time is running out. </br>
+/// 
+const LOW_TIME_WARNING_COLOR: Vec4 = Vec4::new(1.0, 0.0, 0.0, 1.0);
+
 
 pub fn handle_events(this: &mut InGameScene, shared: &mut Shared, event: Event<AppEvent>) -> AppResult<()> {
     handle_player_mouse_events(this, shared, &event)?;
@@ -57,28 +76,35 @@ pub fn handle_events(this: &mut InGameScene, shared: &mut Shared, event: Event<A
 }
 
 pub fn update(this: &mut InGameScene, shared: &mut Shared, total_time: f64, elapsed_time: f64) -> AppResult<()> {
+    // (한국어) 게임 진행 상태를 갱신한 후, 변경된 내용을 화면에 반영합니다.
+    // (English Translation) Updates the game progress state, and then reflects the changes on the screen.
+    update_simulation(this, shared, total_time, elapsed_time)?;
+    apply_sim_events(this, shared, total_time, elapsed_time)?;
     player_update(this, shared, total_time, elapsed_time)?;
+
     update_boss(this, shared, total_time, elapsed_time)?;
-    
     update_bullets(this, shared, total_time, elapsed_time)?;
-    
-    handles_collision(this, shared, total_time, elapsed_time)?;
 
     update_lost_hearts(this, shared, total_time, elapsed_time)?;
     update_owned_tiles(this, shared, total_time, elapsed_time)?;
 
     update_percent_text(this, shared, total_time, elapsed_time)?;
+    update_combo_text(this, shared, total_time, elapsed_time)?;
     update_remaining_time(this, shared, total_time, elapsed_time)?;
+    apply_sim_outcome(this, shared, total_time, elapsed_time)?;
+
+    // (한국어) 게임이 일찍 끝난 경우 남은 시간 텍스트의 색상을 원래대로 되돌립니다.
+    // (English Translation) Restores the color of the remaining time text if the game ended early.
+    if this.state != InGameState::Run {
+        let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+        reset_remaining_timer_color(this, queue);
+    }
     Ok(())
 }
 
 pub fn draw(this: &InGameScene, shared: &mut Shared) -> AppResult<()> {
     // (한국어) 사용할 공유 객체 가져오기.
     // (English Translation) Get shared object to use.
-    let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
     let camera = shared.get::<Arc<GameCamera>>().unwrap();
     let ui_brush = shared.get::<Arc<UiBrush>>().unwrap();
     let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
@@ -86,145 +112,46 @@ pub fn draw(this: &InGameScene, shared: &mut Shared) -> AppResult<()> {
     let sprite_brush = shared.get::<Arc<SpriteBrush>>().unwrap();
     let bullet_brush = shared.get::<Arc<BulletBrush>>().unwrap();
 
-    // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-    // (English Translation) Wait until the previous operation is finished.
-    device.poll(wgpu::Maintain::Wait);
-
-    // (한국어) 다음 프레임을 가져옵니다.
-    // (English Translation) Get the next frame.
-    let frame = surface.get_current_texture()
-        .map_err(|err| game_err!(
-            "Failed to get next frame",
-            "Failed to get next frame for the following reasons: {}",
-            err.to_string()
-        ))?;
-
-    // (한국어) 프레임 버퍼의 텍스처 뷰를 생성합니다.
-    // (English Translation) Creates a texture view of the framebuffer.
-    let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-    // (한국어) 커맨드 버퍼를 생성합니다.
-    // (English Translation) Creates a command buffer.
-    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-    
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(Run(Background)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                            store: wgpu::StoreOp::Store,
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment { 
-                    view: depth.view(), 
-                    depth_ops: Some(wgpu::Operations { 
-                        load: wgpu::LoadOp::Clear(1.0), 
-                        store: wgpu::StoreOp::Store 
-                    }), 
-                    stencil_ops: None 
-                }),
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            },
-        );
-
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        ui_brush.draw(
-            &mut rpass, 
-            [
-                &this.background, 
-                &this.stage_images[this.result_star_index.min(3)], 
-                &this.player_faces[&this.player.face_state], 
-                &this.boss_faces[&this.boss.face_state], 
-            ].into_iter()
-        );
-        ui_brush.draw(&mut rpass, this.owned_hearts.iter());
-        ui_brush.draw(&mut rpass, this.lost_hearts.iter().map(|(_, it)| it));
-        tile_brush.draw(&mut rpass);
-    }
-
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(Run(Ui)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: wgpu::StoreOp::Store,
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment { 
-                    view: depth.view(), 
-                    depth_ops: Some(wgpu::Operations { 
-                        load: wgpu::LoadOp::Clear(1.0), 
-                        store: wgpu::StoreOp::Store 
-                    }), 
-                    stencil_ops: None 
-                }),
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            },
-        );
-
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        ui_brush.draw(&mut rpass, [&this.menu_button, &this.remaining_timer_bg].into_iter());
-        text_brush.draw(&mut rpass, [&this.remaining_timer_text, &this.percent].into_iter());
-    }
+    with_frame(shared, this.clear_color(), |frame| {
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(Run(Background)))");
+
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            ui_brush.draw(
+                &mut rpass, 
+                [
+                    &this.background, 
+                    &this.stage_images[this.result_star_index.min(3)], 
+                    &this.player_faces[&this.player.face_state], 
+                    &this.boss_faces[&this.boss.face_state], 
+                ].into_iter()
+            );
+            ui_brush.draw(&mut rpass, this.hearts.iter());
+            tile_brush.draw(&mut rpass);
+        }
 
-    {
-        let mut rpass = encoder.begin_render_pass(
-            &wgpu::RenderPassDescriptor {
-                label: Some("RenderPass(InGameScene(Run(Sprite)))"),
-                color_attachments: &[
-                    Some(wgpu::RenderPassColorAttachment {
-                        view: &view,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: wgpu::StoreOp::Store,
-                        },
-                    }),
-                ],
-                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment { 
-                    view: depth.view(), 
-                    depth_ops: Some(wgpu::Operations { 
-                        load: wgpu::LoadOp::Clear(1.0), 
-                        store: wgpu::StoreOp::Store 
-                    }), 
-                    stencil_ops: None 
-                }),
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            },
-        );
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(Run(Ui)))");
 
-        // (한국어) 카메라를 바인드 합니다.
-        // (English Translation) Bind the camera. 
-        camera.bind(&mut rpass);
-        sprite_brush.draw(&mut rpass, [&this.player.sprite, &this.boss.sprite].into_iter());
-        bullet_brush.draw(&mut rpass, [&this.enemy_bullet].into_iter());
-    }
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            ui_brush.draw(&mut rpass, [&this.menu_button, &this.remaining_timer_bg].into_iter());
+            text_brush.draw(&mut rpass, [&this.remaining_timer_text, &this.percent, &this.combo_text].into_iter());
+        }
 
-    // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-    // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-    queue.submit(Some(encoder.finish()));
-    frame.present();
+        {
+            let mut rpass = frame.begin_pass("RenderPass(InGameScene(Run(Sprite)))");
 
-    Ok(())
+            // (한국어) 카메라를 바인드 합니다.
+            // (English Translation) Bind the camera. 
+            camera.bind(&mut rpass);
+            sprite_brush.draw(&mut rpass, [&this.player.sprite, &this.boss.sprite].into_iter());
+            bullet_brush.draw(&mut rpass, [&this.enemy_bullet].into_iter());
+        }
+    })
 }
 
 
@@ -243,8 +170,7 @@ fn handle_player_mouse_events(this: &mut InGameScene, shared: &mut Shared, event
     let cursor_pos = shared.get::<PhysicalPosition<f64>>().unwrap();
     let camera = shared.get::<Arc<GameCamera>>().unwrap();
     let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let stream = shared.get::<OutputStreamHandle>().unwrap();
-    let settings = shared.get::<Settings>().unwrap();
+    let audio = shared.get::<Arc<utils::InGameAudio>>().unwrap();
     let asset_bundle = shared.get::<AssetBundle>().unwrap();
     
     match event {
@@ -280,11 +206,8 @@ fn handle_player_mouse_events(this: &mut InGameScene, shared: &mut Shared, event
                     // (English Translation) Play pause sound. 
                     let source = asset_bundle.get(path::PAUSE_SOUND_PATH)?
                         .read(&SoundDecoder)?;
-                    let sink = sound::play_sound(settings.effect_volume, source, stream)?;
-                    thread::spawn(move || {
-                        sink.sleep_until_end();
-                        sink.detach();
-                    });
+                    asset_bundle.release(path::PAUSE_SOUND_PATH);
+                    audio.effect.append(source);
                 }
             } else if MouseButton::Left == *button && !state.is_pressed() {
                 let mut guard = FOCUSED_MENU_BTN.lock().expect("Failed to access variable.");
@@ -304,9 +227,9 @@ fn handle_player_mouse_events(this: &mut InGameScene, shared: &mut Shared, event
                     if is_inside {
                         // (한국어) 일시정지 상태로 변경합니다. 
                         // (English Translation) Changes to pause state. 
-                        this.timer = 0.0;
-                        this.state = InGameState::EnterPause;
-                        this.player.control_state = PlayerControlState::Idle;
+                        reset_remaining_timer_color(this, queue);
+                        open_layer(this, InGameState::Pause);
+                        this.sim_input.control = PlayerControlState::Idle;
                     }
                 }
             }
@@ -329,7 +252,7 @@ fn handle_player_keyboard_events(this: &mut InGameScene, shared: &mut Shared, ev
     // (한국어) 사용할 공유 객체를 가져옵니다.
     // (English Translation) Get the shared object to use.
     let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let stream = shared.get::<OutputStreamHandle>().unwrap();
+    let audio = shared.get::<Arc<utils::InGameAudio>>().unwrap();
     let settings = shared.get::<Settings>().unwrap();
     let asset_bundle = shared.get::<AssetBundle>().unwrap();
     let control = &settings.control;
@@ -345,11 +268,8 @@ fn handle_player_keyboard_events(this: &mut InGameScene, shared: &mut Shared, ev
                     // (English Translation) Play pause sound. 
                     let source = asset_bundle.get(path::PAUSE_SOUND_PATH)?
                         .read(&SoundDecoder)?;
-                    let sink = sound::play_sound(settings.effect_volume, source, stream)?;
-                    thread::spawn(move || {
-                        sink.sleep_until_end();
-                        sink.detach();
-                    });
+                    asset_bundle.release(path::PAUSE_SOUND_PATH);
+                    audio.effect.append(source);
 
                     // (한국어) 선택했던 ui의 색상을 원래대로 되돌립니다. 
                     // (English Translation) Returns the color of the selected ui to its original color.
@@ -362,76 +282,81 @@ fn handle_player_keyboard_events(this: &mut InGameScene, shared: &mut Shared, ev
 
                     // (한국어) 다음 게임 장면 상태로 변경합니다.
                     // (English Translation) Change to the next game scene state. 
-                    this.timer = 0.0;
-                    this.state = InGameState::EnterPause; 
-                    this.player.control_state = PlayerControlState::Idle;
+                    reset_remaining_timer_color(this, queue);
+
+                    open_layer(this, InGameState::Pause);
+                    this.sim_input.control = PlayerControlState::Idle;
                 }
 
                 // (한국어) 사용자가 `위쪽`키를 눌렀을 경우.
                 // (English Translation) When the user presses the `Up` key.
                 if control.up.to_keycode() == code && event.state.is_pressed() && !event.repeat {
-                    if !this.player.path.is_empty() && this.player.control_state == PlayerControlState::Down {
+                    if !this.sim.player.path.is_empty() && this.sim_input.control == PlayerControlState::Down {
                         return Ok(());
                     }
-                    this.player.control_state = PlayerControlState::Up;
+                    this.sim_input.control = PlayerControlState::Up;
+                    this.sim_input.pressed = Some(PlayerControlState::Up);
                 }
 
                 // (한국어) 사용자가 `위쪽`키를 떼었을 경우.
                 // (English Translation) When the user releases the `Up` key.
                 if control.up.to_keycode() == code && !event.state.is_pressed() && !event.repeat 
-                && this.player.control_state == PlayerControlState::Up {
-                    this.player.control_state = PlayerControlState::Idle;
+                && this.sim_input.control == PlayerControlState::Up {
+                    this.sim_input.control = PlayerControlState::Idle;
                 }
 
 
                 // (한국어) 사용자가 `아래쪽`키를 눌렀을 경우.
                 // (English Translation) When the user presses the `Down` key.
                 if control.down.to_keycode() == code && event.state.is_pressed() && !event.repeat {
-                    if !this.player.path.is_empty() && this.player.control_state == PlayerControlState::Up {
+                    if !this.sim.player.path.is_empty() && this.sim_input.control == PlayerControlState::Up {
                         return Ok(());
                     }
-                    this.player.control_state = PlayerControlState::Down;
+                    this.sim_input.control = PlayerControlState::Down;
+                    this.sim_input.pressed = Some(PlayerControlState::Down);
                 }
 
                 // (한국어) 사용자가 `아래쪽`키를 떼었을 경우.
                 // (English Translation) When the user releases the `Down` key.
                 if control.down.to_keycode() == code && !event.state.is_pressed() && !event.repeat 
-                && this.player.control_state == PlayerControlState::Down {
-                    this.player.control_state = PlayerControlState::Idle;
+                && this.sim_input.control == PlayerControlState::Down {
+                    this.sim_input.control = PlayerControlState::Idle;
                 }
 
 
                 // (한국어) 사용자가 `왼쪽`키를 눌렀을 경우.
                 // (English Translation) When the user presses the `Left` key.
                 if control.left.to_keycode() == code && event.state.is_pressed() && !event.repeat {
-                    if !this.player.path.is_empty() && this.player.control_state == PlayerControlState::Right {
+                    if !this.sim.player.path.is_empty() && this.sim_input.control == PlayerControlState::Right {
                         return Ok(());
                     }
-                    this.player.control_state = PlayerControlState::Left;
+                    this.sim_input.control = PlayerControlState::Left;
+                    this.sim_input.pressed = Some(PlayerControlState::Left);
                 }
 
                 // (한국어) 사용자가 `왼쪽`키를 떼었을 경우.
                 // (English Translation) When the user releases the `Left` key.
                 if control.left.to_keycode() == code && !event.state.is_pressed() && !event.repeat 
-                && this.player.control_state == PlayerControlState::Left {
-                    this.player.control_state = PlayerControlState::Idle;
+                && this.sim_input.control == PlayerControlState::Left {
+                    this.sim_input.control = PlayerControlState::Idle;
                 }
 
 
                 // (한국어) 사용자가 `오른쪽`키를 눌렀을 경우.
                 // (English Translation) When the user presses the `Right` key.
                 if control.right.to_keycode() == code && event.state.is_pressed() && !event.repeat {
-                    if !this.player.path.is_empty() && this.player.control_state == PlayerControlState::Left {
+                    if !this.sim.player.path.is_empty() && this.sim_input.control == PlayerControlState::Left {
                         return Ok(());
                     }
-                    this.player.control_state = PlayerControlState::Right;
+                    this.sim_input.control = PlayerControlState::Right;
+                    this.sim_input.pressed = Some(PlayerControlState::Right);
                 }
 
                 // (한국어) 사용자가 `오른쪽`키를 떼었을 경우.
                 // (English Translation) When the user releases the `Right` key.
                 if control.right.to_keycode() == code && !event.state.is_pressed() && !event.repeat 
-                && this.player.control_state == PlayerControlState::Right {
-                    this.player.control_state = PlayerControlState::Idle;
+                && this.sim_input.control == PlayerControlState::Right {
+                    this.sim_input.control = PlayerControlState::Idle;
                 }
             },
             _ => { /* empty */ }
@@ -447,51 +372,49 @@ fn handle_player_keyboard_events(this: &mut InGameScene, shared: &mut Shared, ev
 /// #### English (Translation) </br>
 /// Update the user interface to display time remaining. </br>
 /// 
-fn update_remaining_time(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, elapsed_time: f64) -> AppResult<()> {
+fn update_remaining_time(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, _elapsed_time: f64) -> AppResult<()> {
     // (한국어) 사용할 공유 객체를 가져옵니다.
     // (English Translation) Get the shared object to use.
     let device = shared.get::<Arc<wgpu::Device>>().unwrap();
     let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
     let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
-    let audio = shared.get::<Arc<utils::InGameAudio>>().unwrap();
-
-    // (한국어) 타이머를 갱신합니다.
-    // (English Translation) Updates the timer.
-    this.remaining_time = (this.remaining_time - elapsed_time).max(0.0);
     
     // (한국어) 사용자 인터페이스를 새로 생성합니다. 
     // (English Translation) Create a new user interface. 
-    let min = (this.remaining_time / 60.0) as u32;
-    let sec = (this.remaining_time % 60.0) as u32;
+    let remaining_time = this.sim.remaining_time;
     this.remaining_timer_text.change(
-        &format!("{}:{:0>2}", min, sec), 
+        &utils::format_remaining_time(remaining_time), 
         device, 
         queue, 
         &text_brush
     );
 
-    if this.remaining_time <= 0.0 {
-        audio.voice.stop();
-
-        this.player.face_timer = 0.0;
-        this.player.face_state = PlayerFaceState::Hit;
-        this.player.sprite.update(queue, |instances| {
-            instances[0].texture_index = PlayerFaceState::Hit as u32;
-        });
-
-        this.boss.face_timer = 0.0;
-        this.boss.face_state = BossFaceState::Smile;
-        this.boss.sprite.update(queue, |instances| {
-            instances[0].texture_index = BossFaceState::Smile as u32;
+    // (한국어) 남은 시간이 얼마 남지 않은 경우 남은 시간 텍스트를 깜빡이게 합니다.
+    // (English Translation) Flashes the remaining time text when time is running out.
+    if remaining_time < LOW_TIME_WARNING_SEC {
+        let t = (remaining_time * LOW_TIME_FLASH_RATE).fract();
+        let delta = interpolation::f64::smooth_step(1.0 - (2.0 * t - 1.0).abs(), 1.0) as f32;
+        this.remaining_timer_text.update(queue, |data| {
+            data.color = REMAINING_TIMER_COLOR.lerp(LOW_TIME_WARNING_COLOR, delta);
         });
-
-        this.timer = 0.0;
-        this.state = InGameState::WaitForFinish;
     }
     
     Ok(())
 }
 
+/// #### 한국어 </br>
+/// 남은 시간 텍스트의 색상을 원래대로 되돌립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Restores the color of the remaining time text to its original color. </br>
+/// 
+#[inline]
+fn reset_remaining_timer_color(this: &InGameScene, queue: &wgpu::Queue) {
+    this.remaining_timer_text.update(queue, |data| {
+        data.color = REMAINING_TIMER_COLOR;
+    });
+}
+
 /// #### 한국어 </br>
 /// 소유한 타일을 갱신합니다. </br>
 /// 
@@ -523,7 +446,7 @@ fn update_owned_tiles(this: &mut InGameScene, shared: &mut Shared, _total_time:
 
         tile_brush.update(queue, |instances| {
             for &(row, col) in tiles.iter() {
-                instances[row * this.table.num_cols + col].color = this.table.tiles[row][col].color;
+                instances[row * this.table.num_cols + col].apply_tile(&this.table.tiles[row][col]);
             }
         });
 
@@ -544,190 +467,214 @@ fn update_owned_tiles(this: &mut InGameScene, shared: &mut Shared, _total_time:
 /// Updates lost health heart objects. </br>
 /// 
 fn update_lost_hearts(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, elapsed_time: f64) -> AppResult<()> {
-    const DURATION: f64 = 0.4;
-
     // (한국어) 사용할 공유 객체를 가져옵니다.
     // (English Translation) Get the shared object to use.
     let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
 
-    let mut next = VecDeque::with_capacity(player::MAX_PLAYER_HEARTS);
-    while let Some((mut timer, heart)) = this.lost_hearts.pop_front() {
-        // (한국어) 타이머를 갱신합니다.
-        // (English Translation) Updates the timer.
-        timer += elapsed_time;
+    this.hearts.update(elapsed_time, queue);
+    Ok(())
+}
 
-        // (한국어) 하트의 크기를 갱신합니다.
-        // (English Translation) Update the size of heart.
-        let delta = interpolation::f64::smooth_step(timer, DURATION) as f32;
-        let scale = 1.0 - 1.0 * delta;
-        heart.update(queue, |data| {
-            data.local_scale = (scale, scale, scale).into();
-        });
+/// #### 한국어 </br>
+/// 입력과 충돌 여부로 게임 진행 상태를 갱신합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Updates the game progress state with the input and whether a collision occurred. </br>
+/// 
+fn update_simulation(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, elapsed_time: f64) -> AppResult<()> {
+    let hit = handles_collision(this, shared);
+    let input = SimInput { hit, ..this.sim_input };
+    this.sim_input.pressed = None;
 
-        if timer < DURATION {
-            next.push_back((timer, heart));
-        }
-    }
+    let prev_owned_tiles = this.sim.num_owned_tiles;
+    this.sim = sim::step(std::mem::take(&mut this.sim), &input, elapsed_time);
 
-    this.lost_hearts = next;
+    // (한국어) 차지한 영역이 늘어난 경우 퍼센트 텍스트를 강조합니다.
+    // (English Translation) Highlights the percent text if the owned area has increased.
+    utils::restart_percent_pulse(&mut this.percent_timer, prev_owned_tiles, this.sim.num_owned_tiles);
     Ok(())
 }
 
 /// #### 한국어 </br>
-/// 플레이어를 갱신하는 함수입니다. </br>
+/// 게임 진행 상태에 일어난 변화를 타일, 하트, 얼굴, 목소리에 반영합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// This function updates the player. </br>
+/// Reflects the changes made to the game progress state to the tiles, hearts, faces, and voices. </br>
 /// 
-fn player_update(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, elapsed_time: f64) -> AppResult<()> {
+fn apply_sim_events(this: &mut InGameScene, shared: &mut Shared, total_time: f64, _elapsed_time: f64) -> AppResult<()> {
     // (한국어) 사용할 공유 객체를 가져옵니다.
     // (English Translation) Get the shared object to use.
     let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
     let tile_brush = shared.get::<Arc<TileBrush>>().unwrap();
+    let camera = shared.get::<Arc<GameCamera>>().unwrap();
     let audio = shared.get::<Arc<utils::InGameAudio>>().unwrap();
     let asset_bundle = shared.get::<AssetBundle>().unwrap();
+    let rng = shared.get::<Arc<CosmeticRng>>().unwrap();
 
-    player::update_player_face(elapsed_time, queue, &mut this.player);
-    player::update_player_game_state(elapsed_time, queue, &mut this.player);
-
-    player::translation_player(
-        elapsed_time, 
-        &this.table, 
-        &mut this.player, 
-        &queue
-    );
-
-    if let Some(flag) = player::check_current_pos(
-        &mut this.table, 
-        &mut this.player, 
-        tile_brush, 
-        queue
-    ) {
-        if flag {
-            table::update_owned_tiles(
-                queue, 
-                tile_brush, 
-                &mut this.table, 
-                &mut this.player.path, 
-                &mut this.num_owned_tiles, 
-                &mut this.owned_tiles
-            );
-
-            // (한국어) 결과 점수의 인덱스를 갱신합니다.
-            // (English Translation) Update the index of the resulting score.
-            let percent = this.num_owned_tiles as f32 / this.num_total_tiles as f32 * 100.0;
-            if percent < 20.0 {
-                this.result_star_index = 0;
-            } else if 20.0 <= percent && percent < 50.0 {
-                for text in this.result_challenge_texts[0..=0].iter() {
-                    text.update(queue, |data| {
-                        data.color = (255.0 / 255.0, 215.0 / 255.0, 0.0 / 255.0, 0.0).into();
-                    });
-                }
-                this.result_star_index = 1;
-            } else if 50.0 <= percent && percent < 80.0 {
-                for text in this.result_challenge_texts[0..=1].iter() {
-                    text.update(queue, |data| {
-                        data.color = (255.0 / 255.0, 215.0 / 255.0, 0.0 / 255.0, 0.0).into();
-                    });
+    for event in std::mem::take(&mut this.sim.events) {
+        match event {
+            SimEvent::TrailExtended((row, col)) => {
+                tile_brush.update(queue, |instances| {
+                    instances[row * this.table.num_cols + col].color = this.table.line_color;
+                    instances[row * this.table.num_cols + col].pattern = this.table.line_pattern as u32;
+                });
+            },
+            SimEvent::TrailRetracted(pos) => {
+                player::restore_tiles(queue, &this.table, &[pos], tile_brush);
+            },
+            SimEvent::Captured(capture) => {
+                table::apply_capture(queue, tile_brush, &mut this.table, &capture);
+                this.owned_tiles.push_back((0.0, capture.inside));
+                update_result_stars(this, queue);
+
+                // (한국어) 연속 획득 수는 영역을 획득할 때만 바뀌므로, 이 때에만 배율 텍스트를 바꿉니다.
+                // (English Translation) The combo count only changes when an area is captured, so the multiplier text is changed only then.
+                if this.sim.combo >= 2 {
+                    this.combo_text.change(
+                        &utils::format_combo(this.sim.combo), 
+                        shared.get::<Arc<wgpu::Device>>().unwrap(), 
+                        queue, 
+                        shared.get::<Arc<TextBrush>>().unwrap()
+                    );
                 }
-                this.result_star_index = 2;
-            } else if 80.0 <= percent  && percent < 100.0 {
-                for text in this.result_challenge_texts[0..=2].iter() {
-                    text.update(queue, |data| {
-                        data.color = (255.0 / 255.0, 215.0 / 255.0, 0.0 / 255.0, 0.0).into();
-                    });
+
+                // (한국어) 플레이어의 표정을 웃는 표정으로 변경합니다.
+                // (English Translation) Changes the player's face to a smiley face. 
+                this.player.face_timer = 0.0;
+                this.player.face_state = PlayerFaceState::Smile;
+                this.player.sprite.set_frame(queue, PlayerFaceState::Smile as u32);
+
+                // (한국어) 무작위로 캐릭터 목소리를 재생합니다.
+                // (English Translation) Plays character voices randomly. 
+                if rng.lock().gen_bool(0.3) {
+                    let pan = player_pan(this, camera);
+                    play_random_character_voice(
+                        &this.player_smile_sounds, 
+                        utils::VoiceKind::Smile, 
+                        &mut this.voice_cooldown, 
+                        total_time, 
+                        &audio.voice, 
+                        asset_bundle, 
+                        rng, 
+                        pan
+                    )?;
                 }
-                this.result_star_index = 3;
-            } else {
-                for text in this.result_challenge_texts[0..=2].iter() {
-                    text.update(queue, |data| {
-                        data.color = (255.0 / 255.0, 215.0 / 255.0, 0.0 / 255.0, 0.0).into();
-                    });
+            },
+            SimEvent::Damaged { trail, remaining_hearts } => {
+                // (한국어) 플레이어의 라이프 카운트를 감소시키고, 경로의 타일을 원래 상태로 되돌립니다.
+                // (English Translation) Decreases the player's life count and returns the tiles of the trail to their original state.
+                this.hearts.lose_one();
+                player::restore_tiles(queue, &this.table, &trail, tile_brush);
+                player::show_hit_faces(queue, &mut this.player, &mut this.boss);
+
+                if remaining_hearts > 0 {
+                    // (한국어) 스폰위치로 이동하기 전에 플레이어의 위치에 따른 목소리의 팬 값을 구합니다.
+                    // (English Translation) Gets the pan value of the voice from the player's position before moving to the spawn position.
+                    let pan = player_pan(this, camera);
+
+                    // (한국어) 스폰위치로 돌아간 플레이어는 누르고 있던 방향키를 다시 눌러야 움직입니다.
+                    // (English Translation) A player returned to the spawn position must press the held direction key again to move.
+                    this.sim_input.control = PlayerControlState::Idle;
+
+                    play_random_character_voice(
+                        &this.player_damage_sounds, 
+                        utils::VoiceKind::Damage, 
+                        &mut this.voice_cooldown, 
+                        total_time, 
+                        &audio.voice, 
+                        asset_bundle, 
+                        rng, 
+                        pan
+                    )?;
                 }
-                this.result_star_index = 4;
-            }
+            },
+        }
+    }
 
+    Ok(())
+}
 
-            // (한국어) 퍼센트 인터페이스를 갱신합니다.
-            // (English Translation) Updates the percent interface. 
-            this.percent_timer = 0.0;
-
-            // (한국어) 플레이어의 표정을 웃는 표정으로 변경합니다.
-            // (English Translation) Changes the player's face to a smiley face. 
-            this.player.face_timer = 0.0;
-            this.player.face_state = PlayerFaceState::Smile;
-            this.player.sprite.update(queue, |instances| {
-                instances[0].texture_index = PlayerFaceState::Smile as u32;
-            });
-
-            // (한국어) 무작위로 캐릭터 목소리를 재생합니다.
-            // (English Translation) Plays character voices randomly. 
-            if rand::thread_rng().gen_bool(0.3) {
-                play_random_character_voice(
-                    &this.player_smile_sounds, 
-                    &audio.voice, 
-                    asset_bundle
-                )?;
-            }
-        } else {
-            // (한국어) 플레이어의 라이프 카운트를 감소시킵니다.
-            // (English Translation) Decreases the player's life count.
-            let remaining_life = decrease_player_life_count(
-                &mut this.owned_hearts, 
-                &mut this.lost_hearts
-            );
+/// #### 한국어 </br>
+/// 게임이 끝난 경우 결과에 따라 얼굴을 변경하고 다음 장면 상태로 변경합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// If the game is over, changes the faces according to the result and changes to the next scene state. </br>
+/// 
+fn apply_sim_outcome(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, _elapsed_time: f64) -> AppResult<()> {
+    let Some(outcome) = this.sim.outcome else {
+        return Ok(());
+    };
 
-            if remaining_life == 0 {
-                audio.voice.stop();
+    // (한국어) 사용할 공유 객체들을 가져옵니다.
+    // (English Translation) Get shared objects to use.
+    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
+    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+    let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
+    let audio = shared.get::<Arc<utils::InGameAudio>>().unwrap();
 
-                tile_brush.update(queue, |instances| {
-                    for &(r, c) in this.player.path.iter() {
-                        instances[r * this.table.num_cols + c].color = this.table.tiles[r][c].color;
-                    }
-                });
-                while let Some((r, c)) = this.player.path.pop_front() {
-                    this.table.tiles[r][c].visited = false;
-                }
+    audio.voice.stop();
+    if outcome.is_win() {
+        // (한국어) 클리어 시간을 기록하고 결과 화면의 텍스트를 갱신합니다.
+        // (English Translation) Records the clear time and updates the text on the result screen.
+        let clear_time = GAME_DURATION_SEC - this.sim.remaining_time;
+        this.clear_time = Some(clear_time);
+        this.result_clear_time.change(
+            &utils::format_clear_time(clear_time), 
+            device, 
+            queue, 
+            &text_brush
+        );
+        this.result_clear_time.update(queue, |data| {
+            data.color = (255.0 / 255.0, 215.0 / 255.0, 0.0 / 255.0, 0.0).into();
+        });
 
-                this.player.face_timer = 0.0;
-                this.player.face_state = PlayerFaceState::Hit;
-                this.player.sprite.update(queue, |instances| {
-                    instances[0].texture_index = PlayerFaceState::Hit as u32;
-                });
-    
-                this.boss.face_timer = 0.0;
-                this.boss.face_state = BossFaceState::Smile;
-                this.boss.sprite.update(queue, |instances| {
-                    instances[0].texture_index = BossFaceState::Smile as u32;
-                });
+        this.player.face_timer = 0.0;
+        this.player.face_state = PlayerFaceState::Smile;
+        this.player.sprite.set_frame(queue, PlayerFaceState::Smile as u32);
 
-                this.timer = 0.0;
-                this.state = InGameState::WaitForFinish;
-            } else {
-                // (한국어) 플레이어를 스폰위치로 이동시키고, 타일을 원래 상태로 되돌립니다.
-                // (English Translation) Moves the player to the spawn position and returns the tile to its original state. 
-                player::restore(
-                    queue, 
-                    &mut this.table, 
-                    &mut this.boss, 
-                    &mut this.player, 
-                    tile_brush
-                );
-
-                play_random_character_voice(
-                    &this.player_damage_sounds, 
-                    &audio.voice, 
-                    asset_bundle
-                )?;
-            }
-        }
-    };
+        this.boss.face_timer = 0.0;
+        this.boss.face_state = BossFaceState::Embarrass;
+        this.boss.sprite.set_frame(queue, BossFaceState::Embarrass as u32);
+    } else {
+        player::show_hit_faces(queue, &mut this.player, &mut this.boss);
+    }
 
-    player::set_player_next_position(
-        &this.table, 
-        &mut this.player
-    );
+    this.timer = 0.0;
+    this.state = InGameState::WaitForFinish;
+    Ok(())
+}
+
+/// #### 한국어 </br>
+/// 플레이어가 차지한 영역의 비율과 최대 연속 획득 수에 따라 결과 점수의 인덱스와 도전 과제 텍스트를 갱신합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Updates the index of the resulting score and the challenge texts according to the ratio of the area occupied by the player and the maximum combo count. </br>
+/// 
+fn update_result_stars(this: &mut InGameScene, queue: &wgpu::Queue) {
+    let percent = this.sim.percent();
+    this.result_star_index = utils::result_star_index(percent, this.sim.max_combo);
+
+    let num_achieved = utils::percent_star_index(percent).min(3);
+    for text in this.result_challenge_texts[0..num_achieved].iter() {
+        text.update(queue, |data| {
+            data.color = (255.0 / 255.0, 215.0 / 255.0, 0.0 / 255.0, 0.0).into();
+        });
+    }
+}
+
+/// #### 한국어 </br>
+/// 플레이어를 갱신하는 함수입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This function updates the player. </br>
+/// 
+fn player_update(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, elapsed_time: f64) -> AppResult<()> {
+    // (한국어) 사용할 공유 객체를 가져옵니다.
+    // (English Translation) Get the shared object to use.
+    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+
+    player::update_player_face(elapsed_time, queue, &mut this.player);
+    player::sync_player_sprite(&this.table, &this.player, &this.sim.player, queue);
 
     Ok(())
 }
@@ -744,14 +691,14 @@ fn update_percent_text(this: &mut InGameScene, shared: &mut Shared, _total_time:
     let device = shared.get::<Arc<wgpu::Device>>().unwrap();
     let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
     let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
-    let audio = shared.get::<Arc<utils::InGameAudio>>().unwrap();
+    let settings = shared.get::<Settings>().unwrap();
 
-    // (한국어) 타이머를 갱신합니다.
-    // (English Translation) Updates the timer.
-    this.percent_timer += elapsed_time;
+    // (한국어) 강조 효과의 남은 시간을 갱신합니다.
+    // (English Translation) Updates the remaining time of the highlight effect.
+    this.percent_timer = (this.percent_timer - elapsed_time).max(0.0);
 
-    let per = this.num_owned_tiles as f32 /  this.num_total_tiles as f32 * 100.0;
-    let s = 1.0 + 0.5 - 0.5 * interpolation::f64::smooth_step(this.percent_timer, 0.25) as f32;
+    let per = this.sim.percent();
+    let (s, color) = utils::percent_pulse(this.percent_timer, settings.reduce_motion);
     this.percent.change(
         &format!("{}%", per.floor() as u32), 
         device, 
@@ -760,28 +707,36 @@ fn update_percent_text(this: &mut InGameScene, shared: &mut Shared, _total_time:
     );
     this.percent.update(queue, |data| {
         data.scale = (s, s, s).into();
+        data.color = color;
     });
 
-    // (한국어) 플레이어가 모든 타일을 차지한 경우 다음 장면 상태로 변경합니다.
-    // (English Translation) When a player occupies all tiles, they change to the next scene state. 
-    if per >= 100.0 {
-        audio.voice.stop();
+    Ok(())
+}
 
-        this.player.face_timer = 0.0;
-        this.player.face_state = PlayerFaceState::Smile;
-        this.player.sprite.update(queue, |instances| {
-            instances[0].texture_index = PlayerFaceState::Smile as u32;
-        });
+/// #### 한국어 </br>
+/// 연속 획득 배율을 보여주는 텍스트의 크기와 투명도를 갱신하는 함수입니다. </br>
+/// 일정 시간 동안 영역을 획득하지 못하면 연속 획득이 끊기고 텍스트가 숨겨집니다. </br>
+/// 텍스트의 내용은 영역을 획득할 때 바뀝니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This function updates the size and transparency of the text showing the combo multiplier. </br>
+/// If no area is captured for a while, the combo is broken and the text is hidden. </br>
+/// The content of the text is changed when an area is captured. </br>
+/// 
+fn update_combo_text(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, _elapsed_time: f64) -> AppResult<()> {
+    // (한국어) 사용할 공유 객체를 가져옵니다.
+    // (English Translation) Get the shared object to use.
+    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
 
-        this.boss.face_timer = 0.0;
-        this.boss.face_state = BossFaceState::Embarrass;
-        this.boss.sprite.update(queue, |instances| {
-            instances[0].texture_index = BossFaceState::Embarrass as u32;
-        });
+    // (한국어) 두 번 이상 연속으로 획득한 경우에만 배율을 보여줍니다.
+    // (English Translation) Shows the multiplier only if captured twice or more in a row.
+    let visible = this.sim.combo >= 2;
 
-        this.timer = 0.0;
-        this.state = InGameState::WaitForFinish;
-    }
+    let s = 1.0 + 0.5 - 0.5 * interpolation::f64::smooth_step(this.sim.combo_timer, 0.25) as f32;
+    this.combo_text.update(queue, |data| {
+        data.scale = (s, s, s).into();
+        data.color.w = if visible { 1.0 } else { 0.0 };
+    });
 
     Ok(())
 }
@@ -822,18 +777,17 @@ fn update_boss(this: &mut InGameScene, shared: &mut Shared, total_time: f64, ela
 }
 
 /// #### 한국어 </br>
-/// 모든 충돌을 처리합니다. </br>
+/// 모든 충돌을 확인합니다. 플레이어나 플레이어의 경로에 닿은 총알은 제거됩니다. </br>
+/// 충돌이 일어난 경우 `true`를 반환합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Handles all collision. </br>
+/// Checks all collisions. Bullets that hit the player or the player's trail are removed. </br>
+/// Returns `true` if a collision occurred. </br>
 /// 
-fn handles_collision(this: &mut InGameScene, shared: &mut Shared, _total_time: f64, _elapsed_time: f64) -> AppResult<()> {
+fn handles_collision(this: &mut InGameScene, shared: &mut Shared) -> bool {
     // (한국어) 사용할 공유 객체들을 가져옵니다.
     // (English Translation) Get shared objects to use.
     let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let tile_brush = shared.get::<Arc<TileBrush>>().unwrap();
-    let audio = shared.get::<Arc<utils::InGameAudio>>().unwrap();
-    let asset_bundle = shared.get::<AssetBundle>().unwrap();
 
     // (한국어) 발사된 총알들을 가져옵니다.
     // (English Translation) Take the fired bullets.
@@ -842,58 +796,13 @@ fn handles_collision(this: &mut InGameScene, shared: &mut Shared, _total_time: f
         instances.drain(..).collect()
     };
 
-    // <1>
-    if is_player_collide(&this.boss, &mut this.player, &mut enemy_bullets) {
-        let remaining_life = decrease_player_life_count(
-            &mut this.owned_hearts, 
-            &mut this.lost_hearts
-        );
-
-        if remaining_life == 0 {
-            audio.voice.stop();
-
-            tile_brush.update(queue, |instances| {
-                for &(r, c) in this.player.path.iter() {
-                    instances[r * this.table.num_cols + c].color = this.table.tiles[r][c].color;
-                }
-            });
-            while let Some((r, c)) = this.player.path.pop_front() {
-                this.table.tiles[r][c].visited = false;
-            }
-
-            this.player.face_timer = 0.0;
-            this.player.face_state = PlayerFaceState::Hit;
-            this.player.sprite.update(queue, |instances| {
-                instances[0].texture_index = PlayerFaceState::Hit as u32;
-            });
-
-            this.boss.face_timer = 0.0;
-            this.boss.face_state = BossFaceState::Smile;
-            this.boss.sprite.update(queue, |instances| {
-                instances[0].texture_index = BossFaceState::Smile as u32;
-            });
-
-            this.timer = 0.0;
-            this.state = InGameState::WaitForFinish;
-        } else {
-            // (한국어) 플레이어를 스폰위치로 이동시키고, 타일을 원래 상태로 되돌립니다.
-            // (English Translation) Moves the player to the spawn position and returns the tile to its original state. 
-            player::restore(
-                queue, 
-                &mut this.table, 
-                &mut this.boss, 
-                &mut this.player, 
-                tile_brush
-            );
-
-            play_random_character_voice(
-                &this.player_damage_sounds, 
-                &audio.voice, 
-                asset_bundle
-            )?;
-        }
-    }
-
+    let is_collide = is_player_collide(
+        &this.table, 
+        &this.boss, 
+        &this.player, 
+        &this.sim.player, 
+        &mut enemy_bullets
+    );
 
     // (한국어) 변경된 사항을 적용합니다.
     // (English Translation) Apply changes.
@@ -903,22 +812,25 @@ fn handles_collision(this: &mut InGameScene, shared: &mut Shared, _total_time: f
     }
     this.enemy_bullet.update(queue, |_| { });
 
-    Ok(())
+    return is_collide;
 }
 
 /// #### 한국어 </br>
-/// 플레이어가 적이나 적의 총알과 충돌한 경우 `true`를 반환합니다. </br>
+/// 플레이어나 아직 닫히지 않은 플레이어의 경로가 적이나 적의 총알과 충돌한 경우 `true`를 반환합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Returns `true` if the player collided with an enemy or an enemy bullet. </br>
+/// Returns `true` if the player or the player's trail that has not been closed yet 
+/// collided with an enemy or an enemy bullet. </br>
 /// 
 fn is_player_collide(
+    table: &Table, 
     boss: &Boss, 
-    player: &mut Player, 
+    player: &Player, 
+    body: &PlayerBody, 
     enemy_bullets: &mut Vec<BulletData>
 ) -> bool {
     let mut is_collide = false;
-    if player.game_state != PlayerGameState::Invincibility {
+    if body.game_state != PlayerGameState::Invincibility {
         let player_collider = player.collider();
 
         // (한국어) 1. 플레이어와 보스와의 충돌을 확인합니다.
@@ -926,11 +838,18 @@ fn is_player_collide(
         let boss_collider = boss.collider();
         is_collide |= player_collider.test(&boss_collider);
 
-        // (한국어) 2. 플레이어와 적의 총알과의 충돌을 확인합니다.
-        // (English Translation) 2. Check for collisions between player and enemy bullets.
+        // (한국어) 2. 플레이어의 경로와 보스와의 충돌을 확인합니다.
+        // (English Translation) 2. Check the collision between the player's trail and the boss.
+        is_collide |= table::tile_index(table, (boss_collider.x, boss_collider.y).into())
+            .is_some_and(|point| player::trail_hit(&body.path, point));
+
+        // (한국어) 3. 플레이어 또는 플레이어의 경로와 적의 총알과의 충돌을 확인합니다.
+        // (English Translation) 3. Check for collisions between the player or the player's trail and enemy bullets.
         let mut next_bullets = Vec::with_capacity(enemy_bullets.capacity());
         while let Some(bullet) = enemy_bullets.pop() {
-            if player_collider.test(&bullet.collider()) {
+            let on_trail = table::tile_index(table, bullet.translation.xy())
+                .is_some_and(|point| player::trail_hit(&body.path, point));
+            if on_trail || player_collider.test(&bullet.collider()) {
                 is_collide |= true;
                 continue;
             }
@@ -942,42 +861,45 @@ fn is_player_collide(
 }
 
 /// #### 한국어 </br>
-/// 플레이어의 라이프 카운트를 감소시키고 남은 라이프 카운트를 반환합니다. </br>
+/// 카메라가 보고 있는 영역을 기준으로 플레이어의 위치에 따른 스테레오 팬 값을 반환합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Decrease the player's life count and return the remaining life count. </br>
-/// 
-fn decrease_player_life_count(
-    owned_hearts: &mut VecDeque<UiObject>, 
-    lost_hearts: &mut VecDeque<(f64, UiObject)>
-) -> usize {
-    // (한국어) 플레이어의 라이프 카운트를 감소시킵니다.
-    // (English Translation) Decreases the player's life count.
-    if let Some(heart) = owned_hearts.pop_back() {
-        lost_hearts.push_back((0.0, heart));
-    }
-
-    return owned_hearts.len();
+/// Returns the stereo pan value for the player's position relative to the area the camera is looking at. </br>
+/// 
+fn player_pan(this: &InGameScene, camera: &GameCamera) -> f32 {
+    let instances = this.player.sprite.instances.lock().expect("Failed to access variable.");
+    return sound::camera_pan(camera, instances[0].translation.x);
 }
 
 /// #### 한국어 </br>
-/// 주어진 캐릭터 음성을 무작위로 선택하여 재생합니다. </br>
-/// 이미 재생중인 음성이 있는 경우 생략합니다. </br>
+/// 주어진 캐릭터 음성을 무작위로 선택하여 주어진 팬 값으로 재생합니다. </br>
+/// 재생 여부는 목소리 재사용 대기시간에 따라 결정됩니다. (상세: [`utils::VoiceCooldown`]) </br>
 /// 
 /// #### English (Translation) </br>
-/// Randomly selects and plays a given character's voice. </br>
-/// If there is already audio playing, it will be omitted. </br>
+/// Randomly selects and plays a given character's voice with the given pan value. </br>
+/// Whether it is played is decided by the voice cooldown. (see also: [`utils::VoiceCooldown`]) </br>
 /// 
 fn play_random_character_voice(
     voices: &Vec<&'static str>, 
-    voice_sink: &Sink, 
-    asset_bundle: &AssetBundle
+    kind: utils::VoiceKind, 
+    cooldown: &mut utils::VoiceCooldown, 
+    time: f64, 
+    voice_sink: &SoundSink, 
+    asset_bundle: &AssetBundle, 
+    rng: &CosmeticRng, 
+    pan: f32
 ) -> AppResult<()> {
-    if voice_sink.empty() {
-        let rel_path = voices.choose(&mut rand::thread_rng()).unwrap();
+    let action = cooldown.trigger(kind, time, voice_sink.empty());
+    if action == utils::VoiceAction::Interrupt {
+        voice_sink.stop();
+    }
+
+    if action != utils::VoiceAction::Skip {
+        let rel_path = voices.choose(&mut *rng.lock()).unwrap();
         let source = asset_bundle.get(rel_path)?
             .read(&SoundDecoder)?;
-        voice_sink.append(source);
+        asset_bundle.release(rel_path);
+        voice_sink.append(sound::Panned::new(source, pan));
     }
 
     Ok(())
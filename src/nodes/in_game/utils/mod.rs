@@ -4,21 +4,26 @@ use std::collections::{VecDeque, HashMap};
 use rand::prelude::*;
 use ab_glyph::FontArc;
 use glam::{Vec4, Vec3, Vec2};
-use rodio::{Sink, OutputStreamHandle};
+use rodio::OutputStreamHandle;
 
 use crate::{
+    game_err, 
     assets::bundle::AssetBundle, 
     components::{
         bullet::{Bullet, BulletBrush}, 
         sprite::SpriteBrush, 
         text::{TextBrush, Text, TextBuilder},
-        ui::{UiBrush, UiObject, UiObjectBuilder}, 
-        player::{self, Actor, Player, PlayerFaceState}, 
+        ui::{UiBrush, UiObject, UiObjectBuilder, Slider, ModalWindow, OutsideClick},
+        focus::FocusRing, 
+        interpolation, 
+        player::{self, Actor, Player, PlayerFaceState},
+        heart::HeartBar, 
         boss::{Boss, BossFaceState}, 
-        table::{Table, TileBrush}, 
-        anchor::Anchor, margin::Margin, 
+        table::{BoardSize, Table, TileBrush}, 
+        anchor::Anchor, margin::Margin,
+        sound::SoundSink,  
         script::{Script, ScriptTags}, 
-        user::{Language, Resolution, Settings}, 
+        user::{Language, Resolution, Settings, SettingToggle}, 
     }, 
     nodes::{
         path, 
@@ -26,11 +31,14 @@ use crate::{
         in_game::{
             self, 
             InGameScene, 
+            sim::{SimInput, SimState}, 
             state::InGameState, 
-        }
+        }, 
+        preload::{CancelFlag, LoadResult}, 
     }, 
     render::texture::DdsTextureDecoder, 
-    system::error::AppResult, 
+    scene::back_stack::BackStack, 
+    system::error::{AppResult, GameError}, 
 };
 
 
@@ -45,9 +53,28 @@ use crate::{
 pub enum PauseButton {
     Resume = 0, 
     Setting = 1, 
+    Restart = 2, 
     GiveUp = 3, 
 }
 
+/// #### 한국어 </br>
+/// 일시정지 버튼의 개수입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The number of pause buttons. </br>
+/// 
+pub const NUM_PAUSE_BUTTONS: usize = 4;
+
+// (한국어) 일시정지 버튼의 값이 서로 다르고 범위 안에 있는지 컴파일 시간에 확인합니다.
+// (English Translation) Checks at compile time that the pause button values are distinct and in range.
+const _: () = {
+    assert!(PauseButton::Resume as usize == 0);
+    assert!(PauseButton::Setting as usize == 1);
+    assert!(PauseButton::Restart as usize == 2);
+    assert!(PauseButton::GiveUp as usize == 3);
+    assert!(PauseButton::GiveUp as usize == NUM_PAUSE_BUTTONS - 1);
+};
+
 /// #### 한국어 </br>
 /// 종료 창의 버튼 목록입니다. </br>
 /// 
@@ -77,33 +104,242 @@ pub const SETTING_VOLUME_RANGE_MAX: i32 = 272;
 pub const SETTING_VOLUME_RANGE_MIN: i32 = -240;
 pub const VOLUME_BAR_WIDTH: i32 = 8;
 
+/// #### 한국어 </br>
+/// 해상도 되돌리기 창의 버튼 목록입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the list of buttons in the resolution revert window. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RevertWndButton {
+    Keep = 0, 
+    Revert = 1, 
+}
+
+pub const RESOLUTION_REVERT_SEC: f64 = 10.0;
+
+/// #### 한국어 </br>
+/// 설정 초기화 확인 창의 버튼 목록입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the list of buttons in the settings reset confirmation window. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ResetWndButton {
+    Yes = 0, 
+    No = 1, 
+}
+
+/// #### 한국어 </br>
+/// 결과 화면이 나타날 때 녹화하는 시간입니다. (`Settings::record_results`) </br>
+/// 
+/// #### English (Translation) </br>
+/// Recording time when the result screen appears. (`Settings::record_results`) </br>
+/// 
+pub const RESULT_RECORD_SEC: f64 = 3.0;
+
+/// #### 한국어 </br>
+/// 종료 창이나 설정 초기화 확인 창이 열렸을 때 배경막의 알파 값입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Alpha value of the backdrop when the exit window or the settings reset confirmation window is open. </br>
+/// 
+const EXIT_BACKDROP_ALPHA: f32 = 0.5;
+
 
 /// #### 한국어 </br>
 /// `InGame` 게임 장면에서 사용되는 [`rodio::Sink`]의 집합입니다. </br>
+/// - voice: 캐릭터 목소리를 재생합니다. 게임이 일시정지 되면 함께 일시정지 됩니다. </br>
+/// - effect: 일시정지 소리처럼 일시정지 중에도 들려야 하는 효과음을 재생합니다. </br>
+/// 
+/// 배경 음악은 게임 루프가 [`BgmPlayer`](crate::components::sound::BgmPlayer)로 일시정지 합니다. </br>
 /// 
 /// #### English (Translation) </br>
 /// A setof [`rodio::Sink`] used in `InGame` game scene. </br>
+/// - voice: Plays the character voices. It is paused together when the game is paused. </br>
+/// - effect: Plays sound effects that must be audible even while paused, such as the pause sound. </br>
+/// 
+/// The background music is paused by the game loop through [`BgmPlayer`](crate::components::sound::BgmPlayer). </br>
 /// 
 pub struct InGameAudio {
-    pub background: Sink, 
-    pub voice: Sink, 
+    pub voice: SoundSink, 
+    pub effect: SoundSink, 
 }
 
 impl InGameAudio {
-    pub fn new(settings: &Settings, stream: &OutputStreamHandle) -> AppResult<Arc<Self>> {
+    pub fn new(settings: &Settings, stream: Option<&OutputStreamHandle>) -> AppResult<Arc<Self>> {
         use crate::components::sound;
 
-        let background = sound::create_sink(stream)?;
-        background.set_volume(settings.background_volume.norm());
-
         let voice = sound::create_sink(stream)?;
-        voice.set_volume(settings.voice_volume.norm());
+        voice.set_volume(settings.voice_volume.gain());
+        let effect = sound::create_sink(stream)?;
+        effect.set_volume(settings.effect_volume.gain());
 
         Ok(Self {
-            background, 
             voice, 
+            effect, 
         }.into())
     }
+
+    /// #### 한국어 </br>
+    /// 목소리 재생기를 일시정지 하거나 다시 재생합니다. 효과음 재생기는 계속 재생됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Pauses or resumes the voice player. The sound effect player keeps playing. </br>
+    /// 
+    #[inline]
+    pub fn set_paused(&self, paused: bool) {
+        crate::components::sound::set_paused([&self.voice], paused);
+    }
+
+    /// #### 한국어 </br>
+    /// 설정된 음량을 목소리와 효과음 재생기에 적용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Applies the configured volumes to the voice and sound effect players. </br>
+    /// 
+    #[inline]
+    pub fn apply_volume(&self, settings: &Settings) {
+        self.voice.set_volume(settings.voice_volume.gain());
+        self.effect.set_volume(settings.effect_volume.gain());
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 캐릭터 목소리를 재생한 후 다음 목소리를 재생할 수 있을 때까지의 최소 시간 간격입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The minimum time interval after a character voice starts before the next voice can be played. </br>
+/// 
+pub const VOICE_COOLDOWN_SEC: f64 = 2.0;
+
+/// #### 한국어 </br>
+/// 캐릭터 목소리의 종류입니다. 피격 목소리는 웃음 목소리보다 우선합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The kind of character voice. Damage voices take priority over smile voices. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum VoiceKind {
+    Smile, 
+    Damage, 
+}
+
+/// #### 한국어 </br>
+/// 캐릭터 목소리를 재생하려 할 때 수행할 동작입니다. </br>
+/// - Play: 목소리를 재생합니다. </br>
+/// - Interrupt: 재생 중인 목소리를 멈추고 목소리를 재생합니다. </br>
+/// - Skip: 목소리를 재생하지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The action to take when trying to play a character voice. </br>
+/// - Play: Plays the voice. </br>
+/// - Interrupt: Stops the playing voice and plays the voice. </br>
+/// - Skip: Does not play the voice. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VoiceAction {
+    Play, 
+    Interrupt, 
+    Skip, 
+}
+
+/// #### 한국어 </br>
+/// 캐릭터 목소리가 겹쳐서 재생되지 않도록 마지막으로 재생한 목소리를 기록합니다. </br>
+/// 웃음 목소리는 이전 목소리가 끝났고 이전 목소리가 시작된 후 `VOICE_COOLDOWN_SEC`이 지난 경우에만 재생됩니다. </br>
+/// 피격 목소리는 대기 시간을 무시하며, 재생 중인 웃음 목소리를 중단하고 재생됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Records the last played voice so that character voices do not overlap. </br>
+/// A smile voice is only played if the previous voice has finished and `VOICE_COOLDOWN_SEC` has elapsed since the previous voice started. </br>
+/// A damage voice ignores the cooldown, and interrupts a playing smile voice. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct VoiceCooldown {
+    last: Option<(VoiceKind, f64)>, 
+}
+
+impl VoiceCooldown {
+    /// #### 한국어 </br>
+    /// `time`에 `kind` 목소리를 재생하려 할 때 수행할 동작을 결정하고, 재생하는 경우 기록합니다. </br>
+    /// `sink_empty`는 목소리 [`rodio::Sink`]가 비어있는지 여부입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Decides the action to take when trying to play a `kind` voice at `time`, and records it if it is played. </br>
+    /// `sink_empty` is whether the voice [`rodio::Sink`] is empty. </br>
+    /// 
+    pub fn trigger(&mut self, kind: VoiceKind, time: f64, sink_empty: bool) -> VoiceAction {
+        let action = match self.last {
+            Some((last, _)) if !sink_empty && kind > last => VoiceAction::Interrupt, 
+            _ if !sink_empty => VoiceAction::Skip, 
+            _ if kind == VoiceKind::Damage => VoiceAction::Play, 
+            Some((_, started)) if time - started < VOICE_COOLDOWN_SEC => VoiceAction::Skip, 
+            _ => VoiceAction::Play, 
+        };
+
+        if action != VoiceAction::Skip {
+            self.last = Some((kind, time));
+        }
+        return action;
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 섞인 순서대로 재생되는 배경 음악 목록입니다. </br>
+/// 목록의 마지막 곡이 끝나면 다시 섞으며, 방금 재생한 곡이 바로 다시 재생되지 않도록 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A list of background music played in shuffled order. </br>
+/// When the last track of the list ends, it is shuffled again so that the track just played is not played again right away. </br>
+/// 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BgmPlaylist {
+    tracks: Vec<&'static str>, 
+    index: usize, 
+}
+
+impl BgmPlaylist {
+    pub fn new<R: Rng>(mut candidates: Vec<&'static str>, rng: &mut R) -> Self {
+        assert!(!candidates.is_empty(), "The playlist must have at least one track!");
+        candidates.shuffle(rng);
+        Self { tracks: candidates, index: 0 }
+    }
+
+    #[inline]
+    pub fn tracks(&self) -> &[&'static str] {
+        &self.tracks
+    }
+
+    #[inline]
+    pub fn current(&self) -> &'static str {
+        self.tracks[self.index]
+    }
+
+    /// #### 한국어 </br>
+    /// 다음 곡으로 넘어가고 그 곡을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances to the next track and returns it. </br>
+    /// 
+    pub fn advance<R: Rng>(&mut self, rng: &mut R) -> &'static str {
+        let last = self.current();
+        self.index += 1;
+        if self.index >= self.tracks.len() {
+            self.tracks.shuffle(rng);
+            self.index = 0;
+
+            // (한국어) 새로 섞은 첫 곡이 방금 재생한 곡인 경우 맨 뒤로 보냅니다.
+            // (English Translation) If the first track after shuffling is the one just played, moves it to the end.
+            if self.tracks.len() > 1 && self.tracks[0] == last {
+                self.tracks.rotate_left(1);
+            }
+        }
+        return self.current();
+    }
 }
 
 
@@ -122,8 +358,11 @@ pub fn create_game_scene(
     tile_brush: &TileBrush, 
     bullet_brush: &BulletBrush, 
     texture_map: &HashMap<String, wgpu::Texture>, 
-    asset_bundle: &AssetBundle
-) -> AppResult<InGameScene> {
+    asset_bundle: &AssetBundle, 
+    rng: &mut StdRng, 
+    cosmetic_rng: &mut StdRng, 
+    cancel: &CancelFlag
+) -> AppResult<LoadResult<InGameScene>> {
     let nexon_lv2_gothic_medium = fonts.get(path::NEXON_LV2_GOTHIC_MEDIUM_PATH)
         .expect("Registered font not found!");
 
@@ -145,6 +384,12 @@ pub fn create_game_scene(
         &texture_view, 
         ui_brush
     );
+    let exit_backdrop = create_exit_backdrop(
+        device, 
+        tex_sampler, 
+        &texture_view, 
+        ui_brush
+    );
 
     let setting_volume_background = create_setting_volume_background(
         nexon_lv2_gothic_medium, 
@@ -165,6 +410,12 @@ pub fn create_game_scene(
     );
 
 
+    // (한국어) 불러오기가 취소된 경우 지금까지 생성한 자원들을 해제하고 돌아갑니다.
+    // (English Translation) If loading is canceled, releases the resources created so far and returns.
+    if cancel.is_canceled() {
+        return Ok(LoadResult::Canceled);
+    }
+
     // (한국어) 이미지 파일을 불러오고, 텍스처를 생성합니다. 
     // (English Translation) Load an image file and create a texture. 
     let texture = asset_bundle.get(path::INGAME_BACKGROUND_TEXTURE_PATH)?
@@ -207,12 +458,9 @@ pub fn create_game_scene(
     // (English Translation) Load an image file and create a texture. 
     let default_texture = texture_map.get(path::DEF_IMG_TEXTURE_PATH)
         .expect("Registered image not found!");
-    let stage_texture = texture_map.get(match actor {
-        Actor::Aris => path::ARIS_IMG_TEXTURE_PATH, 
-        Actor::Momoi => path::MOMOI_IMG_TEXTURE_PATH, 
-        Actor::Midori => path::MIDORI_IMG_TEXTURE_PATH, 
-        Actor::Yuzu => path::YUZU_IMG_TEXTURE_PATH, 
-    }).expect("Registered image not found!");
+    let actor_assets = ActorAssets::new(actor);
+    let stage_texture = texture_map.get(actor_assets.stage_image)
+        .expect("Registered image not found!");
     let stage_images = create_stage_image(
         device, 
         tex_sampler, 
@@ -225,37 +473,32 @@ pub fn create_game_scene(
 
     // (한국어) 게임 장면의 타일들을 생성합니다.
     // (English Translation) Create tiles for the `InGame` game scene.
+    let board_size = settings.board_size;
     let table = Table::new(
-        100, 
-        100, 
+        board_size.num_rows(), 
+        board_size.num_cols(), 
         6, 
-        Vec4::new(137.0 / 255.0, 207.0 / 255.0, 243.0 / 255.0, 1.0), 
-        Vec4::new(160.0 / 255.0, 233.0 / 255.0, 255.0 / 255.0, 1.0), 
-        Vec4::new(1.0, 0.0, 0.0, 1.0), 
+        settings.table_theme, 
         Vec3::new(
             -35.0 * PIXEL_PER_METER, 
             -25.0 * PIXEL_PER_METER, 
             -1.0 * PIXEL_PER_METER
         ),
-        Vec2::new(
-            0.5 * PIXEL_PER_METER, 
-            0.5 * PIXEL_PER_METER
-        ),
+        board_tile_size(board_size),
         queue, 
-        tile_brush
+        tile_brush, 
+        rng
     );
 
 
 
+    if cancel.is_canceled() {
+        return Ok(LoadResult::Canceled);
+    }
+
     // (한국어) 이미지 파일을 불러오고, 텍스처를 생성합니다. 
     // (English Translation) Load an image file and create a texture. 
-    let image_rel_path = match actor {
-        Actor::Aris => path::ARIS_PLAYER_TEXTURE_PATH, 
-        Actor::Momoi => path::MOMOI_PLAYER_TEXTURE_PATH, 
-        Actor::Midori => path::MIDORI_PLAYER_TEXTURE_PATH, 
-        Actor::Yuzu => path::YUZU_PLAYER_TEXTURE_PATH, 
-    };
-    let texture = asset_bundle.get(image_rel_path)?
+    let texture = asset_bundle.get(actor_assets.player_texture)?
         .read(&DdsTextureDecoder {
             name: Some("Player"), 
             size: wgpu::Extent3d {
@@ -281,7 +524,7 @@ pub fn create_game_scene(
 
     // (한국어) 사용완료한 에셋을 해제합니다.
     // (English Translation) Release assets that have been used. 
-    asset_bundle.release(image_rel_path);
+    asset_bundle.release(actor_assets.player_texture);
     
     let player = Player::new(
         actor, 
@@ -303,6 +546,10 @@ pub fn create_game_scene(
     );
 
 
+    if cancel.is_canceled() {
+        return Ok(LoadResult::Canceled);
+    }
+
     // (한국어) 이미지 파일을 불러오고, 텍스처를 생성합니다.
     // (English Translation) Load an image file and create a texture. 
     let texture = asset_bundle.get(path::YUUKA_BULLET_TEXTURE_PATH)?
@@ -340,6 +587,10 @@ pub fn create_game_scene(
 
 
 
+    if cancel.is_canceled() {
+        return Ok(LoadResult::Canceled);
+    }
+
     // (한국어) 이미지 파일을 불러오고, 텍스처를 생성합니다.
     // (English Translation) Load an image file and create a texture. 
     let texture = asset_bundle.get(path::YUUKA_ENEMY_TEXTURE_PATH)?
@@ -377,7 +628,8 @@ pub fn create_game_scene(
         device, 
         tex_sampler, 
         &texture_view, 
-        sprite_brush
+        sprite_brush, 
+        rng
     );
 
     let boss_faces = create_boss_face(
@@ -389,6 +641,10 @@ pub fn create_game_scene(
 
 
 
+    if cancel.is_canceled() {
+        return Ok(LoadResult::Canceled);
+    }
+
     // (한국어) 이미지 파일을 불러오고, 텍스처를 생성합니다. 
     // (English Translation) Load an image file and create a texture. 
     let texture = asset_bundle.get(path::BUTTON_ETC_TEXTURE_PATH)?
@@ -495,7 +751,7 @@ pub fn create_game_scene(
         text_brush
     );
 
-    let pause_exit_window = create_exit_window(
+    let (exit_window, exit_message) = create_exit_window(
         nexon_lv2_gothic_medium, 
         script, 
         device, 
@@ -570,6 +826,13 @@ pub fn create_game_scene(
         text_brush
     )?;
 
+    let result_clear_time = create_result_clear_time(
+        nexon_lv2_gothic_bold, 
+        device, 
+        queue, 
+        text_brush
+    );
+
     let pause_exit_buttons = create_exit_buttons(
         nexon_lv2_gothic_medium, 
         script, 
@@ -580,6 +843,10 @@ pub fn create_game_scene(
         ui_brush, 
         text_brush
     )?;
+    let pause_exit_window = ModalWindow::new(exit_window, vec![exit_message], pause_exit_buttons)
+        .with_backdrop(exit_backdrop, EXIT_BACKDROP_ALPHA)
+        .with_outside_click(OutsideClick::Close)
+        .with_focus_order(vec![ExitWndButton::No, ExitWndButton::Yes]);
 
     let setting_languages = create_setting_languages(
         nexon_lv2_gothic_medium, 
@@ -599,6 +866,17 @@ pub fn create_game_scene(
         ui_brush, 
         text_brush
     );
+    let setting_toggles = create_setting_toggles(
+        nexon_lv2_gothic_medium, 
+        script, 
+        settings, 
+        device, 
+        queue, 
+        tex_sampler, 
+        &texture_view, 
+        ui_brush, 
+        text_brush
+    )?;
     let setting_return_button = create_setting_return_button(
         nexon_lv2_gothic_medium, 
         script, 
@@ -609,6 +887,59 @@ pub fn create_game_scene(
         ui_brush, 
         text_brush
     )?;
+    let setting_apply_button = create_setting_apply_button(
+        nexon_lv2_gothic_medium, 
+        script, 
+        device, 
+        queue, 
+        tex_sampler, 
+        &texture_view, 
+        ui_brush, 
+        text_brush
+    )?;
+    let setting_reset_button = create_setting_reset_button(
+        nexon_lv2_gothic_medium, 
+        script, 
+        device, 
+        queue, 
+        tex_sampler, 
+        &texture_view, 
+        ui_brush, 
+        text_brush
+    )?;
+    let (setting_revert_window, setting_revert_countdown, setting_revert_buttons) = create_setting_revert_window(
+        nexon_lv2_gothic_medium, 
+        script, 
+        device, 
+        queue, 
+        tex_sampler, 
+        &window_texture_view, 
+        &texture_view, 
+        ui_brush, 
+        text_brush
+    )?;
+
+    // (한국어) 텍스처 맵에서 더미 텍스처를 가져와 설정 초기화 확인 창을 생성합니다.
+    // (English Translation) Creates the settings reset confirmation window by taking a dummy texture from the texture map.
+    let dummy_texture = texture_map.get(path::DUMMY_TEXTURE_PATH)
+        .expect("A registered texture could not be found.");
+    let dummy_texture_view = dummy_texture.create_view(
+        &wgpu::TextureViewDescriptor {
+            ..Default::default()
+        }
+    );
+    let setting_reset_window = create_setting_reset_window(
+        nexon_lv2_gothic_medium, 
+        script, 
+        device, 
+        queue, 
+        tex_sampler, 
+        &dummy_texture_view, 
+        &window_texture_view, 
+        &texture_view, 
+        ui_brush, 
+        text_brush
+    )?;
 
 
     // (한국어) `dds`이미지 파일로부터 버튼 텍스처를 생성합니다.
@@ -675,6 +1006,10 @@ pub fn create_game_scene(
 
 
 
+    if cancel.is_canceled() {
+        return Ok(LoadResult::Canceled);
+    }
+
     // (한국어) 이미지 파일을 불러오고, 텍스처를 생성합니다. 
     // (English Translation) Load an image file and create a texture. 
     let texture = asset_bundle.get(path::HEART_TEXTURE_PATH)?
@@ -704,8 +1039,7 @@ pub fn create_game_scene(
     // (English Translation) Release assets that have been used. 
     asset_bundle.release(path::HEART_TEXTURE_PATH);
 
-    let lost_hearts = VecDeque::with_capacity(player::MAX_PLAYER_HEARTS);
-    let owned_hearts = create_player_hearts(
+    let hearts = create_player_hearts(
         player::MAX_PLAYER_HEARTS as u32, 
         device, 
         tex_sampler, 
@@ -724,6 +1058,15 @@ pub fn create_game_scene(
         text_brush
     );
 
+    // (한국어) 연속 획득 배율을 보여주는 텍스트를 생성합니다.
+    // (English Translation) Creates text showing the combo multiplier.
+    let combo_text = create_combo_text(
+        nexon_lv2_gothic_bold, 
+        device, 
+        queue, 
+        text_brush
+    );
+
 
 
     // (한국어) 일시정지 버튼 텍스처를 생성합니다.
@@ -778,62 +1121,16 @@ pub fn create_game_scene(
 
     // (한국어) `InGame` 게임 장면에서 사용되는 음향 에셋들을 로드합니다.
     // (English Translation) Load sound assets used in `InGame` game scene. 
-    let player_startup_sound = match actor {
-        Actor::Aris => path::ARIS_STAGE_START_SOUND_PATH,
-        Actor::Momoi => path::MOMOI_STAGE_START_SOUND_PATH, 
-        Actor::Midori => path::MIDORI_STAGE_START_SOUND_PATH, 
-        Actor::Yuzu => path::YUZU_STAGE_START_SOUND_PATH, 
-    };
-
-    let player_smile_sounds = match actor {
-        Actor::Aris => vec![
-            path::ARIS_SMILE_0_SOUND_PATH, 
-            path::ARIS_SMILE_1_SOUND_PATH
-        ],
-        Actor::Momoi => vec![
-            path::MOMOI_SMILE_0_SOUND_PATH, 
-            path::MOMOI_SMILE_1_SOUND_PATH, 
-        ], 
-        Actor::Midori => vec![
-            path::MIDORI_SMILE_0_SOUND_PATH, 
-            path::MIDORI_SMILE_1_SOUND_PATH, 
-        ], 
-        Actor::Yuzu => vec![
-            path::YUZU_SMILE_0_SOUND_PATH, 
-            path::YUZU_SMILE_1_SOUND_PATH, 
-        ], 
-    };
-
-    let player_damage_sounds = match actor {
-        Actor::Aris => vec![
-            path::YUUKA_ATTACK0_SOUND_PATH, 
-            path::ARIS_DAMAGE_0_SOUND_PATH, 
-            path::ARIS_DAMAGE_1_SOUND_PATH, 
-            path::ARIS_DAMAGE_2_SOUND_PATH
-        ], 
-        Actor::Momoi => vec![
-            path::YUUKA_ATTACK0_SOUND_PATH, 
-            path::MOMOI_DAMAGE_0_SOUND_PATH, 
-            path::MOMOI_DAMAGE_1_SOUND_PATH, 
-            path::MOMOI_DAMAGE_2_SOUND_PATH, 
-        ], 
-        Actor::Midori => vec![
-            path::YUUKA_ATTACK0_SOUND_PATH, 
-            path::MIDORI_DAMAGE_0_SOUND_PATH, 
-            path::MIDORI_DAMAGE_1_SOUND_PATH, 
-            path::MIDORI_DAMAGE_2_SOUND_PATH, 
-        ], 
-        Actor::Yuzu => vec![
-            path::YUUKA_ATTACK0_SOUND_PATH, 
-            path::YUZU_DAMAGE_0_SOUND_PATH, 
-            path::YUZU_DAMAGE_1_SOUND_PATH, 
-            path::YUZU_DAMAGE_2_SOUND_PATH, 
-        ],
-    };
-
-    let mut candidates = [path::THEME18_SOUND_PATH, path::THEME19_SOUND_PATH, path::THEME30_SOUND_PATH];
-    candidates.shuffle(&mut rand::thread_rng());
-    let bgm_sound = candidates[0];
+    let player_startup_sound = actor_assets.stage_start_sound;
+    let player_smile_sounds = actor_assets.smile_sounds.to_vec();
+    let player_damage_sounds = actor_assets.damage_sounds.to_vec();
+
+    let candidates = vec![path::THEME18_SOUND_PATH, path::THEME19_SOUND_PATH, path::THEME30_SOUND_PATH];
+    let bgm_playlist = BgmPlaylist::new(candidates, cosmetic_rng);
+
+    if cancel.is_canceled() {
+        return Ok(LoadResult::Canceled);
+    }
 
     // (한국어) 현재 게임 장면에서 사용되는 에셋들을 로드합니다.
     // (English Translation) Loads assets used in the current game scene. 
@@ -844,25 +1141,41 @@ pub fn create_game_scene(
     for rel_path in player_damage_sounds.iter() {
         asset_bundle.get(rel_path)?;
     }
-    asset_bundle.get(bgm_sound)?;
+    for rel_path in bgm_playlist.tracks() {
+        asset_bundle.get(rel_path)?;
+    }
 
 
 
-    Ok(InGameScene {
+    let sim = SimState::new(
+        table.num_rows, 
+        table.num_cols, 
+        table.player_spawn_pos, 
+        hearts.len(), 
+        in_game::GAME_DURATION_SEC
+    );
+
+    Ok(LoadResult::Loaded(InGameScene {
         timer: 0.0, 
-        remaining_time: in_game::GAME_DURATION_SEC, 
         state: InGameState::default(), 
+        back_stack: BackStack::new(InGameState::Run), 
+        sim, 
+        sim_input: SimInput::default(), 
+        board_size, 
         pause_text, 
         pause_buttons, 
+        pause_focus: FocusRing::new(vec![
+            PauseButton::Resume, 
+            PauseButton::Setting, 
+            PauseButton::Restart, 
+            PauseButton::GiveUp, 
+        ]), 
         pause_exit_window, 
-        pause_exit_buttons, 
         percent, 
-        percent_timer: in_game::PERCENT_DURATION, 
-        num_total_tiles: in_game::NUM_TILES as u32, 
-        num_owned_tiles: 0, 
+        percent_timer: 0.0, 
         owned_tiles: VecDeque::new(), 
-        owned_hearts, 
-        lost_hearts, 
+        hearts, 
+        combo_text, 
         foreground, 
         background, 
         stage_images, 
@@ -874,6 +1187,8 @@ pub fn create_game_scene(
         result_stars, 
         result_star_index: 0, 
         result_challenge_texts: result_condition_texts, 
+        result_clear_time, 
+        clear_time: None, 
         table, 
         player, 
         player_faces, 
@@ -883,15 +1198,24 @@ pub fn create_game_scene(
         player_startup_sound, 
         player_smile_sounds, 
         player_damage_sounds, 
-        bgm_sound, 
+        voice_cooldown: VoiceCooldown::default(), 
+        bgm_playlist, 
         setting_windows, 
         setting_titles, 
         setting_languages, 
         setting_resolutions, 
+        setting_toggles, 
         setting_return_button, 
+        setting_apply_button, 
+        setting_reset_button, 
+        setting_reset_window, 
         setting_volume_background, 
         setting_volume_bar, 
-    })
+        setting_revert_window, 
+        setting_revert_countdown, 
+        setting_revert_buttons, 
+        setting_revert: None, 
+    }))
 }
 
 /// #### 한국어 </br>
@@ -918,6 +1242,31 @@ fn create_foreground(
     .build(device)
 }
 
+/// #### 한국어 </br>
+/// 종료 창 뒤의 장면을 어둡게 하는 배경막을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a backdrop that dims the scene behind the exit window. </br>
+/// 
+#[inline]
+fn create_exit_backdrop(
+    device: &wgpu::Device, 
+    tex_sampler: &wgpu::Sampler, 
+    texture_view: &wgpu::TextureView, 
+    ui_brush: &UiBrush
+) -> UiObject {
+    UiObjectBuilder::new(
+        Some("ExitBackdrop"), 
+        tex_sampler, 
+        texture_view, 
+        ui_brush
+    )
+    .with_anchor(Anchor::new(1.0, 0.0, 0.0, 1.0))
+    .with_color((0.0, 0.0, 0.0, 0.0).into())
+    .with_global_translation((0.0, 0.0, 0.9).into())
+    .build(device)
+}
+
 /// #### 한국어 </br>
 /// `InGame` 게임 장면의 배경을 생성합니다. </br>
 /// 
@@ -1069,6 +1418,15 @@ fn create_menu_button(
     .build(device)
 }
 
+/// #### 한국어 </br>
+/// 배경 위에서도 잘 보이도록 남은 시간과 퍼센트 텍스트 아래에 그리는 그림자의 (위치 오프셋, 색상) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The (offset, color) of the shadow drawn under the remaining time and percent texts so that they stay legible over the background. </br>
+/// 
+const TEXT_SHADOW_OFFSET: (i32, i32) = (2, 2);
+const TEXT_SHADOW_COLOR: Vec4 = Vec4::new(0.0, 0.0, 0.0, 1.0);
+
 /// #### 한국어 </br>
 /// 남은 시간을 표시하는 타이머를 생성합니다. </br>
 /// 
@@ -1094,16 +1452,15 @@ fn create_remaining_timer(
     .with_anchor(Anchor::new(1.0 - 0.03666666667, 0.73, 1.0 - 0.1233333333, 0.88))
     .build(device);
 
-    let min = (in_game::GAME_DURATION_SEC / 60.0) as u32;
-    let sec = (in_game::GAME_DURATION_SEC % 60.0) as u32;
     let text = TextBuilder::new(
         Some("RemainingTimer"), 
         font,         
-        &format!("{}:{:0>2}", min, sec), 
+        &format_remaining_time(in_game::GAME_DURATION_SEC), 
         text_brush
     )
     .with_translation((0.0, 0.0, 0.5).into())
     .with_anchor(Anchor::new(1.0 - 0.01666666667, 0.73, 1.0 - 0.1433333333, 0.88))
+    .with_shadow(TEXT_SHADOW_OFFSET, TEXT_SHADOW_COLOR)
     .build(device, queue);
 
     return (bg, text);
@@ -1291,16 +1648,16 @@ fn create_player_hearts(
     tex_sampler: &wgpu::Sampler, 
     texture_view: &wgpu::TextureView, 
     ui_brush: &UiBrush
-) -> VecDeque<UiObject> {
+) -> HeartBar {
     let left = 0.72;
     let right = 0.98;
     let gap = 0.02;
     let height = 0.78;
     let width = ((right - left) - gap * (life_count - 1) as f32) / life_count as f32;
 
-    let mut hearts = VecDeque::with_capacity(life_count as usize);
+    let mut hearts = Vec::with_capacity(life_count as usize);
     for num in 0..life_count {
-        hearts.push_back(UiObjectBuilder::new(
+        hearts.push(UiObjectBuilder::new(
             Some(&format!("PlayerHeart({})", num)), 
             tex_sampler, 
             texture_view, 
@@ -1317,7 +1674,7 @@ fn create_player_hearts(
         .build(device))
     }
 
-    return hearts;
+    return HeartBar::new(hearts);
 }
 
 /// #### 한국어 </br>
@@ -1340,64 +1697,381 @@ fn create_percent_text(
     )
     .with_anchor(Anchor::new(0.15 + 0.3, 0.72, 0.15, 0.98))
     .with_translation((0.0, 0.0, 0.25).into())
+    .with_shadow(TEXT_SHADOW_OFFSET, TEXT_SHADOW_COLOR)
     .build(device, queue)
 }
 
 /// #### 한국어 </br>
-/// 일시정지 사용자 인터페이스 윈도우를 생성합니다. </br>
+/// 연속 획득 배율을 보여주는 사용자 인터페이스를 생성합니다. </br>
+/// 연속 획득이 시작되기 전에는 보이지 않습니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Creates a puse user interface window. </br>
+/// Create a user interface that shows the combo multiplier. </br>
+/// It is not visible until a combo starts. </br>
 /// 
-fn create_pause_text(
-    font: &FontArc, 
+#[inline]
+fn create_combo_text(
+    font: &FontArc,
     device: &wgpu::Device, 
     queue: &wgpu::Queue, 
-    text_brush: &TextBrush, 
-    script: &Script
-) -> AppResult<Text> {
-    let text = script.get(ScriptTags::InGamePauseTitle)?;
-    return Ok(TextBuilder::new(
-        Some("PuaseWindow"), 
+    text_brush: &TextBrush
+) -> Text {
+    TextBuilder::new(
+        Some("Combo"), 
         font, 
-        text, 
+        &format_combo(1), 
         text_brush
-    ).with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
-    .with_margin(Margin::new(240, -320, 112, 320))
-    .with_color((1.0, 1.0, 1.0, 0.0).into())
-    .with_translation((0.0, 0.0, 0.5).into())
-    .build(device, queue));
+    )
+    .with_anchor(Anchor::new(0.15, 0.72, 0.075, 0.98))
+    .with_color((255.0 / 255.0, 215.0 / 255.0, 0.0 / 255.0, 0.0).into())
+    .with_translation((0.0, 0.0, 0.25).into())
+    .build(device, queue)
 }
 
 /// #### 한국어 </br>
-/// 일시정지 화면에서 사용되는 버튼들을 생성합니다. </br>
+/// 연속 획득으로 인정되는 최대 시간 간격입니다. </br>
+/// 이 시간 동안 영역을 획득하지 못하면 연속 획득이 끊깁니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Create buttons used on the pause screen. </br>
+/// The maximum time between captures that still counts as a combo. </br>
+/// If no area is captured within this time, the combo is broken. </br>
 /// 
-fn create_pause_buttons(
-    font: &FontArc, 
-    script: &Script,
-    device: &wgpu::Device, 
-    queue: &wgpu::Queue, 
-    tex_sampler: &wgpu::Sampler, 
-    texture_view: &wgpu::TextureView, 
-    ui_brush: &UiBrush, 
-    text_brush: &TextBrush, 
-) -> AppResult<HashMap<PauseButton, (UiObject, Text)>> {
-    let resume_btn = UiObjectBuilder::new(
-        Some("ResumeButton"), 
-        tex_sampler, 
-        texture_view, 
-        ui_brush
-    )
-    .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
-    .with_margin(Margin::new(100, -160, 40, 160))
-    .with_color((1.0, 1.0, 1.0, 0.0).into())
-    .build(device);
-    let text = script.get(ScriptTags::InGameResumeButton)?;
-    let resume_text = TextBuilder::new(
-        Some("ResumeText"), 
+pub const COMBO_WINDOW_SEC: f64 = 3.0;
+const COMBO_MULTIPLIER_STEP: f32 = 0.5;
+const MAX_COMBO_MULTIPLIER: f32 = 3.0;
+
+/// #### 한국어 </br>
+/// 영역을 획득했을 때의 다음 연속 획득 수를 반환합니다. </br>
+/// `combo_timer`는 마지막으로 영역을 획득한 후 경과한 시간입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the next combo count when an area is captured. </br>
+/// `combo_timer` is the time elapsed since the last capture. </br>
+/// 
+#[inline]
+pub fn next_combo(combo: u32, combo_timer: f64) -> u32 {
+    if combo > 0 && combo_timer <= COMBO_WINDOW_SEC {
+        return combo + 1;
+    }
+    return 1;
+}
+
+/// #### 한국어 </br>
+/// 연속 획득 수에 따른 배율을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the multiplier for the combo count. </br>
+/// 
+#[inline]
+pub fn combo_multiplier(combo: u32) -> f32 {
+    let multiplier = 1.0 + COMBO_MULTIPLIER_STEP * combo.saturating_sub(1) as f32;
+    return multiplier.min(MAX_COMBO_MULTIPLIER);
+}
+
+/// #### 한국어 </br>
+/// 연속 획득 배율을 `x1.5` 형식의 문자열로 변환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Converts the combo multiplier to a string in `x1.5` format. </br>
+/// 
+#[inline]
+pub fn format_combo(combo: u32) -> String {
+    format!("x{:.1}", combo_multiplier(combo))
+}
+
+/// #### 한국어 </br>
+/// 차지한 영역의 비율에 따른 결과 점수의 인덱스를 반환합니다. 달성한 도전 과제의 수와 같습니다. </br>
+/// 모든 영역을 차지한 경우에만 `4`를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the index of the resulting score according to the ratio of the occupied area. It equals the number of achieved challenges. </br>
+/// Returns `4` only if the entire area is occupied. </br>
+/// 
+pub fn percent_star_index(percent: f32) -> usize {
+    if percent < 20.0 {
+        return 0;
+    } else if percent < 50.0 {
+        return 1;
+    } else if percent < 80.0 {
+        return 2;
+    } else if percent < 100.0 {
+        return 3;
+    }
+    return 4;
+}
+
+/// #### 한국어 </br>
+/// 차지한 영역의 비율과 최대 연속 획득 수에 따른 결과 점수의 인덱스를 반환합니다. </br>
+/// 최대 배율에 도달한 적이 있으면 별 하나를 더 받지만, 모든 영역을 차지하지 않으면 `3`을 넘지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the index of the resulting score according to the ratio of the occupied area and the maximum combo count. </br>
+/// If the maximum multiplier has ever been reached, one more star is given, but it does not exceed `3` unless the entire area is occupied. </br>
+/// 
+pub fn result_star_index(percent: f32, max_combo: u32) -> usize {
+    let index = percent_star_index(percent);
+    if index < 3 && combo_multiplier(max_combo) >= MAX_COMBO_MULTIPLIER {
+        return index + 1;
+    }
+    return index;
+}
+
+/// #### 한국어 </br>
+/// 게임 보드가 차지하는 영역의 크기 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The size of the area occupied by the game board. </br>
+/// 
+const BOARD_WIDTH: f32 = 50.0 * PIXEL_PER_METER;
+const BOARD_HEIGHT: f32 = 50.0 * PIXEL_PER_METER;
+
+/// #### 한국어 </br>
+/// 보드 크기에 따른 타일 하나의 크기를 반환합니다. </br>
+/// 보드가 항상 같은 영역을 차지하므로 카메라의 투영 범위는 바꿀 필요가 없습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the size of one tile according to the board size. </br>
+/// Since the board always occupies the same area, the projection bounds of the camera do not need to change. </br>
+/// 
+#[inline]
+pub fn board_tile_size(board_size: BoardSize) -> Vec2 {
+    Vec2::new(
+        BOARD_WIDTH / board_size.num_cols() as f32, 
+        BOARD_HEIGHT / board_size.num_rows() as f32
+    )
+}
+
+/// #### 한국어 </br>
+/// 선택된 캐릭터를 반환합니다. </br>
+/// 개발용 실행 인자로 게임 장면에 바로 진입하여 선택된 캐릭터가 없는 경우,
+/// 디버그 빌드(`debug`)에서는 기본 캐릭터([`Actor::default`])를 사용하고 이를 기록합니다. </br>
+/// 이때 기본 캐릭터의 에셋이 에셋 목록에 없는 경우 오류를 반환합니다. </br>
+/// 릴리즈 빌드에서는 캐릭터를 선택하지 않고 진입하는 경로가 없으므로 오류를 반환합니다. </br>
+/// `debug`에는 보통 `cfg!(debug_assertions)`를 전달합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the selected character. </br>
+/// If there is no selected character because the game scene was entered directly with a development launch argument,
+/// the default character ([`Actor::default`]) is used and logged in debug builds (`debug`). </br>
+/// In that case, an error is returned if the assets of the default character are not in the asset list. </br>
+/// Since there is no path that enters without selecting a character in release builds, an error is returned. </br>
+/// `cfg!(debug_assertions)` is usually passed as `debug`. </br>
+/// 
+pub fn resolve_actor(selected: Option<Actor>, asset_bundle: &AssetBundle, debug: bool) -> AppResult<Actor> {
+    if let Some(actor) = selected {
+        return Ok(actor);
+    }
+
+    if debug {
+        let actor = Actor::default();
+        if let Some(rel_path) = ActorAssets::new(actor).paths().find(|it| !asset_bundle.contains(it)) {
+            return Err(game_err!(
+                "Game Logic Error", 
+                "The asset {} of the default character {:?} could not be found.", rel_path, actor
+            ));
+        }
+        log::warn!("No character was selected. The default character {:?} is used.", actor);
+        return Ok(actor);
+    }
+
+    return Err(game_err!(
+        "Game Logic Error", 
+        "The in-game scene was entered without a selected character."
+    ));
+}
+
+/// #### 한국어 </br>
+/// 캐릭터마다 사용하는 에셋의 경로들 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The paths of the assets used by each character. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActorAssets {
+    pub stage_image: &'static str,
+    pub player_texture: &'static str,
+    pub stage_start_sound: &'static str,
+    pub smile_sounds: [&'static str; 2],
+    pub damage_sounds: [&'static str; 4],
+}
+
+impl ActorAssets {
+    pub fn new(actor: Actor) -> Self {
+        match actor {
+            Actor::Aris => Self {
+                stage_image: path::ARIS_IMG_TEXTURE_PATH, 
+                player_texture: path::ARIS_PLAYER_TEXTURE_PATH, 
+                stage_start_sound: path::ARIS_STAGE_START_SOUND_PATH, 
+                smile_sounds: [
+                    path::ARIS_SMILE_0_SOUND_PATH, 
+                    path::ARIS_SMILE_1_SOUND_PATH, 
+                ], 
+                damage_sounds: [
+                    path::YUUKA_ATTACK0_SOUND_PATH, 
+                    path::ARIS_DAMAGE_0_SOUND_PATH, 
+                    path::ARIS_DAMAGE_1_SOUND_PATH, 
+                    path::ARIS_DAMAGE_2_SOUND_PATH, 
+                ], 
+            },
+            Actor::Momoi => Self {
+                stage_image: path::MOMOI_IMG_TEXTURE_PATH, 
+                player_texture: path::MOMOI_PLAYER_TEXTURE_PATH, 
+                stage_start_sound: path::MOMOI_STAGE_START_SOUND_PATH, 
+                smile_sounds: [
+                    path::MOMOI_SMILE_0_SOUND_PATH, 
+                    path::MOMOI_SMILE_1_SOUND_PATH, 
+                ], 
+                damage_sounds: [
+                    path::YUUKA_ATTACK0_SOUND_PATH, 
+                    path::MOMOI_DAMAGE_0_SOUND_PATH, 
+                    path::MOMOI_DAMAGE_1_SOUND_PATH, 
+                    path::MOMOI_DAMAGE_2_SOUND_PATH, 
+                ], 
+            },
+            Actor::Midori => Self {
+                stage_image: path::MIDORI_IMG_TEXTURE_PATH, 
+                player_texture: path::MIDORI_PLAYER_TEXTURE_PATH, 
+                stage_start_sound: path::MIDORI_STAGE_START_SOUND_PATH, 
+                smile_sounds: [
+                    path::MIDORI_SMILE_0_SOUND_PATH, 
+                    path::MIDORI_SMILE_1_SOUND_PATH, 
+                ], 
+                damage_sounds: [
+                    path::YUUKA_ATTACK0_SOUND_PATH, 
+                    path::MIDORI_DAMAGE_0_SOUND_PATH, 
+                    path::MIDORI_DAMAGE_1_SOUND_PATH, 
+                    path::MIDORI_DAMAGE_2_SOUND_PATH, 
+                ], 
+            },
+            Actor::Yuzu => Self {
+                stage_image: path::YUZU_IMG_TEXTURE_PATH, 
+                player_texture: path::YUZU_PLAYER_TEXTURE_PATH, 
+                stage_start_sound: path::YUZU_STAGE_START_SOUND_PATH, 
+                smile_sounds: [
+                    path::YUZU_SMILE_0_SOUND_PATH, 
+                    path::YUZU_SMILE_1_SOUND_PATH, 
+                ], 
+                damage_sounds: [
+                    path::YUUKA_ATTACK0_SOUND_PATH, 
+                    path::YUZU_DAMAGE_0_SOUND_PATH, 
+                    path::YUZU_DAMAGE_1_SOUND_PATH, 
+                    path::YUZU_DAMAGE_2_SOUND_PATH, 
+                ], 
+            },
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 모든 에셋의 경로를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the paths of all assets. </br>
+    /// 
+    pub fn paths(&self) -> impl Iterator<Item = &'static str> {
+        [self.stage_image, self.player_texture, self.stage_start_sound].into_iter()
+            .chain(self.smile_sounds)
+            .chain(self.damage_sounds)
+    }
+}
+
+/// #### 한국어 </br>
+/// 영역을 획득했을 때 퍼센트 텍스트가 커지는 최대 배율과 바뀌는 색상입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum scale to which the percent text grows and the color it changes to when an area is captured. </br>
+/// 
+const PERCENT_PULSE_SCALE: f32 = 1.25;
+const PERCENT_PULSE_COLOR: Vec4 = Vec4::new(1.0, 215.0 / 255.0, 0.0, 1.0);
+const PERCENT_COLOR: Vec4 = Vec4::new(1.0, 1.0, 1.0, 1.0);
+
+/// #### 한국어 </br>
+/// 차지한 타일의 수가 늘어난 경우 퍼센트 텍스트의 강조 효과를 처음부터 다시 시작합니다. </br>
+/// `percent_timer`는 강조 효과의 남은 시간입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Restarts the highlight effect of the percent text if the number of owned tiles has increased. </br>
+/// `percent_timer` is the remaining time of the highlight effect. </br>
+/// 
+#[inline]
+pub fn restart_percent_pulse(percent_timer: &mut f64, prev_owned_tiles: u32, num_owned_tiles: u32) {
+    if num_owned_tiles > prev_owned_tiles {
+        *percent_timer = in_game::PERCENT_DURATION;
+    }
+}
+
+/// #### 한국어 </br>
+/// 강조 효과의 남은 시간에 따른 퍼센트 텍스트의 (배율, 색상)을 반환합니다. </br>
+/// 남은 시간이 없거나 움직임 줄이기 설정이 켜진 경우 원래의 배율과 색상을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the (scale, color) of the percent text according to the remaining time of the highlight effect. </br>
+/// Returns the original scale and color if there is no remaining time or the reduce motion setting is on. </br>
+/// 
+#[inline]
+pub fn percent_pulse(percent_timer: f64, reduce_motion: bool) -> (f32, Vec4) {
+    if reduce_motion {
+        return (1.0, PERCENT_COLOR);
+    }
+    let t = interpolation::f64::smooth_step(percent_timer.max(0.0), in_game::PERCENT_DURATION) as f32;
+    let scale = 1.0 + (PERCENT_PULSE_SCALE - 1.0) * t;
+    return (scale, PERCENT_COLOR.lerp(PERCENT_PULSE_COLOR, t));
+}
+
+/// #### 한국어 </br>
+/// 일시정지 사용자 인터페이스 윈도우를 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a puse user interface window. </br>
+/// 
+fn create_pause_text(
+    font: &FontArc, 
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    text_brush: &TextBrush, 
+    script: &Script
+) -> AppResult<Text> {
+    let text = script.get(ScriptTags::InGamePauseTitle)?;
+    return Ok(TextBuilder::new(
+        Some("PuaseWindow"), 
+        font, 
+        text, 
+        text_brush
+    ).with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+    .with_margin(Margin::new(240, -320, 112, 320))
+    .with_color((1.0, 1.0, 1.0, 0.0).into())
+    .with_translation((0.0, 0.0, 0.5).into())
+    .build(device, queue));
+}
+
+/// #### 한국어 </br>
+/// 일시정지 화면에서 사용되는 버튼들을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create buttons used on the pause screen. </br>
+/// 
+fn create_pause_buttons(
+    font: &FontArc, 
+    script: &Script,
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    tex_sampler: &wgpu::Sampler, 
+    texture_view: &wgpu::TextureView, 
+    ui_brush: &UiBrush, 
+    text_brush: &TextBrush, 
+) -> AppResult<HashMap<PauseButton, (UiObject, Text)>> {
+    let resume_btn = UiObjectBuilder::new(
+        Some("ResumeButton"), 
+        tex_sampler, 
+        texture_view, 
+        ui_brush
+    )
+    .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+    .with_margin(Margin::new(100, -160, 40, 160))
+    .with_color((1.0, 1.0, 1.0, 0.0).into())
+    .build(device);
+    let text = script.get(ScriptTags::InGameResumeButton)?;
+    let resume_text = TextBuilder::new(
+        Some("ResumeText"), 
         font, 
         text, 
         text_brush
@@ -1414,7 +2088,7 @@ fn create_pause_buttons(
         ui_brush
     )
     .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
-    .with_margin(Margin::new(10, -160, -50, 160))
+    .with_margin(Margin::new(20, -160, -40, 160))
     .with_color((1.0, 1.0, 1.0, 0.0).into())
     .build(device);
     let text = script.get(ScriptTags::InGameSettingButton)?;
@@ -1425,7 +2099,29 @@ fn create_pause_buttons(
         text_brush
     )
     .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
-    .with_margin(Margin::new(10, -160, -50, 160))
+    .with_margin(Margin::new(20, -160, -40, 160))
+    .with_color((0.0, 0.0, 0.0, 0.0).into())
+    .build(device, queue);
+
+    let restart_btn = UiObjectBuilder::new(
+        Some("RestartButton"), 
+        tex_sampler, 
+        texture_view, 
+        ui_brush
+    )
+    .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+    .with_margin(Margin::new(-60, -160, -120, 160))
+    .with_color((1.0, 1.0, 1.0, 0.0).into())
+    .build(device);
+    let text = script.get(ScriptTags::InGameRestartButton)?;
+    let restart_text = TextBuilder::new(
+        Some("RestartText"), 
+        font, 
+        text, 
+        text_brush
+    )
+    .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+    .with_margin(Margin::new(-60, -160, -120, 160))
     .with_color((0.0, 0.0, 0.0, 0.0).into())
     .build(device, queue);
 
@@ -1436,7 +2132,7 @@ fn create_pause_buttons(
         ui_brush
     )
     .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
-    .with_margin(Margin::new(-80, -160, -140, 160))
+    .with_margin(Margin::new(-140, -160, -200, 160))
     .with_color((255.0 / 255.0, 103.0 / 255.0, 105.0 / 255.0, 0.0).into())
     .build(device);
     let text = script.get(ScriptTags::InGameGiveUpButton)?;
@@ -1447,13 +2143,14 @@ fn create_pause_buttons(
         text_brush
     )
     .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
-    .with_margin(Margin::new(-80, -160, -140, 160))
+    .with_margin(Margin::new(-140, -160, -200, 160))
     .with_color((0.0, 0.0, 0.0, 0.0).into())
     .build(device, queue);
 
     return Ok(HashMap::from_iter([
         (PauseButton::Resume, (resume_btn, resume_text)), 
         (PauseButton::Setting, (setting_btn, setting_text)), 
+        (PauseButton::Restart, (restart_btn, restart_text)), 
         (PauseButton::GiveUp, (exit_button, exit_text)), 
     ]));
 }
@@ -1686,6 +2383,62 @@ fn create_result_condition_texts(
     return Ok(texts);
 }
 
+/// #### 한국어 </br>
+/// 결과 화면에 표시되는 클리어 시간 텍스트를 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the clear time text displayed on the result screen. </br>
+/// 
+fn create_result_clear_time(
+    font: &FontArc, 
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    text_brush: &TextBrush
+) -> Text {
+    TextBuilder::new(
+        Some("ClearTime"), 
+        font, 
+        NO_CLEAR_TIME, 
+        text_brush
+    )
+    .with_anchor(Anchor::new(0.4, 0.72, 0.325, 0.98))
+    .with_color((162.0 / 255.0, 162.0 / 255.0, 160.0 / 255.0, 0.0).into())
+    .build(device, queue)
+}
+
+/// #### 한국어 </br>
+/// 남은 시간을 `m:ss` 형식의 문자열로 변환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Converts the remaining time to a string in `m:ss` format. </br>
+/// 
+#[inline]
+pub fn format_remaining_time(sec: f64) -> String {
+    let min = (sec / 60.0) as u32;
+    let sec = (sec % 60.0) as u32;
+    return format!("{}:{:0>2}", min, sec);
+}
+
+/// #### 한국어 </br>
+/// 클리어 시간을 `mm:ss.mmm` 형식의 문자열로 변환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Converts the clear time to a string in `mm:ss.mmm` format. </br>
+/// 
+#[inline]
+pub fn format_clear_time(sec: f64) -> String {
+    let millis = (sec.max(0.0) * 1000.0).round() as u64;
+    return format!("{:0>2}:{:0>2}.{:0>3}", millis / 60_000, millis / 1000 % 60, millis % 1000);
+}
+
+/// #### 한국어 </br>
+/// 스테이지를 클리어하지 못했을 때 표시되는 클리어 시간입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The clear time displayed when the stage was not cleared. </br>
+/// 
+pub const NO_CLEAR_TIME: &str = "--:--.---";
+
 /// #### 한국어 </br>
 /// 종료 창을 생성합니다. </br>
 /// 
@@ -2172,43 +2925,243 @@ pub(super) fn create_setting_resolutions(
 }
 
 /// #### 한국어 </br>
-/// 돌아가기 버튼을 생성합니다. </br>
+/// 켜져 있는 설정 버튼의 색상입니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Creates a return button. </br>
+/// The color of a setting button that is turned on. </br>
+/// 
+pub const TOGGLE_ON_COLOR: Vec4 = Vec4::new(187.0 / 255.0, 239.0 / 255.0, 249.0 / 255.0, 1.0);
+
+/// #### 한국어 </br>
+/// 꺼져 있는 설정 버튼의 색상입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The color of a setting button that is turned off. </br>
+/// 
+pub const TOGGLE_OFF_COLOR: Vec4 = Vec4::new(1.0, 1.0, 1.0, 1.0);
+
+/// #### 한국어 </br>
+/// 설정이 켜져 있는지에 따라 설정 버튼의 색상을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the color of a setting button depending on whether the setting is turned on. </br>
 /// 
 #[inline]
-pub(super) fn create_setting_return_button(
+pub fn toggle_color(on: bool) -> Vec4 {
+    match on {
+        true => TOGGLE_ON_COLOR, 
+        false => TOGGLE_OFF_COLOR, 
+    }
+}
+
+/// #### 한국어 </br>
+/// 설정 버튼에 표시되는 스크립트의 태그를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the tag of the script displayed on a setting button. </br>
+/// 
+#[inline]
+pub fn toggle_script_tag(toggle: SettingToggle) -> ScriptTags {
+    match toggle {
+        SettingToggle::HideCursorInGame => ScriptTags::SettingHideCursorToggle, 
+        SettingToggle::CustomCursor => ScriptTags::SettingCustomCursorToggle, 
+        SettingToggle::ReduceMotion => ScriptTags::SettingReduceMotionToggle, 
+        SettingToggle::SmallBoard => ScriptTags::SettingSmallBoardToggle, 
+    }
+}
+
+/// #### 한국어 </br>
+/// 설정 창의 켜고 끌 수 있는 설정 버튼들을 생성합니다. </br>
+/// 버튼들은 언어 선택 버튼과 같은 줄의 오른쪽 끝에 놓입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates setting buttons that can be turned on and off in the setting window. </br>
+/// The buttons are placed at the right end of the same row as the language selection buttons. </br>
+/// 
+pub(super) fn create_setting_toggles(
     font: &FontArc, 
-    script: &Script,
+    script: &Script, 
+    settings: &Settings, 
     device: &wgpu::Device, 
     queue: &wgpu::Queue, 
     tex_sampler: &wgpu::Sampler, 
     texture_view: &wgpu::TextureView, 
     ui_brush: &UiBrush, 
     text_brush: &TextBrush
-) -> AppResult<(UiObject, Text)> {
-    return Ok((
-        UiObjectBuilder::new(
-            Some("SettingReturnButton"), 
-            tex_sampler, 
-            texture_view, 
-            ui_brush
-        )
-        .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
-        .with_margin(Margin::new(-220, 224, -268, 368))
-        .with_color(Vec4::new(1.0, 1.0, 1.0, 1.0))
-        .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
-        .with_global_translation(Vec3::new(0.0, 0.0, 0.5))
-        .build(device), 
-        TextBuilder::new(
-            Some("SettingReturnButtonText"), 
-            font, 
-            script.get(ScriptTags::SettingReturnButton)?, 
-            text_brush
-        )
-        .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
-        .with_margin(Margin::new(-220, 224, -268, 368))
+) -> AppResult<HashMap<SettingToggle, (UiObject, Text)>> {
+    const TOP: i32 = 164;
+    const LEFT: i32 = -112;
+    const HEIGHT: i32 = 36;
+    const WIDTH: i32 = HEIGHT * 3;
+    const GAP: i32 = 8;
+
+    let mut left = LEFT;
+    let mut toggles = HashMap::new();
+    for toggle in SettingToggle::ALL {
+        toggles.insert(
+            toggle, 
+            (
+                UiObjectBuilder::new(
+                    Some(&format!("{:?}_Toggle", toggle)), 
+                    tex_sampler, 
+                    texture_view, 
+                    ui_brush
+                )
+                .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+                .with_margin(Margin::new(TOP, left, TOP - HEIGHT, left + WIDTH))
+                .with_color(toggle_color(toggle.is_on(settings)))
+                .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
+                .with_global_translation(Vec3::new(0.0, 0.0, 0.5))
+                .build(device), 
+                TextBuilder::new(
+                    Some(&format!("{:?}_ToggleText", toggle)), 
+                    font, 
+                    script.get(toggle_script_tag(toggle))?, 
+                    text_brush
+                )
+                .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+                .with_margin(Margin::new(TOP, left, TOP - HEIGHT, left + WIDTH))
+                .with_color(Vec4::new(0.0, 0.0, 0.0, 1.0))
+                .with_scale(Vec3::new(0.0, 0.0, 0.0))
+                .with_translation(Vec3::new(0.0, 0.0, 0.4))
+                .build(device, queue)
+            )
+        );
+
+        left += GAP + WIDTH;
+    }
+
+    return Ok(toggles);
+}
+
+/// #### 한국어 </br>
+/// 변경된 설정을 적용하는 버튼을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a button that applies the changed settings. </br>
+/// 
+#[inline]
+pub(super) fn create_setting_apply_button(
+    font: &FontArc, 
+    script: &Script,
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    tex_sampler: &wgpu::Sampler, 
+    texture_view: &wgpu::TextureView, 
+    ui_brush: &UiBrush, 
+    text_brush: &TextBrush
+) -> AppResult<(UiObject, Text)> {
+    return Ok((
+        UiObjectBuilder::new(
+            Some("SettingApplyButton"), 
+            tex_sampler, 
+            texture_view, 
+            ui_brush
+        )
+        .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+        .with_margin(Margin::new(-220, 64, -268, 208))
+        .with_color(Vec4::new(1.0, 1.0, 1.0, 1.0))
+        .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
+        .with_global_translation(Vec3::new(0.0, 0.0, 0.5))
+        .build(device), 
+        TextBuilder::new(
+            Some("SettingApplyButtonText"), 
+            font, 
+            script.get(ScriptTags::SettingApplyButton)?, 
+            text_brush
+        )
+        .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+        .with_margin(Margin::new(-220, 64, -268, 208))
+        .with_color(Vec4::new(0.0, 0.0, 0.0, 1.0))
+        .with_scale(Vec3::new(0.0, 0.0, 0.0))
+        .with_translation(Vec3::new(0.0, 0.0, 0.4))
+        .build(device, queue)
+    ))
+}
+
+/// #### 한국어 </br>
+/// 모든 설정을 기본값으로 되돌리는 버튼을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a button that resets all settings to their default values. </br>
+/// 
+#[inline]
+pub(super) fn create_setting_reset_button(
+    font: &FontArc, 
+    script: &Script,
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    tex_sampler: &wgpu::Sampler, 
+    texture_view: &wgpu::TextureView, 
+    ui_brush: &UiBrush, 
+    text_brush: &TextBrush
+) -> AppResult<(UiObject, Text)> {
+    return Ok((
+        UiObjectBuilder::new(
+            Some("SettingResetButton"), 
+            tex_sampler, 
+            texture_view, 
+            ui_brush
+        )
+        .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+        .with_margin(Margin::new(-220, -368, -268, -224))
+        .with_color(Vec4::new(1.0, 1.0, 1.0, 1.0))
+        .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
+        .with_global_translation(Vec3::new(0.0, 0.0, 0.5))
+        .build(device), 
+        TextBuilder::new(
+            Some("SettingResetButtonText"), 
+            font, 
+            script.get(ScriptTags::SettingResetButton)?, 
+            text_brush
+        )
+        .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+        .with_margin(Margin::new(-220, -368, -268, -224))
+        .with_color(Vec4::new(0.0, 0.0, 0.0, 1.0))
+        .with_scale(Vec3::new(0.0, 0.0, 0.0))
+        .with_translation(Vec3::new(0.0, 0.0, 0.4))
+        .build(device, queue)
+    ))
+}
+
+/// #### 한국어 </br>
+/// 돌아가기 버튼을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a return button. </br>
+/// 
+#[inline]
+pub(super) fn create_setting_return_button(
+    font: &FontArc, 
+    script: &Script,
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    tex_sampler: &wgpu::Sampler, 
+    texture_view: &wgpu::TextureView, 
+    ui_brush: &UiBrush, 
+    text_brush: &TextBrush
+) -> AppResult<(UiObject, Text)> {
+    return Ok((
+        UiObjectBuilder::new(
+            Some("SettingReturnButton"), 
+            tex_sampler, 
+            texture_view, 
+            ui_brush
+        )
+        .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+        .with_margin(Margin::new(-220, 224, -268, 368))
+        .with_color(Vec4::new(1.0, 1.0, 1.0, 1.0))
+        .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
+        .with_global_translation(Vec3::new(0.0, 0.0, 0.5))
+        .build(device), 
+        TextBuilder::new(
+            Some("SettingReturnButtonText"), 
+            font, 
+            script.get(ScriptTags::SettingReturnButton)?, 
+            text_brush
+        )
+        .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+        .with_margin(Margin::new(-220, 224, -268, 368))
         .with_color(Vec4::new(0.0, 0.0, 0.0, 1.0))
         .with_scale(Vec3::new(0.0, 0.0, 0.0))
         .with_translation(Vec3::new(0.0, 0.0, 0.4))
@@ -2335,63 +3288,458 @@ pub(super) fn create_setting_volume_bar(
     tex_sampler: &wgpu::Sampler, 
     texture_view: &wgpu::TextureView, 
     ui_brush: &UiBrush
-) -> HashMap<VolumeOptions, UiObject> {
-    const RANGE: i32 = SETTING_VOLUME_RANGE_MAX - SETTING_VOLUME_RANGE_MIN;
+) -> HashMap<VolumeOptions, Slider> {
     let mut bar = HashMap::new();
 
-    let delta = RANGE as f32 * settings.background_volume.norm().min(1.0);
-    let pos = SETTING_VOLUME_RANGE_MIN + delta as i32;
+    let value = settings.background_volume.norm();
+    let pos = Slider::position(SETTING_VOLUME_RANGE_MIN, SETTING_VOLUME_RANGE_MAX, value);
     bar.insert(
         VolumeOptions::Background, 
-        UiObjectBuilder::new(
-            Some("BackgroundVolumeBar"), 
-            tex_sampler, 
-            texture_view, 
-            ui_brush
+        Slider::new(
+            UiObjectBuilder::new(
+                Some("BackgroundVolumeBar"), 
+                tex_sampler, 
+                texture_view, 
+                ui_brush
+            )
+            .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+            .with_margin(Margin::new(-90, pos - VOLUME_BAR_WIDTH / 2, -110, pos + VOLUME_BAR_WIDTH / 2))
+            .with_color(Vec4::new(234.0 / 255.0, 250.0 / 255.0, 253.0 / 255.0, 1.0))
+            .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
+            .with_global_translation(Vec3::new(0.0, 0.0, 0.3))
+            .build(device), 
+            SETTING_VOLUME_RANGE_MIN, 
+            SETTING_VOLUME_RANGE_MAX, 
+            VOLUME_BAR_WIDTH, 
+            value
         )
-        .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
-        .with_margin(Margin::new(-90, pos - VOLUME_BAR_WIDTH / 2, -110, pos + VOLUME_BAR_WIDTH / 2))
-        .with_color(Vec4::new(234.0 / 255.0, 250.0 / 255.0, 253.0 / 255.0, 1.0))
-        .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
-        .with_global_translation(Vec3::new(0.0, 0.0, 0.3))
-        .build(device)
     );
 
-    let delta = RANGE as f32 * settings.effect_volume.norm().min(1.0);
-    let pos = SETTING_VOLUME_RANGE_MIN + delta as i32;
+    let value = settings.effect_volume.norm();
+    let pos = Slider::position(SETTING_VOLUME_RANGE_MIN, SETTING_VOLUME_RANGE_MAX, value);
     bar.insert(
         VolumeOptions::Effect, 
-        UiObjectBuilder::new(
-            Some("EffectVolumeBar"), 
-            tex_sampler, 
-            texture_view, 
-            ui_brush
+        Slider::new(
+            UiObjectBuilder::new(
+                Some("EffectVolumeBar"), 
+                tex_sampler, 
+                texture_view, 
+                ui_brush
+            )
+            .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+            .with_margin(Margin::new(-122, pos - VOLUME_BAR_WIDTH / 2, -142, pos + VOLUME_BAR_WIDTH / 2))
+            .with_color(Vec4::new(234.0 / 255.0, 250.0 / 255.0, 253.0 / 255.0, 1.0))
+            .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
+            .with_global_translation(Vec3::new(0.0, 0.0, 0.3))
+            .build(device), 
+            SETTING_VOLUME_RANGE_MIN, 
+            SETTING_VOLUME_RANGE_MAX, 
+            VOLUME_BAR_WIDTH, 
+            value
         )
-        .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
-        .with_margin(Margin::new(-122, pos - VOLUME_BAR_WIDTH / 2, -142, pos + VOLUME_BAR_WIDTH / 2))
-        .with_color(Vec4::new(234.0 / 255.0, 250.0 / 255.0, 253.0 / 255.0, 1.0))
-        .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
-        .with_global_translation(Vec3::new(0.0, 0.0, 0.3))
-        .build(device)
     );
 
-    let delta = RANGE as f32 * settings.voice_volume.norm().min(1.0);
-    let pos = SETTING_VOLUME_RANGE_MIN + delta as i32;
+    let value = settings.voice_volume.norm();
+    let pos = Slider::position(SETTING_VOLUME_RANGE_MIN, SETTING_VOLUME_RANGE_MAX, value);
     bar.insert(
         VolumeOptions::Voice, 
+        Slider::new(
+            UiObjectBuilder::new(
+                Some("VoiceVolumeBar"), 
+                tex_sampler, 
+                texture_view, 
+                ui_brush
+            )
+            .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+            .with_margin(Margin::new(-154, pos - VOLUME_BAR_WIDTH / 2, -174, pos + VOLUME_BAR_WIDTH / 2))
+            .with_color(Vec4::new(234.0 / 255.0, 250.0 / 255.0, 253.0 / 255.0, 1.0))
+            .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
+            .with_global_translation(Vec3::new(0.0, 0.0, 0.3))
+            .build(device), 
+            SETTING_VOLUME_RANGE_MIN, 
+            SETTING_VOLUME_RANGE_MAX, 
+            VOLUME_BAR_WIDTH, 
+            value
+        )
+    );
+
+    return bar;
+}
+
+/// #### 한국어 </br>
+/// 설정 창의 해상도 되돌리기 창을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a resolution revert window in the setting window. </br>
+/// 
+pub(super) fn create_setting_revert_window(
+    font: &FontArc, 
+    script: &Script, 
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    tex_sampler: &wgpu::Sampler, 
+    window_texture_view: &wgpu::TextureView, 
+    btn_texture_view: &wgpu::TextureView, 
+    ui_brush: &UiBrush, 
+    text_brush: &TextBrush
+) -> AppResult<((UiObject, Text), Text, HashMap<RevertWndButton, (UiObject, Text)>)> {
+    const WND_WIDTH: i32 = 400;
+    const WND_HEIGHT: i32 = WND_WIDTH / 4 * 3;
+
+    const BTN_WIDTH: i32 = 150;
+    const BTN_HEIGHT: i32 = BTN_WIDTH / 3;
+    const BTN_TRANSLATION: Vec3 = Vec3::new(0.0, 0.0, 0.5);
+
+    const KEEP_BTN_COLOR: Vec4 = Vec4::new(1.0, 103.0 / 255.0, 105.0 / 255.0, 1.0);
+    const REVERT_BTN_COLOR: Vec4 = Vec4::new(1.0, 1.0, 1.0, 1.0);
+
+    const TEXT_TRANSLATION: Vec3 = Vec3::new(0.0, 0.0, 0.25);
+    const TEXT_COLOR: Vec4 = Vec4::new(0.0, 0.0, 0.0, 1.0);
+
+    let anchor = Anchor::new(0.5, 0.5, 0.5, 0.5);
+    let window = (
         UiObjectBuilder::new(
-            Some("VoiceVolumeBar"), 
+            Some("RevertWindow"), 
             tex_sampler, 
-            texture_view, 
+            window_texture_view, 
             ui_brush
         )
-        .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
-        .with_margin(Margin::new(-154, pos - VOLUME_BAR_WIDTH / 2, -174, pos + VOLUME_BAR_WIDTH / 2))
-        .with_color(Vec4::new(234.0 / 255.0, 250.0 / 255.0, 253.0 / 255.0, 1.0))
-        .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
-        .with_global_translation(Vec3::new(0.0, 0.0, 0.3))
-        .build(device)
+        .with_anchor(anchor)
+        .with_margin(Margin::new(WND_HEIGHT / 2, -WND_WIDTH / 2, -WND_HEIGHT / 2, WND_WIDTH / 2))
+        .with_color((1.0, 1.0, 1.0, 1.0).into())
+        .with_global_translation((0.0, 0.0, 0.75).into())
+        .build(device), 
+        TextBuilder::new(
+            Some("RevertWindowText"), 
+            font, 
+            script.get(ScriptTags::SettingResolutionRevertMessage)?, 
+            text_brush
+        )
+        .with_anchor(anchor)
+        .with_margin(Margin::new(WND_HEIGHT / 5 + 30, -WND_WIDTH / 2, 30, WND_WIDTH / 2))
+        .with_color(TEXT_COLOR)
+        .with_translation((0.0, 0.0, 0.5).into())
+        .build(device, queue)
     );
 
-    return bar;
+    let countdown = TextBuilder::new(
+        Some("RevertCountdownText"), 
+        font, 
+        &format!("{}", RESOLUTION_REVERT_SEC as u32), 
+        text_brush
+    )
+    .with_anchor(anchor)
+    .with_margin(Margin::new(20, -WND_WIDTH / 2, -20, WND_WIDTH / 2))
+    .with_color(TEXT_COLOR)
+    .with_translation((0.0, 0.0, 0.5).into())
+    .build(device, queue);
+
+    let mut buttons = HashMap::new();
+    const BUTTONS: [(RevertWndButton, ScriptTags, Vec4, i32); 2] = [
+        (RevertWndButton::Keep, ScriptTags::SettingResolutionKeepButton, KEEP_BTN_COLOR, -WND_WIDTH / 5), 
+        (RevertWndButton::Revert, ScriptTags::SettingResolutionRevertButton, REVERT_BTN_COLOR, WND_WIDTH / 5), 
+    ];
+    for (key, tag, color, offset) in BUTTONS {
+        let margin = Margin::new(
+            BTN_HEIGHT / 2 - WND_HEIGHT * 3 / 10,
+            -BTN_WIDTH / 2 + offset,
+            -BTN_HEIGHT / 2 - WND_HEIGHT * 3 / 10,
+            BTN_WIDTH / 2 + offset
+        );
+        buttons.insert(
+            key, 
+            (
+                UiObjectBuilder::new(
+                    Some(&format!("Revert{:?}Button", key)), 
+                    tex_sampler, 
+                    btn_texture_view, 
+                    ui_brush
+                )
+                .with_anchor(anchor)
+                .with_margin(margin)
+                .with_color(color)
+                .with_global_translation(BTN_TRANSLATION)
+                .build(device), 
+                TextBuilder::new(
+                    Some(&format!("Revert{:?}ButtonText", key)), 
+                    font, 
+                    script.get(tag)?, 
+                    text_brush
+                )
+                .with_anchor(anchor)
+                .with_margin(margin)
+                .with_color(TEXT_COLOR)
+                .with_translation(TEXT_TRANSLATION)
+                .build(device, queue)
+            )
+        );
+    }
+
+    return Ok((window, countdown, buttons));
+}
+
+
+/// #### 한국어 </br>
+/// 설정 창의 설정 초기화 확인 창을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a settings reset confirmation window in the setting window. </br>
+/// 
+pub(super) fn create_setting_reset_window(
+    font: &FontArc, 
+    script: &Script, 
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    tex_sampler: &wgpu::Sampler, 
+    backdrop_texture_view: &wgpu::TextureView, 
+    window_texture_view: &wgpu::TextureView, 
+    btn_texture_view: &wgpu::TextureView, 
+    ui_brush: &UiBrush, 
+    text_brush: &TextBrush
+) -> AppResult<ModalWindow<ResetWndButton>> {
+    const WND_WIDTH: i32 = 400;
+    const WND_HEIGHT: i32 = WND_WIDTH / 4 * 3;
+
+    const BTN_WIDTH: i32 = 150;
+    const BTN_HEIGHT: i32 = BTN_WIDTH / 3;
+    const BTN_TRANSLATION: Vec3 = Vec3::new(0.0, 0.0, 0.5);
+
+    const YES_BTN_COLOR: Vec4 = Vec4::new(1.0, 103.0 / 255.0, 105.0 / 255.0, 1.0);
+    const NO_BTN_COLOR: Vec4 = Vec4::new(1.0, 1.0, 1.0, 1.0);
+
+    const TEXT_TRANSLATION: Vec3 = Vec3::new(0.0, 0.0, 0.25);
+    const TEXT_COLOR: Vec4 = Vec4::new(0.0, 0.0, 0.0, 1.0);
+
+    let backdrop = UiObjectBuilder::new(
+        Some("ResetBackdrop"), 
+        tex_sampler, 
+        backdrop_texture_view, 
+        ui_brush
+    )
+    .with_anchor(Anchor::new(1.0, 0.0, 0.0, 1.0))
+    .with_color((0.0, 0.0, 0.0, 0.0).into())
+    .with_global_translation((0.0, 0.0, 0.9).into())
+    .build(device);
+
+    let anchor = Anchor::new(0.5, 0.5, 0.5, 0.5);
+    let window = UiObjectBuilder::new(
+        Some("ResetWindow"), 
+        tex_sampler, 
+        window_texture_view, 
+        ui_brush
+    )
+    .with_anchor(anchor)
+    .with_margin(Margin::new(WND_HEIGHT / 2, -WND_WIDTH / 2, -WND_HEIGHT / 2, WND_WIDTH / 2))
+    .with_color((1.0, 1.0, 1.0, 1.0).into())
+    .with_global_scale((0.0, 0.0, 0.0).into())
+    .with_global_translation((0.0, 0.0, 0.75).into())
+    .build(device);
+
+    let message = TextBuilder::new(
+        Some("ResetWindowText"), 
+        font, 
+        script.get(ScriptTags::SettingResetReconfirmMessage)?, 
+        text_brush
+    )
+    .with_anchor(anchor)
+    .with_margin(Margin::new(WND_HEIGHT / 5, -WND_WIDTH / 2, 0, WND_WIDTH / 2))
+    .with_scale((0.0, 0.0, 0.0).into())
+    .with_color(TEXT_COLOR)
+    .with_translation((0.0, 0.0, 0.5).into())
+    .build(device, queue);
+
+    let mut buttons = HashMap::new();
+    const BUTTONS: [(ResetWndButton, ScriptTags, Vec4, i32); 2] = [
+        (ResetWndButton::Yes, ScriptTags::SettingResetOkayButton, YES_BTN_COLOR, -WND_WIDTH / 5), 
+        (ResetWndButton::No, ScriptTags::SettingResetCancelButton, NO_BTN_COLOR, WND_WIDTH / 5), 
+    ];
+    for (key, tag, color, offset) in BUTTONS {
+        let margin = Margin::new(
+            BTN_HEIGHT / 2 - WND_HEIGHT * 3 / 10,
+            -BTN_WIDTH / 2 + offset,
+            -BTN_HEIGHT / 2 - WND_HEIGHT * 3 / 10,
+            BTN_WIDTH / 2 + offset
+        );
+        buttons.insert(
+            key, 
+            (
+                UiObjectBuilder::new(
+                    Some(&format!("Reset{:?}Button", key)), 
+                    tex_sampler, 
+                    btn_texture_view, 
+                    ui_brush
+                )
+                .with_anchor(anchor)
+                .with_margin(margin)
+                .with_color(color)
+                .with_global_scale((0.0, 0.0, 0.0).into())
+                .with_global_translation(BTN_TRANSLATION)
+                .build(device), 
+                TextBuilder::new(
+                    Some(&format!("Reset{:?}ButtonText", key)), 
+                    font, 
+                    script.get(tag)?, 
+                    text_brush
+                )
+                .with_anchor(anchor)
+                .with_margin(margin)
+                .with_scale((0.0, 0.0, 0.0).into())
+                .with_color(TEXT_COLOR)
+                .with_translation(TEXT_TRANSLATION)
+                .build(device, queue)
+            )
+        );
+    }
+
+    return Ok(ModalWindow::new(window, vec![message], buttons)
+        .with_backdrop(backdrop, EXIT_BACKDROP_ALPHA)
+        .with_outside_click(OutsideClick::Close)
+        .with_focus_order(vec![ResetWndButton::No, ResetWndButton::Yes])
+    );
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn combo_grows_within_window_and_resets_after() {
+        assert_eq!(next_combo(0, 0.0), 1);
+        assert_eq!(next_combo(1, 1.0), 2);
+        assert_eq!(next_combo(2, COMBO_WINDOW_SEC), 3);
+        assert_eq!(next_combo(3, COMBO_WINDOW_SEC + 0.1), 1);
+
+        assert_eq!(combo_multiplier(1), 1.0);
+        assert_eq!(combo_multiplier(2), 1.5);
+        assert_eq!(combo_multiplier(100), MAX_COMBO_MULTIPLIER);
+        assert_eq!(format_combo(2), "x1.5");
+    }
+
+    #[test]
+    fn max_combo_raises_the_result_by_one_star() {
+        assert_eq!(result_star_index(10.0, 1), 0);
+        assert_eq!(result_star_index(49.9, 4), 1);
+        assert_eq!(result_star_index(10.0, 5), 1);
+        assert_eq!(result_star_index(60.0, 100), 3);
+
+        // (한국어) 모든 영역을 차지해야만 마지막 별을 받습니다.
+        // (English Translation) The last star is given only if the entire area is occupied.
+        assert_eq!(result_star_index(90.0, 100), 3);
+        assert_eq!(result_star_index(100.0, 0), 4);
+
+        // (한국어) 도전 과제는 연속 획득과 관계없이 차지한 영역으로만 달성됩니다.
+        // (English Translation) Challenges are achieved only by the occupied area regardless of the combo.
+        assert_eq!(percent_star_index(60.0), 2);
+    }
+
+    #[test]
+    fn smaller_board_fills_the_same_area() {
+        for board_size in [BoardSize::Standard, BoardSize::Small] {
+            let tile_size = board_tile_size(board_size);
+            assert_eq!(tile_size.x * board_size.num_cols() as f32, BOARD_WIDTH);
+            assert_eq!(tile_size.y * board_size.num_rows() as f32, BOARD_HEIGHT);
+        }
+        assert_eq!(board_tile_size(BoardSize::Standard), Vec2::splat(0.5 * PIXEL_PER_METER));
+    }
+
+    #[test]
+    fn owned_tile_increase_restarts_percent_pulse() {
+        let mut percent_timer = 0.0;
+        restart_percent_pulse(&mut percent_timer, 10, 10);
+        assert_eq!(percent_timer, 0.0);
+        assert_eq!(percent_pulse(percent_timer, false), (1.0, PERCENT_COLOR));
+
+        restart_percent_pulse(&mut percent_timer, 10, 25);
+        assert_eq!(percent_timer, in_game::PERCENT_DURATION);
+        assert_eq!(percent_pulse(percent_timer, false), (PERCENT_PULSE_SCALE, PERCENT_PULSE_COLOR));
+
+        // (한국어) 움직임 줄이기 설정이 켜진 경우 강조하지 않습니다.
+        // (English Translation) It is not highlighted if the reduce motion setting is on.
+        assert_eq!(percent_pulse(percent_timer, true), (1.0, PERCENT_COLOR));
+    }
+
+    #[test]
+    fn default_actor_assets_exist() {
+        let assets_dir = std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/assets"));
+        for rel_path in ActorAssets::new(Actor::default()).paths() {
+            assert!(assets_dir.join(rel_path).is_file(), "Asset not found: {}", rel_path);
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn missing_actor_falls_back_to_default() {
+        let paths: Vec<_> = ActorAssets::new(Actor::default()).paths().collect();
+        let asset_bundle = AssetBundle::for_test(&paths);
+        assert_eq!(resolve_actor(Some(Actor::Yuzu), &asset_bundle, true).unwrap(), Actor::Yuzu);
+        assert_eq!(resolve_actor(None, &asset_bundle, true).unwrap(), Actor::default());
+
+        // (한국어) 기본 캐릭터의 에셋이 없는 경우 오류를 반환합니다.
+        // (English Translation) Returns an error if the assets of the default character are missing.
+        let asset_bundle = AssetBundle::for_test(&paths[1..]);
+        assert!(resolve_actor(None, &asset_bundle, true).is_err());
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn missing_actor_is_an_error_without_the_debug_fallback() {
+        let paths: Vec<_> = ActorAssets::new(Actor::default()).paths().collect();
+        let asset_bundle = AssetBundle::for_test(&paths);
+        assert_eq!(resolve_actor(Some(Actor::Yuzu), &asset_bundle, false).unwrap(), Actor::Yuzu);
+        assert!(resolve_actor(None, &asset_bundle, false).is_err());
+    }
+
+    #[test]
+    fn bgm_playlist_never_repeats_immediately() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1879);
+        let candidates = vec!["a", "b", "c"];
+        let mut playlist = BgmPlaylist::new(candidates.clone(), &mut rng);
+
+        let mut last = playlist.current();
+        for _ in 0..100 {
+            let next = playlist.advance(&mut rng);
+            assert_ne!(next, last);
+            assert!(candidates.contains(&next));
+            last = next;
+        }
+
+        let mut single = BgmPlaylist::new(vec!["a"], &mut rng);
+        assert_eq!(single.advance(&mut rng), "a");
+    }
+
+    #[test]
+    fn voice_triggers_within_cooldown_play_once() {
+        let mut cooldown = VoiceCooldown::default();
+        assert_eq!(cooldown.trigger(VoiceKind::Smile, 10.0, true), VoiceAction::Play);
+        assert_eq!(cooldown.trigger(VoiceKind::Smile, 10.5, true), VoiceAction::Skip);
+        assert_eq!(cooldown.trigger(VoiceKind::Smile, 11.0, false), VoiceAction::Skip);
+        assert_eq!(cooldown.trigger(VoiceKind::Smile, 10.0 + VOICE_COOLDOWN_SEC, true), VoiceAction::Play);
+
+        // (한국어) 피격 목소리는 재생 중인 웃음 목소리를 중단하지만, 그 반대는 아닙니다.
+        // (English Translation) A damage voice interrupts a playing smile voice, but not the other way around.
+        assert_eq!(cooldown.trigger(VoiceKind::Damage, 12.1, false), VoiceAction::Interrupt);
+        assert_eq!(cooldown.trigger(VoiceKind::Smile, 12.2, false), VoiceAction::Skip);
+        assert_eq!(cooldown.trigger(VoiceKind::Damage, 12.3, false), VoiceAction::Skip);
+    }
+
+    #[test]
+    fn damage_right_after_a_smile_finishes_is_played() {
+        let mut cooldown = VoiceCooldown::default();
+        assert_eq!(cooldown.trigger(VoiceKind::Smile, 10.0, true), VoiceAction::Play);
+
+        // (한국어) 웃음 목소리가 대기 시간 안에 끝난 직후의 피격 목소리는 재생되어야 합니다.
+        // (English Translation) A damage voice right after a smile voice finishes within the cooldown must be played.
+        assert_eq!(cooldown.trigger(VoiceKind::Damage, 10.8, true), VoiceAction::Play);
+        assert_eq!(cooldown.trigger(VoiceKind::Smile, 11.0, true), VoiceAction::Skip);
+        assert_eq!(cooldown.trigger(VoiceKind::Damage, 11.2, true), VoiceAction::Play);
+    }
+
+    #[test]
+    fn clear_time_is_formatted() {
+        assert_eq!(format_clear_time(5.25), "00:05.250");
+        assert_eq!(format_clear_time(59.9994), "00:59.999");
+        assert_eq!(format_clear_time(60.0), "01:00.000");
+        assert_eq!(format_clear_time(125.5), "02:05.500");
+    }
 }
@@ -5,9 +5,10 @@ use std::thread::{self, JoinHandle};
 use std::collections::HashMap;
 
 use ab_glyph::FontArc;
+use rand::Rng;
+use winit::event::{Event, WindowEvent};
 
 use crate::{
-    game_err,
     assets::bundle::AssetBundle,
     components::{
         text::{TextBrush, Text, TextBuilder},
@@ -18,17 +19,51 @@ use crate::{
         margin::Margin,
         script::{Script, ScriptTags},
     },
-    nodes::{path, consts::PIXEL_PER_METER},
-    render::texture::DdsTextureDecoder, 
-    scene::{node::SceneNode, state::SceneState},
+    nodes::{
+        path, 
+        consts::PIXEL_PER_METER, 
+        preload::{self, RequiredAssets}, 
+        title::TitleLoading, 
+    },
+    render::{texture::DdsTextureDecoder, frame::with_frame}, 
+    scene::{node::SceneNode, phases::Phases, state::{is_scene_changing, state_fn, SceneState}},
     system::{
-        error::{AppResult, GameError},
+        error::{join_thread, AppResult},
+        event::AppEvent,
+        rng::CosmeticRng,
         shared::Shared,
     },
 };
 
 
 
+/// #### 한국어 </br>
+/// 인트로에서 재생될 수 있는 캐릭터 타이틀 음성 목록입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A list of character title voices that can be played in the intro. </br>
+/// 
+const VOICES: [&'static str; 4] = [
+    path::ARIS_TITLE_SOUND_PATH,
+    path::MOMOI_TITLE_SOUND_PATH,
+    path::MIDORI_TITLE_SOUND_PATH,
+    path::YUZU_TITLE_SOUND_PATH,
+];
+
+/// #### 한국어 </br>
+/// `Intro` 게임 장면에서 사용하는 에셋 목록입니다. </br>
+/// 재생할 캐릭터 음성은 무작위로 선택되므로 따로 불러옵니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// List of assets used in the `Intro` game scene. </br>
+/// The character voice to play is chosen at random, so it is loaded separately. </br>
+/// 
+const REQUIRED_ASSETS: [&'static str; 1] = [
+    path::LOGO_TEXTURE_PATH, 
+];
+
+
+
 /// #### 한국어 </br>
 /// `Intro` 게임 장면을 준비하는 게임 장면 입니다. </br>
 /// 
@@ -37,9 +72,18 @@ use crate::{
 /// 
 #[derive(Debug)]
 pub struct IntroLoading {
+    voice: Option<usize>, 
+    attract: bool, 
     loading: Option<JoinHandle<AppResult<IntroScene>>>,
 }
 
+impl RequiredAssets for IntroLoading {
+    #[inline]
+    fn required_assets() -> &'static [&'static str] {
+        &REQUIRED_ASSETS
+    }
+}
+
 impl SceneNode for IntroLoading {
     fn enter(&mut self, shared: &mut Shared) -> AppResult<()> {
         // (한국어) 사용할 공유 객체 가져오기.
@@ -53,15 +97,25 @@ impl SceneNode for IntroLoading {
         let fonts = shared.get::<Arc<HashMap<String, FontArc>>>().unwrap().clone();
         let textures = shared.get::<Arc<HashMap<String, wgpu::Texture>>>().unwrap().clone();
         let asset_bundle = shared.get::<AssetBundle>().unwrap().clone();
+        let attract = self.attract;
+
+        // (한국어) 
+        // 재생할 캐릭터 음성을 처음 한 번만 무작위로 선택하고, 다시 들어올 때는 선택된 음성을 사용합니다.
+        // 
+        // (English Translation) 
+        // Randomly selects the character voice to play only the first time, 
+        // and uses the selected voice on re-entry.
+        // 
+        let voice = match self.voice {
+            Some(voice) => voice, 
+            None => *self.voice.insert(shared.get::<Arc<CosmeticRng>>().unwrap().lock().gen_range(0..VOICES.len())),
+        };
 
         self.loading = Some(thread::spawn(move || {
             // (한국어) 현재 게임 장면에서 사용할 에셋들을 로드합니다. 
             // (English Translation) Loads assets to be used in the current game scene.
-            asset_bundle.get(path::LOGO_TEXTURE_PATH)?;
-            asset_bundle.get(path::ARIS_TITLE_SOUND_PATH)?;
-            asset_bundle.get(path::MOMOI_TITLE_SOUND_PATH)?;
-            asset_bundle.get(path::MIDORI_TITLE_SOUND_PATH)?;
-            asset_bundle.get(path::YUZU_TITLE_SOUND_PATH)?;
+            preload::preload_scene::<Self>(&asset_bundle)?;
+            asset_bundle.get(VOICES[voice])?;
 
             // (한국어) 로고 이미지 텍스처를 생성합니다.
             // (English Translation) Create a logo image texture. 
@@ -133,8 +187,9 @@ impl SceneNode for IntroLoading {
             );
 
             Ok(IntroScene { 
-                timer: 0.0, 
-                state: state::IntroState::default(), 
+                phases: state::phases(), 
+                voice, 
+                attract, 
                 loading: None, 
                 loading_text, 
                 notifications, 
@@ -168,7 +223,7 @@ impl SceneNode for IntroLoading {
 
     fn update(&mut self, shared: &mut Shared, _total_time: f64, _elapsed_time: f64) -> AppResult<()> {
         if self.loading.as_ref().is_some_and(|it| it.is_finished()) {
-            let next_scene = self.loading.take().unwrap().join().unwrap()?;
+            let next_scene = join_thread(self.loading.take().unwrap(), "loading")?;
             *shared.get_mut::<SceneState>().unwrap() = SceneState::Change(Box::new(next_scene));
         }
 
@@ -176,67 +231,31 @@ impl SceneNode for IntroLoading {
     }
 
     fn draw(&self, shared: &mut Shared) -> AppResult<()> {
-        // (한국어) 사용할 공유 객체 가져오기.
-        // (English Translation) Get shared object to use.
-        let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-        let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-        let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-
-        // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-        // (English Translation) Wait until the previous operation is finished.
-        device.poll(wgpu::Maintain::Wait);
-
-        // (한국어) 다음 프레임을 가져옵니다.
-        // (English Translation) Get the next frame.
-        let frame = surface.get_current_texture()
-            .map_err(|err| game_err!(
-                "Failed to get next frame",
-                "Failed to get next frame for the following reasons: {}",
-                err.to_string()
-            ))?;
-
-        // (한국어) 프레임 버퍼의 텍스쳐 뷰를 생성합니다.
-        // (English Translation) Creates a texture view of the framebuffer.
-        let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-        // (한국어) 커맨드 버퍼를 생성합니다.
-        // (English Translation) Creates a command buffer.
-        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-
-        {
-            let mut _rpass = encoder.begin_render_pass(
-                &wgpu::RenderPassDescriptor {
-                    label: Some("RenderPass(IntroLoading)"),
-                    color_attachments: &[
-                        Some(wgpu::RenderPassColorAttachment { 
-                            view: &view, 
-                            resolve_target: None, 
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                                store: wgpu::StoreOp::Store,
-                            }, 
-                        }),
-                    ],
-                    depth_stencil_attachment: None,
-                    timestamp_writes: None,
-                    occlusion_query_set: None,
-                }
-            );
-        }
-
-        // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-        // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-        queue.submit(Some(encoder.finish()));
-        frame.present();
-
-        Ok(())
+        with_frame(shared, self.clear_color(), |frame| {
+            frame.begin_pass("RenderPass(IntroLoading)");
+        })
     }
 }
 
 impl Default for IntroLoading {
     #[inline]
     fn default() -> Self {
-        Self { loading: None }
+        Self { voice: None, attract: false, loading: None }
+    }
+}
+
+impl IntroLoading {
+    /// #### 한국어 </br>
+    /// 타이틀 화면에서 입력이 없어 인트로를 다시 보여주는 게임 장면을 생성합니다. </br>
+    /// 이 인트로는 아무 입력이 있으면 바로 `Title` 게임 장면으로 돌아갑니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a game scene that replays the intro because the title screen was idle. </br>
+    /// This intro returns to the `Title` game scene immediately on any input. </br>
+    /// 
+    #[inline]
+    pub fn attract() -> Self {
+        Self { attract: true, ..Default::default() }
     }
 }
 
@@ -250,8 +269,9 @@ impl Default for IntroLoading {
 /// 
 #[derive(Debug)]
 pub struct IntroScene {
-    timer: f64,
-    state: state::IntroState,
+    phases: Phases<state::IntroState>,
+    voice: usize, 
+    attract: bool, 
     loading: Option<JoinHandle<AppResult<()>>>,
     loading_text: Text, 
     notifications: Vec<Text>,
@@ -259,45 +279,80 @@ pub struct IntroScene {
     logo: UiObject,
 }
 
+#[cfg(test)]
+impl crate::scene::state::SceneStates for IntroScene {
+    const NUM_STATES: usize = state::IntroState::NUM_STATES;
+}
+
 impl SceneNode for IntroScene {
     fn enter(&mut self, shared: &mut Shared) -> AppResult<()> {
         // (한국어) 사용할 공유 객체 가져오기
         // (English Translation) Get shared object to use.
         let asset_bundle = shared.get::<AssetBundle>().unwrap().clone();
         self.loading = Some(thread::spawn(move || {
-            // (한국어) `Title` 게임 장면에서 사용될 에셋들을 로드합니다.
-            // (English Translation) Loads assets to be used in `Title` game scene. 
-            asset_bundle.get(path::CLICK_SOUND_PATH)?;
-            asset_bundle.get(path::CANCEL_SOUND_PATH)?;
-            asset_bundle.get(path::YUUKA_TITLE_SOUND_PATH)?;
-            asset_bundle.get(path::YUUKA_HIDDEN_SOUND_PATH)?;
-            asset_bundle.get(path::STAR_TEXTURE_PATH)?;
-            asset_bundle.get(path::BUTTON_WIDE_TEXTURE_PATH)?;
-            asset_bundle.get(path::BUTTON_MEDIUM_TEXTURE_PATH)?;
-            asset_bundle.get(path::BUTTON_RETURN_TEXTURE_PATH)?;
-            asset_bundle.get(path::TITLE_BUTTON_START_TEXTURE_PATH)?;
-            asset_bundle.get(path::TITLE_BUTTON_SETTING_TEXTURE_PATH)?;
-            asset_bundle.get(path::TITLE_BUTTON_EXIT_TEXTURE_PATH)?;
-            asset_bundle.get(path::TITLE_BACKGROUND_TEXTURE_PATH)?;
-            asset_bundle.get(path::WINDOW_RATIO_4_3_TEXTURE_PATH)?;
-            asset_bundle.get(path::WINDOW_RATIO_8_1_TEXTURE_PATH)?;
-            asset_bundle.get(path::ARIS_STANDING_TEXTURE_PATH)?;
-            asset_bundle.get(path::MOMOI_STANDING_TEXTURE_PATH)?;
-            asset_bundle.get(path::MIDORI_STANDING_TEXTURE_PATH)?;
-            asset_bundle.get(path::YUZU_STANDING_TEXTURE_PATH)?;
-            
+            // (한국어) `Title` 게임 장면에서 사용될 에셋들을 미리 로드합니다.
+            // 참조는 `Title` 게임 장면이 준비될 때 획득합니다.
+            // 
+            // (English Translation) 
+            // Loads assets to be used in `Title` game scene in advance. 
+            // References are acquired when the `Title` game scene is prepared.
+            // 
+            for rel_path in TitleLoading::required_assets() {
+                asset_bundle.prefetch(rel_path)?;
+            }
             Ok(())
         }));
         Ok(())
     }
 
-    #[inline]
+    fn exit(&mut self, shared: &mut Shared) -> AppResult<()> {
+        // (한국어) 현재 게임 장면에서 사용한 에셋들을 해제합니다.
+        // (English Translation) Release assets used in the current game scene.
+        let asset_bundle = shared.get::<AssetBundle>().unwrap();
+        preload::release_scene::<IntroLoading>(asset_bundle);
+        asset_bundle.release(VOICES[self.voice]);
+        Ok(())
+    }
+
+    fn handle_events(&mut self, shared: &mut Shared, event: Event<AppEvent>) -> AppResult<()> {
+        // (한국어) 키를 누르거나 마우스를 클릭하면 현재 상태를 건너뜁니다.
+        // (English Translation) Pressing a key or clicking the mouse skips the current state.
+        let pressed = match event {
+            Event::WindowEvent { event, .. } => match event {
+                WindowEvent::KeyboardInput { event, .. } => !event.repeat && event.state.is_pressed(), 
+                WindowEvent::MouseInput { state, .. } => state.is_pressed(), 
+                _ => false
+            },
+            _ => false
+        };
+
+        // (한국어) 인트로를 다시 보여주는 중인 경우 바로 `Title` 게임 장면으로 돌아갑니다.
+        // (English Translation) If the intro is being replayed, it returns to the `Title` game scene immediately.
+        if pressed && self.attract {
+            if !is_scene_changing(shared) {
+                state::return_to_title(shared);
+            }
+        } else if pressed {
+            state::skip(self, shared);
+        }
+
+        Ok(())
+    }
+
     fn update(&mut self, shared: &mut Shared, total_time: f64, elapsed_time: f64) -> AppResult<()> {
-        state::UPDATE[self.state as usize](self, shared, total_time, elapsed_time)
+        for entered in self.phases.tick(elapsed_time) {
+            state::enter(self, shared, entered);
+        }
+        state_fn(&state::UPDATE, self.phases.current() as usize, "UPDATE")?(self, shared, total_time, elapsed_time)
     }
 
     fn draw(&self, shared: &mut Shared) -> AppResult<()> {
-        state::DRAW[self.state as usize](self, shared)
+        state_fn(&state::DRAW, self.phases.current() as usize, "DRAW")?(self, shared)
+    }
+
+    #[inline]
+    fn clear_color(&self) -> wgpu::Color {
+        wgpu::Color::WHITE
     }
 }
 
@@ -29,8 +29,14 @@ mod display_logo;
 mod wait_loading;
 mod fade_out;
 
+use std::sync::Arc;
+
+
 use crate::{
-    nodes::intro::IntroScene,
+    state_enum,
+    components::sound::SoundSink,
+    nodes::{intro::IntroScene, title::TitleLoading},
+    scene::{phases::Phases, state::SceneState},
     system::{
         error::AppResult,
         shared::Shared,
@@ -77,20 +83,127 @@ pub const DRAW: [&'static DrawFn; 8] = [
 
 
 /// #### 한국어 </br>
-/// 인트로 게임 장면의 상태 목록입니다. </br>
+/// `intro` 게임 장면의 상태들을 순서와 지속 시간에 따라 생성합니다. </br>
+/// `PlayTitleVoice`와 `WaitLoading` 상태는 할 일을 마치면 직접 다음 상태로 넘어갑니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the states of the `intro` game scene with their order and durations. </br>
+/// The `PlayTitleVoice` and `WaitLoading` states move to the next state themselves when their work is done. </br>
+/// 
+pub fn phases() -> Phases<IntroState> {
+    Phases::new([
+        (fade_in::DURATION, IntroState::FadeIn), 
+        (display_notify::DURATION, IntroState::DisplayNotify), 
+        (disappear_notify::DURATION, IntroState::DisappearNotify), 
+        (f64::INFINITY, IntroState::PlayTitleVoice), 
+        (appear_logo::DURATION, IntroState::AppearLogo), 
+        (display_logo::DURATION, IntroState::DisplayLogo), 
+        (f64::INFINITY, IntroState::WaitLoading), 
+        (fade_out::DURATION, IntroState::FadeOut), 
+    ])
+}
+
+/// #### 한국어 </br>
+/// 새로운 상태에 진입할 때 호출됩니다. </br>
+/// 이전 상태의 마지막 갱신이 끝까지 진행되지 않았을 수 있으므로, 이어지는 상태에서 그리는 오브젝트를 최종 값으로 맞춥니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Called when entering a new state. </br>
+/// Since the last update of the previous state may not have reached its end, sets the objects drawn in the following state to their final values. </br>
+/// 
+pub fn enter(this: &mut IntroScene, shared: &mut Shared, state: IntroState) {
+    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+    match state {
+        IntroState::DisplayLogo => this.logo.update(queue, |data| {
+            data.color.w = 1.0;
+        }),
+        _ => { /* empty */ }
+    }
+}
+
+/// #### 한국어 </br>
+/// 현재 상태를 끝까지 빨리 감습니다. </br>
+/// 시간에 따라 진행되는 상태는 다음 갱신 때 다음 상태로 변경되며, 
+/// 재생 중인 캐릭터 음성은 멈춥니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Fast-forwards the current state to its end. </br>
+/// A state that progresses over time changes to the next state on the next update, 
+/// and the playing character voice is stopped. </br>
+/// 
+pub fn skip(this: &mut IntroScene, shared: &mut Shared) {
+    if let Some(voice) = shared.pop::<Arc<SoundSink>>() {
+        voice.stop();
+    }
+
+    this.phases.skip();
+}
+
+/// #### 한국어 </br>
+/// 재생 중인 캐릭터 음성을 멈추고 바로 `Title` 게임 장면으로 돌아갑니다. </br>
+/// 타이틀 화면에서 입력이 없어 인트로를 다시 보여주는 중에 입력이 있을 때 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Stops the playing character voice and returns to the `Title` game scene immediately. </br>
+/// Used when there is an input while replaying the intro because the title screen was idle. </br>
+/// 
+pub fn return_to_title(shared: &mut Shared) {
+    if let Some(voice) = shared.pop::<Arc<SoundSink>>() {
+        voice.stop();
+    }
+
+    *shared.get_mut::<SceneState>().unwrap() = SceneState::Change(Box::new(TitleLoading::default()));
+}
+
+
+
+state_enum! {
+    /// #### 한국어 </br>
+    /// 인트로 게임 장면의 상태 목록입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Status list of `intro` game scene. </br>
+    /// 
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum IntroState {
+        #[default]
+        FadeIn,
+        DisplayNotify,
+        DisappearNotify,
+        PlayTitleVoice,
+        AppearLogo,
+        DisplayLogo,
+        WaitLoading,
+        FadeOut,
+    }
+}
+
+
+/// #### 한국어 </br>
+/// `intro` 게임 장면의 상태별 함수 목록들의 (이름, 길이) 입니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Status list of `intro` game scene. </br>
+/// The (name, length) of the per-state function tables of the `intro` game scene. </br>
 /// 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum IntroState {
-    #[default]
-    FadeIn,
-    DisplayNotify,
-    DisappearNotify,
-    PlayTitleVoice,
-    AppearLogo,
-    DisplayLogo,
-    WaitLoading,
-    FadeOut,
+#[cfg(test)]
+pub const TABLES: [(&'static str, usize); 2] = [
+    ("UPDATE", UPDATE.len()), 
+    ("DRAW", DRAW.len()), 
+];
+
+// (한국어) 상태별 함수 목록의 길이가 상태의 개수와 같은지 컴파일 시간에 확인합니다.
+// (English Translation) Checks at compile time that the length of each per-state function table equals the number of states.
+const _: () = assert!(UPDATE.len() == IntroState::NUM_STATES, "`UPDATE` must have one entry for each `IntroState`.");
+const _: () = assert!(DRAW.len() == IntroState::NUM_STATES, "`DRAW` must have one entry for each `IntroState`.");
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::scene::state::debug_validate_tables;
+
+    #[test]
+    fn state_tables_match_the_scene() {
+        assert!(debug_validate_tables::<IntroScene>(&TABLES).is_ok());
+    }
 }
@@ -1,12 +1,12 @@
 use std::sync::Arc;
 
 use crate::{
-    game_err,
     components::{ui::UiBrush, text::TextBrush, camera::GameCamera},
-    nodes::intro::{IntroScene, state::IntroState},
-    render::depth::DepthBuffer,
+    nodes::intro::IntroScene,
+    render::frame::with_frame,
+    scene::node::SceneNode,
     system::{
-        error::{AppResult, GameError},
+        error::{join_thread, AppResult},
         shared::Shared,
     },
 };
@@ -28,9 +28,8 @@ pub fn update(this: &mut IntroScene, _shared: &mut Shared, _total_time: f64, _el
     // it changes to the next state.
     // 
     if this.loading.as_ref().unwrap().is_finished() {
-        this.loading.take().unwrap().join().unwrap()?;
-        this.state = IntroState::FadeOut;
-        this.timer = 0.0;
+        join_thread(this.loading.take().unwrap(), "loading")?;
+        this.phases.advance();
         return Ok(());
     }
 
@@ -50,65 +49,16 @@ pub fn draw(this: &IntroScene, shared: &mut Shared) -> AppResult<()> {
     // (English Translation) Get shared object to use.
     let ui_brush = shared.get::<Arc<UiBrush>>().unwrap();
     let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
-    let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
     let camera = shared.get::<Arc<GameCamera>>().unwrap();
 
 
-    // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-    // (English Translation) Wait until the previous operation is finished.
-    device.poll(wgpu::Maintain::Wait);
+    with_frame(shared, this.clear_color(), |frame| {
+        {
+            let mut rpass = frame.begin_pass("RenderPass(IntroScene(WaitLoading(Ui)))");
 
-    // (한국어) 다음 프레임을 가져옵니다.
-    // (English Translation) Get the next frame.
-    let frame = surface.get_current_texture()
-        .map_err(|err| game_err!(
-            "Failed to get next frame",
-            "Failed to get next frame for the following reasons: {}",
-            err.to_string()
-        ))?;
-
-    // (한국어) 프레임 버퍼의 텍스쳐 뷰를 생성합니다.
-    // (English Translation) Creates a texture view of the framebuffer.
-    let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-    // (한국어) 커맨드 버퍼를 생성합니다.
-    // (English Translation) Creates a command buffer.
-    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(IntroScene(WaitLoading(Ui)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment { 
-                view: &view, 
-                resolve_target: None, 
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color::WHITE),
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-        ui_brush.draw(&mut rpass, [&this.logo].into_iter());
-        text_brush.draw(&mut rpass, [&this.loading_text].into_iter());
-    }
-
-    // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-    // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-    queue.submit(Some(encoder.finish()));
-    frame.present();
-
-    Ok(())
+            camera.bind(&mut rpass);
+            ui_brush.draw(&mut rpass, [&this.logo].into_iter());
+            text_brush.draw(&mut rpass, [&this.loading_text].into_iter());
+        }
+    })
 }
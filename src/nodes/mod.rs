@@ -3,12 +3,19 @@ pub mod intro;
 pub mod setup;
 pub mod title;
 pub mod in_game;
+pub mod preload;
 
 pub mod consts {
     pub const PIXEL_PER_METER: f32 = 50.0 / 1.0;
 }
 
 pub mod path {
+    use crate::{
+        game_err, 
+        assets::bundle::AssetBundle, 
+        system::error::{AppResult, GameError}, 
+    };
+
     pub const SAVE_PATH: &'static str = "user.sav";
     pub const SETTINGS_PATH: &'static str = "user.settings";
 
@@ -25,12 +32,15 @@ pub mod path {
     pub const UI_TEXT_SHADER_PATH: &'static str = "shaders/text.wgsl";
     pub const SPRITE_SHADER_PATH: &'static str = "shaders/sprite.wgsl";
     pub const TILE_SPRITE_SHADER_PATH: &'static str = "shaders/tile.wgsl";
+    pub const BLIT_SHADER_PATH: &'static str = "shaders/blit.wgsl";
+    pub const BLOOM_SHADER_PATH: &'static str = "shaders/bloom.wgsl";
 
     pub const BULLET_SHADER_PATH: &'static str = "shaders/bullet.wgsl";
 
     // Textures ---------------------------------------------------------------
     pub const LOGO_TEXTURE_PATH: &'static str = "textures/sys/logo.dds";
     pub const DUMMY_TEXTURE_PATH: &'static str = "textures/sys/dummy.dds";
+    pub const WINDOW_ICON_PATH: &'static str = "textures/sys/icon.png";
 
     pub const STAR_TEXTURE_PATH: &'static str = "textures/ui/star.dds";
     pub const HEART_TEXTURE_PATH: &'static str = "textures/ui/heart.dds";
@@ -44,6 +54,8 @@ pub mod path {
     pub const TITLE_BUTTON_EXIT_TEXTURE_PATH: &'static str = "textures/ui/title_button_exit.dds";
     pub const WINDOW_RATIO_4_3_TEXTURE_PATH: &'static str = "textures/ui/window_ratio_4_3.dds";
     pub const WINDOW_RATIO_8_1_TEXTURE_PATH: &'static str = "textures/ui/window_ratio_8_1.dds";
+    pub const CURSOR_NORMAL_TEXTURE_PATH: &'static str = "textures/ui/cursor_normal.png";
+    pub const CURSOR_HOVER_TEXTURE_PATH: &'static str = "textures/ui/cursor_hover.png";
 
     pub const TITLE_BACKGROUND_TEXTURE_PATH: &'static str = "textures/bg/title_background.dds";
     pub const INGAME_BACKGROUND_TEXTURE_PATH: &'static str = "textures/bg/ingame_background.dds";
@@ -125,4 +137,172 @@ pub mod path {
     pub const YUUKA_VICTORY_SOUND_PATH: &'static str = "sounds/yuuka/yuuka_victory.ogg";
     pub const YUUKA_DEFEAT_SOUND_PATH: &'static str = "sounds/yuuka/yuuka_defeat.ogg";
     pub const YUUKA_HIDDEN_SOUND_PATH: &'static str = "sounds/yuuka/yuuka_hidden.ogg";
+
+    /// #### 한국어 </br>
+    /// 등록된 모든 에셋 경로의 목록입니다. </br>
+    /// 새로운 경로 상수를 추가할 때 이 목록에도 추가해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// A list of all registered asset paths. </br>
+    /// When adding a new path constant, it must also be added to this list. </br>
+    /// 
+    const ALL: [&'static str; 96] = [
+        SAVE_PATH, 
+        SETTINGS_PATH, 
+        NEXON_LV2_GOTHIC_BOLD_PATH, 
+        NEXON_LV2_GOTHIC_MEDIUM_PATH, 
+        NEXON_LV2_GOTHIC_PATH, 
+        KOR_SCRIPTS_PATH, 
+        UI_SHADER_PATH, 
+        UI_TEXT_SHADER_PATH, 
+        SPRITE_SHADER_PATH, 
+        TILE_SPRITE_SHADER_PATH, 
+        BLIT_SHADER_PATH, 
+        BLOOM_SHADER_PATH, 
+        BULLET_SHADER_PATH, 
+        LOGO_TEXTURE_PATH, 
+        DUMMY_TEXTURE_PATH, 
+        WINDOW_ICON_PATH, 
+        STAR_TEXTURE_PATH, 
+        HEART_TEXTURE_PATH, 
+        FINISH_TEXTURE_PATH, 
+        BUTTON_MEDIUM_TEXTURE_PATH, 
+        BUTTON_WIDE_TEXTURE_PATH, 
+        BUTTON_ETC_TEXTURE_PATH, 
+        BUTTON_RETURN_TEXTURE_PATH, 
+        TITLE_BUTTON_START_TEXTURE_PATH, 
+        TITLE_BUTTON_SETTING_TEXTURE_PATH, 
+        TITLE_BUTTON_EXIT_TEXTURE_PATH, 
+        WINDOW_RATIO_4_3_TEXTURE_PATH, 
+        WINDOW_RATIO_8_1_TEXTURE_PATH, 
+        CURSOR_NORMAL_TEXTURE_PATH, 
+        CURSOR_HOVER_TEXTURE_PATH, 
+        TITLE_BACKGROUND_TEXTURE_PATH, 
+        INGAME_BACKGROUND_TEXTURE_PATH, 
+        DEF_IMG_TEXTURE_PATH, 
+        ARIS_IMG_TEXTURE_PATH, 
+        MOMOI_IMG_TEXTURE_PATH, 
+        MIDORI_IMG_TEXTURE_PATH, 
+        YUZU_IMG_TEXTURE_PATH, 
+        YUUKA_IMG_TEXTURE_PATH, 
+        ARIS_STANDING_TEXTURE_PATH, 
+        ARIS_PLAYER_TEXTURE_PATH, 
+        MOMOI_STANDING_TEXTURE_PATH, 
+        MOMOI_PLAYER_TEXTURE_PATH, 
+        MIDORI_STANDING_TEXTURE_PATH, 
+        MIDORI_PLAYER_TEXTURE_PATH, 
+        YUZU_STANDING_TEXTURE_PATH, 
+        YUZU_PLAYER_TEXTURE_PATH, 
+        YUUKA_ENEMY_TEXTURE_PATH, 
+        YUUKA_BULLET_TEXTURE_PATH, 
+        CLICK_SOUND_PATH, 
+        CANCEL_SOUND_PATH, 
+        START_SOUND_PATH, 
+        PAUSE_SOUND_PATH, 
+        FINISH_SOUND_PATH, 
+        BULLET_FIRE_SOUND_PATH, 
+        THEME18_SOUND_PATH, 
+        THEME19_SOUND_PATH, 
+        THEME23_SOUND_PATH, 
+        THEME27_SOUND_PATH, 
+        THEME30_SOUND_PATH, 
+        THEME64_SOUND_PATH, 
+        ARIS_TITLE_SOUND_PATH, 
+        ARIS_STAGE_START_SOUND_PATH, 
+        ARIS_SMILE_0_SOUND_PATH, 
+        ARIS_SMILE_1_SOUND_PATH, 
+        ARIS_DAMAGE_0_SOUND_PATH, 
+        ARIS_DAMAGE_1_SOUND_PATH, 
+        ARIS_DAMAGE_2_SOUND_PATH, 
+        MOMOI_TITLE_SOUND_PATH, 
+        MOMOI_STAGE_START_SOUND_PATH, 
+        MOMOI_SMILE_0_SOUND_PATH, 
+        MOMOI_SMILE_1_SOUND_PATH, 
+        MOMOI_DAMAGE_0_SOUND_PATH, 
+        MOMOI_DAMAGE_1_SOUND_PATH, 
+        MOMOI_DAMAGE_2_SOUND_PATH, 
+        MIDORI_TITLE_SOUND_PATH, 
+        MIDORI_STAGE_START_SOUND_PATH, 
+        MIDORI_SMILE_0_SOUND_PATH, 
+        MIDORI_SMILE_1_SOUND_PATH, 
+        MIDORI_DAMAGE_0_SOUND_PATH, 
+        MIDORI_DAMAGE_1_SOUND_PATH, 
+        MIDORI_DAMAGE_2_SOUND_PATH, 
+        YUZU_TITLE_SOUND_PATH, 
+        YUZU_STAGE_START_SOUND_PATH, 
+        YUZU_SMILE_0_SOUND_PATH, 
+        YUZU_SMILE_1_SOUND_PATH, 
+        YUZU_DAMAGE_0_SOUND_PATH, 
+        YUZU_DAMAGE_1_SOUND_PATH, 
+        YUZU_DAMAGE_2_SOUND_PATH, 
+        YUUKA_TITLE_SOUND_PATH, 
+        YUUKA_ATTACK0_SOUND_PATH, 
+        YUUKA_ATTACK1_SOUND_PATH, 
+        YUUKA_ATTACK2_SOUND_PATH, 
+        YUUKA_ATTACK3_SOUND_PATH, 
+        YUUKA_VICTORY_SOUND_PATH, 
+        YUUKA_DEFEAT_SOUND_PATH, 
+        YUUKA_HIDDEN_SOUND_PATH, 
+    ];
+
+    /// #### 한국어 </br>
+    /// 등록된 모든 에셋 경로를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns all registered asset paths. </br>
+    /// 
+    #[inline]
+    pub fn all() -> &'static [&'static str] {
+        &ALL
+    }
+
+    /// #### 한국어 </br>
+    /// 등록된 모든 에셋 경로가 에셋 번들에 있는지 확인합니다. </br>
+    /// 없는 경로가 있는 경우 없는 경로들을 모두 나열한 하나의 오류를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks that every registered asset path exists in the asset bundle. </br>
+    /// If any path is missing, returns a single error listing all the missing paths. </br>
+    /// 
+    pub fn validate_assets(bundle: &AssetBundle) -> AppResult<()> {
+        let missing = missing_paths(all(), |rel_path| bundle.contains(rel_path));
+        if !missing.is_empty() {
+            return Err(game_err!(
+                "Missing asset files", 
+                "The following asset files are not in the asset list: {}", 
+                missing.join(", ")
+            ));
+        }
+
+        return Ok(());
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 경로들 중 `contains`를 만족하지 않는 경로들을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the paths among the given paths that do not satisfy `contains`. </br>
+    /// 
+    fn missing_paths<'a, F>(paths: &[&'a str], contains: F) -> Vec<&'a str>
+    where F: Fn(&str) -> bool {
+        return paths.iter()
+            .copied()
+            .filter(|rel_path| !contains(rel_path))
+            .collect();
+    }
+
+
+    #[cfg(test)]
+    mod test {
+        use super::*;
+
+        #[test]
+        fn fake_missing_path_is_reported() {
+            const FAKE_PATH: &'static str = "textures/sys/not_exist.dds";
+            let paths = [DUMMY_TEXTURE_PATH, FAKE_PATH, CLICK_SOUND_PATH];
+            let missing = missing_paths(&paths, |rel_path| rel_path != FAKE_PATH);
+            assert_eq!(missing, vec![FAKE_PATH]);
+            assert!(missing_paths(all(), |_| true).is_empty());
+        }
+    }
 }
@@ -0,0 +1,227 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering as MemOrdering};
+
+use crate::{
+    assets::bundle::AssetBundle,
+    system::error::AppResult,
+};
+
+
+
+/// #### 한국어 </br>
+/// 게임 장면이 준비될 때 미리 불러와야 하는 에셋 목록을 선언하는 인터페이스 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is an interface that declares the list of assets to be loaded in advance when a game scene is prepared. </br>
+/// 
+pub trait RequiredAssets {
+    /// #### 한국어 </br>
+    /// 게임 장면에서 사용하는 에셋들의 경로를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the paths of the assets used in the game scene. </br>
+    /// 
+    fn required_assets() -> &'static [&'static str];
+}
+
+
+
+/// #### 한국어 </br>
+/// 다른 스레드에서 진행 중인 불러오기 작업에 취소를 알리는 플래그 입니다. </br>
+/// 복제된 플래그들은 같은 값을 공유합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A flag that notifies a loading task running in another thread of cancellation. </br>
+/// Cloned flags share the same value. </br>
+/// 
+#[derive(Debug, Clone, Default)]
+pub struct CancelFlag(Arc<AtomicBool>);
+
+impl CancelFlag {
+    /// #### 한국어 </br>
+    /// 불러오기 작업의 취소를 요청합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Requests cancellation of the loading task. </br>
+    /// 
+    #[inline]
+    pub fn cancel(&self) {
+        self.0.store(true, MemOrdering::Release);
+    }
+
+    /// #### 한국어 </br>
+    /// 불러오기 작업의 취소가 요청된 경우 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if cancellation of the loading task has been requested. </br>
+    /// 
+    #[inline]
+    pub fn is_canceled(&self) -> bool {
+        self.0.load(MemOrdering::Acquire)
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 취소할 수 있는 불러오기 작업의 결과 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The result of a loading task that can be canceled. </br>
+/// 
+#[derive(Debug)]
+pub enum LoadResult<T> {
+    Loaded(T),
+    Canceled,
+}
+
+
+
+/// #### 한국어 </br>
+/// 주어진 에셋들을 순서대로 불러오고 참조를 하나씩 획득합니다. </br>
+/// 에셋을 하나 불러올 때마다 `(불러온 개수, 전체 개수)`로 `progress` 함수를 호출합니다. </br>
+/// 에셋을 불러오는 도중 오류가 발생한 경우 `GameError`를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Loads the given assets in order and acquires one reference to each. </br>
+/// Each time an asset is loaded, the `progress` function is called with `(loaded, total)`. </br>
+/// If an error occurs while loading the assets, it returns `GameError`. </br>
+/// 
+pub fn preload<F>(asset_bundle: &AssetBundle, rel_paths: &[&str], mut progress: F) -> AppResult<()>
+where F: FnMut(usize, usize) {
+    for (idx, rel_path) in rel_paths.iter().enumerate() {
+        asset_bundle.get(rel_path)?;
+        progress(idx + 1, rel_paths.len());
+    }
+
+    return Ok(());
+}
+
+/// #### 한국어 </br>
+/// 게임 장면에서 사용하는 에셋들을 불러오고 진행 상황을 기록합니다. </br>
+/// 불러온 에셋들은 `release_scene` 함수를 호출할 때까지 해제되지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Loads the assets used in the game scene and logs the progress. </br>
+/// The loaded assets are not freed until the `release_scene` function is called. </br>
+/// 
+pub fn preload_scene<S: RequiredAssets>(asset_bundle: &AssetBundle) -> AppResult<()> {
+    let scene = std::any::type_name::<S>();
+    preload(asset_bundle, S::required_assets(), |loaded, total| {
+        log::debug!("Preloading assets for {} ({}/{})", scene, loaded, total);
+    })
+}
+
+/// #### 한국어 </br>
+/// 주어진 에셋들을 `preload` 함수와 같이 불러오되, 에셋을 하나 불러오기 전마다 취소 여부를 확인합니다. </br>
+/// 취소된 경우 지금까지 획득한 참조들을 해제하고 `LoadResult::Canceled`를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Loads the given assets like the `preload` function, but checks for cancellation before loading each asset. </br>
+/// If canceled, it releases the references acquired so far and returns `LoadResult::Canceled`. </br>
+/// 
+pub fn preload_cancelable<F>(
+    asset_bundle: &AssetBundle, 
+    rel_paths: &[&str], 
+    cancel: &CancelFlag, 
+    mut progress: F
+) -> AppResult<LoadResult<()>>
+where F: FnMut(usize, usize) {
+    for (idx, rel_path) in rel_paths.iter().enumerate() {
+        if cancel.is_canceled() {
+            for rel_path in rel_paths[..idx].iter() {
+                asset_bundle.release(rel_path);
+            }
+            return Ok(LoadResult::Canceled);
+        }
+
+        asset_bundle.get(rel_path)?;
+        progress(idx + 1, rel_paths.len());
+    }
+
+    return Ok(LoadResult::Loaded(()));
+}
+
+/// #### 한국어 </br>
+/// 게임 장면에서 사용하는 에셋들을 취소할 수 있도록 불러오고 진행 상황을 기록합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Loads the assets used in the game scene so that it can be canceled, and logs the progress. </br>
+/// 
+pub fn preload_scene_cancelable<S: RequiredAssets>(asset_bundle: &AssetBundle, cancel: &CancelFlag) -> AppResult<LoadResult<()>> {
+    let scene = std::any::type_name::<S>();
+    preload_cancelable(asset_bundle, S::required_assets(), cancel, |loaded, total| {
+        log::debug!("Preloading assets for {} ({}/{})", scene, loaded, total);
+    })
+}
+
+/// #### 한국어 </br>
+/// `preload_scene` 함수로 획득한 게임 장면의 에셋 참조들을 해제합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Releases the asset references of the game scene acquired by the `preload_scene` function. </br>
+/// 
+pub fn release_scene<S: RequiredAssets>(asset_bundle: &AssetBundle) {
+    for rel_path in S::required_assets() {
+        asset_bundle.release(rel_path);
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::nodes::{
+        path,
+        intro::IntroLoading,
+        title::TitleLoading,
+        in_game::InGameLoading,
+        first_time::FirstTimeSetupLoading,
+    };
+
+    #[test]
+    fn no_scene_requires_an_unregistered_path() {
+        let scenes = [
+            ("IntroLoading", IntroLoading::required_assets()),
+            ("TitleLoading", TitleLoading::required_assets()),
+            ("InGameLoading", InGameLoading::required_assets()),
+            ("FirstTimeSetupLoading", FirstTimeSetupLoading::required_assets()),
+        ];
+        for (scene, rel_paths) in scenes {
+            for rel_path in rel_paths {
+                assert!(path::all().contains(rel_path), "{} requires an unregistered path: {}", scene, rel_path);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn canceled_preload_returns_early_and_releases_assets() {
+        const FONT_PATH: &'static str = "fonts/nexon_lv2_gothic.ttf";
+        let asset_bundle = AssetBundle::for_test(&[FONT_PATH]);
+
+        // (한국어) 시작 전에 취소된 경우 아무 에셋도 불러오지 않습니다.
+        // (English Translation) If canceled before starting, no asset is loaded.
+        let cancel = CancelFlag::default();
+        cancel.cancel();
+        let result = preload_cancelable(&asset_bundle, &[FONT_PATH], &cancel, |_, _| panic!("nothing should be loaded"));
+        assert!(matches!(result, Ok(LoadResult::Canceled)));
+
+        // (한국어) 도중에 취소된 경우 남은 에셋을 불러오지 않고, 획득한 참조를 모두 해제합니다.
+        // (English Translation) If canceled midway, the remaining assets are not loaded and the acquired references are all released.
+        let cancel = CancelFlag::default();
+        let mut loaded = 0;
+        let result = preload_cancelable(&asset_bundle, &[FONT_PATH, FONT_PATH, FONT_PATH], &cancel, |count, _| {
+            loaded = count;
+            if count == 2 {
+                cancel.cancel();
+            }
+        });
+        assert!(matches!(result, Ok(LoadResult::Canceled)));
+        assert_eq!(loaded, 2);
+        assert!(!asset_bundle.is_loaded(FONT_PATH));
+
+        let result = preload_cancelable(&asset_bundle, &[FONT_PATH], &CancelFlag::default(), |_, _| ());
+        assert!(matches!(result, Ok(LoadResult::Loaded(()))));
+        assert!(asset_bundle.is_loaded(FONT_PATH));
+    }
+}
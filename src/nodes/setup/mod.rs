@@ -1,4 +1,3 @@
-#[cfg(debug_assertions)]
 mod parser;
 
 use std::sync::Arc;
@@ -7,30 +6,32 @@ use std::collections::HashMap;
 
 use ab_glyph::FontArc;
 use winit::window::Window;
-use rodio::{OutputStream, OutputStreamHandle};
 
 use crate::{
-    game_err,
     assets::bundle::AssetBundle,
     components::{
         ui::UiBrush,
-        text::TextBrush,
+        text::{TextBrush, TextScale},
         sprite::SpriteBrush,
         camera::CameraCreator,
-        font::FontDecoder,
-        script::{Script, ScriptDecoder},
+        cursor::CustomCursor,
+        font::{FontDecoder, FontFallback},
+        script::{self, Script, ScriptDecoder},
         save::{SaveDecoder, SaveEncoder},
         user::{Language, Settings, SettingsEncoder, SettingsDecoder},
+        sound,
     },
     nodes::{
         path, 
         intro::IntroLoading,
+        title::TitleLoading, 
         first_time::FirstTimeSetupLoading, 
     },
-    render::texture::DdsTextureDecoder, 
+    render::{bloom::{self, BloomPipeline}, mipmap::MipmapGenerator, offscreen::{self, BlitPipeline}, sampler::{self, TextureQuality}, texture::{self, DdsTextureDecoder, ImageTextureDecoder}}, 
     scene::{node::SceneNode, state::SceneState},
     system::{
-        error::{AppResult, GameError},
+        error::{join_thread, AppResult},
+        rng::{GameRng, CosmeticRng},
         shared::Shared,
     },
 };
@@ -59,26 +60,32 @@ impl SceneNode for SetupScene {
         // (한국어) 게임에서 사용되는 에셋 파일들을 로드합니다. 
         // (English Translation) Load asset files used in the game. 
         self.loading = Some(thread::spawn(move || {
-            asset_bundle.get(path::SAVE_PATH)?;
-            asset_bundle.get(path::SETTINGS_PATH)?;
+            // (한국어) 등록된 모든 에셋 경로가 에셋 목록에 있는지 먼저 확인합니다.
+            // (English Translation) First checks that every registered asset path is in the asset list.
+            path::validate_assets(&asset_bundle)?;
 
-            asset_bundle.get(path::DUMMY_TEXTURE_PATH)?;
-            asset_bundle.get(path::ARIS_IMG_TEXTURE_PATH)?;
-            asset_bundle.get(path::MOMOI_IMG_TEXTURE_PATH)?;
-            asset_bundle.get(path::MIDORI_IMG_TEXTURE_PATH)?;
-            asset_bundle.get(path::YUZU_IMG_TEXTURE_PATH)?;
-            asset_bundle.get(path::YUUKA_IMG_TEXTURE_PATH)?;
+            asset_bundle.prefetch(path::SAVE_PATH)?;
+            asset_bundle.prefetch(path::SETTINGS_PATH)?;
 
-            asset_bundle.get(path::NEXON_LV2_GOTHIC_PATH)?;
-            asset_bundle.get(path::NEXON_LV2_GOTHIC_BOLD_PATH)?;
-            asset_bundle.get(path::NEXON_LV2_GOTHIC_MEDIUM_PATH)?;
+            asset_bundle.prefetch(path::DUMMY_TEXTURE_PATH)?;
+            asset_bundle.prefetch(path::ARIS_IMG_TEXTURE_PATH)?;
+            asset_bundle.prefetch(path::MOMOI_IMG_TEXTURE_PATH)?;
+            asset_bundle.prefetch(path::MIDORI_IMG_TEXTURE_PATH)?;
+            asset_bundle.prefetch(path::YUZU_IMG_TEXTURE_PATH)?;
+            asset_bundle.prefetch(path::YUUKA_IMG_TEXTURE_PATH)?;
 
-            asset_bundle.get(path::UI_SHADER_PATH)?;
-            asset_bundle.get(path::UI_TEXT_SHADER_PATH)?;
-            asset_bundle.get(path::SPRITE_SHADER_PATH)?;
+            asset_bundle.prefetch(path::NEXON_LV2_GOTHIC_PATH)?;
+            asset_bundle.prefetch(path::NEXON_LV2_GOTHIC_BOLD_PATH)?;
+            asset_bundle.prefetch(path::NEXON_LV2_GOTHIC_MEDIUM_PATH)?;
 
-            asset_bundle.get(path::CLICK_SOUND_PATH)?;
-            asset_bundle.get(path::CANCEL_SOUND_PATH)?;
+            asset_bundle.prefetch(path::UI_SHADER_PATH)?;
+            asset_bundle.prefetch(path::UI_TEXT_SHADER_PATH)?;
+            asset_bundle.prefetch(path::SPRITE_SHADER_PATH)?;
+            asset_bundle.prefetch(path::BLIT_SHADER_PATH)?;
+            asset_bundle.prefetch(path::BLOOM_SHADER_PATH)?;
+
+            asset_bundle.prefetch(path::CLICK_SOUND_PATH)?;
+            asset_bundle.prefetch(path::CANCEL_SOUND_PATH)?;
 
             Ok(())
         }));
@@ -91,55 +98,70 @@ impl SceneNode for SetupScene {
         // (English Translation) Get shared object to use.
         let asset_bundle = shared.get::<AssetBundle>().unwrap();
         let window = shared.get::<Arc<Window>>().unwrap();
+        let adapter = shared.get::<Arc<wgpu::Adapter>>().unwrap();
         let device = shared.get::<Arc<wgpu::Device>>().unwrap();
         let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
         let config = shared.get::<wgpu::SurfaceConfiguration>().unwrap();
 
-        // (한국어) 공용으로 사용하는 텍스처 샘플러를 생성합니다.
-        // (English Translation) Creates a commonly used texture sampler.
-        let tex_sampler = Arc::new(device.create_sampler(
-            &wgpu::SamplerDescriptor {
-                label: Some("Sampler(General)"),
-                address_mode_u: wgpu::AddressMode::ClampToEdge,
-                address_mode_v: wgpu::AddressMode::ClampToEdge,
-                address_mode_w: wgpu::AddressMode::ClampToEdge,
-                min_filter: wgpu::FilterMode::Linear,
-                mag_filter: wgpu::FilterMode::Linear,
-                mipmap_filter: wgpu::FilterMode::Linear,
-                ..Default::default()
-            }
-        ));
-
         let fonts = setup_fonts(asset_bundle)?;
-        let (stream, handle) = setup_sound_engine()?;
+        let font_fallback = setup_font_fallback(&fonts);
         let camera_creator = CameraCreator::new(device.clone(), window.clone());
         let camera = camera_creator.create(Some("Default"), None, None, None, None);
+        let (settings, script) = setup_window(window, asset_bundle)?;
         let ui_brush = setup_ui_brush(device, &camera_creator.camera_layout, config.format, asset_bundle)?;
-        let text_brush = setup_text_brush(device, &camera_creator.camera_layout, config.format, asset_bundle)?;
+        let text_brush = setup_text_brush(device, &camera_creator.camera_layout, config.format, asset_bundle, settings.text_scale, font_fallback.clone())?;
         let sprite_brush = setup_sprite_brush(device, &camera_creator.camera_layout, config.format, asset_bundle)?;
-        let textures = setup_texture_map(device, queue, asset_bundle)?;
-        let (settings, script) = setup_window(window, asset_bundle)?;
+        let blit_pipeline = BlitPipeline::new(device, config.format, asset_bundle)?;
+        let bloom_pipeline = setup_bloom_pipeline(device, adapter, config.format, asset_bundle)?;
+        let mipmap_generator = MipmapGenerator::new(device, asset_bundle)?;
+        let textures = setup_texture_map(device, queue, asset_bundle, settings.texture_quality)?;
         let save = asset_bundle.get(path::SAVE_PATH)?
             .read_or_default(&SaveEncoder, &SaveDecoder)?;
 
         // (한국어) 공유할 객체들을 공유 객체에 등록합니다.
         // (English Translation) Register objects to be shared as shared objects.
-        shared.push(tex_sampler);
         shared.push(fonts);
-        shared.push(stream);
-        shared.push(handle);
+        shared.push(font_fallback);
+        match sound::open_output_stream() {
+            Some((stream, handle)) => {
+                shared.push(stream);
+                shared.push(handle);
+            },
+            None => {
+                shared.push(sound::NoAudioDevice::default());
+            }
+        }
         shared.push(camera_creator);
         shared.push(Arc::new(camera));
         shared.push(text_brush);
         shared.push(ui_brush);
         shared.push(sprite_brush);
+        shared.push(blit_pipeline);
+        if let Some(bloom_pipeline) = bloom_pipeline {
+            shared.push(bloom_pipeline);
+        }
+        shared.push(mipmap_generator);
         shared.push(textures);
+        shared.push(sound::BgmPlayer::new(settings.background_volume));
         shared.push(settings);
         shared.push(save);
         if let Some(script) = script {
             shared.push(Arc::new(script));
         };
 
+        // (한국어) 설정된 텍스처 품질로 공용 텍스처 샘플러를 생성합니다.
+        // (English Translation) Creates a commonly used texture sampler with the configured texture quality.
+        sampler::apply_texture_quality(shared);
+
+        // (한국어) 운영체제 커서 대신 그려질 게임 커서를 생성합니다.
+        // (English Translation) Creates the game cursor to be drawn instead of the OS cursor.
+        let custom_cursor = setup_custom_cursor(shared);
+        shared.push(Arc::new(custom_cursor));
+
+        // (한국어) 설정된 렌더링 배율로 깊이 버퍼와 오프스크린 텍스처를 생성합니다.
+        // (English Translation) Creates the depth buffer and the offscreen texture with the configured render scale.
+        offscreen::apply_render_scale(shared);
+
         Ok(())
     }
 
@@ -147,27 +169,57 @@ impl SceneNode for SetupScene {
         // (한국어) 모든 에셋 파일의 로드가 완료되었는지 확인합니다.
         // (English Translation) Verify that all asset files have completed loading.
         if self.loading.as_ref().is_some_and(|it| it.is_finished()) {
-            self.loading.take().unwrap().join().unwrap()?;
+            join_thread(self.loading.take().unwrap(), "loading")?;
             
-            #[cfg(debug_assertions)] {
-                // (한국어) 주어진 명령줄을 구문분석 합니다.
-                // (English Translation) 주어진 명령줄을 구문분석 합니다.
-                let config = parser::parse_command_lines();
-                if let Some(next_scene) = config.next_scene {
-                    // (한국어) 다음 장면이 설정되어 있는 경우 다음 장면으로 변경합니다.
-                    // (English Translation) If the next scene is set, change to the next scene.
-                    *shared.get_mut().unwrap() = SceneState::Change(next_scene);
-                    
-                    // (한국어) 설정된 언어의 스크립트 파일을 불러옵니다.
-                    // (English Translation) Loads the script file of the set language.
-                    let asset_bundle = shared.get::<AssetBundle>().unwrap();
-                    let rel_path = match config.language {
-                        Language::Korean | Language::Unknown => path::KOR_SCRIPTS_PATH,
-                    };
-                    let script = asset_bundle.get(rel_path)?.read(&ScriptDecoder)?;
-                    shared.push(Arc::new(script));
-                    return  Ok(());
-                } 
+            // (한국어) 
+            // 주어진 명령줄을 구문분석 합니다. 
+            // 잘못된 명령줄이 주어진 경우 사용 방법을 출력하고 평소처럼 진행합니다.
+            // 
+            // (English Translation) 
+            // Parses the given command line. 
+            // If a wrong command line is given, prints the usage and proceeds as usual.
+            //
+            let config = parser::parse_command_lines().unwrap_or_else(|usage| {
+                eprintln!("{}", usage);
+                parser::Config::default()
+            });
+
+            // (한국어) 
+            // 게임 진행용 난수 생성기와 여기서 분기한 소리용 난수 생성기를 등록합니다. 
+            // 시드가 주어지지 않은 경우 임의의 시드를 사용하며, 재현할 수 있도록 시드를 기록합니다.
+            // 
+            // (English Translation) 
+            // Registers the gameplay random number generator and the audio random number generator forked from it. 
+            // If no seed is given, a random seed is used, and the seed is logged so that the run can be reproduced.
+            //
+            let rng = match config.seed {
+                Some(seed) => GameRng::new(seed),
+                None => GameRng::from_entropy(),
+            };
+            log::info!("Random seed: {}", rng.seed());
+            shared.push(Arc::new(CosmeticRng::new(&rng)));
+            shared.push(Arc::new(rng));
+            if let Some(next_scene) = config.next_scene {
+                // (한국어) 스테이지가 지정된 경우 해당 캐릭터를 공유 객체에 등록합니다.
+                // (English Translation) If a stage is specified, registers its actor in the shared object.
+                if let parser::StartScene::InGame(actor) = next_scene {
+                    shared.push(actor);
+                }
+
+                // (한국어) 다음 장면이 설정되어 있는 경우 다음 장면으로 변경합니다.
+                // (English Translation) If the next scene is set, change to the next scene.
+                *shared.get_mut().unwrap() = SceneState::Change(next_scene.create());
+                
+                // (한국어) 설정된 언어의 스크립트 파일을 불러옵니다.
+                // (English Translation) Loads the script file of the set language.
+                let asset_bundle = shared.get::<AssetBundle>().unwrap();
+                let rel_path = match config.language {
+                    Language::Korean | Language::Unknown => path::KOR_SCRIPTS_PATH,
+                };
+                let script = asset_bundle.get(rel_path)?.read(&ScriptDecoder)?;
+                script::set_window_title(shared.get::<Arc<Window>>().unwrap(), Some(&script));
+                shared.push(Arc::new(script));
+                return Ok(());
             }
 
             // (한국어) 사용할 공유 객체 가져오기.
@@ -180,6 +232,7 @@ impl SceneNode for SetupScene {
             // (English Translation) Sets the next game scene.
             *shared.get_mut::<SceneState>().unwrap() = SceneState::Change(match settings.language {
                 Language::Unknown => Box::new(FirstTimeSetupLoading::default()),
+                _ if settings.skip_intro => Box::new(TitleLoading::default()), 
                 _ => Box::new(IntroLoading::default()),
             });
         }
@@ -227,6 +280,21 @@ fn setup_fonts(asset_bundle: &AssetBundle) -> AppResult<Arc<HashMap<String, Font
 }
 
 
+/// #### 한국어 </br>
+/// 글리프가 없는 문자를 그릴 때 사용할 대체 폰트 목록을 설정합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Sets the list of fallback fonts used to draw characters with missing glyphs. </br>
+/// 
+fn setup_font_fallback(fonts: &HashMap<String, FontArc>) -> Arc<FontFallback> {
+    return FontFallback::new([
+        path::NEXON_LV2_GOTHIC_MEDIUM_PATH, 
+        path::NEXON_LV2_GOTHIC_PATH, 
+        path::NEXON_LV2_GOTHIC_BOLD_PATH, 
+    ].into_iter().filter_map(|rel_path| fonts.get(rel_path).cloned())).into();
+}
+
+
 /// #### 한국어 </br>
 /// 사용자 인터페이스 그리기 도구를 설정합니다. </br>
 /// 
@@ -267,7 +335,9 @@ fn setup_text_brush(
     device: &wgpu::Device,
     camera_layout: &wgpu::BindGroupLayout,
     render_format: wgpu::TextureFormat,
-    asset_bundle: &AssetBundle
+    asset_bundle: &AssetBundle, 
+    text_scale: TextScale, 
+    font_fallback: Arc<FontFallback>
 ) -> AppResult<Arc<TextBrush>> {
     TextBrush::new(
         device, 
@@ -283,6 +353,8 @@ fn setup_text_brush(
         wgpu::MultisampleState::default(), 
         None,
         asset_bundle,
+        text_scale,
+        font_fallback,
     )
 }
 
@@ -319,6 +391,29 @@ fn setup_sprite_brush(
 }
 
 
+/// #### 한국어 </br>
+/// 블룸 후처리의 렌더링 파이프라인을 설정합니다. </br>
+/// 어뎁터가 블룸을 지원하지 않는 경우 `None`을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Sets the rendering pipelines of the bloom post-process. </br>
+/// Returns `None` if the adapter does not support bloom. </br>
+/// 
+fn setup_bloom_pipeline(
+    device: &wgpu::Device, 
+    adapter: &wgpu::Adapter, 
+    render_format: wgpu::TextureFormat, 
+    asset_bundle: &AssetBundle
+) -> AppResult<Option<Arc<BloomPipeline>>> {
+    if !bloom::is_supported(&adapter.get_info()) {
+        log::info!("Bloom is disabled because the adapter does not support it.");
+        return Ok(None);
+    }
+
+    return BloomPipeline::new(device, render_format, asset_bundle).map(Some);
+}
+
+
 /// #### 한국어 </br>
 /// 텍스처 캐시를 설정합니다. </br>
 /// 
@@ -328,8 +423,13 @@ fn setup_sprite_brush(
 fn setup_texture_map(
     device: &wgpu::Device, 
     queue: &wgpu::Queue, 
-    asset_bundle: &AssetBundle
+    asset_bundle: &AssetBundle, 
+    quality: TextureQuality
 ) -> AppResult<Arc<HashMap<String, wgpu::Texture>>> {
+    // (한국어) 설정된 텍스처 품질에 따라 불러올 텍스처의 최대 크기를 설정합니다.
+    // (English Translation) Sets the maximum size of textures to load according to the configured texture quality.
+    texture::set_texture_size_limit(quality.max_texture_dimension());
+
     // (한국어) 더미 텍스처를 생성합니다.
     // (English Translation) Create a dummy texture.
     let dummy = asset_bundle.get(path::DUMMY_TEXTURE_PATH)?
@@ -470,6 +570,29 @@ fn setup_texture_map(
             queue
         })?;
 
+    // (한국어) 게임 커서 텍스처들을 생성합니다.
+    // (English Translation) Create the game cursor textures.
+    let cursor_normal = asset_bundle.get(path::CURSOR_NORMAL_TEXTURE_PATH)?
+        .read(&ImageTextureDecoder {
+            name: Some("CursorNormal"), 
+            format: wgpu::TextureFormat::Rgba8Unorm, 
+            mipmaps: false, 
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST, 
+            view_formats: &[], 
+            device, 
+            queue
+        })?;
+    let cursor_hover = asset_bundle.get(path::CURSOR_HOVER_TEXTURE_PATH)?
+        .read(&ImageTextureDecoder {
+            name: Some("CursorHover"), 
+            format: wgpu::TextureFormat::Rgba8Unorm, 
+            mipmaps: false, 
+            usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST, 
+            view_formats: &[], 
+            device, 
+            queue
+        })?;
+
     // (한국어) 사용완료한 에셋을 해제합니다.
     // (English Translation)  Release assets that have been used. 
     asset_bundle.release(path::DUMMY_TEXTURE_PATH);
@@ -479,6 +602,8 @@ fn setup_texture_map(
     asset_bundle.release(path::MIDORI_IMG_TEXTURE_PATH);
     asset_bundle.release(path::YUZU_IMG_TEXTURE_PATH);
     asset_bundle.release(path::YUUKA_IMG_TEXTURE_PATH);
+    asset_bundle.release(path::CURSOR_NORMAL_TEXTURE_PATH);
+    asset_bundle.release(path::CURSOR_HOVER_TEXTURE_PATH);
 
     return Ok(HashMap::from_iter([
         (path::DUMMY_TEXTURE_PATH.to_string(), dummy), 
@@ -488,24 +613,30 @@ fn setup_texture_map(
         (path::MIDORI_IMG_TEXTURE_PATH.to_string(), midori_img),
         (path::YUZU_IMG_TEXTURE_PATH.to_string(), yuzu_img),
         (path::YUUKA_IMG_TEXTURE_PATH.to_string(), yuuka_img),
+        (path::CURSOR_NORMAL_TEXTURE_PATH.to_string(), cursor_normal),
+        (path::CURSOR_HOVER_TEXTURE_PATH.to_string(), cursor_hover),
     ]).into());
 }
 
-
 /// #### 한국어 </br>
-/// 게임에서 사용할 사운드 엔진을 설정합니다. </br>
+/// 텍스처 캐시와 공용 텍스처 샘플러로 게임 커서를 설정합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Set the sound engine to use in the game. </br>
+/// Set up the game cursor with the texture cache and the shared texture sampler. </br>
 /// 
-fn setup_sound_engine() -> AppResult<(OutputStream, OutputStreamHandle)> {
-    let (stream, handle) = OutputStream::try_default()
-        .map_err(|err| game_err!(
-            "Sound engine initialization failed",
-            "Sound engine initialization failed for following reasons: {}",
-            err.to_string()
-        ))?;
-    return Ok((stream, handle));
+fn setup_custom_cursor(shared: &Shared) -> CustomCursor {
+    // (한국어) 사용할 공유 객체 가져오기.
+    // (English Translation) Get shared object to use.
+    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
+    let tex_sampler = shared.get::<Arc<wgpu::Sampler>>().unwrap();
+    let ui_brush = shared.get::<Arc<UiBrush>>().unwrap();
+    let textures = shared.get::<Arc<HashMap<String, wgpu::Texture>>>().unwrap();
+
+    let normal_view = textures.get(path::CURSOR_NORMAL_TEXTURE_PATH).unwrap()
+        .create_view(&wgpu::TextureViewDescriptor::default());
+    let hover_view = textures.get(path::CURSOR_HOVER_TEXTURE_PATH).unwrap()
+        .create_view(&wgpu::TextureViewDescriptor::default());
+    return CustomCursor::new(device, tex_sampler, &normal_view, &hover_view, ui_brush);
 }
 
 
@@ -533,11 +664,9 @@ fn setup_window(window: &Window, asset_bundle: &AssetBundle) -> AppResult<(Setti
     // (한국어) 애플리케이션 윈도우를 설정합니다.
     // (English Translation) Set the application window.
     settings.resolution = set_window_size(window, settings.resolution)?;
-    window.set_title(match settings.language {
-        Language::Unknown => "Select a language",
-        _ => "Millennium Run",
-    });
+    script::set_window_title(window, script.as_ref());
     window.set_visible(true);
+    window.set_cursor_visible(!settings.custom_cursor);
 
     // (한국어) 설정 파일을 갱신합니다.
     // (English Translation) Updates the settings file.
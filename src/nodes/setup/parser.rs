@@ -1,7 +1,10 @@
 use std::env;
 
 use crate::{
-    components::user::Language,
+    components::{
+        user::Language,
+        player::Actor,
+    },
     nodes::{
         first_time::FirstTimeSetupLoading,
         intro::IntroLoading,
@@ -16,35 +19,76 @@ const USAGE: &'static str = r#"
 Usage: [PROGRAM_PATH] <OPTIONS>
 
 Options
+    --dev : Enables the options below in release builds. Without it, release builds ignore the command line.
     -s <SCENE>, --scene <SCENE> : Specify the starting game scene. If no language is specified at this time, 'Korean' will be displayed.
+    -a <ACTOR>, --stage <ACTOR> : Start the stage of the given actor.
     -l <LANGUAGE>, --language <LANGUAGE> : Specify the language used. This option does not affect the user settings file.
+    --seed <SEED> : Seeds the random number generator with the given unsigned integer to reproduce a run.
 
 Scenes
-    FirstTimeSetup, Intro, Title, InGame
+    Title
+    FirstTimeSetup, Intro, InGame (debug builds only)
+
+Actors
+    Aris, Momoi, Midori, Yuzu
 
 Languages
     Korean
 "#;
 
 
+/// #### 한국어 </br>
+/// 명령줄로 지정할 수 있는 시작 게임 장면 목록입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// List of starting game scenes that can be specified on the command line. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StartScene {
+    FirstTimeSetup,
+    Intro,
+    Title,
+    InGame(Actor),
+}
+
+impl StartScene {
+    /// #### 한국어 </br>
+    /// 시작 게임 장면을 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates the starting game scene. </br>
+    /// 
+    pub fn create(self) -> Box<dyn SceneNode> {
+        match self {
+            Self::FirstTimeSetup => Box::new(FirstTimeSetupLoading::default()),
+            Self::Intro => Box::new(IntroLoading::default()),
+            Self::Title => Box::new(TitleLoading::default()),
+            Self::InGame(_) => Box::new(InGameLoading::default()),
+        }
+    }
+}
+
+
 /// #### 한국어 </br>
 /// 주어진 명령줄의 구문분석 결과를 담고 있습니다. </br>
 /// 
 /// #### English (Translation) </br>
 /// It contains the results of the syntax analysis of the given command line. </br>
 /// 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Config {
-    pub next_scene: Option<Box<dyn SceneNode>>,
+    pub next_scene: Option<StartScene>,
     pub language: Language,
+    pub seed: Option<u64>,
 }
 
 impl Default for Config {
     #[inline]
     fn default() -> Self {
-        Self { 
+        Self {
             next_scene: None,
             language: Language::Korean,
+            seed: None,
         }
     }
 }
@@ -52,39 +96,61 @@ impl Default for Config {
 
 /// #### 한국어 </br>
 /// 주어진 명령줄을 구문분석 합니다. </br>
-/// <b>잘못된 명령줄이 주어졌을 경우 프로그램 실행을 중단합니다.</b></br>
+/// 릴리즈 빌드에서는 `--dev` 옵션이 주어진 경우에만 안전한 일부 옵션을 허용합니다. </br>
+/// <b>잘못된 명령줄이 주어졌을 경우 사용 방법이 담긴 메시지를 반환합니다.</b></br>
 /// 
 /// #### English (Translation)
 /// The syntax analysis of the given command line. </br>
-/// <b>If a wrong command line is given, it will stop running the program.<b></br>
+/// Release builds only allow a safe subset of options when the `--dev` option is given. </br>
+/// <b>If a wrong command line is given, it returns a message containing the usage.</b></br>
 /// 
-pub fn parse_command_lines() -> Config {
+pub fn parse_command_lines() -> Result<Config, String> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    return parse(&args, !cfg!(debug_assertions));
+}
+
+
+/// #### 한국어 </br>
+/// 명령줄 인자를 구문분석 합니다. </br>
+/// `restricted`가 `true`인 경우 `--dev` 옵션이 없으면 모든 인자를 무시하고,
+/// 시작 장면은 `Title`과 스테이지만 허용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Parses the command line arguments. </br>
+/// If `restricted` is `true`, all arguments are ignored without the `--dev` option,
+/// and only `Title` and stages are allowed as the starting scene. </br>
+/// 
+fn parse(args: &[String], restricted: bool) -> Result<Config, String> {
     let mut config = Config::default();
-    let args: Vec<String> = env::args().collect();
-    
-    if args.len() > 1 {
-        let mut iter = args.iter().skip(1);
-        while let Some(arg) = iter.next() {
-            match arg.as_str() {
-                "-s" | "--scene" => parse_scene_option(&mut iter, &mut config),
-                "-l" | "--language" => parse_language_option(&mut iter, &mut config),
-                _ => help(),
-            }
+    if restricted && !args.iter().any(|arg| arg == "--dev") {
+        return Ok(config);
+    }
+
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--dev" => { /* empty */ },
+            "-s" | "--scene" => parse_scene_option(&mut iter, &mut config, restricted)?,
+            "-a" | "--stage" => parse_stage_option(&mut iter, &mut config)?,
+            "-l" | "--language" => parse_language_option(&mut iter, &mut config)?,
+            "--seed" => parse_seed_option(&mut iter, &mut config)?,
+            _ => return Err(help(&format!("Unknown option: {}", arg))),
         }
     }
 
-    return config;
+    return Ok(config);
 }
 
 
 /// #### 한국어 </br>
-/// 프로그램 사용 방법을 출력하고 프로그램 실행을 중단시킵니다. </br>
+/// 오류 메시지와 프로그램 사용 방법을 담은 문자열을 만듭니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Prints out how to use the program and aborts the program running.
+/// Makes a string containing the error message and how to use the program. </br>
+/// 
 #[inline]
-fn help() -> ! {
-    panic!("{}", USAGE)
+fn help(msg: &str) -> String {
+    format!("{}\n{}", msg, USAGE)
 }
 
 
@@ -94,19 +160,37 @@ fn help() -> ! {
 /// #### English (Translation) </br>
 /// Parses scene options. </br>
 /// 
-fn parse_scene_option<'a, I>(iter: &mut I, config: &mut Config)
+fn parse_scene_option<'a, I>(iter: &mut I, config: &mut Config, restricted: bool) -> Result<(), String>
 where I: Iterator<Item = &'a String> {
-    if let Some(arg)= iter.next() {
-        match arg.as_str() {
-            "FirstTimeSetup" => config.next_scene = Some(Box::new(FirstTimeSetupLoading::default())),
-            "Intro" => config.next_scene = Some(Box::new(IntroLoading::default())),
-            "Title" => config.next_scene = Some(Box::new(TitleLoading::default())),
-            "InGame" => config.next_scene = Some(Box::new(InGameLoading::default())),
-            _ => help()
-        }
-    } else {
-        help()
-    }
+    let arg = iter.next().ok_or_else(|| help("Missing scene name."))?;
+    config.next_scene = Some(match arg.as_str() {
+        "Title" => StartScene::Title,
+        "FirstTimeSetup" if !restricted => StartScene::FirstTimeSetup,
+        "Intro" if !restricted => StartScene::Intro,
+        "InGame" if !restricted => StartScene::InGame(Actor::default()),
+        _ => return Err(help(&format!("Unknown or unavailable scene: {}", arg))),
+    });
+    Ok(())
+}
+
+
+/// #### 한국어 </br>
+/// 스테이지 옵션을 구문분석 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Parses stage options. </br>
+/// 
+fn parse_stage_option<'a, I>(iter: &mut I, config: &mut Config) -> Result<(), String>
+where I: Iterator<Item = &'a String> {
+    let arg = iter.next().ok_or_else(|| help("Missing actor name."))?;
+    config.next_scene = Some(StartScene::InGame(match arg.as_str() {
+        "Aris" => Actor::Aris,
+        "Momoi" => Actor::Momoi,
+        "Midori" => Actor::Midori,
+        "Yuzu" => Actor::Yuzu,
+        _ => return Err(help(&format!("Unknown actor: {}", arg))),
+    }));
+    Ok(())
 }
 
 
@@ -116,14 +200,56 @@ where I: Iterator<Item = &'a String> {
 /// #### English (Translation) </br>
 /// Parses language options. </br>
 /// 
-fn parse_language_option<'a, I>(iter: &mut I, config: &mut Config) 
+fn parse_language_option<'a, I>(iter: &mut I, config: &mut Config) -> Result<(), String>
 where I: Iterator<Item = &'a String> {
-    if let Some(arg) = iter.next() {
-        match arg.as_str() {
-            "Korean" => config.language = Language::Korean,
-            _ => help()
-        }
-    } else {
-        help()
+    let arg = iter.next().ok_or_else(|| help("Missing language name."))?;
+    config.language = match arg.as_str() {
+        "Korean" => Language::Korean,
+        _ => return Err(help(&format!("Unknown language: {}", arg))),
+    };
+    Ok(())
+}
+
+
+/// #### 한국어 </br>
+/// 난수 시드 옵션을 구문분석 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Parses random seed options. </br>
+/// 
+fn parse_seed_option<'a, I>(iter: &mut I, config: &mut Config) -> Result<(), String>
+where I: Iterator<Item = &'a String> {
+    let arg = iter.next().ok_or_else(|| help("Missing seed."))?;
+    config.seed = Some(arg.parse().map_err(|_| help(&format!("Invalid seed: {}", arg)))?);
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn release_requires_dev_flag_and_safe_scenes() {
+        assert_eq!(parse(&args("-s Title"), true), Ok(Config::default()));
+        assert_eq!(
+            parse(&args("--dev --stage Midori"), true).unwrap().next_scene,
+            Some(StartScene::InGame(Actor::Midori))
+        );
+        assert!(parse(&args("--dev -s Intro"), true).is_err());
+        assert!(parse(&args("-s Intro"), false).is_ok());
+        assert!(parse(&args("--dev -s Credit"), true).is_err());
+    }
+
+    #[test]
+    fn seed_option_is_parsed() {
+        assert_eq!(parse(&args("--dev --seed 1884"), true).unwrap().seed, Some(1884));
+        assert_eq!(parse(&args("--seed 1884"), true).unwrap().seed, None);
+        assert!(parse(&args("--seed"), false).is_err());
+        assert!(parse(&args("--seed -1"), false).is_err());
     }
 }
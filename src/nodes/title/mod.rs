@@ -7,34 +7,35 @@ use std::collections::HashMap;
 
 use ab_glyph::FontArc;
 use winit::event::Event;
-use rodio::{OutputStreamHandle, Source, Sink};
 
 use crate::{
-    game_err,
     assets::bundle::AssetBundle,
     components::{
-        ui::{UiBrush, UiObject},
+        ui::{UiBrush, UiObject, Slider, ModalWindow, DimOverlay},
+        focus::FocusRing,
         text::{TextBrush, Text, TextBuilder},
         sprite::{Sprite, SpriteBrush},
         collider2d::shape::AABB,
         anchor::Anchor, margin::Margin, 
         camera::{CameraCreator, GameCamera},
+        cursor, 
         transform::Projection, 
-        sound::SoundDecoder,
         script::Script,
-        user::{Language, Resolution, Settings},
+        user::{Language, Resolution, Settings, SettingToggle},
         player::Actor, 
         save::SaveData, 
     },
-    render::depth::DepthBuffer, 
+    render::{adapter::AdapterWarning, frame::with_frame}, 
     nodes::{
         path, 
         consts::PIXEL_PER_METER, 
+        preload::{self, RequiredAssets}, 
+        intro::IntroLoading, 
         title::state::TitleState, 
     }, 
-    scene::{node::SceneNode, state::SceneState},
+    scene::{back_stack::BackStack, idle::{is_user_input, IdleTimer}, node::SceneNode, state::{ignores_button_input, is_scene_changing, state_fn, SceneState}},
     system::{
-        error::{AppResult, GameError},
+        error::{join_thread, AppResult},
         event::AppEvent,
         shared::Shared,
     },
@@ -42,6 +43,35 @@ use crate::{
 
 
 
+/// #### 한국어 </br>
+/// `Title` 게임 장면에서 사용하는 에셋 목록입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// List of assets used in the `Title` game scene. </br>
+/// 
+const REQUIRED_ASSETS: [&'static str; 18] = [
+    path::CLICK_SOUND_PATH, 
+    path::CANCEL_SOUND_PATH, 
+    path::YUUKA_TITLE_SOUND_PATH, 
+    path::YUUKA_HIDDEN_SOUND_PATH, 
+    path::STAR_TEXTURE_PATH, 
+    path::BUTTON_WIDE_TEXTURE_PATH, 
+    path::BUTTON_MEDIUM_TEXTURE_PATH, 
+    path::BUTTON_RETURN_TEXTURE_PATH, 
+    path::TITLE_BUTTON_START_TEXTURE_PATH, 
+    path::TITLE_BUTTON_SETTING_TEXTURE_PATH, 
+    path::TITLE_BUTTON_EXIT_TEXTURE_PATH, 
+    path::TITLE_BACKGROUND_TEXTURE_PATH, 
+    path::WINDOW_RATIO_4_3_TEXTURE_PATH, 
+    path::WINDOW_RATIO_8_1_TEXTURE_PATH, 
+    path::ARIS_STANDING_TEXTURE_PATH, 
+    path::MOMOI_STANDING_TEXTURE_PATH, 
+    path::MIDORI_STANDING_TEXTURE_PATH, 
+    path::YUZU_STANDING_TEXTURE_PATH, 
+];
+
+
+
 /// #### 한국어 </br>
 /// `Title` 게임 장면을 준비하는 게임 장면 입니다. </br>
 /// 
@@ -65,6 +95,13 @@ impl TitleLoading {
     }
 }
 
+impl RequiredAssets for TitleLoading {
+    #[inline]
+    fn required_assets() -> &'static [&'static str] {
+        &REQUIRED_ASSETS
+    }
+}
+
 impl SceneNode for TitleLoading {
     fn enter(&mut self, shared: &mut Shared) -> AppResult<()> {
         // (한국어) 사용할 공유 객체를 가져옵니다.
@@ -81,28 +118,12 @@ impl SceneNode for TitleLoading {
         let sprite_brush = shared.get::<Arc<SpriteBrush>>().unwrap().clone();
         let texture_map = shared.get::<Arc<HashMap<String, wgpu::Texture>>>().unwrap().clone();
         let asset_bundle = shared.get::<AssetBundle>().unwrap().clone();
+        let show_gpu_warning = shared.get::<AdapterWarning>().is_some() && !settings.suppress_gpu_warning;
 
         self.loading = Some(thread::spawn(move || {
             // (한국어) 현재 장면에서 사용할 에셋들을 불러옵니다. 
             // (English Translation) Loads assets to be used in the current game scene. 
-            asset_bundle.get(path::CLICK_SOUND_PATH)?;
-            asset_bundle.get(path::CANCEL_SOUND_PATH)?;
-            asset_bundle.get(path::YUUKA_TITLE_SOUND_PATH)?;
-            asset_bundle.get(path::YUUKA_HIDDEN_SOUND_PATH)?;
-            asset_bundle.get(path::STAR_TEXTURE_PATH)?;
-            asset_bundle.get(path::BUTTON_WIDE_TEXTURE_PATH)?;
-            asset_bundle.get(path::BUTTON_MEDIUM_TEXTURE_PATH)?;
-            asset_bundle.get(path::BUTTON_RETURN_TEXTURE_PATH)?;
-            asset_bundle.get(path::TITLE_BUTTON_START_TEXTURE_PATH)?;
-            asset_bundle.get(path::TITLE_BUTTON_SETTING_TEXTURE_PATH)?;
-            asset_bundle.get(path::TITLE_BUTTON_EXIT_TEXTURE_PATH)?;
-            asset_bundle.get(path::TITLE_BACKGROUND_TEXTURE_PATH)?;
-            asset_bundle.get(path::WINDOW_RATIO_4_3_TEXTURE_PATH)?;
-            asset_bundle.get(path::WINDOW_RATIO_8_1_TEXTURE_PATH)?;
-            asset_bundle.get(path::ARIS_STANDING_TEXTURE_PATH)?;
-            asset_bundle.get(path::MOMOI_STANDING_TEXTURE_PATH)?;
-            asset_bundle.get(path::MIDORI_STANDING_TEXTURE_PATH)?;
-            asset_bundle.get(path::YUZU_STANDING_TEXTURE_PATH)?;
+            preload::preload_scene::<Self>(&asset_bundle)?;
 
             let nexon_lv2_gothic_medium = fonts.get(path::NEXON_LV2_GOTHIC_MEDIUM_PATH)
                 .expect("A registered font could not be found.");
@@ -122,7 +143,8 @@ impl SceneNode for TitleLoading {
                 &text_brush, 
                 &sprite_brush, 
                 &texture_map, 
-                &asset_bundle
+                &asset_bundle, 
+                show_gpu_warning
             )
         }));
 
@@ -192,9 +214,10 @@ impl SceneNode for TitleLoading {
         let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
 
         if self.loading.as_ref().is_some_and(|it| it.is_finished()) {
-            let mut next_scene = self.loading.take().unwrap().join().unwrap()?;
+            let mut next_scene = join_thread(self.loading.take().unwrap(), "loading")?;
             if self.actor.is_some() {
                 next_scene.state = TitleState::ReturnStage;
+                next_scene.back_stack.push(TitleState::Stage);
                 next_scene.foreground.update(queue, |data| {
                     data.color = (0.0, 0.0, 0.0, 1.0).into();
                 });
@@ -212,77 +235,29 @@ impl SceneNode for TitleLoading {
     fn draw(&self, shared: &mut Shared) -> AppResult<()> {
         // (한국어) 사용할 공유 객체 가져오기.
         // (English Translation) Get shared object to use.
-        let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-        let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-        let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-        let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
         let camera = shared.get::<Arc<GameCamera>>().unwrap();
         let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
 
-        // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-        // (English Translation) Wait until the previous operation is finished.
-        device.poll(wgpu::Maintain::Wait);
-
-        // (한국어) 다음 프레임을 가져옵니다.
-        // (English Translation) Get the next frame.
-        let frame = surface.get_current_texture()
-            .map_err(|err| game_err!(
-                "Failed to get next frame",
-                "Failed to get next frame for the following reasons: {}",
-                err.to_string()
-            ))?;
-
-        // (한국어) 프레임 버퍼의 텍스쳐 뷰를 생성합니다.
-        // (English Translation) Creates a texture view of the framebuffer.
-        let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-        // (한국어) 커맨드 버퍼를 생성합니다.
-        // (English Translation) Creates a command buffer.
-        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-
-        {
-            let mut rpass = encoder.begin_render_pass(
-                &wgpu::RenderPassDescriptor {
-                    label: Some("RenderPass(TitleLoading)"),
-                    color_attachments: &[
-                        Some(wgpu::RenderPassColorAttachment { 
-                            view: &view, 
-                            resolve_target: None, 
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Clear(if self.actor.is_none() { 
-                                    wgpu::Color::WHITE 
-                                } else {
-                                    wgpu::Color::BLACK
-                                }),
-                                store: wgpu::StoreOp::Store,
-                            }, 
-                        }),
-                    ],
-                    depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment { 
-                        view: depth.view(), 
-                        depth_ops: Some(wgpu::Operations {
-                            load: wgpu::LoadOp::Clear(1.0), 
-                            store: wgpu::StoreOp::Discard
-                        }), 
-                        stencil_ops: None 
-                    }),
-                    timestamp_writes: None,
-                    occlusion_query_set: None,
-                }
-            );
-
-            camera.bind(&mut rpass);
-            text_brush.draw(&mut rpass, [
-                self.loading_text.as_ref().unwrap()
-            ].into_iter());
-        }
+        with_frame(shared, self.clear_color(), |frame| {
+            {
+                let mut rpass = frame.begin_pass("RenderPass(TitleLoading)");
 
-        // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-        // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-        queue.submit(Some(encoder.finish()));
-        frame.present();
+                camera.bind(&mut rpass);
+                text_brush.draw(&mut rpass, [
+                    self.loading_text.as_ref().unwrap()
+                ].into_iter());
+            }
+        })
+    }
 
-        Ok(())
+    #[inline]
+    fn clear_color(&self) -> wgpu::Color {
+        // (한국어) 인트로 장면에서 넘어온 경우 인트로 장면의 배경색을 이어서 사용합니다.
+        // (English Translation) When coming from the intro scene, keeps using the intro scene's background color.
+        match self.actor {
+            Some(_) => wgpu::Color::BLACK,
+            None => wgpu::Color::WHITE,
+        }
     }
 }
 
@@ -309,27 +284,50 @@ impl Default for TitleLoading {
 pub struct TitleScene {
     pub timer: f64,
     pub state: state::TitleState,
+    pub back_stack: BackStack<state::TitleState>, 
+    pub idle_timer: IdleTimer, 
 
     pub foreground: UiObject, 
+    pub dim_overlay: DimOverlay, 
     pub background: Sprite,
 
     pub sprites: Vec<(Sprite, AABB)>,
     pub menu_buttons: Vec<(UiObject, Text)>,
+    pub menu_focus: FocusRing<utils::MenuButtons>,
+    pub continue_actor: Option<Actor>, 
     pub return_button: UiObject,
     
     pub exit_msg_box: Vec<(UiObject, Text)>,
+    pub exit_msg_focus: FocusRing<utils::ExitMessageBox>,
 
     pub stage_window: UiObject,
     pub stage_enter_button: (UiObject, Text), 
     pub stage_images: HashMap<Actor, (UiObject, UiObject, Text)>, 
+    pub stage_locks: HashMap<Actor, UiObject>, 
+    pub selected_actor: Actor, 
     
     pub setting_titles: Vec<Text>, 
     pub setting_windows: Vec<UiObject>, 
     pub setting_languages: HashMap<Language, (UiObject, Text)>, 
     pub setting_resolutions: HashMap<Resolution, (UiObject, Text)>, 
+    pub setting_toggles: HashMap<SettingToggle, (UiObject, Text)>, 
     pub setting_return_button: (UiObject, Text), 
+    pub setting_apply_button: (UiObject, Text), 
+    pub setting_reset_button: (UiObject, Text), 
+    pub setting_reset_window: ModalWindow<utils::ResetWndButton>, 
     pub setting_volume_background: HashMap<utils::VolumeOptions, (UiObject, Text)>,
-    pub setting_volume_bar: HashMap<utils::VolumeOptions, UiObject>, 
+    pub setting_volume_bar: HashMap<utils::VolumeOptions, Slider>, 
+    pub setting_revert_window: (UiObject, Text), 
+    pub setting_revert_countdown: Text, 
+    pub setting_revert_buttons: HashMap<utils::RevertWndButton, (UiObject, Text)>, 
+    pub setting_revert: Option<(Resolution, f64)>, 
+
+    pub gpu_warning: Option<Text>, 
+}
+
+#[cfg(test)]
+impl crate::scene::state::SceneStates for TitleScene {
+    const NUM_STATES: usize = state::TitleState::NUM_STATES;
 }
 
 impl SceneNode for TitleScene {
@@ -338,25 +336,17 @@ impl SceneNode for TitleScene {
 
         // (한국어) 사용할 공유 객체를 가져옵니다.
         // (English Translation) Get shared object to use.
-        let stream = shared.get::<OutputStreamHandle>().unwrap();
+        let mut bgm = shared.pop::<sound::BgmPlayer>().unwrap();
+        let stream = sound::output(shared);
         let asset_bundle = shared.get::<AssetBundle>().unwrap();
-        let settings = shared.get::<Settings>().unwrap();
-
-        // (한국어) 배경 음악을 재생합니다.
-        // (English Translation) Play background music.
-        let source = asset_bundle.get(path::THEME64_SOUND_PATH)?
-            .read(&SoundDecoder)?
-            .amplify(0.5)
-            .repeat_infinite();
-        let sink = sound::play_sound(settings.background_volume, source, stream)?;
 
-        // (한국어) 사용을 완료한 에셋을 정리합니다.
-        // (English Translation) Release assets that have been used.
-        asset_bundle.release(path::THEME64_SOUND_PATH);
+        // (한국어) 이전 배경 음악에서 타이틀 배경 음악으로 전환합니다.
+        // (English Translation) Switches from the previous background music to the title background music.
+        bgm.crossfade_to(path::THEME64_SOUND_PATH, sound::BGM_CROSSFADE_SEC, asset_bundle, stream)?;
     
-        // (한국어) 배경 음악을 공유 객체에 등록합니다.
-        // (English Translation) Register background music to a shared object.
-        shared.push(sink);
+        // (한국어) 사용한 공유 객체를 반환합니다.
+        // (English Translation) Returns the shared object used.
+        shared.push(bgm);
 
         Ok(())
     }
@@ -365,27 +355,77 @@ impl SceneNode for TitleScene {
         // (한국어) 사용을 완료한 에셋을 정리합니다.
         // (English Translation) Release assets that have been used.
         let asset_bundle = shared.get::<AssetBundle>().unwrap();
-        asset_bundle.release(path::YUUKA_TITLE_SOUND_PATH);
-        asset_bundle.release(path::YUUKA_HIDDEN_SOUND_PATH);
-        
-        // (한국어) 배경 음악을 제거합니다.
-        // (English Translation) Detach background music.
-        shared.pop::<Sink>().unwrap().stop();
+        preload::release_scene::<TitleLoading>(asset_bundle);
         Ok(())
     }
 
     #[inline]
     fn handle_events(&mut self, shared: &mut Shared, event: Event<AppEvent>) -> AppResult<()> {
-        state::HANDLE_EVENTS[self.state as usize](self, shared, event)
+        // (한국어) 사용자의 입력이 있는 경우 입력이 없던 시간을 초기화 합니다.
+        // (English Translation) Resets the idle time if there is a user input.
+        if is_user_input(&event) {
+            self.idle_timer.reset();
+        }
+
+        // (한국어) 화면이 바뀌는 중에는 버튼 입력을 무시합니다.
+        // (English Translation) Ignores button input while the screen is transitioning.
+        let transitioning = self.state.is_transitioning() || is_scene_changing(shared);
+        if ignores_button_input(transitioning, &event) {
+            return Ok(());
+        }
+
+        state_fn(&state::HANDLE_EVENTS, self.state as usize, "HANDLE_EVENTS")?(self, shared, event)
     }
 
     #[inline]
     fn update(&mut self, shared: &mut Shared, total_time: f64, elapsed_time: f64) -> AppResult<()> {
-        state::UPDATES[self.state as usize](self, shared, total_time, elapsed_time)
+        state_fn(&state::UPDATES, self.state as usize, "UPDATES")?(self, shared, total_time, elapsed_time)?;
+
+        // (한국어) 메뉴 화면에서 입력 없이 제한 시간이 지난 경우 인트로를 다시 보여줍니다.
+        // (English Translation) If the timeout passes without input on the menu screen, shows the intro again.
+        if state::update_idle_timer(self.state, &mut self.idle_timer, elapsed_time) && !is_scene_changing(shared) {
+            log::info!("No input on the title screen. Playing the intro.");
+            *shared.get_mut::<SceneState>().unwrap() = SceneState::Change(Box::new(IntroLoading::attract()));
+        }
+
+        // (한국어) 배경을 어둡게 하는 오버레이의 애니메이션을 진행합니다.
+        // (English Translation) Advances the animation of the overlay that dims the background.
+        let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+        self.dim_overlay.update(queue, elapsed_time);
+        Ok(())
     }
 
     #[inline]
     fn draw(&self, shared: &mut Shared) -> AppResult<()> {
-        state::DRAWS[self.state as usize](self, shared)
+        state_fn(&state::DRAWS, self.state as usize, "DRAWS")?(self, shared)
+    }
+
+    fn hovers_button(&self, shared: &Shared) -> bool {
+        match self.state {
+            TitleState::Menu => cursor::is_over_any(
+                shared, 
+                self.menu_buttons.iter().map(|(ui, _)| ui)
+            ),
+            TitleState::MsgBox => cursor::is_over_any(
+                shared, 
+                self.exit_msg_box.iter()
+                    .enumerate()
+                    .filter(|(idx, _)| utils::ExitMessageBox::Background as usize != *idx)
+                    .map(|(_, (ui, _))| ui)
+            ),
+            TitleState::Stage => cursor::is_over_any(shared, [&self.return_button]),
+            TitleState::Selected => cursor::is_over_any(
+                shared, 
+                [&self.return_button, &self.stage_enter_button.0]
+            ),
+            TitleState::Setting => state::hovers_setting_button(self, shared),
+            _ => false,
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    fn on_script_reload(&mut self, shared: &mut Shared) -> AppResult<()> {
+        let script = shared.get::<Arc<Script>>().unwrap().clone();
+        state::apply_script(self, shared, &script)
     }
 }
@@ -3,7 +3,6 @@ use std::sync::Arc;
 use winit::event::Event;
 
 use crate::{
-    game_err,
     components::{
         text::{TextBrush, Text}, 
         ui::{UiBrush, UiObject},
@@ -14,9 +13,10 @@ use crate::{
         TitleScene,
         state::TitleState,
     },
-    render::depth::DepthBuffer,
+    render::frame::with_frame,
+    scene::node::SceneNode,
     system::{
-        error::{AppResult, GameError},
+        error::AppResult,
         event::AppEvent,
         shared::Shared,
     },
@@ -41,6 +41,10 @@ pub fn update(this: &mut TitleScene, shared: &mut Shared, _total_time: f64, elap
     // (English Translation) Updates the elapsed time.
     this.timer += elapsed_time;
 
+    // (한국어) 뒤의 장면을 어둡게 만듭니다.
+    // (English Translation) Dims the scene behind.
+    this.dim_overlay.show(DURATION);
+
 
     // (한국어) 사용할 공유 객체 가져오기.
     // (English Translation) Get shared object to use.
@@ -55,12 +59,15 @@ pub fn update(this: &mut TitleScene, shared: &mut Shared, _total_time: f64, elap
     let scale = 1.0 * delta;
     let iter = [
             &this.setting_return_button.0, 
+            &this.setting_apply_button.0, 
+            &this.setting_reset_button.0, 
         ].into_iter()
         .chain(this.setting_windows.iter())
         .chain(this.setting_languages.values().map(|(it, _)| it))
         .chain(this.setting_resolutions.values().map(|(it, _)| it))
+        .chain(this.setting_toggles.values().map(|(it, _)| it))
         .chain(this.setting_volume_background.values().map(|(it, _)| it))
-        .chain(this.setting_volume_bar.values());
+        .chain(this.setting_volume_bar.values().map(|it| &it.handle));
     for ui in iter {
         ui.update(queue, |data| {
             data.global_scale = (scale, scale, scale).into() 
@@ -69,10 +76,13 @@ pub fn update(this: &mut TitleScene, shared: &mut Shared, _total_time: f64, elap
 
     let iter = [
             &this.setting_return_button.1, 
+            &this.setting_apply_button.1, 
+            &this.setting_reset_button.1, 
         ].into_iter()
         .chain(this.setting_titles.iter())
         .chain(this.setting_languages.values().map(|(_, it)| it))
         .chain(this.setting_resolutions.values().map(|(_, it)| it))
+        .chain(this.setting_toggles.values().map(|(_, it)| it))
         .chain(this.setting_volume_background.values().map(|(_, it)| it));
     for text in iter {
         text.update(queue, |data| {
@@ -98,154 +108,81 @@ pub fn draw(this: &TitleScene, shared: &mut Shared) -> AppResult<()> {
     let sprite_brush = shared.get::<Arc<SpriteBrush>>().unwrap();
     let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
     let ui_brush = shared.get::<Arc<UiBrush>>().unwrap();
-    let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
     let camera = shared.get::<Arc<GameCamera>>().unwrap();
     
-    // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-    // (English Translation) Wait until the previous operation is finished.
-    device.poll(wgpu::Maintain::Wait);
-
-    // (한국어) 다음 프레임을 가져옵니다.
-    // (English Translation) Get the next frame.
-    let frame = surface.get_current_texture()
-        .map_err(|err| game_err!(
-            "Failed to get next frame",
-            "Failed to get next frame for the following reasons: {}",
-            err.to_string()
-        ))?;
-
-    // (한국어) 프레임 버퍼의 텍스쳐 뷰를 생성합니다.
-    // (English Translation) Creates a texture view of the framebuffer.
-    let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-    // (한국어) 커맨드 버퍼를 생성합니다.
-    // (English Translation) Creates a command buffer.
-    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(EnterSettingState(Background)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment { 
-                view: &view, 
-                resolve_target: None, 
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-        
-        // (한국어) 배경 오브젝트 그리기.
-        // (English Translation) Drawing background objects.
-        sprite_brush.draw(&mut rpass, [&this.background].into_iter());
-    }
-
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(SettingState(Ui)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment { 
-                view: &view, 
-                resolve_target: None, 
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Load,
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-        
-        // (한국어) 사용자 인터페이스 그리기.
-        // (English Translation) Drawing the user interface.
-        ui_brush.draw(
-            &mut rpass, 
-            this.menu_buttons.iter()
-            .map(|(ui, _)| ui)
-        );
-        text_brush.draw(
-            &mut rpass, 
-            this.menu_buttons.iter()
-            .map(|(_, it)| it)
-        );
-    }
-
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(SettingState(Ui)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment { 
-                view: &view, 
-                resolve_target: None, 
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Load,
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-        
-        // (한국어) 사용자 인터페이스 그리기.
-        // (English Translation) Drawing the user interface.
-        let iter = [
-                &this.setting_return_button.0, 
-            ].into_iter()
-            .chain(this.setting_windows.iter())
-            .chain(this.setting_languages.values().map(|(it, _)| it))
-            .chain(this.setting_resolutions.values().map(|(it, _)| it))
-            .chain(this.setting_volume_background.values().map(|(it, _)| it))
-            .chain(this.setting_volume_bar.values());
-        ui_brush.draw(&mut rpass, iter);
-
-        let iter = [
-                &this.setting_return_button.1, 
-            ].into_iter()
-            .chain(this.setting_titles.iter())
-            .chain(this.setting_languages.values().map(|(_, it)| it))
-            .chain(this.setting_resolutions.values().map(|(_, it)| it))
-            .chain(this.setting_volume_background.values().map(|(_, it)| it));
-        text_brush.draw(&mut rpass, iter);
-    }
-
-    // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-    // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-    queue.submit(Some(encoder.finish()));
-    frame.present();
-    
-    Ok(())
+    with_frame(shared, this.clear_color(), |frame| {
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(EnterSettingState(Background)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 배경 오브젝트 그리기.
+            // (English Translation) Drawing background objects.
+            sprite_brush.draw(&mut rpass, [&this.background].into_iter());
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(SettingState(Ui)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 사용자 인터페이스 그리기.
+            // (English Translation) Drawing the user interface.
+            ui_brush.draw(
+                &mut rpass, 
+                this.menu_buttons.iter()
+                .map(|(ui, _)| ui)
+            );
+            text_brush.draw(
+                &mut rpass, 
+                this.menu_buttons.iter()
+                .map(|(_, it)| it)
+            );
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(EnterSettingState(Dim)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 뒤의 장면을 어둡게 하는 오버레이 그리기.
+            // (English Translation) Drawing the overlay that dims the scene behind.
+            this.dim_overlay.draw(ui_brush, &mut rpass);
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(SettingState(Ui)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 사용자 인터페이스 그리기.
+            // (English Translation) Drawing the user interface.
+            let iter = [
+                    &this.setting_return_button.0, 
+                    &this.setting_apply_button.0, 
+                    &this.setting_reset_button.0, 
+                ].into_iter()
+                .chain(this.setting_windows.iter())
+                .chain(this.setting_languages.values().map(|(it, _)| it))
+                .chain(this.setting_resolutions.values().map(|(it, _)| it))
+                .chain(this.setting_toggles.values().map(|(it, _)| it))
+                .chain(this.setting_volume_background.values().map(|(it, _)| it))
+                .chain(this.setting_volume_bar.values().map(|it| &it.handle));
+            ui_brush.draw(&mut rpass, iter);
+
+            let iter = [
+                    &this.setting_return_button.1, 
+                    &this.setting_apply_button.1, 
+                    &this.setting_reset_button.1, 
+                ].into_iter()
+                .chain(this.setting_titles.iter())
+                .chain(this.setting_languages.values().map(|(_, it)| it))
+                .chain(this.setting_resolutions.values().map(|(_, it)| it))
+                .chain(this.setting_toggles.values().map(|(_, it)| it))
+                .chain(this.setting_volume_background.values().map(|(_, it)| it));
+            text_brush.draw(&mut rpass, iter);
+        }
+    })
 }
 
 
@@ -3,20 +3,17 @@ use std::sync::Arc;
 use winit::event::Event;
 
 use crate::{
-    game_err,
     components::{
         text::{TextBrush, Text}, 
         ui::{UiBrush, UiObject},
         camera::GameCamera,
         sprite::SpriteBrush,
     },
-    nodes::title::{
-        TitleScene,
-        state::TitleState,
-    }, 
-    render::depth::DepthBuffer,
+    nodes::title::TitleScene,
+    render::frame::with_frame,
+    scene::node::SceneNode,
     system::{
-        error::{AppResult, GameError}, 
+        error::AppResult, 
         event::AppEvent,
         shared::Shared, 
     }
@@ -42,6 +39,10 @@ pub fn update(this: &mut TitleScene, shared: &mut Shared, _total_time: f64, elap
     // (English Translation) Updates the elapsed time.
     this.timer += elapsed_time;
 
+    // (한국어) 뒤의 장면을 원래대로 밝게 만듭니다.
+    // (English Translation) Brightens the scene behind back.
+    this.dim_overlay.hide(DURATION);
+
     // (한국어) 사용할 공유 객체 가져오기.
     // (English Translation) Get shared object to use.
     let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
@@ -57,7 +58,7 @@ pub fn update(this: &mut TitleScene, shared: &mut Shared, _total_time: f64, elap
     // (한국어) 지속 시간보다 클 경우 다음 상태로 변경합니다.
     // (English Translation) Changes to the next state if it is greater than the duration. 
     if this.timer >= DURATION {
-        this.state = TitleState::Menu;
+        this.state = this.back_stack.current();
         this.timer = 0.0;
         return Ok(());
     }
@@ -71,147 +72,68 @@ pub fn draw(this: &TitleScene, shared: &mut Shared) -> AppResult<()> {
     let sprite_brush = shared.get::<Arc<SpriteBrush>>().unwrap();
     let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
     let ui_brush = shared.get::<Arc<UiBrush>>().unwrap();
-    let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
     let camera = shared.get::<Arc<GameCamera>>().unwrap();
 
 
-    // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-    // (English Translation) Wait until the previous operation is finished.
-    device.poll(wgpu::Maintain::Wait);
-
-    // (한국어) 다음 프레임을 가져옵니다.
-    // (English Translation) Get the next frame.
-    let frame = surface.get_current_texture()
-        .map_err(|err| game_err!(
-            "Failed to get next frame",
-            "Failed to get next frame for the following reasons: {}",
-            err.to_string()
-        ))?;
-
-    // (한국어) 프레임 버퍼의 텍스쳐 뷰를 생성합니다.
-    // (English Translation) Creates a texture view of the framebuffer.
-    let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-    // (한국어) 커맨드 버퍼를 생성합니다.
-    // (English Translation) Creates a command buffer.
-    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(ExitMsgBoxState(Background)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment { 
-                view: &view, 
-                resolve_target: None, 
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-        
-        // (한국어) 배경 오브젝트 그리기.
-        // (English Translation) Drawing background objects.
-        sprite_brush.draw(&mut rpass, [&this.background].into_iter());
-    }
-
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(ExitMsgBoxState(Ui)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                view: &view,
-                resolve_target: None,
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Load,
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-
-        // (한국어) 메뉴 버튼 그리기.
-        // (English Translation) Drawing the menu buttons.
-        ui_brush.draw(
-            &mut rpass, 
-            this.menu_buttons.iter()
-            .map(|(ui, _)| ui)
-        );
-        text_brush.draw(
-            &mut rpass, 
-            this.menu_buttons.iter()
-            .map(|(_, it)| it)
-        );
-    }
-
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(ExitMsgBoxState(Ui)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                view: &view,
-                resolve_target: None,
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Load,
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-
-        // (한국어) 메시지 상자 그리기.
-        // (English Translation) Drawing the message box.
-        ui_brush.draw(
-            &mut rpass, 
-            this.exit_msg_box.iter()
-            .map(|(ui, _)| ui)
-        );
-        text_brush.draw(
-            &mut rpass, 
-            this.exit_msg_box.iter()
-            .map(|(_, it)| it)
-        );
-    }
-
-    // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-    // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-    queue.submit(Some(encoder.finish()));
-    frame.present();
-
-    Ok(())
+    with_frame(shared, this.clear_color(), |frame| {
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(ExitMsgBoxState(Background)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 배경 오브젝트 그리기.
+            // (English Translation) Drawing background objects.
+            sprite_brush.draw(&mut rpass, [&this.background].into_iter());
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(ExitMsgBoxState(Ui)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 메뉴 버튼 그리기.
+            // (English Translation) Drawing the menu buttons.
+            ui_brush.draw(
+                &mut rpass, 
+                this.menu_buttons.iter()
+                .map(|(ui, _)| ui)
+            );
+            text_brush.draw(
+                &mut rpass, 
+                this.menu_buttons.iter()
+                .map(|(_, it)| it)
+            );
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(ExitMsgBoxState(Dim)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 뒤의 장면을 어둡게 하는 오버레이 그리기.
+            // (English Translation) Drawing the overlay that dims the scene behind.
+            this.dim_overlay.draw(ui_brush, &mut rpass);
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(ExitMsgBoxState(Ui)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 메시지 상자 그리기.
+            // (English Translation) Drawing the message box.
+            ui_brush.draw(
+                &mut rpass, 
+                this.exit_msg_box.iter()
+                .map(|(ui, _)| ui)
+            );
+            text_brush.draw(
+                &mut rpass, 
+                this.exit_msg_box.iter()
+                .map(|(_, it)| it)
+            );
+        }
+    })
 }
 
 
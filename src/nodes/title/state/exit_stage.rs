@@ -3,21 +3,20 @@ use std::sync::Arc;
 use winit::event::Event;
 
 use crate::{
-    game_err,
     components::{
         text::{TextBrush, Text}, 
         ui::{UiBrush, UiObject},
         camera::GameCamera, 
-        transform::Projection,
         sprite::SpriteBrush,
     },
     nodes::title::{
+        utils,
         TitleScene, 
-        state::TitleState,
     },
-    render::depth::DepthBuffer,
+    render::frame::with_frame,
+    scene::node::SceneNode,
     system::{
-        error::{AppResult, GameError},
+        error::AppResult,
         event::AppEvent,
         shared::Shared,
     }
@@ -62,12 +61,12 @@ pub fn update(this: &mut TitleScene, shared: &mut Shared, _total_time: f64, elap
     this.return_button.update(queue, |data| {
         data.color.w = stage_alpha;
     });
-    update_camera(camera, queue, delta);
+    camera.lerp_projection(queue, utils::STAGE_PROJECTION, utils::MENU_PROJECTION, delta);
 
     // (한국어) 지속 시간보다 클 경우 다음 상태로 변경합니다.
     // (English Translation) changes to the next state if it is greater than the duration.
     if this.timer >= DURATION {
-        this.state = TitleState::Menu;
+        this.state = this.back_stack.current();
         this.timer = 0.0;
         return Ok(());
     }
@@ -81,142 +80,53 @@ pub fn draw(this: &TitleScene, shared: &mut Shared) -> AppResult<()> {
     let sprite_brush = shared.get::<Arc<SpriteBrush>>().unwrap();
     let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
     let ui_brush = shared.get::<Arc<UiBrush>>().unwrap();
-    let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
     let camera = shared.get::<Arc<GameCamera>>().unwrap();
 
     
-    // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-    // (English Translation) Wait until the previous operation is finished.
-    device.poll(wgpu::Maintain::Wait);
-
-    // (한국어) 다음 프레임을 가져옵니다.
-    // (English Translation) Get the next frame.
-    let frame = surface.get_current_texture()
-        .map_err(|err| game_err!(
-            "Failed to get next frame",
-            "Failed to get next frame for the following reasons: {}",
-            err.to_string()
-        ))?;
-
-    // (한국어) 프레임 버퍼의 텍스쳐 뷰를 생성합니다.
-    // (English Translation) Creates a texture view of the framebuffer.
-    let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-    // (한국어) 커맨드 버퍼를 생성합니다.
-    // (English Translation) Creates a command buffer.
-    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(ExitStage(Background)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment { 
-                view: &view, 
-                resolve_target: None, 
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-
-        // (한국어) 배경 오브젝트들 그리기.
-        // (English Translation) Drawing background objects.
-        sprite_brush.draw(&mut rpass, [&this.background].into_iter());
-    }
-
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(EnterStage(Sprites)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment { 
-                view: &view, 
-                resolve_target: None, 
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Load,
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-
-        // (한국어) 스프라이트 오브젝트들 그리기.
-        // (English Translation) Drawing sprite objects.
-        sprite_brush.draw(&mut rpass, this.sprites.iter().map(|(it, _)| it));
-    }
-
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(ExitStage(Ui)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                view: &view,
-                resolve_target: None,
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Load,
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-
-        // (한국어) 메뉴 버튼 그리기.
-        // (English Translation) Drawing the menu buttons.
-        ui_brush.draw(
-            &mut rpass, 
-            this.menu_buttons.iter()
-            .map(|(ui, _)| ui)
-        );
-        text_brush.draw(
-            &mut rpass, 
-            this.menu_buttons.iter()
-            .map(|(_, it)| it)
-        );
-
-        // (한국어) 시스템 버튼 그리기.
-        // (English Translation) Drawing system buttons.
-        ui_brush.draw(&mut rpass, [&this.return_button].into_iter());
-    }
-
-    // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-    // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-    queue.submit(Some(encoder.finish()));
-    frame.present();
-
-    Ok(())
+    with_frame(shared, this.clear_color(), |frame| {
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(ExitStage(Background)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 배경 오브젝트들 그리기.
+            // (English Translation) Drawing background objects.
+            sprite_brush.draw(&mut rpass, [&this.background].into_iter());
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(EnterStage(Sprites)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 스프라이트 오브젝트들 그리기.
+            // (English Translation) Drawing sprite objects.
+            sprite_brush.draw(&mut rpass, this.sprites.iter().map(|(it, _)| it));
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(ExitStage(Ui)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 메뉴 버튼 그리기.
+            // (English Translation) Drawing the menu buttons.
+            ui_brush.draw(
+                &mut rpass, 
+                this.menu_buttons.iter()
+                .map(|(ui, _)| ui)
+            );
+            text_brush.draw(
+                &mut rpass, 
+                this.menu_buttons.iter()
+                .map(|(_, it)| it)
+            );
+
+            // (한국어) 시스템 버튼 그리기.
+            // (English Translation) Drawing system buttons.
+            ui_brush.draw(&mut rpass, [&this.return_button].into_iter());
+        }
+    })
 }
 
 
@@ -225,27 +135,6 @@ fn delta_time(elapsed_time: f64, duration: f64) -> f32 {
     return 3.0 * t * t - 2.0 * t * t * t;
 }
 
-/// #### 한국어 </br>
-/// 게임 카메라의 투영 행렬을 갱신합니다. </br>
-/// 
-/// #### English (Translation) </br>
-/// Update the game camera's projection matrix. </br>
-/// 
-fn update_camera(camera: &GameCamera, queue: &wgpu::Queue, delta: f32) {
-    use crate::nodes::title::utils;
-    camera.update(queue, |data| {
-        data.projection = Projection::new_ortho(
-            utils::STAGE_TOP + (utils::MENU_TOP - utils::STAGE_TOP) * delta, 
-            utils::STAGE_LEFT + (utils::MENU_LEFT - utils::STAGE_LEFT) * delta, 
-            utils::STAGE_BOTTOM + (utils::MENU_BOTTOM - utils::STAGE_BOTTOM) * delta, 
-            utils::STAGE_RIGHT + (utils::MENU_RIGHT - utils::STAGE_RIGHT) * delta, 
-            0.0, 
-            1000.0
-        );
-    });
-}
-
-
 /// #### 한국어 </br>
 /// 사용자 인터페이스 객체의 알파 값을 갱신합니다. </br>
 /// 
@@ -1,6 +1,6 @@
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
-use rodio::OutputStreamHandle;
 use glam::{Vec4, Vec3, Vec4Swizzles};
 use winit::{
     event::{Event, WindowEvent, MouseButton},
@@ -10,20 +10,29 @@ use winit::{
 
 use crate::{
     game_err,
+    assets::bundle::AssetBundle,
     components::{
         collider2d::Collider2d,
         text::TextBrush,  
         ui::UiBrush, 
         sprite::SpriteBrush,
         camera::GameCamera, 
+        focus::FocusAction, 
+        save::{SaveData, SaveEncoder},
+        user::{Settings, SettingsSnapshot},
         sound, 
     },
-    nodes::title::{
-        utils,
-        TitleScene, 
-        state::TitleState,
+    nodes::{
+        path,
+        in_game::InGameLoading, 
+        title::{
+            utils,
+            TitleScene, 
+            state::{TitleState, open_layer},
+        },
     },
-    render::depth::DepthBuffer,
+    render::{adapter::AdapterWarning, frame::with_frame},
+    scene::{node::SceneNode, state::SceneState},
     system::{
         error::{AppResult, GameError},
         event::AppEvent,
@@ -39,9 +48,18 @@ use crate::{
 /// 
 static FOCUSED_MENU_BTN: Mutex<Option<(usize, Vec3, Vec3)>> = Mutex::new(None);
 
+/// #### 한국어 </br>
+/// 최고 기록 파일의 이름입니다. `F9` 키로 내보내고 `F10` 키로 불러옵니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The name of the best records file. Exported with the `F9` key and imported with the `F10` key. </br>
+/// 
+const RECORDS_FILE_NAME: &str = "Records.json";
+
 
 
 pub fn handle_events(this: &mut TitleScene, shared: &mut Shared, event: Event<AppEvent>) -> AppResult<()> {
+    dismiss_gpu_warning(this, shared, &event);
     handle_keyboard_input(this, shared, &event)?;
     handle_mouse_input(this, shared, &event)?;
     Ok(())
@@ -57,108 +75,64 @@ pub fn draw(this: &TitleScene, shared: &mut Shared) -> AppResult<()> {
     let sprite_brush = shared.get::<Arc<SpriteBrush>>().unwrap();
     let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
     let ui_brush = shared.get::<Arc<UiBrush>>().unwrap();
-    let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
     let camera = shared.get::<Arc<GameCamera>>().unwrap();
 
 
-    // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-    // (English Translation) Wait until the previous operation is finished.
-    device.poll(wgpu::Maintain::Wait);
-
-    // (한국어) 다음 프레임을 가져옵니다.
-    // (English Translation) Get the next frame.
-    let frame = surface.get_current_texture()
-        .map_err(|err| game_err!(
-            "Failed to get next frame",
-            "Failed to get next frame for the following reasons: {}",
-            err.to_string()
-        ))?;
-
-    // (한국어) 프레임 버퍼의 텍스쳐 뷰를 생성합니다.
-    // (English Translation) Creates a texture view of the framebuffer.
-    let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-    // (한국어) 커맨드 버퍼를 생성합니다.
-    // (English Translation) Creates a command buffer.
-    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(MenuState(Background)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment { 
-                view: &view, 
-                resolve_target: None, 
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-        
-        // (한국어) 배경 오브젝트 그리기.
-        // (English Translation) Drawing background objects.
-        sprite_brush.draw(&mut rpass, [&this.background].into_iter());
-    }
+    with_frame(shared, this.clear_color(), |frame| {
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(MenuState(Background)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 배경 오브젝트 그리기.
+            // (English Translation) Drawing background objects.
+            sprite_brush.draw(&mut rpass, [&this.background].into_iter());
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(MenuState(Ui)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 메뉴 버튼 그리기.
+            // (English Translation) Drawing the menu buttons.
+            ui_brush.draw(
+                &mut rpass, 
+                this.menu_buttons.iter()
+                .map(|(ui, _)| ui)
+            );
+            text_brush.draw(
+                &mut rpass, 
+                this.menu_buttons.iter()
+                .map(|(_, it)| it)
+                .chain(this.gpu_warning.iter())
+            );
+        }
+    })
+}
 
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(MenuState(Ui)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                view: &view,
-                resolve_target: None,
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Load,
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-
-        // (한국어) 메뉴 버튼 그리기.
-        // (English Translation) Drawing the menu buttons.
-        ui_brush.draw(
-            &mut rpass, 
-            this.menu_buttons.iter()
-            .map(|(ui, _)| ui)
-        );
-        text_brush.draw(
-            &mut rpass, 
-            this.menu_buttons.iter()
-            .map(|(_, it)| it)
-        );
-    }
 
-    // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-    // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-    queue.submit(Some(encoder.finish()));
-    frame.present();
+/// #### 한국어 </br>
+/// 키를 누르거나 마우스를 클릭하면 소프트웨어 렌더링 경고를 닫습니다. </br>
+/// 닫은 경고는 애플리케이션을 다시 실행할 때까지 표시되지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Closes the software rendering warning when a key is pressed or the mouse is clicked. </br>
+/// The closed warning is not shown again until the application is restarted. </br>
+/// 
+fn dismiss_gpu_warning(this: &mut TitleScene, shared: &mut Shared, event: &Event<AppEvent>) {
+    let pressed = match event {
+        Event::WindowEvent { event, .. } => match event {
+            WindowEvent::KeyboardInput { event, .. } => !event.repeat && event.state.is_pressed(), 
+            WindowEvent::MouseInput { state, .. } => state.is_pressed(), 
+            _ => false
+        },
+        _ => false
+    };
 
-    Ok(())
+    if pressed && this.gpu_warning.take().is_some() {
+        shared.pop::<AdapterWarning>();
+    }
 }
 
 
@@ -171,6 +145,22 @@ fn handle_keyboard_input(this: &mut TitleScene, shared: &mut Shared, event: &Eve
         Event::WindowEvent { event, .. } => match event {
             WindowEvent::KeyboardInput { event, .. }
             => if let PhysicalKey::Code(code) = event.physical_key {
+                // (한국어) 키보드 포커스 입력을 처리합니다. 포커스된 버튼의 활성화는 클릭과 같이 처리됩니다.
+                // (English Translation) Handles keyboard focus input. Activating the focused button is handled like a click.
+                if !event.repeat && event.state.is_pressed() {
+                    match this.menu_focus.handle_key(code) {
+                        Some(FocusAction::Activate(tag)) => {
+                            ui_pressed(tag, this, shared)?;
+                            return ui_released(tag, this, shared);
+                        },
+                        Some(FocusAction::Moved(_)) => {
+                            this.menu_focus.highlight(queue, this.menu_buttons.iter().enumerate().map(|(idx, (ui, _))| (utils::MenuButtons::from(idx), ui)));
+                            return Ok(());
+                        },
+                        None => { /* empty */ }
+                    }
+                }
+
                 if KeyCode::Escape == code && !event.repeat && event.state.is_pressed() {
                     sound::play_click_sound(shared)?;
 
@@ -190,8 +180,11 @@ fn handle_keyboard_input(this: &mut TitleScene, shared: &mut Shared, event: &Eve
 
                     // (한국어) 다음 게임 장면 상태로 변경합니다.
                     // (English Translation) Change to the next game scene state.
-                    this.state = TitleState::EnterMsgBox;
-                    this.timer = 0.0;
+                    open_layer(this, TitleState::MsgBox);
+                } else if KeyCode::F9 == code && !event.repeat && event.state.is_pressed() {
+                    export_records(shared)?;
+                } else if KeyCode::F10 == code && !event.repeat && event.state.is_pressed() {
+                    import_records(shared)?;
                 };
             },
             _ => { /* empty */ }
@@ -202,6 +195,72 @@ fn handle_keyboard_input(this: &mut TitleScene, shared: &mut Shared, event: &Eve
     Ok(())
 }
 
+/// #### 한국어 </br>
+/// 최고 기록을 내보내거나 불러올 파일의 경로입니다. (실행 파일 옆) </br>
+/// 
+/// #### English (Translation) </br>
+/// The path of the file to export or import the best records. (Next to the executable) </br>
+/// 
+fn records_path() -> AppResult<PathBuf> {
+    std::env::current_exe()
+        .map(|path| path.with_file_name(RECORDS_FILE_NAME))
+        .map_err(|err| game_err!(
+            "Failed to find records file",
+            "The records file path failed to resolve for the following reasons: {}",
+            err.to_string()
+        ))
+}
+
+/// #### 한국어 </br>
+/// 최고 기록을 파일로 내보냅니다. 실패한 경우 로그를 남기고 취소 효과음을 재생합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Exports the best records to a file. On failure, logs it and plays the cancel sound. </br>
+/// 
+fn export_records(shared: &mut Shared) -> AppResult<()> {
+    let save = shared.get::<SaveData>().unwrap();
+    match records_path().and_then(|path| save.export_json(&path).map(|_| path)) {
+        Ok(path) => {
+            log::info!("Records exported to {}.", path.display());
+            sound::play_click_sound(shared)
+        },
+        Err(err) => {
+            log::error!("{:?}", err);
+            sound::play_cancel_sound(shared)
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 파일의 기록 중 현재 기록보다 높은 기록만 불러와 세이브 파일에 저장합니다. </br>
+/// 실패한 경우 로그를 남기고 취소 효과음을 재생합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Imports only the records in the file that are higher than the current ones and saves them to the save file. </br>
+/// On failure, logs it and plays the cancel sound. </br>
+/// 
+fn import_records(shared: &mut Shared) -> AppResult<()> {
+    let mut save = *shared.get::<SaveData>().unwrap();
+    match records_path().and_then(|path| save.import_json(&path)) {
+        Ok(true) => {
+            let asset_bundle = shared.get::<AssetBundle>().unwrap();
+            asset_bundle.get(path::SAVE_PATH)?.write(&SaveEncoder, &save)?;
+            shared.push(save);
+            log::info!("Records imported. They will be shown the next time the title screen is loaded.");
+            sound::play_click_sound(shared)
+        },
+        Ok(false) => {
+            log::info!("Records imported. No record was higher than the current ones.");
+            sound::play_click_sound(shared)
+        },
+        Err(err) => {
+            log::error!("{:?}", err);
+            sound::play_cancel_sound(shared)
+        }
+    }
+}
+
+
 
 fn handle_mouse_input(this: &mut TitleScene, shared: &mut Shared, event: &Event<AppEvent>) ->AppResult<()> {
     // (한국어) 사용할 공유 객체 가져오기.
@@ -320,6 +379,9 @@ fn ui_pressed(btn: utils::MenuButtons, this: &mut TitleScene, shared: &mut Share
         utils::MenuButtons::Exit => {
             sound::play_click_sound(shared)
         },
+        utils::MenuButtons::Continue => {
+            sound::play_click_sound(shared)
+        },
         _ => Ok(())
     }
 }
@@ -330,22 +392,33 @@ fn ui_pressed(btn: utils::MenuButtons, this: &mut TitleScene, shared: &mut Share
 fn ui_released(btn: utils::MenuButtons, this: &mut TitleScene, shared: &mut Shared) -> AppResult<()> {
     match btn {
         utils::MenuButtons::Start => {
-            this.state = TitleState::EnterStage;
-            this.timer = 0.0;
+            open_layer(this, TitleState::Stage);
             Ok(())
         },
         utils::MenuButtons::Setting => {
-            let stream = shared.get::<OutputStreamHandle>().unwrap();
+            let stream = sound::output(shared);
             let sink = sound::create_sink(stream)?;
             shared.push((1usize, sink));
 
-            this.state = TitleState::EnterSetting;
-            this.timer = 0.0;
+            // (한국어) 설정 창을 취소할 때 되돌릴 현재 설정을 저장합니다.
+            // (English Translation) Stores the current settings to revert to when the setting window is canceled.
+            let snapshot = SettingsSnapshot::new(shared.get::<Settings>().unwrap());
+            shared.push(snapshot);
+
+            open_layer(this, TitleState::Setting);
             Ok(())
         },
         utils::MenuButtons::Exit => {
-            this.state = TitleState::EnterMsgBox;
-            this.timer = 0.0;
+            open_layer(this, TitleState::MsgBox);
+            Ok(())
+        },
+        utils::MenuButtons::Continue => {
+            // (한국어) 스테이지 선택을 건너뛰고 마지막으로 플레이한 캐릭터의 스테이지에 바로 입장합니다.
+            // (English Translation) Skips the stage selection and enters the stage of the last played character directly.
+            if let Some(actor) = this.continue_actor {
+                shared.push(actor);
+                *shared.get_mut::<SceneState>().unwrap() = SceneState::Change(Box::new(InGameLoading::default()));
+            }
             Ok(())
         },
         _ => Ok(())
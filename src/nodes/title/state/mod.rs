@@ -14,10 +14,16 @@ mod selected;
 mod setting;
 mod return_stage;
 
+pub use setting::hovers_setting_button;
+#[cfg(debug_assertions)]
+pub use setting::apply_script;
+
 use winit::event::Event;
 
 use crate::{
+    state_enum,
     nodes::title::TitleScene,
+    scene::{back_stack::Layer, idle::IdleTimer}, 
     system::{
         error::AppResult, 
         event::AppEvent, 
@@ -27,30 +33,69 @@ use crate::{
 
 
 
-/// #### 한국어 </br>
-/// `title` 게임 장면의 상태 목록입니다. </br>
-/// 
-/// #### English (Translation) </br>
-/// Status list of `title` game scene. </br>
-/// 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum TitleState {
-    #[default]
-    Enter,
-    Menu,
-    EnterSetting,
-    ExitSetting,
-    Setting,
-    EnterMsgBox,
-    ExitMsgBox,
-    MsgBox,
-    EnterStage,
-    ExitStage,
-    Stage,
-    EnterSelected,
-    ExitSelected,
-    Selected,
-    ReturnStage, 
+state_enum! {
+    /// #### 한국어 </br>
+    /// `title` 게임 장면의 상태 목록입니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Status list of `title` game scene. </br>
+    /// 
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum TitleState {
+        #[default]
+        Enter,
+        Menu,
+        EnterSetting,
+        ExitSetting,
+        Setting,
+        EnterMsgBox,
+        ExitMsgBox,
+        MsgBox,
+        EnterStage,
+        ExitStage,
+        Stage,
+        EnterSelected,
+        ExitSelected,
+        Selected,
+        ReturnStage, 
+    }
+}
+
+impl TitleState {
+    /// #### 한국어 </br>
+    /// 화면이 바뀌는 애니메이션이 진행 중인 상태인 경우 `true`를 반환합니다. </br>
+    /// 이 상태에서는 버튼이 사라지거나 나타나는 중이므로 버튼 입력을 받지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if the state is playing a screen transition animation. </br>
+    /// Buttons are fading in or out in this state, so button input is not accepted. </br>
+    /// 
+    #[inline]
+    pub fn is_transitioning(self) -> bool {
+        !matches!(self, Self::Menu | Self::Setting | Self::MsgBox | Self::Stage | Self::Selected)
+    }
+}
+
+impl Layer for TitleState {
+    fn enter_transition(self) -> Self {
+        match self {
+            Self::Setting => Self::EnterSetting, 
+            Self::MsgBox => Self::EnterMsgBox, 
+            Self::Stage => Self::EnterStage, 
+            Self::Selected => Self::EnterSelected, 
+            _ => self, 
+        }
+    }
+
+    fn exit_transition(self) -> Self {
+        match self {
+            Self::Setting => Self::ExitSetting, 
+            Self::MsgBox => Self::ExitMsgBox, 
+            Self::Stage => Self::ExitStage, 
+            Self::Selected => Self::ExitSelected, 
+            _ => self, 
+        }
+    }
 }
 
 
@@ -112,3 +157,123 @@ pub const DRAWS: [&'static DrawFn; 15] = [
     &selected::draw,
     &return_stage::draw, 
 ];
+
+
+/// #### 한국어 </br>
+/// `title` 게임 장면의 상태별 함수 목록들의 (이름, 길이) 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The (name, length) of the per-state function tables of the `title` game scene. </br>
+/// 
+#[cfg(test)]
+pub const TABLES: [(&'static str, usize); 3] = [
+    ("HANDLE_EVENTS", HANDLE_EVENTS.len()), 
+    ("UPDATES", UPDATES.len()), 
+    ("DRAWS", DRAWS.len()), 
+];
+
+// (한국어) 상태별 함수 목록의 길이가 상태의 개수와 같은지 컴파일 시간에 확인합니다.
+// (English Translation) Checks at compile time that the length of each per-state function table equals the number of states.
+const _: () = assert!(HANDLE_EVENTS.len() == TitleState::NUM_STATES, "`HANDLE_EVENTS` must have one entry for each `TitleState`.");
+const _: () = assert!(UPDATES.len() == TitleState::NUM_STATES, "`UPDATES` must have one entry for each `TitleState`.");
+const _: () = assert!(DRAWS.len() == TitleState::NUM_STATES, "`DRAWS` must have one entry for each `TitleState`.");
+
+
+
+/// #### 한국어 </br>
+/// 메뉴 화면에서 입력이 없던 시간을 갱신하고, 제한 시간을 넘긴 경우 `true`를 반환합니다. </br>
+/// 메뉴 화면이 아닌 경우 시간을 재지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Updates the idle time on the menu screen, and returns `true` if it has passed the timeout. </br>
+/// Time is not measured outside the menu screen. </br>
+/// 
+pub fn update_idle_timer(state: TitleState, idle_timer: &mut IdleTimer, elapsed_time: f64) -> bool {
+    if state != TitleState::Menu {
+        idle_timer.reset();
+        return false;
+    }
+    return idle_timer.tick(elapsed_time);
+}
+
+/// #### 한국어 </br>
+/// 주어진 상태를 현재 상태 위에 열고, 그 상태가 열리는 전환 상태로 변경합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Opens the given state on top of the current state, and changes to the transition state in which it opens. </br>
+/// 
+pub fn open_layer(this: &mut TitleScene, layer: TitleState) {
+    this.state = this.back_stack.open(layer);
+    this.timer = 0.0;
+}
+
+/// #### 한국어 </br>
+/// 가장 위에 열린 상태 하나를 닫고, 그 상태가 닫히는 전환 상태로 변경합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Closes the topmost open state, and changes to the transition state in which it closes. </br>
+/// 
+pub fn close_layer(this: &mut TitleScene) {
+    if let Some(state) = this.back_stack.back() {
+        this.state = state;
+        this.timer = 0.0;
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use winit::{
+        event::{DeviceId, ElementState, MouseButton, WindowEvent},
+        window::WindowId,
+    };
+    use super::*;
+    use crate::scene::state::{debug_validate_tables, ignores_button_input};
+
+    #[test]
+    fn state_tables_match_the_scene() {
+        assert!(debug_validate_tables::<TitleScene>(&TABLES).is_ok());
+    }
+
+    #[test]
+    fn idle_menu_plays_the_intro_after_the_timeout() {
+        let mut idle_timer = IdleTimer::new(60);
+        assert!(!update_idle_timer(TitleState::Menu, &mut idle_timer, 59.0));
+
+        // (한국어) 입력이 있으면 처음부터 다시 잽니다.
+        // (English Translation) An input starts measuring again from the beginning.
+        idle_timer.reset();
+        assert!(!update_idle_timer(TitleState::Menu, &mut idle_timer, 59.0));
+        assert!(update_idle_timer(TitleState::Menu, &mut idle_timer, 1.0));
+
+        // (한국어) 메뉴 화면이 아닌 경우 시간을 재지 않습니다.
+        // (English Translation) Time is not measured outside the menu screen.
+        idle_timer.reset();
+        assert!(!update_idle_timer(TitleState::Stage, &mut idle_timer, 120.0));
+        assert!(!update_idle_timer(TitleState::Menu, &mut idle_timer, 1.0));
+
+        let mut disabled = IdleTimer::new(0);
+        assert!(!update_idle_timer(TitleState::Menu, &mut disabled, 1.0e6));
+    }
+
+    #[test]
+    fn click_is_accepted_only_in_interactive_states() {
+        let click = Event::WindowEvent { 
+            window_id: unsafe { WindowId::dummy() }, 
+            event: WindowEvent::MouseInput { 
+                device_id: unsafe { DeviceId::dummy() }, 
+                state: ElementState::Pressed, 
+                button: MouseButton::Left, 
+            },
+        };
+
+        // (한국어) 메뉴가 나타나는 동안의 클릭은 무시되고, 메뉴 상태에 도달한 후에 받아들여집니다.
+        // (English Translation) A click while the menu is appearing is ignored, and accepted once the menu state is reached.
+        assert!(ignores_button_input(TitleState::Enter.is_transitioning(), &click));
+        assert!(!ignores_button_input(TitleState::Menu.is_transitioning(), &click));
+
+        assert!(ignores_button_input(TitleState::EnterSelected.is_transitioning(), &click));
+        assert!(!ignores_button_input(TitleState::Selected.is_transitioning(), &click));
+        assert!(ignores_button_input(TitleState::ExitSelected.is_transitioning(), &click));
+    }
+}
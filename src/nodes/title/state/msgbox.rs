@@ -8,23 +8,23 @@ use winit::{
 };
 
 use crate::{
-    game_err,
     components::{
         collider2d::Collider2d,
         text::TextBrush, 
         ui::UiBrush,
         camera::GameCamera,
         sprite::SpriteBrush,
+        focus::FocusAction,
     },
     nodes::title::{
         utils,
         TitleScene,
-        state::TitleState,
+        state::close_layer,
     }, 
-    render::depth::DepthBuffer,
-    scene::state::SceneState,
+    render::frame::with_frame,
+    scene::{node::SceneNode, state::SceneState},
     system::{
-        error::{AppResult, GameError}, 
+        error::AppResult, 
         event::AppEvent,
         shared::Shared, 
     }
@@ -56,114 +56,53 @@ pub fn draw(this: &TitleScene, shared: &mut Shared) -> AppResult<()> {
     let sprite_brush = shared.get::<Arc<SpriteBrush>>().unwrap();
     let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
     let ui_brush = shared.get::<Arc<UiBrush>>().unwrap();
-    let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
     let camera = shared.get::<Arc<GameCamera>>().unwrap();
 
 
-    // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-    // (English Translation) Wait until the previous operation is finished.
-    device.poll(wgpu::Maintain::Wait);
-
-    // (한국어) 다음 프레임을 가져옵니다.
-    // (English Translation) Get the next frame.
-    let frame = surface.get_current_texture()
-        .map_err(|err| game_err!(
-            "Failed to get next frame",
-            "Failed to get next frame for the following reasons: {}",
-            err.to_string()
-        ))?;
-
-    // (한국어) 프레임 버퍼의 텍스쳐 뷰를 생성합니다.
-    // (English Translation) Creates a texture view of the framebuffer.
-    let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-    // (한국어) 커맨드 버퍼를 생성합니다.
-    // (English Translation) Creates a command buffer.
-    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(MsgBoxState(Background)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment { 
-                view: &view, 
-                resolve_target: None, 
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-        
-        // (한국어) 배경 오브젝트 그리기.
-        // (English Translation) Drawing background objects.
-        sprite_brush.draw(&mut rpass, [&this.background].into_iter());
-    }
+    with_frame(shared, this.clear_color(), |frame| {
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(MsgBoxState(Background)))");
 
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(MsgBoxState(Ui)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                view: &view,
-                resolve_target: None,
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Load,
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-
-        // (한국어) 메시지 상자 그리기.
-        // (English Translation) Drawing the message box.
-        ui_brush.draw(
-            &mut rpass, 
-            this.exit_msg_box.iter()
-            .map(|(ui, _)| ui)
-        );
-        text_brush.draw(
-            &mut rpass, 
-            this.exit_msg_box.iter()
-            .map(|(_, it)| it)
-        );
-    }
+            camera.bind(&mut rpass);
 
-    // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-    // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-    queue.submit(Some(encoder.finish()));
-    frame.present();
+            // (한국어) 배경 오브젝트 그리기.
+            // (English Translation) Drawing background objects.
+            sprite_brush.draw(&mut rpass, [&this.background].into_iter());
+        }
 
-    Ok(())
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(MsgBoxState(Dim)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 뒤의 장면을 어둡게 하는 오버레이 그리기.
+            // (English Translation) Drawing the overlay that dims the scene behind.
+            this.dim_overlay.draw(ui_brush, &mut rpass);
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(MsgBoxState(Ui)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 메시지 상자 그리기.
+            // (English Translation) Drawing the message box.
+            ui_brush.draw(
+                &mut rpass, 
+                this.exit_msg_box.iter()
+                .map(|(ui, _)| ui)
+            );
+            text_brush.draw(
+                &mut rpass, 
+                this.exit_msg_box.iter()
+                .map(|(_, it)| it)
+            );
+        }
+    })
 }
 
 
 fn handle_keyboard_input(this: &mut TitleScene, shared: &mut Shared, event: &Event<AppEvent>) -> AppResult<()> {
-    use crate::components::sound;
-    
     // (한국어) 사용할 공유 객체 가져오기.
     // (English Translation) Get shared object to use.
     let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
@@ -172,11 +111,25 @@ fn handle_keyboard_input(this: &mut TitleScene, shared: &mut Shared, event: &Eve
         Event::WindowEvent { event, .. } => match event {
             WindowEvent::KeyboardInput { event, .. }
             => if let PhysicalKey::Code(code) = event.physical_key {
+                // (한국어) 키보드 포커스 입력을 처리합니다. 포커스된 버튼의 활성화는 클릭과 같이 처리됩니다.
+                // (English Translation) Handles keyboard focus input. Activating the focused button is handled like a click.
+                if !event.repeat && event.state.is_pressed() {
+                    match this.exit_msg_focus.handle_key(code) {
+                        Some(FocusAction::Activate(tag)) => {
+                            ui_pressed(tag, this, shared)?;
+                            return ui_released(tag, this, shared);
+                        },
+                        Some(FocusAction::Moved(_)) => {
+                            this.exit_msg_focus.highlight(queue, this.exit_msg_box.iter().enumerate().skip(1).map(|(idx, (ui, _))| (utils::ExitMessageBox::from(idx), ui)));
+                            return Ok(());
+                        },
+                        None => { /* empty */ }
+                    }
+                }
+
                 if KeyCode::Enter == code && !event.repeat && event.state.is_pressed() {
                     *shared.get_mut::<SceneState>().unwrap() = SceneState::Pop;
-                } else if KeyCode::Escape == code && !event.repeat && event.state.is_pressed() {
-                    sound::play_cancel_sound(shared)?;
-                    
+                } else if let Some(btn) = utils::ExitMessageBox::from_shortcut(code).filter(|_| !event.repeat && event.state.is_pressed()) {
                     // (한국어) 선택했던 ui의 색상을 원래대로 되돌립니다.
                     // (English Translation) Returns the color of the selected ui to its original color.
                     let mut guard = FOCUSED_MSG_BTN.lock().expect("Failed to access variable.");
@@ -190,11 +143,12 @@ fn handle_keyboard_input(this: &mut TitleScene, shared: &mut Shared, event: &Eve
                             });
                         }
                     }
+                    drop(guard);
 
-                    // (한국어) 다음 게임 장면 상태로 변경합니다.
-                    // (English Translation) Change to the next game scene state.
-                    this.state = TitleState::ExitMsgBox;
-                    this.timer = 0.0;
+                    // (한국어) 단축키에 해당하는 버튼을 누른 것과 같이 처리합니다. (`Y`: 종료, `N`, `Escape`: 닫기)
+                    // (English Translation) Handles it as if the button corresponding to the shortcut was pressed. (`Y`: quit, `N`, `Escape`: close)
+                    ui_pressed(btn, this, shared)?;
+                    return ui_released(btn, this, shared);
                 };
             },
             _ => { /* empty */ }
@@ -338,8 +292,7 @@ fn ui_released(btn: utils::ExitMessageBox, this: &mut TitleScene, shared: &mut S
             Ok(())
         },
         utils::ExitMessageBox::No => {
-            this.state = TitleState::ExitMsgBox;
-            this.timer = 0.0;
+            close_layer(this);
             Ok(())
         },
         _ => Ok(())
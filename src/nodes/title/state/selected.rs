@@ -8,7 +8,6 @@ use winit::{
 };
 
 use crate::{
-    game_err,
     components::{
         ui::UiBrush, 
         text::TextBrush, 
@@ -21,14 +20,15 @@ use crate::{
     nodes::{
         title::{
             TitleScene, 
-            state::TitleState,
+            state::close_layer,
+            utils, 
         },
         in_game::InGameLoading,
     }, 
-    render::depth::DepthBuffer, 
-    scene::state::SceneState, 
+    render::frame::with_frame, 
+    scene::{node::SceneNode, state::SceneState}, 
     system::{
-        error::{AppResult, GameError},
+        error::AppResult,
         event::AppEvent, 
         shared::Shared, 
     }, 
@@ -68,169 +68,61 @@ pub fn draw(this: &TitleScene, shared: &mut Shared) -> AppResult<()> {
     let sprite_brush = shared.get::<Arc<SpriteBrush>>().unwrap();
     let ui_brush = shared.get::<Arc<UiBrush>>().unwrap();
     let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
-    let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
     let camera = shared.get::<Arc<GameCamera>>().unwrap();
     let actor = shared.get::<Actor>().unwrap();
 
     
-    // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-    // (English Translation) Wait until the previous operation is finished.
-    device.poll(wgpu::Maintain::Wait);
-
-    // (한국어) 다음 프레임을 가져옵니다.
-    // (English Translation) Get the next frame.
-    let frame = surface.get_current_texture()
-        .map_err(|err| game_err!(
-            "Failed to get next frame",
-            "Failed to get next frame for the following reasons: {}",
-            err.to_string()
-        ))?;
-
-    // (한국어) 프레임 버퍼의 텍스쳐 뷰를 생성합니다.
-    // (English Translation) Creates a texture view of the framebuffer.
-    let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-    // (한국어) 커맨드 버퍼를 생성합니다.
-    // (English Translation) Creates a command buffer.
-    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(EnterSelected(Background)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment { 
-                view: &view, 
-                resolve_target: None, 
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-
-        // (한국어) 배경 오브젝트들 그리기.
-        // (English Translation) Drawing background objects.
-        sprite_brush.draw(&mut rpass, [&this.background].into_iter());
-    }
-
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(EnterSelected(Sprites)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment { 
-                view: &view, 
-                resolve_target: None, 
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Load,
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-
-        // (한국어) 배경 오브젝트들 그리기.
-        // (English Translation) Drawing background objects.
-        sprite_brush.draw(&mut rpass, this.sprites.iter().map(|(it, _)| it));
-    }
-
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(EnterSelected(Ui)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                view: &view,
-                resolve_target: None,
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Load,
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-
-        // (한국어) 버튼 그리기.
-        // (English Translation) Drawing the buttons.
-        ui_brush.draw(&mut rpass, [&this.return_button].into_iter());
-    }
-
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(EnterSelected(Ui)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                view: &view,
-                resolve_target: None,
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Load,
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-
-        // (한국어) 스테이지 윈도우 창을 그립니다. 
-        // (English Translation) Drawing the stage window.
-        ui_brush.draw(&mut rpass, [
-            &this.stage_window, 
-            &this.stage_enter_button.0, 
-            &this.stage_images[&actor].0, 
-            &this.stage_images[&actor].1, 
-        ].into_iter());
-        text_brush.draw(&mut rpass, [
-            &this.stage_enter_button.1, 
-            &this.stage_images[&actor].2,
-        ].into_iter());
-    }
-
-    // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-    // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-    queue.submit(Some(encoder.finish()));
-    frame.present();
-
-    Ok(())
+    with_frame(shared, this.clear_color(), |frame| {
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(EnterSelected(Background)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 배경 오브젝트들 그리기.
+            // (English Translation) Drawing background objects.
+            sprite_brush.draw(&mut rpass, [&this.background].into_iter());
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(EnterSelected(Sprites)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 배경 오브젝트들 그리기.
+            // (English Translation) Drawing background objects.
+            sprite_brush.draw(&mut rpass, this.sprites.iter().map(|(it, _)| it));
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(EnterSelected(Ui)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 버튼 그리기.
+            // (English Translation) Drawing the buttons.
+            ui_brush.draw(&mut rpass, [&this.return_button].into_iter());
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(EnterSelected(Ui)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 스테이지 윈도우 창을 그립니다. 
+            // (English Translation) Drawing the stage window.
+            ui_brush.draw(&mut rpass, [
+                &this.stage_window, 
+                &this.stage_enter_button.0, 
+                &this.stage_images[&actor].0, 
+                &this.stage_images[&actor].1, 
+            ].into_iter()
+            .chain(this.stage_locks.get(actor)));
+            text_brush.draw(&mut rpass, [
+                &this.stage_enter_button.1, 
+                &this.stage_images[&actor].2,
+            ].into_iter());
+        }
+    })
 }
 
 
@@ -239,6 +131,7 @@ fn handle_keyboard_input(this: &mut TitleScene, shared: &mut Shared, event: &Eve
     // (English Translation) Get shared object to use.
     let sprite = shared.get::<Actor>().unwrap();
     let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+    let locked = this.stage_locks.contains_key(sprite);
 
     match event {
         Event::WindowEvent { event, .. } => match event {
@@ -284,12 +177,29 @@ fn handle_keyboard_input(this: &mut TitleScene, shared: &mut Shared, event: &Eve
 
                     // (한국어) 다음 게임 장면 상태로 변경합니다.
                     // (English Translation) Change to the next game scene state.
-                    this.state = TitleState::ExitSelected;
-                    this.timer = 0.0;
+                    close_layer(this);
                 } else if KeyCode::Enter == code && !event.repeat && event.state.is_pressed() {
+                    // (한국어) 잠긴 스테이지에는 입장할 수 없습니다.
+                    // (English Translation) A locked stage cannot be entered.
+                    if locked {
+                        return sound::play_cancel_sound(shared);
+                    }
+
                     sound::play_click_sound(shared)?;
                     let state = shared.get_mut::<SceneState>().unwrap();
                     *state = SceneState::Change(Box::new(InGameLoading::default()));
+                } else if let Some(step) = utils::actor_cycle_step(code).filter(|_| event.state.is_pressed()) {
+                    // (한국어) 스테이지 입장 버튼이 눌려진 동안에는 캐릭터를 바꾸지 않습니다.
+                    // (English Translation) Does not change the character while the stage enter button is pressed.
+                    if FOCUSED_STAGE_WND.lock().expect("Failed to access variable.").is_some() {
+                        return Ok(());
+                    }
+
+                    let next = utils::cycle_actor(*sprite, step, |actor| this.stage_locks.contains_key(&actor));
+                    if next != *sprite {
+                        sound::play_click_sound(shared)?;
+                        change_actor(this, shared, next);
+                    }
                 }
             },
             _ => { /* empty */ }
@@ -301,6 +211,60 @@ fn handle_keyboard_input(this: &mut TitleScene, shared: &mut Shared, event: &Eve
 }
 
 
+/// #### 한국어 </br>
+/// 선택된 캐릭터를 바꾸고, 스프라이트 이미지와 스테이지 윈도우, 카메라를 새로운 캐릭터에 맞게 갱신합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Changes the selected character, and updates the sprite images, the stage window and the camera for the new character. </br>
+/// 
+fn change_actor(this: &mut TitleScene, shared: &mut Shared, next: Actor) {
+    // (한국어) 사용할 공유 객체 가져오기.
+    // (English Translation) Get shared object to use.
+    let prev = shared.pop::<Actor>().unwrap();
+    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+    let camera = shared.get::<Arc<GameCamera>>().unwrap();
+
+    // (한국어) 선택된 스프라이트의 이미지를 변경합니다.
+    // (English Translation) Changes the image of the selected sprite.
+    for (actor, texture_index) in [(prev, 0), (next, 1)] {
+        if let Some((sprite, _)) = this.sprites.get(actor as usize) {
+            sprite.update(queue, |instances| {
+                for instance in instances.iter_mut() {
+                    instance.texture_index = texture_index;
+                }
+            });
+        }
+    }
+
+    // (한국어) 새로운 캐릭터의 스테이지 이미지를 보이게 합니다.
+    // (English Translation) Makes the stage image of the new character visible.
+    this.stage_images[&next].0.update(queue, |data| {
+        data.color.w = 1.0;
+    });
+    this.stage_images[&next].1.update(queue, |data| {
+        data.color.w = 1.0;
+    });
+    this.stage_images[&next].2.update(queue, |data| {
+        data.color.w = 1.0;
+    });
+    if let Some(lock) = this.stage_locks.get(&next) {
+        lock.update(queue, |data| {
+            data.color.w = utils::STAGE_LOCK_ALPHA;
+        });
+    }
+
+    // (한국어) 카메라가 새로운 캐릭터의 스테이지를 확대하도록 합니다.
+    // (English Translation) Makes the camera zoom in on the stage of the new character.
+    let projection = utils::selected_stage_projection(next);
+    camera.update(queue, |data| {
+        data.projection = projection;
+    });
+
+    this.selected_actor = next;
+    shared.push(next);
+}
+
+
 fn handle_mouse_input(this: &mut TitleScene, shared: &mut Shared, event: &Event<AppEvent>) -> AppResult<()> {
     handle_mouse_input_for_ui(this, shared, event)?;
     handle_mouse_input_for_sys(this, shared, event)?;
@@ -314,6 +278,7 @@ fn handle_mouse_input_for_ui(this: &mut TitleScene, shared: &mut Shared, event:
     let cursor_pos = shared.get::<PhysicalPosition<f64>>().unwrap();
     let camera = shared.get::<Arc<GameCamera>>().unwrap();
     let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+    let actor = shared.get::<Actor>().unwrap();
     
     match event {
         Event::WindowEvent { event, .. } => match event {
@@ -323,6 +288,12 @@ fn handle_mouse_input_for_ui(this: &mut TitleScene, shared: &mut Shared, event:
                     // (English Translation) Make sure the mouse cursor is inside the ui area.
                     let selected = this.stage_enter_button.0.test(&(cursor_pos, camera));
 
+                    // (한국어) 잠긴 스테이지에는 입장할 수 없습니다.
+                    // (English Translation) A locked stage cannot be entered.
+                    if selected && this.stage_locks.contains_key(actor) {
+                        return sound::play_cancel_sound(shared);
+                    }
+
                     // (한국어)
                     // 마우스 커서가 ui 영역 안에 있는 경우:
                     // 1. `FOCUSED`에 해당 ui의 태그, 색상, 텍스트 색상을 저장합니다.
@@ -521,8 +492,7 @@ fn sys_ui_released(this: &mut TitleScene, shared: &mut Shared) -> AppResult<()>
         });
     }
 
-    this.state = TitleState::ExitSelected;
-    this.timer = 0.0;
+    close_layer(this);
     Ok(())
 }
 
@@ -1,6 +1,5 @@
 use std::sync::{Arc, Mutex};
 
-use rodio::Sink;
 use glam::{Vec4, Vec3, Vec4Swizzles};
 use winit::{
     window::Window, 
@@ -10,31 +9,36 @@ use winit::{
 };
 
 use crate::{
-    game_err, 
     assets::bundle::AssetBundle, 
     components::{
         camera::GameCamera, 
+        cursor, 
         collider2d::Collider2d, 
-        script::{ScriptDecoder, ScriptTags}, 
+        script::{self, Script, ScriptDecoder, ScriptTags}, 
         sprite::SpriteBrush, 
         text::TextBrush, 
-        ui::UiBrush, 
+        ui::{UiBrush, ModalHit}, 
+        focus::FocusAction, 
         sound, 
         user::{
+            self, 
             Language, 
             Resolution, 
             Settings, 
-            SettingsEncoder
+            SettingsEncoder, 
+            SettingsSnapshot, 
+            SettingToggle, 
         }
     },
     nodes::title::{
         utils, 
         TitleScene, 
-        state::TitleState, 
+        state::close_layer, 
     }, 
-    render::depth::DepthBuffer, 
+    render::{offscreen, sampler, frame::with_frame}, 
+    scene::node::SceneNode,
     system::{
-        error::{AppResult, GameError},
+        error::AppResult,
         event::AppEvent,
         shared::Shared,
     }
@@ -48,6 +52,22 @@ use crate::{
 /// 
 static FOCUSED_ITEM: Mutex<Option<(Items, Vec3, Vec3)>> = Mutex::new(None);
 
+/// #### 한국어 </br>
+/// 선택된 해상도 되돌리기 창 버튼의 색상 데이터를 담고 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Contains the color data of the selected resolution revert window button. </br>
+/// 
+static FOCUSED_REVERT_BTN: Mutex<Option<(utils::RevertWndButton, Vec3, Vec3)>> = Mutex::new(None);
+
+/// #### 한국어 </br>
+/// 선택된 설정 초기화 확인 창 버튼의 색상 데이터를 담고 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Contains the color data of the selected settings reset confirmation window button. </br>
+/// 
+static FOCUSED_RESET_BTN: Mutex<Option<(utils::ResetWndButton, Vec3, Vec3)>> = Mutex::new(None);
+
 
 /// #### 한국어 </br>
 /// 설정창의 인터페이스 옵션 목록입니다. </br> 
@@ -59,18 +79,69 @@ static FOCUSED_ITEM: Mutex<Option<(Items, Vec3, Vec3)>> = Mutex::new(None);
 enum Items {
     Language(Language), 
     Resolution(Resolution), 
+    Toggle(SettingToggle), 
     Volume(utils::VolumeOptions), 
+    Apply, 
+    Reset, 
     Return, 
 }
 
 pub fn handle_events(this: &mut TitleScene, shared: &mut Shared, event: Event<AppEvent>) -> AppResult<()> {
+    // (한국어) 해상도 되돌리기 창이 표시된 경우 해당 창의 입력만 처리합니다.
+    // (English Translation) When the resolution revert window is displayed, only input to that window is handled.
+    if this.setting_revert.is_some() {
+        return handle_revert_window_input(this, shared, &event);
+    }
+
+    // (한국어) 설정 초기화 확인 창이 열려 있는 경우 해당 창의 입력만 처리합니다.
+    // (English Translation) When the settings reset confirmation window is open, only input to that window is handled.
+    if this.setting_reset_window.captures_input() {
+        return handle_reset_window_input(this, shared, &event);
+    }
+
     handle_keyboard_input(this, shared, &event)?;
     handle_mouse_input(this, shared, &event)?;
     Ok(())
 }
 
-pub fn update(_this: &mut TitleScene, _shared: &mut Shared, _total_time: f64, _elapsed_time: f64) -> AppResult<()> {
-    Ok(())
+/// #### 한국어 </br>
+/// 커서가 설정 창에서 누를 수 있는 버튼 위에 있는 경우 `true`를 반환합니다. </br>
+/// 해상도 되돌리기 창이나 설정 초기화 확인 창이 열려 있는 경우 해당 창의 버튼만 확인합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns `true` if the cursor is over a button that can be pressed in the setting window. </br>
+/// When the resolution revert window or the settings reset confirmation window is open, only the buttons of that window are checked. </br>
+/// 
+pub fn hovers_setting_button(this: &TitleScene, shared: &Shared) -> bool {
+    if this.setting_revert.is_some() {
+        return cursor::is_over_any(shared, this.setting_revert_buttons.values().map(|(ui, _)| ui));
+    }
+
+    if this.setting_reset_window.captures_input() {
+        return cursor::is_over_any(shared, this.setting_reset_window.buttons.values().map(|(ui, _)| ui));
+    }
+
+    let buttons = [
+        &this.setting_return_button.0, 
+        &this.setting_apply_button.0, 
+        &this.setting_reset_button.0, 
+    ].into_iter()
+    .chain(this.setting_languages.values().map(|(ui, _)| ui))
+    .chain(this.setting_resolutions.values().map(|(ui, _)| ui))
+    .chain(this.setting_toggles.values().map(|(ui, _)| ui))
+    .chain(this.setting_volume_bar.values().map(|it| &it.handle));
+    return cursor::is_over_any(shared, buttons);
+}
+
+pub fn update(this: &mut TitleScene, shared: &mut Shared, _total_time: f64, elapsed_time: f64) -> AppResult<()> {
+    // (한국어) 설정 초기화 확인 창의 애니메이션을 갱신합니다.
+    // (English Translation) Updates the animation of the settings reset confirmation window.
+    {
+        let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+        this.setting_reset_window.update(queue, elapsed_time);
+    }
+
+    update_revert_countdown(this, shared, elapsed_time)
 }
 
 pub fn draw(this: &TitleScene, shared: &mut Shared) -> AppResult<()> {
@@ -79,115 +150,92 @@ pub fn draw(this: &TitleScene, shared: &mut Shared) -> AppResult<()> {
     let sprite_brush = shared.get::<Arc<SpriteBrush>>().unwrap();
     let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
     let ui_brush = shared.get::<Arc<UiBrush>>().unwrap();
-    let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
     let camera = shared.get::<Arc<GameCamera>>().unwrap();
     
-    // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-    // (English Translation) Wait until the previous operation is finished.
-    device.poll(wgpu::Maintain::Wait);
-
-    // (한국어) 다음 프레임을 가져옵니다.
-    // (English Translation) Get the next frame.
-    let frame = surface.get_current_texture()
-        .map_err(|err| game_err!(
-            "Failed to get next frame",
-            "Failed to get next frame for the following reasons: {}",
-            err.to_string()
-        ))?;
-
-    // (한국어) 프레임 버퍼의 텍스쳐 뷰를 생성합니다.
-    // (English Translation) Creates a texture view of the framebuffer.
-    let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-    // (한국어) 커맨드 버퍼를 생성합니다.
-    // (English Translation) Creates a command buffer.
-    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(SettingState(Background)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment { 
-                view: &view, 
-                resolve_target: None, 
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-        
-        // (한국어) 배경 오브젝트 그리기.
-        // (English Translation) Drawing background objects.
-        sprite_brush.draw(&mut rpass, [&this.background].into_iter());
-    }
+    with_frame(shared, this.clear_color(), |frame| {
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(SettingState(Background)))");
 
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(SettingState(Ui)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment { 
-                view: &view, 
-                resolve_target: None, 
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Load,
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-        
-        // (한국어) 사용자 인터페이스 그리기.
-        // (English Translation) Drawing the user interface.
-        let iter = [
-                &this.setting_return_button.0, 
-            ].into_iter()
-            .chain(this.setting_windows.iter())
-            .chain(this.setting_languages.values().map(|(it, _)| it))
-            .chain(this.setting_resolutions.values().map(|(it, _)| it))
-            .chain(this.setting_volume_background.values().map(|(it, _)| it))
-            .chain(this.setting_volume_bar.values());
-        ui_brush.draw(&mut rpass, iter);
-
-        let iter = [
-                &this.setting_return_button.1, 
-            ].into_iter()
-            .chain(this.setting_titles.iter())
-            .chain(this.setting_languages.values().map(|(_, it)| it))
-            .chain(this.setting_resolutions.values().map(|(_, it)| it))
-            .chain(this.setting_volume_background.values().map(|(_, it)| it));
-        text_brush.draw(&mut rpass, iter);
-    }
+            camera.bind(&mut rpass);
 
-    // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-    // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-    queue.submit(Some(encoder.finish()));
-    frame.present();
+            // (한국어) 배경 오브젝트 그리기.
+            // (English Translation) Drawing background objects.
+            sprite_brush.draw(&mut rpass, [&this.background].into_iter());
+        }
 
-    Ok(())
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(SettingState(Dim)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 뒤의 장면을 어둡게 하는 오버레이 그리기.
+            // (English Translation) Drawing the overlay that dims the scene behind.
+            this.dim_overlay.draw(ui_brush, &mut rpass);
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(SettingState(Ui)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 사용자 인터페이스 그리기.
+            // (English Translation) Drawing the user interface.
+            let iter = [
+                    &this.setting_return_button.0, 
+                    &this.setting_apply_button.0, 
+                    &this.setting_reset_button.0, 
+                ].into_iter()
+                .chain(this.setting_windows.iter())
+                .chain(this.setting_languages.values().map(|(it, _)| it))
+                .chain(this.setting_resolutions.values().map(|(it, _)| it))
+                .chain(this.setting_toggles.values().map(|(it, _)| it))
+                .chain(this.setting_volume_background.values().map(|(it, _)| it))
+                .chain(this.setting_volume_bar.values().map(|it| &it.handle));
+            ui_brush.draw(&mut rpass, iter);
+
+            let iter = [
+                    &this.setting_return_button.1, 
+                    &this.setting_apply_button.1, 
+                    &this.setting_reset_button.1, 
+                ].into_iter()
+                .chain(this.setting_titles.iter())
+                .chain(this.setting_languages.values().map(|(_, it)| it))
+                .chain(this.setting_resolutions.values().map(|(_, it)| it))
+                .chain(this.setting_toggles.values().map(|(_, it)| it))
+                .chain(this.setting_volume_background.values().map(|(_, it)| it));
+            text_brush.draw(&mut rpass, iter);
+        }
+
+        // (한국어) 설정 초기화 확인 창이 표시된 경우 창을 그립니다.
+        // (English Translation) Draws the settings reset confirmation window if it is displayed.
+        if !this.setting_reset_window.is_hidden() {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(SettingState(ResetWindow)))");
+
+            camera.bind(&mut rpass);
+            this.setting_reset_window.draw(ui_brush, text_brush, &mut rpass);
+        }
+
+        // (한국어) 해상도 되돌리기 창이 표시된 경우 창을 그립니다.
+        // (English Translation) Draws the resolution revert window if it is displayed.
+        if this.setting_revert.is_some() {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(SettingState(RevertWindow)))");
+
+            camera.bind(&mut rpass);
+
+            let iter = [
+                    &this.setting_revert_window.0, 
+                ].into_iter()
+                .chain(this.setting_revert_buttons.values().map(|(it, _)| it));
+            ui_brush.draw(&mut rpass, iter);
+
+            let iter = [
+                    &this.setting_revert_window.1, 
+                    &this.setting_revert_countdown, 
+                ].into_iter()
+                .chain(this.setting_revert_buttons.values().map(|(_, it)| it));
+            text_brush.draw(&mut rpass, iter);
+        }
+    })
 }
 
 fn handle_keyboard_input(this: &mut TitleScene, shared: &mut Shared, event: &Event<AppEvent>) -> AppResult<()> {
@@ -219,22 +267,36 @@ fn handle_keyboard_input(this: &mut TitleScene, shared: &mut Shared, event: &Eve
                                     text.update(queue, |data| data.color = (text_color, data.color.w).into());
                                 }
                             },
-                            Items::Volume(it) => {
-                                if let Some(ui) = this.setting_volume_bar.get(&it) {
+                            Items::Toggle(it) => {
+                                if let Some((ui, text)) = this.setting_toggles.get(&it) {
                                     ui.update(queue, |data| data.color = (ui_color, data.color.w).into());
+                                    text.update(queue, |data| data.color = (text_color, data.color.w).into());
+                                }
+                            },
+                            Items::Volume(it) => {
+                                if let Some(slider) = this.setting_volume_bar.get(&it) {
+                                    slider.handle.update(queue, |data| data.color = (ui_color, data.color.w).into());
+                                    slider.release();
                                 }
                             },
                             Items::Return => {
                                 this.setting_return_button.0.update(queue, |data| data.color = (ui_color, data.color.w).into());
                                 this.setting_return_button.1.update(queue, |data| data.color = (text_color, data.color.w).into());
+                            },
+                            Items::Apply => {
+                                this.setting_apply_button.0.update(queue, |data| data.color = (ui_color, data.color.w).into());
+                                this.setting_apply_button.1.update(queue, |data| data.color = (text_color, data.color.w).into());
+                            },
+                            Items::Reset => {
+                                this.setting_reset_button.0.update(queue, |data| data.color = (ui_color, data.color.w).into());
+                                this.setting_reset_button.1.update(queue, |data| data.color = (text_color, data.color.w).into());
                             }
                         }
                     }
 
-                    // (한국어) 다음 게임 장면 상태로 변경합니다. 
-                    // (English Translation) Change to the next game scene state. 
-                    this.timer = 0.0;
-                    this.state = TitleState::ExitSetting;
+                    // (한국어) 변경 사항을 취소하고 설정 창을 닫습니다.
+                    // (English Translation) Cancels the changes and closes the setting window.
+                    cancel_settings(this, shared)?;
                 }
             }, 
             _ => { /* empty */ }
@@ -260,10 +322,14 @@ fn handle_mouse_input(this: &mut TitleScene, shared: &mut Shared, event: &Event<
                     // (English Translation) Make sure the mouse cursor is inside the ui area. 
                     let select = [
                             (Items::Return, &this.setting_return_button.0), 
+                            (Items::Apply, &this.setting_apply_button.0), 
+                            (Items::Reset, &this.setting_reset_button.0), 
                         ].into_iter()
                         .chain(this.setting_languages.iter().map(|(&language, (it, _))| (Items::Language(language), it)))
                         .chain(this.setting_resolutions.iter().map(|(&resolution, (it, _))| (Items::Resolution(resolution), it)))
-                        .chain(this.setting_volume_bar.iter().map(|(&volume, it)| (Items::Volume(volume), it)))
+                        .chain(this.setting_toggles.iter().map(|(&toggle, (it, _))| (Items::Toggle(toggle), it)))
+                        .chain(this.setting_volume_bar.iter().map(|(&volume, it)| (Items::Volume(volume), &it.handle)))
+                        .chain(this.setting_volume_background.iter().map(|(&volume, (it, _))| (Items::Volume(volume), it)))
                         .find_map(|(it, ui)| {
                             ui.test(&(cursor_pos, camera)).then_some(it)
                         });
@@ -306,14 +372,27 @@ fn handle_mouse_input(this: &mut TitleScene, shared: &mut Shared, event: &Event<
                                     text.update(queue, |data| data.color *= Vec4::new(0.5, 0.5, 0.5, 1.0));
                                 }
                             },
-                            Items::Volume(volume) => {
-                                if let Some(ui) = this.setting_volume_bar.get(&volume) {
+                            Items::Toggle(toggle) => {
+                                if let Some((ui, text)) = this.setting_toggles.get(&toggle) {
                                     let ui_color = { ui.data.lock().expect("Failed to access variable.").color.xyz() };
+                                    let text_color = { text.data.lock().expect("Failed to access variable.").color.xyz() };
+                                    
+                                    let mut guard = FOCUSED_ITEM.lock().expect("Failed to access variable.");
+                                    *guard = Some((item, ui_color, text_color));
+
+                                    ui.update(queue, |data| data.color *= Vec4::new(0.5, 0.5, 0.5, 1.0));
+                                    text.update(queue, |data| data.color *= Vec4::new(0.5, 0.5, 0.5, 1.0));
+                                }
+                            },
+                            Items::Volume(volume) => {
+                                if let Some(slider) = this.setting_volume_bar.get(&volume) {
+                                    let ui_color = { slider.handle.data.lock().expect("Failed to access variable.").color.xyz() };
 
                                     let mut guard = FOCUSED_ITEM.lock().expect("Failed to access variable.");
                                     *guard = Some((item, ui_color, Vec3::ZERO));
 
-                                    ui.update(queue, |data| data.color *= Vec4::new(0.5, 0.5, 0.5, 1.0));
+                                    slider.handle.update(queue, |data| data.color *= Vec4::new(0.5, 0.5, 0.5, 1.0));
+                                    slider.grab();
                                 }
                             },
                             Items::Return => {
@@ -325,6 +404,26 @@ fn handle_mouse_input(this: &mut TitleScene, shared: &mut Shared, event: &Event<
 
                                 this.setting_return_button.0.update(queue, |data| data.color *= Vec4::new(0.5, 0.5, 0.5, 1.0));
                                 this.setting_return_button.1.update(queue, |data| data.color *= Vec4::new(0.5, 0.5, 0.5, 1.0));
+                            },
+                            Items::Apply => {
+                                let ui_color = { this.setting_apply_button.0.data.lock().expect("Failed to access variable.").color.xyz() };
+                                let text_color = { this.setting_apply_button.1.data.lock().expect("Failed to access variable.").color.xyz() };
+
+                                let mut guard = FOCUSED_ITEM.lock().expect("Failed to access variable.");
+                                *guard = Some((item, ui_color, text_color));
+
+                                this.setting_apply_button.0.update(queue, |data| data.color *= Vec4::new(0.5, 0.5, 0.5, 1.0));
+                                this.setting_apply_button.1.update(queue, |data| data.color *= Vec4::new(0.5, 0.5, 0.5, 1.0));
+                            },
+                            Items::Reset => {
+                                let ui_color = { this.setting_reset_button.0.data.lock().expect("Failed to access variable.").color.xyz() };
+                                let text_color = { this.setting_reset_button.1.data.lock().expect("Failed to access variable.").color.xyz() };
+
+                                let mut guard = FOCUSED_ITEM.lock().expect("Failed to access variable.");
+                                *guard = Some((item, ui_color, text_color));
+
+                                this.setting_reset_button.0.update(queue, |data| data.color *= Vec4::new(0.5, 0.5, 0.5, 1.0));
+                                this.setting_reset_button.1.update(queue, |data| data.color *= Vec4::new(0.5, 0.5, 0.5, 1.0));
                             }
                         };
 
@@ -348,15 +447,30 @@ fn handle_mouse_input(this: &mut TitleScene, shared: &mut Shared, event: &Event<
                                     text.update(queue, |data| data.color = (text_color, data.color.w).into());
                                 }
                             },
-                            Items::Volume(it) => {
-                                if let Some(ui) = this.setting_volume_bar.get(&it) {
+                            Items::Toggle(it) => {
+                                if let Some((ui, text)) = this.setting_toggles.get(&it) {
                                     ui.update(queue, |data| data.color = (ui_color, data.color.w).into());
+                                    text.update(queue, |data| data.color = (text_color, data.color.w).into());
+                                }
+                            },
+                            Items::Volume(it) => {
+                                if let Some(slider) = this.setting_volume_bar.get(&it) {
+                                    slider.handle.update(queue, |data| data.color = (ui_color, data.color.w).into());
+                                    slider.release();
                                     return ui_released(this, shared, item);
                                 }
                             },
                             Items::Return => {
                                 this.setting_return_button.0.update(queue, |data| data.color = (ui_color, data.color.w).into());
                                 this.setting_return_button.1.update(queue, |data| data.color = (text_color, data.color.w).into());
+                            },
+                            Items::Apply => {
+                                this.setting_apply_button.0.update(queue, |data| data.color = (ui_color, data.color.w).into());
+                                this.setting_apply_button.1.update(queue, |data| data.color = (text_color, data.color.w).into());
+                            },
+                            Items::Reset => {
+                                this.setting_reset_button.0.update(queue, |data| data.color = (ui_color, data.color.w).into());
+                                this.setting_reset_button.1.update(queue, |data| data.color = (text_color, data.color.w).into());
                             }
                         };
                         
@@ -364,9 +478,12 @@ fn handle_mouse_input(this: &mut TitleScene, shared: &mut Shared, event: &Event<
                         // (English Translation) Make sure the mouse cursor is inside the ui area. 
                         let select = [
                                 (Items::Return, &this.setting_return_button), 
+                                (Items::Apply, &this.setting_apply_button), 
+                                (Items::Reset, &this.setting_reset_button), 
                             ].into_iter()
                             .chain(this.setting_languages.iter().map(|(&language, it)| (Items::Language(language), it)))
                             .chain(this.setting_resolutions.iter().map(|(&resolution, it)| (Items::Resolution(resolution), it)))
+                            .chain(this.setting_toggles.iter().map(|(&toggle, it)| (Items::Toggle(toggle), it)))
                             .find_map(|(it, (ui, _))| {
                                 ui.test(&(cursor_pos, camera)).then_some(it)
                             });
@@ -405,6 +522,20 @@ fn ui_pressed(this: &mut TitleScene, shared: &mut Shared, item: Items) -> AppRes
         Items::Resolution(_) => {
             sound::play_click_sound(shared)
         },
+        Items::Toggle(_) => {
+            sound::play_click_sound(shared)
+        },
+        Items::Volume(_) => {
+            // (한국어) 손잡이를 누른 위치로 옮기고 끌기를 시작합니다.
+            // (English Translation) Moves the handle to the pressed position and starts dragging.
+            ui_dragged(this, shared, item)
+        },
+        Items::Apply => {
+            sound::play_click_sound(shared)
+        },
+        Items::Reset => {
+            sound::play_click_sound(shared)
+        },
         Items::Return => {
             sound::play_cancel_sound(shared)
         },
@@ -424,46 +555,58 @@ fn ui_released(this: &mut TitleScene, shared: &mut Shared, item: Items) -> AppRe
         Items::Resolution(new) => {
             change_resolution(this, shared, new)
         },
-        Items::Volume(option) => match option {
-            utils::VolumeOptions::Background => Ok(()), 
-            utils::VolumeOptions::Effect => sound::play_click_sound(shared),
-            utils::VolumeOptions::Voice => {
-                const NUM_HIDDEN: usize = 10;
+        Items::Toggle(toggle) => {
+            change_toggle(this, shared, toggle)
+        },
+        Items::Volume(option) => {
+            match option {
+                utils::VolumeOptions::Background => Ok(()), 
+                utils::VolumeOptions::Effect => sound::play_click_sound(shared),
+                utils::VolumeOptions::Voice => {
+                    const NUM_HIDDEN: usize = 10;
 
-                // (한국어) 사용할 공유 객체들을 가져옵니다.
-                // (English Translation) Get shared objects to use.
-                let (cnt, sink) = shared.pop::<(usize, Sink)>().unwrap();
-                let settings = shared.get::<Settings>().unwrap();
-                let asset_bundle = shared.get::<AssetBundle>().unwrap();
-
-                // (한국어) 캐릭터 목소리 데이터를 가져옵니다.
-                // (English Translation) Get character voice data. 
-                let source = if cnt % NUM_HIDDEN == 0 {
-                    asset_bundle.get(path::YUUKA_HIDDEN_SOUND_PATH)?
-                        .read(&sound::SoundDecoder)?
-                } else {
-                    asset_bundle.get(path::YUUKA_TITLE_SOUND_PATH)?
-                        .read(&sound::SoundDecoder)?
-                };
-
-                // (한국어) 캐릭터 목소리를 재생시킵니다.
-                // (English Translation) Play the character's voice.
-                sink.stop();
-                sink.set_volume(settings.voice_volume.norm());
-                sink.append(source);
-
-                // (한국어) 사용한 공유 객체들 반환합니다.
-                // (English Translation) Returns the shared objects used. 
-                shared.push(((cnt + 1) % NUM_HIDDEN, sink));
-
-                Ok(())
-            },
+                    // (한국어) 사용할 공유 객체들을 가져옵니다.
+                    // (English Translation) Get shared objects to use.
+                    let (cnt, sink) = shared.pop::<(usize, sound::SoundSink)>().unwrap();
+                    let settings = shared.get::<Settings>().unwrap();
+                    let asset_bundle = shared.get::<AssetBundle>().unwrap();
+
+                    // (한국어) 캐릭터 목소리 데이터를 가져옵니다.
+                    // (English Translation) Get character voice data. 
+                    let rel_path = match cnt % NUM_HIDDEN == 0 {
+                        true => path::YUUKA_HIDDEN_SOUND_PATH, 
+                        false => path::YUUKA_TITLE_SOUND_PATH, 
+                    };
+                    let source = asset_bundle.get(rel_path)?
+                        .read(&sound::SoundDecoder)?;
+                    asset_bundle.release(rel_path);
+
+                    // (한국어) 캐릭터 목소리를 재생시킵니다.
+                    // (English Translation) Play the character's voice.
+                    sink.stop();
+                    sink.set_volume(settings.voice_volume.gain());
+                    sink.append(source);
+
+                    // (한국어) 사용한 공유 객체들 반환합니다.
+                    // (English Translation) Returns the shared objects used. 
+                    shared.push(((cnt + 1) % NUM_HIDDEN, sink));
+
+                    Ok(())
+                },
+            }
         },
-        Items::Return => {
-            this.timer = 0.0;
-            this.state = TitleState::ExitSetting;
+        Items::Apply => {
+            confirm_settings(this, shared)
+        },
+        Items::Reset => {
+            // (한국어) 실수로 초기화하지 않도록 확인 창을 엽니다.
+            // (English Translation) Opens a confirmation window to avoid resetting by accident.
+            this.setting_reset_window.show();
             Ok(())
         },
+        Items::Return => {
+            cancel_settings(this, shared)
+        },
         _ => Ok(())
     }
 }
@@ -471,57 +614,36 @@ fn ui_released(this: &mut TitleScene, shared: &mut Shared, item: Items) -> AppRe
 #[allow(unused_variables)]
 #[allow(unreachable_patterns)]
 fn ui_dragged(this: &mut TitleScene, shared: &mut Shared, item: Items) -> AppResult<()> {
-    use crate::nodes::path;
-
     match item {
         Items::Volume(option) => {
             // (한국어) 사용할 공유 객체들을 가져옵니다.
             // (English Translation) Get shared object to use. 
-            let mut settings = shared.pop::<Settings>().unwrap();
+            let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
             let camera = shared.get::<Arc<GameCamera>>().unwrap();
             let cursor_pos = shared.get::<PhysicalPosition<f64>>().unwrap();
-            let asset_bundle = shared.get::<AssetBundle>().unwrap();
-
-            // (한국어) 인터페이스의 위치를 계산합니다.
-            // (English Translation) Calculate the position of the interface. 
-            const RANGE: i32 = utils::SETTING_VOLUME_RANGE_MAX - utils::SETTING_VOLUME_RANGE_MIN;
-            let (scale, center) = {
-                let guard = camera.data.lock().expect("Failed to access variable.");
-                (guard.scale_factor, guard.viewport.x + guard.viewport.width / 2.0)
+
+            // (한국어) 손잡이의 위치를 갱신하고 새로운 볼륨 값을 계산합니다.
+            // (English Translation) Updates the position of the handle and calculates the new volume value.
+            let value = match this.setting_volume_bar.get(&option)
+                .and_then(|slider| slider.handle_drag(queue, cursor_pos, camera)) {
+                Some(value) => value, 
+                None => return Ok(()), 
             };
-            
-            let pos = (cursor_pos.x as f32 - center).clamp(
-                utils::SETTING_VOLUME_RANGE_MIN as f32 * scale, 
-                utils::SETTING_VOLUME_RANGE_MAX as f32 * scale
-            ) / scale;
-            let pos = pos as i32;
-
-            // (한국어) 인터페이스의 위치를 갱신합니다.
-            // (English Translation) Updates the position of the interface.
-            let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-            if let Some(ui) = this.setting_volume_bar.get(&option) {
-                ui.update(queue, |data| {
-                    data.margin.set_left(pos - utils::VOLUME_BAR_WIDTH / 2);
-                    data.margin.set_right(pos + utils::VOLUME_BAR_WIDTH / 2);
-                });
-            }
-            
+
+            let mut settings = shared.pop::<Settings>().unwrap();
+
             // (한국어) 계산된 볼륨 값을 설정합니다.
             // (English Translation) Sets the calculated volume value. 
-            let delta = pos - utils::SETTING_VOLUME_RANGE_MIN;
-            let volume = (delta as f32 / RANGE as f32 * 100.0) as u8;
+            let volume = (value * 100.0) as u8;
             match option {
                 utils::VolumeOptions::Background => {
                     settings.background_volume.set(volume);
-                    shared.get::<Sink>().unwrap().set_volume(settings.background_volume.norm());
+                    shared.get_mut::<sound::BgmPlayer>().unwrap().set_volume(settings.background_volume.gain());
                 }, 
                 utils::VolumeOptions::Effect => settings.effect_volume.set(volume),
                 utils::VolumeOptions::Voice => settings.voice_volume.set(volume), 
             };
 
-            // (한국어) 갱신된 설정을 저장합니다.
-            // (English Translation) Save updated settings. 
-            asset_bundle.get(path::SETTINGS_PATH)?.write(&SettingsEncoder, &settings)?;
             shared.push(settings);
 
             Ok(())
@@ -530,6 +652,108 @@ fn ui_dragged(this: &mut TitleScene, shared: &mut Shared, item: Items) -> AppRes
     }
 }
 
+/// #### 한국어 </br>
+/// 현재 설정을 설정 파일에 저장합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Saves the current settings to the settings file. </br>
+/// 
+fn save_settings(shared: &Shared) -> AppResult<()> {
+    use crate::nodes::path;
+
+    let settings = shared.get::<Settings>().unwrap();
+    let asset_bundle = shared.get::<AssetBundle>().unwrap();
+    asset_bundle.get(path::SETTINGS_PATH)?.write(&SettingsEncoder, settings)?;
+    Ok(())
+}
+
+/// #### 한국어 </br>
+/// 변경된 설정을 설정 파일에 저장하고 설정 창을 닫습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Saves the changed settings to the settings file and closes the setting window. </br>
+/// 
+fn confirm_settings(this: &mut TitleScene, shared: &mut Shared) -> AppResult<()> {
+    shared.pop::<SettingsSnapshot>();
+    save_settings(shared)?;
+
+    close_layer(this);
+    Ok(())
+}
+
+/// #### 한국어 </br>
+/// 설정 창이 열렸을 때의 설정으로 되돌리고 설정 창을 닫습니다. </br>
+/// 표시 언어, 해상도, 재생 중인 음향의 볼륨도 함께 되돌립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reverts to the settings at the time the setting window was opened and closes the setting window. </br>
+/// The display language, the resolution and the volume of the playing sounds are reverted as well. </br>
+/// 
+fn cancel_settings(this: &mut TitleScene, shared: &mut Shared) -> AppResult<()> {
+    if let Some(snapshot) = shared.pop::<SettingsSnapshot>() {
+        let original = *snapshot.original();
+        change_language(this, shared, original.language)?;
+        apply_resolution(shared, original.resolution)?;
+
+        let mut settings = shared.pop::<Settings>().unwrap();
+        snapshot.restore(&mut settings);
+        shared.get_mut::<sound::BgmPlayer>().unwrap().set_volume(settings.background_volume.gain());
+        shared.push(settings);
+        cursor::apply_custom_cursor(shared);
+        update_toggle_colors(this, shared);
+    }
+
+    close_layer(this);
+    Ok(())
+}
+
+/// #### 한국어 </br>
+/// 모든 설정을 기본값으로 되돌리고 바로 적용한 뒤 설정 파일에 저장합니다. </br>
+/// 초기화는 확정된 변경이므로 이후 설정 창을 취소해도 되돌려지지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Resets all settings to their default values, applies them immediately and saves them to the settings file. </br>
+/// The reset is a confirmed change, so it is not reverted even if the setting window is canceled afterwards. </br>
+/// 
+fn reset_settings(this: &mut TitleScene, shared: &mut Shared) -> AppResult<()> {
+    let defaults = Settings::default();
+    change_language(this, shared, defaults.language)?;
+    restore_revert_button_color(this, shared);
+    this.setting_revert = None;
+    apply_resolution(shared, defaults.resolution)?;
+
+    let (settings, snapshot) = user::reset_settings(&shared.pop::<Settings>().unwrap());
+
+    // (한국어) 재생 중인 음향의 볼륨과 볼륨 조절 막대를 갱신합니다.
+    // (English Translation) Updates the volume of the playing sounds and the volume control bars.
+    shared.get_mut::<sound::BgmPlayer>().unwrap().set_volume(settings.background_volume.gain());
+    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+    for (option, slider) in this.setting_volume_bar.iter() {
+        let volume = match option {
+            utils::VolumeOptions::Background => settings.background_volume, 
+            utils::VolumeOptions::Effect => settings.effect_volume, 
+            utils::VolumeOptions::Voice => settings.voice_volume, 
+        };
+        slider.set_value(queue, volume.norm());
+    }
+
+    shared.push(snapshot);
+    shared.push(settings);
+    cursor::apply_custom_cursor(shared);
+    update_toggle_colors(this, shared);
+
+    // (한국어) 텍스처 품질이 바뀐 경우 공용 텍스처 샘플러를 다시 생성합니다.
+    // 현재 게임 장면의 객체들은 다음 게임 장면을 불러올 때 새로운 샘플러를 사용합니다.
+    // 
+    // (English Translation) 
+    // If the texture quality has changed, creates the shared texture sampler again.
+    // Objects of the current game scene use the new sampler when the next game scene is loaded.
+    // 
+    sampler::apply_texture_quality(shared);
+    offscreen::apply_render_scale(shared);
+    save_settings(shared)
+}
+
 fn change_language(this: &mut TitleScene, shared: &mut Shared, new: Language) -> AppResult<()> {    
     use crate::nodes::path;
 
@@ -551,21 +775,72 @@ fn change_language(this: &mut TitleScene, shared: &mut Shared, new: Language) ->
     let script = match settings.language {
         Language::Korean => asset_bundle.get(path::KOR_SCRIPTS_PATH)?
             .read(&ScriptDecoder)?, 
-        Language::Unknown => panic!("The given language is an unknown language.")
+        Language::Unknown => asset_bundle.get(path::KOR_SCRIPTS_PATH)?
+            .read(&ScriptDecoder)?, 
     };
 
     // (한국어) 현재 게임 장면의 표시 언어를 변경합니다.
     // (English Translation) Change the display language of the current game scene. 
+    apply_script(this, shared, &script)?;
+    script::set_window_title(shared.get::<Arc<Window>>().unwrap(), Some(&script));
+
+    // (한국어) 공유 객체를 갱신합니다.
+    // (English Translation) Updates a shared object. 
+    shared.push(settings);
+    shared.push(Arc::new(script));
+
+    Ok(())
+}
+
+/// #### 한국어 </br>
+/// 주어진 스크립트로 타이틀 장면의 모든 텍스트를 갱신합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Updates all texts in the title scene with the given script. </br>
+/// 
+pub fn apply_script(this: &mut TitleScene, shared: &Shared, script: &Script) -> AppResult<()> {
     let device = shared.get::<Arc<wgpu::Device>>().unwrap();
     let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
     let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
-    const MENU: [(utils::MenuButtons, ScriptTags); 3] = [
+    const MENU: [(utils::MenuButtons, ScriptTags); 4] = [
         (utils::MenuButtons::Start, ScriptTags::TitleStartButton), 
         (utils::MenuButtons::Setting, ScriptTags::TitleSettingButton), 
         (utils::MenuButtons::Exit, ScriptTags::TitleExitButton),
+        (utils::MenuButtons::Continue, ScriptTags::TitleContinueButton),
     ];
     for (idx, tag) in MENU {
-        this.menu_buttons[idx as usize].1.change(
+        // (한국어) `이어하기` 버튼은 이어할 스테이지가 있는 경우에만 존재합니다.
+        // (English Translation) The `continue` button exists only if there is a stage to continue.
+        if let Some((_, text)) = this.menu_buttons.get_mut(idx as usize) {
+            text.change(
+                script.get(tag)?, 
+                device, 
+                queue, 
+                text_brush
+            );
+        }
+    }
+    if let Some(text) = this.gpu_warning.as_mut() {
+        text.change(
+            script.get(ScriptTags::TitleGpuWarning)?, 
+            device, 
+            queue, 
+            text_brush
+        );
+    }
+
+    this.setting_revert_window.1.change(
+        script.get(ScriptTags::SettingResolutionRevertMessage)?, 
+        device, 
+        queue, 
+        text_brush
+    );
+    const REVERT_BTN: [(utils::RevertWndButton, ScriptTags); 2] = [
+        (utils::RevertWndButton::Keep, ScriptTags::SettingResolutionKeepButton), 
+        (utils::RevertWndButton::Revert, ScriptTags::SettingResolutionRevertButton), 
+    ];
+    for (key, tag) in REVERT_BTN {
+        this.setting_revert_buttons.get_mut(&key).unwrap().1.change(
             script.get(tag)?, 
             device, 
             queue, 
@@ -611,51 +886,416 @@ fn change_language(this: &mut TitleScene, shared: &mut Shared, new: Language) ->
             text_brush
         );
     }
+    for (&toggle, (_, text)) in this.setting_toggles.iter_mut() {
+        text.change(
+            script.get(utils::toggle_script_tag(toggle))?, 
+            device, 
+            queue, 
+            text_brush
+        );
+    }
     this.setting_return_button.1.change(
         script.get(ScriptTags::SettingReturnButton)?, 
         device, 
         queue, 
         text_brush
     );
+    this.setting_apply_button.1.change(
+        script.get(ScriptTags::SettingApplyButton)?, 
+        device, 
+        queue, 
+        text_brush
+    );
+    this.setting_reset_button.1.change(
+        script.get(ScriptTags::SettingResetButton)?, 
+        device, 
+        queue, 
+        text_brush
+    );
+
+    const RESET_WND: [(utils::ResetWndButton, ScriptTags); 2] = [
+        (utils::ResetWndButton::Yes, ScriptTags::SettingResetOkayButton), 
+        (utils::ResetWndButton::No, ScriptTags::SettingResetCancelButton), 
+    ];
+    this.setting_reset_window.texts[0].change(
+        script.get(ScriptTags::SettingResetReconfirmMessage)?, 
+        device, 
+        queue, 
+        text_brush
+    );
+    for (key, tag) in RESET_WND {
+        this.setting_reset_window.buttons.get_mut(&key).unwrap().1.change(
+            script.get(tag)?, 
+            device, 
+            queue, 
+            text_brush
+        );
+    }
 
-    // (한국어) 설정 파일을 갱신합니다.
-    // (English Translation) Updates the settings file.
-    asset_bundle.get(path::SETTINGS_PATH)?.write(&SettingsEncoder, &settings)?;
+    Ok(())
+}
 
-    // (한국어) 공유 객체를 갱신합니다.
-    // (English Translation) Updates a shared object. 
+/// #### 한국어 </br>
+/// 주어진 설정을 켜거나 끄고 설정 버튼의 색상을 갱신합니다. </br>
+/// 설정 파일에는 설정 창에서 확인한 경우에만 저장됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Turns the given setting on or off and updates the color of the setting button. </br>
+/// It is only saved to the settings file when confirmed in the setting window. </br>
+/// 
+fn change_toggle(this: &mut TitleScene, shared: &mut Shared, toggle: SettingToggle) -> AppResult<()> {
+    let mut settings = shared.pop::<Settings>().unwrap();
+    toggle.flip(&mut settings);
     shared.push(settings);
-    shared.push(Arc::new(script));
 
+    cursor::apply_custom_cursor(shared);
+    update_toggle_colors(this, shared);
     Ok(())
 }
 
-fn change_resolution(_this: &mut TitleScene, shared: &mut Shared, new: Resolution) -> AppResult<()> {
-    use crate::nodes::path;
-    use crate::components::user::set_window_size;
+/// #### 한국어 </br>
+/// 현재 설정에 맞게 설정 버튼들의 색상을 갱신합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Updates the colors of the setting buttons to match the current settings. </br>
+/// 
+fn update_toggle_colors(this: &TitleScene, shared: &Shared) {
+    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+    let settings = shared.get::<Settings>().unwrap();
+    for (toggle, (ui, _)) in this.setting_toggles.iter() {
+        let color = utils::toggle_color(toggle.is_on(settings));
+        ui.update(queue, |data| data.color = color);
+    }
+}
+
+fn change_resolution(this: &mut TitleScene, shared: &mut Shared, new: Resolution) -> AppResult<()> {
+    // (한국어) 
+    // 해상도가 변경된 경우 이전 해상도로 되돌리기 위한 카운트다운을 시작합니다.
+    //
+    // (English Translation)
+    // If the resolution has changed, starts a countdown to revert to the previous resolution.
+    //
+    if let Some(prev) = apply_resolution(shared, new)? {
+        let device = shared.get::<Arc<wgpu::Device>>().unwrap();
+        let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+        let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
+        this.setting_revert_countdown.change(
+            &format!("{}", utils::RESOLUTION_REVERT_SEC as u32), 
+            device, 
+            queue, 
+            text_brush
+        );
+        this.setting_revert = Some((prev, utils::RESOLUTION_REVERT_SEC));
+    }
+
+    Ok(())
+}
+
+/// #### 한국어 </br>
+/// 주어진 해상도를 적용합니다. 설정 파일에는 설정 창에서 확인한 경우에만 저장됩니다. </br>
+/// 해상도가 변경된 경우 이전 해상도를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Applies the given resolution. It is only saved to the settings file when confirmed in the setting window. </br>
+/// Returns the previous resolution if the resolution has changed. </br>
+/// 
+fn apply_resolution(shared: &mut Shared, new: Resolution) -> AppResult<Option<Resolution>> {
+    use crate::render::resize_render_ctx;
+    use crate::components::user::{is_resolution_supported, set_window_size};
 
     // (한국어) 현재 해상도와 같을 경우 실행하지 않습니다.
     // (English Translation) If it is the same as the current resolution, it will not run.
     let settings = shared.get::<Settings>().unwrap();
     if settings.resolution == new {
-        return Ok(());
+        return Ok(None);
+    }
+
+    // (한국어) 현재 모니터보다 큰 해상도일 경우 무시합니다.
+    // (English Translation) Ignores a resolution larger than the current monitor.
+    let window = shared.get::<Arc<Window>>().unwrap().clone();
+    if !is_resolution_supported(&window, new) {
+        log::warn!("The resolution {:?} is larger than the current monitor.", new);
+        return Ok(None);
     }
 
     // (한국어) 사용자가 선택한 해상도로 설정합니다.
     // (English Translation) Set to the resolution selected by the user.
     let mut settings = shared.pop::<Settings>().unwrap();
-    let window = shared.get::<Arc<Window>>().unwrap();
-    settings.resolution = set_window_size(window, new)?;
-    
-
-    // (한국어) 설정 파일을 갱신합니다.
-    // (English Translation) Updates the settings file.
-    let asset_bundle = shared.get::<AssetBundle>().unwrap();
-    asset_bundle.get(path::SETTINGS_PATH)?.write(&SettingsEncoder, &settings)?;
+    let prev = settings.resolution;
+    settings.resolution = set_window_size(&window, new)?;
 
     // (한국어) 공유 객체를 갱신합니다.
     // (English Translation) Updates a shared object. 
+    let changed = settings.resolution != prev;
     shared.push(settings);
 
+    // (한국어) 
+    // 변경된 윈도우 크기를 바로 적용합니다.
+    // 크기 변경이 나중에 적용되는 경우 `Resized` 이벤트에서 다시 설정됩니다.
+    //
+    // (English Translation)
+    // Applies the changed window size immediately.
+    // If the resize is applied later, it is reconfigured in the `Resized` event.
+    //
+    resize_render_ctx(&window, shared, None);
+
+    return Ok(changed.then_some(prev));
+}
+
+/// #### 한국어 </br>
+/// 해상도 되돌리기 카운트다운을 갱신합니다. </br>
+/// 시간이 다 된 경우 이전 해상도로 되돌립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Updates the resolution revert countdown. </br>
+/// When time runs out, it reverts to the previous resolution. </br>
+/// 
+fn update_revert_countdown(this: &mut TitleScene, shared: &mut Shared, elapsed_time: f64) -> AppResult<()> {
+    if let Some((prev, remaining)) = this.setting_revert.as_mut() {
+        let last_sec = remaining.ceil() as u32;
+        *remaining = (*remaining - elapsed_time).max(0.0);
+        let sec = remaining.ceil() as u32;
+        let prev = *prev;
+
+        if sec == 0 {
+            return revert_resolution(this, shared, prev);
+        }
+
+        if sec != last_sec {
+            let device = shared.get::<Arc<wgpu::Device>>().unwrap();
+            let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+            let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
+            this.setting_revert_countdown.change(
+                &format!("{}", sec), 
+                device, 
+                queue, 
+                text_brush
+            );
+        }
+    }
+
     Ok(())
-}
\ No newline at end of file
+}
+
+/// #### 한국어 </br>
+/// 이전 해상도로 되돌리고 해상도 되돌리기 창을 닫습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reverts to the previous resolution and closes the resolution revert window. </br>
+/// 
+fn revert_resolution(this: &mut TitleScene, shared: &mut Shared, prev: Resolution) -> AppResult<()> {
+    restore_revert_button_color(this, shared);
+    this.setting_revert = None;
+    apply_resolution(shared, prev)?;
+    Ok(())
+}
+
+/// #### 한국어 </br>
+/// 선택된 해상도 되돌리기 창 버튼의 색상을 원래대로 되돌립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Restores the color of the selected resolution revert window button. </br>
+/// 
+fn restore_revert_button_color(this: &TitleScene, shared: &Shared) -> Option<utils::RevertWndButton> {
+    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+    let mut guard = FOCUSED_REVERT_BTN.lock().expect("Failed to access variable.");
+    let (key, ui_color, text_color) = guard.take()?;
+    if let Some((ui, text)) = this.setting_revert_buttons.get(&key) {
+        ui.update(queue, |data| data.color = (ui_color, data.color.w).into());
+        text.update(queue, |data| data.color = (text_color, data.color.w).into());
+    }
+    return Some(key);
+}
+
+/// #### 한국어 </br>
+/// 해상도 되돌리기 창의 입력 이벤트를 처리합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Handles input events for the resolution revert window. </br>
+/// 
+fn handle_revert_window_input(this: &mut TitleScene, shared: &mut Shared, event: &Event<AppEvent>) -> AppResult<()> {
+    let prev = match this.setting_revert {
+        Some((prev, _)) => prev, 
+        None => return Ok(()), 
+    };
+
+    match event {
+        Event::WindowEvent { event, .. } => match event {
+            WindowEvent::KeyboardInput { event, .. } => 
+            if let PhysicalKey::Code(code) = event.physical_key {
+                if KeyCode::Escape == code && event.state.is_pressed() {
+                    sound::play_cancel_sound(shared)?;
+                    revert_resolution(this, shared, prev)?;
+                }
+            }, 
+            WindowEvent::MouseInput { state, button, .. } => {
+                // (한국어) 사용할 공유 객체들을 가져옵니다.
+                // (English Translation) Get shared objects to use.
+                let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+                let camera = shared.get::<Arc<GameCamera>>().unwrap();
+                let cursor_pos = shared.get::<PhysicalPosition<f64>>().unwrap();
+
+                // (한국어) 마우스 커서가 ui 영역 안에 있는지 확인합니다.
+                // (English Translation) Make sure the mouse cursor is inside the ui area. 
+                let select = this.setting_revert_buttons.iter()
+                    .find_map(|(&key, (ui, _))| {
+                        ui.test(&(cursor_pos, camera)).then_some(key)
+                    });
+
+                if MouseButton::Left == *button && state.is_pressed() {
+                    if let Some((key, (ui, text))) = select.and_then(|key| this.setting_revert_buttons.get(&key).map(|it| (key, it))) {
+                        let ui_color = { ui.data.lock().expect("Failed to access variable.").color.xyz() };
+                        let text_color = { text.data.lock().expect("Failed to access variable.").color.xyz() };
+
+                        let mut guard = FOCUSED_REVERT_BTN.lock().expect("Failed to access variable.");
+                        *guard = Some((key, ui_color, text_color));
+
+                        ui.update(queue, |data| data.color *= Vec4::new(0.5, 0.5, 0.5, 1.0));
+                        text.update(queue, |data| data.color *= Vec4::new(0.5, 0.5, 0.5, 1.0));
+                    }
+                } else if MouseButton::Left == *button && !state.is_pressed() {
+                    // (한국어) 선택된 ui가 이전에 선택된 ui와 일치하는 경우:
+                    // (English Translation) If the selected ui matches a previously selected ui:
+                    let focused = restore_revert_button_color(this, shared);
+                    if let Some(key) = focused.filter(|&key| Some(key) == select) {
+                        match key {
+                            utils::RevertWndButton::Keep => {
+                                sound::play_click_sound(shared)?;
+                                this.setting_revert = None;
+                            },
+                            utils::RevertWndButton::Revert => {
+                                sound::play_cancel_sound(shared)?;
+                                revert_resolution(this, shared, prev)?;
+                            },
+                        }
+                    }
+                }
+            },
+            _ => { /* empty */ }
+        }, 
+        _ => { /* empty */ }
+    }
+
+    Ok(())
+}
+
+/// #### 한국어 </br>
+/// 선택된 설정 초기화 확인 창 버튼의 색상을 원래대로 되돌립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Restores the color of the selected settings reset confirmation window button. </br>
+/// 
+fn restore_reset_button_color(this: &TitleScene, shared: &Shared) -> Option<utils::ResetWndButton> {
+    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+    let mut guard = FOCUSED_RESET_BTN.lock().expect("Failed to access variable.");
+    let (key, ui_color, text_color) = guard.take()?;
+    if let Some((ui, text)) = this.setting_reset_window.buttons.get(&key) {
+        ui.update(queue, |data| data.color = (ui_color, data.color.w).into());
+        text.update(queue, |data| data.color = (text_color, data.color.w).into());
+    }
+    return Some(key);
+}
+
+/// #### 한국어 </br>
+/// 설정 초기화 확인 창의 버튼이 눌렸을 때의 동작을 처리합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Handles the action when a button in the settings reset confirmation window is pressed. </br>
+/// 
+fn reset_window_released(this: &mut TitleScene, shared: &mut Shared, key: utils::ResetWndButton) -> AppResult<()> {
+    this.setting_reset_window.hide();
+    match key {
+        utils::ResetWndButton::Yes => {
+            sound::play_click_sound(shared)?;
+            reset_settings(this, shared)
+        },
+        utils::ResetWndButton::No => {
+            sound::play_cancel_sound(shared)
+        },
+    }
+}
+
+/// #### 한국어 </br>
+/// 설정 초기화 확인 창의 입력 이벤트를 처리합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Handles input events for the settings reset confirmation window. </br>
+/// 
+fn handle_reset_window_input(this: &mut TitleScene, shared: &mut Shared, event: &Event<AppEvent>) -> AppResult<()> {
+    match event {
+        Event::WindowEvent { event, .. } => match event {
+            WindowEvent::KeyboardInput { event, .. } => 
+            if let PhysicalKey::Code(code) = event.physical_key {
+                if event.repeat || !event.state.is_pressed() {
+                    return Ok(());
+                }
+
+                // (한국어) 키보드 포커스 입력을 처리합니다. 포커스된 버튼의 활성화는 클릭과 같이 처리됩니다.
+                // (English Translation) Handles keyboard focus input. Activating the focused button is handled like a click.
+                let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+                match this.setting_reset_window.handle_key(queue, code) {
+                    Some(FocusAction::Activate(key)) => {
+                        restore_reset_button_color(this, shared);
+                        return reset_window_released(this, shared, key);
+                    },
+                    Some(FocusAction::Moved(_)) => return Ok(()), 
+                    None => { /* empty */ }
+                }
+
+                if KeyCode::Escape == code {
+                    restore_reset_button_color(this, shared);
+                    if this.setting_reset_window.dismiss() {
+                        sound::play_cancel_sound(shared)?;
+                    }
+                }
+            }, 
+            WindowEvent::MouseInput { state, button, .. } => {
+                // (한국어) 사용할 공유 객체들을 가져옵니다.
+                // (English Translation) Get shared objects to use.
+                let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+                let camera = shared.get::<Arc<GameCamera>>().unwrap();
+                let cursor_pos = shared.get::<PhysicalPosition<f64>>().unwrap();
+
+                if MouseButton::Left == *button && state.is_pressed() {
+                    // (한국어) 
+                    // 창이 모든 클릭을 가로챕니다. 
+                    // 배경막을 클릭한 경우 초기화하지 않고 창을 닫습니다.
+                    //
+                    // (English Translation) 
+                    // The window captures every click. 
+                    // If the backdrop is clicked, closes the window without resetting.
+                    //
+                    let select = match this.setting_reset_window.hit_test(cursor_pos, camera) {
+                        Some(ModalHit::Button(key)) => this.setting_reset_window.buttons.get_key_value(&key), 
+                        Some(ModalHit::Dismissed) => return sound::play_cancel_sound(shared), 
+                        _ => None, 
+                    };
+
+                    if let Some((&key, (ui, text))) = select {
+                        let ui_color = { ui.data.lock().expect("Failed to access variable.").color.xyz() };
+                        let text_color = { text.data.lock().expect("Failed to access variable.").color.xyz() };
+
+                        let mut guard = FOCUSED_RESET_BTN.lock().expect("Failed to access variable.");
+                        *guard = Some((key, ui_color, text_color));
+
+                        ui.update(queue, |data| data.color *= Vec4::new(0.5, 0.5, 0.5, 1.0));
+                        text.update(queue, |data| data.color *= Vec4::new(0.5, 0.5, 0.5, 1.0));
+                    }
+                } else if MouseButton::Left == *button && !state.is_pressed() {
+                    // (한국어) 선택된 ui가 이전에 선택된 ui와 일치하는 경우:
+                    // (English Translation) If the selected ui matches a previously selected ui:
+                    let focused = restore_reset_button_color(this, shared);
+                    let select = this.setting_reset_window.buttons.iter()
+                        .find_map(|(&key, (ui, _))| ui.test(&(cursor_pos, camera)).then_some(key));
+                    if let Some(key) = focused.filter(|&key| Some(key) == select) {
+                        reset_window_released(this, shared, key)?;
+                    }
+                }
+            },
+            _ => { /* empty */ }
+        }, 
+        _ => { /* empty */ }
+    }
+
+    Ok(())
+}
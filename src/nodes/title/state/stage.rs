@@ -8,7 +8,6 @@ use winit::{
 };
 
 use crate::{
-    game_err,
     components::{
         ui::UiBrush, 
         sprite::SpriteBrush,
@@ -18,11 +17,13 @@ use crate::{
     },
     nodes::title::{
         TitleScene,
-        state::TitleState, 
+        state::{TitleState, open_layer, close_layer}, 
+        utils, 
     },
-    render::depth::DepthBuffer,
+    render::frame::with_frame,
+    scene::node::SceneNode,
     system::{
-        error::{AppResult, GameError},
+        error::AppResult,
         event::AppEvent,
         shared::Shared,
     }
@@ -61,129 +62,40 @@ pub fn draw(this: &TitleScene, shared: &mut Shared) -> AppResult<()> {
     // (English Translation) Get shared object to use.
     let sprite_brush = shared.get::<Arc<SpriteBrush>>().unwrap();
     let ui_brush = shared.get::<Arc<UiBrush>>().unwrap();
-    let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
-    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
-    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
-    let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
     let camera = shared.get::<Arc<GameCamera>>().unwrap();
 
     
-    // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
-    // (English Translation) Wait until the previous operation is finished.
-    device.poll(wgpu::Maintain::Wait);
-
-    // (한국어) 다음 프레임을 가져옵니다.
-    // (English Translation) Get the next frame.
-    let frame = surface.get_current_texture()
-        .map_err(|err| game_err!(
-            "Failed to get next frame",
-            "Failed to get next frame for the following reasons: {}",
-            err.to_string()
-        ))?;
-
-    // (한국어) 프레임 버퍼의 텍스쳐 뷰를 생성합니다.
-    // (English Translation) Creates a texture view of the framebuffer.
-    let view = frame.texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
-
-    // (한국어) 커맨드 버퍼를 생성합니다.
-    // (English Translation) Creates a command buffer.
-    let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(StageState(Background)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment { 
-                view: &view, 
-                resolve_target: None, 
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-
-        // (한국어) 배경 오브젝트들 그리기.
-        // (English Translation) Drawing background objects.
-        sprite_brush.draw(&mut rpass, [&this.background].into_iter());
-    }
+    with_frame(shared, this.clear_color(), |frame| {
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(StageState(Background)))");
 
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(EnterStage(Sprites)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment { 
-                view: &view, 
-                resolve_target: None, 
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Load,
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-
-        // (한국어) 스프라이트 오브젝트들 그리기.
-        // (English Translation) Drawing sprite objects.
-        sprite_brush.draw(&mut rpass, this.sprites.iter().map(|(it, _)| it));
-    }
+            camera.bind(&mut rpass);
 
-    {
-        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("RenderPass(TitleScene(StageState(Ui)))"),
-            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                view: &view,
-                resolve_target: None,
-                ops: wgpu::Operations {
-                    load: wgpu::LoadOp::Load,
-                    store: wgpu::StoreOp::Store,
-                }
-            })],
-            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
-                view: depth.view(),
-                depth_ops: Some(wgpu::Operations {
-                    load: wgpu::LoadOp::Clear(1.0),
-                    store: wgpu::StoreOp::Store,
-                }),
-                stencil_ops: None,
-            }),
-            timestamp_writes: None,
-            occlusion_query_set: None,
-        });
-
-        camera.bind(&mut rpass);
-
-        // (한국어) 시스템 버튼 그리기.
-        // (English Translation) Drawing the system buttons.
-        ui_brush.draw(&mut rpass, [&this.return_button].into_iter());
-    }
+            // (한국어) 배경 오브젝트들 그리기.
+            // (English Translation) Drawing background objects.
+            sprite_brush.draw(&mut rpass, [&this.background].into_iter());
+        }
 
-    // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
-    // (English Translation) Submit command buffers to the queue and output to the framebuffer.
-    queue.submit(Some(encoder.finish()));
-    frame.present();
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(EnterStage(Sprites)))");
 
-    Ok(())
+            camera.bind(&mut rpass);
+
+            // (한국어) 스프라이트 오브젝트들 그리기.
+            // (English Translation) Drawing sprite objects.
+            sprite_brush.draw(&mut rpass, this.sprites.iter().map(|(it, _)| it));
+        }
+
+        {
+            let mut rpass = frame.begin_pass("RenderPass(TitleScene(StageState(Ui)))");
+
+            camera.bind(&mut rpass);
+
+            // (한국어) 시스템 버튼 그리기.
+            // (English Translation) Drawing the system buttons.
+            ui_brush.draw(&mut rpass, [&this.return_button].into_iter());
+        }
+    })
 }
 
 
@@ -230,8 +142,14 @@ fn handle_keyboard_input(this: &mut TitleScene, shared: &mut Shared, event: &Eve
 
                     // (한국어) 다음 게임 장면 상태로 변경합니다.
                     // (English Translation) Change to the next game scene state.
-                    this.state = TitleState::ExitStage;
-                    this.timer = 0.0;
+                    close_layer(this);
+                } else if utils::actor_cycle_step(code).is_some() && !event.repeat && event.state.is_pressed() {
+                    // (한국어) 마지막으로 선택했던 캐릭터의 스테이지를 선택합니다.
+                    // (English Translation) Selects the stage of the last selected character.
+                    if FOCUSED_SPRITE.lock().expect("Failed to access variable.").is_none() {
+                        sound::play_click_sound(shared)?;
+                        sprite_released(this.selected_actor, this, shared)?;
+                    }
                 }
             },
             _ => { /* empty */ }
@@ -478,30 +396,27 @@ fn sprite_released(sp: Actor, this: &mut TitleScene, shared: &mut Shared) -> App
             instance.texture_index = 1;
         }
     });
+    this.selected_actor = sp;
     
     match sp {
         Actor::Aris => {
             shared.push(Actor::Aris);
-            this.state = TitleState::EnterSelected;
-            this.timer = 0.0;
+            open_layer(this, TitleState::Selected);
             Ok(())
         },
         Actor::Momoi => {
             shared.push(Actor::Momoi);
-            this.state = TitleState::EnterSelected;
-            this.timer = 0.0;
+            open_layer(this, TitleState::Selected);
             Ok(())
         },
         Actor::Midori => {
             shared.push(Actor::Midori);
-            this.state = TitleState::EnterSelected;
-            this.timer = 0.0;
+            open_layer(this, TitleState::Selected);
             Ok(())
         },
         Actor::Yuzu => {
             shared.push(Actor::Yuzu);
-            this.state = TitleState::EnterSelected;
-            this.timer = 0.0;
+            open_layer(this, TitleState::Selected);
             Ok(())
         },
         _ => Ok(())
@@ -511,8 +426,7 @@ fn sprite_released(sp: Actor, this: &mut TitleScene, shared: &mut Shared) -> App
 
 #[allow(unused_variables)]
 fn ui_released(this: &mut TitleScene, _shared: &mut Shared) -> AppResult<()> {
-    this.state = TitleState::ExitStage;
-    this.timer = 0.0;
+    close_layer(this);
     Ok(())
 }
 
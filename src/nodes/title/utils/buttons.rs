@@ -6,10 +6,10 @@ use glam::{Vec4, Vec3};
 use crate::{
     components::{
         text::{TextBrush, Text, TextBuilder},
-        ui::{UiBrush, UiObject, UiObjectBuilder},
+        ui::{UiBrush, UiObject, UiObjectBuilder, Slider},
         anchor::Anchor, margin::Margin,
         script::{Script, ScriptTags},
-        user::{Settings, Language, Resolution},
+        user::{Settings, SettingToggle, Language, Resolution},
     },
     system::error::AppResult,
 };
@@ -27,6 +27,7 @@ pub enum MenuButtons {
     Start = 0,
     Setting = 1,
     Exit = 2,
+    Continue = 3,
 }
 
 impl From<usize> for MenuButtons {
@@ -36,6 +37,7 @@ impl From<usize> for MenuButtons {
             0 => Self::Start,
             1 => Self::Setting,
             2 => Self::Exit,
+            3 => Self::Continue,
             _ => panic!("index out of range!")
         }
     }
@@ -58,11 +60,14 @@ pub(super) struct MenuButtonTextureViews<'a> {
 
 /// #### 한국어 </br>
 /// 메뉴 버튼들을 생성합니다. </br>
+/// `with_continue`가 `true`인 경우 `시작` 버튼 위에 `이어하기` 버튼을 마지막 항목으로 추가합니다. </br>
 /// 
 /// #### English (Translation) </br>
 /// Create a menu buttons. </br>
+/// If `with_continue` is `true`, adds a `continue` button above the `start` button as the last entry. </br>
 /// 
 pub(super) fn create_menu_buttons<'a>(
+    with_continue: bool, 
     font: &'a FontArc, 
     device: &'a wgpu::Device, 
     queue: &'a wgpu::Queue, 
@@ -180,11 +185,45 @@ pub(super) fn create_menu_buttons<'a>(
     // (한국어) 주의: 순서를 바꾸지 마세요.                                            |
     // (English Translation) Caution: Do not change the order.                 |
     //-------------------------------------------------------------------------*
-    return Ok(vec![
+    let mut buttons = vec![
         start_button,
         setting_button,
         exit_button,
-    ]);
+    ];
+
+
+    // (한국어) 이어할 스테이지가 있는 경우 `이어하기` 버튼을 생성합니다.
+    // (English Translation) Create a `continue` button if there is a stage to continue.
+    if with_continue {
+        let anchor = Anchor::new(ANCHOR_TOP, ANCHOR_LEFT, ANCHOR_BOTTOM, ANCHOR_RIGHT);
+        let margin = Margin::new(2 * GAP + HEIGHT / 2, -WIDTH / 2, 2 * GAP - HEIGHT / 2, WIDTH / 2);
+        buttons.push((
+            UiObjectBuilder::new(
+                Some("ContinueButton"),
+                tex_sampler,
+                texture_views.start_btn_texture_view, 
+                ui_brush
+            )
+            .with_anchor(anchor)
+            .with_margin(margin)
+            .with_color(UI_COLOR)
+            .with_global_translation(UI_TRANSLATION)
+            .build(device),
+            TextBuilder::new(
+                Some("ContinueButton"),
+                font,
+                script.get(ScriptTags::TitleContinueButton)?, 
+                text_brush
+            )
+            .with_anchor(anchor)
+            .with_margin(margin)
+            .with_color(TEXT_COLOR)
+            .with_translation(TEXT_TRANSLATION)
+            .build(device, queue),
+        ));
+    }
+
+    return Ok(buttons);
 }
 
 
@@ -383,6 +422,206 @@ pub(super) fn create_setting_resolutions(
     return resolutions;
 }
 
+/// #### 한국어 </br>
+/// 켜져 있는 설정 버튼의 색상입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The color of a setting button that is turned on. </br>
+/// 
+pub const TOGGLE_ON_COLOR: Vec4 = Vec4::new(187.0 / 255.0, 239.0 / 255.0, 249.0 / 255.0, 1.0);
+
+/// #### 한국어 </br>
+/// 꺼져 있는 설정 버튼의 색상입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The color of a setting button that is turned off. </br>
+/// 
+pub const TOGGLE_OFF_COLOR: Vec4 = Vec4::new(1.0, 1.0, 1.0, 1.0);
+
+/// #### 한국어 </br>
+/// 설정이 켜져 있는지에 따라 설정 버튼의 색상을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the color of a setting button depending on whether the setting is turned on. </br>
+/// 
+#[inline]
+pub fn toggle_color(on: bool) -> Vec4 {
+    match on {
+        true => TOGGLE_ON_COLOR, 
+        false => TOGGLE_OFF_COLOR, 
+    }
+}
+
+/// #### 한국어 </br>
+/// 설정 버튼에 표시되는 스크립트의 태그를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the tag of the script displayed on a setting button. </br>
+/// 
+#[inline]
+pub fn toggle_script_tag(toggle: SettingToggle) -> ScriptTags {
+    match toggle {
+        SettingToggle::HideCursorInGame => ScriptTags::SettingHideCursorToggle, 
+        SettingToggle::CustomCursor => ScriptTags::SettingCustomCursorToggle, 
+        SettingToggle::ReduceMotion => ScriptTags::SettingReduceMotionToggle, 
+        SettingToggle::SmallBoard => ScriptTags::SettingSmallBoardToggle, 
+    }
+}
+
+/// #### 한국어 </br>
+/// 설정 창의 켜고 끌 수 있는 설정 버튼들을 생성합니다. </br>
+/// 버튼들은 언어 선택 버튼과 같은 줄의 오른쪽 끝에 놓입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates setting buttons that can be turned on and off in the setting window. </br>
+/// The buttons are placed at the right end of the same row as the language selection buttons. </br>
+/// 
+pub(super) fn create_setting_toggles(
+    font: &FontArc, 
+    script: &Script, 
+    settings: &Settings, 
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    tex_sampler: &wgpu::Sampler, 
+    texture_view: &wgpu::TextureView, 
+    ui_brush: &UiBrush, 
+    text_brush: &TextBrush
+) -> AppResult<HashMap<SettingToggle, (UiObject, Text)>> {
+    const TOP: i32 = 164;
+    const LEFT: i32 = -112;
+    const HEIGHT: i32 = 36;
+    const WIDTH: i32 = HEIGHT * 3;
+    const GAP: i32 = 8;
+
+    let mut left = LEFT;
+    let mut toggles = HashMap::new();
+    for toggle in SettingToggle::ALL {
+        toggles.insert(
+            toggle, 
+            (
+                UiObjectBuilder::new(
+                    Some(&format!("{:?}_Toggle", toggle)), 
+                    tex_sampler, 
+                    texture_view, 
+                    ui_brush
+                )
+                .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+                .with_margin(Margin::new(TOP, left, TOP - HEIGHT, left + WIDTH))
+                .with_color(toggle_color(toggle.is_on(settings)))
+                .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
+                .with_global_translation(Vec3::new(0.0, 0.0, 0.5))
+                .build(device), 
+                TextBuilder::new(
+                    Some(&format!("{:?}_ToggleText", toggle)), 
+                    font, 
+                    script.get(toggle_script_tag(toggle))?, 
+                    text_brush
+                )
+                .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+                .with_margin(Margin::new(TOP, left, TOP - HEIGHT, left + WIDTH))
+                .with_color(Vec4::new(0.0, 0.0, 0.0, 1.0))
+                .with_scale(Vec3::new(0.0, 0.0, 0.0))
+                .with_translation(Vec3::new(0.0, 0.0, 0.4))
+                .build(device, queue)
+            )
+        );
+
+        left += GAP + WIDTH;
+    }
+
+    return Ok(toggles);
+}
+
+/// #### 한국어 </br>
+/// 변경된 설정을 적용하는 버튼을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a button that applies the changed settings. </br>
+/// 
+#[inline]
+pub(super) fn create_setting_apply_button(
+    font: &FontArc, 
+    script: &Script,
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    tex_sampler: &wgpu::Sampler, 
+    texture_view: &wgpu::TextureView, 
+    ui_brush: &UiBrush, 
+    text_brush: &TextBrush
+) -> AppResult<(UiObject, Text)> {
+    return Ok((
+        UiObjectBuilder::new(
+            Some("SettingApplyButton"), 
+            tex_sampler, 
+            texture_view, 
+            ui_brush
+        )
+        .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+        .with_margin(Margin::new(-220, 64, -268, 208))
+        .with_color(Vec4::new(1.0, 1.0, 1.0, 1.0))
+        .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
+        .with_global_translation(Vec3::new(0.0, 0.0, 0.5))
+        .build(device), 
+        TextBuilder::new(
+            Some("SettingApplyButtonText"), 
+            font, 
+            script.get(ScriptTags::SettingApplyButton)?, 
+            text_brush
+        )
+        .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+        .with_margin(Margin::new(-220, 64, -268, 208))
+        .with_color(Vec4::new(0.0, 0.0, 0.0, 1.0))
+        .with_scale(Vec3::new(0.0, 0.0, 0.0))
+        .with_translation(Vec3::new(0.0, 0.0, 0.4))
+        .build(device, queue)
+    ))
+}
+
+/// #### 한국어 </br>
+/// 모든 설정을 기본값으로 되돌리는 버튼을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a button that resets all settings to their default values. </br>
+/// 
+#[inline]
+pub(super) fn create_setting_reset_button(
+    font: &FontArc, 
+    script: &Script,
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    tex_sampler: &wgpu::Sampler, 
+    texture_view: &wgpu::TextureView, 
+    ui_brush: &UiBrush, 
+    text_brush: &TextBrush
+) -> AppResult<(UiObject, Text)> {
+    return Ok((
+        UiObjectBuilder::new(
+            Some("SettingResetButton"), 
+            tex_sampler, 
+            texture_view, 
+            ui_brush
+        )
+        .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+        .with_margin(Margin::new(-220, -368, -268, -224))
+        .with_color(Vec4::new(1.0, 1.0, 1.0, 1.0))
+        .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
+        .with_global_translation(Vec3::new(0.0, 0.0, 0.5))
+        .build(device), 
+        TextBuilder::new(
+            Some("SettingResetButtonText"), 
+            font, 
+            script.get(ScriptTags::SettingResetButton)?, 
+            text_brush
+        )
+        .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+        .with_margin(Margin::new(-220, -368, -268, -224))
+        .with_color(Vec4::new(0.0, 0.0, 0.0, 1.0))
+        .with_scale(Vec3::new(0.0, 0.0, 0.0))
+        .with_translation(Vec3::new(0.0, 0.0, 0.4))
+        .build(device, queue)
+    ))
+}
+
 /// #### 한국어 </br>
 /// 돌아가기 버튼을 생성합니다. </br>
 /// 
@@ -565,62 +804,79 @@ pub(super) fn create_setting_volume_bar(
     tex_sampler: &wgpu::Sampler, 
     texture_view: &wgpu::TextureView, 
     ui_brush: &UiBrush
-) -> HashMap<VolumeOptions, UiObject> {
-    const RANGE: i32 = SETTING_VOLUME_RANGE_MAX - SETTING_VOLUME_RANGE_MIN;
+) -> HashMap<VolumeOptions, Slider> {
     let mut bar = HashMap::new();
 
-    let delta = RANGE as f32 * settings.background_volume.norm().min(1.0);
-    let pos = SETTING_VOLUME_RANGE_MIN + delta as i32;
+    let value = settings.background_volume.norm();
+    let pos = Slider::position(SETTING_VOLUME_RANGE_MIN, SETTING_VOLUME_RANGE_MAX, value);
     bar.insert(
         VolumeOptions::Background, 
-        UiObjectBuilder::new(
-            Some("BackgroundVolumeBar"), 
-            tex_sampler, 
-            texture_view, 
-            ui_brush
+        Slider::new(
+            UiObjectBuilder::new(
+                Some("BackgroundVolumeBar"), 
+                tex_sampler, 
+                texture_view, 
+                ui_brush
+            )
+            .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+            .with_margin(Margin::new(-90, pos - VOLUME_BAR_WIDTH / 2, -110, pos + VOLUME_BAR_WIDTH / 2))
+            .with_color(Vec4::new(234.0 / 255.0, 250.0 / 255.0, 253.0 / 255.0, 1.0))
+            .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
+            .with_global_translation(Vec3::new(0.0, 0.0, 0.3))
+            .build(device), 
+            SETTING_VOLUME_RANGE_MIN, 
+            SETTING_VOLUME_RANGE_MAX, 
+            VOLUME_BAR_WIDTH, 
+            value
         )
-        .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
-        .with_margin(Margin::new(-90, pos - VOLUME_BAR_WIDTH / 2, -110, pos + VOLUME_BAR_WIDTH / 2))
-        .with_color(Vec4::new(234.0 / 255.0, 250.0 / 255.0, 253.0 / 255.0, 1.0))
-        .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
-        .with_global_translation(Vec3::new(0.0, 0.0, 0.3))
-        .build(device)
     );
 
-    let delta = RANGE as f32 * settings.effect_volume.norm().min(1.0);
-    let pos = SETTING_VOLUME_RANGE_MIN + delta as i32;
+    let value = settings.effect_volume.norm();
+    let pos = Slider::position(SETTING_VOLUME_RANGE_MIN, SETTING_VOLUME_RANGE_MAX, value);
     bar.insert(
         VolumeOptions::Effect, 
-        UiObjectBuilder::new(
-            Some("EffectVolumeBar"), 
-            tex_sampler, 
-            texture_view, 
-            ui_brush
+        Slider::new(
+            UiObjectBuilder::new(
+                Some("EffectVolumeBar"), 
+                tex_sampler, 
+                texture_view, 
+                ui_brush
+            )
+            .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+            .with_margin(Margin::new(-122, pos - VOLUME_BAR_WIDTH / 2, -142, pos + VOLUME_BAR_WIDTH / 2))
+            .with_color(Vec4::new(234.0 / 255.0, 250.0 / 255.0, 253.0 / 255.0, 1.0))
+            .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
+            .with_global_translation(Vec3::new(0.0, 0.0, 0.3))
+            .build(device), 
+            SETTING_VOLUME_RANGE_MIN, 
+            SETTING_VOLUME_RANGE_MAX, 
+            VOLUME_BAR_WIDTH, 
+            value
         )
-        .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
-        .with_margin(Margin::new(-122, pos - VOLUME_BAR_WIDTH / 2, -142, pos + VOLUME_BAR_WIDTH / 2))
-        .with_color(Vec4::new(234.0 / 255.0, 250.0 / 255.0, 253.0 / 255.0, 1.0))
-        .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
-        .with_global_translation(Vec3::new(0.0, 0.0, 0.3))
-        .build(device)
     );
 
-    let delta = RANGE as f32 * settings.voice_volume.norm().min(1.0);
-    let pos = SETTING_VOLUME_RANGE_MIN + delta as i32;
+    let value = settings.voice_volume.norm();
+    let pos = Slider::position(SETTING_VOLUME_RANGE_MIN, SETTING_VOLUME_RANGE_MAX, value);
     bar.insert(
         VolumeOptions::Voice, 
-        UiObjectBuilder::new(
-            Some("VoiceVolumeBar"), 
-            tex_sampler, 
-            texture_view, 
-            ui_brush
+        Slider::new(
+            UiObjectBuilder::new(
+                Some("VoiceVolumeBar"), 
+                tex_sampler, 
+                texture_view, 
+                ui_brush
+            )
+            .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
+            .with_margin(Margin::new(-154, pos - VOLUME_BAR_WIDTH / 2, -174, pos + VOLUME_BAR_WIDTH / 2))
+            .with_color(Vec4::new(234.0 / 255.0, 250.0 / 255.0, 253.0 / 255.0, 1.0))
+            .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
+            .with_global_translation(Vec3::new(0.0, 0.0, 0.3))
+            .build(device), 
+            SETTING_VOLUME_RANGE_MIN, 
+            SETTING_VOLUME_RANGE_MAX, 
+            VOLUME_BAR_WIDTH, 
+            value
         )
-        .with_anchor(Anchor::new(0.5, 0.5, 0.5, 0.5))
-        .with_margin(Margin::new(-154, pos - VOLUME_BAR_WIDTH / 2, -174, pos + VOLUME_BAR_WIDTH / 2))
-        .with_color(Vec4::new(234.0 / 255.0, 250.0 / 255.0, 253.0 / 255.0, 1.0))
-        .with_global_scale(Vec3::new(0.0, 0.0, 0.0))
-        .with_global_translation(Vec3::new(0.0, 0.0, 0.3))
-        .build(device)
     );
 
     return bar;
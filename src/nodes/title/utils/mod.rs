@@ -9,15 +9,18 @@ pub use window::*;
 use std::collections::HashMap;
 
 use ab_glyph::FontArc;
+use winit::keyboard::KeyCode;
 
 use crate::{
     assets::bundle::AssetBundle, 
     components::{
-        ui::{UiBrush, UiObject, UiObjectBuilder},
+        ui::{UiBrush, UiObject, UiObjectBuilder, DimOverlay},
+        focus::FocusRing,
         text::{Text, TextBrush, TextBuilder}, 
-        script::Script,
+        script::{Script, ScriptTags},
         sprite::SpriteBrush, 
         anchor::Anchor, 
+        transform::Projection, 
         player::Actor, 
         save::SaveData, 
         user::Settings, 
@@ -30,11 +33,20 @@ use crate::{
         consts::PIXEL_PER_METER, 
     },
     render::texture::DdsTextureDecoder,
+    scene::{back_stack::BackStack, idle::IdleTimer}, 
     system::error::AppResult,
 };
 
 
 
+/// #### 한국어 </br>
+/// 종료 메시지 상자나 설정 창이 열렸을 때 뒤의 장면이 어두워지는 최대 알파 값입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum alpha value to which the scene behind dims when the exit message box or the setting window opens. </br>
+/// 
+pub const DIM_OVERLAY_ALPHA: f32 = 0.5;
+
 pub const MENU_TOP: f32 = (1.5 - 2.5) * PIXEL_PER_METER;
 pub const MENU_LEFT: f32 = -2.0 * PIXEL_PER_METER;
 pub const MENU_BOTTOM: f32 = (-1.5 - 2.5) * PIXEL_PER_METER;
@@ -45,6 +57,71 @@ pub const STAGE_LEFT: f32 = -4.0 * PIXEL_PER_METER;
 pub const STAGE_BOTTOM: f32 = (-3.0 + 1.0) * PIXEL_PER_METER;
 pub const STAGE_RIGHT: f32 = 4.0 * PIXEL_PER_METER;
 
+/// #### 한국어 </br>
+/// 메뉴 화면과 스테이지 선택 화면의 카메라 투영 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Camera projections of the menu screen and the stage selection screen. </br>
+/// 
+pub const MENU_PROJECTION: Projection = Projection::new_ortho(MENU_TOP, MENU_LEFT, MENU_BOTTOM, MENU_RIGHT, 0.0, 1000.0);
+pub const STAGE_PROJECTION: Projection = Projection::new_ortho(STAGE_TOP, STAGE_LEFT, STAGE_BOTTOM, STAGE_RIGHT, 0.0, 1000.0);
+
+/// #### 한국어 </br>
+/// 선택된 스테이지를 확대한 카메라 투영을 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the camera projection zoomed in on the selected stage. </br>
+/// 
+pub const fn selected_stage_projection(actor: Actor) -> Projection {
+    match actor {
+        Actor::Aris => Projection::new_ortho(STAGE_ARIS_TOP, STAGE_ARIS_LEFT, STAGE_ARIS_BOTTOM, STAGE_ARIS_RIGHT, 0.0, 1000.0),
+        Actor::Momoi => Projection::new_ortho(STAGE_MOMOI_TOP, STAGE_MOMOI_LEFT, STAGE_MOMOI_BOTTOM, STAGE_MOMOI_RIGHT, 0.0, 1000.0),
+        Actor::Midori => Projection::new_ortho(STAGE_MIDORI_TOP, STAGE_MIDORI_LEFT, STAGE_MIDORI_BOTTOM, STAGE_MIDORI_RIGHT, 0.0, 1000.0),
+        Actor::Yuzu => Projection::new_ortho(STAGE_YUZU_TOP, STAGE_YUZU_LEFT, STAGE_YUZU_BOTTOM, STAGE_YUZU_RIGHT, 0.0, 1000.0),
+    }
+}
+
+/// #### 한국어 </br>
+/// 잠긴 스테이지 위에 덮이는 오버레이의 불투명도 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The opacity of the overlay covering a locked stage. </br>
+/// 
+pub const STAGE_LOCK_ALPHA: f32 = 0.75;
+
+/// #### 한국어 </br>
+/// 캐릭터별 스테이지 잠금 해제 조건 목록입니다. </br>
+/// 조건이 `None`인 캐릭터는 항상 잠금 해제되어 있으며, </br>
+/// 그렇지 않으면 주어진 캐릭터의 스테이지 진행도(백분율)가 기준 이상이어야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A list of stage unlock conditions per character. </br>
+/// A character whose condition is `None` is always unlocked, </br>
+/// otherwise the stage progress (percentage) of the given character must be above the threshold. </br>
+/// 
+pub const STAGE_UNLOCK_RULES: [(Actor, Option<(Actor, f32)>); 4] = [
+    (Actor::Aris, None), 
+    (Actor::Momoi, Some((Actor::Aris, 50.0))), 
+    (Actor::Midori, Some((Actor::Momoi, 50.0))), 
+    (Actor::Yuzu, Some((Actor::Midori, 50.0))), 
+];
+
+/// #### 한국어 </br>
+/// 스테이지 선택 화면에서 이전 캐릭터를 선택하는 키 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The key that selects the previous character on the stage select screen. </br>
+/// 
+pub const PREV_ACTOR_KEY: KeyCode = KeyCode::ArrowLeft;
+
+/// #### 한국어 </br>
+/// 스테이지 선택 화면에서 다음 캐릭터를 선택하는 키 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The key that selects the next character on the stage select screen. </br>
+/// 
+pub const NEXT_ACTOR_KEY: KeyCode = KeyCode::ArrowRight;
+
 
 
 pub fn create_title_scene(
@@ -60,7 +137,8 @@ pub fn create_title_scene(
     text_brush: &TextBrush, 
     sprite_brush: &SpriteBrush, 
     texture_map: &HashMap<String, wgpu::Texture>, 
-    asset_bundle: &AssetBundle
+    asset_bundle: &AssetBundle, 
+    show_gpu_warning: bool
 ) -> AppResult<TitleScene> {
     // (한국어) `dds`이미지 파일로부터 배경 텍스처를 생성합니다.
     // (English Translation) Create a background texture from a `dds`image file. 
@@ -337,7 +415,9 @@ pub fn create_title_scene(
         setting_btn_texture_view: &setting_btn_texture_view,
         exit_btn_texture_view: &exit_btn_texture_view, 
     };
+    let continue_actor = continue_actor(save);
     let menu_buttons = create_menu_buttons(
+        continue_actor.is_some(), 
         nexon_lv2_gothic_medium, 
         device, 
         queue, 
@@ -563,6 +643,17 @@ pub fn create_title_scene(
         ui_brush, 
         text_brush
     );
+    let setting_toggles = create_setting_toggles(
+        nexon_lv2_gothic_medium, 
+        script, 
+        settings, 
+        device, 
+        queue, 
+        tex_sampler, 
+        &btn_texture_view, 
+        ui_brush, 
+        text_brush
+    )?;
     let setting_return_button = create_setting_return_button(
         nexon_lv2_gothic_medium, 
         script, 
@@ -573,6 +664,37 @@ pub fn create_title_scene(
         ui_brush, 
         text_brush
     )?;
+    let setting_apply_button = create_setting_apply_button(
+        nexon_lv2_gothic_medium, 
+        script, 
+        device, 
+        queue, 
+        tex_sampler, 
+        &btn_texture_view, 
+        ui_brush, 
+        text_brush
+    )?;
+    let setting_reset_button = create_setting_reset_button(
+        nexon_lv2_gothic_medium, 
+        script, 
+        device, 
+        queue, 
+        tex_sampler, 
+        &btn_texture_view, 
+        ui_brush, 
+        text_brush
+    )?;
+    let (setting_revert_window, setting_revert_countdown, setting_revert_buttons) = create_setting_revert_window(
+        nexon_lv2_gothic_medium, 
+        script, 
+        device, 
+        queue, 
+        tex_sampler, 
+        &window_texture_view, 
+        &btn_texture_view, 
+        ui_brush, 
+        text_brush
+    )?;
 
     let texture = texture_map.get(path::DUMMY_TEXTURE_PATH)
         .expect("Registered texture not found!");
@@ -591,6 +713,18 @@ pub fn create_title_scene(
         ui_brush, 
         text_brush
     )?;
+    let setting_reset_window = create_setting_reset_window(
+        nexon_lv2_gothic_medium, 
+        script, 
+        device, 
+        queue, 
+        tex_sampler, 
+        &dummy_texture_view, 
+        &window_texture_view, 
+        &btn_texture_view, 
+        ui_brush, 
+        text_brush
+    )?;
     let setting_volume_bar = create_setting_volume_bar(
         settings, 
         device, 
@@ -629,6 +763,12 @@ pub fn create_title_scene(
         &texture_view, 
         ui_brush
     );
+    let dim_overlay = create_dim_overlay(
+        device, 
+        tex_sampler, 
+        &texture_view, 
+        ui_brush
+    );
 
 
     let star_texture = asset_bundle.get(path::STAR_TEXTURE_PATH)?
@@ -648,6 +788,21 @@ pub fn create_title_scene(
             device,
             queue,
         })?;
+    let texture = texture_map.get(path::DUMMY_TEXTURE_PATH)
+        .expect("Registered texture not found!");
+    let dummy_texture_view = texture.create_view(
+        &wgpu::TextureViewDescriptor {
+            ..Default::default()
+        }
+    );
+    let stage_locks = create_stage_lock(
+        save, 
+        device, 
+        tex_sampler, 
+        &dummy_texture_view, 
+        ui_brush
+    );
+
     let stage_images = create_stage_image(
         nexon_lv2_gothic_medium, 
         &save, 
@@ -660,26 +815,52 @@ pub fn create_title_scene(
         text_brush
     );
 
+    let gpu_warning = match show_gpu_warning {
+        true => Some(create_gpu_warning_text(nexon_lv2_gothic_medium, script, device, queue, text_brush)?),
+        false => None,
+    };
+
 
     return Ok(TitleScene {
         timer: 0.0, 
         state: TitleState::Enter,
+        back_stack: BackStack::new(TitleState::Menu), 
+        idle_timer: IdleTimer::new(settings.attract_timeout_sec), 
         foreground, 
+        dim_overlay, 
         background, 
         sprites,
         menu_buttons, 
+        menu_focus: FocusRing::new(
+            continue_actor.map(|_| MenuButtons::Continue).into_iter()
+            .chain([MenuButtons::Start, MenuButtons::Setting, MenuButtons::Exit])
+            .collect()
+        ), 
+        continue_actor, 
         return_button, 
         exit_msg_box, 
+        exit_msg_focus: FocusRing::new(vec![ExitMessageBox::Yes, ExitMessageBox::No]), 
         stage_window, 
         stage_enter_button, 
         stage_images, 
+        stage_locks, 
+        selected_actor: Actor::default(), 
         setting_titles, 
         setting_windows, 
         setting_languages, 
         setting_resolutions, 
+        setting_toggles, 
         setting_return_button, 
+        setting_apply_button, 
+        setting_reset_button, 
+        setting_reset_window, 
         setting_volume_background, 
         setting_volume_bar, 
+        setting_revert_window, 
+        setting_revert_countdown, 
+        setting_revert_buttons, 
+        setting_revert: None, 
+        gpu_warning, 
     })
 }
 
@@ -709,6 +890,55 @@ fn create_foreground(
     .build(device)
 }
 
+/// #### 한국어 </br>
+/// 종료 메시지 상자나 설정 창 뒤의 장면을 어둡게 하는 오버레이를 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates an overlay that dims the scene behind the exit message box or the setting window. </br>
+/// 
+#[inline]
+fn create_dim_overlay(
+    device: &wgpu::Device, 
+    tex_sampler: &wgpu::Sampler, 
+    texture_view: &wgpu::TextureView, 
+    ui_brush: &UiBrush
+) -> DimOverlay {
+    let overlay = UiObjectBuilder::new(
+        Some("DimOverlay"), 
+        tex_sampler, 
+        texture_view, 
+        ui_brush
+    )
+    .with_anchor(Anchor::new(1.0, 0.0, 0.0, 1.0))
+    .with_color((0.0, 0.0, 0.0, 0.0).into())
+    .build(device);
+    return DimOverlay::new(overlay, DIM_OVERLAY_ALPHA);
+}
+
+/// #### 한국어 </br>
+/// 소프트웨어 렌더링으로 실행 중임을 알리는 경고 텍스트를 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a warning text notifying that the game is running with software rendering. </br>
+/// 
+fn create_gpu_warning_text(
+    font: &FontArc, 
+    script: &Script, 
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    text_brush: &TextBrush
+) -> AppResult<Text> {
+    Ok(TextBuilder::new(
+        Some("GpuWarningText"), 
+        font, 
+        script.get(ScriptTags::TitleGpuWarning)?, 
+        text_brush
+    )
+    .with_anchor(Anchor::new(0.98, 0.0, 0.92, 1.0))
+    .with_color((0.8, 0.1, 0.1, 1.0).into())
+    .build(device, queue))
+}
+
 /// #### 한국어 </br>
 /// 스테이지 이미지들을 생성합니다. </br>
 /// 
@@ -735,12 +965,7 @@ fn create_stage_image(
 
     let mut stage_image = HashMap::new();
     for (actor, label, rel_path) in MAP {
-        let percent = match actor {
-            Actor::Aris => save.stage_aris,
-            Actor::Momoi => save.stage_momoi, 
-            Actor::Midori => save.stage_midori, 
-            Actor::Yuzu => save.stage_yuzu
-        } as f32 / NUM_TILES as f32 * 100.0;
+        let percent = stage_percent(save, actor);
 
         let stage_img_texture_view = if percent < 20.0 {
             let texture = texture_map.get(path::DEF_IMG_TEXTURE_PATH)
@@ -842,3 +1067,186 @@ fn create_stage_image(
 
     return stage_image;
 }
+
+/// #### 한국어 </br>
+/// 잠긴 캐릭터의 스테이지 이미지 위에 덮이는 오버레이들을 생성합니다. </br>
+/// 잠금 해제된 캐릭터는 오버레이를 가지지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates overlays covering the stage image of locked characters. </br>
+/// Unlocked characters have no overlay. </br>
+/// 
+fn create_stage_lock(
+    save: &SaveData, 
+    device: &wgpu::Device, 
+    tex_sampler: &wgpu::Sampler, 
+    texture_view: &wgpu::TextureView, 
+    ui_brush: &UiBrush
+) -> HashMap<Actor, UiObject> {
+    let mut stage_lock = HashMap::new();
+    for (actor, _) in STAGE_UNLOCK_RULES {
+        if is_stage_unlocked(save, actor) {
+            continue;
+        }
+
+        stage_lock.insert(
+            actor, 
+            UiObjectBuilder::new(
+                Some(&format!("{:?}StageLock", actor)), 
+                tex_sampler, 
+                texture_view, 
+                ui_brush
+            )
+            .with_anchor(Anchor::new(
+                1.0 - 0.05, 
+                0.5 - 0.225, 
+                1.0 - 0.35, 
+                0.5 - 0.225 + 0.225
+            ))
+            .with_color((0.0, 0.0, 0.0, 0.0).into())
+            .with_global_translation((0.0, 0.0, 0.2).into())
+            .build(device)
+        );
+    }
+
+    return stage_lock;
+}
+
+/// #### 한국어 </br>
+/// 캐릭터 스테이지의 진행도를 백분율로 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the progress of the character's stage as a percentage. </br>
+/// 
+#[inline]
+pub fn stage_percent(save: &SaveData, actor: Actor) -> f32 {
+    return match actor {
+        Actor::Aris => save.stage_aris,
+        Actor::Momoi => save.stage_momoi, 
+        Actor::Midori => save.stage_midori, 
+        Actor::Yuzu => save.stage_yuzu
+    } as f32 / NUM_TILES as f32 * 100.0;
+}
+
+/// #### 한국어 </br>
+/// `STAGE_UNLOCK_RULES`에 따라 캐릭터의 스테이지가 잠금 해제되었는지 확인합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Checks whether the character's stage is unlocked according to `STAGE_UNLOCK_RULES`. </br>
+/// 
+pub fn is_stage_unlocked(save: &SaveData, actor: Actor) -> bool {
+    return STAGE_UNLOCK_RULES.iter()
+        .find(|(it, _)| *it == actor)
+        .map_or(true, |(_, rule)| match rule {
+            Some((required, threshold)) => stage_percent(save, *required) >= *threshold, 
+            None => true, 
+        });
+}
+
+/// #### 한국어 </br>
+/// `이어하기` 버튼으로 바로 입장할 마지막으로 플레이한 캐릭터를 반환합니다. </br>
+/// 플레이한 기록이 없거나 스테이지가 잠긴 경우 `None`을 반환하며, 이때 `이어하기` 버튼은 표시되지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the last played character to enter directly with the `continue` button. </br>
+/// Returns `None` if there is no play record or the stage is locked, and the `continue` button is not shown in that case. </br>
+/// 
+#[inline]
+pub fn continue_actor(save: &SaveData) -> Option<Actor> {
+    save.last_actor.filter(|actor| is_stage_unlocked(save, *actor))
+}
+
+/// #### 한국어 </br>
+/// 키 코드에 해당하는 캐릭터 선택 방향을 반환합니다. (이전: `-1`, 다음: `1`) </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the character selection direction corresponding to the key code. (previous: `-1`, next: `1`) </br>
+/// 
+#[inline]
+pub fn actor_cycle_step(code: KeyCode) -> Option<isize> {
+    match code {
+        PREV_ACTOR_KEY => Some(-1),
+        NEXT_ACTOR_KEY => Some(1),
+        _ => None,
+    }
+}
+
+/// #### 한국어 </br>
+/// 현재 캐릭터에서 주어진 방향으로 잠기지 않은 다음 캐릭터를 반환합니다. </br>
+/// 마지막 캐릭터 다음은 첫 번째 캐릭터로 돌아가며, 선택할 수 있는 다른 캐릭터가 없으면 현재 캐릭터를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the next unlocked character from the current character in the given direction. </br>
+/// It wraps from the last character to the first one, and returns the current character if no other character can be selected. </br>
+/// 
+pub fn cycle_actor<F>(current: Actor, step: isize, is_locked: F) -> Actor
+where F: Fn(Actor) -> bool {
+    let num_actors = STAGE_UNLOCK_RULES.len() as isize;
+    let mut index = current as isize;
+    for _ in 0..num_actors {
+        index = (index + step.signum()).rem_euclid(num_actors);
+        let actor = Actor::from(index as usize);
+        if !is_locked(actor) {
+            return actor;
+        }
+    }
+    return current;
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn only_first_actor_unlocked_without_progress() {
+        let save = SaveData::default();
+        assert!(is_stage_unlocked(&save, Actor::Aris));
+        assert!(!is_stage_unlocked(&save, Actor::Momoi));
+        assert!(!is_stage_unlocked(&save, Actor::Midori));
+        assert!(!is_stage_unlocked(&save, Actor::Yuzu));
+    }
+
+    #[test]
+    fn continue_selects_the_last_played_actor() {
+        // (한국어) 처음 시작한 세이브 파일에서는 `이어하기`를 사용할 수 없습니다.
+        // (English Translation) `Continue` is not available on a fresh save file.
+        let save = SaveData::default();
+        assert_eq!(continue_actor(&save), None);
+
+        let save = SaveData { last_actor: Some(Actor::Aris), ..Default::default() };
+        assert_eq!(continue_actor(&save), Some(Actor::Aris));
+
+        let save = SaveData { stage_aris: NUM_TILES as u16, last_actor: Some(Actor::Momoi), ..Default::default() };
+        assert_eq!(continue_actor(&save), Some(Actor::Momoi));
+
+        // (한국어) 잠긴 스테이지로는 이어할 수 없습니다.
+        // (English Translation) A locked stage cannot be continued.
+        let save = SaveData { last_actor: Some(Actor::Yuzu), ..Default::default() };
+        assert_eq!(continue_actor(&save), None);
+    }
+
+    #[test]
+    fn escape_closes_exit_box_without_quitting() {
+        use winit::keyboard::KeyCode;
+
+        assert_eq!(ExitMessageBox::from_shortcut(KeyCode::Escape), Some(ExitMessageBox::No));
+        assert_eq!(ExitMessageBox::from_shortcut(KeyCode::KeyN), Some(ExitMessageBox::No));
+        assert_eq!(ExitMessageBox::from_shortcut(KeyCode::KeyY), Some(ExitMessageBox::Yes));
+        assert_eq!(ExitMessageBox::from_shortcut(KeyCode::Space), None);
+    }
+
+    #[test]
+    fn cycling_past_last_actor_wraps_to_first() {
+        assert_eq!(cycle_actor(Actor::Yuzu, 1, |_| false), Actor::Aris);
+        assert_eq!(cycle_actor(Actor::Aris, -1, |_| false), Actor::Yuzu);
+        assert_eq!(cycle_actor(Actor::Momoi, 1, |_| false), Actor::Midori);
+
+        // (한국어) 잠긴 캐릭터는 건너뜁니다.
+        // (English Translation) Locked characters are skipped.
+        let locked = |actor| matches!(actor, Actor::Momoi | Actor::Midori);
+        assert_eq!(cycle_actor(Actor::Aris, 1, locked), Actor::Yuzu);
+        assert_eq!(cycle_actor(Actor::Yuzu, 1, locked), Actor::Aris);
+        assert_eq!(cycle_actor(Actor::Aris, 1, |actor| actor != Actor::Aris), Actor::Aris);
+    }
+}
@@ -1,10 +1,13 @@
+use std::collections::HashMap;
+
 use ab_glyph::FontArc;
 use glam::{Vec4, Vec3};
+use winit::keyboard::KeyCode;
 
 use crate::{
     components::{
         text::{TextBrush, Text, TextBuilder},
-        ui::{UiBrush, UiObject, UiObjectBuilder},
+        ui::{UiBrush, UiObject, UiObjectBuilder, ModalWindow, OutsideClick},
         anchor::Anchor,
         margin::Margin,
         script::{Script, ScriptTags},
@@ -39,6 +42,61 @@ impl From<usize> for ExitMessageBox {
     }
 }
 
+impl ExitMessageBox {
+    /// #### 한국어 </br>
+    /// 키보드 단축키에 해당하는 버튼을 반환합니다. </br>
+    /// - `Y`: 예 </br>
+    /// - `N`, `Escape`: 아니오 </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the button corresponding to the keyboard shortcut. </br>
+    /// - `Y`: Yes </br>
+    /// - `N`, `Escape`: No </br>
+    /// 
+    #[inline]
+    pub fn from_shortcut(code: KeyCode) -> Option<Self> {
+        match code {
+            KeyCode::KeyY => Some(Self::Yes),
+            KeyCode::KeyN | KeyCode::Escape => Some(Self::No),
+            _ => None,
+        }
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 해상도 되돌리기 창의 버튼 목록입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the list of buttons in the resolution revert window. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RevertWndButton {
+    Keep = 0, 
+    Revert = 1, 
+}
+
+pub const RESOLUTION_REVERT_SEC: f64 = 10.0;
+
+/// #### 한국어 </br>
+/// 설정 초기화 확인 창의 버튼 목록입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the list of buttons in the settings reset confirmation window. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ResetWndButton {
+    Yes = 0, 
+    No = 1, 
+}
+
+/// #### 한국어 </br>
+/// 설정 초기화 확인 창이 열렸을 때 배경막의 알파 값입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Alpha value of the backdrop when the settings reset confirmation window is open. </br>
+/// 
+const RESET_BACKDROP_ALPHA: f32 = 0.5;
 
 /// #### 한국어 </br>
 /// 종료 메시지 박스를 생성하는데 사용되는 텍스처 뷰 집합입니다. </br>
@@ -536,3 +594,234 @@ pub(super) fn create_setting_window_titles(
         item2_sub_title, 
     ]);
 }
+
+/// #### 한국어 </br>
+/// 설정 창의 해상도 되돌리기 창을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a resolution revert window in the setting window. </br>
+/// 
+pub(super) fn create_setting_revert_window(
+    font: &FontArc, 
+    script: &Script, 
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    tex_sampler: &wgpu::Sampler, 
+    window_texture_view: &wgpu::TextureView, 
+    btn_texture_view: &wgpu::TextureView, 
+    ui_brush: &UiBrush, 
+    text_brush: &TextBrush
+) -> AppResult<((UiObject, Text), Text, HashMap<RevertWndButton, (UiObject, Text)>)> {
+    const WND_WIDTH: i32 = 400;
+    const WND_HEIGHT: i32 = WND_WIDTH / 4 * 3;
+
+    const BTN_WIDTH: i32 = 150;
+    const BTN_HEIGHT: i32 = BTN_WIDTH / 3;
+    const BTN_TRANSLATION: Vec3 = Vec3::new(0.0, 0.0, 0.5);
+
+    const KEEP_BTN_COLOR: Vec4 = Vec4::new(1.0, 103.0 / 255.0, 105.0 / 255.0, 1.0);
+    const REVERT_BTN_COLOR: Vec4 = Vec4::new(1.0, 1.0, 1.0, 1.0);
+
+    const TEXT_TRANSLATION: Vec3 = Vec3::new(0.0, 0.0, 0.25);
+    const TEXT_COLOR: Vec4 = Vec4::new(0.0, 0.0, 0.0, 1.0);
+
+    let anchor = Anchor::new(0.5, 0.5, 0.5, 0.5);
+    let window = (
+        UiObjectBuilder::new(
+            Some("RevertWindow"), 
+            tex_sampler, 
+            window_texture_view, 
+            ui_brush
+        )
+        .with_anchor(anchor)
+        .with_margin(Margin::new(WND_HEIGHT / 2, -WND_WIDTH / 2, -WND_HEIGHT / 2, WND_WIDTH / 2))
+        .with_color((1.0, 1.0, 1.0, 1.0).into())
+        .with_global_translation((0.0, 0.0, 0.75).into())
+        .build(device), 
+        TextBuilder::new(
+            Some("RevertWindowText"), 
+            font, 
+            script.get(ScriptTags::SettingResolutionRevertMessage)?, 
+            text_brush
+        )
+        .with_anchor(anchor)
+        .with_margin(Margin::new(WND_HEIGHT / 5 + 30, -WND_WIDTH / 2, 30, WND_WIDTH / 2))
+        .with_color(TEXT_COLOR)
+        .with_translation((0.0, 0.0, 0.5).into())
+        .build(device, queue)
+    );
+
+    let countdown = TextBuilder::new(
+        Some("RevertCountdownText"), 
+        font, 
+        &format!("{}", RESOLUTION_REVERT_SEC as u32), 
+        text_brush
+    )
+    .with_anchor(anchor)
+    .with_margin(Margin::new(20, -WND_WIDTH / 2, -20, WND_WIDTH / 2))
+    .with_color(TEXT_COLOR)
+    .with_translation((0.0, 0.0, 0.5).into())
+    .build(device, queue);
+
+    let mut buttons = HashMap::new();
+    const BUTTONS: [(RevertWndButton, ScriptTags, Vec4, i32); 2] = [
+        (RevertWndButton::Keep, ScriptTags::SettingResolutionKeepButton, KEEP_BTN_COLOR, -WND_WIDTH / 5), 
+        (RevertWndButton::Revert, ScriptTags::SettingResolutionRevertButton, REVERT_BTN_COLOR, WND_WIDTH / 5), 
+    ];
+    for (key, tag, color, offset) in BUTTONS {
+        let margin = Margin::new(
+            BTN_HEIGHT / 2 - WND_HEIGHT * 3 / 10,
+            -BTN_WIDTH / 2 + offset,
+            -BTN_HEIGHT / 2 - WND_HEIGHT * 3 / 10,
+            BTN_WIDTH / 2 + offset
+        );
+        buttons.insert(
+            key, 
+            (
+                UiObjectBuilder::new(
+                    Some(&format!("Revert{:?}Button", key)), 
+                    tex_sampler, 
+                    btn_texture_view, 
+                    ui_brush
+                )
+                .with_anchor(anchor)
+                .with_margin(margin)
+                .with_color(color)
+                .with_global_translation(BTN_TRANSLATION)
+                .build(device), 
+                TextBuilder::new(
+                    Some(&format!("Revert{:?}ButtonText", key)), 
+                    font, 
+                    script.get(tag)?, 
+                    text_brush
+                )
+                .with_anchor(anchor)
+                .with_margin(margin)
+                .with_color(TEXT_COLOR)
+                .with_translation(TEXT_TRANSLATION)
+                .build(device, queue)
+            )
+        );
+    }
+
+    return Ok((window, countdown, buttons));
+}
+
+/// #### 한국어 </br>
+/// 설정 창의 설정 초기화 확인 창을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a settings reset confirmation window in the setting window. </br>
+/// 
+pub(super) fn create_setting_reset_window(
+    font: &FontArc, 
+    script: &Script, 
+    device: &wgpu::Device, 
+    queue: &wgpu::Queue, 
+    tex_sampler: &wgpu::Sampler, 
+    backdrop_texture_view: &wgpu::TextureView, 
+    window_texture_view: &wgpu::TextureView, 
+    btn_texture_view: &wgpu::TextureView, 
+    ui_brush: &UiBrush, 
+    text_brush: &TextBrush
+) -> AppResult<ModalWindow<ResetWndButton>> {
+    const WND_WIDTH: i32 = 400;
+    const WND_HEIGHT: i32 = WND_WIDTH / 4 * 3;
+
+    const BTN_WIDTH: i32 = 150;
+    const BTN_HEIGHT: i32 = BTN_WIDTH / 3;
+    const BTN_TRANSLATION: Vec3 = Vec3::new(0.0, 0.0, 0.5);
+
+    const YES_BTN_COLOR: Vec4 = Vec4::new(1.0, 103.0 / 255.0, 105.0 / 255.0, 1.0);
+    const NO_BTN_COLOR: Vec4 = Vec4::new(1.0, 1.0, 1.0, 1.0);
+
+    const TEXT_TRANSLATION: Vec3 = Vec3::new(0.0, 0.0, 0.25);
+    const TEXT_COLOR: Vec4 = Vec4::new(0.0, 0.0, 0.0, 1.0);
+
+    let backdrop = UiObjectBuilder::new(
+        Some("ResetBackdrop"), 
+        tex_sampler, 
+        backdrop_texture_view, 
+        ui_brush
+    )
+    .with_anchor(Anchor::new(1.0, 0.0, 0.0, 1.0))
+    .with_color((0.0, 0.0, 0.0, 0.0).into())
+    .with_global_translation((0.0, 0.0, 0.9).into())
+    .build(device);
+
+    let anchor = Anchor::new(0.5, 0.5, 0.5, 0.5);
+    let window = UiObjectBuilder::new(
+        Some("ResetWindow"), 
+        tex_sampler, 
+        window_texture_view, 
+        ui_brush
+    )
+    .with_anchor(anchor)
+    .with_margin(Margin::new(WND_HEIGHT / 2, -WND_WIDTH / 2, -WND_HEIGHT / 2, WND_WIDTH / 2))
+    .with_color((1.0, 1.0, 1.0, 1.0).into())
+    .with_global_scale((0.0, 0.0, 0.0).into())
+    .with_global_translation((0.0, 0.0, 0.75).into())
+    .build(device);
+
+    let message = TextBuilder::new(
+        Some("ResetWindowText"), 
+        font, 
+        script.get(ScriptTags::SettingResetReconfirmMessage)?, 
+        text_brush
+    )
+    .with_anchor(anchor)
+    .with_margin(Margin::new(WND_HEIGHT / 5, -WND_WIDTH / 2, 0, WND_WIDTH / 2))
+    .with_scale((0.0, 0.0, 0.0).into())
+    .with_color(TEXT_COLOR)
+    .with_translation((0.0, 0.0, 0.5).into())
+    .build(device, queue);
+
+    let mut buttons = HashMap::new();
+    const BUTTONS: [(ResetWndButton, ScriptTags, Vec4, i32); 2] = [
+        (ResetWndButton::Yes, ScriptTags::SettingResetOkayButton, YES_BTN_COLOR, -WND_WIDTH / 5), 
+        (ResetWndButton::No, ScriptTags::SettingResetCancelButton, NO_BTN_COLOR, WND_WIDTH / 5), 
+    ];
+    for (key, tag, color, offset) in BUTTONS {
+        let margin = Margin::new(
+            BTN_HEIGHT / 2 - WND_HEIGHT * 3 / 10,
+            -BTN_WIDTH / 2 + offset,
+            -BTN_HEIGHT / 2 - WND_HEIGHT * 3 / 10,
+            BTN_WIDTH / 2 + offset
+        );
+        buttons.insert(
+            key, 
+            (
+                UiObjectBuilder::new(
+                    Some(&format!("Reset{:?}Button", key)), 
+                    tex_sampler, 
+                    btn_texture_view, 
+                    ui_brush
+                )
+                .with_anchor(anchor)
+                .with_margin(margin)
+                .with_color(color)
+                .with_global_scale((0.0, 0.0, 0.0).into())
+                .with_global_translation(BTN_TRANSLATION)
+                .build(device), 
+                TextBuilder::new(
+                    Some(&format!("Reset{:?}ButtonText", key)), 
+                    font, 
+                    script.get(tag)?, 
+                    text_brush
+                )
+                .with_anchor(anchor)
+                .with_margin(margin)
+                .with_scale((0.0, 0.0, 0.0).into())
+                .with_color(TEXT_COLOR)
+                .with_translation(TEXT_TRANSLATION)
+                .build(device, queue)
+            )
+        );
+    }
+
+    return Ok(ModalWindow::new(window, vec![message], buttons)
+        .with_backdrop(backdrop, RESET_BACKDROP_ALPHA)
+        .with_outside_click(OutsideClick::Close)
+        .with_focus_order(vec![ResetWndButton::No, ResetWndButton::Yes])
+    );
+}
@@ -0,0 +1,168 @@
+use serde::{Serialize, Deserialize};
+
+
+
+/// #### 한국어 </br>
+/// 렌더링 장치 어뎁터를 선택할 때 선호하는 전력 사용 방식 입니다. </br>
+/// - 자동: 그래픽스 라이브러리의 기본 선택을 따릅니다. (기본값) </br>
+/// - 저전력: 내장 그래픽처럼 전력을 적게 사용하는 장치를 선호합니다. </br>
+/// - 고성능: 외장 그래픽처럼 성능이 높은 장치를 선호합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The preferred power usage when selecting a rendering device adapter. </br>
+/// - Auto: Follows the default choice of the graphics library. (default) </br>
+/// - LowPower: Prefers devices that use less power, such as integrated graphics. </br>
+/// - HighPerformance: Prefers devices with higher performance, such as discrete graphics. </br>
+/// 
+#[derive(Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GpuPreference {
+    #[default]
+    Auto,
+    LowPower,
+    HighPerformance,
+}
+
+impl From<GpuPreference> for wgpu::PowerPreference {
+    #[inline]
+    fn from(value: GpuPreference) -> Self {
+        match value {
+            GpuPreference::Auto => wgpu::PowerPreference::None,
+            GpuPreference::LowPower => wgpu::PowerPreference::LowPower,
+            GpuPreference::HighPerformance => wgpu::PowerPreference::HighPerformance,
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 선택된 렌더링 장치 어뎁터에 대한 경고 목록 입니다. </br>
+/// - 소프트웨어 렌더링: CPU로 렌더링하는 어뎁터(llvmpipe, WARP 등)가 선택되어 게임이 느리게 동작할 수 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a list of warnings about the selected rendering device adapter. </br>
+/// - SoftwareRendering: An adapter that renders on the CPU (llvmpipe, WARP, etc.) was selected, so the game may run slowly. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AdapterWarning {
+    SoftwareRendering,
+}
+
+impl AdapterWarning {
+    /// #### 한국어 </br>
+    /// 어뎁터 정보로부터 사용자에게 알려야 할 경고를 분류합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Classifies the warning to notify the user from the adapter information. </br>
+    /// 
+    #[inline]
+    pub fn classify(info: &wgpu::AdapterInfo) -> Option<Self> {
+        match info.device_type {
+            wgpu::DeviceType::Cpu => Some(Self::SoftwareRendering),
+            _ => None,
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 렌더링 장치를 생성해 볼 어뎁터 후보들을 우선순위 순서대로 반환합니다. </br>
+/// 1. 이름이 주어진 경우, 같은 이름을 가지고 표면을 지원하는 어뎁터 </br>
+/// 2. 선호하는 전력 사용 방식으로 요청한 어뎁터 </br>
+/// 3. 선호하는 방식이 있는 경우, 기본 방식으로 요청한 어뎁터 </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the adapter candidates to try creating a rendering device with, in order of priority. </br>
+/// 1. If a name is given, the adapter with the same name that supports the surface </br>
+/// 2. The adapter requested with the preferred power usage </br>
+/// 3. If there is a preference, the adapter requested with the default usage </br>
+/// 
+pub fn adapter_candidates(
+    instance: &wgpu::Instance,
+    surface: &wgpu::Surface,
+    preference: GpuPreference,
+    adapter_name: Option<&str>
+) -> Vec<wgpu::Adapter> {
+    let mut candidates = Vec::with_capacity(3);
+
+    // (한국어) 사용할 수 있는 어뎁터 목록을 기록하고, 이름이 같은 어뎁터를 찾습니다.
+    // (English Translation) Logs the list of available adapters and finds the adapter with the same name.
+    for adapter in instance.enumerate_adapters(wgpu::Backends::all()) {
+        let info = adapter.get_info();
+        log::info!("Available adapter :: <Name:{}> <Backend:{:?}> <Type:{:?}>", info.name, info.backend, info.device_type);
+        if adapter_name.is_some_and(|name| matches_name(&info.name, name))
+        && candidates.is_empty()
+        && adapter.is_surface_supported(surface) {
+            candidates.push(adapter);
+        }
+    }
+    if let Some(name) = adapter_name.filter(|_| candidates.is_empty()) {
+        log::warn!("The adapter \"{}\" was not found or does not support the surface.", name);
+    }
+
+    let mut preferences = vec![preference];
+    if preference != GpuPreference::Auto {
+        preferences.push(GpuPreference::Auto);
+    }
+    for preference in preferences {
+        let adapter = pollster::block_on(
+            instance.request_adapter(&wgpu::RequestAdapterOptions {
+                compatible_surface: Some(surface),
+                force_fallback_adapter: false,
+                power_preference: preference.into(),
+            })
+        );
+        match adapter {
+            Some(adapter) => candidates.push(adapter),
+            None => log::warn!("No adapter was found. (preference:{:?})", preference),
+        }
+    }
+
+    return candidates;
+}
+
+/// #### 한국어 </br>
+/// 어뎁터 이름이 설정된 이름과 같은 경우 `true`를 반환합니다. (앞뒤 공백, 대소문자 무시) </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns `true` if the adapter name equals the configured name. (ignoring surrounding whitespace and case) </br>
+/// 
+#[inline]
+fn matches_name(adapter_name: &str, name: &str) -> bool {
+    adapter_name.trim().eq_ignore_ascii_case(name.trim())
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn adapter_name_ignores_case_and_whitespace() {
+        assert!(matches_name("NVIDIA GeForce RTX 3060 Laptop GPU", " nvidia geforce rtx 3060 laptop gpu "));
+        assert!(!matches_name("NVIDIA GeForce RTX 3060 Laptop GPU", "NVIDIA GeForce"));
+        assert_eq!(wgpu::PowerPreference::from(GpuPreference::Auto), wgpu::PowerPreference::None);
+        assert_eq!(wgpu::PowerPreference::from(GpuPreference::LowPower), wgpu::PowerPreference::LowPower);
+    }
+
+    #[test]
+    fn cpu_adapter_is_classified_as_software_rendering() {
+        let mut info = wgpu::AdapterInfo {
+            name: "llvmpipe (LLVM 15.0.7, 256 bits)".to_string(),
+            vendor: 0x10005,
+            device: 0,
+            device_type: wgpu::DeviceType::Cpu,
+            driver: "llvmpipe".to_string(),
+            driver_info: String::new(),
+            backend: wgpu::Backend::Vulkan,
+        };
+        assert_eq!(AdapterWarning::classify(&info), Some(AdapterWarning::SoftwareRendering));
+
+        info.device_type = wgpu::DeviceType::IntegratedGpu;
+        assert_eq!(AdapterWarning::classify(&info), None);
+        info.device_type = wgpu::DeviceType::DiscreteGpu;
+        assert_eq!(AdapterWarning::classify(&info), None);
+    }
+}
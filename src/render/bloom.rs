@@ -0,0 +1,389 @@
+//! #### 한국어 </br>
+//! 장면의 밝은 부분을 빛나게 하는 블룸 후처리를 관리합니다. </br>
+//! 블룸은 오프스크린 텍스처에 그려진 장면에 적용되며, 다음 순서로 진행됩니다. </br>
+//! 1. 밝은 부분 추출: 일정 밝기 이상인 부분만 절반 크기의 텍스처에 그립니다. </br>
+//! 2. 분리 가능한 블러: 가로, 세로 방향으로 한 번씩 흐리게 만듭니다. </br>
+//! 3. 합성: 장면과 흐려진 빛을 더해서 프레임 버퍼에 그립니다. </br>
+//! 
+//! 블룸은 기본적으로 꺼져 있으며, 소프트웨어 렌더링 어뎁터에서는 사용할 수 없습니다. </br>
+//! 
+//! #### English (Translation) </br>
+//! Manages the bloom post-process that makes the bright parts of the scene glow. </br>
+//! Bloom is applied to the scene drawn into the offscreen texture, in the following order. </br>
+//! 1. Bright pass: Draws only the parts above a certain luminance into a half-size texture. </br>
+//! 2. Separable blur: Blurs once in the horizontal and once in the vertical direction. </br>
+//! 3. Composite: Adds the blurred glow to the scene and draws it into the framebuffer. </br>
+//! 
+//! Bloom is off by default, and cannot be used on software rendering adapters. </br>
+//! 
+use std::sync::Arc;
+
+use crate::{
+    assets::bundle::AssetBundle,
+    components::user::Settings,
+    render::{
+        adapter::AdapterWarning,
+        offscreen::{self, OffscreenTarget},
+        shader::WgslDecoder,
+    },
+    system::{
+        error::AppResult,
+        shared::Shared,
+    },
+};
+
+
+
+/// #### 한국어 </br>
+/// 주어진 어뎁터에서 블룸 후처리를 사용할 수 있는지 확인합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Checks whether the bloom post-process can be used on the given adapter. </br>
+/// 
+#[inline]
+pub fn is_supported(info: &wgpu::AdapterInfo) -> bool {
+    AdapterWarning::classify(info).is_none()
+}
+
+
+/// #### 한국어 </br>
+/// 주어진 내부 해상도에서 블룸 중간 텍스처의 크기를 반환합니다. (최소 `1x1`) </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the size of the bloom intermediate textures at the given internal resolution. (at least `1x1`) </br>
+/// 
+#[inline]
+fn bloom_size(width: u32, height: u32) -> (u32, u32) {
+    ((width / 2).max(1), (height / 2).max(1))
+}
+
+
+
+/// #### 한국어 </br>
+/// 블룸 후처리의 렌더링 파이프라인들 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// These are the rendering pipelines of the bloom post-process. </br>
+/// 
+#[derive(Debug)]
+pub struct BloomPipeline {
+    bright: wgpu::RenderPipeline,
+    blur_horizontal: wgpu::RenderPipeline,
+    blur_vertical: wgpu::RenderPipeline,
+    composite: wgpu::RenderPipeline,
+    texture_layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+}
+
+impl BloomPipeline {
+    pub fn new(
+        device: &wgpu::Device,
+        render_format: wgpu::TextureFormat,
+        asset_bundle: &AssetBundle
+    ) -> AppResult<Arc<Self>> {
+        let module = create_shader_module(device, asset_bundle)?;
+        let texture_layout = offscreen::create_texture_layout(device);
+        let pass_layout = &[&texture_layout];
+        let composite_layout = &[&texture_layout, &texture_layout];
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Sampler(Bloom)"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            min_filter: wgpu::FilterMode::Linear,
+            mag_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        Ok(Self {
+            bright: create_pipeline(device, &module, pass_layout, render_format, "fs_bright"),
+            blur_horizontal: create_pipeline(device, &module, pass_layout, render_format, "fs_blur_horizontal"),
+            blur_vertical: create_pipeline(device, &module, pass_layout, render_format, "fs_blur_vertical"),
+            composite: create_pipeline(device, &module, composite_layout, render_format, "fs_composite"),
+            texture_layout,
+            sampler,
+        }.into())
+    }
+
+    /// #### 한국어 </br>
+    /// 오프스크린 텍스처의 장면에 블룸을 적용하여 주어진 프레임 버퍼 전체에 그리는 렌더 패스들을 기록합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Records the render passes that apply bloom to the scene of the offscreen texture and draw it over the whole given framebuffer. </br>
+    /// 
+    pub fn apply(&self, encoder: &mut wgpu::CommandEncoder, bloom: &Bloom, view: &wgpu::TextureView) {
+        record_pass(encoder, "RenderPass(Bloom(Bright))", &self.bright, &bloom.bright_view, &[&bloom.scene_bind_group]);
+        record_pass(encoder, "RenderPass(Bloom(BlurHorizontal))", &self.blur_horizontal, &bloom.blur_view, &[&bloom.bright_bind_group]);
+        record_pass(encoder, "RenderPass(Bloom(BlurVertical))", &self.blur_vertical, &bloom.bright_view, &[&bloom.blur_bind_group]);
+        record_pass(encoder, "RenderPass(Bloom(Composite))", &self.composite, view, &[&bloom.scene_bind_group, &bloom.bright_bind_group]);
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 블룸 후처리에 사용되는 중간 텍스처들 입니다. </br>
+/// 오프스크린 텍스처가 다시 생성되면 (창 크기나 렌더링 배율이 바뀐 경우) 함께 다시 생성됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// These are the intermediate textures used in the bloom post-process. </br>
+/// They are created again together when the offscreen texture is created again (when the window size or the render scale changes). </br>
+/// 
+#[derive(Debug)]
+pub struct Bloom {
+    scene: Arc<OffscreenTarget>,
+    scene_bind_group: wgpu::BindGroup,
+    bright_view: wgpu::TextureView,
+    bright_bind_group: wgpu::BindGroup,
+    blur_view: wgpu::TextureView,
+    blur_bind_group: wgpu::BindGroup,
+}
+
+impl Bloom {
+    pub fn new(
+        device: &wgpu::Device,
+        pipeline: &BloomPipeline,
+        scene: Arc<OffscreenTarget>,
+        render_format: wgpu::TextureFormat
+    ) -> Self {
+        let (width, height) = scene.size();
+        let (width, height) = bloom_size(width, height);
+
+        let scene_bind_group = create_bind_group(device, pipeline, scene.view());
+        let bright_view = device.create_texture(&offscreen::texture_descriptor(width, height, render_format))
+            .create_view(&wgpu::TextureViewDescriptor::default());
+        let bright_bind_group = create_bind_group(device, pipeline, &bright_view);
+        let blur_view = device.create_texture(&offscreen::texture_descriptor(width, height, render_format))
+            .create_view(&wgpu::TextureViewDescriptor::default());
+        let blur_bind_group = create_bind_group(device, pipeline, &blur_view);
+
+        Self {
+            scene,
+            scene_bind_group,
+            bright_view,
+            bright_bind_group,
+            blur_view,
+            blur_bind_group,
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 블룸이 주어진 오프스크린 텍스처로 생성되었는지 확인합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether the bloom was created with the given offscreen texture. </br>
+    /// 
+    #[inline]
+    pub fn matches(&self, scene: &Arc<OffscreenTarget>) -> bool {
+        Arc::ptr_eq(&self.scene, scene)
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 설정에서 블룸이 켜져 있는지 확인합니다. </br>
+/// 어뎁터가 블룸을 지원하지 않아 파이프라인이 생성되지 않은 경우 `false`를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Checks whether bloom is turned on in the settings. </br>
+/// Returns `false` if the pipeline was not created because the adapter does not support bloom. </br>
+/// 
+#[inline]
+pub fn is_enabled(shared: &Shared) -> bool {
+    shared.get::<Settings>().is_some_and(|settings| settings.bloom)
+    && shared.get::<Arc<BloomPipeline>>().is_some()
+}
+
+
+/// #### 한국어 </br>
+/// 설정과 현재 오프스크린 텍스처에 맞게 블룸 중간 텍스처들을 다시 설정합니다. </br>
+/// 블룸이 꺼져 있거나 오프스크린 텍스처가 없는 경우 중간 텍스처들을 제거합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reconfigures the bloom intermediate textures to fit the settings and the current offscreen texture. </br>
+/// If bloom is turned off or there is no offscreen texture, the intermediate textures are removed. </br>
+/// 
+pub fn apply_bloom(shared: &mut Shared) {
+    let pipeline = shared.get::<Arc<BloomPipeline>>().filter(|_| is_enabled(shared)).cloned();
+    let scene = shared.get::<Arc<OffscreenTarget>>().cloned();
+    match pipeline.zip(scene) {
+        Some((pipeline, scene)) => {
+            let reusable = shared.get::<Arc<Bloom>>()
+                .is_some_and(|bloom| bloom.matches(&scene));
+            if !reusable {
+                let device = shared.get::<Arc<wgpu::Device>>().unwrap();
+                let format = shared.get::<wgpu::SurfaceConfiguration>().unwrap().format;
+                let bloom = Bloom::new(device, &pipeline, scene, format);
+                log::info!("Bloom textures created.");
+                shared.push(Arc::new(bloom));
+            }
+        },
+        None => {
+            shared.pop::<Arc<Bloom>>();
+        }
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 주어진 원본 텍스처로 주어진 대상 텍스처 전체를 그리는 렌더 패스를 기록합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Records a render pass that draws the whole given target texture from the given source textures. </br>
+/// 
+fn record_pass(
+    encoder: &mut wgpu::CommandEncoder,
+    label: &str,
+    pipeline: &wgpu::RenderPipeline,
+    view: &wgpu::TextureView,
+    bind_groups: &[&wgpu::BindGroup]
+) {
+    let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some(label),
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+            view,
+            resolve_target: None,
+            ops: wgpu::Operations {
+                load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                store: wgpu::StoreOp::Store,
+            }
+        })],
+        depth_stencil_attachment: None,
+        timestamp_writes: None,
+        occlusion_query_set: None,
+    });
+
+    rpass.set_pipeline(pipeline);
+    for (idx, bind_group) in bind_groups.iter().enumerate() {
+        rpass.set_bind_group(idx as u32, bind_group, &[]);
+    }
+    rpass.draw(0..3, 0..1);
+}
+
+
+/// #### 한국어 </br>
+/// 주어진 텍스처 뷰를 읽는 바인드 그룹을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a bind group that reads the given texture view. </br>
+/// 
+fn create_bind_group(
+    device: &wgpu::Device,
+    pipeline: &BloomPipeline,
+    view: &wgpu::TextureView
+) -> wgpu::BindGroup {
+    device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("BindGroup(Bloom)"),
+        layout: &pipeline.texture_layout,
+        entries: &[
+            wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(view),
+            },
+            wgpu::BindGroupEntry {
+                binding: 1,
+                resource: wgpu::BindingResource::Sampler(&pipeline.sampler),
+            },
+        ],
+    })
+}
+
+
+/// #### 한국어 </br>
+/// 블룸 후처리의 쉐이더 모듈을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a shader module for the bloom post-process. </br>
+/// 
+fn create_shader_module(
+    device: &wgpu::Device,
+    asset_bundle: &AssetBundle
+) -> AppResult<wgpu::ShaderModule> {
+    use crate::nodes::path;
+    let module = asset_bundle.get(path::BLOOM_SHADER_PATH)?
+        .read(&WgslDecoder { name: Some("Bloom"), device })?;
+    asset_bundle.release(path::BLOOM_SHADER_PATH);
+    return Ok(module);
+}
+
+
+/// #### 한국어 </br>
+/// 주어진 프래그먼트 진입점으로 블룸 후처리의 렌더링 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a rendering pipeline of the bloom post-process with the given fragment entry point. </br>
+/// 
+fn create_pipeline(
+    device: &wgpu::Device,
+    module: &wgpu::ShaderModule,
+    bind_group_layouts: &[&wgpu::BindGroupLayout],
+    render_format: wgpu::TextureFormat,
+    entry_point: &str
+) -> wgpu::RenderPipeline {
+    // (한국어) 렌더링 파이프라인 레이아웃을 생성합니다.
+    // (English Translation) Creates a rendering pipeline layout.
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(Bloom)"),
+            bind_group_layouts,
+            push_constant_ranges: &[],
+        },
+    );
+
+    // (한국어) 렌더링 파이프라인을 생성합니다.
+    // (English Translation) Create a rendering pipeline.
+    device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some(&format!("RenderPipeline(Bloom({}))", entry_point)),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module,
+                entry_point,
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: render_format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                cull_mode: None,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        }
+    )
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bloom_runs_at_half_resolution_on_hardware_adapters() {
+        assert_eq!(bloom_size(1280, 720), (640, 360));
+        assert_eq!(bloom_size(1, 1), (1, 1));
+
+        let mut info = wgpu::AdapterInfo {
+            name: "llvmpipe (LLVM 15.0.7, 256 bits)".to_string(),
+            vendor: 0x10005,
+            device: 0,
+            device_type: wgpu::DeviceType::Cpu,
+            driver: "llvmpipe".to_string(),
+            driver_info: String::new(),
+            backend: wgpu::Backend::Vulkan,
+        };
+        assert!(!is_supported(&info));
+        info.device_type = wgpu::DeviceType::DiscreteGpu;
+        assert!(is_supported(&info));
+    }
+}
@@ -1,7 +1,3 @@
-use winit::window::Window;
-
-
-
 /// #### 한국어 </br>
 /// 깊이 테스트에 사용되는 깊이 버퍼 입니다. </br>
 /// 
@@ -11,18 +7,20 @@ use winit::window::Window;
 #[derive(Debug)]
 pub struct DepthBuffer {
     texture_view: wgpu::TextureView,
+    width: u32, 
+    height: u32, 
 }
 
 impl DepthBuffer {
-    pub fn new(window: &Window, device: &wgpu::Device) -> Self {
+    pub fn new(device: &wgpu::Device, width: u32, height: u32) -> Self {
         // (한국어) 깊이 버퍼 텍스처를 생성합니다.
         // (English Translation) Create a depth buffer texture. 
         let texture = device.create_texture(
             &wgpu::TextureDescriptor {
                 label: Some("Depth Buffer"),
                 size: wgpu::Extent3d {
-                    width: window.inner_size().width,
-                    height: window.inner_size().height,
+                    width,
+                    height,
                     depth_or_array_layers: 1,
                 },
                 mip_level_count: 1,
@@ -38,7 +36,18 @@ impl DepthBuffer {
         // (English Translation) Create a depth buffer texture view.
         let texture_view = texture.create_view(&wgpu::TextureViewDescriptor { ..Default::default() });
 
-        Self { texture_view }
+        Self { texture_view, width, height }
+    }
+
+    /// #### 한국어 </br>
+    /// 깊이 버퍼의 크기가 주어진 크기와 같은지 확인합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether the size of the depth buffer is equal to the given size. </br>
+    /// 
+    #[inline]
+    pub fn matches(&self, width: u32, height: u32) -> bool {
+        size_matches((self.width, self.height), (width, height))
     }
 
     #[inline]
@@ -46,3 +55,31 @@ impl DepthBuffer {
         &self.texture_view
     }
 }
+
+
+/// #### 한국어 </br>
+/// 두 (너비, 높이) 크기가 같은지 확인합니다. </br>
+/// 크기가 같은 경우 깊이 버퍼와 오프스크린 텍스처를 다시 생성하지 않고 재사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Checks whether two (width, height) sizes are equal. </br>
+/// If the sizes are equal, the depth buffer and the offscreen texture are reused instead of being recreated. </br>
+/// 
+#[inline]
+pub fn size_matches(size: (u32, u32), other: (u32, u32)) -> bool {
+    size == other
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn only_identical_sizes_match() {
+        assert!(size_matches((1280, 720), (1280, 720)));
+        assert!(!size_matches((1280, 720), (1280, 721)));
+        assert!(!size_matches((1280, 720), (720, 1280)));
+        assert!(!size_matches((1280, 720), (0, 0)));
+    }
+}
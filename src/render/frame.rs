@@ -0,0 +1,308 @@
+use std::sync::Arc;
+
+use crate::{
+    game_err,
+    components::{
+        camera::GameCamera,
+        cursor::{self, CustomCursor},
+        text::TextBrush,
+        toast::ToastQueue,
+        ui::UiBrush,
+    },
+    render::{
+        bloom::{Bloom, BloomPipeline},
+        depth::DepthBuffer,
+        offscreen::{BlitPipeline, OffscreenTarget},
+        screenshot::{self, CaptureTarget},
+    },
+    system::{
+        error::{AppResult, GameError},
+        shared::Shared,
+    },
+};
+
+
+
+/// #### 한국어 </br>
+/// 그리는 중인 프레임 입니다. </br>
+/// 처음 시작한 렌더 패스만 배경색으로 프레임 버퍼를 지우고, 이후의 렌더 패스는 이전 내용 위에 그립니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A frame being drawn. </br>
+/// Only the first render pass clears the framebuffer with the background color,
+/// and the following render passes draw over the previous contents. </br>
+/// 
+pub struct Frame<'a> {
+    view: &'a wgpu::TextureView,
+    encoder: wgpu::CommandEncoder,
+    depth: &'a DepthBuffer,
+    clear: Option<wgpu::Color>,
+}
+
+impl<'a> Frame<'a> {
+    /// #### 한국어 </br>
+    /// 프레임 버퍼와 깊이 버퍼에 그리는 새 렌더 패스를 시작합니다. </br>
+    /// 깊이 버퍼는 렌더 패스마다 지워집니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Begins a new render pass that draws into the framebuffer and the depth buffer. </br>
+    /// The depth buffer is cleared for every render pass. </br>
+    /// 
+    pub fn begin_pass<'pass>(&'pass mut self, label: &'pass str) -> wgpu::RenderPass<'pass> {
+        let load = match self.clear.take() {
+            Some(color) => wgpu::LoadOp::Clear(color),
+            None => wgpu::LoadOp::Load,
+        };
+
+        self.encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some(label),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: self.view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load,
+                    store: wgpu::StoreOp::Store,
+                }
+            })],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: self.depth.view(),
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(1.0),
+                    store: wgpu::StoreOp::Store,
+                }),
+                stencil_ops: None,
+            }),
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        })
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 다음 프레임을 가져와 주어진 함수로 그린 후, 명령어 대기열에 제출하고 화면에 출력합니다. </br>
+/// 그리기 함수는 `Frame::begin_pass`로 렌더 패스를 시작하여 카메라를 바인드하고 오브젝트를 그립니다. </br>
+/// 알림 메시지가 있는 경우 그리기 함수가 그린 내용 위에 알림 메시지를 그립니다. </br>
+/// 오프스크린 텍스처가 있는 경우 그 텍스처에 그린 후 프레임 버퍼로 늘려서 복사하고, 블룸이 켜져 있는 경우 블룸을 적용합니다. </br>
+/// 게임 커서를 사용하는 경우 늘려서 복사하거나 블룸을 적용한 뒤의 프레임 버퍼 위에 게임 커서를 그립니다. </br>
+/// 프레임 버퍼를 복사할 수 없는데 화면 캡처나 녹화가 진행 중인 경우, 복사할 수 있는 중간 텍스처에도 같은 내용을 그립니다. </br>
+/// <b>복구할 수 없는 오류로 다음 프레임을 가져오지 못한 경우 `GameError`를 반환합니다.</b></br>
+/// 
+/// #### English (Translation) </br>
+/// Gets the next frame, draws it with the given function, submits it to the queue and presents it. </br>
+/// The drawing function begins render passes with `Frame::begin_pass`, binds the camera and draws the objects. </br>
+/// If there are notification messages, they are drawn over the contents drawn by the drawing function. </br>
+/// If there is an offscreen texture, it draws into that texture and then stretches and copies it to the framebuffer, applying bloom if it is turned on. </br>
+/// If the game cursor is used, it is drawn over the framebuffer after it has been stretched and copied or bloom has been applied. </br>
+/// If a screenshot or recording is in progress but the framebuffer cannot be copied, the same contents are also drawn into an intermediate texture that can be copied. </br>
+/// <b>Returns `GameError` if the next frame could not be acquired due to an unrecoverable error.</b></br>
+/// 
+pub fn with_frame<F>(shared: &Shared, clear: wgpu::Color, draw: F) -> AppResult<()>
+where F: FnOnce(&mut Frame) {
+    // (한국어) 사용할 공유 객체 가져오기.
+    // (English Translation) Get shared object to use.
+    let surface = shared.get::<Arc<wgpu::Surface>>().unwrap();
+    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
+    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap();
+    let depth = shared.get::<Arc<DepthBuffer>>().unwrap();
+    let offscreen = shared.get::<Arc<OffscreenTarget>>().zip(shared.get::<Arc<BlitPipeline>>());
+    let bloom = shared.get::<Arc<Bloom>>().zip(shared.get::<Arc<BloomPipeline>>());
+
+    // (한국어) 이전 작업이 끝날 때 까지 기다립니다.
+    // (English Translation) Wait until the previous operation is finished.
+    device.poll(wgpu::Maintain::Wait);
+
+    // (한국어) 다음 프레임을 가져옵니다. 가져오지 못한 경우 이번 프레임을 건너뜁니다.
+    // (English Translation) Get the next frame. If it could not be acquired, skips this frame.
+    let frame = match next_frame(shared, surface, device)? {
+        Some(frame) => frame,
+        None => return Ok(()),
+    };
+
+    // (한국어) 프레임 버퍼의 텍스쳐 뷰와 커맨드 버퍼를 생성합니다.
+    // (English Translation) Creates a texture view of the framebuffer and a command buffer.
+    let view = frame.texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+    // (한국어) 
+    // 화면 캡처나 녹화가 진행 중인데 프레임 버퍼를 복사할 수 없는 경우 중간 텍스처를 생성합니다.
+    // 오프스크린 텍스처가 없는 경우 게임 장면은 중간 텍스처의 임시 오프스크린 텍스처에 그려집니다.
+    //
+    // (English Translation) 
+    // If a screenshot or recording is in progress but the framebuffer cannot be copied, creates an intermediate texture.
+    // If there is no offscreen texture, the game scene is drawn into the temporary offscreen texture of the intermediate texture.
+    //
+    let blit = shared.get::<Arc<BlitPipeline>>();
+    let capture = blit.and_then(|blit| CaptureTarget::for_frame(device, blit, &frame.texture, offscreen.is_some()));
+    let scene = offscreen.map(|(target, _)| target.as_ref())
+        .or(capture.as_ref().and_then(|capture| capture.scene()));
+
+    let mut ctx = Frame {
+        view: match scene {
+            Some(target) => target.view(),
+            None => &view,
+        },
+        encoder: device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default()),
+        depth,
+        clear: Some(clear),
+    };
+
+    draw(&mut ctx);
+
+    // (한국어) 알림 메시지가 있는 경우 게임 장면의 모든 내용 위에 그립니다.
+    // (English Translation) If there are notification messages, draws them on top of all the contents of the game scene.
+    if let Some(toasts) = shared.get::<ToastQueue>().filter(|toasts| !toasts.is_empty()) {
+        let text_brush = shared.get::<Arc<TextBrush>>().unwrap();
+        let camera = shared.get::<Arc<GameCamera>>().unwrap();
+        let mut rpass = ctx.begin_pass("RenderPass(Toast)");
+        camera.bind(&mut rpass);
+        text_brush.draw(&mut rpass, toasts.texts());
+    }
+
+    // (한국어) 
+    // 오프스크린 텍스처에 그린 경우 블룸을 적용하거나 프레임 버퍼로 늘려서 복사합니다.
+    // 중간 텍스처가 있는 경우 중간 텍스처에도 같은 내용을 그립니다.
+    //
+    // (English Translation) 
+    // If drawn into the offscreen texture, applies bloom or stretches and copies it to the framebuffer.
+    // If there is an intermediate texture, the same contents are also drawn into it.
+    //
+    let outputs = std::iter::once(&view).chain(capture.as_ref().map(|capture| capture.view()));
+    for output in outputs.clone() {
+        match (scene, bloom, blit) {
+            (Some(_), Some((bloom, pipeline)), _) => pipeline.apply(&mut ctx.encoder, bloom, output),
+            (Some(target), None, Some(blit)) => blit.blit(&mut ctx.encoder, target, output),
+            _ => { /* empty */ }
+        }
+    }
+
+    // (한국어) 
+    // 게임 커서가 보이는 경우 늘려서 복사하거나 블룸을 적용한 뒤의 프레임 버퍼 위에 그립니다.
+    // 깊이 버퍼는 내부 해상도의 크기이므로, 프레임 버퍼와 크기가 다른 경우 프레임 버퍼 크기의 깊이 버퍼를 사용합니다.
+    //
+    // (English Translation) 
+    // If the game cursor is visible, draws it over the framebuffer after it has been stretched and copied or bloom has been applied.
+    // The depth buffer has the size of the internal resolution, so a depth buffer of the framebuffer size is used if their sizes differ.
+    //
+    if let Some(custom_cursor) = shared.get::<Arc<CustomCursor>>().filter(|_| cursor::is_visible(shared)) {
+        let ui_brush = shared.get::<Arc<UiBrush>>().unwrap();
+        let camera = shared.get::<Arc<GameCamera>>().unwrap();
+        let (width, height) = (frame.texture.width(), frame.texture.height());
+        let framebuffer_depth = (!depth.matches(width, height)).then(|| DepthBuffer::new(device, width, height));
+        let cursor_depth = framebuffer_depth.as_ref().unwrap_or(depth);
+        for output in outputs {
+            let mut rpass = begin_overlay_pass(&mut ctx.encoder, "RenderPass(CustomCursor)", output, cursor_depth);
+            camera.bind(&mut rpass);
+            custom_cursor.draw(ui_brush, &mut rpass);
+        }
+    }
+
+    // (한국어) 명령어 대기열에 커맨드 버퍼를 제출하고, 프레임 버퍼를 출력합니다.
+    // (English Translation) Submit command buffers to the queue and output to the framebuffer.
+    queue.submit(Some(ctx.encoder.finish()));
+    screenshot::present(device, queue, frame, capture.as_ref());
+
+    Ok(())
+}
+
+
+/// #### 한국어 </br>
+/// 주어진 텍스처 뷰의 내용 위에 그리는 새 렌더 패스를 시작합니다. </br>
+/// 깊이 버퍼는 텍스처 뷰와 같은 크기여야 하며, 렌더 패스를 시작할 때 지워집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Begins a new render pass that draws over the contents of the given texture view. </br>
+/// The depth buffer must have the same size as the texture view, and it is cleared when the render pass begins. </br>
+/// 
+fn begin_overlay_pass<'pass>(
+    encoder: &'pass mut wgpu::CommandEncoder, 
+    label: &'pass str, 
+    view: &'pass wgpu::TextureView, 
+    depth: &'pass DepthBuffer
+) -> wgpu::RenderPass<'pass> {
+    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some(label),
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+            view,
+            resolve_target: None,
+            ops: wgpu::Operations {
+                load: wgpu::LoadOp::Load,
+                store: wgpu::StoreOp::Store,
+            }
+        })],
+        depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+            view: depth.view(),
+            depth_ops: Some(wgpu::Operations {
+                load: wgpu::LoadOp::Clear(1.0),
+                store: wgpu::StoreOp::Store,
+            }),
+            stencil_ops: None,
+        }),
+        timestamp_writes: None,
+        occlusion_query_set: None,
+    })
+}
+
+
+/// #### 한국어 </br>
+/// 다음 프레임을 가져옵니다. </br>
+/// 렌더링 표면을 잃어버리거나 오래된 경우 다시 설정한 후 한 번 더 시도합니다. </br>
+/// 일시적인 오류로 프레임을 가져오지 못한 경우 `None`을 반환하여 이번 프레임을 건너뜁니다. </br>
+/// <b>메모리가 부족한 경우 `GameError`를 반환합니다.</b></br>
+/// 
+/// #### English (Translation) </br>
+/// Gets the next frame. </br>
+/// If the rendering surface has been lost or is outdated, reconfigures it and tries once more. </br>
+/// Returns `None` to skip this frame if it could not be acquired due to a transient error. </br>
+/// <b>Returns `GameError` if out of memory.</b></br>
+/// 
+fn next_frame(shared: &Shared, surface: &wgpu::Surface, device: &wgpu::Device) -> AppResult<Option<wgpu::SurfaceTexture>> {
+    let frame = match surface.get_current_texture() {
+        Err(wgpu::SurfaceError::Lost) | Err(wgpu::SurfaceError::Outdated) => {
+            log::warn!("The rendering surface was lost. Reconfiguring it.");
+            if let Some(config) = shared.get::<wgpu::SurfaceConfiguration>() {
+                surface.configure(device, config);
+            }
+            surface.get_current_texture()
+        },
+        result => result,
+    };
+
+    match frame {
+        Ok(frame) => Ok(Some(frame)),
+        Err(err) if !is_fatal(&err) => {
+            log::warn!("Skipping a frame for the following reasons: {}", err);
+            Ok(None)
+        },
+        Err(err) => Err(game_err!(
+            "Failed to get next frame",
+            "Failed to get next frame for the following reasons: {}",
+            err.to_string()
+        )),
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 프레임을 가져오는 중에 발생한 오류가 복구할 수 없는 오류인지 확인합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Checks whether an error that occurred while acquiring a frame is unrecoverable. </br>
+/// 
+#[inline]
+fn is_fatal(err: &wgpu::SurfaceError) -> bool {
+    matches!(err, wgpu::SurfaceError::OutOfMemory)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn only_out_of_memory_is_fatal() {
+        assert!(is_fatal(&wgpu::SurfaceError::OutOfMemory));
+        assert!(!is_fatal(&wgpu::SurfaceError::Lost));
+        assert!(!is_fatal(&wgpu::SurfaceError::Outdated));
+        assert!(!is_fatal(&wgpu::SurfaceError::Timeout));
+    }
+}
@@ -0,0 +1,173 @@
+//! #### 한국어 </br>
+//! 밉맵이 없는 텍스처의 밉맵을 GPU에서 생성합니다. </br>
+//! 각 밉 레벨은 이전 레벨을 선형 필터링으로 절반 크기로 줄여 그리는 렌더 패스로 생성됩니다. </br>
+//! 
+//! #### English (Translation) </br>
+//! Generates mipmaps on the GPU for textures that do not have mipmaps. </br>
+//! Each mip level is created by a render pass that draws the previous level downsampled to half size with linear filtering. </br>
+//! 
+use std::sync::Arc;
+
+use crate::{
+    assets::bundle::AssetBundle,
+    system::error::AppResult,
+};
+
+use super::offscreen;
+
+
+
+/// #### 한국어 </br>
+/// 주어진 크기의 텍스처가 `1x1` 크기까지 가질 수 있는 전체 밉 레벨의 수를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the number of mip levels a texture of the given size can have down to a size of `1x1`. </br>
+/// 
+#[cfg(test)]
+#[inline]
+pub fn mip_level_count(width: u32, height: u32) -> u32 {
+    u32::BITS - width.max(height).max(1).leading_zeros()
+}
+
+
+
+/// #### 한국어 </br>
+/// 텍스처의 밉맵을 생성하는 생성기 입니다. </br>
+/// 블릿 쉐이더를 사용하며, 텍스처 형식에 맞는 렌더링 파이프라인을 생성할 때마다 만듭니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a generator that creates mipmaps of textures. </br>
+/// It uses the blit shader, and creates a rendering pipeline matching the texture format each time it generates. </br>
+/// 
+#[derive(Debug)]
+pub struct MipmapGenerator {
+    module: wgpu::ShaderModule,
+    texture_layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+}
+
+impl MipmapGenerator {
+    pub fn new(device: &wgpu::Device, asset_bundle: &AssetBundle) -> AppResult<Arc<Self>> {
+        let module = offscreen::create_shader_module(device, asset_bundle)?;
+        let texture_layout = offscreen::create_texture_layout(device);
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Sampler(Mipmap)"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            min_filter: wgpu::FilterMode::Linear,
+            mag_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        Ok(Self {
+            module,
+            texture_layout,
+            sampler,
+        }.into())
+    }
+
+    /// #### 한국어 </br>
+    /// 텍스처의 첫 번째 밉 레벨로부터 `levels`개의 밉 레벨을 채웁니다. </br>
+    /// `levels`는 텍스처의 밉 레벨 수를 넘을 수 없으며, 모든 배열 레이어에 대해 생성합니다. </br>
+    /// 텍스처는 `RENDER_ATTACHMENT`와 `TEXTURE_BINDING` 용도로 생성되어야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Fills `levels` mip levels from the first mip level of the texture. </br>
+    /// `levels` cannot exceed the number of mip levels of the texture, and they are generated for all array layers. </br>
+    /// The texture must be created with the `RENDER_ATTACHMENT` and `TEXTURE_BINDING` usages. </br>
+    /// 
+    #[allow(dead_code)]
+    pub fn generate_mipmaps(
+        &self, 
+        device: &wgpu::Device, 
+        queue: &wgpu::Queue, 
+        texture: &wgpu::Texture, 
+        levels: u32
+    ) {
+        debug_assert!(
+            texture.usage().contains(wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING), 
+            "The texture must be usable as a render attachment and a texture binding!"
+        );
+        let levels = levels.min(texture.mip_level_count());
+        if levels <= 1 {
+            return;
+        }
+
+        let pipeline = offscreen::create_pipeline(device, &self.module, &self.texture_layout, texture.format());
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("CommandEncoder(Mipmap)"),
+        });
+
+        for layer in 0..texture.depth_or_array_layers() {
+            let views: Vec<_> = (0..levels)
+                .map(|level| texture.create_view(&wgpu::TextureViewDescriptor {
+                    label: Some("TextureView(Mipmap)"),
+                    dimension: Some(wgpu::TextureViewDimension::D2),
+                    base_mip_level: level,
+                    mip_level_count: Some(1),
+                    base_array_layer: layer,
+                    array_layer_count: Some(1),
+                    ..Default::default()
+                }))
+                .collect();
+
+            // (한국어) 이전 밉 레벨을 절반 크기로 줄여 다음 밉 레벨에 그립니다.
+            // (English Translation) Draws the previous mip level downsampled to half size into the next mip level.
+            for pair in views.windows(2) {
+                let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some("BindGroup(Mipmap)"),
+                    layout: &self.texture_layout,
+                    entries: &[
+                        wgpu::BindGroupEntry {
+                            binding: 0,
+                            resource: wgpu::BindingResource::TextureView(&pair[0]),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 1,
+                            resource: wgpu::BindingResource::Sampler(&self.sampler),
+                        },
+                    ],
+                });
+
+                let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("RenderPass(Mipmap)"),
+                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                        view: &pair[1],
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                            store: wgpu::StoreOp::Store,
+                        }
+                    })],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+
+                rpass.set_pipeline(&pipeline);
+                rpass.set_bind_group(0, &bind_group, &[]);
+                rpass.draw(0..3, 0..1);
+            }
+        }
+
+        queue.submit(Some(encoder.finish()));
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn full_mip_chain_of_power_of_two_texture() {
+        assert_eq!(mip_level_count(256, 256), 9);
+        assert_eq!(mip_level_count(1024, 512), 11);
+        assert_eq!(mip_level_count(1, 1), 1);
+
+        // (한국어) 2의 거듭제곱이 아닌 크기는 긴 변을 기준으로 내림합니다.
+        // (English Translation) Sizes that are not a power of two round down by the longer side.
+        assert_eq!(mip_level_count(300, 200), 9);
+    }
+}
@@ -1,6 +1,16 @@
+pub mod adapter;
+pub mod bloom;
 pub mod depth;
+pub mod frame;
+pub mod mipmap;
+pub mod offscreen;
+pub mod recorder;
+pub mod sampler;
+pub mod screenshot;
 pub mod shader;
 pub mod texture;
+pub mod tracker;
+pub mod visibility;
 
 
 
@@ -10,9 +20,13 @@ use winit::window::Window;
 
 use crate::{
     game_err,
-    system::error::{
-        AppResult,
-        GameError,
+    components::camera::{CameraData, GameCamera},
+    system::{
+        error::{
+            AppResult,
+            GameError,
+        },
+        shared::Shared,
     },
 };
 
@@ -20,14 +34,20 @@ use crate::{
 
 /// #### 한국어 </br>
 /// `wgpu` 렌더링 컨텍스트들을 생성합니다. </br>
+/// 렌더링 장치 어뎁터는 주어진 이름과 선호하는 전력 사용 방식에 따라 선택됩니다. </br>
 /// 이 함수를 실행하는 중에 오류가 발생한 경우 `GameError`를 반환합니다. </br>
 /// 
 /// #### English (Translation) </br>
 /// Create `wgpu` rendering contexts. </br>
+/// The rendering device adapter is selected according to the given name and the preferred power usage. </br>
 /// If an error occurs while executing this function, it returns `GameError`. </br>
 /// 
 #[inline]
-pub fn setup_render_ctx(window: Arc<Window>) -> AppResult<(
+pub fn setup_render_ctx(
+    window: Arc<Window>, 
+    preference: adapter::GpuPreference, 
+    adapter_name: Option<&str>
+) -> AppResult<(
     Arc<wgpu::Instance>,
     Arc<wgpu::Surface<'static>>,
     Arc<wgpu::Adapter>,
@@ -37,13 +57,73 @@ pub fn setup_render_ctx(window: Arc<Window>) -> AppResult<(
 )> {
     let instance = create_render_instance();
     let surface = create_render_surface(&instance, window.clone())?;
-    let adapter = create_render_adapter(&instance, &surface)?;
-    let (device, queue) = create_render_device_and_queue(&adapter)?;
+    let (adapter, device, queue) = create_render_adapter_and_device(&instance, &surface, preference, adapter_name)?;
     let depth_buffer = create_depth_buffer(&window, &device);
     Ok((instance, surface, adapter, device, queue, depth_buffer))
 }
 
 
+/// #### 한국어 </br>
+/// 현재 애플리케이션 윈도우의 크기에 맞게 프레임 버퍼, 깊이 버퍼, 오프스크린 텍스처, 카메라를 다시 설정합니다. </br>
+/// 윈도우가 다른 모니터로 옮겨져 `ScaleFactorChanged` 이벤트로 새 화면 배율이 전달된 경우 그 배율을 사용하고, 
+/// 그렇지 않은 경우 윈도우의 화면 배율을 사용합니다. </br>
+/// 윈도우의 크기가 `0`인 경우 아무것도 하지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reconfigures the framebuffer, depth buffer, offscreen texture, and camera to fit the current application window size. </br>
+/// If the window was moved to another monitor and a new scale factor was delivered by the `ScaleFactorChanged` event, 
+/// that scale factor is used, otherwise the scale factor of the window is used. </br>
+/// Does nothing if the size of the window is `0`. </br>
+/// 
+pub fn resize_render_ctx(window: &Window, shared: &mut Shared, scale_factor: Option<f64>) {
+    let instance = shared.get::<Arc<wgpu::Instance>>().unwrap().clone();
+    let surface = shared.get::<Arc<wgpu::Surface>>().unwrap().clone();
+    let device = shared.get::<Arc<wgpu::Device>>().unwrap().clone();
+    let queue = shared.get::<Arc<wgpu::Queue>>().unwrap().clone();
+    let config = shared.get_mut::<wgpu::SurfaceConfiguration>().unwrap();
+
+    let width = window.inner_size().width;
+    let height = window.inner_size().height;
+
+    if width > 0 && height > 0 {
+        instance.poll_all(true);
+        config.width = width;
+        config.height = height;
+        surface.configure(&device, config);
+
+        // (한국어) 내부 해상도에 맞게 깊이 버퍼와 오프스크린 텍스처를 다시 설정합니다.
+        // (English Translation) Reconfigures the depth buffer and the offscreen texture to fit the internal resolution.
+        offscreen::apply_render_scale(shared);
+
+        // (한국어) 커서 위치와 같은 기준을 사용하도록 모니터가 아닌 윈도우의 화면 배율을 사용합니다.
+        // (English Translation) Uses the scale factor of the window rather than the monitor so that it matches the cursor position.
+        let scale_factor = scale_factor.unwrap_or_else(|| window.scale_factor());
+        if let Some(camera) = shared.get::<Arc<GameCamera>>() {
+            camera.update(&queue, |data| {
+                apply_window_size(data, width, height, scale_factor);
+            });
+        }
+    }
+}
+
+/// #### 한국어 </br>
+/// 카메라 데이터에 새로운 윈도우 크기와 화면 배율을 적용합니다. </br>
+/// 뷰포트는 윈도우 전체를 덮도록 원점에서 다시 시작합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Applies the new window size and scale factor to the camera data. </br>
+/// The viewport starts again from the origin so that it covers the entire window. </br>
+/// 
+#[inline]
+fn apply_window_size(data: &mut CameraData, width: u32, height: u32, scale_factor: f64) {
+    data.viewport.x = 0.0;
+    data.viewport.y = 0.0;
+    data.viewport.width = width as f32;
+    data.viewport.height = height as f32;
+    data.scale_factor = scale_factor as f32;
+}
+
+
 /// #### 한국어 </br>
 /// `wgpu` 렌더링 인스턴스를 생성합니다. </br>
 /// 
@@ -103,30 +183,43 @@ fn create_render_surface(
 
 
 /// #### 한국어 </br>
-/// `wgpu` 렌더링 장치 어뎁터를 생성합니다. </br>
-/// 적절한 장치를 찾지 못한 경우 `GameError`를 반환합니다. </br>
+/// `wgpu` 렌더링 장치 어뎁터를 선택하고 논리적 장치와 명령어 대기열을 생성합니다. </br>
+/// 어뎁터 후보에서 장치를 생성하지 못한 경우 다음 후보로 넘어갑니다. </br>
+/// 모든 후보에서 장치를 생성하지 못한 경우 `GameError`를 반환합니다. </br>
 /// 
 /// #### English (Translation) </br>
-/// Create a `wgpu` rendering device adapter. </br>
-/// Returns `GameError` if no suitable device is found. </br>
+/// Selects a `wgpu` rendering device adapter and creates a logical device and command queue. </br>
+/// If the device cannot be created from an adapter candidate, it moves on to the next candidate. </br>
+/// Returns `GameError` if the device cannot be created from any candidate. </br>
 /// 
-#[inline]
-fn create_render_adapter(
-    instance: &wgpu::Instance,
-    surface: &wgpu::Surface
-) -> AppResult<Arc<wgpu::Adapter>> {
-    pollster::block_on(
-        instance.request_adapter(&wgpu::RequestAdapterOptions {
-            compatible_surface: Some(surface),
-            force_fallback_adapter: false,
-            power_preference: wgpu::PowerPreference::default()
-        })
-    )
-    .map(|adapter| adapter.into())
-    .ok_or_else(|| game_err!(
+fn create_render_adapter_and_device(
+    instance: &wgpu::Instance, 
+    surface: &wgpu::Surface, 
+    preference: adapter::GpuPreference, 
+    adapter_name: Option<&str>
+) -> AppResult<(Arc<wgpu::Adapter>, Arc<wgpu::Device>, Arc<wgpu::Queue>)> {
+    let mut last_err = None;
+    for adapter in adapter::adapter_candidates(instance, surface, preference, adapter_name) {
+        let info = adapter.get_info();
+        match create_render_device_and_queue(&adapter) {
+            Ok((device, queue)) => {
+                log::info!("Rendering adapter selected :: <Name:{}> <Backend:{:?}> <Type:{:?}>", info.name, info.backend, info.device_type);
+                if let Some(warning) = adapter::AdapterWarning::classify(&info) {
+                    log::warn!("The game may run slowly on the selected adapter. ({:?})", warning);
+                }
+                return Ok((adapter.into(), device, queue));
+            },
+            Err(err) => {
+                log::warn!("Failed to create a device on the adapter \"{}\": {:?}", info.name, err);
+                last_err = Some(err);
+            }
+        }
+    }
+
+    Err(last_err.unwrap_or_else(|| game_err!(
         "Failed to create rendering context",
         "No suitable device was found."
-    ))
+    )))
 }
 
 
@@ -170,5 +263,36 @@ fn create_render_device_and_queue(
 /// 
 #[inline]
 fn create_depth_buffer(window: &Window, device: &wgpu::Device,) -> Arc<depth::DepthBuffer>  {
-    Arc::new(depth::DepthBuffer::new(window, device))
+    Arc::new(depth::DepthBuffer::new(device, window.inner_size().width, window.inner_size().height))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::components::{
+        camera::Viewport,
+        transform::{Projection, Transform},
+    };
+
+    #[test]
+    fn moving_to_another_monitor_updates_the_camera() {
+        let mut data = CameraData {
+            viewport: Viewport { x: 40.0, y: 30.0, width: 800.0, height: 600.0, ..Default::default() },
+            transform: Transform::default(),
+            projection: Projection::new_ortho(1.0, -1.0, -1.0, 1.0, 0.0, 1000.0),
+            scale_factor: 1.0,
+        };
+
+        // (한국어) 화면 배율이 1.5인 모니터로 옮겨지면 윈도우의 물리 크기도 함께 커집니다.
+        // (English Translation) When moved to a monitor with a scale factor of 1.5, the physical size of the window also grows.
+        apply_window_size(&mut data, 1200, 900, 1.5);
+        assert_eq!(data.scale_factor, 1.5);
+        assert_eq!((data.viewport.x, data.viewport.y), (0.0, 0.0));
+        assert_eq!((data.viewport.width, data.viewport.height), (1200.0, 900.0));
+
+        apply_window_size(&mut data, 800, 600, 1.0);
+        assert_eq!(data.scale_factor, 1.0);
+        assert_eq!((data.viewport.width, data.viewport.height), (800.0, 600.0));
+    }
 }
@@ -0,0 +1,459 @@
+//! #### 한국어 </br>
+//! 창 크기와 별개인 내부 해상도로 게임 장면을 그리는 오프스크린 렌더 타겟을 관리합니다. </br>
+//! 렌더링 배율이 `100%`보다 작은 경우 게임 장면은 `창 크기 * 배율` 크기의 오프스크린 텍스처에 그려지고,
+//! 프레임의 마지막에 블릿 패스가 프레임 버퍼 크기로 늘려서 복사합니다. </br>
+//! 깊이 버퍼도 내부 해상도에 맞춰 생성됩니다. </br>
+//! 
+//! 렌더링 배율이 `100%`이고 블룸이 꺼져 있는 경우 오프스크린 텍스처를 사용하지 않고 프레임 버퍼에 바로 그립니다. </br>
+//! 텍스트도 내부 해상도로 그려지므로 배율이 낮을수록 흐려집니다. </br>
+//! 
+//! #### English (Translation) </br>
+//! Manages the offscreen render target that draws the game scenes at an internal resolution decoupled from the window size. </br>
+//! If the render scale is less than `100%`, the game scenes are drawn into an offscreen texture of size `window size * scale`,
+//! and at the end of the frame a blit pass stretches and copies it to the size of the framebuffer. </br>
+//! The depth buffer is also created to fit the internal resolution. </br>
+//! 
+//! If the render scale is `100%` and bloom is turned off, the offscreen texture is not used and the scenes are drawn directly into the framebuffer. </br>
+//! Text is also drawn at the internal resolution, so it becomes blurrier as the scale gets lower. </br>
+//! 
+use std::sync::Arc;
+
+use serde::{Serialize, Deserialize};
+use winit::window::Window;
+
+use crate::{
+    assets::bundle::AssetBundle,
+    components::user::Settings,
+    render::{bloom, depth::{self, DepthBuffer}, shader::WgslDecoder},
+    system::{
+        error::AppResult,
+        shared::Shared,
+    },
+};
+
+
+
+/// #### 한국어 </br>
+/// 창 크기에 곱해지는 내부 해상도의 배율을 백분율로 담고있는 구조체 입니다. </br>
+/// 화면을 알아볼 수 있도록 `50% ~ 100%` 범위로 제한됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a structure that contains the scale of the internal resolution multiplied to the window size, as a percentage. </br>
+/// It is limited to the range `50% ~ 100%` so that the screen stays recognizable. </br>
+/// 
+#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct RenderScale(u8);
+
+impl RenderScale {
+    pub const MIN_PERCENT: u8 = 50;
+    pub const MAX_PERCENT: u8 = 100;
+
+    /// #### 한국어 </br>
+    /// 주어진 백분율로 새로운 렌더링 배율을 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a new render scale with the given percentage. </br>
+    /// 
+    #[cfg(test)]
+    #[inline]
+    pub fn new(percent: u8) -> Self {
+        Self(percent.clamp(Self::MIN_PERCENT, Self::MAX_PERCENT))
+    }
+
+    /// #### 한국어 </br>
+    /// `0.5 ~ 1.0` 사이의 값으로 변환된 배율을 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Get the scale converted to a value between `0.5 and 1.0`. </br>
+    /// 
+    #[inline]
+    pub fn norm(&self) -> f32 {
+        self.0.clamp(Self::MIN_PERCENT, Self::MAX_PERCENT) as f32 / 100.0
+    }
+
+    /// #### 한국어 </br>
+    /// 배율이 `100%`인 경우 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if the scale is `100%`. </br>
+    /// 
+    #[inline]
+    pub fn is_native(&self) -> bool {
+        self.0 >= Self::MAX_PERCENT
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 창 크기에 배율을 적용한 내부 해상도를 반환합니다. (최소 `1x1`) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the internal resolution with the scale applied to the given window size. (at least `1x1`) </br>
+    /// 
+    #[inline]
+    pub fn scaled_size(&self, width: u32, height: u32) -> (u32, u32) {
+        let scale = self.norm();
+        let width = ((width as f32 * scale).round() as u32).max(1);
+        let height = ((height as f32 * scale).round() as u32).max(1);
+        return (width, height);
+    }
+}
+
+impl Default for RenderScale {
+    #[inline]
+    fn default() -> Self {
+        Self(100)
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 오프스크린 텍스처를 프레임 버퍼로 늘려서 복사하는 렌더링 파이프라인 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a rendering pipeline that stretches and copies the offscreen texture to the framebuffer. </br>
+/// 
+#[derive(Debug)]
+pub struct BlitPipeline {
+    pipeline: wgpu::RenderPipeline,
+    texture_layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+}
+
+impl BlitPipeline {
+    pub fn new(
+        device: &wgpu::Device,
+        render_format: wgpu::TextureFormat,
+        asset_bundle: &AssetBundle
+    ) -> AppResult<Arc<Self>> {
+        let module = create_shader_module(device, asset_bundle)?;
+        let texture_layout = create_texture_layout(device);
+        let pipeline = create_pipeline(device, &module, &texture_layout, render_format);
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("Sampler(Blit)"),
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            min_filter: wgpu::FilterMode::Linear,
+            mag_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        Ok(Self {
+            pipeline,
+            texture_layout,
+            sampler,
+        }.into())
+    }
+
+    /// #### 한국어 </br>
+    /// 오프스크린 텍스처를 주어진 프레임 버퍼 전체에 늘려서 그리는 렌더 패스를 기록합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Records a render pass that stretches and draws the offscreen texture over the whole given framebuffer. </br>
+    /// 
+    pub fn blit(&self, encoder: &mut wgpu::CommandEncoder, target: &OffscreenTarget, view: &wgpu::TextureView) {
+        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("RenderPass(Blit)"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                    store: wgpu::StoreOp::Store,
+                }
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+
+        rpass.set_pipeline(&self.pipeline);
+        rpass.set_bind_group(0, &target.bind_group, &[]);
+        rpass.draw(0..3, 0..1);
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 게임 장면이 내부 해상도로 그려지는 오프스크린 텍스처 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is an offscreen texture into which the game scenes are drawn at the internal resolution. </br>
+/// 
+#[derive(Debug)]
+pub struct OffscreenTarget {
+    texture_view: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+    width: u32,
+    height: u32,
+}
+
+impl OffscreenTarget {
+    pub fn new(
+        device: &wgpu::Device,
+        blit: &BlitPipeline,
+        render_format: wgpu::TextureFormat,
+        width: u32,
+        height: u32
+    ) -> Self {
+        // (한국어) 오프스크린 텍스처와 텍스처 뷰를 생성합니다.
+        // (English Translation) Creates an offscreen texture and texture view.
+        let texture = device.create_texture(&texture_descriptor(width, height, render_format));
+        let texture_view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        // (한국어) 블릿 패스에서 사용할 바인드 그룹을 생성합니다.
+        // (English Translation) Creates the bind group to use in the blit pass.
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("BindGroup(Offscreen)"),
+            layout: &blit.texture_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&texture_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&blit.sampler),
+                },
+            ],
+        });
+
+        Self { texture_view, bind_group, width, height }
+    }
+
+    /// #### 한국어 </br>
+    /// 오프스크린 텍스처의 크기가 주어진 크기와 같은지 확인합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Checks whether the size of the offscreen texture is equal to the given size. </br>
+    /// 
+    #[inline]
+    pub fn matches(&self, width: u32, height: u32) -> bool {
+        depth::size_matches((self.width, self.height), (width, height))
+    }
+
+    #[inline]
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    #[inline]
+    pub fn view(&self) -> &wgpu::TextureView {
+        &self.texture_view
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 현재 창 크기와 설정의 렌더링 배율에 맞게 깊이 버퍼와 오프스크린 텍스처를 다시 설정합니다. </br>
+/// 크기가 바뀌지 않은 경우 기존의 객체를 그대로 사용합니다. </br>
+/// 배율이 `100%`이고 블룸이 꺼져 있거나, 블릿 파이프라인이 아직 생성되지 않은 경우 오프스크린 텍스처를 제거합니다. </br>
+/// 마지막으로 오프스크린 텍스처에 맞게 블룸 중간 텍스처들을 다시 설정합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reconfigures the depth buffer and the offscreen texture to fit the current window size and the render scale of the settings. </br>
+/// If the size has not changed, the existing objects are reused. </br>
+/// If the scale is `100%` and bloom is turned off, or the blit pipeline has not been created yet, the offscreen texture is removed. </br>
+/// Finally, the bloom intermediate textures are reconfigured to fit the offscreen texture. </br>
+/// 
+pub fn apply_render_scale(shared: &mut Shared) {
+    let window = shared.get::<Arc<Window>>().unwrap();
+    let device = shared.get::<Arc<wgpu::Device>>().unwrap().clone();
+    let format = shared.get::<wgpu::SurfaceConfiguration>().unwrap().format;
+    let width = window.inner_size().width;
+    let height = window.inner_size().height;
+    if width == 0 || height == 0 {
+        return;
+    }
+
+    let scale = shared.get::<Settings>().map_or(RenderScale::default(), |settings| settings.render_scale);
+    let blit = shared.get::<Arc<BlitPipeline>>().filter(|_| !scale.is_native() || bloom::is_enabled(shared)).cloned();
+    let (width, height) = match blit {
+        Some(_) => scale.scaled_size(width, height),
+        None => (width, height),
+    };
+
+    // (한국어) 크기가 바뀐 경우에만 깊이 버퍼를 다시 생성합니다.
+    // (English Translation) Recreates the depth buffer only if the size has changed.
+    let reusable = shared.get::<Arc<DepthBuffer>>()
+        .is_some_and(|depth| depth.matches(width, height));
+    if !reusable {
+        shared.push(Arc::new(DepthBuffer::new(&device, width, height)));
+    }
+
+    match blit {
+        Some(blit) => {
+            let reusable = shared.get::<Arc<OffscreenTarget>>()
+                .is_some_and(|target| target.matches(width, height));
+            if !reusable {
+                log::info!("Offscreen target created. (size:{}x{}, scale:{}%)", width, height, scale.0);
+                shared.push(Arc::new(OffscreenTarget::new(&device, &blit, format, width, height)));
+            }
+        },
+        None => {
+            shared.pop::<Arc<OffscreenTarget>>();
+        }
+    }
+
+    bloom::apply_bloom(shared);
+}
+
+
+/// #### 한국어 </br>
+/// 주어진 크기의 오프스크린 텍스처 서술자를 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates an offscreen texture descriptor of the given size. </br>
+/// 
+pub(super) fn texture_descriptor(width: u32, height: u32, render_format: wgpu::TextureFormat) -> wgpu::TextureDescriptor<'static> {
+    wgpu::TextureDescriptor {
+        label: Some("Texture(Offscreen)"),
+        size: wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: render_format,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+        view_formats: &[],
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 블릿 패스의 쉐이더 모듈을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create a shader module for the blit pass. </br>
+/// 
+pub(super) fn create_shader_module(
+    device: &wgpu::Device,
+    asset_bundle: &AssetBundle
+) -> AppResult<wgpu::ShaderModule> {
+    use crate::nodes::path;
+    let module = asset_bundle.get(path::BLIT_SHADER_PATH)?
+        .read(&WgslDecoder { name: Some("Blit"), device })?;
+    asset_bundle.release(path::BLIT_SHADER_PATH);
+    return Ok(module);
+}
+
+
+/// #### 한국어 </br>
+/// 오프스크린 텍스처 바인드 그룹 레이아웃을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Create an offscreen texture bind group layout. </br>
+/// 
+pub(super) fn create_texture_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(
+        &wgpu::BindGroupLayoutDescriptor {
+            label: Some("BindGroupLayout(Texture(Offscreen))"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float {
+                            filterable: true
+                        },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(
+                        wgpu::SamplerBindingType::Filtering
+                    ),
+                    count: None
+                },
+            ],
+        },
+    )
+}
+
+
+/// #### 한국어 </br>
+/// 블릿 패스의 렌더링 파이프라인을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a rendering pipeline for the blit pass. </br>
+/// 
+pub(super) fn create_pipeline(
+    device: &wgpu::Device,
+    module: &wgpu::ShaderModule,
+    texture_layout: &wgpu::BindGroupLayout,
+    render_format: wgpu::TextureFormat
+) -> wgpu::RenderPipeline {
+    // (한국어) 렌더링 파이프라인 레이아웃을 생성합니다.
+    // (English Translation) Creates a rendering pipeline layout.
+    let pipeline_layout = device.create_pipeline_layout(
+        &wgpu::PipelineLayoutDescriptor {
+            label: Some("PipelineLayout(Blit)"),
+            bind_group_layouts: &[texture_layout],
+            push_constant_ranges: &[],
+        },
+    );
+
+    // (한국어) 렌더링 파이프라인을 생성합니다.
+    // (English Translation) Create a rendering pipeline.
+    device.create_render_pipeline(
+        &wgpu::RenderPipelineDescriptor {
+            label: Some("RenderPipeline(Blit)"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module,
+                entry_point: "vs_main",
+                buffers: &[],
+            },
+            fragment: Some(wgpu::FragmentState {
+                module,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: render_format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            primitive: wgpu::PrimitiveState {
+                topology: wgpu::PrimitiveTopology::TriangleList,
+                cull_mode: None,
+                ..Default::default()
+            },
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+        }
+    )
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn offscreen_texture_matches_scaled_window_size() {
+        let format = wgpu::TextureFormat::Bgra8Unorm;
+
+        let (width, height) = RenderScale::new(50).scaled_size(1280, 720);
+        let desc = texture_descriptor(width, height, format);
+        assert_eq!((desc.size.width, desc.size.height), (640, 360));
+
+        let (width, height) = RenderScale::new(75).scaled_size(1920, 1080);
+        let desc = texture_descriptor(width, height, format);
+        assert_eq!((desc.size.width, desc.size.height), (1440, 810));
+
+        assert_eq!(RenderScale::new(10), RenderScale::new(50));
+        assert_eq!(RenderScale::default().scaled_size(1280, 720), (1280, 720));
+        assert_eq!(RenderScale::new(50).scaled_size(1, 1), (1, 1));
+    }
+}
@@ -0,0 +1,223 @@
+use std::thread;
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use image::{Delay, Frame, RgbaImage};
+use image::imageops::{self, FilterType};
+use image::codecs::gif::{GifEncoder, Repeat};
+
+use super::screenshot::{self, Readback};
+
+
+
+/// #### 한국어 </br>
+/// 한 번에 녹화할 수 있는 최대 프레임 수 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum number of frames that can be recorded at once. </br>
+/// 
+const MAX_FRAMES: usize = 90;
+
+/// #### 한국어 </br>
+/// 녹화된 프레임의 최대 너비 입니다. 더 큰 프레임은 비율을 유지하며 축소됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum width of a recorded frame. Larger frames are scaled down keeping their ratio. </br>
+/// 
+const MAX_WIDTH: u32 = 480;
+
+/// #### 한국어 </br>
+/// 출력된 프레임 중 몇 프레임마다 하나를 녹화할지 나타냅니다. (60Hz 기준 약 20fps) </br>
+/// 
+/// #### English (Translation) </br>
+/// Records one out of this many presented frames. (About 20fps at 60Hz) </br>
+/// 
+const FRAME_STEP: usize = 3;
+const FRAME_DELAY_MS: u32 = 50;
+
+
+
+/// #### 한국어 </br>
+/// 진행 중인 녹화의 상태를 담고 있습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Contains the state of a recording in progress. </br>
+/// 
+#[derive(Debug)]
+struct Recording {
+    sender: Sender<Readback>,
+    remaining: usize,
+    counter: usize,
+}
+
+/// #### 한국어 </br>
+/// 현재 진행 중인 녹화 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The recording currently in progress. </br>
+/// 
+static RECORDING: Mutex<Option<Recording>> = Mutex::new(None);
+
+
+
+/// #### 한국어 </br>
+/// 주어진 시간 동안 출력되는 프레임을 녹화하기 시작합니다. </br>
+/// 녹화가 끝나면 백그라운드 스레드에서 실행 파일 옆에 `GIF` 파일로 저장합니다. </br>
+/// 이미 녹화 중인 경우 아무것도 하지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Starts recording the frames presented during the given time. </br>
+/// When finished, saves them as a `GIF` file next to the executable on a background thread. </br>
+/// Does nothing if a recording is already in progress. </br>
+/// 
+pub fn start(device: &Arc<wgpu::Device>, duration_sec: f64) {
+    let mut guard = RECORDING.lock().expect("Failed to access variable.");
+    if guard.is_some() {
+        log::warn!("A recording is already in progress.");
+        return;
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    let device = device.clone();
+    thread::spawn(move || {
+        match encode(&device, receiver) {
+            Ok(path) => log::info!("Recording saved to {}.", path.display()),
+            Err(msg) => log::error!("Failed to save the recording: {}", msg),
+        }
+    });
+
+    *guard = Some(Recording { sender, remaining: num_frames(duration_sec), counter: 0 });
+}
+
+
+/// #### 한국어 </br>
+/// 녹화가 진행 중인 경우 `true`를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns `true` if a recording is in progress. </br>
+/// 
+#[inline]
+pub(super) fn is_recording() -> bool {
+    RECORDING.lock().expect("Failed to access variable.").is_some()
+}
+
+
+/// #### 한국어 </br>
+/// 녹화 중인 경우 출력하기 직전의 프레임 버퍼를 복사하여 녹화기에 전달합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// While recording, copies the framebuffer just before presenting and passes it to the recorder. </br>
+/// 
+pub(super) fn capture(device: &wgpu::Device, queue: &wgpu::Queue, texture: &wgpu::Texture) {
+    let mut guard = RECORDING.lock().expect("Failed to access variable.");
+    let recording = match guard.as_mut() {
+        Some(recording) => recording,
+        None => return,
+    };
+
+    recording.counter += 1;
+    if (recording.counter - 1) % FRAME_STEP != 0 {
+        return;
+    }
+
+    // (한국어) 
+    // 녹화가 끝나거나 오류가 발생한 경우 송신자를 정리합니다.
+    // 송신자가 정리되면 백그라운드 스레드가 파일을 저장합니다.
+    //
+    // (English Translation) 
+    // Drops the sender when the recording ends or an error occurs.
+    // Once the sender is dropped, the background thread saves the file.
+    //
+    match Readback::copy(device, queue, texture) {
+        Ok(readback) => {
+            recording.sender.send(readback).ok();
+            recording.remaining -= 1;
+            if recording.remaining == 0 {
+                *guard = None;
+            }
+        },
+        Err(msg) => {
+            log::error!("Failed to record a frame: {}", msg);
+            *guard = None;
+        }
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 주어진 시간 동안 녹화할 프레임 수를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the number of frames to record during the given time. </br>
+/// 
+#[inline]
+fn num_frames(duration_sec: f64) -> usize {
+    let frames = (duration_sec * 1000.0 / FRAME_DELAY_MS as f64).ceil() as usize;
+    return frames.clamp(1, MAX_FRAMES);
+}
+
+
+/// #### 한국어 </br>
+/// 최대 너비에 맞게 축소된 크기를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the size scaled down to fit the maximum width. </br>
+/// 
+#[inline]
+fn scaled_size(width: u32, height: u32) -> (u32, u32) {
+    if width <= MAX_WIDTH {
+        return (width, height);
+    }
+    return (MAX_WIDTH, (height as u64 * MAX_WIDTH as u64 / width as u64).max(1) as u32);
+}
+
+
+/// #### 한국어 </br>
+/// 전달받은 프레임을 축소하여 모은 뒤, 송신자가 정리되면 `GIF` 파일로 저장합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Collects the received frames scaled down, and saves them as a `GIF` file once the sender is dropped. </br>
+/// 
+fn encode(device: &wgpu::Device, receiver: Receiver<Readback>) -> Result<PathBuf, String> {
+    let mut frames = Vec::with_capacity(MAX_FRAMES);
+    for readback in receiver {
+        let (width, height) = (readback.width, readback.height);
+        let image = RgbaImage::from_raw(width, height, readback.read(device)?)
+            .ok_or_else(|| "the frame size does not match.".to_string())?;
+
+        let (new_width, new_height) = scaled_size(width, height);
+        let image = match (new_width, new_height) == (width, height) {
+            true => image,
+            false => imageops::resize(&image, new_width, new_height, FilterType::Triangle),
+        };
+        frames.push(Frame::from_parts(image, 0, 0, Delay::from_numer_denom_ms(FRAME_DELAY_MS, 1)));
+    }
+
+    if frames.is_empty() {
+        return Err("no frames were recorded.".to_string());
+    }
+
+    let path = screenshot::output_path("Recording", "gif")?;
+    let file = File::create(&path).map_err(|e| e.to_string())?;
+    let mut encoder = GifEncoder::new(BufWriter::new(file));
+    encoder.set_repeat(Repeat::Infinite).map_err(|e| e.to_string())?;
+    encoder.encode_frames(frames).map_err(|e| e.to_string())?;
+    return Ok(path);
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn recording_is_bounded() {
+        assert_eq!(num_frames(1.0), 20);
+        assert_eq!(num_frames(60.0), MAX_FRAMES);
+        assert_eq!(scaled_size(1920, 1080), (MAX_WIDTH, 270));
+        assert_eq!(scaled_size(320, 240), (320, 240));
+    }
+}
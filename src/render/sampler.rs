@@ -0,0 +1,200 @@
+//! #### 한국어 </br>
+//! 게임 장면의 텍스처들이 공용으로 사용하는 텍스처 샘플러를 관리합니다. </br>
+//! 샘플러는 `Settings::texture_quality`에 따라 생성되며, 공유 객체에 `Arc<wgpu::Sampler>`로 등록됩니다. </br>
+//! 
+//! 설정이 바뀌면 공용 샘플러를 다시 생성하지만, 이미 생성된 바인드 그룹은 이전 샘플러를 계속 참조합니다. </br>
+//! 다음 객체들은 공용 샘플러로 바인드 그룹을 생성하므로 다시 생성해야 새로운 샘플러가 적용됩니다. </br>
+//! - [`UiObject`](crate::components::ui::UiObject) (버튼, 창, 배경, 이미지 등) </br>
+//! - [`Sprite`](crate::components::sprite::Sprite) (플레이어, 보스, 총알, 별 등) </br>
+//! 
+//! 이 객체들은 모두 로딩 게임 장면에서 생성되므로, 다음 게임 장면을 불러올 때 새로운 샘플러로 다시 생성됩니다. </br>
+//! [`TextBrush`](crate::components::text::TextBrush)는 자체 샘플러를 사용하므로 영향을 받지 않습니다. </br>
+//! 
+//! #### English (Translation) </br>
+//! Manages the texture sampler shared by the textures of the game scenes. </br>
+//! The sampler is created according to `Settings::texture_quality` and registered as `Arc<wgpu::Sampler>` in the shared object. </br>
+//! 
+//! When the setting changes, the shared sampler is created again, but bind groups that already exist keep referencing the previous sampler. </br>
+//! The following objects create their bind groups with the shared sampler, so they must be created again to use the new sampler. </br>
+//! - [`UiObject`](crate::components::ui::UiObject) (buttons, windows, backgrounds, images, etc.) </br>
+//! - [`Sprite`](crate::components::sprite::Sprite) (player, boss, bullets, stars, etc.) </br>
+//! 
+//! All of these objects are created in loading game scenes, so they are created again with the new sampler when the next game scene is loaded. </br>
+//! [`TextBrush`](crate::components::text::TextBrush) uses its own sampler, so it is not affected. </br>
+//! 
+use std::sync::Arc;
+
+use serde::{Serialize, Deserialize};
+
+use crate::{
+    components::user::Settings,
+    render::texture,
+    system::shared::Shared,
+};
+
+
+
+/// #### 한국어 </br>
+/// 텍스처 품질 설정 목록 입니다. </br>
+/// - 낮음: 밉맵 사이를 보간하지 않으며, 큰 텍스처는 한 단계 작은 밉맵부터 불러옵니다. </br>
+/// - 보통: 선형 필터링을 사용합니다. (기본값) </br>
+/// - 높음: 선형 필터링과 4배 비등방성 필터링을 사용합니다. </br>
+/// - 매우 높음: 선형 필터링과 16배 비등방성 필터링을 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a list of texture quality settings. </br>
+/// - Low: Does not interpolate between mipmaps, and large textures are loaded from the next smaller mipmap. </br>
+/// - Medium: Uses linear filtering. (default) </br>
+/// - High: Uses linear filtering and 4x anisotropic filtering. </br>
+/// - Ultra: Uses linear filtering and 16x anisotropic filtering. </br>
+/// 
+#[derive(Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextureQuality {
+    Low,
+    #[default]
+    Medium,
+    High,
+    Ultra,
+}
+
+impl TextureQuality {
+    /// #### 한국어 </br>
+    /// 밉맵 사이의 필터링 방식을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the filtering mode between mipmaps. </br>
+    /// 
+    #[inline]
+    pub fn mipmap_filter(self) -> wgpu::FilterMode {
+        match self {
+            Self::Low => wgpu::FilterMode::Nearest,
+            _ => wgpu::FilterMode::Linear,
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 비등방성 필터링의 최대 배율을 반환합니다. `1`인 경우 사용하지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the maximum ratio of anisotropic filtering. It is not used if it is `1`. </br>
+    /// 
+    #[inline]
+    pub fn anisotropy(self) -> u16 {
+        match self {
+            Self::Low | Self::Medium => 1,
+            Self::High => 4,
+            Self::Ultra => 16,
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 불러올 텍스처의 최대 크기를 반환합니다. (픽셀) </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the maximum size of textures to load. (pixels) </br>
+    /// 
+    #[inline]
+    pub fn max_texture_dimension(self) -> u32 {
+        match self {
+            Self::Low => 1024,
+            _ => u32::MAX,
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 주어진 텍스처 품질의 샘플러 서술자를 생성합니다. </br>
+/// 어댑터가 비등방성 필터링을 지원하지 않는 경우 선형 필터링으로 대체합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a sampler descriptor of the given texture quality. </br>
+/// If the adapter does not support anisotropic filtering, it falls back to linear filtering. </br>
+/// 
+fn sampler_descriptor(quality: TextureQuality, supports_anisotropy: bool) -> wgpu::SamplerDescriptor<'static> {
+    let quality = match quality.anisotropy() > 1 && !supports_anisotropy {
+        true => {
+            log::warn!("Anisotropic filtering is not supported. Falls back to linear filtering. (quality:{:?})", quality);
+            TextureQuality::Medium
+        },
+        false => quality,
+    };
+
+    return wgpu::SamplerDescriptor {
+        label: Some("Sampler(General)"),
+        address_mode_u: wgpu::AddressMode::ClampToEdge,
+        address_mode_v: wgpu::AddressMode::ClampToEdge,
+        address_mode_w: wgpu::AddressMode::ClampToEdge,
+        min_filter: wgpu::FilterMode::Linear,
+        mag_filter: wgpu::FilterMode::Linear,
+        mipmap_filter: quality.mipmap_filter(),
+        anisotropy_clamp: quality.anisotropy(),
+        ..Default::default()
+    };
+}
+
+/// #### 한국어 </br>
+/// 주어진 텍스처 품질로 공용 텍스처 샘플러를 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates the shared texture sampler with the given texture quality. </br>
+/// 
+pub fn create_texture_sampler(
+    device: &wgpu::Device,
+    adapter: &wgpu::Adapter,
+    quality: TextureQuality
+) -> wgpu::Sampler {
+    let supports_anisotropy = adapter.get_downlevel_capabilities()
+        .flags
+        .contains(wgpu::DownlevelFlags::ANISOTROPIC_FILTERING);
+    return device.create_sampler(&sampler_descriptor(quality, supports_anisotropy));
+}
+
+/// #### 한국어 </br>
+/// 설정의 텍스처 품질이 공용 샘플러와 다른 경우 샘플러를 다시 생성하여 공유 객체에 등록합니다. </br>
+/// 이미 생성된 바인드 그룹은 다시 생성될 때까지 이전 샘플러를 사용합니다. (상세: [모듈 문서](self)) </br>
+/// 
+/// #### English (Translation) </br>
+/// If the texture quality of the settings differs from the shared sampler, creates the sampler again and registers it in the shared object. </br>
+/// Bind groups that already exist use the previous sampler until they are created again. (see also: [module documentation](self)) </br>
+/// 
+pub fn apply_texture_quality(shared: &mut Shared) {
+    let quality = shared.get::<Settings>().unwrap().texture_quality;
+    if shared.get::<TextureQuality>().is_some_and(|applied| *applied == quality) {
+        return;
+    }
+
+    let device = shared.get::<Arc<wgpu::Device>>().unwrap();
+    let adapter = shared.get::<Arc<wgpu::Adapter>>().unwrap();
+    let tex_sampler = Arc::new(create_texture_sampler(device, adapter, quality));
+    texture::set_texture_size_limit(quality.max_texture_dimension());
+    log::info!("Texture sampler created. (quality:{:?})", quality);
+
+    shared.push(tex_sampler);
+    shared.push(quality);
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn unsupported_anisotropy_falls_back_to_linear() {
+        let desc = sampler_descriptor(TextureQuality::Ultra, true);
+        assert_eq!(desc.anisotropy_clamp, 16);
+        assert_eq!(desc.mipmap_filter, wgpu::FilterMode::Linear);
+
+        let desc = sampler_descriptor(TextureQuality::Ultra, false);
+        assert_eq!(desc.anisotropy_clamp, 1);
+        assert_eq!(desc.min_filter, wgpu::FilterMode::Linear);
+        assert_eq!(desc.mag_filter, wgpu::FilterMode::Linear);
+        assert_eq!(desc.mipmap_filter, wgpu::FilterMode::Linear);
+
+        let desc = sampler_descriptor(TextureQuality::Low, false);
+        assert_eq!(desc.anisotropy_clamp, 1);
+        assert_eq!(desc.mipmap_filter, wgpu::FilterMode::Nearest);
+    }
+}
@@ -0,0 +1,376 @@
+use std::env;
+use std::thread;
+use std::sync::Arc;
+use std::sync::mpsc;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::atomic::{AtomicBool, Ordering as MemOrdering};
+
+use super::{offscreen::{BlitPipeline, OffscreenTarget}, recorder};
+
+
+
+/// #### 한국어 </br>
+/// 다음 프레임을 화면 캡처할지 여부를 나타냅니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Indicates whether to capture the next frame as a screenshot. </br>
+/// 
+static REQUESTED: AtomicBool = AtomicBool::new(false);
+
+
+
+/// #### 한국어 </br>
+/// 다음에 출력되는 프레임의 화면 캡처를 요청합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Requests a screenshot of the next frame to be presented. </br>
+/// 
+#[inline]
+pub fn request() {
+    REQUESTED.store(true, MemOrdering::Release);
+}
+
+
+/// #### 한국어 </br>
+/// 화면 캡처가 가능하도록 프레임 버퍼의 용도를 반환합니다. </br>
+/// 렌더링 표면이 복사를 지원하지 않는 경우 화면 캡처는 복사할 수 있는 중간 텍스처에 그려집니다. (상세: [`CaptureTarget`]) </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the framebuffer usage so that screenshots can be taken. </br>
+/// If the rendering surface does not support copying, screenshots are drawn into an intermediate texture that can be copied. (see also: [`CaptureTarget`]) </br>
+/// 
+pub fn surface_usage(surface: &wgpu::Surface, adapter: &wgpu::Adapter) -> wgpu::TextureUsages {
+    let usages = surface.get_capabilities(adapter).usages;
+    if usages.contains(wgpu::TextureUsages::COPY_SRC) {
+        return wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC;
+    }
+
+    log::warn!("The rendering surface does not support copying. Screenshots are drawn into an intermediate texture.");
+    return wgpu::TextureUsages::RENDER_ATTACHMENT;
+}
+
+
+/// #### 한국어 </br>
+/// 프레임 버퍼를 출력합니다. </br>
+/// 화면 캡처가 요청된 경우 출력하기 전에 프레임 버퍼를 버퍼에 복사하고,
+/// 백그라운드 스레드에서 실행 파일 옆에 `PNG` 파일로 저장합니다. </br>
+/// 녹화 중인 경우 녹화기에도 프레임을 전달합니다. </br>
+/// 중간 텍스처가 주어진 경우 프레임 버퍼 대신 중간 텍스처를 복사합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Presents the framebuffer. </br>
+/// If a screenshot has been requested, copies the framebuffer into a buffer before presenting
+/// and saves it as a `PNG` file next to the executable on a background thread. </br>
+/// While recording, the frame is also passed to the recorder. </br>
+/// If an intermediate texture is given, it is copied instead of the framebuffer. </br>
+/// 
+pub fn present(
+    device: &Arc<wgpu::Device>, 
+    queue: &wgpu::Queue, 
+    frame: wgpu::SurfaceTexture, 
+    capture_target: Option<&CaptureTarget>
+) {
+    let texture = capture_target.map_or(&frame.texture, |target| &target.texture);
+    if REQUESTED.swap(false, MemOrdering::AcqRel) {
+        capture(device, queue, texture);
+    }
+    recorder::capture(device, queue, texture);
+
+    frame.present();
+}
+
+
+/// #### 한국어 </br>
+/// 이번 프레임에 중간 텍스처가 필요한 경우 `true`를 반환합니다. </br>
+/// 화면 캡처나 녹화가 진행 중인데 프레임 버퍼를 복사할 수 없는 경우에만 필요합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns `true` if an intermediate texture is needed for this frame. </br>
+/// It is only needed if a screenshot or recording is in progress and the framebuffer cannot be copied. </br>
+/// 
+#[inline]
+fn needs_capture_target(framebuffer_usage: wgpu::TextureUsages, pending: bool) -> bool {
+    pending && !framebuffer_usage.contains(wgpu::TextureUsages::COPY_SRC)
+}
+
+
+/// #### 한국어 </br>
+/// 프레임 버퍼를 복사할 수 없을 때 화면 캡처와 녹화에 사용할 중간 텍스처 입니다. </br>
+/// 프레임 버퍼와 같은 크기와 형식이며, 프레임 버퍼에 출력하는 내용을 한 번 더 그려서 복사합니다. </br>
+/// 오프스크린 텍스처가 없는 경우 게임 장면을 그릴 임시 오프스크린 텍스처를 함께 가집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// An intermediate texture used for screenshots and recording when the framebuffer cannot be copied. </br>
+/// It has the same size and format as the framebuffer, and the contents presented to the framebuffer are drawn once more into it to be copied. </br>
+/// If there is no offscreen texture, it also holds a temporary offscreen texture into which the game scene is drawn. </br>
+/// 
+#[derive(Debug)]
+pub struct CaptureTarget {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    scene: Option<OffscreenTarget>,
+}
+
+impl CaptureTarget {
+    /// #### 한국어 </br>
+    /// 이번 프레임에 중간 텍스처가 필요한 경우 주어진 프레임 버퍼에 맞는 중간 텍스처를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates an intermediate texture that fits the given framebuffer if one is needed for this frame. </br>
+    /// 
+    pub fn for_frame(
+        device: &wgpu::Device, 
+        blit: &BlitPipeline, 
+        framebuffer: &wgpu::Texture, 
+        has_offscreen: bool
+    ) -> Option<Self> {
+        let pending = REQUESTED.load(MemOrdering::Acquire) || recorder::is_recording();
+        if !needs_capture_target(framebuffer.usage(), pending) {
+            return None;
+        }
+
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Texture(Capture)"),
+            size: framebuffer.size(),
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: framebuffer.format(),
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let scene = (!has_offscreen).then(|| OffscreenTarget::new(
+            device, 
+            blit, 
+            framebuffer.format(), 
+            framebuffer.width(), 
+            framebuffer.height()
+        ));
+        return Some(Self { texture, view, scene });
+    }
+
+    #[inline]
+    pub fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+
+    #[inline]
+    pub fn scene(&self) -> Option<&OffscreenTarget> {
+        self.scene.as_ref()
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 프레임 버퍼를 복사한 버퍼입니다. </br>
+/// 화면 캡처와 녹화 기능이 함께 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A buffer into which the framebuffer has been copied. </br>
+/// Shared by the screenshot and recording features. </br>
+/// 
+#[derive(Debug)]
+pub(super) struct Readback {
+    buffer: wgpu::Buffer,
+    pub width: u32,
+    pub height: u32,
+    bytes_per_row: u32,
+    bgra: bool,
+}
+
+impl Readback {
+    /// #### 한국어 </br>
+    /// 주어진 텍스처를 버퍼에 복사하는 명령을 제출합니다. </br>
+    /// 지원하지 않는 텍스처 형식인 경우 오류 메시지를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Submits a command that copies the given texture into a buffer. </br>
+    /// Returns an error message if the texture format is not supported. </br>
+    /// 
+    pub fn copy(device: &wgpu::Device, queue: &wgpu::Queue, texture: &wgpu::Texture) -> Result<Self, String> {
+        if !texture.usage().contains(wgpu::TextureUsages::COPY_SRC) {
+            return Err("the framebuffer cannot be copied.".to_string());
+        }
+
+        let width = texture.width();
+        let height = texture.height();
+        let bgra = match texture.format() {
+            wgpu::TextureFormat::Bgra8Unorm | wgpu::TextureFormat::Bgra8UnormSrgb => true,
+            wgpu::TextureFormat::Rgba8Unorm | wgpu::TextureFormat::Rgba8UnormSrgb => false,
+            format => return Err(format!("unsupported framebuffer format. ({:?})", format)),
+        };
+
+        // (한국어) 행의 크기는 `wgpu`가 요구하는 정렬 크기에 맞춰야 합니다.
+        // (English Translation) The row size must be aligned to the alignment required by `wgpu`.
+        let bytes_per_row = padded_bytes_per_row(width);
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Buffer(Readback)"),
+            size: (bytes_per_row * height) as wgpu::BufferAddress,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.copy_texture_to_buffer(
+            texture.as_image_copy(),
+            wgpu::ImageCopyBuffer {
+                buffer: &buffer,
+                layout: wgpu::ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(bytes_per_row),
+                    rows_per_image: Some(height),
+                },
+            },
+            texture.size()
+        );
+        queue.submit(Some(encoder.finish()));
+
+        return Ok(Self { buffer, width, height, bytes_per_row, bgra });
+    }
+
+    /// #### 한국어 </br>
+    /// 복사가 완료될 때까지 기다린 후 `RGBA` 순서의 픽셀 데이터를 반환합니다. </br>
+    /// <b>이 함수는 백그라운드 스레드에서 호출해야 합니다.</b></br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Waits until the copy is complete and returns the pixel data in `RGBA` order. </br>
+    /// <b>This function should be called on a background thread.</b></br>
+    /// 
+    pub fn read(self, device: &wgpu::Device) -> Result<Vec<u8>, String> {
+        let slice = self.buffer.slice(..);
+        let (sender, receiver) = mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            sender.send(result).ok();
+        });
+        device.poll(wgpu::Maintain::Wait);
+        receiver.recv()
+            .map_err(|e| e.to_string())?
+            .map_err(|e| e.to_string())?;
+
+        let pixels = unpad_rows(&slice.get_mapped_range(), self.width, self.height, self.bytes_per_row, self.bgra);
+        self.buffer.unmap();
+        return Ok(pixels);
+    }
+}
+
+
+/// #### 한국어 </br>
+/// 실행 파일 옆에 타임스탬프가 붙은 파일 경로를 만듭니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Makes a timestamped file path next to the executable. </br>
+/// 
+pub(super) fn output_path(prefix: &str, extension: &str) -> Result<PathBuf, String> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| e.to_string())?
+        .as_millis();
+    return env::current_exe()
+        .map_err(|e| e.to_string())
+        .map(|path| path.with_file_name(format!("{}_{}.{}", prefix, timestamp, extension)));
+}
+
+
+/// #### 한국어 </br>
+/// 프레임 버퍼를 복사하고 백그라운드 스레드에서 `PNG` 파일로 저장합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Copies the framebuffer and saves it as a `PNG` file on a background thread. </br>
+/// 
+fn capture(device: &Arc<wgpu::Device>, queue: &wgpu::Queue, texture: &wgpu::Texture) {
+    let readback = match Readback::copy(device, queue, texture) {
+        Ok(readback) => readback,
+        Err(msg) => {
+            log::error!("Failed to take a screenshot: {}", msg);
+            return;
+        }
+    };
+
+    let device = device.clone();
+    thread::spawn(move || {
+        match save(&device, readback) {
+            Ok(path) => log::info!("Screenshot saved to {}.", path.display()),
+            Err(msg) => log::error!("Failed to take a screenshot: {}", msg),
+        }
+    });
+}
+
+
+/// #### 한국어 </br>
+/// 복사가 완료된 버퍼를 읽어 타임스탬프가 붙은 `PNG` 파일로 저장합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Reads the copied buffer and saves it as a timestamped `PNG` file. </br>
+/// 
+fn save(device: &wgpu::Device, readback: Readback) -> Result<PathBuf, String> {
+    let (width, height) = (readback.width, readback.height);
+    let pixels = readback.read(device)?;
+    let path = output_path("Screenshot", "png")?;
+    image::save_buffer(&path, &pixels, width, height, image::ColorType::Rgba8)
+        .map_err(|e| e.to_string())?;
+    return Ok(path);
+}
+
+
+/// #### 한국어 </br>
+/// 정렬된 행의 크기를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the aligned row size. </br>
+/// 
+#[inline]
+fn padded_bytes_per_row(width: u32) -> u32 {
+    let unpadded = width * 4;
+    let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+    return unpadded.div_ceil(align) * align;
+}
+
+
+/// #### 한국어 </br>
+/// 정렬용 여백을 제거하고 픽셀 데이터를 `RGBA` 순서로 바꿉니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Removes the alignment padding and converts pixel data to `RGBA` order. </br>
+/// 
+fn unpad_rows(data: &[u8], width: u32, height: u32, bytes_per_row: u32, bgra: bool) -> Vec<u8> {
+    let row_len = (width * 4) as usize;
+    let mut pixels = Vec::with_capacity(row_len * height as usize);
+    for row in data.chunks(bytes_per_row as usize).take(height as usize) {
+        pixels.extend_from_slice(&row[..row_len]);
+    }
+
+    if bgra {
+        for pixel in pixels.chunks_exact_mut(4) {
+            pixel.swap(0, 2);
+        }
+    }
+    return pixels;
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn capture_target_is_only_used_when_the_framebuffer_cannot_be_copied() {
+        let copyable = wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC;
+        assert!(needs_capture_target(wgpu::TextureUsages::RENDER_ATTACHMENT, true));
+        assert!(!needs_capture_target(wgpu::TextureUsages::RENDER_ATTACHMENT, false));
+        assert!(!needs_capture_target(copyable, true));
+    }
+
+    #[test]
+    fn padding_is_removed_and_channels_swapped() {
+        let bytes_per_row = padded_bytes_per_row(1);
+        assert_eq!(bytes_per_row, wgpu::COPY_BYTES_PER_ROW_ALIGNMENT);
+
+        let mut data = vec![0u8; (bytes_per_row * 2) as usize];
+        data[..4].copy_from_slice(&[1, 2, 3, 4]);
+        data[bytes_per_row as usize..bytes_per_row as usize + 4].copy_from_slice(&[5, 6, 7, 8]);
+
+        assert_eq!(unpad_rows(&data, 1, 2, bytes_per_row, true), vec![3, 2, 1, 4, 7, 6, 5, 8]);
+    }
+}
@@ -1,3 +1,5 @@
+use std::sync::atomic::{AtomicU32, Ordering as MemOrdering};
+
 use crate::{
     game_err,
     assets::interface::AssetDecoder,
@@ -5,6 +7,100 @@ use crate::{
 };
 
 
+
+/// #### 한국어 </br>
+/// 텍스처 품질 설정에 따라 불러올 `dds` 텍스처의 최대 크기 입니다. (픽셀) </br>
+/// 디코더들은 여러 스레드에서 사용되므로 공유 객체 대신 전역 변수에 저장합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The maximum size of `dds` textures to load according to the texture quality setting. (pixels) </br>
+/// Decoders are used from several threads, so it is stored in a global variable instead of the shared object. </br>
+/// 
+static TEXTURE_SIZE_LIMIT: AtomicU32 = AtomicU32::new(u32::MAX);
+
+/// #### 한국어 </br>
+/// 이후에 불러올 `dds` 텍스처의 최대 크기를 설정합니다. (픽셀) </br>
+/// 이미 생성된 텍스처에는 영향을 주지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Sets the maximum size of `dds` textures loaded afterwards. (pixels) </br>
+/// It does not affect textures that have already been created. </br>
+/// 
+#[inline]
+pub fn set_texture_size_limit(limit: u32) {
+    TEXTURE_SIZE_LIMIT.store(limit, MemOrdering::Release);
+}
+
+/// #### 한국어 </br>
+/// 텍스처의 가장 긴 변이 주어진 최대 크기 이하가 되도록 건너뛰어야 하는 상위 밉맵의 개수를 반환합니다. </br>
+/// 가장 작은 밉맵 하나는 항상 남깁니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the number of top mipmaps to skip so that the longest side of the texture is at most the given maximum size. </br>
+/// The smallest mipmap is always kept. </br>
+/// 
+pub fn mip_levels_to_skip(size: wgpu::Extent3d, mip_level_count: u32, max_dimension: u32) -> u32 {
+    let mut skip = 0;
+    while skip + 1 < mip_level_count && (size.width.max(size.height) >> skip) > max_dimension {
+        skip += 1;
+    }
+    return skip;
+}
+
+/// #### 한국어 </br>
+/// 주어진 밉맵 단계의 크기를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the size of the given mipmap level. </br>
+/// 
+#[inline]
+fn mip_level_size(size: wgpu::Extent3d, level: u32) -> wgpu::Extent3d {
+    wgpu::Extent3d {
+        width: (size.width >> level).max(1),
+        height: (size.height >> level).max(1),
+        depth_or_array_layers: size.depth_or_array_layers,
+    }
+}
+
+/// #### 한국어 </br>
+/// 레이어 하나의 주어진 밉맵 단계가 차지하는 바이트 수를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns the number of bytes taken by the given mipmap level of a single layer. </br>
+/// 
+fn mip_level_bytes(format: wgpu::TextureFormat, size: wgpu::Extent3d, level: u32) -> usize {
+    let (block_width, block_height) = format.block_dimensions();
+    let block_size = format.block_copy_size(None).unwrap_or(4) as usize;
+    let mip = mip_level_size(size, level);
+    let blocks_x = ((mip.width + block_width - 1) / block_width) as usize;
+    let blocks_y = ((mip.height + block_height - 1) / block_height) as usize;
+    return blocks_x * blocks_y * block_size;
+}
+
+/// #### 한국어 </br>
+/// 레이어 순서로 저장된 텍스처 데이터에서 각 레이어의 상위 밉맵들을 제거합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Removes the top mipmaps of each layer from texture data stored in layer-major order. </br>
+/// 
+fn skip_top_mips(
+    data: &[u8], 
+    format: wgpu::TextureFormat, 
+    size: wgpu::Extent3d, 
+    mip_level_count: u32, 
+    skip: u32
+) -> Vec<u8> {
+    let skipped: usize = (0..skip).map(|level| mip_level_bytes(format, size, level)).sum();
+    let kept: usize = (skip..mip_level_count).map(|level| mip_level_bytes(format, size, level)).sum();
+
+    let mut reduced = Vec::with_capacity(kept * size.depth_or_array_layers as usize);
+    for layer in data.chunks(skipped + kept).take(size.depth_or_array_layers as usize) {
+        reduced.extend_from_slice(&layer[skipped.min(layer.len())..]);
+    }
+    return reduced;
+}
+
+
 /// #### 한국어 </br>
 /// `dds` 이미지 파일로부터 텍스처를 만드는 디코더 입니다. </br>
 /// 
@@ -39,22 +135,257 @@ impl<'a> AssetDecoder for DdsTextureDecoder<'a> {
                 err.to_string()
             ))?;
 
+        // (한국어) 텍스처가 장치의 한계나 텍스처 품질 설정보다 큰 경우 상위 밉맵을 건너뛰고 불러옵니다.
+        // (English Translation) If the texture is larger than the device limit or the texture quality setting, it is loaded skipping the top mipmaps.
+        let max_dimension = self.device.limits().max_texture_dimension_2d
+            .min(TEXTURE_SIZE_LIMIT.load(MemOrdering::Acquire));
+        let skip = mip_levels_to_skip(self.size, self.mip_level_count, max_dimension);
+        let (size, mip_level_count, data) = match skip {
+            0 => (self.size, self.mip_level_count, dds.data),
+            _ => {
+                let size = mip_level_size(self.size, skip);
+                log::info!(
+                    "Texture({}) is downgraded from {}x{} to {}x{}. (max:{})", 
+                    self.name.unwrap_or("Unknown"), 
+                    self.size.width, 
+                    self.size.height, 
+                    size.width, 
+                    size.height, 
+                    max_dimension
+                );
+                let data = skip_top_mips(&dds.data, self.format, self.size, self.mip_level_count, skip);
+                (size, self.mip_level_count - skip, data)
+            }
+        };
+        if size.width.max(size.height) > max_dimension {
+            log::warn!(
+                "Texture({}) is larger than the maximum size and has no smaller mipmap. ({}x{}, max:{})", 
+                self.name.unwrap_or("Unknown"), 
+                size.width, 
+                size.height, 
+                max_dimension
+            );
+        }
+
         let texture = self.device.create_texture_with_data(
             self.queue, 
             &wgpu::TextureDescriptor {
                 label: Some(&format!("Texture({})", self.name.unwrap_or("Unknown"))),
-                size: self.size,
+                size,
                 dimension: self.dimension,
                 format: self.format,
-                mip_level_count: self.mip_level_count,
+                mip_level_count,
                 sample_count: self.sample_count,
                 usage: self.usage,
                 view_formats: self.view_formats,
             }, 
             wgpu::util::TextureDataOrder::LayerMajor,
-            &dds.data
+            &data
         );
 
         Ok(texture)
     }
 }
+
+
+
+/// #### 한국어 </br>
+/// `png`, `jpeg` 이미지 파일로부터 텍스처를 만드는 디코더 입니다. </br>
+/// 미리 변환된 `dds` 파일이 없는 사용자 콘텐츠를 불러올 때 사용합니다. </br>
+/// 이미지는 `RGBA8` 형식으로 디코딩되며, `mipmaps`가 `true`인 경우 CPU에서 밉맵을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a decoder that creates texture from `png`, `jpeg` image files. </br>
+/// It is used to load user content that does not have a pre-converted `dds` file. </br>
+/// The image is decoded into the `RGBA8` format, and mipmaps are generated on the CPU if `mipmaps` is `true`. </br>
+/// 
+#[derive(Debug, Clone, Copy)]
+pub struct ImageTextureDecoder<'a> {
+    pub name: Option<&'a str>,
+    pub format: wgpu::TextureFormat,
+    pub mipmaps: bool,
+    pub usage: wgpu::TextureUsages,
+    pub view_formats: &'a [wgpu::TextureFormat],
+    pub device: &'a wgpu::Device,
+    pub queue: &'a wgpu::Queue,
+}
+
+impl<'a> AssetDecoder for ImageTextureDecoder<'a> {
+    type Output = wgpu::Texture;
+
+    fn decode(&self, buf: &[u8]) -> AppResult<Self::Output> {
+        use wgpu::util::DeviceExt;
+
+        debug_assert!(
+            matches!(self.format, wgpu::TextureFormat::Rgba8Unorm | wgpu::TextureFormat::Rgba8UnormSrgb), 
+            "The texture format must be RGBA8!"
+        );
+
+        let image = decode_rgba(buf)?;
+        let mips = match self.mipmaps {
+            true => mip_chain(image),
+            false => vec![image],
+        };
+
+        let data: Vec<u8> = mips.iter()
+            .flat_map(|mip| mip.as_raw().iter().copied())
+            .collect();
+
+        let texture = self.device.create_texture_with_data(
+            self.queue, 
+            &wgpu::TextureDescriptor {
+                label: Some(&format!("Texture({})", self.name.unwrap_or("Unknown"))),
+                size: wgpu::Extent3d {
+                    width: mips[0].width(),
+                    height: mips[0].height(),
+                    depth_or_array_layers: 1,
+                },
+                dimension: wgpu::TextureDimension::D2,
+                format: self.format,
+                mip_level_count: mips.len() as u32,
+                sample_count: 1,
+                usage: self.usage,
+                view_formats: self.view_formats,
+            }, 
+            wgpu::util::TextureDataOrder::LayerMajor,
+            &data
+        );
+
+        Ok(texture)
+    }
+}
+
+/// #### 한국어 </br>
+/// `png`, `jpeg` 이미지 파일로부터 애플리케이션 윈도우 아이콘에 사용할 `RGBA8` 이미지를 만드는 디코더 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a decoder that creates an `RGBA8` image for the application window icon from `png`, `jpeg` image files. </br>
+/// 
+#[derive(Debug, Clone, Copy)]
+pub struct IconDecoder;
+
+impl AssetDecoder for IconDecoder {
+    type Output = image::RgbaImage;
+
+    #[inline]
+    fn decode(&self, buf: &[u8]) -> AppResult<Self::Output> {
+        decode_rgba(buf)
+    }
+}
+
+/// #### 한국어 </br>
+/// `png`, `jpeg` 이미지 파일을 `RGBA8` 이미지로 디코딩합니다. </br>
+/// 지원하지 않는 형식이거나 디코딩에 실패한 경우 `GameError`를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Decodes a `png`, `jpeg` image file into an `RGBA8` image. </br>
+/// Returns `GameError` if the format is not supported or decoding fails. </br>
+/// 
+fn decode_rgba(buf: &[u8]) -> AppResult<image::RgbaImage> {
+    let format = match image::guess_format(buf) {
+        Ok(format @ (image::ImageFormat::Png | image::ImageFormat::Jpeg)) => format,
+        _ => return Err(game_err!(
+            "Unsupported image format",
+            "Image assets must be PNG or JPEG files."
+        )),
+    };
+
+    let image = image::load_from_memory_with_format(buf, format)
+        .map_err(|err| game_err!(
+            "Image decoding failed",
+            "Image decoding failed for the following reasons: {}",
+            err.to_string()
+        ))?;
+
+    return Ok(image.into_rgba8());
+}
+
+/// #### 한국어 </br>
+/// 주어진 이미지부터 `1x1` 크기까지 절반씩 줄여가며 밉맵 목록을 생성합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Creates a list of mipmaps by halving from the given image down to a size of `1x1`. </br>
+/// 
+fn mip_chain(image: image::RgbaImage) -> Vec<image::RgbaImage> {
+    use image::imageops::{self, FilterType};
+
+    let mut mips = vec![image];
+    loop {
+        let last = mips.last().unwrap();
+        if last.width() == 1 && last.height() == 1 {
+            break;
+        }
+
+        let width = (last.width() / 2).max(1);
+        let height = (last.height() / 2).max(1);
+        let mip = imageops::resize(last, width, height, FilterType::Triangle);
+        mips.push(mip);
+    }
+
+    return mips;
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn small_png_is_decoded_with_mipmaps() {
+        use image::ImageEncoder;
+        use image::codecs::png::PngEncoder;
+
+        let pixels = [255u8; 4 * 2 * 4];
+        let mut png = Vec::new();
+        PngEncoder::new(&mut png)
+            .write_image(&pixels, 4, 2, image::ColorType::Rgba8)
+            .unwrap();
+
+        let image = decode_rgba(&png).unwrap();
+        assert_eq!(image.dimensions(), (4, 2));
+
+        let sizes: Vec<_> = mip_chain(image).iter().map(|mip| mip.dimensions()).collect();
+        assert_eq!(sizes, vec![(4, 2), (2, 1), (1, 1)]);
+
+        let err = decode_rgba(b"DDS |\x00\x00\x00").err().unwrap();
+        assert!(err.to_string().starts_with("<Unsupported image format>"));
+    }
+
+    #[test]
+    fn configured_limit_skips_top_mips() {
+        let size = wgpu::Extent3d { width: 2048, height: 2048, depth_or_array_layers: 3 };
+        let format = wgpu::TextureFormat::Bc7RgbaUnorm;
+        assert_eq!(mip_levels_to_skip(size, 12, 8192), 0);
+        assert_eq!(mip_levels_to_skip(size, 12, 2048), 0);
+        assert_eq!(mip_levels_to_skip(size, 12, 1024), 1);
+        assert_eq!(mip_levels_to_skip(size, 12, 500), 3);
+
+        // (한국어) 더 작은 밉맵이 없는 경우 그대로 불러옵니다.
+        // (English Translation) If there is no smaller mipmap, it is loaded as it is.
+        assert_eq!(mip_levels_to_skip(size, 1, 1024), 0);
+
+        // (한국어) 각 레이어에서 가장 큰 밉맵만 제거되어야 합니다.
+        // (English Translation) Only the largest mipmap must be removed from each layer.
+        let layer_bytes: Vec<usize> = (0..12).map(|level| mip_level_bytes(format, size, level)).collect();
+        assert_eq!(layer_bytes[0], 512 * 512 * 16);
+        assert_eq!(layer_bytes[11], 16);
+
+        let layer_len: usize = layer_bytes.iter().sum();
+        let data: Vec<u8> = (0..3).flat_map(|layer| vec![layer as u8; layer_len]).collect();
+        let reduced = skip_top_mips(&data, format, size, 12, 1);
+        let reduced_len = layer_len - layer_bytes[0];
+        assert_eq!(reduced.len(), 3 * reduced_len);
+        for (layer, chunk) in reduced.chunks(reduced_len).enumerate() {
+            assert!(chunk.iter().all(|&byte| byte == layer as u8));
+        }
+        assert_eq!(mip_level_size(size, 1), wgpu::Extent3d { width: 1024, height: 1024, depth_or_array_layers: 3 });
+    }
+
+    #[test]
+    fn bundled_icon_is_decoded_as_rgba() {
+        let buf = include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/assets/textures/sys/icon.png"));
+        let icon = IconDecoder.decode(buf).unwrap();
+        assert_eq!(icon.dimensions(), (32, 32));
+        assert_eq!(icon.as_raw().len(), 32 * 32 * 4);
+    }
+}
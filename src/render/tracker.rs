@@ -0,0 +1,175 @@
+//! #### 한국어 </br>
+//! 디버그 빌드에서 그리기 도구로 생성한 GPU 자원의 개수를 추적합니다. </br>
+//! 사용자 인터페이스, 텍스트, 스프라이트 등은 생성될 때 [`Tracked`]를 함께 가지며, 해제될 때 개수가 줄어듭니다. </br>
+//! 게임 루프는 게임 장면이 바뀔 때마다 남아있는 자원과 로드된 에셋의 변화량을 기록하므로,
+//! 해제를 잊은 게임 장면을 찾을 수 있습니다. </br>
+//! 릴리즈 빌드에서는 아무것도 세지 않습니다. </br>
+//! 
+//! #### English (Translation) </br>
+//! Tracks the number of GPU resources created by the drawing tools in debug builds. </br>
+//! User interfaces, texts, sprites, etc. hold a [`Tracked`] when they are created, and the count decreases when they are dropped. </br>
+//! The game loop logs the change of the remaining resources and loaded assets every time the game scene changes,
+//! so a game scene that forgets to release something can be found. </br>
+//! Nothing is counted in release builds. </br>
+//! 
+use std::sync::atomic::{AtomicIsize, Ordering as MemOrdering};
+
+#[cfg(debug_assertions)]
+use crate::assets::bundle::AssetBundle;
+
+
+
+static LIVE_BUFFERS: AtomicIsize = AtomicIsize::new(0);
+static LIVE_BIND_GROUPS: AtomicIsize = AtomicIsize::new(0);
+
+/// #### 한국어 </br>
+/// 자원의 개수를 비교하는 테스트와 GPU 자원을 생성하는 테스트가 동시에 실행되지 않도록 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Keeps tests that compare resource counts from running at the same time as tests that create GPU resources. </br>
+/// 
+#[cfg(all(test, debug_assertions))]
+pub static TEST_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+
+
+/// #### 한국어 </br>
+/// 추적 중인 자원의 개수 입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The number of tracked resources. </br>
+/// 
+#[cfg(debug_assertions)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceCounts {
+    pub buffers: isize,
+    pub bind_groups: isize,
+    pub loaded_assets: isize,
+}
+
+#[cfg(debug_assertions)]
+impl ResourceCounts {
+    /// #### 한국어 </br>
+    /// 현재 남아있는 자원과 로드된 에셋의 개수를 가져옵니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Gets the number of remaining resources and loaded assets. </br>
+    /// 
+    pub fn live(asset_bundle: &AssetBundle) -> Self {
+        Self {
+            buffers: LIVE_BUFFERS.load(MemOrdering::Acquire),
+            bind_groups: LIVE_BIND_GROUPS.load(MemOrdering::Acquire),
+            loaded_assets: asset_bundle.num_loaded() as isize,
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 이전 개수로부터의 변화량을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the change from the given previous counts. </br>
+    /// 
+    #[inline]
+    pub fn delta(&self, previous: &Self) -> Self {
+        Self {
+            buffers: self.buffers - previous.buffers,
+            bind_groups: self.bind_groups - previous.bind_groups,
+            loaded_assets: self.loaded_assets - previous.loaded_assets,
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// GPU 자원을 가진 객체가 함께 가지는 추적 표식 입니다. </br>
+/// 생성될 때 주어진 개수만큼 늘리고, 해제될 때 같은 개수만큼 줄입니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A tracking marker held together by an object that owns GPU resources. </br>
+/// It increases the counts by the given amounts when created, and decreases them by the same amounts when dropped. </br>
+/// 
+#[derive(Debug)]
+pub struct Tracked {
+    buffers: isize,
+    bind_groups: isize,
+}
+
+impl Tracked {
+    #[inline]
+    pub fn new(buffers: usize, bind_groups: usize) -> Self {
+        let tracked = Self { buffers: buffers as isize, bind_groups: bind_groups as isize };
+        tracked.add(1);
+        return tracked;
+    }
+
+    #[inline]
+    fn add(&self, sign: isize) {
+        if cfg!(debug_assertions) {
+            LIVE_BUFFERS.fetch_add(sign * self.buffers, MemOrdering::AcqRel);
+            LIVE_BIND_GROUPS.fetch_add(sign * self.bind_groups, MemOrdering::AcqRel);
+        }
+    }
+}
+
+impl Drop for Tracked {
+    #[inline]
+    fn drop(&mut self) {
+        self.add(-1);
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 게임 장면이 바뀐 후 남아있는 자원과 로드된 에셋의 개수를 이전 게임 장면 전환과 비교하여 기록합니다. </br>
+/// 다음 비교에 사용할 현재 개수를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Logs the number of remaining resources and loaded assets after the game scene changed, compared to the previous scene transition. </br>
+/// Returns the current counts to use for the next comparison. </br>
+/// 
+#[cfg(debug_assertions)]
+pub fn log_scene_transition(previous: &ResourceCounts, asset_bundle: &AssetBundle) -> ResourceCounts {
+    let current = ResourceCounts::live(asset_bundle);
+    let delta = current.delta(previous);
+    log::debug!(
+        "Scene changed. (buffers:{} ({:+}), bind groups:{} ({:+}), loaded assets:{} ({:+}))",
+        current.buffers,
+        delta.buffers,
+        current.bind_groups,
+        delta.bind_groups,
+        current.loaded_assets,
+        delta.loaded_assets
+    );
+    return current;
+}
+
+
+#[cfg(all(test, debug_assertions))]
+mod test {
+    use super::*;
+
+    fn live() -> (isize, isize) {
+        (LIVE_BUFFERS.load(MemOrdering::Acquire), LIVE_BIND_GROUPS.load(MemOrdering::Acquire))
+    }
+
+    #[test]
+    fn dropping_tracked_resources_nets_to_zero() {
+        let _guard = TEST_LOCK.lock().unwrap_or_else(|err| err.into_inner());
+        let before = live();
+        let tracked: Vec<_> = (0..16).map(|_| Tracked::new(2, 1)).collect();
+        let (buffers, bind_groups) = live();
+        assert!(buffers - before.0 >= 32);
+        assert!(bind_groups - before.1 >= 16);
+
+        drop(tracked);
+        let after = live();
+        assert_eq!(after.0 - before.0, 0);
+        assert_eq!(after.1 - before.1, 0);
+
+        let previous = ResourceCounts { buffers: 4, bind_groups: 2, loaded_assets: 10 };
+        let current = ResourceCounts { buffers: 4, bind_groups: 3, loaded_assets: 8 };
+        assert_eq!(current.delta(&previous), ResourceCounts { buffers: 0, bind_groups: 1, loaded_assets: -2 });
+    }
+}
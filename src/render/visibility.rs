@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use winit::event::WindowEvent;
+
+
+
+/// #### 한국어 </br>
+/// 윈도우가 보이지 않는 동안 게임 루프가 한 번 돌 때마다 쉬는 시간 입니다. </br>
+/// 그리지 않는 동안에는 수직 동기화로 기다리지 않으므로 게임 루프가 쉬지 않고 도는 것을 막습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The time the game loop rests on each iteration while the window is not visible. </br>
+/// Since it does not wait for vertical sync while not drawing, this keeps the game loop from spinning without rest. </br>
+/// 
+pub const HIDDEN_FRAME_TIME: Duration = Duration::from_millis(50);
+
+
+
+/// #### 한국어 </br>
+/// 윈도우가 최소화되어 크기가 `0`이거나 다른 창에 완전히 가려졌는지 추적합니다. </br>
+/// 이 상태에서는 프레임을 가져올 수 없거나 그려도 보이지 않으므로 그리기를 건너뜁니다. </br>
+/// 윈도우가 복원되면 `Resized` 이벤트로 렌더링 표면이 다시 설정된 후 그리기를 다시 시작합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Tracks whether the window is minimized to a size of `0` or completely covered by other windows. </br>
+/// In this state the frame cannot be acquired or is not visible even if drawn, so drawing is skipped. </br>
+/// When the window is restored, drawing resumes after the rendering surface is reconfigured by the `Resized` event. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct WindowVisibility {
+    zero_size: bool,
+    occluded: bool,
+}
+
+impl WindowVisibility {
+    /// #### 한국어 </br>
+    /// 윈도우 이벤트로 보이는 상태를 갱신합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the visibility with a window event. </br>
+    /// 
+    pub fn handle_event(&mut self, event: &WindowEvent) {
+        match event {
+            WindowEvent::Resized(size) => self.zero_size = size.width == 0 || size.height == 0,
+            WindowEvent::Occluded(occluded) => self.occluded = *occluded,
+            _ => { /* empty */ }
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 윈도우가 보이는 경우 `true`를 반환합니다. 이 경우에만 게임 장면을 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if the window is visible. The game scene is drawn only in this case. </br>
+    /// 
+    #[inline]
+    pub fn should_draw(&self) -> bool {
+        !self.zero_size && !self.occluded
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use winit::dpi::PhysicalSize;
+    use super::*;
+
+    #[test]
+    fn draws_are_skipped_while_zero_size() {
+        let mut visibility = WindowVisibility::default();
+        assert!(visibility.should_draw());
+
+        visibility.handle_event(&WindowEvent::Resized(PhysicalSize::new(0, 0)));
+        assert!(!visibility.should_draw());
+
+        // (한국어) 다른 이벤트는 보이는 상태를 바꾸지 않습니다.
+        // (English Translation) Other events do not change the visibility.
+        visibility.handle_event(&WindowEvent::Focused(true));
+        assert!(!visibility.should_draw());
+
+        visibility.handle_event(&WindowEvent::Resized(PhysicalSize::new(1280, 720)));
+        assert!(visibility.should_draw());
+
+        // (한국어) 가려진 경우에도 크기와 상관없이 그리지 않습니다.
+        // (English Translation) Drawing is also skipped when occluded, regardless of the size.
+        visibility.handle_event(&WindowEvent::Occluded(true));
+        assert!(!visibility.should_draw());
+        visibility.handle_event(&WindowEvent::Occluded(false));
+        assert!(visibility.should_draw());
+    }
+}
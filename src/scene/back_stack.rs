@@ -0,0 +1,135 @@
+/// #### 한국어 </br>
+/// 게임 장면 안에서 겹쳐서 열리는 상태(일시정지, 설정 창, 메시지 상자 등)입니다. </br>
+/// 각 상태는 열릴 때와 닫힐 때 거치는 전환 상태를 가집니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A state that opens on top of another within a game scene. (pause, setting window, message box, etc.) </br>
+/// Each state has the transition states it goes through when it opens and closes. </br>
+/// 
+pub trait Layer: Copy {
+    /// #### 한국어 </br>
+    /// 이 상태가 열릴 때 거치는 전환 상태를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the transition state gone through when this state opens. </br>
+    /// 
+    fn enter_transition(self) -> Self;
+
+    /// #### 한국어 </br>
+    /// 이 상태가 닫힐 때 거치는 전환 상태를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the transition state gone through when this state closes. </br>
+    /// 
+    fn exit_transition(self) -> Self;
+}
+
+
+
+/// #### 한국어 </br>
+/// 게임 장면 안에서 겹쳐서 열린 상태들의 스택 입니다. </br>
+/// `Escape` 키 등으로 뒤로 가면 가장 위의 상태 하나만 닫히며, 닫힌 후에는 그 아래의 상태로 돌아갑니다. </br>
+/// 아무것도 열려있지 않은 경우 기본 상태로 돌아갑니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A stack of states opened on top of each other within a game scene. </br>
+/// Going back, e.g. with the `Escape` key, closes only the topmost state, and then returns to the state beneath it. </br>
+/// If nothing is open, it returns to the base state. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct BackStack<S> {
+    base: S,
+    layers: Vec<S>,
+}
+
+impl<S: Layer> BackStack<S> {
+    #[inline]
+    pub fn new(base: S) -> Self {
+        Self { base, layers: Vec::new() }
+    }
+
+    /// #### 한국어 </br>
+    /// 가장 위에 열린 상태를 반환합니다. 열린 상태가 없는 경우 기본 상태를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the topmost open state. Returns the base state if no state is open. </br>
+    /// 
+    #[inline]
+    pub fn current(&self) -> S {
+        self.layers.last().copied().unwrap_or(self.base)
+    }
+
+    /// #### 한국어 </br>
+    /// 전환 없이 상태를 스택에 쌓습니다. 이미 열린 화면으로 게임 장면을 시작할 때 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Pushes a state onto the stack without a transition. Used when a game scene starts with a screen already open. </br>
+    /// 
+    #[inline]
+    pub fn push(&mut self, layer: S) {
+        self.layers.push(layer);
+    }
+
+    /// #### 한국어 </br>
+    /// 상태를 스택에 쌓고, 그 상태가 열릴 때 거치는 전환 상태를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Pushes a state onto the stack, and returns the transition state gone through when it opens. </br>
+    /// 
+    #[inline]
+    pub fn open(&mut self, layer: S) -> S {
+        self.push(layer);
+        layer.enter_transition()
+    }
+
+    /// #### 한국어 </br>
+    /// 가장 위의 상태 하나를 닫고, 그 상태가 닫힐 때 거치는 전환 상태를 반환합니다. </br>
+    /// 열린 상태가 없는 경우 `None`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Closes the topmost state, and returns the transition state gone through when it closes. </br>
+    /// Returns `None` if no state is open. </br>
+    /// 
+    #[inline]
+    pub fn back(&mut self) -> Option<S> {
+        self.layers.pop().map(Layer::exit_transition)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Screen {
+        Main,
+        Open,
+        Window,
+        Close,
+    }
+
+    impl Layer for Screen {
+        fn enter_transition(self) -> Self {
+            Self::Open
+        }
+
+        fn exit_transition(self) -> Self {
+            Self::Close
+        }
+    }
+
+    #[test]
+    fn back_at_the_base_does_nothing() {
+        let mut stack = BackStack::new(Screen::Main);
+        assert_eq!(stack.current(), Screen::Main);
+        assert_eq!(stack.back(), None);
+
+        assert_eq!(stack.open(Screen::Window), Screen::Open);
+        assert_eq!(stack.current(), Screen::Window);
+
+        assert_eq!(stack.back(), Some(Screen::Close));
+        assert_eq!(stack.current(), Screen::Main);
+        assert_eq!(stack.back(), None);
+    }
+}
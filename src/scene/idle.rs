@@ -0,0 +1,124 @@
+use winit::event::{Event, WindowEvent};
+
+use crate::system::event::AppEvent;
+
+
+
+/// #### 한국어 </br>
+/// 마지막 입력 이후 경과한 시간을 재는 타이머 입니다. </br>
+/// 제한 시간이 `0`인 경우 사용하지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A timer that measures the time elapsed since the last input. </br>
+/// It is disabled if the timeout is `0`. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IdleTimer {
+    timeout: f64,
+    elapsed: f64,
+}
+
+impl IdleTimer {
+    #[inline]
+    pub fn new(timeout_sec: u32) -> Self {
+        Self { timeout: timeout_sec as f64, elapsed: 0.0 }
+    }
+
+    /// #### 한국어 </br>
+    /// 제한 시간이 설정된 경우 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if a timeout is set. </br>
+    /// 
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        self.timeout > 0.0
+    }
+
+    /// #### 한국어 </br>
+    /// 입력이 있었으므로 경과한 시간을 초기화 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Resets the elapsed time because there was an input. </br>
+    /// 
+    #[inline]
+    pub fn reset(&mut self) {
+        self.elapsed = 0.0;
+    }
+
+    /// #### 한국어 </br>
+    /// 경과한 시간을 갱신하고, 제한 시간을 넘긴 경우 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the elapsed time, and returns `true` if it has passed the timeout. </br>
+    /// 
+    #[inline]
+    pub fn tick(&mut self, elapsed_time: f64) -> bool {
+        self.elapsed += elapsed_time;
+        self.is_enabled() && self.elapsed >= self.timeout
+    }
+}
+
+/// #### 한국어 </br>
+/// 사용자의 입력(키보드, 마우스, 터치)인 경우 `true`를 반환합니다. </br>
+/// 다시 그리기, 창 크기 변경 등의 윈도우 이벤트는 입력으로 보지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns `true` if the event is a user input. (keyboard, mouse, touch) </br>
+/// Window events such as redraws and window resizing are not regarded as input. </br>
+/// 
+#[inline]
+pub fn is_user_input(event: &Event<AppEvent>) -> bool {
+    matches!(
+        event, 
+        Event::WindowEvent { 
+            event: WindowEvent::KeyboardInput { .. } 
+            | WindowEvent::MouseInput { .. } 
+            | WindowEvent::MouseWheel { .. } 
+            | WindowEvent::CursorMoved { .. } 
+            | WindowEvent::Touch(_), 
+            .. 
+        }
+    )
+}
+
+
+#[cfg(test)]
+mod test {
+    use winit::{event::DeviceId, dpi::PhysicalPosition, window::WindowId};
+    use super::*;
+
+    #[test]
+    fn input_resets_the_idle_timer() {
+        let mut timer = IdleTimer::new(30);
+        assert!(!timer.tick(20.0));
+
+        // (한국어) 입력이 있으면 처음부터 다시 잽니다.
+        // (English Translation) An input starts measuring again from the beginning.
+        timer.reset();
+        assert!(!timer.tick(20.0));
+        assert!(timer.tick(10.0));
+
+        // (한국어) 제한 시간이 `0`인 경우 시간이 지나도 넘기지 않습니다.
+        // (English Translation) If the timeout is `0`, it never passes no matter how much time elapses.
+        let mut timer = IdleTimer::new(0);
+        assert!(!timer.is_enabled());
+        assert!(!timer.tick(1.0e6));
+    }
+
+    #[test]
+    fn only_user_input_counts_as_input() {
+        let window_id = unsafe { WindowId::dummy() };
+        let cursor_moved = Event::WindowEvent { 
+            window_id, 
+            event: WindowEvent::CursorMoved { 
+                device_id: unsafe { DeviceId::dummy() }, 
+                position: PhysicalPosition::new(0.0, 0.0), 
+            },
+        };
+        assert!(is_user_input(&cursor_moved));
+
+        let redraw = Event::WindowEvent { window_id, event: WindowEvent::RedrawRequested };
+        assert!(!is_user_input(&redraw));
+    }
+}
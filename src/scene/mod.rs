@@ -1,2 +1,6 @@
+pub mod back_stack;
+pub mod idle;
 pub mod node;
+pub mod phases;
+pub mod shutdown;
 pub mod state;
@@ -44,6 +44,21 @@ pub trait SceneNode : fmt::Debug {
         Ok(())
     }
 
+    /// #### 한국어 </br>
+    /// 애플리케이션이 종료될 때 장면 스택에 남아있는 게임 장면마다 호출되는 함수입니다. </br>
+    /// `exit`와 달리 다음 게임 장면을 준비하지 않으며, 재생 중인 소리를 멈추는 등의 정리만 수행합니다. </br>
+    /// <b>함수를 실행하는 도중 오류가 발생한 경우 `GameError`를 반환합니다.</b></br>
+    /// 
+    /// #### English (Translation) </br>
+    /// This function is called for each game scene remaining in the scene stack when the application terminates. </br>
+    /// Unlike `exit`, it does not prepare the next game scene, and only cleans up such as stopping playing sounds. </br>
+    /// <b>If an error occurs while executing the function, it returns `GameError`.</b></br>
+    /// 
+    #[inline]
+    fn on_shutdown(&mut self, shared: &mut Shared) -> AppResult<()> {
+        Ok(())
+    }
+
     /// #### 한국어 </br>
     /// 애플리케이션 윈도우 이벤트를 처리하는 함수입니다. </br>
     /// <b>함수를 실행하는 도중 오류가 발생한 경우 `GameError`를 반환합니다.</b></br>
@@ -82,4 +97,46 @@ pub trait SceneNode : fmt::Debug {
     fn draw(&self, shared: &mut Shared) -> AppResult<()> {
         Ok(())
     }
+
+    /// #### 한국어 </br>
+    /// 디버그 빌드에서 스크립트 파일이 다시 불러와졌을 때 호출되는 함수입니다. </br>
+    /// 새 스크립트는 이미 공유 객체에 등록되어 있습니다. </br>
+    /// <b>함수를 실행하는 도중 오류가 발생한 경우 `GameError`를 반환합니다.</b></br>
+    /// 
+    /// #### English (Translation) </br>
+    /// This function is called when the script file is reloaded in debug builds. </br>
+    /// The new script is already registered in the shared object. </br>
+    /// <b>If an error occurs while executing the function, it returns `GameError`.</b></br>
+    /// 
+    #[cfg(debug_assertions)]
+    #[inline]
+    fn on_script_reload(&mut self, shared: &mut Shared) -> AppResult<()> {
+        Ok(())
+    }
+
+    /// #### 한국어 </br>
+    /// 커서가 현재 게임 장면에서 누를 수 있는 버튼 위에 있는 경우 `true`를 반환하는 함수입니다. </br>
+    /// 게임 커서는 이 값에 따라 강조 이미지를 그립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// This function returns `true` if the cursor is over a button that can be pressed in the current game scene. </br>
+    /// The game cursor draws the highlighted image according to this value. </br>
+    /// 
+    #[inline]
+    fn hovers_button(&self, shared: &Shared) -> bool {
+        false
+    }
+
+    /// #### 한국어 </br>
+    /// 게임 장면의 배경색을 반환하는 함수입니다. </br>
+    /// 프레임 버퍼를 지울 때 이 색상을 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// This function returns the background color of the game scene. </br>
+    /// This color is used when clearing the framebuffer. </br>
+    /// 
+    #[inline]
+    fn clear_color(&self) -> wgpu::Color {
+        wgpu::Color::BLACK
+    }
 }
@@ -0,0 +1,218 @@
+/// #### 한국어 </br>
+/// 정해진 순서와 지속 시간에 따라 진행되는 게임 장면의 단계들 입니다. </br>
+/// 각 단계는 (지속 시간, 단계)로 주어지며, 지속 시간이 지나면 다음 단계로 넘어갑니다. </br>
+/// 지속 시간이 `f64::INFINITY`인 단계는 [`Phases::advance`]를 호출할 때까지 유지됩니다. </br>
+/// 마지막 단계는 지속 시간이 지나도 유지되며, 이때 [`Phases::is_finished`]가 `true`를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// The phases of a game scene that progress in a fixed order with durations. </br>
+/// Each phase is given as (duration, phase), and it moves to the next phase after the duration. </br>
+/// A phase whose duration is `f64::INFINITY` is kept until [`Phases::advance`] is called. </br>
+/// The last phase is kept even after its duration, and [`Phases::is_finished`] then returns `true`. </br>
+/// 
+#[derive(Debug, Clone)]
+pub struct Phases<P> {
+    phases: Vec<(f64, P)>,
+    index: usize,
+    timer: f64,
+}
+
+impl<P: Copy> Phases<P> {
+    /// #### 한국어 </br>
+    /// <b>주어진 단계가 비어있는 경우 프로그램 실행을 중단합니다.</b></br>
+    /// 
+    /// #### English (Translation) </br>
+    /// <b>Abort program execution if the given phases are empty.</b></br>
+    /// 
+    pub fn new<I>(phases: I) -> Self
+    where I: IntoIterator<Item = (f64, P)> {
+        let phases: Vec<_> = phases.into_iter().collect();
+        assert!(!phases.is_empty(), "The given phases must not be empty.");
+        Self { phases, index: 0, timer: 0.0 }
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 단계를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the current phase. </br>
+    /// 
+    #[inline]
+    pub fn current(&self) -> P {
+        self.phases[self.index].1
+    }
+
+    #[inline]
+    fn duration(&self) -> f64 {
+        self.phases[self.index].0
+    }
+
+    #[inline]
+    fn is_last(&self) -> bool {
+        self.index + 1 >= self.phases.len()
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 단계의 진행도를 `0.0`에서 `1.0` 사이의 값으로 반환합니다. </br>
+    /// 지속 시간이 없는 단계는 `1.0`, 끝나지 않는 단계는 `0.0`을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the progress of the current phase as a value between `0.0` and `1.0`. </br>
+    /// A phase without a duration returns `1.0`, and an endless phase returns `0.0`. </br>
+    /// 
+    pub fn progress(&self) -> f64 {
+        let duration = self.duration();
+        if duration <= 0.0 {
+            return 1.0;
+        } else if duration.is_infinite() {
+            return 0.0;
+        }
+        return (self.timer / duration).clamp(0.0, 1.0);
+    }
+
+    /// #### 한국어 </br>
+    /// 마지막 단계의 지속 시간이 지난 경우 `true`를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns `true` if the duration of the last phase has passed. </br>
+    /// 
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.is_last() && self.timer >= self.duration()
+    }
+
+    /// #### 한국어 </br>
+    /// 경과한 시간을 갱신하고, 지속 시간이 지난 단계들을 넘어갑니다. </br>
+    /// 남은 시간은 다음 단계로 이어지며, 이번 갱신에서 새로 진입한 단계들을 순서대로 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Updates the elapsed time and moves past the phases whose durations have passed. </br>
+    /// The remaining time carries over to the next phase, and it returns the phases newly entered in this update in order. </br>
+    /// 
+    pub fn tick(&mut self, elapsed_time: f64) -> Vec<P> {
+        self.timer += elapsed_time;
+
+        let mut entered = Vec::new();
+        while !self.is_last() && self.timer >= self.duration() {
+            self.timer -= self.duration();
+            self.index += 1;
+            entered.push(self.current());
+        }
+        return entered;
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 단계를 바로 끝내고 다음 단계로 넘어갑니다. </br>
+    /// 다음 단계가 있는 경우 그 단계를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Ends the current phase immediately and moves to the next phase. </br>
+    /// Returns the next phase if there is one. </br>
+    /// 
+    pub fn advance(&mut self) -> Option<P> {
+        if self.is_last() {
+            self.timer = self.timer.max(self.duration());
+            return None;
+        }
+        self.index += 1;
+        self.timer = 0.0;
+        return Some(self.current());
+    }
+
+    /// #### 한국어 </br>
+    /// 현재 단계를 끝까지 빨리 감습니다. 다음 갱신 때 다음 단계로 넘어갑니다. </br>
+    /// 끝나지 않는 단계는 영향을 받지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Fast-forwards the current phase to its end. It moves to the next phase on the next update. </br>
+    /// Endless phases are not affected. </br>
+    /// 
+    #[inline]
+    pub fn skip(&mut self) {
+        if self.duration().is_finite() {
+            self.timer = self.timer.max(self.duration());
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Step {
+        First,
+        Second,
+        Wait,
+        Last,
+    }
+
+    fn create_phases() -> Phases<Step> {
+        Phases::new([
+            (1.0, Step::First),
+            (0.5, Step::Second),
+            (f64::INFINITY, Step::Wait),
+            (2.0, Step::Last),
+        ])
+    }
+
+    #[test]
+    fn phases_advance_after_their_durations() {
+        let mut phases = create_phases();
+        assert_eq!(phases.current(), Step::First);
+        assert!(phases.tick(0.5).is_empty());
+        assert_eq!(phases.current(), Step::First);
+
+        // (한국어) 남은 시간은 다음 단계로 이어지며, 여러 단계를 한 번에 넘어갈 수 있습니다.
+        // (English Translation) The remaining time carries over, and several phases can be passed at once.
+        assert_eq!(phases.tick(1.25), vec![Step::Second, Step::Wait]);
+        assert_eq!(phases.current(), Step::Wait);
+
+        // (한국어) 끝나지 않는 단계는 직접 넘어가야 합니다.
+        // (English Translation) An endless phase must be advanced manually.
+        assert!(phases.tick(100.0).is_empty());
+        phases.skip();
+        assert!(phases.tick(0.0).is_empty());
+        assert_eq!(phases.advance(), Some(Step::Last));
+
+        assert!(phases.tick(3.0).is_empty());
+        assert_eq!(phases.current(), Step::Last);
+        assert!(phases.is_finished());
+        assert_eq!(phases.advance(), None);
+    }
+
+    #[test]
+    fn progress_at_phase_boundaries() {
+        let mut phases = create_phases();
+        assert_eq!(phases.progress(), 0.0);
+
+        phases.tick(0.5);
+        assert_eq!(phases.progress(), 0.5);
+
+        // (한국어) 경계에 도달하면 다음 단계의 시작으로 넘어갑니다.
+        // (English Translation) Reaching the boundary moves to the start of the next phase.
+        phases.tick(0.5);
+        assert_eq!(phases.current(), Step::Second);
+        assert_eq!(phases.progress(), 0.0);
+
+        phases.skip();
+        assert_eq!(phases.current(), Step::Second);
+        assert_eq!(phases.progress(), 1.0);
+
+        phases.tick(0.0);
+        assert_eq!(phases.current(), Step::Wait);
+        assert_eq!(phases.progress(), 0.0);
+
+        phases.advance();
+        phases.tick(2.0);
+        assert_eq!(phases.progress(), 1.0);
+        assert!(phases.is_finished());
+
+        // (한국어) 지속 시간이 없는 단계는 항상 끝난 상태입니다.
+        // (English Translation) A phase without a duration is always complete.
+        let phases = Phases::new([(0.0, Step::Last)]);
+        assert_eq!(phases.progress(), 1.0);
+        assert!(phases.is_finished());
+    }
+}
@@ -0,0 +1,115 @@
+//! #### 한국어 </br>
+//! 애플리케이션을 종료할 때 게임 스레드에서 실행되는 종료 절차를 정의합니다. </br>
+//! 윈도우 스레드는 게임 스레드가 종료 절차를 마칠 때까지 기다린 후 이벤트 루프를 끝내므로, 
+//! 저장 파일이나 설정 파일이 쓰는 도중에 끊기지 않습니다. </br>
+//! 
+//! #### English (Translation) </br>
+//! Defines the shutdown procedure that runs on the game thread when the application terminates. </br>
+//! The window thread waits until the game thread finishes the shutdown procedure before ending the event loop, 
+//! so the save file and the settings file are not cut off in the middle of writing. </br>
+//! 
+use std::collections::VecDeque;
+
+use crate::{
+    assets::bundle::AssetBundle,
+    components::{
+        save::{SaveData, SaveEncoder},
+        sound::BgmPlayer,
+        user::{Settings, SettingsEncoder},
+    },
+    nodes::path,
+    scene::node::SceneNode,
+    system::{
+        error::AppResult,
+        shared::Shared,
+    },
+};
+
+
+
+/// #### 한국어 </br>
+/// 장면 스택의 모든 게임 장면의 종료 함수(`on_shutdown`)를 가장 최근의 장면부터 호출하고, 
+/// 주어진 `flush` 함수로 남은 데이터를 기록한 뒤 배경 음악을 멈춥니다. </br>
+/// 함수를 실행하는 도중 오류가 발생한 경우 `GameError`를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Calls the shutdown function (`on_shutdown`) of all game scenes in the scene stack starting from the most recent one, 
+/// writes the remaining data with the given `flush` function, and then stops the background music. </br>
+/// If an error occurs while executing the function, it returns `GameError`. </br>
+/// 
+pub fn shutdown<F>(
+    scene_stack: &mut VecDeque<Box<dyn SceneNode>>, 
+    shared: &mut Shared, 
+    flush: F
+) -> AppResult<()>
+where F: FnOnce(&mut Shared) -> AppResult<()> {
+    while let Some(mut scene) = scene_stack.pop_back() {
+        scene.on_shutdown(shared)?;
+    }
+
+    flush(shared)?;
+
+    if let Some(bgm) = shared.get_mut::<BgmPlayer>() {
+        bgm.stop();
+    }
+
+    return Ok(());
+}
+
+/// #### 한국어 </br>
+/// 공유 객체에 등록된 설정과 저장 데이터를 에셋 파일에 기록합니다. </br>
+/// 준비 게임 장면이 끝나기 전에 종료되어 등록되지 않은 데이터는 기록하지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Writes the settings and save data registered in the shared object to the asset files. </br>
+/// Data that is not registered because the application closed before the setup game scene finished is not written. </br>
+/// 
+pub fn flush_user_data(shared: &mut Shared) -> AppResult<()> {
+    let asset_bundle = shared.get::<AssetBundle>().unwrap();
+    if let Some(settings) = shared.get::<Settings>() {
+        asset_bundle.get(path::SETTINGS_PATH)?.write(&SettingsEncoder, settings)?;
+        log::info!("Settings flushed.");
+    }
+    if let Some(save) = shared.get::<SaveData>() {
+        asset_bundle.get(path::SAVE_PATH)?.write(&SaveEncoder, save)?;
+        log::info!("Save data flushed.");
+    }
+
+    return Ok(());
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug)]
+    struct RecordingScene(&'static str);
+
+    impl SceneNode for RecordingScene {
+        fn on_shutdown(&mut self, shared: &mut Shared) -> AppResult<()> {
+            shared.get_mut::<Vec<&'static str>>().unwrap().push(self.0);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn save_flush_runs_on_simulated_close() {
+        let mut shared = Shared::new();
+        shared.push(Vec::<&'static str>::new());
+
+        let mut scene_stack: VecDeque<Box<dyn SceneNode>> = VecDeque::new();
+        scene_stack.push_back(Box::new(RecordingScene("Title")));
+        scene_stack.push_back(Box::new(RecordingScene("InGame")));
+
+        shutdown(&mut scene_stack, &mut shared, |shared| {
+            shared.get_mut::<Vec<&'static str>>().unwrap().push("Flush");
+            Ok(())
+        }).unwrap();
+
+        // (한국어) 모든 장면이 정리된 뒤에 데이터가 기록되어야 합니다.
+        // (English Translation) The data must be written after all scenes have been cleaned up.
+        assert!(scene_stack.is_empty());
+        assert_eq!(shared.get::<Vec<&'static str>>().unwrap(), &vec!["InGame", "Title", "Flush"]);
+    }
+}
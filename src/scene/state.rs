@@ -1,4 +1,56 @@
-use crate::scene::node::SceneNode;
+use winit::event::{Event, WindowEvent};
+
+use crate::{
+    game_err,
+    scene::node::SceneNode,
+    system::{
+        error::{AppResult, GameError},
+        event::AppEvent,
+        shared::Shared,
+    },
+};
+
+
+
+/// #### 한국어 </br>
+/// 상태 목록 열거형을 선언하고, 변형(variant)의 개수로 상태 개수 `NUM_STATES`를 정의합니다. </br>
+/// 상태별 함수 목록은 상태 번호로 접근하므로, 모든 상태 번호가 `NUM_STATES`보다 작은지 컴파일 시간에 확인합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Declares a state list enum, and defines the number of states `NUM_STATES` from the number of its variants. </br>
+/// Since the per-state function tables are accessed by state index, it checks at compile time that every state index is less than `NUM_STATES`. </br>
+/// 
+#[macro_export]
+macro_rules! state_enum {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident $(= $value:expr)?),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant $(= $value)?),+
+        }
+
+        impl $name {
+            /// #### 한국어 </br>
+            /// 상태의 개수입니다. </br>
+            /// 
+            /// #### English (Translation) </br>
+            /// The number of states. </br>
+            /// 
+            pub const NUM_STATES: usize = [$(stringify!($variant)),+].len();
+        }
+
+        const _: () = {
+            $(assert!(
+                ($name::$variant as usize) < $name::NUM_STATES,
+                concat!("`", stringify!($name), "::", stringify!($variant), "` is out of range of the state tables.")
+            );)+
+        };
+    };
+}
 
 
 
@@ -52,3 +104,168 @@ pub enum SceneState {
     /// 
     Reset(Box<dyn SceneNode>),
 }
+
+
+
+/// #### 한국어 </br>
+/// 다음 장면 상태변화가 예약되어 있는 경우 `true`를 반환합니다. </br>
+/// 이 경우 현재 장면은 곧 종료되므로 버튼 입력을 받지 않아야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns `true` if the next scene state change is already scheduled. </br>
+/// In this case the current scene is about to end, so it must not accept button input. </br>
+/// 
+#[inline]
+pub fn is_scene_changing(shared: &Shared) -> bool {
+    shared.get::<SceneState>().is_some_and(|state| !matches!(state, SceneState::Keep))
+}
+
+/// #### 한국어 </br>
+/// 버튼을 누르는 입력(마우스 버튼, 키보드)인 경우 `true`를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns `true` if the event is an input that presses a button. (mouse button, keyboard) </br>
+/// 
+#[inline]
+pub fn is_button_input(event: &Event<AppEvent>) -> bool {
+    matches!(
+        event, 
+        Event::WindowEvent { event: WindowEvent::MouseInput { .. } | WindowEvent::KeyboardInput { .. }, .. }
+    )
+}
+
+/// #### 한국어 </br>
+/// 게임 장면이 전환 중인 경우 버튼 입력을 무시해야 하는지 여부를 반환합니다. </br>
+/// 전환 중에도 커서 이동, 창 크기 변경 등 다른 이벤트는 처리되어야 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns whether the button input should be ignored because the game scene is transitioning. </br>
+/// Other events such as cursor movement and window resizing must still be handled during the transition. </br>
+/// 
+#[inline]
+pub fn ignores_button_input(transitioning: bool, event: &Event<AppEvent>) -> bool {
+    transitioning && is_button_input(event)
+}
+
+
+
+/// #### 한국어 </br>
+/// 상태별 함수 목록에서 주어진 상태 번호의 함수를 가져옵니다. </br>
+/// 목록에 해당 상태의 함수가 없는 경우 어느 목록인지 알려주는 `GameError`를 반환합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Gets the function of the given state index from the per-state function table. </br>
+/// If the table has no function for the state, it returns a `GameError` naming the table. </br>
+/// 
+#[inline]
+pub fn state_fn<F: ?Sized>(table: &[&'static F], index: usize, name: &str) -> AppResult<&'static F> {
+    table.get(index).copied().ok_or_else(|| game_err!(
+        "Invalid scene state",
+        "The function table `{}` has no entry for state {}. (length:{})",
+        name,
+        index,
+        table.len()
+    ))
+}
+
+
+
+/// #### 한국어 </br>
+/// 상태별 함수 목록을 사용하는 게임 장면의 상태 개수를 선언합니다. </br>
+/// [`SceneNode`]는 트레이트 객체로 사용되어 연관 상수를 가질 수 없으므로 별도의 트레이트로 분리합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Declares the number of states of a game scene that uses per-state function tables. </br>
+/// Since [`SceneNode`] is used as a trait object and cannot have associated constants, it is separated into its own trait. </br>
+/// 
+#[cfg(test)]
+pub trait SceneStates {
+    const NUM_STATES: usize;
+}
+
+/// #### 한국어 </br>
+/// (이름, 길이)로 주어진 상태별 함수 목록들의 길이가 게임 장면의 상태 개수와 같은지 확인합니다. </br>
+/// 각 게임 장면의 테스트에서 사용합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Checks that the lengths of the per-state function tables given as (name, length) 
+/// equal the number of states of the game scene. </br>
+/// It is used in the tests of each game scene. </br>
+/// 
+#[cfg(test)]
+pub fn debug_validate_tables<S: SceneStates>(tables: &[(&str, usize)]) -> AppResult<()> {
+    for &(name, len) in tables.iter() {
+        if len != S::NUM_STATES {
+            return Err(game_err!(
+                "Invalid scene state table",
+                "The function table `{}` has {} entries, but `{}` has {} states.",
+                name,
+                len,
+                std::any::type_name::<S>(),
+                S::NUM_STATES
+            ));
+        }
+    }
+    return Ok(());
+}
+
+
+#[cfg(test)]
+mod test {
+    use winit::{
+        dpi::PhysicalPosition,
+        event::{DeviceId, ElementState, MouseButton},
+        window::WindowId,
+    };
+    use super::*;
+
+    fn first() -> u32 { 1 }
+    fn second() -> u32 { 2 }
+
+    struct TwoStates;
+
+    impl SceneStates for TwoStates {
+        const NUM_STATES: usize = 2;
+    }
+
+    #[test]
+    fn state_fn_reports_missing_entries() {
+        const TABLE: [&'static dyn Fn() -> u32; 2] = [&first, &second];
+        assert_eq!(state_fn(&TABLE, 1, "TABLE").map(|f| f()), Ok(2));
+
+        let err = state_fn(&TABLE, 2, "TABLE").map(|f| f()).unwrap_err();
+        assert!(err.to_string().contains("`TABLE` has no entry for state 2"));
+    }
+
+    #[test]
+    fn table_drift_is_reported() {
+        assert!(debug_validate_tables::<TwoStates>(&[("UPDATES", 2), ("DRAWS", 2)]).is_ok());
+
+        let err = debug_validate_tables::<TwoStates>(&[("UPDATES", 2), ("DRAWS", 1)]).unwrap_err();
+        assert!(err.to_string().contains("`DRAWS` has 1 entries"));
+    }
+
+    #[test]
+    fn button_input_is_ignored_while_the_scene_is_changing() {
+        let window_id = unsafe { WindowId::dummy() };
+        let device_id = unsafe { DeviceId::dummy() };
+        let click = Event::WindowEvent { 
+            window_id, 
+            event: WindowEvent::MouseInput { device_id, state: ElementState::Pressed, button: MouseButton::Left },
+        };
+        let moved = Event::WindowEvent { 
+            window_id, 
+            event: WindowEvent::CursorMoved { device_id, position: PhysicalPosition::new(1.0, 2.0) },
+        };
+
+        let mut shared = Shared::new();
+        shared.push(SceneState::Keep);
+        assert!(!is_scene_changing(&shared));
+        assert!(!ignores_button_input(is_scene_changing(&shared), &click));
+
+        shared.push(SceneState::Pop);
+        assert!(is_scene_changing(&shared));
+        assert!(ignores_button_input(is_scene_changing(&shared), &click));
+        assert!(!ignores_button_input(is_scene_changing(&shared), &moved));
+    }
+}
@@ -1,5 +1,7 @@
 use std::fmt;
+use std::any::Any;
 use std::result::Result;
+use std::thread::JoinHandle;
 
 
 
@@ -77,6 +79,42 @@ impl ToString for GameError {
 
 
 
+/// #### 한국어 </br>
+/// 주어진 스레드가 끝날 때까지 기다리고 그 결과를 반환합니다. </br>
+/// 스레드에서 패닉이 발생한 경우 패닉 메시지를 담은 `GameError`를 반환하여 
+/// 애플리케이션이 오류 메시지를 표시하는 과정을 거쳐 중단되도록 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Waits for the given thread to finish and returns its result. </br>
+/// If the thread panicked, it returns a `GameError` containing the panic message 
+/// so that the application aborts through the path that displays the error message. </br>
+/// 
+pub fn join_thread<T>(handle: JoinHandle<AppResult<T>>, name: &str) -> AppResult<T> {
+    return handle.join().unwrap_or_else(|payload| Err(game_err!(
+        "Thread panicked",
+        "The {} thread panicked for the following reasons: {}",
+        name,
+        panic_message(payload.as_ref())
+    )));
+}
+
+/// #### 한국어 </br>
+/// 패닉 정보에서 패닉 메시지를 꺼냅니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Extracts the panic message from the panic payload. </br>
+/// 
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&'static str>() {
+        return message;
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        return message;
+    }
+    return "Unknown panic.";
+}
+
+
+
 /// #### 한국어 </br>
 /// 화면에 에러 메시지를 표시합니다. </br>
 /// 사용자가 확인 버튼을 누르면 애플리케이션 실행이 중단됩니다. </br>
@@ -107,3 +145,24 @@ pub fn popup_err_msg_and_abort(err: GameError) -> ! {
 
     abort()
 }
+
+
+#[cfg(test)]
+mod test {
+    use std::thread;
+    use super::*;
+
+    #[test]
+    fn panicking_worker_yields_a_game_error() {
+        let handle = thread::spawn(|| -> AppResult<u32> { Ok(1894) });
+        assert_eq!(join_thread(handle, "loading"), Ok(1894));
+
+        let handle = thread::spawn(|| -> AppResult<u32> { panic!("texture {} is missing", "Background") });
+        let err = join_thread(handle, "loading").unwrap_err();
+        assert!(err.to_string().contains("The loading thread panicked"));
+        assert!(err.to_string().contains("texture Background is missing"));
+
+        let handle = thread::spawn(|| -> AppResult<u32> { panic!("static message") });
+        assert!(join_thread(handle, "loading").unwrap_err().to_string().contains("static message"));
+    }
+}
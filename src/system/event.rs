@@ -1,3 +1,13 @@
+use std::sync::Mutex;
+use std::collections::VecDeque;
+
+use winit::{
+    event::{Event, WindowEvent},
+    window::WindowId,
+};
+
+
+
 /// #### 한국어 </br>
 /// 애플리케이션 이벤트 목록 입니다. </br>
 /// 
@@ -13,4 +23,289 @@ pub enum AppEvent {
     /// Quit the application. </br>
     /// 
     Terminate,
+
+    /// #### 한국어 </br>
+    /// 게임 스레드가 종료 절차를 마쳤음을 알립니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Notifies that the game thread has finished the shutdown procedure. </br>
+    /// 
+    Finished,
+}
+
+
+
+/// #### 한국어 </br>
+/// 윈도우 스레드가 받은 이벤트를 처리하는 방법 목록 입니다. </br>
+/// - 전달: 게임 스레드의 이벤트 대기열에 한 번 추가합니다. </br>
+/// - 종료: 게임 스레드에 종료를 요청합니다. 대기열에 추가하지 않습니다. </br>
+/// - 완료: 종료 절차를 마친 게임 스레드를 join 한 뒤 이벤트 루프를 끝냅니다. </br>
+/// - 무시: 게임 스레드에 전달하지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is a list of ways to handle events received by the window thread. </br>
+/// - Enqueue: Adds it once to the event queue of the game thread. </br>
+/// - Terminate: Requests the game thread to terminate. It is not enqueued. </br>
+/// - Finished: Joins the game thread that finished the shutdown procedure, then ends the event loop. </br>
+/// - Drop: It is not passed to the game thread. </br>
+/// 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventRoute {
+    Enqueue,
+    Terminate,
+    Finished,
+    Drop,
+}
+
+impl EventRoute {
+    /// #### 한국어 </br>
+    /// 이벤트를 처리하는 방법을 결정합니다. </br>
+    /// 게임 장면들은 애플리케이션 윈도우의 윈도우 이벤트만 처리하므로, 다음 이벤트들은 의도적으로 무시합니다. </br>
+    /// - 이벤트 루프 제어 이벤트 (`NewEvents`, `AboutToWait`, `LoopExiting`) </br>
+    /// - 장치 이벤트, 일시중단/재개 이벤트, 메모리 경고 </br>
+    /// - 다른 윈도우의 이벤트 </br>
+    /// 
+    /// 창 닫기 요청(`CloseRequested`), 창 파괴(`Destroyed`)와 종료 이벤트(`AppEvent::Terminate`, `AppEvent::Finished`)는 
+    /// 윈도우 스레드에서만 처리되며 게임 스레드에 전달되지 않습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Decides how to handle the event. </br>
+    /// Game scenes only handle window events of the application window, so the following events are intentionally dropped. </br>
+    /// - Event loop control events (`NewEvents`, `AboutToWait`, `LoopExiting`) </br>
+    /// - Device events, suspend/resume events, memory warnings </br>
+    /// - Events of other windows </br>
+    /// 
+    /// The close request (`CloseRequested`), window destruction (`Destroyed`), and terminate events (`AppEvent::Terminate`, `AppEvent::Finished`) 
+    /// are handled only on the window thread and are not passed to the game thread. </br>
+    /// 
+    pub fn of(event: &Event<AppEvent>, window_id: WindowId) -> Self {
+        match event {
+            Event::WindowEvent { window_id: id, .. } if *id != window_id => Self::Drop,
+            Event::WindowEvent { event: WindowEvent::CloseRequested | WindowEvent::Destroyed, .. } => Self::Terminate,
+            Event::WindowEvent { .. } => Self::Enqueue,
+            Event::UserEvent(AppEvent::Terminate) => Self::Terminate,
+            Event::UserEvent(AppEvent::Finished) => Self::Finished,
+            _ => Self::Drop,
+        }
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 윈도우 스레드가 추가하고 게임 스레드가 꺼내는 이벤트 대기열 입니다. </br>
+/// 게임 스레드가 멈춰 있는 동안 대기열이 끝없이 커지지 않도록 다음과 같이 동작합니다. </br>
+/// - 연속된 커서 이동 이벤트는 가장 최근의 이벤트 하나로 합쳐집니다. </br>
+/// - 최대 개수가 주어진 경우, 넘친 만큼 가장 오래된 중요하지 않은 이벤트부터 버립니다. </br>
+/// - 중요한 이벤트(크기 변경, 키보드, 마우스 버튼 등)는 버리지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// This is the event queue that the window thread pushes to and the game thread pops from. </br>
+/// It works as follows so that the queue does not grow endlessly while the game thread is stalled. </br>
+/// - Consecutive cursor move events are merged into the most recent one. </br>
+/// - If a maximum count is given, the oldest non-critical events are dropped as much as it overflows. </br>
+/// - Critical events (resizing, keyboard, mouse buttons, etc.) are never dropped. </br>
+/// 
+#[derive(Debug)]
+pub struct EventQueue {
+    events: Mutex<VecDeque<Event<AppEvent>>>,
+    capacity: Option<usize>,
+}
+
+impl EventQueue {
+    #[inline]
+    pub const fn new(capacity: Option<usize>) -> Self {
+        Self { events: Mutex::new(VecDeque::new()), capacity }
+    }
+
+    /// #### 한국어 </br>
+    /// 이벤트를 대기열의 끝에 추가합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Adds the event to the end of the queue. </br>
+    /// 
+    pub fn push(&self, event: Event<AppEvent>) {
+        let mut events = self.events.lock().expect("Failed to access variable.");
+
+        // (한국어) 연속된 커서 이동 이벤트는 가장 최근의 이벤트로 덮어씁니다.
+        // (English Translation) Consecutive cursor move events are overwritten by the most recent one.
+        if is_cursor_moved(&event) && events.back().is_some_and(is_cursor_moved) {
+            *events.back_mut().unwrap() = event;
+            return;
+        }
+        events.push_back(event);
+
+        // (한국어) 최대 개수를 넘은 경우 가장 오래된 중요하지 않은 이벤트를 버립니다.
+        // (English Translation) If it exceeds the maximum count, drops the oldest non-critical event.
+        if let Some(capacity) = self.capacity {
+            while events.len() > capacity {
+                match events.iter().position(|event| !is_critical(event)) {
+                    Some(idx) => { events.remove(idx); },
+                    None => break,
+                }
+            }
+        }
+    }
+
+    /// #### 한국어 </br>
+    /// 대기열의 가장 앞의 이벤트를 꺼냅니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Pops the event at the front of the queue. </br>
+    /// 
+    #[inline]
+    pub fn pop(&self) -> Option<Event<AppEvent>> {
+        self.events.lock().expect("Failed to access variable.").pop_front()
+    }
+
+    #[cfg(test)]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.events.lock().expect("Failed to access variable.").len()
+    }
+}
+
+#[inline]
+fn is_cursor_moved(event: &Event<AppEvent>) -> bool {
+    matches!(event, Event::WindowEvent { event: WindowEvent::CursorMoved { .. }, .. })
+}
+
+/// #### 한국어 </br>
+/// 대기열이 넘쳐도 버리지 않아야 하는 이벤트인 경우 `true`를 반환합니다. </br>
+/// 커서 이동, 창 이동과 같이 다음 이벤트가 이전 상태를 대신하는 이벤트만 버릴 수 있습니다. </br>
+/// 마우스 휠과 축 이동은 변화량이므로 버리면 입력이 사라지기 때문에 버리지 않습니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Returns `true` if the event must not be dropped even if the queue overflows. </br>
+/// Only events whose next event replaces the previous state can be dropped, 
+/// such as cursor moves and window moves. </br>
+/// Mouse wheels and axis motions are deltas, so they are kept because dropping them loses input. </br>
+/// 
+fn is_critical(event: &Event<AppEvent>) -> bool {
+    !matches!(
+        event, 
+        Event::WindowEvent { 
+            event: WindowEvent::CursorMoved { .. } 
+                | WindowEvent::Moved(_), 
+            .. 
+        }
+    )
+}
+
+
+#[cfg(test)]
+mod test {
+    use winit::{
+        dpi::{PhysicalPosition, PhysicalSize},
+        event::{DeviceId, StartCause, MouseScrollDelta, TouchPhase},
+    };
+    use super::*;
+
+    fn cursor_moved(window_id: WindowId, x: f64) -> Event<AppEvent> {
+        let device_id = unsafe { DeviceId::dummy() };
+        Event::WindowEvent { 
+            window_id, 
+            event: WindowEvent::CursorMoved { device_id, position: PhysicalPosition::new(x, 0.0) },
+        }
+    }
+
+    #[test]
+    fn consecutive_cursor_moves_collapse_to_latest() {
+        let window_id = unsafe { WindowId::dummy() };
+        let queue = EventQueue::new(None);
+        for x in 0..1000 {
+            queue.push(cursor_moved(window_id, x as f64));
+        }
+
+        assert_eq!(queue.len(), 1);
+        match queue.pop() {
+            Some(Event::WindowEvent { event: WindowEvent::CursorMoved { position, .. }, .. }) => {
+                assert_eq!(position.x, 999.0);
+            },
+            event => panic!("unexpected event: {:?}", event),
+        }
+        assert!(queue.pop().is_none());
+    }
+
+    #[test]
+    fn overflow_drops_oldest_non_critical_events() {
+        let window_id = unsafe { WindowId::dummy() };
+        let resized = Event::WindowEvent { window_id, event: WindowEvent::Resized(PhysicalSize::new(800, 600)) };
+        let moved = Event::WindowEvent { window_id, event: WindowEvent::Moved(PhysicalPosition::new(0, 0)) };
+        let close = Event::WindowEvent { window_id, event: WindowEvent::CloseRequested };
+
+        let queue = EventQueue::new(Some(2));
+        queue.push(moved.clone());
+        queue.push(resized.clone());
+        queue.push(moved.clone());
+        queue.push(close.clone());
+        queue.push(cursor_moved(window_id, 1.0));
+
+        // (한국어) 중요한 이벤트는 최대 개수를 넘어도 남아있어야 합니다.
+        // (English Translation) Critical events must remain even if they exceed the maximum count.
+        assert_eq!(queue.pop(), Some(resized));
+        assert_eq!(queue.pop(), Some(close));
+        assert!(queue.pop().is_none());
+    }
+
+    #[test]
+    fn mouse_wheel_deltas_survive_overflow() {
+        let window_id = unsafe { WindowId::dummy() };
+        let device_id = unsafe { DeviceId::dummy() };
+        let wheel = Event::WindowEvent { 
+            window_id, 
+            event: WindowEvent::MouseWheel { device_id, delta: MouseScrollDelta::LineDelta(0.0, 1.0), phase: TouchPhase::Moved },
+        };
+
+        let queue = EventQueue::new(Some(2));
+        for _ in 0..4 {
+            queue.push(wheel.clone());
+        }
+        queue.push(cursor_moved(window_id, 1.0));
+
+        // (한국어) 변화량 이벤트는 하나도 빠짐없이 전달되어야 합니다.
+        // (English Translation) Every delta event must be delivered.
+        for _ in 0..4 {
+            assert_eq!(queue.pop(), Some(wheel.clone()));
+        }
+        assert!(queue.pop().is_none());
+    }
+
+    #[test]
+    fn cursor_moved_is_enqueued_once_and_close_is_not() {
+        // (한국어) 가짜 식별자는 비교에만 사용됩니다.
+        // (English Translation) The dummy identifiers are used only for comparison.
+        let window_id = unsafe { WindowId::dummy() };
+        let device_id = unsafe { DeviceId::dummy() };
+
+        let events: Vec<Event<AppEvent>> = vec![
+            Event::NewEvents(StartCause::Poll),
+            Event::WindowEvent { 
+                window_id, 
+                event: WindowEvent::CursorMoved { device_id, position: PhysicalPosition::new(1.0, 2.0) },
+            },
+            Event::AboutToWait,
+            Event::WindowEvent { window_id, event: WindowEvent::CloseRequested },
+            Event::UserEvent(AppEvent::Terminate),
+            Event::UserEvent(AppEvent::Finished),
+        ];
+
+        let mut queue = Vec::new();
+        let mut terminate = 0;
+        let mut finished = 0;
+        for event in events {
+            match EventRoute::of(&event, window_id) {
+                EventRoute::Enqueue => queue.push(event),
+                EventRoute::Terminate => terminate += 1,
+                EventRoute::Finished => finished += 1,
+                EventRoute::Drop => { /* empty */ },
+            }
+        }
+
+        assert_eq!(queue.len(), 1);
+        assert!(matches!(queue[0], Event::WindowEvent { event: WindowEvent::CursorMoved { .. }, .. }));
+        assert!(!queue.iter().any(|event| matches!(event, Event::WindowEvent { event: WindowEvent::CloseRequested, .. })));
+        assert_eq!(terminate, 2);
+        assert_eq!(finished, 1);
+    }
 }
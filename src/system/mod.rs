@@ -1,4 +1,5 @@
 pub mod error;
 pub mod event;
+pub mod rng;
 pub mod shared;
 pub mod timer;
@@ -0,0 +1,173 @@
+use std::sync::{Mutex, MutexGuard};
+
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+
+
+/// #### 한국어 </br>
+/// 게임 진행에 사용하는 시드가 주어진 난수 생성기 입니다. </br>
+/// 같은 시드로 실행하면 보드 배치와 탄막 패턴이 같은 순서로 재현됩니다. </br>
+/// 소리의 재생 시점에 따라 뽑는 횟수가 달라지는 목소리, 배경 음악 등은 [`CosmeticRng`]를 사용해야 합니다. </br>
+/// 공유 객체에 `Arc<GameRng>`로 등록됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A seeded random number generator used for gameplay. </br>
+/// Running with the same seed reproduces the board layout and bullet patterns in the same order. </br>
+/// Voices, background music, etc. whose number of draws depends on audio timing must use [`CosmeticRng`]. </br>
+/// It is registered as `Arc<GameRng>` in the shared object. </br>
+/// 
+#[derive(Debug)]
+pub struct GameRng {
+    seed: u64,
+    rng: Mutex<StdRng>,
+}
+
+impl GameRng {
+    #[inline]
+    pub fn new(seed: u64) -> Self {
+        Self { seed, rng: Mutex::new(StdRng::seed_from_u64(seed)) }
+    }
+
+    /// #### 한국어 </br>
+    /// 임의의 시드로 난수 생성기를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a random number generator with a random seed. </br>
+    /// 
+    #[inline]
+    pub fn from_entropy() -> Self {
+        Self::new(rand::random())
+    }
+
+    /// #### 한국어 </br>
+    /// 난수 생성기의 시드를 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the seed of the random number generator. </br>
+    /// 
+    #[inline]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// #### 한국어 </br>
+    /// 난수 생성기를 잠그고 가져옵니다. 다른 곳에서 잠그지 않도록 짧게 사용해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Locks and gets the random number generator. It should be used briefly so that it does not block elsewhere. </br>
+    /// 
+    #[inline]
+    pub fn lock(&self) -> MutexGuard<'_, StdRng> {
+        self.rng.lock().expect("Failed to access variable.")
+    }
+
+    /// #### 한국어 </br>
+    /// 이 난수 생성기에서 시드를 뽑아 새로운 난수 생성기를 생성합니다. </br>
+    /// 다른 스레드에서 게임 장면을 준비할 때 재현 가능한 난수를 사용하기 위해 사용합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a new random number generator with a seed drawn from this random number generator. </br>
+    /// Used to get reproducible random numbers when preparing a game scene in another thread. </br>
+    /// 
+    #[inline]
+    pub fn fork(&self) -> StdRng {
+        StdRng::seed_from_u64(self.lock().gen())
+    }
+}
+
+
+
+/// #### 한국어 </br>
+/// 게임 진행에 영향을 주지 않는 목소리, 배경 음악 선택 등에 사용하는 난수 생성기 입니다. </br>
+/// 게임 진행용 난수 생성기에서 분기되므로 같은 시드로 재현할 수 있지만, 여기서 뽑은 난수는 게임 진행용 난수에 영향을 주지 않습니다. </br>
+/// 공유 객체에 `Arc<CosmeticRng>`로 등록됩니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// A random number generator used for voices, background music choices, etc. that do not affect gameplay. </br>
+/// It is forked from the gameplay random number generator so it can be reproduced with the same seed, 
+/// but the numbers drawn from it do not affect the gameplay random numbers. </br>
+/// It is registered as `Arc<CosmeticRng>` in the shared object. </br>
+/// 
+#[derive(Debug)]
+pub struct CosmeticRng {
+    rng: Mutex<StdRng>,
+}
+
+impl CosmeticRng {
+    /// #### 한국어 </br>
+    /// 게임 진행용 난수 생성기에서 분기하여 새로운 난수 생성기를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a new random number generator forked from the gameplay random number generator. </br>
+    /// 
+    #[inline]
+    pub fn new(gameplay: &GameRng) -> Self {
+        Self { rng: Mutex::new(gameplay.fork()) }
+    }
+
+    /// #### 한국어 </br>
+    /// 난수 생성기를 잠그고 가져옵니다. 다른 곳에서 잠그지 않도록 짧게 사용해야 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Locks and gets the random number generator. It should be used briefly so that it does not block elsewhere. </br>
+    /// 
+    #[inline]
+    pub fn lock(&self) -> MutexGuard<'_, StdRng> {
+        self.rng.lock().expect("Failed to access variable.")
+    }
+
+    /// #### 한국어 </br>
+    /// 이 난수 생성기에서 시드를 뽑아 새로운 난수 생성기를 생성합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a new random number generator with a seed drawn from this random number generator. </br>
+    /// 
+    #[inline]
+    pub fn fork(&self) -> StdRng {
+        StdRng::seed_from_u64(self.lock().gen())
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn same_seed_produces_identical_sequences() {
+        let a = GameRng::new(1884);
+        let b = GameRng::new(1884);
+        let seq_a: Vec<u32> = (0..16).map(|_| a.lock().gen()).collect();
+        let seq_b: Vec<u32> = (0..16).map(|_| b.lock().gen()).collect();
+        assert_eq!(seq_a, seq_b);
+        assert_eq!(a.seed(), b.seed());
+
+        // (한국어) 분기된 난수 생성기도 같은 순서를 만듭니다.
+        // (English Translation) Forked random number generators also produce the same sequence.
+        let (mut fork_a, mut fork_b) = (a.fork(), b.fork());
+        assert_eq!(fork_a.gen::<u64>(), fork_b.gen::<u64>());
+
+        let c = GameRng::new(1885);
+        let seq_c: Vec<u32> = (0..16).map(|_| c.lock().gen()).collect();
+        assert_ne!(seq_a, seq_c);
+    }
+
+    #[test]
+    fn cosmetic_draws_do_not_change_gameplay_sequence() {
+        let a = GameRng::new(1884);
+        let cosmetic_a = CosmeticRng::new(&a);
+        let b = GameRng::new(1884);
+        let cosmetic_b = CosmeticRng::new(&b);
+
+        // (한국어) 소리의 재생 시점에 따라 목소리를 뽑는 횟수가 달라져도 게임 진행용 난수는 같아야 합니다.
+        // (English Translation) Even if the number of voice draws differs depending on audio timing, the gameplay numbers must be the same.
+        for _ in 0..3 {
+            let _: u32 = cosmetic_a.lock().gen();
+        }
+        let _: u32 = cosmetic_b.lock().gen();
+
+        let seq_a: Vec<u32> = (0..16).map(|_| a.lock().gen()).collect();
+        let seq_b: Vec<u32> = (0..16).map(|_| b.lock().gen()).collect();
+        assert_eq!(seq_a, seq_b);
+    }
+}
@@ -1,7 +1,18 @@
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use std::num::NonZeroU32;
 
-const MAX_FRAME_TIMES: usize = 50;
+
+
+/// #### 한국어 </br>
+/// 공유 객체에 등록되어 있는 동안 게임이 일시정지 되었음을 나타냅니다. </br>
+/// 게임 루프는 이 객체가 있는 동안 타이머의 전체 시간을 멈추고 배경 음악을 일시정지 합니다. </br>
+/// 
+/// #### English (Translation) </br>
+/// Indicates that the game is paused while it is registered in the shared object. </br>
+/// While it exists, the game loop stops the total time of the timer and pauses the background music. </br>
+/// 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GamePaused;
 
 
 
@@ -9,23 +20,29 @@ const MAX_FRAME_TIMES: usize = 50;
 /// 애플리케이션에서 사용하는 타이머 입니다. </br>
 /// 매번 `tick`함수를 호출하여 경과 시간과 프레임 레이트를 측정합니다. </br>
 /// 
+/// 일시정지된 동안에도 경과 시간은 계속 측정되어 메뉴 조작을 위한 갱신은 계속 실행되지만, 
+/// 전체 시간은 늘어나지 않습니다. 다시 시작할 때 전체 시간은 멈춘 시점부터 이어집니다. </br>
+/// 
 /// #### English (Translation) </br>
 /// This is the timer used by the application. </br>
 /// Call the `tick` function each loop to measure the elapsed time and frame rate. </br>
 /// 
+/// While paused, the elapsed time is still measured so updates for menu interaction keep running, 
+/// but the total time does not increase. When resumed, the total time continues from where it stopped. </br>
+/// 
 #[derive(Debug, Clone, Copy)]
 pub struct GameTimer {
     base_timepoint: Instant,
     previous_timepoint: Instant,
     current_time_point: Instant,
 
-    frame_times: [f64; MAX_FRAME_TIMES],
-    cnt_frame_times: usize,
-
     elapsed_time_sec: f64,
     fps_elapsed_time_sec: f64,
     frame_per_seconds: u64,
     frame_rate: u64,
+
+    paused: bool,
+    paused_duration: Duration,
 }
 
 impl GameTimer {
@@ -39,49 +56,71 @@ impl GameTimer {
     /// 
     #[inline]
     pub fn new() -> Self {
-        let timepoint = Instant::now();
+        Self::new_at(Instant::now())
+    }
+
+    /// #### 한국어 </br>
+    /// 주어진 시점부터 측정을 시작하는 새로운 타이머를 생성합니다. </br>
+    /// [`GameTimer::tick_at`]과 함께 사용하여 시간을 직접 주입할 수 있습니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Creates a new timer that starts measuring from the given point in time. </br>
+    /// Can be used with [`GameTimer::tick_at`] to inject time manually. </br>
+    /// 
+    #[inline]
+    pub fn new_at(timepoint: Instant) -> Self {
         Self { 
             base_timepoint: timepoint, 
             previous_timepoint: timepoint, 
             current_time_point: timepoint, 
-            frame_times: [0.0; MAX_FRAME_TIMES],
-            cnt_frame_times: 0,
             elapsed_time_sec: 0.0,
             fps_elapsed_time_sec: 0.0,
             frame_per_seconds: 0,
-            frame_rate: 0
+            frame_rate: 0,
+            paused: false,
+            paused_duration: Duration::ZERO,
         }
     }
 
     /// #### 한국어 </br>
-    /// 경과 시간과 프레임 레이트를 측정합니다. </br>
-    /// `vsync`에 값이 주어질 경우 해당 프레임 레이트만큼 프로그램 실행을 지연시킵니다. </br>
+    /// 단조 증가하는 시계([`Instant`])로 경과 시간과 프레임 레이트를 측정합니다. </br>
+    /// - `tick(None)`: 현재 시점을 바로 측정합니다. </br>
+    /// - `tick(Some(fps))`: 이전 `tick`으로부터 `1 / fps`초가 지날 때까지 프로그램 실행을 지연시킨 후 측정합니다. </br>
     /// 
     /// #### English (Translation) </br>
-    /// Measure elapsed time and frame rate. </br>
-    /// If a value is given to `vsync`, program execution is delayed by the corresponding frame rate. </br>
+    /// Measure elapsed time and frame rate with a monotonic clock ([`Instant`]). </br>
+    /// - `tick(None)`: Measures the current point in time immediately. </br>
+    /// - `tick(Some(fps))`: Delays program execution until `1 / fps` seconds have passed since the previous `tick`, then measures. </br>
     /// 
     pub fn tick(&mut self, vsync: Option<NonZeroU32>) {
-        self.current_time_point = Instant::now();
-        let mut elapsed_time_sec = self.current_time_point
-            .saturating_duration_since(self.previous_timepoint)
-            .as_secs_f64();
-
+        let mut current_time_point = Instant::now();
         if let Some(fps) = vsync {
-            while elapsed_time_sec < (1.0 / fps.get() as f64) {
-                self.current_time_point = Instant::now();
-                elapsed_time_sec = self.current_time_point
-                    .saturating_duration_since(self.previous_timepoint)
-                    .as_secs_f64();
+            let frame_time_sec = 1.0 / fps.get() as f64;
+            while current_time_point.saturating_duration_since(self.previous_timepoint).as_secs_f64() < frame_time_sec {
+                current_time_point = Instant::now();
             }
         }
 
-        self.previous_timepoint = self.current_time_point;
+        self.tick_at(current_time_point);
+    }
 
-        if (self.elapsed_time_sec - elapsed_time_sec).abs() < 1.0 {
-            self.frame_times.copy_within(0..(MAX_FRAME_TIMES - 1), 1);
-            self.frame_times[0] = elapsed_time_sec;
-            self.cnt_frame_times = (self.cnt_frame_times + 1).min(MAX_FRAME_TIMES);
+    /// #### 한국어 </br>
+    /// 시계를 읽지 않고 주어진 시점까지 타이머를 진행시킵니다. </br>
+    /// 이전 시점보다 앞선 시점이 주어질 경우 경과 시간은 `0`으로 처리됩니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Advances the timer to the given point in time without reading the clock. </br>
+    /// If a point earlier than the previous one is given, the elapsed time is treated as `0`. </br>
+    /// 
+    pub fn tick_at(&mut self, current_time_point: Instant) {
+        self.current_time_point = current_time_point;
+        let elapsed_time = self.current_time_point
+            .saturating_duration_since(self.previous_timepoint);
+        let elapsed_time_sec = elapsed_time.as_secs_f64();
+
+        self.previous_timepoint = self.current_time_point;
+        if self.paused {
+            self.paused_duration += elapsed_time;
         }
 
         self.frame_per_seconds += 1;
@@ -92,25 +131,127 @@ impl GameTimer {
             self.fps_elapsed_time_sec -= 1.0;
         }
 
-        self.elapsed_time_sec = 0.0;
-        if self.cnt_frame_times > 0 {
-            self.elapsed_time_sec = self.frame_times
-                .iter()
-                .take(self.cnt_frame_times)
-                .sum();
-            self.elapsed_time_sec /= self.cnt_frame_times as f64;
-        }
+        self.elapsed_time_sec = elapsed_time_sec;
     }
 
+    /// #### 한국어 </br>
+    /// 이전 `tick`으로부터 경과한 시간(초)을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the time (in seconds) elapsed since the previous `tick`. </br>
+    /// 
     #[inline]
     pub fn elapsed_time_sec(&self) -> f64 {
         self.elapsed_time_sec
     }
 
+    /// #### 한국어 </br>
+    /// 타이머가 생성된 이후 일시정지된 시간을 제외하고 흐른 시간(초)을 반환합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Returns the time (in seconds) that has passed since the timer was created, excluding paused time. </br>
+    /// 
     #[inline]
     pub fn total_time_sec(&self) -> f64 {
         self.current_time_point.saturating_duration_since(
             self.base_timepoint
-        ).as_secs_f64()
+        ).saturating_sub(self.paused_duration).as_secs_f64()
+    }
+
+    /// #### 한국어 </br>
+    /// 다음 `tick`부터 전체 시간이 늘어나지 않도록 타이머를 일시정지 합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Pauses the timer so that the total time does not increase from the next `tick`. </br>
+    /// 
+    #[inline]
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// #### 한국어 </br>
+    /// 일시정지된 타이머를 다시 시작합니다. </br>
+    /// 
+    /// #### English (Translation) </br>
+    /// Resumes the paused timer. </br>
+    /// 
+    #[inline]
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn elapsed_time_is_the_last_frame_time() {
+        let mut timer = GameTimer::new();
+        let mut timepoint = timer.base_timepoint;
+        for millis in [10, 20, 30, 40] {
+            timepoint += Duration::from_millis(millis);
+            timer.tick_at(timepoint);
+        }
+
+        assert!((timer.elapsed_time_sec() - 0.040).abs() < 1e-9);
+    }
+
+    #[test]
+    fn total_time_accumulates() {
+        let base = Instant::now();
+        let mut timer = GameTimer::new_at(base);
+        assert_eq!(timer.total_time_sec(), 0.0);
+
+        let step = Duration::from_micros(16_667);
+        let mut timepoint = base;
+        for i in 1..=120u32 {
+            timepoint += step;
+            timer.tick_at(timepoint);
+            assert_eq!(timer.total_time_sec(), (step * i).as_secs_f64());
+            assert_eq!(timer.elapsed_time_sec(), step.as_secs_f64());
+        }
+    }
+
+    #[test]
+    fn earlier_timepoint_is_ignored() {
+        let base = Instant::now();
+        let mut timer = GameTimer::new_at(base + Duration::from_secs(1));
+        timer.tick_at(base);
+        assert_eq!(timer.elapsed_time_sec(), 0.0);
+        assert_eq!(timer.total_time_sec(), 0.0);
+    }
+
+    #[test]
+    fn paused_time_does_not_accumulate() {
+        let base = Instant::now();
+        let mut timer = GameTimer::new_at(base);
+        let step = Duration::from_millis(100);
+        let mut timepoint = base + step;
+        timer.tick_at(timepoint);
+        let paused_at = timer.total_time_sec();
+
+        // (한국어) 일시정지된 동안에도 메뉴 갱신을 위한 경과 시간은 측정됩니다.
+        // (English Translation) Even while paused, the elapsed time for menu updates is measured.
+        timer.pause();
+        for _ in 0..10 {
+            timepoint += step;
+            timer.tick_at(timepoint);
+            assert_eq!(timer.elapsed_time_sec(), step.as_secs_f64());
+            assert_eq!(timer.total_time_sec(), paused_at);
+        }
+
+        // (한국어) 다시 시작하면 멈춘 시점부터 이어집니다.
+        // (English Translation) When resumed, it continues from where it stopped.
+        timer.resume();
+        timepoint += step;
+        timer.tick_at(timepoint);
+        assert_eq!(timer.total_time_sec(), (step * 2).as_secs_f64());
     }
 }